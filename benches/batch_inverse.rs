@@ -0,0 +1,45 @@
+//! Scalar inversions one at a time, and with `Scalar::batch_inverse`
+//!
+//! Run with `cargo bench --bench batch_inverse`
+
+use eccoxide::curve::sec2::p256r1::Scalar;
+use std::hint::black_box;
+use std::time::Instant;
+
+const VALUES: usize = 1000;
+const ROUNDS: usize = 20;
+
+fn main() {
+    let mut s = Scalar::from_u64(0x5eed);
+    let values: Vec<Scalar> = (0..VALUES)
+        .map(|i| {
+            s = s.square() + Scalar::from_u64(i as u64 + 1);
+            s.clone()
+        })
+        .collect();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for v in values.iter() {
+            black_box(black_box(v).inverse());
+        }
+    }
+    let single = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let mut batch = values.clone();
+        black_box(Scalar::batch_inverse(black_box(&mut batch)));
+    }
+    let batch = start.elapsed();
+
+    let n = (ROUNDS * VALUES) as f64;
+    println!(
+        "inverse:         {:8.1} ns/scalar",
+        single.as_nanos() as f64 / n
+    );
+    println!(
+        "batch_inverse:   {:8.1} ns/scalar",
+        batch.as_nanos() as f64 / n
+    );
+}
@@ -0,0 +1,59 @@
+//! Repeated serialization of the same points, with and without `CachedPoint`
+//!
+//! Run with `cargo bench --bench cached_point`
+
+use eccoxide::curve::sec2::p256r1::{CachedPoint, Point, PointAffine, Scalar};
+use eccoxide::curve::Sign;
+use std::hint::black_box;
+use std::time::Instant;
+
+const POINTS: u64 = 16;
+const ROUNDS: usize = 10_000;
+
+fn compress(p: &PointAffine) -> [u8; 33] {
+    let (x, sign) = p.compress();
+    let mut out = [0u8; 33];
+    out[0] = match sign {
+        Sign::Positive => 0x02,
+        Sign::Negative => 0x03,
+    };
+    out[1..].copy_from_slice(&x.to_bytes());
+    out
+}
+
+fn main() {
+    let points: Vec<PointAffine> = (1..=POINTS)
+        .map(|n| {
+            (&Point::generator() * &Scalar::from_u64(n))
+                .to_affine()
+                .unwrap()
+        })
+        .collect();
+    let cached: Vec<CachedPoint> = points.iter().cloned().map(CachedPoint::new).collect();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for p in points.iter() {
+            black_box(compress(black_box(p)));
+        }
+    }
+    let plain = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for p in cached.iter() {
+            black_box(black_box(p).compressed_bytes());
+        }
+    }
+    let with_cache = start.elapsed();
+
+    let n = (ROUNDS as u64 * POINTS) as f64;
+    println!(
+        "compress:        {:8.1} ns/point",
+        plain.as_nanos() as f64 / n
+    );
+    println!(
+        "CachedPoint:     {:8.1} ns/point",
+        with_cache.as_nanos() as f64 / n
+    );
+}
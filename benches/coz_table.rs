@@ -0,0 +1,54 @@
+//! Variable time scalar multiplication of jacobian points, with the co-Z table of
+//! odd multiples and with the plain table (both with the doubling for any curve)
+//!
+//! Run with `cargo bench --bench coz_table`
+
+use eccoxide::curve::affine;
+use eccoxide::curve::jacobian::JacobianPoint;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ROUNDS: usize = 200;
+
+macro_rules! bench_curve {
+    ($curve:ident) => {{
+        use eccoxide::curve::sec2::$curve::{Curve, FieldElement, Point, Scalar};
+
+        let g = Point::generator().to_affine().unwrap();
+        let (x, y) = g.to_coordinate();
+        let p = JacobianPoint::<FieldElement>::from_affine(
+            &affine::Point::new(x.clone(), y.clone(), Curve).unwrap(),
+        );
+        let mut s = Scalar::from_u64(0x5eed);
+        let scalars: Vec<[u8; Scalar::SIZE_BYTES]> = (0..ROUNDS)
+            .map(|i| {
+                s = s.square() + Scalar::from_u64(i as u64 + 1);
+                s.to_bytes()
+            })
+            .collect();
+
+        let start = Instant::now();
+        for n in scalars.iter() {
+            black_box(black_box(&p).scale_vartime(n, Curve));
+        }
+        let coz = start.elapsed();
+
+        let start = Instant::now();
+        for n in scalars.iter() {
+            black_box(black_box(&p).scale_vartime_plain(n, Curve));
+        }
+        let plain = start.elapsed();
+
+        println!(
+            "{}: co-Z table {:8.1} us/mul, plain table {:8.1} us/mul",
+            stringify!($curve),
+            coz.as_nanos() as f64 / ROUNDS as f64 / 1000.0,
+            plain.as_nanos() as f64 / ROUNDS as f64 / 1000.0
+        );
+    }};
+}
+
+fn main() {
+    bench_curve!(p256k1);
+    bench_curve!(p256r1);
+}
@@ -0,0 +1,66 @@
+//! Scalar multiplications of the generator with the `Mul` operator (the Montgomery
+//! ladder) and with the `GeneratorTable` of `Point::base_scalar_mul`, e.g. for the
+//! key generations
+//!
+//! Run with `cargo bench --bench generator_table`
+
+use std::hint::black_box;
+use std::time::Instant;
+
+const VALUES: usize = 100;
+const ROUNDS: usize = 5;
+
+macro_rules! bench_curve {
+    ($name:literal, $module:path) => {{
+        use curve::{Point, Scalar};
+        use $module as curve;
+
+        let mut s = Scalar::from_u64(0x5eed);
+        let values: Vec<Scalar> = (0..VALUES)
+            .map(|i| {
+                s = s.square() + Scalar::from_u64(i as u64 + 1);
+                s.clone()
+            })
+            .collect();
+
+        let start = Instant::now();
+        black_box(Point::base_scalar_mul(&Scalar::one()));
+        let setup = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            for v in values.iter() {
+                black_box(&Point::generator() * black_box(v));
+            }
+        }
+        let ladder = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            for v in values.iter() {
+                black_box(Point::base_scalar_mul(black_box(v)));
+            }
+        }
+        let table = start.elapsed();
+
+        let n = (ROUNDS * VALUES) as f64;
+        println!(
+            "{} generator * scalar: {:8.1} us/scalar",
+            $name,
+            ladder.as_nanos() as f64 / n / 1000.0
+        );
+        println!(
+            "{} base_scalar_mul:    {:8.1} us/scalar ({:.1}x, table in {:.1} ms)",
+            $name,
+            table.as_nanos() as f64 / n / 1000.0,
+            ladder.as_nanos() as f64 / table.as_nanos() as f64,
+            setup.as_secs_f64() * 1000.0
+        );
+    }};
+}
+
+fn main() {
+    bench_curve!("p256k1", eccoxide::curve::sec2::p256k1);
+    bench_curve!("p256r1", eccoxide::curve::sec2::p256r1);
+    bench_curve!("p384r1", eccoxide::curve::sec2::p384r1);
+}
@@ -0,0 +1,60 @@
+//! Scalar inversions with the constant time addition chain and the variable time
+//! binary extended Euclidean algorithm, the latter being for the verification of
+//! signatures, where s is public
+//!
+//! Run with `cargo bench --bench inverse_vartime`
+
+use std::hint::black_box;
+use std::time::Instant;
+
+const VALUES: usize = 1000;
+const ROUNDS: usize = 20;
+
+macro_rules! bench_curve {
+    ($name:literal, $module:path) => {{
+        use curve::Scalar;
+        use $module as curve;
+
+        let mut s = Scalar::from_u64(0x5eed);
+        let values: Vec<Scalar> = (0..VALUES)
+            .map(|i| {
+                s = s.square() + Scalar::from_u64(i as u64 + 1);
+                s.clone()
+            })
+            .collect();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            for v in values.iter() {
+                black_box(black_box(v).inverse());
+            }
+        }
+        let ct = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            for v in values.iter() {
+                black_box(black_box(v).inverse_vartime());
+            }
+        }
+        let vartime = start.elapsed();
+
+        let n = (ROUNDS * VALUES) as f64;
+        println!(
+            "{} inverse:          {:8.1} ns/scalar",
+            $name,
+            ct.as_nanos() as f64 / n
+        );
+        println!(
+            "{} inverse_vartime:  {:8.1} ns/scalar ({:.1}x)",
+            $name,
+            vartime.as_nanos() as f64 / n,
+            ct.as_nanos() as f64 / vartime.as_nanos() as f64
+        );
+    }};
+}
+
+fn main() {
+    bench_curve!("p256r1", eccoxide::curve::sec2::p256r1);
+    bench_curve!("p384r1", eccoxide::curve::sec2::p384r1);
+}
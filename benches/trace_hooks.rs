@@ -0,0 +1,74 @@
+//! Operations carrying a trace hook: scalar multiplication, point decompression and
+//! bytes rejections, to compare a build with the `trace-secrets` feature and one without
+//!
+//! Without the feature, the hooks expand to nothing, so the timings are the same as
+//! before the hooks were added, and the binary has no `eccoxide::trace` symbol (checked
+//! by the `trace hooks` CI job).
+//!
+//! Run with `cargo bench --bench trace_hooks` and
+//! `cargo bench --bench trace_hooks --features trace-secrets`
+
+use eccoxide::curve::sec2::p256r1::{FieldElement, Point, PointAffine, Scalar};
+use eccoxide::curve::Sign;
+use std::hint::black_box;
+use std::time::Instant;
+
+const VALUES: usize = 100;
+const ROUNDS: usize = 10;
+
+fn main() {
+    let mut s = Scalar::from_u64(0x5eed);
+    let scalars: Vec<Scalar> = (0..VALUES)
+        .map(|i| {
+            s = s.square() + Scalar::from_u64(i as u64 + 1);
+            s.clone()
+        })
+        .collect();
+    let xs: Vec<[u8; 32]> = scalars
+        .iter()
+        .map(|s| {
+            let p = (&Point::generator() * s).to_affine().unwrap();
+            p.to_coordinate().0.to_bytes()
+        })
+        .collect();
+
+    let g = Point::generator();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for s in scalars.iter() {
+            black_box(black_box(&g) * black_box(s));
+        }
+    }
+    let mul = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for x in xs.iter() {
+            let x = FieldElement::from_bytes(black_box(x)).unwrap();
+            black_box(PointAffine::decompress(&x, Sign::Positive));
+        }
+    }
+    let decompress = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for _ in 0..VALUES {
+            black_box(Scalar::from_bytes(black_box(&[0xff; 32])));
+        }
+    }
+    let rejected = start.elapsed();
+
+    let n = (ROUNDS * VALUES) as f64;
+    println!(
+        "point * scalar:        {:8.1} ns/op",
+        mul.as_nanos() as f64 / n
+    );
+    println!(
+        "point decompression:   {:8.1} ns/op",
+        decompress.as_nanos() as f64 / n
+    );
+    println!(
+        "rejected scalar bytes: {:8.1} ns/op",
+        rejected.as_nanos() as f64 / n
+    );
+}
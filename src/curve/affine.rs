@@ -4,21 +4,79 @@
 //!
 //! Some other operations (negation, sub, etc) are also possible but this is not exhaustive
 use super::weierstrass::WeierstrassCurve;
-use crate::curve::field::{Field, FieldSqrt, Sign};
+use crate::curve::field::{Field, FieldSqrt, PrimeField, Sign};
+use crate::mp::ct::{Choice, CtEqual, CtSelect};
 use core::ops::{Add, Mul, Sub};
 
 /// Affine point operation over Field element FE
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// The coordinates are private, so that a point is only created on the curve
+/// with `new` or `from_coordinate`, or explicitly without any check with
+/// `new_unchecked`. The formulas don't detect the points that are not on the curve,
+/// and silently give meaningless results for them.
+///
+/// Equality compares both coordinates in constant time, without short-circuiting
+/// on the X coordinate.
+#[derive(Clone, Debug)]
 pub struct Point<FE> {
-    pub x: FE,
-    pub y: FE,
+    pub(crate) x: FE,
+    pub(crate) y: FE,
 }
 
+impl<FE: CtEqual> CtEqual for Point<FE> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+impl<FE: CtEqual> PartialEq for Point<FE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).is_true()
+    }
+}
+
+impl<FE: CtEqual> Eq for Point<FE> {}
+
 impl<FE: Field> Point<FE> {
+    /// Create a point from its coordinates, without checking that it's on the curve
+    ///
+    /// This is only for coordinates known to be on the curve (e.g. constants),
+    /// use `new` otherwise.
+    pub fn new_unchecked(x: FE, y: FE) -> Self {
+        Point { x, y }
+    }
+
+    /// The X coordinate
+    pub fn x(&self) -> &FE {
+        &self.x
+    }
+
+    /// The Y coordinate
+    pub fn y(&self) -> &FE {
+        &self.y
+    }
+
     pub fn to_coordinate(&self) -> (&FE, &FE) {
         (&self.x, &self.y)
     }
 
+    /// Consume the point, returning its coordinates without cloning them
+    pub fn into_coordinates(self) -> (FE, FE) {
+        (self.x, self.y)
+    }
+}
+
+impl<FE: Field + CtSelect> Point<FE> {
+    /// Negate the point if the choice is true, otherwise leave it unchanged
+    ///
+    /// The negated Y coordinate is always computed and selected in constant time
+    pub fn conditional_negate(&mut self, c: Choice) {
+        let ny = -self.y.clone();
+        self.y = FE::ct_select(&self.y, &ny, c);
+    }
+}
+
+impl<FE: PrimeField> Point<FE> {
     pub fn compress(&self) -> (&FE, Sign) {
         (&self.x, self.y.sign())
     }
@@ -26,7 +84,7 @@ impl<FE: Field> Point<FE> {
 
 impl<FE> Point<FE>
 where
-    FE: FieldSqrt,
+    FE: PrimeField + FieldSqrt,
     // extend field operation to `&FE OP &FE`
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
     for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
@@ -56,6 +114,29 @@ where
     }
 }
 
+impl<FE> Point<FE>
+where
+    FE: PrimeField + FieldSqrt + CtSelect,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// The two Y coordinates of the points with the X coordinate, the even one
+    /// (`Sign::Positive`) first, or None if X is not the coordinate of a point
+    ///
+    /// A single square root is computed, and the root is swapped with its
+    /// negation in constant time depending on its parity.
+    pub fn y_candidates<C: WeierstrassCurve<FieldElement = FE>>(
+        x: &FE,
+        curve: C,
+    ) -> Option<(FE, FE)> {
+        let yy = x.square() * x + (curve.a() * x) + curve.b();
+        let y = yy.sqrt().into_option()?;
+        let ny = -y.clone();
+        let odd = y.sign().to_choice();
+        Some((FE::ct_select(&y, &ny, odd), FE::ct_select(&ny, &y, odd)))
+    }
+}
+
 impl<FE> Point<FE>
 where
     FE: Field,
@@ -63,23 +144,31 @@ where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
     for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
 {
+    /// Create a point from its coordinates, or None if it's not on the curve
+    pub fn new<C: WeierstrassCurve<FieldElement = FE>>(x: FE, y: FE, curve: C) -> Option<Self> {
+        let p = Point { x, y };
+        if p.is_on_curve(curve) {
+            Some(p)
+        } else {
+            None
+        }
+    }
+
+    /// Same as `new` with borrowed coordinates
     pub fn from_coordinate<C: WeierstrassCurve<FieldElement = FE>>(
         x: &FE,
         y: &FE,
         curve: C,
     ) -> Option<Self> {
-        let y2 = y.square();
-        let x3 = x.square() * x;
-        let ax = curve.a() * x;
-
-        if y2 == x3 + ax + curve.b() {
-            Some(Point {
-                x: x.clone(),
-                y: y.clone(),
-            })
-        } else {
-            None
-        }
+        Self::new(x.clone(), y.clone(), curve)
+    }
+
+    /// Check that the point verifies the curve equation y^2 = x^3 + ax + b
+    pub fn is_on_curve<C: WeierstrassCurve<FieldElement = FE>>(&self, curve: C) -> bool {
+        let y2 = self.y.square();
+        let x3 = self.x.square() * &self.x;
+        let ax = curve.a() * &self.x;
+        y2 == x3 + ax + curve.b()
     }
 
     pub fn double<C: WeierstrassCurve<FieldElement = FE>>(&self, curve: C) -> Self {
@@ -87,7 +176,8 @@ where
             x: ref x1,
             y: ref y1,
         } = self;
-        let l = (FE::from(3u64) * (x1.square()) + curve.a()) * (y1.double()).inverse();
+        let xx = x1.square();
+        let l = (xx.double() + &xx + curve.a()) * (y1.double()).inverse();
         let l2 = l.square();
         let x3 = l2 - x1.double();
         let y3 = l * (x1 - &x3) - y1;
@@ -106,6 +196,10 @@ where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
     for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
 {
+    /// Add two points with different x coordinates, with the chord formula
+    ///
+    /// With the same x coordinate, the points are equal or opposite, and the result
+    /// is not meaningful: see `add` for the complete addition.
     pub fn add_different<'b>(&self, other: &'b Self) -> Self {
         let Point {
             x: ref x1,
@@ -121,8 +215,61 @@ where
         let y3 = l * (x1 - &x3) - y1;
         Point { x: x3, y: y3 }
     }
+
+    /// Add two points, or None if the result is the point at infinity, which
+    /// happens when the points are opposite
+    ///
+    /// Equal points are doubled, and the others added with the chord formula. The
+    /// choice between those cases is not constant time.
+    pub fn add<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        other: &Self,
+        curve: C,
+    ) -> Option<Self> {
+        if self.x != other.x {
+            Some(self.add_different(other))
+        } else if self.y == other.y && !self.y.is_zero() {
+            Some(self.double(curve))
+        } else {
+            None
+        }
+    }
+}
+
+// curve of the addition operator, which doesn't know the curve of the points: only
+// the doubling uses the curve parameters, so asking for them means the points are equal
+struct UnknownCurve<FE>(core::marker::PhantomData<FE>);
+
+impl<FE> Clone for UnknownCurve<FE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<FE> Copy for UnknownCurve<FE> {}
+
+impl<FE> WeierstrassCurve for UnknownCurve<FE> {
+    type FieldElement = FE;
+
+    fn a(self) -> &'static FE {
+        panic!("affine doubling needs the curve, see Point::add")
+    }
+    fn b(self) -> &'static FE {
+        panic!("affine doubling needs the curve, see Point::add")
+    }
+    fn b3(self) -> &'static FE {
+        panic!("affine doubling needs the curve, see Point::add")
+    }
 }
 
+/// Add two points, with the fallible `add`
+///
+/// # Panics
+///
+/// When the points are opposite, as their sum is the point at infinity, and when
+/// they are equal, as the doubling needs the curve parameters: see `Point::add` and
+/// `Point::double`. The curve specific points (e.g. `sec2::p256r1::PointAffine`) have
+/// an operator doubling equal points.
 impl<'x, 'y, FE> std::ops::Add<&'y Point<FE>> for &'x Point<FE>
 where
     FE: Field,
@@ -135,6 +282,7 @@ where
 {
     type Output = Point<FE>;
     fn add(self, other: &'y Point<FE>) -> Point<FE> {
-        self.add_different(other)
+        Point::add(self, other, UnknownCurve(core::marker::PhantomData))
+            .expect("affine sum is not the point at infinity")
     }
 }
@@ -1,350 +1,305 @@
 //!
-//! macros to generate various types ($SCALAR, PointAffine, Point) given different curve properties.
+//! macros to generate the curve types (PointAffine, Point) given different curve properties.
 //!
-//! Reference reading:
-//!
-//! * [Complete addition formulas for prime order elliptic curves](https://eprint.iacr.org/2015/1060.pdf) (1)
-//! * Handbook of Elliptic and Hyperelliptic Curve Cryptography - Chapter 13
-//! * [NIST.SP.800-186](https://csrc.nist.gov/publications/detail/sp/800-186/draft) : Appendix D & E
+//! The points are thin wrappers around the generic `affine::Point` and `projective::Point`
+//! over the BigUint field elements, the formulas being shared with the fiat curves.
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! point_impl {
-    ($FE:ident, $SCALAR:ident, $gx:expr, $gy:expr) => {
-        /// Affine Point on the curve
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub struct PointAffine {
-            x: $FE,
-            y: $FE,
+    ($FE:ident, $SCALAR:ident) => {
+        // the curve constants, computed on first use
+        fn curve_a() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE(BigUint::from_bytes_be(&A_BYTES)))
         }
 
-        /// Point on the curve
-        #[derive(Clone, Debug)]
-        pub struct Point {
-            x: $FE,
-            y: $FE,
-            z: $FE,
+        fn curve_b() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE(BigUint::from_bytes_be(&B_BYTES)))
         }
 
-        impl PartialEq for Point {
-            fn eq(&self, other: &Point) -> bool {
-                self.to_affine() == other.to_affine()
-            }
+        fn curve_b3() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| &(curve_b() + curve_b()) + curve_b())
+        }
+
+        fn generator_x() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE(BigUint::from_bytes_be(&GX_BYTES)))
         }
 
-        impl Eq for Point {}
+        fn generator_y() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE(BigUint::from_bytes_be(&GY_BYTES)))
+        }
+
+        impl WeierstrassCurve for Curve {
+            type FieldElement = $FE;
+
+            fn a(self) -> &'static Self::FieldElement {
+                curve_a()
+            }
+
+            fn b(self) -> &'static Self::FieldElement {
+                curve_b()
+            }
 
-        lazy_static! {
-            static ref A: $FE = $FE(BigUint::from_bytes_be(&A_BYTES));
-            static ref B: $FE = $FE(BigUint::from_bytes_be(&B_BYTES));
-            static ref B3: $FE =
-                $FE(BigUint::from_bytes_be(&B_BYTES) * BigUint::from_bytes_be(&[3]));
-            static ref GX: $FE = $FE(BigUint::from_bytes_be(&GX_BYTES));
-            static ref GY: $FE = $FE(BigUint::from_bytes_be(&GY_BYTES));
+            fn b3(self) -> &'static Self::FieldElement {
+                curve_b3()
+            }
         }
 
+        /// Affine Point on the curve
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct PointAffine(affine::Point<$FE>);
+
+        /// Point on the curve
+        ///
+        /// This uses the projective coordinates (X:Y:Z), and equality compares the
+        /// equivalence classes, so two representations of the same point are equal.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct Point(projective::Point<$FE>);
+
         impl PointAffine {
             /// Curve generator point
             pub fn generator() -> Self {
-                PointAffine {
-                    x: GX.clone(),
-                    y: GY.clone(),
-                }
+                PointAffine(affine::Point::new_unchecked(
+                    generator_x().clone(),
+                    generator_y().clone(),
+                ))
             }
 
             // check if y^2 = x^3 + a*x + b (mod p) holds
             pub fn from_coordinate(x: &$FE, y: &$FE) -> Option<Self> {
-                let y2 = y * y;
-                let x3 = x.power(3);
-                let ax = &*A * x;
-
-                if y2 == x3 + ax + &*B {
-                    Some(PointAffine {
-                        x: x.clone(),
-                        y: y.clone(),
-                    })
-                } else {
-                    None
-                }
+                affine::Point::from_coordinate(x, y, Curve).map(PointAffine)
             }
 
             pub fn to_coordinate(&self) -> (&$FE, &$FE) {
-                (&self.x, &self.y)
+                self.0.to_coordinate()
+            }
+
+            /// Consume the affine point, returning its coordinates without cloning them
+            pub fn into_coordinates(self) -> ($FE, $FE) {
+                self.0.into_coordinates()
             }
 
             pub fn double(&self) -> PointAffine {
-                let PointAffine {
-                    x: ref x1,
-                    y: ref y1,
-                } = self;
-                let l = ($FE::from_u64(3) * (x1 * x1) + &*A)
-                    * ($FE::from_u64(2) * y1).inverse().unwrap();
-                let l2 = &l * &l;
-                let x3 = l2 - $FE::from_u64(2) * x1;
-                let y3 = l * (x1 - &x3) - y1;
-                PointAffine { x: x3, y: y3 }
+                PointAffine(self.0.double(Curve))
             }
 
             pub fn compress(&self) -> (&$FE, bool) {
-                (&self.x, self.y.high_bit_set())
+                let (x, y) = self.0.to_coordinate();
+                (x, y.high_bit_set())
             }
 
             pub fn decompress(x: &$FE, bit: bool) -> Option<Self> {
                 // Y^2 = X^3 - A*X + b
-                let yy = x.power(3) + (&*A * x) + &*B;
+                let yy = x.power(3) + (Curve.a() * x) + Curve.b();
                 let y = yy.sqrt()?;
-                let x = x.clone();
-                if bit == y.high_bit_set() {
-                    Some(PointAffine { x, y })
-                } else {
-                    Some(PointAffine { x, y: -y })
-                }
+                let y = if bit == y.high_bit_set() { y } else { -y };
+                Some(PointAffine(affine::Point::new_unchecked(x.clone(), y)))
+            }
+
+            /// Add two affine points, or None if the result is the point at infinity,
+            /// which happens when the points are opposite
+            ///
+            /// Equal points are doubled, the others are added with the chord formula.
+            pub fn add(&self, other: &PointAffine) -> Option<PointAffine> {
+                self.0.add(&other.0, Curve).map(PointAffine)
             }
         }
 
+        /// Add two affine points
+        ///
+        /// # Panics
+        ///
+        /// When the points are opposite, as their sum is the point at infinity, see
+        /// `PointAffine::add` for the fallible addition.
         impl<'a, 'b> std::ops::Add<&'b PointAffine> for &'a PointAffine {
             type Output = PointAffine;
             fn add(self, other: &'b PointAffine) -> PointAffine {
-                let PointAffine {
-                    x: ref x1,
-                    y: ref y1,
-                } = &self;
-                let PointAffine {
-                    x: ref x2,
-                    y: ref y2,
-                } = &other;
-                let l = (y1 - y2) * (x1 - x2).inverse().expect("inverse exist");
-                let l2 = &l * &l;
-                let x3 = l2 - x1 - x2;
-                let y3 = &l * (x1 - &x3) - y1;
-                PointAffine { x: x3, y: y3 }
+                PointAffine::add(self, other).expect("affine sum is not the point at infinity")
+            }
+        }
+
+        // the compressed points of the bool convention of `compress` have the parity of Y
+        // as the sign, the one of SEC1 and of the fiat curves
+        fn parity(y: &$FE) -> $crate::curve::Sign {
+            if y.to_bytes()[$FE::SIZE_BYTES - 1] & 1 == 1 {
+                $crate::curve::Sign::Negative
+            } else {
+                $crate::curve::Sign::Positive
+            }
+        }
+
+        impl $crate::curve::EllipticCurve for Curve {
+            type FieldElement = $FE;
+            type Scalar = $SCALAR;
+            type Point = Point;
+            type PointAffine = PointAffine;
+
+            const FE_BYTES: usize = $FE::SIZE_BYTES;
+            const SCALAR_BYTES: usize = $SCALAR::SIZE_BYTES;
+
+            fn generator() -> Point {
+                Point::generator()
+            }
+
+            fn order_bytes() -> &'static [u8] {
+                &ORDER_BYTES
+            }
+
+            fn scalar_from_bytes(bytes: &[u8]) -> Option<$SCALAR> {
+                $SCALAR::from_slice(bytes)
+            }
+
+            fn scalar_to_bytes(scalar: &$SCALAR) -> Vec<u8> {
+                scalar.to_bytes().to_vec()
+            }
+
+            fn field_element_from_bytes(bytes: &[u8]) -> Option<$FE> {
+                $FE::from_slice(bytes)
+            }
+
+            fn field_element_to_bytes(fe: &$FE) -> Vec<u8> {
+                fe.to_bytes().to_vec()
+            }
+
+            fn point_decompress(x: &$FE, sign: $crate::curve::Sign) -> Option<PointAffine> {
+                let p = PointAffine::decompress(x, false)?;
+                let y = p.to_coordinate().1;
+                if parity(y) == sign {
+                    Some(p)
+                } else {
+                    PointAffine::from_coordinate(x, &-y)
+                }
+            }
+
+            fn point_compress(p: &PointAffine) -> ($FE, $crate::curve::Sign) {
+                let (x, y) = p.to_coordinate();
+                (x.clone(), parity(y))
+            }
+
+            fn point_to_affine(p: &Point) -> Option<PointAffine> {
+                p.to_affine()
+            }
+
+            fn point_from_affine(p: &PointAffine) -> Point {
+                Point::from_affine(p)
+            }
+
+            fn add(p: &Point, q: &Point) -> Point {
+                p + q
+            }
+
+            fn scale(p: &Point, scalar: &$SCALAR) -> Point {
+                p.scale_ct(scalar)
             }
         }
 
         impl Point {
             /// Curve generator point
             pub fn generator() -> Self {
-                Point {
-                    x: GX.clone(),
-                    y: GY.clone(),
-                    z: $FE::one(),
-                }
+                Point::from_affine(&PointAffine::generator())
             }
 
             /// Point at infinity
             pub fn infinity() -> Self {
-                Point {
-                    x: $FE::zero(),
-                    y: $FE::one(),
-                    z: $FE::zero(),
-                }
+                Point(projective::Point::infinity())
             }
 
             pub fn from_affine(p: &PointAffine) -> Self {
-                Point {
-                    x: p.x.clone(),
-                    y: p.y.clone(),
-                    z: $FE::one(),
-                }
+                Point(projective::Point::from_affine(&p.0))
             }
 
+            /// Affine point, None for the point at infinity
+            ///
+            /// This costs a field inversion, also when the point is already normalized
             pub fn to_affine(&self) -> Option<PointAffine> {
-                match self.z.inverse() {
-                    None => None,
-                    Some(inv) => Some(PointAffine {
-                        x: &self.x * &inv,
-                        y: &self.y * &inv,
-                    }),
-                }
+                self.0.to_affine().map(PointAffine)
             }
 
+            /// Same as `to_affine`, but consuming the point, which reuses the buffers
+            /// of the coordinates
+            pub fn into_affine(self) -> Option<PointAffine> {
+                self.0.into_affine().map(PointAffine)
+            }
+
+            /// Normalize the point, leaving the point at infinity unchanged
+            ///
+            /// This costs a field inversion, also when the point is already normalized
             pub fn normalize(&mut self) {
-                let zinv = self.z.inverse().unwrap();
-
-                self.x = &self.x * &zinv;
-                self.y = &self.y * &zinv;
-                self.z = $FE::one()
-            }
-
-            fn add_different<'b>(&self, other: &'b Point) -> Point {
-                assert!(self != other);
-
-                let Point {
-                    x: ref x1,
-                    y: ref y1,
-                    z: ref z1,
-                } = &self;
-                let Point {
-                    x: ref x2,
-                    y: ref y2,
-                    z: ref z2,
-                } = &other;
-
-                // Algorithm 1 from (1) - addition formula for arbitrary a
-                //
-                // ```magma
-                // ADD := function ( X1 , Y1 , Z1 , X2 , Y2 , Z2 ,a , b3 )
-                //     t0 := X1 * X2 ; t1 := Y1 * Y2 ; t2 := Z1 * Z2 ;
-                //     t3 := X1 + Y1 ; t4 := X2 + Y2 ; t3 := t3 * t4 ;
-                //     t4 := t0 + t1 ; t3 := t3 - t4 ; t4 := X1 + Z1 ;
-                //     t5 := X2 + Z2 ; t4 := t4 * t5 ; t5 := t0 + t2 ;
-                //     t4 := t4 - t5 ; t5 := Y1 + Z1 ; X3 := Y2 + Z2 ;
-                //     t5 := t5 * X3 ; X3 := t1 + t2 ; t5 := t5 - X3 ;
-                //     Z3 := a * t4 ; X3 := b3 * t2 ; Z3 := X3 + Z3 ;
-                //     X3 := t1 - Z3 ; Z3 := t1 + Z3 ; Y3 := X3 * Z3 ;
-                //     t1 := t0 + t0 ; t1 := t1 + t0 ; t2 := a * t2 ;
-                //     t4 := b3 * t4 ; t1 := t1 + t2 ; t2 := t0 - t2 ;
-                //     t2 := a * t2 ; t4 := t4 + t2 ; t0 := t1 * t4 ;
-                //     Y3 := Y3 + t0 ; t0 := t5 * t4 ; X3 := t3 * X3 ;
-                //     X3 := X3 - t0 ; t0 := t3 * t1 ; Z3 := t5 * Z3 ;
-                //     Z3 := Z3 + t0 ;
-                //     return X3 , Y3 , Z3 ;
-                // end function ;
-                // ```
-
-                let t0 = x1 * x2;
-                let t1 = y1 * y2;
-                let t2 = z1 * z2;
-                let t3 = x1 + y1;
-                let t4 = x2 + y2;
-                let t3 = t3 * t4;
-                let t4 = &t0 + &t1;
-                let t3 = t3 - &t4;
-                let t4 = x1 + z1;
-                let t5 = x2 + z2;
-                let t4 = t4 * &t5;
-                let t5 = &t0 + &t2;
-                let t4 = t4 - &t5;
-                let t5 = y1 + z1;
-                let x3 = y2 + z2;
-                let t5 = t5 * &x3;
-                let x3 = &t1 + &t2;
-                let t5 = t5 - &x3;
-                let z3 = &*A * &t4;
-                let x3 = &*B3 * &t2;
-                let z3 = &x3 + z3;
-                let x3 = &t1 - &z3;
-                let z3 = &t1 + &z3;
-                let y3 = &x3 * &z3;
-                let t1 = t0.double();
-                let t1 = t1 + &t0;
-                let t2 = &*A * t2;
-                let t4 = &*B3 * &t4;
-                let t1 = t1 + &t2;
-                let t2 = &t0 - t2;
-                let t2 = &*A * t2;
-                let t4 = t4 + &t2;
-                let t0 = &t1 * &t4;
-                let y3 = y3 + t0;
-                let t0 = &t5 * &t4;
-                let x3 = &t3 * x3;
-                let x3 = x3 - &t0;
-                let t0 = &t3 * &t1;
-                let z3 = &t5 * &z3;
-                let z3 = z3 + t0;
-
-                Point {
-                    x: x3,
-                    y: y3,
-                    z: z3,
-                }
+                self.0.normalize()
             }
 
-            pub fn double(&self) -> Self {
-                let Point {
-                    x: ref x,
-                    y: ref y,
-                    z: ref z,
-                } = &self;
-
-                // Algorithm 3 from (1) - doubling formula for arbitrary a
-                // ```magma
-                // DBL := function (X ,Y ,Z ,a , b3 )
-                //    t0 := X ^2; t1 := Y ^2; t2 := Z ^2;
-                //    t3 := X * Y ; t3 := t3 + t3 ; Z3 := X * Z ;
-                //    Z3 := Z3 + Z3 ; X3 := a * Z3 ; Y3 := b3 * t2 ;
-                //    Y3 := X3 + Y3 ; X3 := t1 - Y3 ; Y3 := t1 + Y3 ;
-                //    Y3 := X3 * Y3 ; X3 := t3 * X3 ; Z3 := b3 * Z3 ;
-                //    t2 := a * t2 ; t3 := t0 - t2 ; t3 := a * t3 ;
-                //    t3 := t3 + Z3 ; Z3 := t0 + t0 ; t0 := Z3 + t0 ;
-                //    t0 := t0 + t2 ; t0 := t0 * t3 ; Y3 := Y3 + t0 ;
-                //    t2 := Y * Z ; t2 := t2 + t2 ; t0 := t2 * t3 ;
-                //    X3 := X3 - t0 ; Z3 := t2 * t1 ; Z3 := Z3 + Z3 ;
-                //    Z3 := Z3 + Z3 ;
-                //    return X3 , Y3 , Z3 ;
-                // end function ;
-                // ```
-                let t0 = x * x;
-                let t1 = y * y;
-                let t2 = z * z;
-                let t3 = x * y;
-                let t3 = t3.double();
-                let z3 = x * z;
-                let z3 = &z3 + &z3;
-                let x3 = &*A * &z3;
-                let y3 = &*B3 * &t2;
-                let y3 = &x3 + &y3;
-                let x3 = &t1 - &y3;
-                let y3 = &t1 + &y3;
-                let y3 = &x3 * &y3;
-                let x3 = &t3 * &x3;
-                let z3 = &*B3 * &z3;
-                let t2 = &*A * &t2;
-                let t3 = &t0 - &t2;
-                let t3 = &*A * &t3;
-                let t3 = &t3 + &z3;
-                let z3 = &t0 + &t0;
-                let t0 = &z3 + &t0;
-                let t0 = &t0 + &t2;
-                let t0 = &t0 * &t3;
-                let y3 = &y3 + &t0;
-                let t2 = y * z;
-                let t2 = &t2 + &t2;
-                let t0 = &t2 * &t3;
-                let x3 = &x3 - &t0;
-                let z3 = &t2 * &t1;
-                let z3 = &z3 + &z3;
-                let z3 = &z3 + &z3;
-
-                Point {
-                    x: x3,
-                    y: y3,
-                    z: z3,
-                }
+            /// Same as `normalize`, by value
+            pub fn normalized(self) -> Self {
+                Point(self.0.normalized())
             }
 
-            fn add_or_double<'b>(&self, other: &'b Point) -> Point {
-                if self == other {
-                    self.double()
-                } else {
-                    self.add_different(other)
-                }
+            /// Check if Z is one, which is a single comparison. The point at infinity
+            /// is not normalized
+            pub fn is_normalized(&self) -> bool {
+                self.0.is_normalized()
             }
 
-            /// scalar multiplication : `n * self` with double-and-add algorithm with increasing index
-            fn scalar_mul_daa_limbs8(&self, n: &[u8]) -> Self {
-                let mut a = self.clone();
-                let mut q = Point::infinity();
+            /// Scalar multiplication by a big endian integer of any size
+            ///
+            /// See the `Mul` implementation for the semantics, for example multiplying
+            /// by the group order bytes gives the point at infinity.
+            ///
+            /// Inputs longer than `SIZE_BYTES + 8` bytes of the scalar trigger a debug
+            /// assertion (`SIZE_BYTES` with the `paranoid` feature), see `scale_bytes_exact`
+            pub fn scale_bytes(&self, n: &[u8]) -> Point {
+                $crate::curve::check_scale_bytes_len(n.len(), $SCALAR::SIZE_BYTES);
+                Point(self.0.scale_vartime(n, Curve))
+            }
 
-                for digit in n.iter().rev() {
-                    for i in 0..8 {
-                        if digit & (1 << i) != 0 {
-                            q = &q + &a;
-                        }
-                        a = a.double()
-                    }
-                }
-                q
+            /// Scalar multiplication by a small integer, `n * P`
+            ///
+            /// Only the 64 bits of n are processed, which is faster than a multiplication
+            /// by the same value as a `Scalar`. This is also `self * n`
+            pub fn scale_u64(&self, n: u64) -> Point {
+                self.scale_bytes(&n.to_be_bytes())
+            }
+
+            /// Scalar multiplication by a big endian integer of exactly the size of a scalar
+            pub fn scale_bytes_exact(&self, n: &[u8; $SCALAR::SIZE_BYTES]) -> Point {
+                Point(self.0.scale_vartime(n, Curve))
+            }
+
+            pub fn double(&self) -> Self {
+                Point(self.0.double(Curve))
+            }
+
+            /// Scalar multiplication with a Montgomery ladder, one addition and one doubling
+            /// for every bit of the scalar
+            ///
+            /// This is the `Mul` operator with the `default-mul-ct` feature (the default), and
+            /// is always available. Note that only the sequence of operations is regular, the
+            /// arbitrary precision arithmetic of these curves is not constant time.
+            pub fn scale_ct(&self, scalar: &$SCALAR) -> Point {
+                Point(self.0.scale_ladder(&scalar.to_bytes(), Curve))
+            }
+
+            /// Variable time scalar multiplication, with double-and-add
+            ///
+            /// This is the `Mul` operator with the `default-mul-vartime` feature, and is
+            /// always available.
+            pub fn scale_vartime(&self, scalar: &$SCALAR) -> Point {
+                Point(self.0.scale_vartime(&scalar.to_bytes(), Curve))
             }
         }
 
         impl From<PointAffine> for Point {
             fn from(p: PointAffine) -> Self {
-                Point {
-                    x: p.x,
-                    y: p.y,
+                let (x, y) = p.into_coordinates();
+                Point(projective::Point {
+                    x,
+                    y,
                     z: $FE::one(),
-                }
+                })
             }
         }
 
@@ -362,11 +317,7 @@ macro_rules! point_impl {
             type Output = Point;
 
             fn neg(self) -> Self::Output {
-                Point {
-                    x: self.x,
-                    y: -self.y,
-                    z: self.z,
-                }
+                Point(-self.0)
             }
         }
 
@@ -374,11 +325,7 @@ macro_rules! point_impl {
             type Output = Point;
 
             fn neg(self) -> Self::Output {
-                Point {
-                    x: self.x.clone(),
-                    y: -&self.y,
-                    z: self.z.clone(),
-                }
+                Point(-&self.0)
             }
         }
 
@@ -390,11 +337,49 @@ macro_rules! point_impl {
         // (of any size), not just the *field element* scalar defined in F(p).
         // this semantic abuse makes it easier to use.
 
+        /// Scalar multiplication `n * P`
+        ///
+        /// The result only depends on n modulo the group order: multiplying by zero (or by
+        /// the order with `scale_bytes`) gives the point at infinity, multiplying by one gives
+        /// a point equal to P, and multiplying the point at infinity gives the point at infinity.
+        ///
+        /// This is `Point::scale_ct` with the `default-mul-ct` feature (the default), and
+        /// `Point::scale_vartime` with the `default-mul-vartime` feature.
         impl<'a, 'b> std::ops::Mul<&'b $SCALAR> for &'a Point {
             type Output = Point;
 
+            #[cfg(feature = "default-mul-ct")]
+            fn mul(self, other: &'b $SCALAR) -> Point {
+                self.scale_ct(other)
+            }
+
+            #[cfg(not(feature = "default-mul-ct"))]
+            fn mul(self, other: &'b $SCALAR) -> Point {
+                self.scale_vartime(other)
+            }
+        }
+
+        impl<'a> std::ops::Mul<$SCALAR> for &'a Point {
+            type Output = Point;
+
+            fn mul(self, other: $SCALAR) -> Point {
+                self * &other
+            }
+        }
+
+        impl<'b> std::ops::Mul<&'b $SCALAR> for Point {
+            type Output = Point;
+
             fn mul(self, other: &'b $SCALAR) -> Point {
-                self.scalar_mul_daa_limbs8(&other.to_bytes())
+                &self * other
+            }
+        }
+
+        impl std::ops::Mul<$SCALAR> for Point {
+            type Output = Point;
+
+            fn mul(self, other: $SCALAR) -> Point {
+                &self * &other
             }
         }
 
@@ -406,6 +391,35 @@ macro_rules! point_impl {
             }
         }
 
+        /// Scalar multiplication by a small integer, see `Point::scale_u64`
+        impl<'a> std::ops::Mul<u64> for &'a Point {
+            type Output = Point;
+
+            fn mul(self, other: u64) -> Point {
+                self.scale_u64(other)
+            }
+        }
+
+        impl std::ops::Mul<u64> for Point {
+            type Output = Point;
+
+            fn mul(self, other: u64) -> Point {
+                self.scale_u64(other)
+            }
+        }
+
+        /// Scalar multiplication by the big endian bytes of a scalar
+        ///
+        /// The bytes are parsed with `Scalar::from_bytes`, so the result is None when
+        /// they are not less than the group order (`Point::scale_bytes_exact` accepts any value)
+        impl<'a, 'b> std::ops::Mul<&'b [u8; $SCALAR::SIZE_BYTES]> for &'a Point {
+            type Output = Option<Point>;
+
+            fn mul(self, other: &'b [u8; $SCALAR::SIZE_BYTES]) -> Option<Point> {
+                $SCALAR::from_bytes(other).map(|s| self * &s)
+            }
+        }
+
         // **************
         // Point Addition
         // **************
@@ -414,7 +428,7 @@ macro_rules! point_impl {
             type Output = Point;
 
             fn add(self, other: &'b Point) -> Point {
-                self.add_or_double(other)
+                Point(self.0.add_or_double(&other.0, Curve))
             }
         }
 
@@ -441,6 +455,38 @@ macro_rules! point_impl {
                 &self + &other
             }
         }
+
+        impl<'a, 'b> std::ops::Sub<&'b Point> for &'a Point {
+            type Output = Point;
+
+            fn sub(self, other: &'b Point) -> Point {
+                self + (-other)
+            }
+        }
+
+        impl<'b> std::ops::Sub<&'b Point> for Point {
+            type Output = Point;
+
+            fn sub(self, other: &'b Point) -> Point {
+                &self - other
+            }
+        }
+
+        impl<'a> std::ops::Sub<Point> for &'a Point {
+            type Output = Point;
+
+            fn sub(self, other: Point) -> Point {
+                self - &other
+            }
+        }
+
+        impl std::ops::Sub<Point> for Point {
+            type Output = Point;
+
+            fn sub(self, other: Point) -> Point {
+                &self - &other
+            }
+        }
     };
 }
 
@@ -449,12 +495,30 @@ macro_rules! point_impl {
 macro_rules! test_scalar_arithmetic {
     ($scalar: ident) => {
         use super::$scalar;
+        use $crate::mp::ct::CtEqual;
 
         #[test]
         fn negate() {
             assert_eq!($scalar::one() + (-$scalar::one()), $scalar::zero())
         }
 
+        #[test]
+        fn ct_eq() {
+            let mut values = vec![$scalar::zero(), $scalar::one(), -$scalar::one()];
+            let mut x = $scalar::from_u64(0xdeadbeef);
+            for _ in 0..32 {
+                x = &x * &x + $scalar::one();
+                values.push(x.clone());
+            }
+            for a in values.iter() {
+                for b in values.iter() {
+                    assert_eq!(a.ct_eq(b).is_true(), a == b);
+                    assert_eq!(CtEqual::ct_eq(a, b).is_true(), a == b);
+                }
+                assert!(a.ct_eq(&(a.clone() + $scalar::zero())).is_true());
+            }
+        }
+
         #[test]
         fn high() {
             assert!(!($scalar::one().high_bit_set()), "1");
@@ -482,6 +546,87 @@ macro_rules! test_scalar_arithmetic {
             }
         }
 
+        #[test]
+        fn to_u64() {
+            use std::convert::TryFrom;
+
+            for v in [0u64, 1, 0xff, 0xdeadbeef, u64::MAX].iter() {
+                let s = $scalar::from_u64(*v);
+                assert_eq!(s.to_u64(), Some(*v));
+                assert_eq!(s.to_u128(), Some(*v as u128));
+                assert_eq!(u64::try_from(&s), Ok(*v));
+            }
+
+            let above = $scalar::from_u64(u64::MAX) + $scalar::one();
+            assert_eq!(above.to_u64(), None);
+            assert_eq!(u128::try_from(&above), Ok(u64::MAX as u128 + 1));
+
+            let m1 = -$scalar::one();
+            assert_eq!(m1.to_u64(), None);
+            if $scalar::SIZE_BITS > 128 {
+                assert_eq!(m1.to_u128(), None);
+            }
+        }
+
+        #[test]
+        fn write_to_slice() {
+            use $crate::curve::field::InvalidLength;
+            let s = $scalar::from_u64(0xdeadbeef);
+
+            let mut out = vec![0u8; $scalar::SIZE_BYTES];
+            assert_eq!(s.write_to_slice(&mut out), Ok($scalar::SIZE_BYTES));
+            assert_eq!(&out[..], &s.to_bytes()[..]);
+
+            for len in [0, $scalar::SIZE_BYTES - 1, $scalar::SIZE_BYTES + 1] {
+                let mut out = vec![0u8; len];
+                assert_eq!(
+                    s.write_to_slice(&mut out),
+                    Err(InvalidLength {
+                        expected: $scalar::SIZE_BYTES,
+                        got: len
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn is_canonical_bytes() {
+            let pm1 = (-$scalar::one()).to_bytes();
+            let mut p = pm1;
+            for b in p.iter_mut().rev() {
+                let (v, carry) = b.overflowing_add(1);
+                *b = v;
+                if !carry {
+                    break;
+                }
+            }
+            assert!($scalar::is_canonical_bytes(&pm1));
+            assert!(!$scalar::is_canonical_bytes(&p));
+            assert!(!$scalar::is_canonical_bytes(&[0xff; $scalar::SIZE_BYTES]));
+            assert!(!$scalar::is_canonical_slice(&pm1[1..]));
+            assert!(!$scalar::is_canonical_slice(&[0; $scalar::SIZE_BYTES + 1]));
+
+            let mut x = $scalar::from_u64(0xdeadbeef).to_bytes();
+            for i in 0..64 {
+                for b in x.iter_mut() {
+                    *b = b.wrapping_mul(31).wrapping_add(i as u8 + 7);
+                }
+                let mut bytes = x;
+                if i % 2 == 1 {
+                    let top = (i / 2) % ($scalar::SIZE_BYTES - 1) + 1;
+                    bytes[..top].copy_from_slice(&p[..top]);
+                }
+                assert_eq!(
+                    $scalar::is_canonical_bytes(&bytes),
+                    $scalar::from_bytes(&bytes).is_some()
+                );
+                assert_eq!(
+                    $scalar::is_canonical_slice(&bytes),
+                    $scalar::from_slice(&bytes).is_some()
+                );
+            }
+        }
+
         #[test]
         fn sqrt() {
             let y = $scalar::one().sqrt().unwrap();
@@ -502,6 +647,65 @@ macro_rules! test_point_arithmetic {
             assert_eq!(Point::infinity() + &p, p);
         }
 
+        // infinity is (0,Y,0) with Y non zero, whatever the operation giving it
+        #[test]
+        fn point_infinity_representation() {
+            let inf = Point::infinity();
+            let g = Point::generator();
+            let results = [
+                inf.double(),
+                &inf + &inf,
+                &inf * &$SCALAR::from_u64(7),
+                &inf * &$SCALAR::from_u64(u64::MAX),
+                &g * &$SCALAR::zero(),
+                &g + &-&g,
+                (&g + &-&g).double(),
+            ];
+            for (i, p) in results.iter().enumerate() {
+                assert!(
+                    p.0.x.is_zero() && !p.0.y.is_zero() && p.0.z.is_zero(),
+                    "{}",
+                    i
+                );
+                assert_eq!(p, &inf);
+                assert_ne!(p, &g);
+            }
+        }
+
+        #[test]
+        fn point_normalized() {
+            let g = Point::from_affine(&PointAffine::generator());
+            assert!(g.is_normalized());
+            let p = &g.double() + &g;
+            assert!(!p.is_normalized());
+            let normalized = p.clone().normalized();
+            assert!(normalized.is_normalized());
+            assert_eq!(normalized.to_affine(), p.to_affine());
+
+            assert!(!Point::infinity().is_normalized());
+            assert!(!Point::infinity().normalized().is_normalized());
+        }
+
+        #[test]
+        fn point_affine_add() {
+            let g = Point::generator();
+            let ga = g.to_affine().unwrap();
+            let p = (&g * &$SCALAR::from_u64(1245)).to_affine().unwrap();
+
+            // same point, opposite points, and different x
+            assert_eq!(ga.add(&ga), Some(ga.double()));
+            assert_eq!(&ga + &ga, g.double().to_affine().unwrap());
+            assert_eq!(ga.add(&(-&g).to_affine().unwrap()), None);
+            assert_eq!(ga.add(&p), (&g + &Point::from(&p)).to_affine());
+        }
+
+        #[test]
+        #[should_panic(expected = "point at infinity")]
+        fn point_affine_add_opposite() {
+            let g = Point::generator();
+            let _ = &g.to_affine().unwrap() + &(-&g).to_affine().unwrap();
+        }
+
         #[test]
         fn point_affine_projective() {
             assert_eq!(
@@ -564,6 +768,8 @@ macro_rules! test_point_arithmetic {
             let (x, ysign) = p.compress();
             assert_eq!(p, PointAffine::decompress(x, ysign).unwrap());
         }
+
+        $crate::test_point_operators!($SCALAR);
     };
 }
 
@@ -571,36 +777,59 @@ macro_rules! test_point_arithmetic {
 #[macro_export]
 macro_rules! bigint_prime_curve {
     ($m: ident, $szfe: expr) => {
+        $crate::bigint_prime_curve!($m, $szfe, $szfe);
+    };
+    ($m: ident, $szfe: expr, $szscalar: expr) => {
         pub mod $m {
             use crate::curve::bigint::maths::{mod_inverse, tonelli_shanks};
+            use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
             use crate::params::sec2::$m::*;
             use crate::{bigint_scalar_impl, point_impl};
-            use lazy_static;
             use num_bigint::BigUint;
-            use num_traits::{cast::ToPrimitive, identities::One};
-
-            lazy_static! {
-                static ref P: BigUint = BigUint::from_bytes_be(&P_BYTES);
-                static ref PMOD4: u32 = {
-                    let pmodded = &*P & BigUint::from(0b11u32);
-                    pmodded.to_u32().unwrap()
-                };
-
-                // "constant" (P + 1) / 4
-                static ref PP1D4: BigUint = (&*P + BigUint::one()) / BigUint::from(4u32);
-
-                static ref ORDER: BigUint = BigUint::from_bytes_be(&ORDER_BYTES);
-                static ref OMOD4: u32 = {
-                    let pmodded = &*ORDER & BigUint::from(0b11u32);
-                    pmodded.to_u32().unwrap()
-                };
-
-                // "constant" (ORDER + 1) / 4
-                static ref OP1D4: BigUint = (&*P + BigUint::one()) / BigUint::from(4u32);
-            }
-            bigint_scalar_impl!(FieldElement, &*P, $szfe, PMOD4, PP1D4);
-            bigint_scalar_impl!(Scalar, &*ORDER, $szfe, OMOD4, OP1D4);
-            point_impl!(FieldElement, Scalar, &*GX, &*GY);
+            use num_traits::identities::One;
+
+            // the moduli and (modulus + 1) / 4 are computed on first use, their residues mod 4
+            // are constants
+            fn p() -> &'static BigUint {
+                static VALUE: std::sync::OnceLock<BigUint> = std::sync::OnceLock::new();
+                VALUE.get_or_init(|| BigUint::from_bytes_be(&P_BYTES))
+            }
+
+            const PMOD4: u32 = (P_BYTES[P_BYTES.len() - 1] & 0b11) as u32;
+
+            fn pp1d4() -> &'static BigUint {
+                static VALUE: std::sync::OnceLock<BigUint> = std::sync::OnceLock::new();
+                VALUE.get_or_init(|| (p() + BigUint::one()) / BigUint::from(4u32))
+            }
+
+            fn order() -> &'static BigUint {
+                static VALUE: std::sync::OnceLock<BigUint> = std::sync::OnceLock::new();
+                VALUE.get_or_init(|| BigUint::from_bytes_be(&ORDER_BYTES))
+            }
+
+            const OMOD4: u32 = (ORDER_BYTES[ORDER_BYTES.len() - 1] & 0b11) as u32;
+
+            fn op1d4() -> &'static BigUint {
+                static VALUE: std::sync::OnceLock<BigUint> = std::sync::OnceLock::new();
+                VALUE.get_or_init(|| (order() + BigUint::one()) / BigUint::from(4u32))
+            }
+
+            bigint_scalar_impl!(FieldElement, p(), $szfe, &PMOD4, pp1d4());
+            bigint_scalar_impl!(Scalar, order(), $szscalar, &OMOD4, op1d4());
+
+            /// The curve itself, to describe its implementation and parameterize the
+            /// point arithmetic
+            #[derive(Debug, Clone, Copy)]
+            pub struct Curve;
+
+            impl Curve {
+                /// Implementation of the base field arithmetic
+                pub const fn backend() -> $crate::curve::Backend {
+                    $crate::curve::Backend::BigInt
+                }
+            }
+
+            point_impl!(FieldElement, Scalar);
 
             #[cfg(test)]
             mod tests {
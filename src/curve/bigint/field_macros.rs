@@ -2,6 +2,12 @@
 #[macro_export]
 macro_rules! bigint_scalar_impl {
     ($ty: ident, $p: expr, $sz: expr, $pmod4: expr, $pp1d4: expr) => {
+        /// Element of a prime field, backed by a BigUint
+        ///
+        /// # :warning: Variable time equality
+        ///
+        /// `==` compares the BigUint values, which stops at the first differing limb,
+        /// so its timing depends on the values. Use `ct_eq` to compare secret values.
         #[derive(Clone)]
         pub struct $ty(num_bigint::BigUint);
 
@@ -11,6 +17,12 @@ macro_rules! bigint_scalar_impl {
             }
         }
 
+        impl $crate::mp::ct::CtEqual for $ty {
+            fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                $ty::ct_eq(self, other)
+            }
+        }
+
         impl std::fmt::Debug for $ty {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 let bs = self.0.to_bytes_be();
@@ -44,11 +56,39 @@ macro_rules! bigint_scalar_impl {
                 Self(BigUint::from_u64(n).unwrap())
             }
 
+            /// Return the value of the element as a u64, or None if it doesn't fit
+            pub fn to_u64(&self) -> Option<u64> {
+                use num_traits::cast::ToPrimitive;
+                self.0.to_u64()
+            }
+
+            /// Return the value of the element as a u128, or None if it doesn't fit
+            pub fn to_u128(&self) -> Option<u128> {
+                use num_traits::cast::ToPrimitive;
+                self.0.to_u128()
+            }
+
             pub fn is_zero(&self) -> bool {
                 use num_traits::identities::Zero;
                 self.0.is_zero()
             }
 
+            /// Compare two elements in constant time
+            ///
+            /// Both values are exported to u64 limbs, padded to the number of limbs
+            /// of the modulus, and compared without stopping at the first difference.
+            /// Note that the BigUint export itself is not guaranteed to be constant time,
+            /// this only removes the early exit of the comparison.
+            pub fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                use $crate::mp::ct::CtEqual;
+                let limbs = (($p).bits() as usize).div_ceil(64);
+                let mut a = self.0.to_u64_digits();
+                let mut b = other.0.to_u64_digits();
+                a.resize(limbs, 0);
+                b.resize(limbs, 0);
+                a[..].ct_eq(&b[..])
+            }
+
             // there's no really negative number in Fp, but if high bit is set ...
             pub fn high_bit_set(&self) -> bool {
                 //use num_traits::identities::Zero;
@@ -93,8 +133,14 @@ macro_rules! bigint_scalar_impl {
             /// Compute the square root 'x' of the field element such that x*x = self
             pub fn sqrt(&self) -> Option<Self> {
                 if *$pmod4 == 3 {
-                    // P mod 4 == 3, then we can compute sqrt with one exponentiation with (P+1)/4
-                    Some(Self(self.0.modpow(&*$pp1d4, $p)))
+                    // P mod 4 == 3, then we can compute sqrt with one exponentiation with (P+1)/4,
+                    // which only gives a square root if self is a quadratic residue
+                    let r = Self(self.0.modpow(&*$pp1d4, $p));
+                    if &r * &r == *self {
+                        Some(r)
+                    } else {
+                        None
+                    }
                 } else {
                     tonelli_shanks(&self.0, $p).map(|n| Self(n))
                 }
@@ -113,6 +159,41 @@ macro_rules! bigint_scalar_impl {
                 }
             }
 
+            /// Check if the bytes representation is canonical, i.e. less than the modulus
+            ///
+            /// Note that unlike the fiat scalars this is not constant time
+            pub fn is_canonical_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> bool {
+                &BigUint::from_bytes_be(bytes) < $p
+            }
+
+            /// Same as `is_canonical_bytes` for a slice, which is false for a size mismatch
+            pub fn is_canonical_slice(slice: &[u8]) -> bool {
+                slice.len() == Self::SIZE_BYTES && &BigUint::from_bytes_be(slice) < $p
+            }
+
+            /// Initialize a new scalar from its bytes representation, rejecting zero
+            ///
+            /// Same as `from_bytes_in_range` with `allow_zero` false
+            pub fn from_bytes_nonzero(bytes: &[u8; Self::SIZE_BYTES]) -> Option<Self> {
+                Self::from_bytes_in_range(bytes, false)
+            }
+
+            /// Initialize a new scalar from its bytes representation, which needs to be
+            /// less than the modulus, and non zero unless `allow_zero` is set
+            ///
+            /// Note that unlike the fiat scalars this is not constant time
+            pub fn from_bytes_in_range(
+                bytes: &[u8; Self::SIZE_BYTES],
+                allow_zero: bool,
+            ) -> Option<Self> {
+                Self::from_bytes(bytes).filter(|s| allow_zero || !s.is_zero())
+            }
+
+            /// Same as `from_bytes_nonzero` for a slice, which also returns None for a size mismatch
+            pub fn from_slice_nonzero(slice: &[u8]) -> Option<Self> {
+                Self::from_slice(slice).filter(|s| !s.is_zero())
+            }
+
             /// Similar to from_bytes but take values from a slice.
             ///
             /// If the slice is not of the right size, then None is returned
@@ -141,13 +222,37 @@ macro_rules! bigint_scalar_impl {
 
             /// Output the scalar bytes representation to the mutable slice
             ///
-            /// the slice needs to be of the correct size
+            /// the slice needs to be of the correct size, otherwise this function panics.
+            /// Use `write_to_slice` to handle the size mismatch as an error
             pub fn to_slice(&self, slice: &mut [u8]) {
-                assert_eq!(slice.len(), Self::SIZE_BYTES);
+                if let Err(e) = self.write_to_slice(slice) {
+                    panic!(
+                        "to_slice: slice of {} bytes, expecting {} bytes",
+                        e.got, e.expected
+                    )
+                }
+            }
+
+            /// Output the scalar bytes representation to the mutable slice, returning
+            /// the number of bytes written
+            ///
+            /// the slice needs to be exactly SIZE_BYTES long, shorter and longer slices
+            /// are both rejected with an error and the slice is left untouched.
+            pub fn write_to_slice(
+                &self,
+                slice: &mut [u8],
+            ) -> Result<usize, $crate::curve::field::InvalidLength> {
+                if slice.len() != Self::SIZE_BYTES {
+                    return Err($crate::curve::field::InvalidLength {
+                        expected: Self::SIZE_BYTES,
+                        got: slice.len(),
+                    });
+                }
 
                 // TODO don't create temporary buffer
                 let bytes = self.to_bytes();
                 slice.copy_from_slice(&bytes[..]);
+                Ok(Self::SIZE_BYTES)
             }
 
             /// Initialize from a wide buffer of random data.
@@ -161,11 +266,76 @@ macro_rules! bigint_scalar_impl {
             }
         }
 
+        impl std::convert::TryFrom<&$ty> for u64 {
+            type Error = $crate::curve::field::IntegerOverflow;
+
+            fn try_from(v: &$ty) -> Result<u64, Self::Error> {
+                v.to_u64().ok_or($crate::curve::field::IntegerOverflow)
+            }
+        }
+
+        impl std::convert::TryFrom<&$ty> for u128 {
+            type Error = $crate::curve::field::IntegerOverflow;
+
+            fn try_from(v: &$ty) -> Result<u128, Self::Error> {
+                v.to_u128().ok_or($crate::curve::field::IntegerOverflow)
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Debug::fmt(self, f)
+            }
+        }
+
+        /// Field operations for the generic point arithmetic of the `affine` and
+        /// `projective` modules
+        ///
+        /// Unlike the inherent method, `Field::inverse` panics on zero as the fiat
+        /// field elements do.
+        impl $crate::curve::field::Field for $ty {
+            fn zero() -> Self {
+                $ty::zero()
+            }
+            fn is_zero(&self) -> bool {
+                $ty::is_zero(self)
+            }
+            fn one() -> Self {
+                $ty::one()
+            }
+            fn double(&self) -> Self {
+                $ty::double(self)
+            }
+            fn inverse(&self) -> Self {
+                $ty::inverse(self).expect("inverse of zero")
+            }
+            fn square(&self) -> Self {
+                self * self
+            }
+            fn cube(&self) -> Self {
+                &(self * self) * self
+            }
+        }
+
+        /// Selection on the bytes of the elements, without any branch on the choice
+        ///
+        /// As for `ct_eq`, the BigUint conversions are not guaranteed to be constant time.
+        impl $crate::mp::ct::CtSelect for $ty {
+            fn ct_select(a: &Self, b: &Self, choice: $crate::mp::ct::Choice) -> Self {
+                let mask = choice.to_mask_u64() as u8;
+                let mut out = a.to_bytes();
+                for (o, b) in out.iter_mut().zip(b.to_bytes().iter()) {
+                    *o ^= mask & (*o ^ b);
+                }
+                $ty(BigUint::from_bytes_be(&out))
+            }
+        }
+
         impl std::ops::Neg for $ty {
             type Output = $ty;
 
             fn neg(self) -> Self::Output {
-                $ty($p - self.0)
+                $ty(($p - self.0) % $p)
             }
         }
 
@@ -173,7 +343,7 @@ macro_rules! bigint_scalar_impl {
             type Output = $ty;
 
             fn neg(self) -> Self::Output {
-                $ty($p - &self.0)
+                $ty(($p - &self.0) % $p)
             }
         }
 
@@ -265,7 +435,7 @@ macro_rules! bigint_scalar_impl {
             type Output = $ty;
 
             fn mul(self, other: &'b $ty) -> $ty {
-                $ty((&self.0 * &other.0) % $p)
+                $ty((self.0 * &other.0) % $p)
             }
         }
 
@@ -36,15 +36,11 @@ pub enum LegendreSymbol {
 }
 
 pub fn legendre_symbol(a: &BigUint, p: &BigUint) -> LegendreSymbol {
-    use num_traits::cast::ToPrimitive;
-
-    let r: u64 = a
-        .modpow(&((p - BigUint::one()) / BigUint::from(2u64)), p)
-        .to_u64()
-        .unwrap();
-    if r == 1 {
+    // a^((p-1)/2) is either 0, 1 or p-1, which doesn't fit a machine integer
+    let r = a.modpow(&((p - BigUint::one()) / BigUint::from(2u64)), p);
+    if r.is_one() {
         LegendreSymbol::One
-    } else if r == 0 {
+    } else if r.is_zero() {
         LegendreSymbol::Zero
     } else {
         LegendreSymbol::MinusOne
@@ -53,8 +49,10 @@ pub fn legendre_symbol(a: &BigUint, p: &BigUint) -> LegendreSymbol {
 
 // p need to be prime, but this is not checked
 pub fn tonelli_shanks(n: &BigUint, p: &BigUint) -> Option<BigUint> {
-    if legendre_symbol(n, p) != LegendreSymbol::One {
-        return None;
+    match legendre_symbol(n, p) {
+        LegendreSymbol::Zero => return Some(BigUint::zero()),
+        LegendreSymbol::MinusOne => return None,
+        LegendreSymbol::One => {}
     }
 
     let pm1 = p - BigUint::one();
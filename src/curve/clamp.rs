@@ -0,0 +1,78 @@
+//! Clamping of 32 bytes scalars, for the keys coming from X25519 style systems
+//!
+//! X25519 (RFC 7748) doesn't use its 32 bytes secret as is: the bytes are little
+//! endian, the 3 low bits are cleared (the cofactor of curve25519 is 8), the bit 255
+//! is cleared and the bit 254 is set. The curves `Scalar::from_bytes_clamped` apply
+//! such a policy, then reduce the value modulo the group order, so that the same
+//! exported key always gives the same scalar.
+//!
+//! The information lost on the way:
+//!
+//! * the cleared and set bits of the policy: with `ClampPolicy::X25519`, only 251 of
+//!   the 256 bits remain, and the 32 keys differing only by these bits give the same scalar
+//! * the reduction modulo the order, when the clamped value is not less than the order
+//!   (never the case with `ClampPolicy::X25519` on the 256 bits orders, which are
+//!   above 2^255, but always the case on the smaller curves)
+//!
+//! The scalar is not the discrete logarithm of the X25519 public key, which is on
+//! another curve: only the secret is carried over, and the public key needs to be
+//! computed again on the target curve.
+
+/// Bits masking and byte order applied to a 32 bytes scalar before its reduction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClampPolicy {
+    /// The bytes are little endian, as the X25519 keys, instead of big endian
+    pub little_endian: bool,
+    /// Number of least significant bits cleared, at most 8 (3 for X25519)
+    pub clear_low_bits: u8,
+    /// Clear the most significant bit, the bit 255
+    pub clear_bit_255: bool,
+    /// Set the bit 254, after clearing the low bits and the bit 255
+    pub set_bit_254: bool,
+}
+
+impl ClampPolicy {
+    /// The clamping of the X25519 secrets of RFC 7748, on little endian bytes
+    pub const X25519: ClampPolicy = ClampPolicy {
+        little_endian: true,
+        clear_low_bits: 3,
+        clear_bit_255: true,
+        set_bit_254: true,
+    };
+
+    /// No clamping of big endian bytes, only the reduction modulo the order
+    pub const NONE: ClampPolicy = ClampPolicy {
+        little_endian: false,
+        clear_low_bits: 0,
+        clear_bit_255: false,
+        set_bit_254: false,
+    };
+
+    /// Apply the policy to the bytes, returning the big endian bytes of the clamped value
+    ///
+    /// Clamping the output again with the same masks (and big endian bytes) gives the
+    /// same value. Panics if more than 8 low bits are cleared.
+    pub fn clamp(&self, bytes: &[u8; 32]) -> [u8; 32] {
+        assert!(self.clear_low_bits <= 8, "clamp: too many low bits");
+        let mut out = *bytes;
+        if self.little_endian {
+            out.reverse();
+        }
+        out[31] &= (0xffu16 << self.clear_low_bits) as u8;
+        if self.clear_bit_255 {
+            out[0] &= 0x7f;
+        }
+        if self.set_bit_254 {
+            out[0] |= 0x40;
+        }
+        out
+    }
+
+    /// The same masks on big endian bytes, which is what applies to the output of `clamp`
+    pub const fn big_endian(self) -> ClampPolicy {
+        ClampPolicy {
+            little_endian: false,
+            ..self
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Twisted Edwards curves, and their points in extended coordinates (X:Y:Z:T)
+//!
+//! All twisted Edwards curves are defined as a*x^{2} + y^{2} = 1 + d*x^{2}*y^{2}
+//!
+//! The addition and doubling formulas are the ones from
+//! [Twisted Edwards Curves Revisited](https://eprint.iacr.org/2008/522.pdf) (add-2008-hwcd
+//! and dbl-2008-hwcd), which are unified: the same addition formula works for doubling and
+//! for the neutral element. They are complete when a is a square and d is not a square.
+
+use super::field::Field;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Twisted Edwards curves are defined as a*x^{2} + y^{2} = 1 + d*x^{2}*y^{2}
+pub trait EdwardsCurve: Copy + Clone {
+    type FieldElement;
+
+    // Twisted Edwards a parameter
+    fn a(self) -> &'static Self::FieldElement;
+    // Twisted Edwards d parameter
+    fn d(self) -> &'static Self::FieldElement;
+}
+
+/// Point in extended coordinates with field element FE
+///
+/// Affine point associated with (X:Y:Z:T) : (X/Z, Y/Z), with T = X*Y/Z.
+/// The neutral element is (0:1:1:0), which is the affine point (0,1).
+///
+/// Note that 2 points are equal if they are in the same equivalence class,
+/// which is determined with 4 FieldElement multiplications.
+#[derive(Clone, Debug)]
+pub struct EdwardsPoint<FE> {
+    pub x: FE,
+    pub y: FE,
+    pub z: FE,
+    pub t: FE,
+}
+
+impl<FE: Field> PartialEq for EdwardsPoint<FE>
+where
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+    fn eq(&self, other: &EdwardsPoint<FE>) -> bool {
+        &self.x * &other.z == &other.x * &self.z && &self.y * &other.z == &other.y * &self.z
+    }
+}
+
+impl<FE: Field> Eq for EdwardsPoint<FE> where for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE> {}
+
+impl<FE> EdwardsPoint<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// Returns the neutral element (0,1)
+    pub fn identity() -> Self {
+        EdwardsPoint {
+            x: FE::zero(),
+            y: FE::one(),
+            z: FE::one(),
+            t: FE::zero(),
+        }
+    }
+
+    /// Create a point from its affine coordinates, without checking the curve equation
+    pub fn from_affine(x: &FE, y: &FE) -> Self {
+        EdwardsPoint {
+            x: x.clone(),
+            y: y.clone(),
+            z: FE::one(),
+            t: x * y,
+        }
+    }
+
+    /// Try to create a point from its affine coordinates
+    ///
+    /// check if the equation a*x^2 + y^2 = 1 + d*x^2*y^2 holds for this curve, if it doesn't
+    /// None is returned
+    pub fn from_coordinate<C: EdwardsCurve<FieldElement = FE>>(
+        x: &FE,
+        y: &FE,
+        curve: C,
+    ) -> Option<Self> {
+        let xx = x.square();
+        let yy = y.square();
+        let lhs = curve.a() * &xx + &yy;
+        let rhs = FE::one() + &(curve.d() * &(&xx * &yy));
+        if lhs == rhs {
+            Some(Self::from_affine(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Return the affine coordinates (X/Z, Y/Z), or None if Z is zero
+    ///
+    /// Z is never zero with complete formulas, but can be with the other curves
+    pub fn to_affine(&self) -> Option<(FE, FE)> {
+        if self.z.is_zero() {
+            None
+        } else {
+            let inv = self.z.inverse();
+            Some((&self.x * &inv, &self.y * &inv))
+        }
+    }
+
+    /// Check if the point is the neutral element
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    /// Point addition with the unified add-2008-hwcd formula
+    pub fn add<C: EdwardsCurve<FieldElement = FE>>(&self, other: &Self, curve: C) -> Self {
+        let a = &self.x * &other.x;
+        let b = &self.y * &other.y;
+        let c = &(&self.t * curve.d()) * &other.t;
+        let d = &self.z * &other.z;
+        let e = &(&(&self.x + &self.y) * &(&other.x + &other.y)) - &(&a + &b);
+        let f = &d - &c;
+        let g = &d + &c;
+        let h = &b - &(curve.a() * &a);
+        EdwardsPoint {
+            x: &e * &f,
+            y: &g * &h,
+            z: &f * &g,
+            t: &e * &h,
+        }
+    }
+
+    /// Point doubling with the dbl-2008-hwcd formula
+    pub fn double<C: EdwardsCurve<FieldElement = FE>>(&self, curve: C) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().double();
+        let d = curve.a() * &a;
+        let e = &(&self.x + &self.y).square() - &(&a + &b);
+        let g = &d + &b;
+        let f = &g - &c;
+        let h = &d - &b;
+        EdwardsPoint {
+            x: &e * &f,
+            y: &g * &h,
+            z: &f * &g,
+            t: &e * &h,
+        }
+    }
+
+    /// scalar multiplication : `n * self` with double-and-add, where n is in big endian
+    ///
+    /// This is variable time, and only meant for the points and scalars that are public
+    pub fn scale<C: EdwardsCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Self {
+        let mut q = Self::identity();
+        for digit in n.iter() {
+            for i in (0..8).rev() {
+                q = q.double(curve);
+                if (digit >> i) & 1 == 1 {
+                    q = q.add(self, curve);
+                }
+            }
+        }
+        q
+    }
+}
+
+impl<FE: Field> Neg for &EdwardsPoint<FE> {
+    type Output = EdwardsPoint<FE>;
+
+    fn neg(self) -> EdwardsPoint<FE> {
+        EdwardsPoint {
+            x: -self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+            t: -self.t.clone(),
+        }
+    }
+}
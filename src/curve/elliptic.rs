@@ -0,0 +1,87 @@
+//! Uniform interface over the curves, for the code generic over the curve
+//!
+//! Each curve module (e.g. `sec2::p256r1`, `sec2::p521r1`, `sm2`) has the same
+//! `FieldElement`, `Scalar`, `Point` and `PointAffine` types, and its `Curve` implements
+//! `EllipticCurve` with them. The byte sizes differ between the curves, so the byte
+//! representations go through slices and vectors, and their sizes are the associated
+//! constants `FE_BYTES` and `SCALAR_BYTES`:
+//!
+//! ```
+//! # #[cfg(all(feature = "p256r1", feature = "p521r1"))] {
+//! use eccoxide::curve::sec2::{p256r1, p521r1};
+//! use eccoxide::curve::EllipticCurve;
+//!
+//! fn keygen<C: EllipticCurve>(sk: &C::Scalar) -> C::Point {
+//!     C::scale(&C::generator(), sk)
+//! }
+//!
+//! fn public_key_x<C: EllipticCurve>(secret: &[u8]) -> Option<Vec<u8>> {
+//!     let sk = C::scalar_from_bytes(secret)?;
+//!     let p = C::point_to_affine(&keygen::<C>(&sk))?;
+//!     let (x, _) = C::point_compress(&p);
+//!     Some(C::field_element_to_bytes(&x))
+//! }
+//!
+//! let x = public_key_x::<p256r1::Curve>(&[1; p256r1::Curve::SCALAR_BYTES]).unwrap();
+//! assert_eq!(x.len(), 32);
+//! let x = public_key_x::<p521r1::Curve>(&[1; p521r1::Curve::SCALAR_BYTES]).unwrap();
+//! assert_eq!(x.len(), 66);
+//! # }
+//! ```
+
+use super::Sign;
+use core::fmt::Debug;
+
+/// A prime order elliptic curve, with its field element, scalar and point types
+///
+/// The signs of the compressed points are the ones of SEC1, the parity of Y, and the
+/// scalar multiplication is the constant time one of the curve (`Point::scale_ct`).
+pub trait EllipticCurve: Copy + Clone + Debug {
+    type FieldElement: Clone + Debug;
+    type Scalar: Clone + Debug;
+    type Point: Clone + Debug + PartialEq + Eq;
+    type PointAffine: Clone + Debug + PartialEq + Eq;
+
+    /// Size in bytes of a field element
+    const FE_BYTES: usize;
+    /// Size in bytes of a scalar
+    const SCALAR_BYTES: usize;
+
+    /// The generator of the group of points
+    fn generator() -> Self::Point;
+
+    /// The group order, in big endian bytes
+    fn order_bytes() -> &'static [u8];
+
+    /// Scalar of `SCALAR_BYTES` big endian bytes, None if the size is wrong or the value
+    /// is not less than the group order
+    fn scalar_from_bytes(bytes: &[u8]) -> Option<Self::Scalar>;
+
+    /// The `SCALAR_BYTES` big endian bytes of the scalar
+    fn scalar_to_bytes(scalar: &Self::Scalar) -> Vec<u8>;
+
+    /// Field element of `FE_BYTES` big endian bytes, None if the size is wrong or the
+    /// value is not less than the field prime
+    fn field_element_from_bytes(bytes: &[u8]) -> Option<Self::FieldElement>;
+
+    /// The `FE_BYTES` big endian bytes of the field element
+    fn field_element_to_bytes(fe: &Self::FieldElement) -> Vec<u8>;
+
+    /// The point of X coordinate x whose Y has the sign, None if x is not on the curve
+    fn point_decompress(x: &Self::FieldElement, sign: Sign) -> Option<Self::PointAffine>;
+
+    /// The X coordinate and the sign of Y of the point
+    fn point_compress(p: &Self::PointAffine) -> (Self::FieldElement, Sign);
+
+    /// The affine point, None for the point at infinity
+    fn point_to_affine(p: &Self::Point) -> Option<Self::PointAffine>;
+
+    /// The point of the affine point
+    fn point_from_affine(p: &Self::PointAffine) -> Self::Point;
+
+    /// `p + q`
+    fn add(p: &Self::Point, q: &Self::Point) -> Self::Point;
+
+    /// Constant time `scalar * p`
+    fn scale(p: &Self::Point, scalar: &Self::Scalar) -> Self::Point;
+}
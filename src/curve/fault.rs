@@ -0,0 +1,57 @@
+//! Countermeasures against fault attacks
+//!
+//! A fault (a glitch of the clock or of the power supply, a laser shot) during a
+//! secret dependent computation can make it output a wrong result, and wrong results
+//! leak the secret: an ECDSA signature made from a faulted `k * G` gives the secret
+//! key with a few lattice computations, and a faulted ECDH multiplication can land on
+//! a weak curve. The hardened entry points check the result before releasing it,
+//! and return None when the check fails:
+//!
+//! * `Point::scale_verified` of the curves: `scalar * P` with the constant time ladder,
+//!   checked by multiplying the result by the inverse of the scalar, which has to
+//!   give P back. This costs two ladders and a scalar inversion, a bit more than
+//!   twice `Point::scale_ct`.
+//! * `ecdsa::sign_verified` of the curves with ECDSA: the signature is verified against
+//!   the public key of the secret key before being released. This costs the public
+//!   key multiplication and the verification, about three times `ecdsa::sign`.
+//!
+//! A None is not an error of the inputs, but of the hardware: the operation can be
+//! retried, but repeated failures are better treated as an attack. The checks only
+//! catch a single fault: a fault on both the computation and its check, with the
+//! right values, is not detected.
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+thread_local! {
+    static ARMED: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// Corrupt the n-th fault point reached on this thread from now, counting from 0
+#[cfg(test)]
+pub(crate) fn arm(n: usize) {
+    ARMED.with(|armed| armed.set(Some(n)))
+}
+
+/// Disarm the fault, returning true if it was injected
+#[cfg(test)]
+pub(crate) fn disarm() -> bool {
+    ARMED.with(|armed| armed.replace(None)) == Some(usize::MAX)
+}
+
+// the fault point of the `fault_point` macro, usize::MAX recording the injection
+#[cfg(test)]
+pub(crate) fn inject<T, F: FnOnce(T) -> T>(value: T, corrupt: F) -> T {
+    ARMED.with(|armed| match armed.get() {
+        Some(0) => {
+            armed.set(Some(usize::MAX));
+            corrupt(value)
+        }
+        Some(usize::MAX) | None => value,
+        Some(n) => {
+            armed.set(Some(n - 1));
+            value
+        }
+    })
+}
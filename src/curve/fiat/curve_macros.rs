@@ -2,13 +2,30 @@
 #[macro_export]
 macro_rules! fiat_define_weierstrass_curve {
     ($FE:ident) => {
-        lazy_static! {
-            static ref A: $FE = $FE::from_bytes(&A_BYTES).unwrap();
-            static ref B: $FE = $FE::from_bytes(&B_BYTES).unwrap();
-            static ref B3: $FE = $FE::from_bytes(&B3_BYTES).unwrap();
-            static ref GX: $FE = $FE::from_bytes(&GX_BYTES).unwrap();
-            static ref GY: $FE = $FE::from_bytes(&GY_BYTES).unwrap();
-            static ref ORDER: &'static [u8] = &ORDER_BYTES;
+        // the curve constants, computed on first use
+        fn curve_a() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE::from_bytes(&A_BYTES).unwrap())
+        }
+
+        fn curve_b() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE::from_bytes(&B_BYTES).unwrap())
+        }
+
+        fn curve_b3() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| &(curve_b() + curve_b()) + curve_b())
+        }
+
+        fn generator_x() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE::from_bytes(&GX_BYTES).unwrap())
+        }
+
+        fn generator_y() -> &'static $FE {
+            static VALUE: std::sync::OnceLock<$FE> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| $FE::from_bytes(&GY_BYTES).unwrap())
         }
 
         /// The Weierstrass elliptic curve object itself
@@ -18,12 +35,17 @@ macro_rules! fiat_define_weierstrass_curve {
         impl Curve {
             /// Get the group order as an array of bytes in big endian representation
             pub fn group_order(self) -> &'static [u8] {
-                &ORDER
+                &ORDER_BYTES
             }
 
             /// Return the generator field element in affine coordinate (X,Y)
             pub fn generator() -> (&'static $FE, &'static $FE) {
-                (&GX, &GY)
+                (generator_x(), generator_y())
+            }
+
+            /// Implementation of the base field arithmetic, which depends on the features
+            pub const fn backend() -> $crate::curve::Backend {
+                <FieldOps as $crate::curve::fiat::ops::FiatFieldOps>::BACKEND
             }
         }
 
@@ -31,15 +53,15 @@ macro_rules! fiat_define_weierstrass_curve {
             type FieldElement = $FE;
 
             fn a(self) -> &'static Self::FieldElement {
-                &A
+                curve_a()
             }
 
             fn b(self) -> &'static Self::FieldElement {
-                &B
+                curve_b()
             }
 
             fn b3(self) -> &'static Self::FieldElement {
-                &B3
+                curve_b3()
             }
         }
     };
@@ -52,22 +74,101 @@ macro_rules! fiat_define_weierstrass_points {
         /// Affine Point on the curve of type (X,Y)
         ///
         /// Note that this representation cannot handle the point at infinity
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub struct PointAffine(affine::Point<$FE>);
+        ///
+        /// Equality is constant time, and `CtEqual::ct_eq` gives the same comparison as
+        /// a `Choice`, to combine with other constant time conditions.
+        #[derive(Clone, Debug)]
+        pub struct PointAffine(pub(crate) affine::Point<$FE>);
 
         /// Point on the curve using a more optimised representation
         ///
         /// This implementation used projective coordinate (X:Y:Z)
-        #[derive(Clone, Debug, PartialEq, Eq)]
+        ///
+        /// Equality compares the equivalence classes in constant time, so two
+        /// representations of the same point are equal. `CtEqual::ct_eq` gives the
+        /// same comparison as a `Choice`, which is to be preferred over `==` when the
+        /// result is combined with other secret dependent conditions.
+        #[derive(Clone, Debug)]
         pub struct Point(projective::Point<$FE>);
 
+        impl $crate::mp::ct::CtEqual for PointAffine {
+            fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl PartialEq for PointAffine {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::mp::ct::CtEqual::ct_eq(self, other).is_true()
+            }
+        }
+
+        impl Eq for PointAffine {}
+
+        impl $crate::mp::ct::CtEqual for Point {
+            fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl PartialEq for Point {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::mp::ct::CtEqual::ct_eq(self, other).is_true()
+            }
+        }
+
+        impl Eq for Point {}
+
+        impl Scalar {
+            /// The field element reduced modulo the group order, along with whether it
+            /// was reduced, i.e. the element is not less than the order
+            ///
+            /// This is the conversion of the X coordinates to scalars of ECDSA, the choice
+            /// telling the recovery which of x or x + n is the X coordinate of R. It's never
+            /// reduced on the curves with an order greater than the field prime (p224k1).
+            /// This is constant time.
+            pub fn from_field_reduced(fe: &$FE) -> (Scalar, $crate::mp::ct::Choice) {
+                use $crate::mp::ct::CtEqual;
+                let s = Scalar::from_be_bytes_mod_p(&fe.to_bytes());
+                let exact = $FE::from_be_bytes_mod_p(&s.to_bytes()).ct_eq(fe);
+                (s, exact.negate())
+            }
+        }
+
+        impl $FE {
+            /// The field element of the same value as the scalar, if it's less than the
+            /// field prime
+            ///
+            /// This is always the case when the order is less than the field prime, and
+            /// is none for the scalars between p and n otherwise (p224k1). This is constant
+            /// time.
+            pub fn try_from_scalar_exact(s: &Scalar) -> $crate::mp::ct::CtOption<$FE> {
+                use $crate::mp::ct::CtEqual;
+                let fe = $FE::from_be_bytes_mod_p(&s.to_bytes());
+                let exact = Scalar::from_be_bytes_mod_p(&fe.to_bytes()).ct_eq(s);
+                $crate::mp::ct::CtOption::from((exact, fe))
+            }
+        }
+
+        /// Bytes representation of an X coordinate, which can't be confused with the scalar bytes
+        pub type XCoordinateBytes =
+            $crate::curve::tagged::XCoordinateBytes<Curve, { $FE::SIZE_BYTES }>;
+
         impl PointAffine {
             /// Curve generator point in affine coordinate
             pub fn generator() -> Self {
-                PointAffine(affine::Point {
-                    x: GX.clone(),
-                    y: GY.clone(),
-                })
+                PointAffine(affine::Point::new_unchecked(
+                    generator_x().clone(),
+                    generator_y().clone(),
+                ))
+            }
+
+            /// The bytes of the X coordinate, typed as X coordinate bytes of this curve
+            ///
+            /// This is the ECDH shared secret when the point is the shared point, see
+            /// the `tagged` module for the rationale
+            pub fn x_bytes(&self) -> XCoordinateBytes {
+                XCoordinateBytes::new(self.to_coordinate().0.to_bytes())
             }
 
             /// Try to create an affine point with X, Y coordinates.
@@ -81,7 +182,13 @@ macro_rules! fiat_define_weierstrass_points {
             /// Return the tuple of coordinate (x, y) associated with this
             /// affine point
             pub fn to_coordinate(&self) -> (&FieldElement, &FieldElement) {
-                (&self.0.x, &self.0.y)
+                self.0.to_coordinate()
+            }
+
+            /// Consume the affine point, returning its coordinates (x, y)
+            /// without cloning them
+            pub fn into_coordinates(self) -> (FieldElement, FieldElement) {
+                self.0.into_coordinates()
             }
 
             /// Double the affine point Self
@@ -108,14 +215,402 @@ macro_rules! fiat_define_weierstrass_points {
             ///
             /// This is often refered as point decompression
             pub fn decompress(x: &FieldElement, sign: Sign) -> Option<Self> {
-                affine::Point::decompress(x, sign, Curve).map(PointAffine)
+                let p = affine::Point::decompress(x, sign, Curve).map(PointAffine);
+                trace_event!($crate::trace::TraceEvent::Decompress {
+                    module: module_path!(),
+                    x: &x.to_bytes(),
+                    sign,
+                    success: p.is_some(),
+                });
+                p
+            }
+
+            /// Try to create the affine point with the X component and an even Y component
+            ///
+            /// This is the `lift_x` of BIP-340, and the same as `decompress(x, Sign::Positive)`
+            pub fn decompress_even_y(x: &FieldElement) -> Option<Self> {
+                Self::decompress_both(x).map(|(even, _)| even)
+            }
+
+            /// Try to create the two affine points with the X component, the one with an
+            /// even Y component first, and the one with an odd Y component second
+            ///
+            /// The two points are negations of each other, and are both computed
+            /// with a single square root.
+            pub fn decompress_both(x: &FieldElement) -> Option<(Self, Self)> {
+                let (even, odd) = affine::Point::y_candidates(x, Curve)?;
+                Some((
+                    PointAffine(affine::Point::new_unchecked(x.clone(), even)),
+                    PointAffine(affine::Point::new_unchecked(x.clone(), odd)),
+                ))
+            }
+
+            /// Try to create an affine point from its SEC1 encoding
+            ///
+            /// Both the compressed (0x02 or 0x03 followed by X) and the uncompressed
+            /// (0x04 followed by X and Y) encodings are supported. None is returned
+            /// for any other encoding (including the point at infinity 0x00 and the hybrid
+            /// encodings), for a length mismatch, for non canonical coordinates, or if
+            /// the point is not on the curve.
+            pub fn from_sec1_bytes(bytes: &[u8]) -> Option<Self> {
+                Self::decode_sec1(bytes, $crate::curve::sec1::ValidationFlags::default()).ok()
+            }
+
+            /// Decode the SEC1 encoding of a point, compressed, uncompressed or hybrid
+            ///
+            /// The forms accepted are the ones allowed by the flags, and the point at
+            /// infinity is rejected, see `Point::decode_sec1` to accept it. The parity
+            /// of the hybrid prefix needs to be the one of Y, as X9.62 requires.
+            pub fn decode_sec1(
+                bytes: &[u8],
+                flags: $crate::curve::sec1::ValidationFlags,
+            ) -> Result<Self, $crate::curve::sec1::Sec1Error> {
+                use $crate::curve::sec1::Sec1Error;
+
+                let (tag, rest) = bytes.split_first().ok_or(Sec1Error::InvalidLength)?;
+                let coordinate =
+                    |b: &[u8]| FieldElement::from_slice(b).ok_or(Sec1Error::NotCanonical);
+                let sign = if *tag & 1 == 0 {
+                    Sign::Positive
+                } else {
+                    Sign::Negative
+                };
+                let (allowed, size) = match *tag {
+                    0x00 if rest.is_empty() => return Err(Sec1Error::Infinity),
+                    0x00 => return Err(Sec1Error::InvalidLength),
+                    0x02 | 0x03 => (flags.allow_compressed, FieldElement::SIZE_BYTES),
+                    0x04 => (flags.allow_uncompressed, 2 * FieldElement::SIZE_BYTES),
+                    0x06 | 0x07 => (flags.allow_hybrid, 2 * FieldElement::SIZE_BYTES),
+                    _ => return Err(Sec1Error::InvalidPrefix),
+                };
+                if rest.len() != size {
+                    return Err(Sec1Error::InvalidLength);
+                }
+                if !allowed {
+                    return Err(Sec1Error::Disallowed);
+                }
+                if size == FieldElement::SIZE_BYTES {
+                    return Self::decompress(&coordinate(rest)?, sign).ok_or(Sec1Error::NotOnCurve);
+                }
+                let (x, y) = rest.split_at(FieldElement::SIZE_BYTES);
+                let point = Self::from_coordinate(&coordinate(x)?, &coordinate(y)?)
+                    .ok_or(Sec1Error::NotOnCurve)?;
+                if *tag != 0x04 && point.to_coordinate().1.sign() != sign {
+                    return Err(Sec1Error::ParityMismatch);
+                }
+                Ok(point)
+            }
+
+            /// Encode the point in the SEC1 form
+            pub fn encode_sec1(&self, form: $crate::curve::sec1::Sec1Form) -> Vec<u8> {
+                match form {
+                    $crate::curve::sec1::Sec1Form::Compressed => compressed_sec1(self).to_vec(),
+                    $crate::curve::sec1::Sec1Form::Uncompressed => {
+                        let mut out = self.encode_hybrid().to_vec();
+                        out[0] = 0x04;
+                        out
+                    }
+                    $crate::curve::sec1::Sec1Form::Hybrid => self.encode_hybrid().to_vec(),
+                }
+            }
+
+            /// Hybrid SEC1 encoding of the point, 0x06 or 0x07 (the parity of Y) followed by X and Y
+            ///
+            /// This is the uncompressed encoding with the redundant parity of the compressed
+            /// one, only found in some legacy systems.
+            pub fn encode_hybrid(&self) -> [u8; 1 + 2 * FieldElement::SIZE_BYTES] {
+                let (x, y) = self.to_coordinate();
+                let mut out = [0u8; 1 + 2 * FieldElement::SIZE_BYTES];
+                out[0] = match y.sign() {
+                    Sign::Positive => 0x06,
+                    Sign::Negative => 0x07,
+                };
+                out[1..1 + FieldElement::SIZE_BYTES].copy_from_slice(&x.to_bytes());
+                out[1 + FieldElement::SIZE_BYTES..].copy_from_slice(&y.to_bytes());
+                out
+            }
+
+            /// Validate many SEC1 encodings of points, e.g. the public keys of a batch of clients
+            ///
+            /// Each result is the point, or the reason for rejecting its encoding as with
+            /// `decode_sec1`: the point at infinity, a prefix or length mismatch, an encoding
+            /// form that isn't allowed by the flags, a non canonical coordinate, a point not on
+            /// the curve, or a hybrid parity mismatch.
+            ///
+            /// The uncompressed encodings only need the curve equation, which doesn't have
+            /// any inversion, and the compressed ones a square root each, so the cost is
+            /// dominated by the compressed encodings. With `fail_fast`, the validation stops
+            /// at the first rejected encoding, which is then the last result, otherwise there's
+            /// one result for each encoding.
+            pub fn batch_validate_sec1(
+                encodings: &[&[u8]],
+                flags: $crate::curve::sec1::ValidationFlags,
+            ) -> Vec<Result<Self, $crate::curve::sec1::Sec1Error>> {
+                let mut results = Vec::with_capacity(encodings.len());
+                for bytes in encodings {
+                    let r = Self::decode_sec1(bytes, flags);
+                    let stop = flags.fail_fast && r.is_err();
+                    results.push(r);
+                    if stop {
+                        break;
+                    }
+                }
+                results
+            }
+
+            /// Check if the bytes are a valid SEC1 encoding of a point, as accepted by `from_sec1_bytes`
+            ///
+            /// Unlike `FieldElement::is_canonical_bytes`, this needs the on curve check
+            /// of the coordinates, and a square root for the compressed encodings, so it
+            /// is as expensive as parsing the point.
+            pub fn is_valid_sec1_encoding(bytes: &[u8]) -> bool {
+                Self::from_sec1_bytes(bytes).is_some()
+            }
+
+            /// Check if this point is the same as the compressed point (X bytes, sign of Y)
+            ///
+            /// This doesn't need to decompress the point, instead the canonical
+            /// bytes of X of this point are compared in constant time with the x_bytes.
+            /// Non canonical encodings of X (value greater or equal to the field order),
+            /// or encodings of the wrong size never match.
+            pub fn matches_compressed(&self, x_bytes: &[u8], sign: Sign) -> bool {
+                if x_bytes.len() != FieldElement::SIZE_BYTES {
+                    return false;
+                }
+                let mut expected = [0u8; FieldElement::SIZE_BYTES];
+                expected.copy_from_slice(x_bytes);
+
+                let (x, ysign) = self.compress();
+                let same_sign = Choice::from_bit((ysign == sign) as u64);
+                (x.to_bytes().ct_eq(&expected) & same_sign).is_true()
+            }
+
+            /// Check if this point is the one of the SEC1 encoding, compressed, uncompressed
+            /// or hybrid
+            ///
+            /// Like `matches_compressed`, this doesn't decode the bytes, instead the
+            /// encoding of this point in the same form is compared in constant time with
+            /// the bytes. Non canonical coordinates, a hybrid prefix with the wrong parity,
+            /// the point at infinity, an unknown prefix or a wrong size never match.
+            pub fn matches_sec1(&self, bytes: &[u8]) -> bool {
+                match bytes.split_first() {
+                    Some((0x02, x_bytes)) => self.matches_compressed(x_bytes, Sign::Positive),
+                    Some((0x03, x_bytes)) => self.matches_compressed(x_bytes, Sign::Negative),
+                    Some((tag @ (0x04 | 0x06 | 0x07), _))
+                        if bytes.len() == 1 + 2 * FieldElement::SIZE_BYTES =>
+                    {
+                        let mut expected = self.encode_hybrid();
+                        if *tag == 0x04 {
+                            expected[0] = 0x04;
+                        }
+                        let mut got = [0u8; 1 + 2 * FieldElement::SIZE_BYTES];
+                        got.copy_from_slice(bytes);
+                        expected.ct_eq(&got).is_true()
+                    }
+                    _ => false,
+                }
+            }
+
+            /// Negate the point in constant time if the choice is true, otherwise leave it unchanged
+            pub fn conditional_negate(&mut self, c: Choice) {
+                self.0.conditional_negate(c)
+            }
+        }
+
+        // compressed SEC1 encoding of an affine point: 0x02 or 0x03 (the sign of Y) followed by X
+        fn compressed_sec1(point: &PointAffine) -> [u8; 1 + FieldElement::SIZE_BYTES] {
+            let (x, sign) = point.compress();
+            compressed_sec1_parts(x, sign)
+        }
+
+        fn compressed_sec1_parts(
+            x: &FieldElement,
+            sign: Sign,
+        ) -> [u8; 1 + FieldElement::SIZE_BYTES] {
+            let mut compressed = [0u8; 1 + FieldElement::SIZE_BYTES];
+            compressed[0] = match sign {
+                Sign::Positive => 0x02,
+                Sign::Negative => 0x03,
+            };
+            compressed[1..].copy_from_slice(&x.to_bytes());
+            compressed
+        }
+
+        /// Affine point with its compressed SEC1 encoding, computed on first use
+        ///
+        /// Equality and hashing are on the compressed encoding, which is unique for
+        /// each point, so this can be used as a map key without compressing the point
+        /// every time. Clones keep the encoding if it was already computed.
+        #[derive(Clone, Debug)]
+        pub struct CachedPoint {
+            point: PointAffine,
+            compressed: std::sync::OnceLock<[u8; 1 + FieldElement::SIZE_BYTES]>,
+        }
+
+        impl CachedPoint {
+            /// Wrap an affine point, the encoding is only computed when first needed
+            pub fn new(point: PointAffine) -> Self {
+                CachedPoint {
+                    point,
+                    compressed: std::sync::OnceLock::new(),
+                }
+            }
+
+            /// Try to create a point from its SEC1 encoding, compressed or uncompressed
+            ///
+            /// The compressed encoding is taken from the input, without compressing
+            /// the point again. See `PointAffine::from_sec1_bytes` for the encodings
+            /// that are rejected.
+            pub fn from_sec1_bytes(bytes: &[u8]) -> Option<Self> {
+                let point = PointAffine::from_sec1_bytes(bytes)?;
+                let mut compressed = [0u8; 1 + FieldElement::SIZE_BYTES];
+                compressed[0] = match bytes[0] {
+                    0x04 => match point.compress().1 {
+                        Sign::Positive => 0x02,
+                        Sign::Negative => 0x03,
+                    },
+                    tag => tag,
+                };
+                compressed[1..].copy_from_slice(&bytes[1..1 + FieldElement::SIZE_BYTES]);
+                Some(CachedPoint {
+                    point,
+                    compressed: std::sync::OnceLock::from(compressed),
+                })
+            }
+
+            /// Get the underlying affine point
+            pub fn as_point(&self) -> &PointAffine {
+                &self.point
+            }
+
+            /// Get the compressed SEC1 encoding of the point, computing it on the first call
+            pub fn compressed_bytes(&self) -> &[u8; 1 + FieldElement::SIZE_BYTES] {
+                self.compressed.get_or_init(|| compressed_sec1(&self.point))
             }
         }
 
+        impl PartialEq for CachedPoint {
+            fn eq(&self, other: &Self) -> bool {
+                self.compressed_bytes() == other.compressed_bytes()
+            }
+        }
+
+        impl Eq for CachedPoint {}
+
+        impl std::hash::Hash for CachedPoint {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.compressed_bytes().hash(state)
+            }
+        }
+
+        impl From<PointAffine> for CachedPoint {
+            fn from(point: PointAffine) -> Self {
+                CachedPoint::new(point)
+            }
+        }
+
+        impl From<CachedPoint> for PointAffine {
+            fn from(point: CachedPoint) -> PointAffine {
+                point.point
+            }
+        }
+
+        impl PointAffine {
+            /// Add two affine points, or None if the result is the point at infinity,
+            /// which the affine representation cannot handle
+            ///
+            /// The choice between the doubling and the addition is not constant time.
+            pub fn add(&self, other: &PointAffine) -> Option<PointAffine> {
+                self.0.add(&other.0, Curve).map(PointAffine)
+            }
+        }
+
+        /// Add two affine points
+        ///
+        /// # Panics
+        ///
+        /// When the points are opposite, as their sum is the point at infinity, see
+        /// `PointAffine::add` for the fallible addition.
         impl<'a, 'b> std::ops::Add<&'b PointAffine> for &'a PointAffine {
             type Output = PointAffine;
             fn add(self, other: &'b PointAffine) -> PointAffine {
-                PointAffine(&self.0 + &other.0)
+                PointAffine::add(self, other).expect("affine sum is not the point at infinity")
+            }
+        }
+
+        impl $crate::curve::EllipticCurve for Curve {
+            type FieldElement = $FE;
+            type Scalar = Scalar;
+            type Point = Point;
+            type PointAffine = PointAffine;
+
+            const FE_BYTES: usize = $FE::SIZE_BYTES;
+            const SCALAR_BYTES: usize = Scalar::SIZE_BYTES;
+
+            fn generator() -> Point {
+                Point::generator()
+            }
+
+            fn order_bytes() -> &'static [u8] {
+                Curve.group_order()
+            }
+
+            fn scalar_from_bytes(bytes: &[u8]) -> Option<Scalar> {
+                Scalar::from_slice(bytes)
+            }
+
+            fn scalar_to_bytes(scalar: &Scalar) -> Vec<u8> {
+                scalar.to_bytes().to_vec()
+            }
+
+            fn field_element_from_bytes(bytes: &[u8]) -> Option<$FE> {
+                $FE::from_slice(bytes)
+            }
+
+            fn field_element_to_bytes(fe: &$FE) -> Vec<u8> {
+                fe.to_bytes().to_vec()
+            }
+
+            fn point_decompress(x: &$FE, sign: Sign) -> Option<PointAffine> {
+                PointAffine::decompress(x, sign)
+            }
+
+            fn point_compress(p: &PointAffine) -> ($FE, Sign) {
+                let (x, sign) = p.compress();
+                (x.clone(), sign)
+            }
+
+            fn point_to_affine(p: &Point) -> Option<PointAffine> {
+                p.to_affine()
+            }
+
+            fn point_from_affine(p: &PointAffine) -> Point {
+                Point::from_affine(p)
+            }
+
+            fn add(p: &Point, q: &Point) -> Point {
+                p + q
+            }
+
+            fn scale(p: &Point, scalar: &Scalar) -> Point {
+                p.scale_ct(scalar)
+            }
+        }
+
+        impl Curve {
+            /// Check the consistency of the generator with the curve parameters
+            ///
+            /// The generator has to be on the curve, multiplied by the group order
+            /// it gives the point at infinity, and multiplied by the order minus one
+            /// it gives its own negation. This is a cheap sanity check of the
+            /// parameters, e.g. to run at startup.
+            pub fn verify_parameters(self) -> bool {
+                let (gx, gy) = Self::generator();
+                if PointAffine::from_coordinate(gx, gy).is_none() {
+                    return false;
+                }
+                let g = Point::generator();
+                g.scale_bytes(self.group_order()) == Point::infinity() && &g * &Scalar::max() == -&g
             }
         }
 
@@ -123,8 +618,8 @@ macro_rules! fiat_define_weierstrass_points {
             /// Curve generator point
             pub fn generator() -> Self {
                 Point(projective::Point {
-                    x: GX.clone(),
-                    y: GY.clone(),
+                    x: generator_x().clone(),
+                    y: generator_y().clone(),
                     z: FieldElement::one(),
                 })
             }
@@ -137,34 +632,441 @@ macro_rules! fiat_define_weierstrass_points {
             /// Convert an affine point to optimised point representation
             ///
             /// In projective coordinate it means, (X,Y) => (X:Y:1)
+            ///
+            /// The affine points are always on the curve, which is verified again
+            /// with a debug assertion.
             pub fn from_affine(p: &PointAffine) -> Self {
+                debug_assert!(
+                    p.0.is_on_curve(Curve),
+                    "from_affine: point not on the curve"
+                );
                 Point(projective::Point::from_affine(&p.0))
             }
 
             /// Convert a point to the affine point
             ///
-            /// In projective coordinate it means, (X:Y:Z) => (X/Z, Y/Z)
+            /// In projective coordinate it means, (X:Y:Z) => (X/Z, Y/Z), which costs
+            /// a field inversion, also when the point is already normalized, so that
+            /// the time doesn't depend on it
             pub fn to_affine(&self) -> Option<PointAffine> {
                 self.0.to_affine().map(PointAffine)
             }
 
+            /// Same as `to_affine`, but consuming the point, which reuses the coordinates
+            pub fn into_affine(self) -> Option<PointAffine> {
+                self.0.into_affine().map(PointAffine)
+            }
+
+            /// Compress the point as the affine X component and the sign of the affine Y component
+            ///
+            /// Same as `to_affine` followed by `PointAffine::compress`, without building the
+            /// affine point. The point at infinity gives None
+            pub fn compress(&self) -> Option<(FieldElement, Sign)> {
+                self.0.compress()
+            }
+
+            /// Compressed SEC1 encoding of the point, 0x02 or 0x03 (the sign of Y) followed by X
+            ///
+            /// The point at infinity gives None, its SEC1 encoding being the single byte 0x00
+            pub fn to_sec1_compressed(&self) -> Option<[u8; 1 + FieldElement::SIZE_BYTES]> {
+                self.compress()
+                    .map(|(x, sign)| compressed_sec1_parts(&x, sign))
+            }
+
+            /// Decode the SEC1 encoding of a point, including the point at infinity 0x00
+            ///
+            /// See `PointAffine::decode_sec1` for the other forms and the errors.
+            pub fn decode_sec1(
+                bytes: &[u8],
+                flags: $crate::curve::sec1::ValidationFlags,
+            ) -> Result<Self, $crate::curve::sec1::Sec1Error> {
+                match PointAffine::decode_sec1(bytes, flags) {
+                    Err($crate::curve::sec1::Sec1Error::Infinity) => Ok(Point::infinity()),
+                    r => r.map(|p| Point::from_affine(&p)),
+                }
+            }
+
+            /// Encode the point in the SEC1 form, the point at infinity being the single byte 0x00
+            pub fn encode_sec1(&self, form: $crate::curve::sec1::Sec1Form) -> Vec<u8> {
+                match self.to_affine() {
+                    None => vec![0x00],
+                    Some(p) => p.encode_sec1(form),
+                }
+            }
+
             /// Normalize the point, keeping the same representation
             ///
-            /// In projective coordinate it means, (X:Y:Z) => (X/Z:Y/Z:1)
+            /// In projective coordinate it means, (X:Y:Z) => (X/Z:Y/Z:1), which costs
+            /// a field inversion, also when the point is already normalized. The point
+            /// at infinity is unchanged.
             pub fn normalize(&mut self) {
                 self.0.normalize()
             }
+
+            /// Same as `normalize`, by value
+            pub fn normalized(self) -> Self {
+                Point(self.0.normalized())
+            }
+
+            /// Check if the point is normalized (Z = 1), X and Y being then the affine
+            /// coordinates
+            ///
+            /// This is a single field element comparison. The points from `from_affine`
+            /// are normalized, the results of the arithmetic usually aren't, and the point
+            /// at infinity never is.
+            pub fn is_normalized(&self) -> bool {
+                self.0.is_normalized()
+            }
+
+            /// Bytes of the projective coordinates as they are, X || Y || Z in big endian
+            ///
+            /// This is for checkpointing long computations, without the inversion of the
+            /// normalization. It is not an interchange format: equal points usually have
+            /// different encodings, and only `from_unnormalized_bytes` reads them back.
+            /// The layout doesn't change between versions.
+            pub fn to_unnormalized_bytes(&self) -> [u8; 3 * FieldElement::SIZE_BYTES] {
+                let n = FieldElement::SIZE_BYTES;
+                let mut out = [0u8; 3 * FieldElement::SIZE_BYTES];
+                out[..n].copy_from_slice(&self.0.x.to_bytes());
+                out[n..2 * n].copy_from_slice(&self.0.y.to_bytes());
+                out[2 * n..].copy_from_slice(&self.0.z.to_bytes());
+                out
+            }
+
+            /// Read back the bytes of `to_unnormalized_bytes`
+            ///
+            /// Returns None if a coordinate is not less than the field modulus, or if
+            /// (X:Y:Z) is not on the curve: Y²Z = X³ + aXZ² + bZ³, and not (0:0:0)
+            pub fn from_unnormalized_bytes(
+                bytes: &[u8; 3 * FieldElement::SIZE_BYTES],
+            ) -> Option<Point> {
+                let n = FieldElement::SIZE_BYTES;
+                let x = FieldElement::from_slice(&bytes[..n])?;
+                let y = FieldElement::from_slice(&bytes[n..2 * n])?;
+                let z = FieldElement::from_slice(&bytes[2 * n..])?;
+                let zz = z.square();
+                let lhs = &y.square() * &z;
+                let rhs = &(&x.square() * &x)
+                    + &(&(&(Curve.a() * &x) * &zz) + &(Curve.b() * &(&zz * &z)));
+                if lhs != rhs || (y.is_zero() && z.is_zero()) {
+                    return None;
+                }
+                Some(Point(projective::Point { x, y, z }))
+            }
+
+            /// Scalar multiplication by a big endian integer of any size
+            ///
+            /// See the `Mul` implementation for the semantics, for example multiplying
+            /// by the group order bytes gives the point at infinity.
+            ///
+            /// Inputs longer than `Scalar::SIZE_BYTES + 8` bytes are almost always the
+            /// scalar of another curve, and trigger a debug assertion. With the `paranoid`
+            /// feature, any input longer than `Scalar::SIZE_BYTES` does. Use `scale_bytes_exact`
+            /// to have the size checked at compile time.
+            pub fn scale_bytes(&self, n: &[u8]) -> Point {
+                $crate::curve::check_scale_bytes_len(n.len(), Scalar::SIZE_BYTES);
+                Point(self.0.scale_vartime(n, Curve))
+            }
+
+            /// Scalar multiplication by a small integer, `n * P`
+            ///
+            /// Only the 64 bits of n are processed, which is faster than a multiplication
+            /// by the same value as a `Scalar`. This is also `self * n`
+            pub fn scale_u64(&self, n: u64) -> Point {
+                self.scale_bytes(&n.to_be_bytes())
+            }
+
+            /// Scalar multiplication by a big endian integer of exactly the size of a scalar
+            ///
+            /// Same as `scale_bytes`, and the value can still be greater than the group order
+            pub fn scale_bytes_exact(&self, n: &[u8; Scalar::SIZE_BYTES]) -> Point {
+                Point(self.0.scale_vartime(n, Curve))
+            }
+
+            /// Scalar multiplication with an explicitly chosen algorithm
+            ///
+            /// All the algorithms give the same point, and `self * scalar` is the same point
+            /// as `self.scale_with(scalar, MulAlgorithm::DEFAULT)`. The `Daa8` and `Wnaf`
+            /// algorithms are variable time.
+            ///
+            /// Panics if the width of `Wnaf` is not between 2 and 8
+            pub fn scale_with(&self, scalar: &Scalar, alg: $crate::curve::MulAlgorithm) -> Point {
+                match alg {
+                    $crate::curve::MulAlgorithm::Daa8 => self.scale(scalar),
+                    $crate::curve::MulAlgorithm::Ladder => self.scale_ladder(scalar),
+                    $crate::curve::MulAlgorithm::Wnaf { width } => self.scale_wnaf(scalar, width),
+                }
+            }
+
+            /// Constant time scalar multiplication, with the Montgomery ladder
+            ///
+            /// This is the `Mul` operator with the `default-mul-ct` feature (the default), and
+            /// is always available, for the libraries which handle secret scalars whatever
+            /// the features chosen by the application.
+            pub fn scale_ct(&self, scalar: &Scalar) -> Point {
+                fault_point!(self.scale_ladder(scalar), |p: Point| &p
+                    + &Point::generator())
+            }
+
+            /// Constant time scalar multiplication of the generator, with the `GeneratorTable`
+            /// of the curve, created on the first call
+            ///
+            /// This gives the same point as `Point::generator() * scalar`.
+            pub fn base_scalar_mul(scalar: &Scalar) -> Point {
+                static TABLE: std::sync::OnceLock<GeneratorTable> = std::sync::OnceLock::new();
+                TABLE.get_or_init(GeneratorTable::new).mul(scalar)
+            }
+
+            /// Constant time scalar multiplication checked against faults, None if
+            /// the check fails
+            ///
+            /// The result of `scale_ct` is multiplied by the inverse of the scalar, which
+            /// has to give this point back: a fault during either multiplication gives
+            /// None instead of a wrong point. This is a bit more than twice the cost of
+            /// `scale_ct`, see the `fault` module.
+            #[cfg(feature = "fault-hardening")]
+            pub fn scale_verified(&self, scalar: &Scalar) -> Option<Point> {
+                // 0 has no inverse, and the product is infinity whatever the point
+                if scalar.is_zero() {
+                    return Some(Point::infinity());
+                }
+                let r = self.scale_ct(scalar);
+                let back = r.scale_ct(&scalar.inverse());
+                if $crate::mp::ct::CtEqual::ct_eq(&back, self).is_true() {
+                    Some(r)
+                } else {
+                    None
+                }
+            }
+
+            /// Variable time scalar multiplication, for the points and scalars that are public
+            ///
+            /// This gives the same point as `self * scalar`, computed in Jacobian coordinates
+            /// (see the `jacobian` module) which are faster but not constant time, e.g. for
+            /// signature verification. This is the `Mul` operator with the `default-mul-vartime`
+            /// feature, and is always available.
+            pub fn scale_vartime(&self, scalar: &Scalar) -> Point {
+                self.scale_jacobian(scalar)
+            }
+
+            /// Variable time scalar multiplication, the same as `scale_vartime`
+            pub fn mul_vartime(&self, scalar: &Scalar) -> Point {
+                self.scale_vartime(scalar)
+            }
+
+            /// Variable time double scalar multiplication `a * self + b * other`, for the
+            /// points and scalars that are public
+            ///
+            /// This gives the same point as `self * a + other * b`, with a single chain of
+            /// doublings over the joint sparse form of the scalars (see `Scalar::jsf`).
+            pub fn double_scalar_mul_vartime(
+                &self,
+                a: &Scalar,
+                other: &Point,
+                b: &Scalar,
+            ) -> Point {
+                self.double_scale_vartime(a, other, b)
+            }
+
+            /// Variable time `s1 * p1 + s2 * p2`, the same as `p1.double_scalar_mul_vartime(s1, p2, s2)`
+            ///
+            /// Either scalar can be zero, and either point can be the point at infinity
+            /// or the other point.
+            pub fn mul_add_vartime(s1: &Scalar, p1: &Point, s2: &Scalar, p2: &Point) -> Point {
+                p1.double_scale_vartime(s1, p2, s2)
+            }
+
+            /// Variable time `a * G + b * other`, G being the generator, e.g. for the
+            /// verification of signatures
+            pub fn mul_gen_add_vartime(a: &Scalar, b: &Scalar, other: &Point) -> Point {
+                Point::generator().double_scale_vartime(a, other, b)
+            }
+
+            /// Scalar multiplication returning the affine point, or None if the result is infinity
+            ///
+            /// This is a convenience wrapper for `self.scale_ct(scalar).to_affine()`, constant
+            /// time whatever the `default-mul-*` feature: the inversion of Z isn't folded in
+            /// the multiplication, it is the one of `to_affine` afterwards.
+            /// The result can be serialized or compared without any further normalization,
+            /// where a projective point needs 4 multiplications per comparison. Prefer the
+            /// plain `Mul` when the result is used in further point arithmetic.
+            pub fn mul_to_affine(&self, scalar: &Scalar) -> Option<PointAffine> {
+                self.scale_ct(scalar).to_affine()
+            }
+
+            /// Negate the point in constant time if the choice is true, otherwise leave it unchanged
+            pub fn conditional_negate(&mut self, c: Choice) {
+                self.0.conditional_negate(c)
+            }
+
+            /// Multi scalar multiplication : `scalars[0] * points[0] + ... + scalars[k] * points[k]`
+            ///
+            /// This is variable time, and panics if the number of scalars and points differ.
+            /// Use `MsmAccumulator` to not buffer all the pairs.
+            pub fn msm(scalars: &[Scalar], points: &[Point]) -> Point {
+                let scalars: Vec<_> = scalars.iter().map(|s| s.to_bytes()).collect();
+                let points: Vec<_> = points.iter().map(|p| p.0.clone()).collect();
+                Point(projective::Point::<$FE>::multi_scale(
+                    &scalars, &points, Curve,
+                ))
+            }
+
+            /// Same as `msm` on arrays, without allocation
+            ///
+            /// The scalars and points are copied on the stack, and the bucket method
+            /// uses windows of 4 bits.
+            pub fn msm_array<const N: usize>(scalars: &[Scalar; N], points: &[Point; N]) -> Point {
+                let scalars: [[u8; Scalar::SIZE_BYTES]; N] =
+                    core::array::from_fn(|i| scalars[i].to_bytes());
+                let points: [projective::Point<$FE>; N] =
+                    core::array::from_fn(|i| points[i].0.clone());
+                let mut buckets: [projective::Point<$FE>; 15] =
+                    core::array::from_fn(|_| projective::Point::infinity());
+                Point(projective::Point::<$FE>::multi_scale_buckets(
+                    &scalars,
+                    &points,
+                    &mut buckets,
+                    Curve,
+                ))
+            }
+
+            /// Convert the points to affine points, with a single field inversion
+            ///
+            /// The points at infinity are converted to None.
+            pub fn batch_to_affine(points: &[Point]) -> Vec<Option<PointAffine>> {
+                let points: Vec<_> = points.iter().map(|p| p.0.clone()).collect();
+                projective::Point::<$FE>::batch_to_affine(&points)
+                    .into_iter()
+                    .map(|p| p.map(PointAffine))
+                    .collect()
+            }
+
+            /// Same as `batch_to_affine` on arrays, without allocation
+            pub fn batch_to_affine_array<const N: usize>(
+                points: &[Point; N],
+            ) -> [Option<PointAffine>; N] {
+                let points: [projective::Point<$FE>; N] =
+                    core::array::from_fn(|i| points[i].0.clone());
+                projective::Point::<$FE>::batch_to_affine_array(&points).map(|p| p.map(PointAffine))
+            }
+        }
+
+        /// Streaming multi scalar multiplication
+        ///
+        /// Give the same result as `Point::msm`, but the pairs are pushed one at a time,
+        /// and summed in chunks, so the memory used stays bounded. The Debug output
+        /// doesn't show the scalars.
+        #[derive(Clone)]
+        pub struct MsmAccumulator(projective::MsmAccumulator<Curve, [u8; Scalar::SIZE_BYTES]>);
+
+        impl MsmAccumulator {
+            /// Create a new accumulator, whose sum is the point at infinity
+            pub fn new() -> Self {
+                MsmAccumulator(projective::MsmAccumulator::<Curve, _>::new(Curve))
+            }
+
+            /// Add `scalar * point` to the sum
+            pub fn push(&mut self, scalar: &Scalar, point: &Point) {
+                self.0.push(scalar.to_bytes(), &point.0)
+            }
+
+            /// Return the sum of all the pushed `scalar * point`
+            pub fn finalize(self) -> Point {
+                Point(self.0.finalize())
+            }
+        }
+
+        impl Default for MsmAccumulator {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl core::fmt::Debug for MsmAccumulator {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        /// Table of the multiples of a point, for constant time fixed base scalar
+        /// multiplications with windows of W bits
+        ///
+        /// N is the number of entries, 2^W-1, and both are checked at compile time
+        /// (see `projective::PointTable`). `PointTable4` and `PointTable6` are the
+        /// usual widths, the larger table being faster to use but slower to create.
+        #[derive(Clone, Debug)]
+        pub struct PointTable<const W: usize, const N: usize>(projective::PointTable<$FE, W, N>);
+
+        /// Table with windows of 4 bits (15 entries)
+        pub type PointTable4 = PointTable<4, 15>;
+
+        /// Table with windows of 6 bits (63 entries)
+        pub type PointTable6 = PointTable<6, 63>;
+
+        impl<const W: usize, const N: usize> PointTable<W, N> {
+            /// Table of the multiples of the point, or None for the point at infinity
+            pub fn new(p: &Point) -> Option<Self> {
+                projective::PointTable::<$FE, W, N>::new(&p.0, Curve).map(PointTable)
+            }
+
+            /// Width of the windows, in bits
+            pub const fn width(&self) -> usize {
+                W
+            }
+
+            /// Scalar multiplication of the point of the table, the same as `point * scalar`
+            pub fn mul(&self, scalar: &Scalar) -> Point {
+                Point(self.0.scale(&scalar.to_bytes(), Curve))
+            }
+        }
+
+        /// Table of the multiples of the generator for each window of 5 bits of the
+        /// scalars, for constant time fixed base scalar multiplications without doublings
+        ///
+        /// The scalars are recoded in signed digits (see `Scalar::recode_signed_fixed`), so
+        /// there are 16 entries per window. This is about 5 times faster than
+        /// `Point::generator() * scalar`, e.g. for the key generations, but the table takes
+        /// a while to compute: use `Point::base_scalar_mul`, which creates the table of the
+        /// curve once.
+        #[derive(Clone, Debug)]
+        pub struct GeneratorTable(projective::FixedBaseTable<$FE, 5, 16>);
+
+        impl GeneratorTable {
+            /// Table of the multiples of the generator
+            pub fn new() -> Self {
+                let g = Point::generator();
+                GeneratorTable(
+                    projective::FixedBaseTable::<$FE, 5, 16>::new(
+                        &g.0,
+                        Scalar::SIZE_BYTES * 8,
+                        Curve,
+                    )
+                    .expect("generator multiples are not infinity"),
+                )
+            }
+
+            /// Scalar multiplication of the generator, the same as `Point::generator() * scalar`
+            pub fn mul(&self, scalar: &Scalar) -> Point {
+                let digits = scalar.recode_signed_fixed(self.0.width());
+                Point(self.0.scale_recoded(&digits, Curve))
+            }
+        }
+
+        impl Default for GeneratorTable {
+            fn default() -> Self {
+                Self::new()
+            }
         }
 
         impl From<PointAffine> for Point {
             fn from(p: PointAffine) -> Self {
-                Point(projective::Point::from_affine(&p.0))
+                Point::from_affine(&p)
             }
         }
 
         impl From<&PointAffine> for Point {
             fn from(p: &PointAffine) -> Self {
-                Point(projective::Point::from_affine(&p.0))
+                Point::from_affine(&p)
             }
         }
 
@@ -196,12 +1098,61 @@ macro_rules! fiat_define_weierstrass_points {
         // (of any size), not just the *field element* scalar defined in F(p).
         // this semantic abuse makes it easier to use.
 
+        /// Scalar multiplication `n * P`
+        ///
+        /// The result only depends on n modulo the group order: multiplying by zero (or by
+        /// the order with `scale_bytes`) gives the point at infinity, multiplying by one gives
+        /// a point equal to P, and multiplying the point at infinity gives the point at infinity.
+        ///
+        /// This is the constant time `Point::scale_ct` with the `default-mul-ct` feature (the
+        /// default), and the variable time `Point::scale_vartime` with the `default-mul-vartime`
+        /// feature, only for the applications where all the scalars are public. The libraries
+        /// should call one of them explicitly. See `Point::scale_with` for the other algorithms.
         impl<'a, 'b> std::ops::Mul<&'b Scalar> for &'a Point {
             type Output = Point;
 
             fn mul(self, other: &'b Scalar) -> Point {
-                self.scale(other)
-                //Point(self.0.scale_a0(&other.to_bytes(), Curve))
+                #[cfg(feature = "default-mul-ct")]
+                let r = self.scale_ct(other);
+                #[cfg(not(feature = "default-mul-ct"))]
+                let r = self.scale_vartime(other);
+                // events containing secrets are only emitted with the trace-secrets feature
+                #[cfg(feature = "trace-secrets")]
+                {
+                    let coordinates = r
+                        .to_affine()
+                        .map(|p| (p.0.x().to_bytes(), p.0.y().to_bytes()));
+                    $crate::trace::emit(&$crate::trace::TraceEvent::ScalarMul {
+                        module: module_path!(),
+                        scalar: &other.to_bytes(),
+                        result: coordinates.as_ref().map(|(x, y)| (&x[..], &y[..])),
+                    });
+                }
+                r
+            }
+        }
+
+        impl<'a> std::ops::Mul<Scalar> for &'a Point {
+            type Output = Point;
+
+            fn mul(self, other: Scalar) -> Point {
+                self * &other
+            }
+        }
+
+        impl<'b> std::ops::Mul<&'b Scalar> for Point {
+            type Output = Point;
+
+            fn mul(self, other: &'b Scalar) -> Point {
+                &self * other
+            }
+        }
+
+        impl std::ops::Mul<Scalar> for Point {
+            type Output = Point;
+
+            fn mul(self, other: Scalar) -> Point {
+                &self * &other
             }
         }
 
@@ -213,6 +1164,35 @@ macro_rules! fiat_define_weierstrass_points {
             }
         }
 
+        /// Scalar multiplication by a small integer, see `Point::scale_u64`
+        impl<'a> std::ops::Mul<u64> for &'a Point {
+            type Output = Point;
+
+            fn mul(self, other: u64) -> Point {
+                self.scale_u64(other)
+            }
+        }
+
+        impl std::ops::Mul<u64> for Point {
+            type Output = Point;
+
+            fn mul(self, other: u64) -> Point {
+                self.scale_u64(other)
+            }
+        }
+
+        /// Scalar multiplication by the big endian bytes of a scalar
+        ///
+        /// The bytes are parsed with `Scalar::from_bytes`, so the result is None when
+        /// they are not less than the group order (`Point::scale_bytes_exact` accepts any value)
+        impl<'a, 'b> std::ops::Mul<&'b [u8; Scalar::SIZE_BYTES]> for &'a Point {
+            type Output = Option<Point>;
+
+            fn mul(self, other: &'b [u8; Scalar::SIZE_BYTES]) -> Option<Point> {
+                Scalar::from_bytes(other).map(|s| self * &s)
+            }
+        }
+
         // **************
         // Point Addition
         // **************
@@ -257,6 +1237,22 @@ macro_rules! fiat_define_weierstrass_points {
             }
         }
 
+        impl<'b> std::ops::Sub<&'b Point> for Point {
+            type Output = Point;
+
+            fn sub(self, other: &'b Point) -> Point {
+                &self - other
+            }
+        }
+
+        impl<'a> std::ops::Sub<Point> for &'a Point {
+            type Output = Point;
+
+            fn sub(self, other: Point) -> Point {
+                self - &other
+            }
+        }
+
         impl std::ops::Sub<Point> for Point {
             type Output = Point;
 
@@ -266,6 +1262,534 @@ macro_rules! fiat_define_weierstrass_points {
         }
     };
 }
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_define_keys {
+    () => {
+        /// Secret key, which is a non zero scalar
+        ///
+        /// All the constructors reject zero, so a secret key always has a public key
+        /// that is not the point at infinity. The Debug output doesn't show the scalar.
+        ///
+        /// Equality is constant time, and there's no variable time alternative. The hash
+        /// is over the big endian bytes, which are the canonical encoding of the key.
+        #[derive(Clone)]
+        pub struct SecretKey(Scalar);
+
+        /// Public key, which is a point on the curve that is not the point at infinity
+        ///
+        /// Since the curve has a prime order, any point on the curve apart from
+        /// the point at infinity is a valid public key. The compressed SEC1 encoding
+        /// is computed once at creation.
+        ///
+        /// A public key isn't secret, so equality and hash are both on the compressed
+        /// encoding, and equality is variable time.
+        #[derive(Clone, Debug)]
+        pub struct PublicKey {
+            point: PointAffine,
+            compressed: [u8; 1 + FieldElement::SIZE_BYTES],
+        }
+
+        impl SecretKey {
+            /// Create a secret key from a scalar, returning None if the scalar is zero
+            pub fn from_scalar(scalar: Scalar) -> Option<Self> {
+                if scalar.is_zero() {
+                    None
+                } else {
+                    Some(SecretKey(scalar))
+                }
+            }
+
+            /// Create a secret key from the big endian bytes of the scalar
+            ///
+            /// Returns None if the value is zero or not less than the group order
+            pub fn from_bytes(bytes: &[u8; Scalar::SIZE_BYTES]) -> Option<Self> {
+                Scalar::from_bytes_nonzero(bytes).map(SecretKey)
+            }
+
+            /// Same as `from_bytes` for a slice, which also returns None for a size mismatch
+            pub fn from_slice(slice: &[u8]) -> Option<Self> {
+                Scalar::from_slice_nonzero(slice).map(SecretKey)
+            }
+
+            /// Generate a random secret key
+            #[cfg(feature = "rand_core")]
+            pub fn generate<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> Self {
+                loop {
+                    if let Some(key) = Self::from_scalar(Scalar::random(rng)) {
+                        return key;
+                    }
+                }
+            }
+
+            /// Output the big endian bytes of the scalar
+            pub fn to_bytes(&self) -> [u8; Scalar::SIZE_BYTES] {
+                self.0.to_bytes()
+            }
+
+            /// Get the public key associated with this secret key
+            pub fn public_key(&self) -> PublicKey {
+                let p = Point::generator()
+                    .scale_ct(&self.0)
+                    .to_affine()
+                    .expect("non zero multiple of the generator");
+                PublicKey::from_point(p)
+            }
+
+            /// Get the underlying scalar
+            pub fn as_scalar(&self) -> &Scalar {
+                &self.0
+            }
+        }
+
+        impl PartialEq for SecretKey {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::mp::ct::CtEqual::ct_eq(&self.0, &other.0).is_true()
+            }
+        }
+
+        impl Eq for SecretKey {}
+
+        impl $crate::mp::ct::CtEqual for SecretKey {
+            fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                $crate::mp::ct::CtEqual::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        impl std::hash::Hash for SecretKey {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.to_bytes().hash(state)
+            }
+        }
+
+        impl PartialEq for PublicKey {
+            fn eq(&self, other: &Self) -> bool {
+                self.compressed == other.compressed
+            }
+        }
+
+        impl Eq for PublicKey {}
+
+        impl std::hash::Hash for PublicKey {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.compressed.hash(state)
+            }
+        }
+
+        impl core::fmt::Debug for SecretKey {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("SecretKey(..)")
+            }
+        }
+
+        impl From<SecretKey> for Scalar {
+            fn from(key: SecretKey) -> Scalar {
+                key.0
+            }
+        }
+
+        impl PublicKey {
+            /// Create a public key from an affine point
+            ///
+            /// An affine point is always on the curve and never the point at infinity,
+            /// so this cannot fail
+            pub fn from_point(point: PointAffine) -> Self {
+                let compressed = compressed_sec1(&point);
+                PublicKey { point, compressed }
+            }
+
+            /// Try to create a public key from its SEC1 encoding, compressed or uncompressed
+            ///
+            /// See `PointAffine::from_sec1_bytes` for the encodings that are rejected,
+            /// which include the point at infinity
+            pub fn from_sec1_bytes(bytes: &[u8]) -> Option<Self> {
+                PointAffine::from_sec1_bytes(bytes).map(Self::from_point)
+            }
+
+            /// Output the SEC1 encoding of the public key
+            ///
+            /// The compressed encoding is 0x02 or 0x03 (the sign of Y) followed by X, and
+            /// the uncompressed encoding is 0x04 followed by X and Y
+            pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+                if compressed {
+                    self.compressed.to_vec()
+                } else {
+                    let (x, y) = self.point.to_coordinate();
+                    let mut out = Vec::with_capacity(1 + 2 * FieldElement::SIZE_BYTES);
+                    out.push(0x04);
+                    out.extend_from_slice(&x.to_bytes());
+                    out.extend_from_slice(&y.to_bytes());
+                    out
+                }
+            }
+
+            /// Get the underlying affine point
+            pub fn as_point(&self) -> &PointAffine {
+                &self.point
+            }
+        }
+
+        impl From<PointAffine> for PublicKey {
+            fn from(point: PointAffine) -> Self {
+                PublicKey::from_point(point)
+            }
+        }
+
+        impl From<PublicKey> for PointAffine {
+            fn from(key: PublicKey) -> PointAffine {
+                key.point
+            }
+        }
+
+        /// Rerandomization of key pairs, e.g. for the static keys of long running signers
+        ///
+        /// The pair (d, Q = d.G) becomes (d + r, Q + r.G), which is still a valid pair,
+        /// and `derandomize` with the same r gives back the original pair. r needs to
+        /// be fresh for every rerandomization, and is as sensitive as the secret itself.
+        pub mod keypair {
+            use super::{Point, Scalar};
+
+            /// Rerandomize the pair (d, Q) with a random r, returning (d + r, Q + r.G, r)
+            ///
+            /// r is drawn again when it's zero, or when d + r is zero, so the new secret
+            /// is always a valid secret key. The returned r is for audit, and to undo
+            /// the rerandomization with `derandomize`.
+            #[cfg(feature = "rand_core")]
+            pub fn rerandomize<R: rand_core::RngCore + rand_core::CryptoRng>(
+                secret: &Scalar,
+                public: &Point,
+                rng: &mut R,
+            ) -> (Scalar, Point, Scalar) {
+                loop {
+                    let r = Scalar::random(rng);
+                    if let Some((secret, public)) = rerandomize_with(secret, public, &r) {
+                        return (secret, public, r);
+                    }
+                }
+            }
+
+            /// Rerandomize the pair (d, Q) with the given r, returning (d + r, Q + r.G)
+            ///
+            /// Returns None if r is zero, or if d + r is zero
+            pub fn rerandomize_with(
+                secret: &Scalar,
+                public: &Point,
+                r: &Scalar,
+            ) -> Option<(Scalar, Point)> {
+                let d = secret + r;
+                if r.is_zero() || d.is_zero() {
+                    return None;
+                }
+                Some((d, public + &Point::generator().scale_ct(r)))
+            }
+
+            /// Undo a rerandomization by r, returning (d - r, Q - r.G)
+            ///
+            /// Returns None if d - r is zero, which is not the case when (d, Q) is
+            /// the result of a rerandomization by r of a valid pair
+            pub fn derandomize(
+                secret: &Scalar,
+                public: &Point,
+                r: &Scalar,
+            ) -> Option<(Scalar, Point)> {
+                let d = secret - r;
+                if d.is_zero() {
+                    return None;
+                }
+                Some((d, public - &Point::generator().scale_ct(r)))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_define_signed_scalar {
+    () => {
+        /// Scalar with an explicit sign, for the values of the form ±s where the sign is data
+        ///
+        /// The magnitude is kept as given, and the sign is only applied when converting
+        /// to a scalar (`to_scalar`), or when multiplying a point, by negating the resulting
+        /// point. Both are done in constant time.
+        ///
+        /// Equality is on the value: +s and -(-s) are equal, and so are +0 and -0.
+        #[derive(Clone, Debug)]
+        pub struct SignedScalar {
+            pub sign: Sign,
+            pub magnitude: Scalar,
+        }
+
+        impl SignedScalar {
+            /// Create a signed scalar from its sign and magnitude
+            pub fn new(sign: Sign, magnitude: Scalar) -> Self {
+                SignedScalar { sign, magnitude }
+            }
+
+            /// Create the signed scalar +s
+            pub fn positive(magnitude: Scalar) -> Self {
+                Self::new(Sign::Positive, magnitude)
+            }
+
+            /// Create the signed scalar -s
+            pub fn negative(magnitude: Scalar) -> Self {
+                Self::new(Sign::Negative, magnitude)
+            }
+
+            /// Get the value of the signed scalar as a scalar, negating the magnitude
+            /// in constant time when the sign is negative
+            pub fn to_scalar(&self) -> Scalar {
+                let negated = -&self.magnitude;
+                $crate::mp::ct::CtSelect::ct_select(
+                    &self.magnitude,
+                    &negated,
+                    self.sign.to_choice(),
+                )
+            }
+
+            /// Check if the value is zero, whatever the sign
+            pub fn is_zero(&self) -> bool {
+                self.magnitude.is_zero()
+            }
+        }
+
+        // the product of the signs, as the xor of the negative flags, selected in constant time
+        fn sign_mul(a: Sign, b: Sign) -> Sign {
+            $crate::mp::ct::CtSelect::ct_select(
+                &Sign::Positive,
+                &Sign::Negative,
+                a.to_choice() ^ b.to_choice(),
+            )
+        }
+
+        impl PartialEq for SignedScalar {
+            fn eq(&self, other: &Self) -> bool {
+                self.to_scalar() == other.to_scalar()
+            }
+        }
+
+        impl Eq for SignedScalar {}
+
+        impl From<Scalar> for SignedScalar {
+            fn from(s: Scalar) -> Self {
+                SignedScalar::positive(s)
+            }
+        }
+
+        impl From<SignedScalar> for Scalar {
+            fn from(s: SignedScalar) -> Scalar {
+                s.to_scalar()
+            }
+        }
+
+        impl From<&SignedScalar> for Scalar {
+            fn from(s: &SignedScalar) -> Scalar {
+                s.to_scalar()
+            }
+        }
+
+        impl std::ops::Neg for SignedScalar {
+            type Output = SignedScalar;
+
+            fn neg(self) -> SignedScalar {
+                SignedScalar::new(sign_mul(self.sign, Sign::Negative), self.magnitude)
+            }
+        }
+
+        impl<'a> std::ops::Neg for &'a SignedScalar {
+            type Output = SignedScalar;
+
+            fn neg(self) -> SignedScalar {
+                -self.clone()
+            }
+        }
+
+        impl<'a, 'b> std::ops::Mul<&'b SignedScalar> for &'a SignedScalar {
+            type Output = SignedScalar;
+
+            fn mul(self, other: &'b SignedScalar) -> SignedScalar {
+                SignedScalar::new(
+                    sign_mul(self.sign, other.sign),
+                    &self.magnitude * &other.magnitude,
+                )
+            }
+        }
+
+        impl<'a, 'b> std::ops::Mul<&'b Scalar> for &'a SignedScalar {
+            type Output = SignedScalar;
+
+            fn mul(self, other: &'b Scalar) -> SignedScalar {
+                SignedScalar::new(self.sign, &self.magnitude * other)
+            }
+        }
+
+        impl<'a, 'b> std::ops::Add<&'b Scalar> for &'a SignedScalar {
+            type Output = Scalar;
+
+            fn add(self, other: &'b Scalar) -> Scalar {
+                self.to_scalar() + other
+            }
+        }
+
+        impl<'a, 'b> std::ops::Sub<&'b Scalar> for &'a SignedScalar {
+            type Output = Scalar;
+
+            fn sub(self, other: &'b Scalar) -> Scalar {
+                self.to_scalar() - other
+            }
+        }
+
+        impl<'a, 'b> std::ops::Mul<&'b SignedScalar> for &'a Point {
+            type Output = Point;
+
+            fn mul(self, other: &'b SignedScalar) -> Point {
+                let mut p = self * &other.magnitude;
+                p.conditional_negate(other.sign.to_choice());
+                p
+            }
+        }
+
+        impl<'a, 'b> std::ops::Mul<&'b Point> for &'a SignedScalar {
+            type Output = Point;
+
+            fn mul(self, other: &'b Point) -> Point {
+                other * self
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_define_bytes_api {
+    () => {
+        /// Byte oriented functions, for the wrappers (e.g. C API) that don't want to
+        /// deal with the typed interface
+        ///
+        /// The points are taken as SEC1 encodings, compressed or uncompressed, and are
+        /// always returned as compressed SEC1 encodings. The scalars are the big endian
+        /// bytes of the exact scalar size. Any invalid input (wrong length, point not on
+        /// the curve or at infinity, scalar not less than the group order) and any result
+        /// at infinity give None.
+        ///
+        /// These are the operations of the libsecp256k1 C API of the same names.
+        pub mod bytes {
+            use super::{FieldElement, Point, PublicKey, Scalar, SecretKey};
+
+            /// Size of the compressed SEC1 encoding of a point
+            pub const COMPRESSED_SIZE: usize = 1 + FieldElement::SIZE_BYTES;
+
+            fn parse_point(bytes: &[u8]) -> Option<Point> {
+                PublicKey::from_sec1_bytes(bytes).map(|pk| Point::from_affine(pk.as_point()))
+            }
+
+            fn output(point: Point) -> Option<[u8; COMPRESSED_SIZE]> {
+                point.to_sec1_compressed()
+            }
+
+            /// Compute the public key of a secret key, which needs to be non zero
+            pub fn pubkey_create(secret: &[u8]) -> Option<[u8; COMPRESSED_SIZE]> {
+                SecretKey::from_slice(secret).map(|sk| sk.public_key().compressed)
+            }
+
+            /// Compute `pubkey + tweak * G`, where the tweak can be zero
+            pub fn pubkey_tweak_add(pubkey: &[u8], tweak: &[u8]) -> Option<[u8; COMPRESSED_SIZE]> {
+                let p = parse_point(pubkey)?;
+                let t = Scalar::from_slice(tweak)?;
+                output(&p + &Point::generator().scale_ct(&t))
+            }
+
+            /// Add two points
+            pub fn point_add_bytes(a: &[u8], b: &[u8]) -> Option<[u8; COMPRESSED_SIZE]> {
+                let a = parse_point(a)?;
+                let b = parse_point(b)?;
+                output(&a + &b)
+            }
+
+            /// Multiply a point by a scalar, which needs to be non zero
+            pub fn scalar_mul_bytes(point: &[u8], scalar: &[u8]) -> Option<[u8; COMPRESSED_SIZE]> {
+                let p = parse_point(point)?;
+                let s = SecretKey::from_slice(scalar)?;
+                output(p.scale_ct(s.as_scalar()))
+            }
+        }
+    };
+}
+// negation, subtraction and every owned/borrowed form of the point operators,
+// for the curves of both families (with `Point` and the scalar type in scope)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! test_point_operators {
+    ($SCALAR:ident) => {
+        // pseudo random looking scalars
+        fn operator_scalars() -> Vec<$SCALAR> {
+            let mut v = vec![$SCALAR::zero(), $SCALAR::one(), -$SCALAR::one()];
+            let mut s = $SCALAR::from_u64(0x0e1a);
+            for i in 0..5 {
+                s = &(&s * &s) + &$SCALAR::from_u64(i + 1);
+                v.push(s.clone());
+            }
+            v
+        }
+
+        fn operator_points() -> Vec<Point> {
+            operator_scalars()
+                .iter()
+                .map(|s| &Point::generator() * s)
+                .chain(Some(Point::infinity()))
+                .collect()
+        }
+
+        #[test]
+        fn point_neg_sub() {
+            let inf = Point::infinity();
+            for p in operator_points() {
+                assert_eq!(&p - &p, inf);
+                assert_eq!(&p + &(-&p), inf);
+                assert_eq!(-(-&p), p);
+                assert_eq!(-(-(p.clone())), p);
+                assert_eq!(&inf - &p, -&p);
+                assert_eq!(&p - &inf, p);
+            }
+        }
+
+        #[test]
+        fn point_sub_scalars() {
+            let g = Point::generator();
+            let scalars = operator_scalars();
+            for a in scalars.iter() {
+                for b in scalars.iter() {
+                    assert_eq!(&g * &(a - b), &(&g * a) - &(&g * b));
+                }
+            }
+        }
+
+        #[test]
+        fn point_operator_forms() {
+            let g = Point::generator();
+            let s = $SCALAR::from_u64(0xdead_beef);
+            let p = &g * &s;
+            let sum = &g + &p;
+            let diff = &g - &p;
+            let prod = &g * &s;
+
+            assert_eq!(&g + &p, sum);
+            assert_eq!(&g + p.clone(), sum);
+            assert_eq!(g.clone() + &p, sum);
+            assert_eq!(g.clone() + p.clone(), sum);
+
+            assert_eq!(&g - &p, diff);
+            assert_eq!(&g - p.clone(), diff);
+            assert_eq!(g.clone() - &p, diff);
+            assert_eq!(g.clone() - p.clone(), diff);
+
+            assert_eq!(&g * &s, prod);
+            assert_eq!(&g * s.clone(), prod);
+            assert_eq!(g.clone() * &s, prod);
+            assert_eq!(g.clone() * s.clone(), prod);
+            assert_eq!(&s * &g, prod);
+        }
+    };
+}
 /*
 
 #[cfg(test)]
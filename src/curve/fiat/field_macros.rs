@@ -1,11 +1,13 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! fiat_field_common_impl {
-    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FE_LIMBS_SIZE:expr, $fiat_add:ident, $fiat_sub:ident, $fiat_mul:ident, $fiat_square:ident, $fiat_opp:ident, $fiat_nonzero:ident) => {
+    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FIELD_P_LIMBS:expr, $FIELD_MICRO_LIMBS:expr, $FE_LIMBS_SIZE:expr, $OPS:ident) => {
         $(#[$outer])*
         #[derive(Clone)]
+        #[cfg_attr(feature = "subtle", derive(Copy))]
         pub struct $FE([u64; $FE_LIMBS_SIZE]);
 
+        // constant time, `CtEqual::ct_eq` keeps the result as a Choice
         impl PartialEq for $FE {
             fn eq(&self, other: &Self) -> bool {
                 self.ct_eq(other).is_true()
@@ -13,44 +15,62 @@ macro_rules! fiat_field_common_impl {
         }
         impl Eq for $FE {}
 
-        impl std::fmt::Debug for $FE {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                for b in &self.to_bytes()[..] {
-                    write!(f, "{:02x}", b)?
-                }
-                Ok(())
+        impl core::fmt::Debug for $FE {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, f)
             }
         }
 
-        impl std::fmt::Display for $FE {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                for b in &self.to_bytes()[..] {
-                    write!(f, "{:02x}", b)?
-                }
-                Ok(())
+        impl core::fmt::Display for $FE {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut out = [0u8; 2 * Self::SIZE_BYTES];
+                self.write_hex(&mut out);
+                // only ascii hexadecimal characters are written by write_hex
+                f.write_str(core::str::from_utf8(&out).unwrap())
             }
         }
 
         impl CtZero for $FE {
             fn ct_zero(&self) -> Choice {
                 let mut out = 0u64;
-                $fiat_nonzero(&mut out, &self.0);
+                $OPS::nonzero(&mut out, &self.0);
                 out.ct_zero()
             }
             fn ct_nonzero(&self) -> Choice {
                 let mut out = 0u64;
-                $fiat_nonzero(&mut out, &self.0);
+                $OPS::nonzero(&mut out, &self.0);
                 out.ct_nonzero()
             }
         }
 
         impl CtEqual<$FE> for $FE {
             fn ct_eq(&self, other: &$FE) -> Choice {
+                debug_assert!(self.is_reduced() && other.is_reduced());
                 let r = self - other;
                 r.ct_zero()
             }
         }
 
+        #[cfg(feature = "subtle")]
+        impl subtle::ConstantTimeEq for $FE {
+            fn ct_eq(&self, other: &$FE) -> subtle::Choice {
+                CtEqual::ct_eq(self, other).into()
+            }
+        }
+
+        impl $crate::mp::ct::CtSelect for $FE {
+            fn ct_select(a: &$FE, b: &$FE, choice: Choice) -> $FE {
+                $FE($crate::mp::ct::CtSelect::ct_select(&a.0, &b.0, choice))
+            }
+        }
+
+        #[cfg(feature = "subtle")]
+        impl subtle::ConditionallySelectable for $FE {
+            fn conditional_select(a: &$FE, b: &$FE, choice: subtle::Choice) -> $FE {
+                $crate::mp::ct::CtSelect::ct_select(a, b, choice.into())
+            }
+        }
+
         impl $FE {
             /// Size in bits of this element of the field
             pub const SIZE_BITS: usize = $SIZE_BITS;
@@ -58,44 +78,70 @@ macro_rules! fiat_field_common_impl {
             /// Size in bytes of this element of the field
             pub const SIZE_BYTES: usize = (Self::SIZE_BITS + 7) / 8;
 
+            /// The constant 0
+            pub const ZERO: Self = Self::from_u64_const(0);
+
+            /// The constant 1
+            pub const ONE: Self = Self::from_u64_const(1);
+
+            /// The constant 2
+            pub const TWO: Self = Self::from_u64_const(2);
+
+            /// The constant 3
+            pub const THREE: Self = Self::from_u64_const(3);
+
             /// the zero constant (additive identity)
             pub fn zero() -> Self {
-                Self::init([0u64; $FE_LIMBS_SIZE])
+                Self::ZERO
             }
 
             pub fn is_zero(&self) -> bool {
                 let mut cond = 0;
-                $fiat_nonzero(&mut cond, &self.0);
+                $OPS::nonzero(&mut cond, &self.0);
                 cond == 0
             }
 
             /// The one constant (multiplicative identity)
             pub fn one() -> Self {
-                let mut limbs = [0u64; $FE_LIMBS_SIZE];
-                limbs[0] = 1;
-                Self::init(limbs)
+                Self::ONE
             }
 
-            pub fn to_string(&self) -> String {
-                let mut s = String::new();
+            /// Write the big endian bytes representation as lowercase hexadecimal
+            ///
+            /// The hexadecimal characters are computed without branches or table lookup,
+            /// so that the time taken doesn't depend on the value, and no allocation is done.
+            pub fn write_hex(&self, out: &mut [u8; 2 * Self::SIZE_BYTES]) {
+                // nibble 0..9 => '0'..'9' and 10..15 => 'a'..'f', where the
+                // ('a' - '0' - 10) offset is masked in when 9 - nibble is negative
+                fn hex_char(nibble: u8) -> u8 {
+                    let n = nibble as i16;
+                    (n + 0x30 + (((9 - n) >> 8) & 0x27)) as u8
+                }
                 let bytes = self.to_bytes();
-                for b in bytes.iter() {
-                    s.push_str(&format!("{:02x}", b));
+                for (o, b) in out.chunks_mut(2).zip(bytes.iter()) {
+                    o[0] = hex_char(b >> 4);
+                    o[1] = hex_char(b & 0xf);
                 }
-                s
             }
 
             /// Return a new element that is the square of this one
             ///
             /// Always true: `self.square() == self * self`
             pub fn square(&self) -> Self {
+                debug_assert!(self.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_square(&mut out, &self.0);
+                $OPS::square(&mut out, &self.0);
                 Self(out)
             }
 
-            /// Repeadtly square
-            fn square_rep(&self, count: usize) -> Self {
+            /// Square `count` times in a row, which raises the element to the power 2^count
+            ///
+            /// This is the building block of the addition chains, and `square_rep(0)`
+            /// is the element itself
+            pub fn square_rep(&self, count: usize) -> Self {
+                if count == 0 {
+                    return self.clone();
+                }
                 let mut x = self.square();
                 for _ in 1..count {
                     x = x.square();
@@ -103,10 +149,41 @@ macro_rules! fiat_field_common_impl {
                 x
             }
 
+            /// Raise to the power 2^k, same as `square_rep(k)`
+            pub fn pow_2k(&self, k: usize) -> Self {
+                self.square_rep(k)
+            }
+
+            /// Raise to a power given as a chain of (squarings, multiplier) steps
+            ///
+            /// The multiplier refers to the odd powers self^(2*multiplier+1), see the
+            /// chains module for the format. The sequence of operations only depends
+            /// on the chain, not on the value.
+            #[cfg(any(test, feature = "small-code"))]
+            fn pow_chain(&self, chain: &[(u16, u8)]) -> Self {
+                let x2 = self.square();
+                let mut odd: [Self; 8] = core::array::from_fn(|_| self.clone());
+                for i in 1..odd.len() {
+                    odd[i] = &odd[i - 1] * &x2;
+                }
+
+                let mut t = Self::one();
+                for (squarings, mul) in chain.iter() {
+                    if *squarings > 0 {
+                        t = t.square_rep(*squarings as usize);
+                    }
+                    if let Some(m) = odd.get(*mul as usize) {
+                        t = t * m;
+                    }
+                }
+                t
+            }
+
             /// Double the field element, this is equivalent to 2*self or self+self, but can be implemented faster
             pub fn double(&self) -> Self {
+                debug_assert!(self.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_add(&mut out, &self.0, &self.0);
+                $OPS::add(&mut out, &self.0, &self.0);
                 $FE(out)
             }
 
@@ -148,11 +225,144 @@ macro_rules! fiat_field_common_impl {
                 q
             }
 
+            /// Invert all the values in place, with a single inversion (Montgomery's trick)
+            ///
+            /// The returned Choice is true when all the values were inverted. When any value
+            /// is zero, it's false and the values are left untouched. The operations done
+            /// only depend on the number of values, not on which of them are zero.
+            pub fn batch_inverse(values: &mut [Self]) -> Choice {
+                let mut scratch = vec![Self::one(); values.len()];
+                Self::batch_inverse_into(values, &mut scratch)
+            }
+
+            /// Same as `batch_inverse`, on arrays, so that nothing is allocated
+            pub fn batch_inverse_array<const N: usize>(values: &mut [Self; N]) -> Choice {
+                let mut scratch: [Self; N] = core::array::from_fn(|_| Self::one());
+                Self::batch_inverse_into(values, &mut scratch)
+            }
+
+            /// Same as `batch_inverse`, using `scratch` for the intermediate products
+            ///
+            /// Panics if `scratch` is not of the same length as the values
+            pub fn batch_inverse_into(values: &mut [Self], scratch: &mut [Self]) -> Choice {
+                use $crate::mp::ct::{CtSelect, CtZero};
+
+                assert_eq!(
+                    values.len(),
+                    scratch.len(),
+                    "batch_inverse: scratch length mismatch"
+                );
+
+                // scratch[i] is the product of the values up to i
+                let mut acc = Self::one();
+                for (v, product) in values.iter().zip(scratch.iter_mut()) {
+                    acc = &acc * v;
+                    *product = acc.clone();
+                }
+
+                // a zero product is replaced by one, which is invertible, and the results discarded
+                let ok = acc.ct_nonzero();
+                let mut inv = Self::ct_select(&Self::one(), &acc, ok).inverse();
+                for i in (0..values.len()).rev() {
+                    let vinv = if i == 0 {
+                        inv.clone()
+                    } else {
+                        &inv * &scratch[i - 1]
+                    };
+                    inv = &inv * &values[i];
+                    values[i] = Self::ct_select(&values[i], &vinv, ok);
+                }
+                ok
+            }
+
+            /// Compute the square root with a method chosen from the modulus p
+            ///
+            /// This is the same for every field: (p+1)/4 when p = 3 mod 4, Atkin's
+            /// method when p = 5 mod 8, and a constant time Tonelli-Shanks otherwise.
+            /// It is slower than the tuned `sqrt` of the known curves, and is used for
+            /// new curves through `fiat_field_sqrt_define!(FieldElement, generic)`
+            pub fn sqrt_generic(&self) -> $crate::mp::ct::CtOption<Self> {
+                use $crate::curve::fiat::sqrt::{SqrtMethod, SqrtParams};
+                use $crate::mp::ct::{CtEqual, CtSelect};
+
+                // the method and exponents, and for Tonelli-Shanks, c^q for the first non residue c
+                static PARAMS: std::sync::OnceLock<(SqrtParams, $FE)> = std::sync::OnceLock::new();
+                let (params, c5) = PARAMS.get_or_init(|| {
+                    let params = SqrtParams::new(&(-$FE::one()).to_bytes());
+                    let c = match params.method {
+                        SqrtMethod::TonelliShanks => {
+                            let minus_one = -$FE::one();
+                            let mut i = 2;
+                            loop {
+                                let c = $FE::from_u64(i);
+                                if c.power(&params.euler) == minus_one {
+                                    break c.power(&params.q);
+                                }
+                                i += 1;
+                            }
+                        }
+                        _ => $FE::one(),
+                    };
+                    (params, c)
+                });
+
+                let r = match params.method {
+                    SqrtMethod::Mod4 => self.power(&params.exponent),
+                    SqrtMethod::Mod8 => {
+                        let gamma = self.double().power(&params.exponent);
+                        let ggamma = self * &gamma;
+                        let i = (&ggamma * &gamma).double();
+                        ggamma * (i - Self::one())
+                    }
+                    SqrtMethod::TonelliShanks => {
+                        let mut z = self.power(&params.exponent);
+                        let mut t = &z.square() * self;
+                        z = &z * self;
+                        let mut b = t.clone();
+                        let mut c = c5.clone();
+                        for i in (2..=params.s).rev() {
+                            for _ in 2..i {
+                                b = b.square();
+                            }
+                            let e = b.ct_eq(&Self::one());
+                            z = Self::ct_select(&(&z * &c), &z, e);
+                            c = c.square();
+                            t = Self::ct_select(&(&t * &c), &t, e);
+                            b = t.clone();
+                        }
+                        z
+                    }
+                };
+                $crate::mp::ct::CtOption::from((r.square().ct_eq(self), r))
+            }
+
+            /// Initialize from the bytes representation (BE) of a value known to be canonical
+            ///
+            /// The value is trusted to be less than the modulus, which is only checked
+            /// by a debug assertion. In release builds, a greater value is reduced modulo p,
+            /// the same as `from_bytes_reduce`, which is the one to use when that's expected.
+            pub fn from_bytes_unchecked(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
+                debug_assert!(
+                    Self::is_canonical_bytes(bytes),
+                    "from_bytes_unchecked: value not less than the modulus"
+                );
+                Self::from_bytes_reduce(bytes)
+            }
+
             /// Similar to 'from_bytes' but take values from a slice.
             ///
+            /// The slice is expected to be exactly `SIZE_BYTES` long, and note that
+            /// the field element and scalar of a same curve doesn't necessarily have
+            /// the same size (e.g. p224k1 field elements are 28 bytes, and scalars 29 bytes).
+            ///
             /// If the slice is not of the right size, then None is returned
             pub fn from_slice(slice: &[u8]) -> Option<Self> {
                 if slice.len() != Self::SIZE_BYTES {
+                    trace_event!($crate::trace::TraceEvent::FromBytesRejected {
+                        module: module_path!(),
+                        type_name: stringify!($FE),
+                        reason: "invalid length",
+                    });
                     return None;
                 }
                 let mut buf = [0u8; Self::SIZE_BYTES];
@@ -160,34 +370,163 @@ macro_rules! fiat_field_common_impl {
                 Self::from_bytes(&buf)
             }
 
+            /// Same as `is_canonical_bytes` for a slice, which is false for a size mismatch
+            pub fn is_canonical_slice(slice: &[u8]) -> bool {
+                if slice.len() != Self::SIZE_BYTES {
+                    return false;
+                }
+                let mut buf = [0u8; Self::SIZE_BYTES];
+                buf.copy_from_slice(slice);
+                Self::is_canonical_bytes(&buf)
+            }
+
             /// Output the scalar bytes representation to the mutable slice
             ///
-            /// the slice needs to be of the correct size
+            /// the slice needs to be of the correct size, otherwise this function panics.
+            /// Use `write_to_slice` to handle the size mismatch as an error
             pub fn to_slice(&self, slice: &mut [u8]) {
-                assert_eq!(slice.len(), Self::SIZE_BYTES);
+                if let Err(e) = self.write_to_slice(slice) {
+                    panic!(
+                        "to_slice: slice of {} bytes, expecting {} bytes",
+                        e.got, e.expected
+                    )
+                }
+            }
+
+            /// Output the scalar bytes representation to the mutable slice, returning
+            /// the number of bytes written
+            ///
+            /// the slice needs to be exactly SIZE_BYTES long, shorter and longer slices
+            /// are both rejected with an error and the slice is left untouched.
+            pub fn write_to_slice(
+                &self,
+                slice: &mut [u8],
+            ) -> Result<usize, $crate::curve::field::InvalidLength> {
+                if slice.len() != Self::SIZE_BYTES {
+                    return Err($crate::curve::field::InvalidLength {
+                        expected: Self::SIZE_BYTES,
+                        got: slice.len(),
+                    });
+                }
 
                 // TODO don't create temporary buffer
                 let bytes = self.to_bytes();
                 slice.copy_from_slice(&bytes[..]);
+                Ok(Self::SIZE_BYTES)
             }
 
-            // Initialize from a wide buffer of random data.
-            //
-            // The difference with 'from_bytes' or 'from_slice' is that it takes
-            // a random initialized buffer and used modulo operation to initialize
-            // as a field element, but due to inherent bias in modulo operation
-            // we take a double sized buffer.
-            //pub fn init_from_wide_bytes(_random: [u8; Self::SIZE_BYTES * 2]) -> Self {
-            //    todo!()
-            //}
+            /// Return the value of the element as a u64, or None if it doesn't fit
+            pub fn to_u64(&self) -> Option<u64> {
+                let bytes = self.to_bytes();
+                let (high, low) = bytes.split_at(Self::SIZE_BYTES - 8);
+                let mut acc = 0u64;
+                for b in high.iter() {
+                    acc |= *b as u64
+                }
+                let mut v = [0u8; 8];
+                v.copy_from_slice(low);
+                CtOption::from((acc.ct_zero(), u64::from_be_bytes(v))).into_option()
+            }
+
+            /// Return the value of the element as a u128, or None if it doesn't fit
+            pub fn to_u128(&self) -> Option<u128> {
+                let bytes = self.to_bytes();
+                let (high, low) = bytes.split_at(Self::SIZE_BYTES - 16);
+                let mut acc = 0u64;
+                for b in high.iter() {
+                    acc |= *b as u64
+                }
+                let mut v = [0u8; 16];
+                v.copy_from_slice(low);
+                CtOption::from((acc.ct_zero(), u128::from_be_bytes(v))).into_option()
+            }
+
+            /// Number of u64 limbs needed to hold the canonical value of an element
+            pub const LIMBS_U64: usize = Self::SIZE_BITS.div_ceil(64);
+
+            /// Return the canonical (non Montgomery) value of the element as u64 limbs,
+            /// least significant limb first
+            ///
+            /// This is the same value as `to_bytes`: the big endian bytes of the
+            /// value are the concatenation of the big endian bytes of the limbs
+            /// in reverse order, without the leading padding zeros.
+            pub fn to_limbs_u64(&self) -> [u64; Self::LIMBS_U64] {
+                let bytes = self.to_bytes();
+                let mut limbs = [0u64; Self::LIMBS_U64];
+                for (i, b) in bytes.iter().rev().enumerate() {
+                    limbs[i / 8] |= (*b as u64) << (8 * (i % 8));
+                }
+                limbs
+            }
+
+            /// Generate a uniformly distributed random element
+            ///
+            /// The excess bits of the leading byte are cleared, and any value
+            /// greater or equal to the order of the field is rejected and
+            /// a new value is drawn from the random generator.
+            #[cfg(feature = "rand_core")]
+            pub fn random<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> Self {
+                let mut bytes = [0u8; Self::SIZE_BYTES];
+                loop {
+                    rng.fill_bytes(&mut bytes);
+                    bytes[0] &= 0xff >> (Self::SIZE_BYTES * 8 - Self::SIZE_BITS);
+                    if let Some(e) = Self::from_bytes(&bytes) {
+                        return e;
+                    }
+                }
+            }
+
+            /// Initialize from big endian bytes of any length, reduced modulo the order of the field
+            ///
+            /// The difference with 'from_bytes' or 'from_slice' is that the value isn't
+            /// rejected when greater than the order, which is what `hash_to_field` of
+            /// RFC 9380 needs for its `L` bytes strings (e.g. 48 bytes for p256r1).
+            ///
+            /// For uniformly random bytes of twice the size of the modulus, the bias of the
+            /// result is negligible (about 2^-SIZE_BITS), e.g. for the random scalars.
+            ///
+            /// The value is reduced by chunks of the size of the modulus, with the Barrett
+            /// reduction of `mp::barrett`, so the time taken only depends on the length of
+            /// the input. The empty input is zero.
+            pub fn from_be_bytes_mod_p(bytes: &[u8]) -> Self {
+                // number of 64 bits limbs of the modulus
+                const N: usize = $FIELD_P_LIMBS.len();
+
+                // the first chunk is the zero padded remainder of the division in chunks
+                let (head, tail) = bytes.split_at(bytes.len() % (8 * N));
+                let mut first = [0u8; 8 * N];
+                first[8 * N - head.len()..].copy_from_slice(head);
+
+                // each wide value is the previous reduced value followed by the next chunk
+                let mut r = [0u64; N];
+                for chunk in core::iter::once(&first[..]).chain(tail.chunks(8 * N)) {
+                    let mut wide = [0u64; 2 * N];
+                    wide[..N].copy_from_slice(&r);
+                    for (l, b) in wide[N..].iter_mut().zip(chunk.chunks(8)) {
+                        let mut limb = [0u8; 8];
+                        limb.copy_from_slice(b);
+                        *l = u64::from_be_bytes(limb);
+                    }
+                    r = $crate::mp::barrett::reduce_wide(&wide, &$FIELD_P_LIMBS, &$FIELD_MICRO_LIMBS);
+                }
+
+                let mut out = [0u8; 8 * N];
+                for (b, l) in out.chunks_mut(8).zip(r.iter()) {
+                    b.copy_from_slice(&l.to_be_bytes());
+                }
+                let mut bytes = [0u8; Self::SIZE_BYTES];
+                bytes.copy_from_slice(&out[8 * N - Self::SIZE_BYTES..]);
+                Self::from_bytes_unchecked(&bytes)
+            }
         }
 
         impl std::ops::Neg for $FE {
             type Output = $FE;
 
             fn neg(self) -> Self::Output {
+                debug_assert!(self.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_opp(&mut out, &self.0);
+                $OPS::opp(&mut out, &self.0);
                 $FE(out)
             }
         }
@@ -196,8 +535,9 @@ macro_rules! fiat_field_common_impl {
             type Output = $FE;
 
             fn neg(self) -> Self::Output {
+                debug_assert!(self.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_opp(&mut out, &self.0);
+                $OPS::opp(&mut out, &self.0);
                 $FE(out)
             }
         }
@@ -210,8 +550,9 @@ macro_rules! fiat_field_common_impl {
             type Output = $FE;
 
             fn add(self, other: &'b $FE) -> $FE {
+                debug_assert!(self.is_reduced() && other.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_add(&mut out, &self.0, &other.0);
+                $OPS::add(&mut out, &self.0, &other.0);
                 $FE(out)
             }
         }
@@ -248,8 +589,9 @@ macro_rules! fiat_field_common_impl {
             type Output = $FE;
 
             fn sub(self, other: &'b $FE) -> $FE {
+                debug_assert!(self.is_reduced() && other.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_sub(&mut out, &self.0, &other.0);
+                $OPS::sub(&mut out, &self.0, &other.0);
                 $FE(out)
             }
         }
@@ -286,8 +628,9 @@ macro_rules! fiat_field_common_impl {
             type Output = $FE;
 
             fn mul(self, other: &'b $FE) -> $FE {
+                debug_assert!(self.is_reduced() && other.is_reduced());
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_mul(&mut out, &self.0, &other.0);
+                $OPS::mul(&mut out, &self.0, &other.0);
                 $FE(out)
             }
         }
@@ -322,6 +665,54 @@ macro_rules! fiat_field_common_impl {
             }
         }
 
+        impl std::convert::TryFrom<&$FE> for u64 {
+            type Error = $crate::curve::field::IntegerOverflow;
+
+            fn try_from(v: &$FE) -> Result<u64, Self::Error> {
+                v.to_u64().ok_or($crate::curve::field::IntegerOverflow)
+            }
+        }
+
+        impl std::convert::TryFrom<&$FE> for u128 {
+            type Error = $crate::curve::field::IntegerOverflow;
+
+            fn try_from(v: &$FE) -> Result<u128, Self::Error> {
+                v.to_u128().ok_or($crate::curve::field::IntegerOverflow)
+            }
+        }
+
+        #[cfg(feature = "num-bigint-interop")]
+        impl From<&$FE> for num_bigint::BigUint {
+            fn from(v: &$FE) -> num_bigint::BigUint {
+                num_bigint::BigUint::from_bytes_be(&v.to_bytes())
+            }
+        }
+
+        #[cfg(feature = "num-bigint-interop")]
+        impl std::convert::TryFrom<&num_bigint::BigUint> for $FE {
+            type Error = $crate::curve::field::NotReduced;
+
+            fn try_from(v: &num_bigint::BigUint) -> Result<$FE, Self::Error> {
+                let bytes = v.to_bytes_be();
+                if bytes.len() > $FE::SIZE_BYTES {
+                    return Err($crate::curve::field::NotReduced);
+                }
+                let mut buf = [0u8; $FE::SIZE_BYTES];
+                buf[$FE::SIZE_BYTES - bytes.len()..].copy_from_slice(&bytes);
+                $FE::from_bytes(&buf).ok_or($crate::curve::field::NotReduced)
+            }
+        }
+
+        #[cfg(feature = "num-bigint-interop")]
+        impl $FE {
+            /// Initialize from a big integer of any size, reduced modulo the order of the field
+            ///
+            /// Use `TryFrom` instead to reject the integers that are not reduced
+            pub fn from_biguint_reduced(v: &num_bigint::BigUint) -> Self {
+                Self::from_be_bytes_mod_p(&v.to_bytes_be())
+            }
+        }
+
         impl Field for $FE {
             fn zero() -> $FE {
                 $FE::zero()
@@ -332,9 +723,6 @@ macro_rules! fiat_field_common_impl {
             fn one() -> $FE {
                 $FE::one()
             }
-            fn sign(&self) -> Sign {
-                self.sign()
-            }
             fn double(&self) -> $FE {
                 self.double()
             }
@@ -348,30 +736,48 @@ macro_rules! fiat_field_common_impl {
                 self.square() * self
             }
         }
+
+        impl $crate::curve::field::PrimeField for $FE {
+            const SIZE_BITS: usize = $FE::SIZE_BITS;
+            const SIZE_BYTES: usize = $FE::SIZE_BYTES;
+
+            fn from_u64(v: u64) -> $FE {
+                $FE::from_u64(v)
+            }
+            fn sign(&self) -> Sign {
+                self.sign()
+            }
+            fn from_slice(slice: &[u8]) -> Option<$FE> {
+                $FE::from_slice(slice)
+            }
+            fn write_to_slice(
+                &self,
+                slice: &mut [u8],
+            ) -> Result<usize, $crate::curve::field::InvalidLength> {
+                self.write_to_slice(slice)
+            }
+        }
     };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! fiat_field_ops_impl {
-    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FIELD_P_LIMBS:expr, $FE_LIMBS_SIZE:expr, $fiat_nonzero:ident, $fiat_add:ident, $fiat_sub:ident, $fiat_mul:ident, $fiat_square:ident, $fiat_opp:ident, $fiat_to_bytes:ident, $fiat_from_bytes:ident, montgomery { $fiat_to_montgomery:ident, $fiat_from_montgomery:ident }) => {
+    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FIELD_P_LIMBS:expr, $FIELD_MICRO_LIMBS:expr, $FE_LIMBS_SIZE:expr, $OPS:ident, montgomery) => {
         crate::fiat_field_common_impl!(
             $(#[$outer])*
             $FE,
             $SIZE_BITS,
+            $FIELD_P_LIMBS,
+            $FIELD_MICRO_LIMBS,
             $FE_LIMBS_SIZE,
-            $fiat_add,
-            $fiat_sub,
-            $fiat_mul,
-            $fiat_square,
-            $fiat_opp,
-            $fiat_nonzero
+            $OPS
         );
 
         impl $FE {
             fn init(current: [u64; $FE_LIMBS_SIZE]) -> Self {
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_to_montgomery(&mut out, &current);
+                $OPS::to_montgomery(&mut out, &current);
                 Self(out)
             }
 
@@ -381,11 +787,57 @@ macro_rules! fiat_field_ops_impl {
                 Self::init(limbs)
             }
 
+            /// Same as `from_u64`, usable in constants
+            ///
+            /// The montgomery form n * 2^(64*limbs) mod p is computed by doubling n
+            /// modulo p, which is a lot slower than `from_u64` at runtime, e.g.
+            /// `const EIGHT: Scalar = Scalar::from_u64_const(8);`
+            pub const fn from_u64_const(n: u64) -> Self {
+                // little endian limbs of p
+                let mut p = [0u64; $FE_LIMBS_SIZE];
+                let mut i = 0;
+                while i < $FIELD_P_LIMBS.len() {
+                    p[i] = $FIELD_P_LIMBS[$FIELD_P_LIMBS.len() - 1 - i];
+                    i += 1;
+                }
+                let mut limbs = [0u64; $FE_LIMBS_SIZE];
+                limbs[0] = n;
+                Self($crate::curve::fiat::montgomery_64::shl_mod(
+                    &p,
+                    limbs,
+                    64 * $FE_LIMBS_SIZE,
+                ))
+            }
+
+
+            /// Check the invariant of the representation: the Montgomery limbs are less than
+            /// the modulus
+            ///
+            /// The zero test (`nonzero` on the limbs), and so the equality, are only right
+            /// for such values. All the constructors and operations keep it, which the
+            /// arithmetic checks on its operands with debug assertions.
+            pub(crate) fn is_reduced(&self) -> bool {
+                // the limbs of the modulus are big endian, and the element ones little endian
+                let p = $FIELD_P_LIMBS;
+                for i in (0..$FE_LIMBS_SIZE).rev() {
+                    let pi = if i < p.len() { p[p.len() - 1 - i] } else { 0 };
+                    if self.0[i] != pi {
+                        return self.0[i] < pi;
+                    }
+                }
+                false
+            }
+
+            // canonical (non Montgomery) value, least significant limb first
+            fn canonical_limbs(&self) -> [u64; $FE_LIMBS_SIZE] {
+                let mut out = [0u64; $FE_LIMBS_SIZE];
+                $OPS::from_montgomery(&mut out, &self.0);
+                out
+            }
 
             /// Get the sign of the field element
             pub fn sign(&self) -> Sign {
-                let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_montgomery(&mut out, &self.0);
+                let out = self.canonical_limbs();
                 if out[0] & 1 == 1 {
                     Sign::Negative
                 } else {
@@ -395,28 +847,50 @@ macro_rules! fiat_field_ops_impl {
 
             // there's no really negative number in Fp, but if high bit is set ...
             pub fn is_negative(&self) -> bool {
-                let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_montgomery(&mut out, &self.0);
-                (out[0] & 1) != 0
+                (self.canonical_limbs()[0] & 1) != 0
             }
 
-            /// Initialize a new scalar from its bytes representation (BE)
+            /// Initialize from the bytes representation (BE) of any value, reduced modulo p
             ///
-            /// This doesn't verify if the element represented fits in the field,
-            /// so that run the run of having elements that are greater or equal
-            /// than the order of the field
-            pub fn from_bytes_unchecked(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
+            /// Unlike `from_bytes`, the values greater or equal than the modulus are
+            /// accepted, e.g. the bytes of p give zero, and the bytes of p+1 give one.
+            /// The conversion to the montgomery domain reduces any value that fits in
+            /// the limbs, in constant time.
+            pub fn from_bytes_reduce(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
                 let mut buf = [0u8; Self::SIZE_BYTES];
                 buf.copy_from_slice(bytes);
                 buf.reverse(); // swap endianness
 
                 let mut out = [0u64; $FE_LIMBS_SIZE];
                 let mut out_mont = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_bytes(&mut out, &buf);
-                $fiat_to_montgomery(&mut out_mont, &out);
+                $OPS::from_bytes(&mut out, &buf);
+                $OPS::to_montgomery(&mut out_mont, &out);
                 $FE(out_mont)
             }
 
+            /// Check if the bytes representation (BE) is canonical, i.e. less than the modulus
+            ///
+            /// This is the constant time check done by `from_bytes`, without the
+            /// conversion to the montgomery domain nor constructing an element.
+            pub fn is_canonical_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> bool {
+                use crate::mp::ct::CtLesser;
+                use crate::mp::limbs::LimbsLE;
+
+                let mut buf = [0u8; Self::SIZE_BYTES];
+                buf.copy_from_slice(bytes);
+                buf.reverse(); // swap endianness
+
+                let mut out = [0u64; $FE_LIMBS_SIZE];
+                $OPS::from_bytes(&mut out, &buf);
+
+                // little endian limbs of p
+                let mut p = [0u64; $FE_LIMBS_SIZE];
+                for (i, limb) in $FIELD_P_LIMBS.iter().rev().enumerate() {
+                    p[i] = *limb;
+                }
+                LimbsLE::ct_lt(LimbsLE(&out), LimbsLE(&p)).is_true()
+            }
+
             /// Initialize a new scalar from its bytes representation (BE)
             ///
             /// If the represented value overflow the field element size,
@@ -431,58 +905,60 @@ macro_rules! fiat_field_ops_impl {
 
                 let mut out = [0u64; $FE_LIMBS_SIZE];
                 let mut out_mont = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_bytes(&mut out, &buf);
+                $OPS::from_bytes(&mut out, &buf);
 
                 let p = $FIELD_P_LIMBS.iter().rev().copied().collect::<Vec<_>>();
 
                 // TODO: non constant
                 if LimbsLE::ct_lt(LimbsLE(&out), LimbsLE(&p[..])).is_true() {
-                    $fiat_to_montgomery(&mut out_mont, &out);
+                    $OPS::to_montgomery(&mut out_mont, &out);
                     Some($FE(out_mont))
                 } else {
+                    trace_event!($crate::trace::TraceEvent::FromBytesRejected {
+                        module: module_path!(),
+                        type_name: stringify!($FE),
+                        reason: "value not less than the modulus",
+                    });
                     None
                 }
             }
 
             /// Output the scalar bytes representation (BE)
             pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
-                let mut out_normal = [0u64; $FE_LIMBS_SIZE];
                 let mut out = [0u8; Self::SIZE_BYTES];
-                $fiat_from_montgomery(&mut out_normal, &self.0);
-                $fiat_to_bytes(&mut out, &out_normal);
+                $OPS::to_bytes(&mut out, &self.canonical_limbs());
                 out.reverse(); // swap endianness
                 out
             }
         }
     };
-    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FIELD_P_BYTES:expr, $FE_LIMBS_SIZE:expr, $fiat_nonzero:ident, $fiat_add:ident, $fiat_sub:ident, $fiat_mul:ident, $fiat_square:ident, $fiat_opp:ident, $fiat_to_bytes:ident, $fiat_from_bytes:ident, solinas) => {
+    ($(#[$outer:meta])* $FE:ident, $SIZE_BITS:expr, $FIELD_P_BYTES:expr, $FIELD_P_LIMBS:expr, $FIELD_MICRO_LIMBS:expr, $FE_LIMBS_SIZE:expr, $OPS:ident, solinas) => {
         crate::fiat_field_common_impl!(
             $FE,
             $SIZE_BITS,
+            $FIELD_P_LIMBS,
+            $FIELD_MICRO_LIMBS,
             $FE_LIMBS_SIZE,
-            $fiat_add,
-            $fiat_sub,
-            $fiat_mul,
-            $fiat_square,
-            $fiat_opp,
-            $fiat_nonzero
+            $OPS
         );
 
         impl $FE {
-            // build from a little endian limbs size
-            //
-            // probably should be removed from unsaturated solinas strategy, as this easy
-            // to introduce serious bugs..
-            fn init(current: [u64; $FE_LIMBS_SIZE]) -> Self {
-                Self(current)
+            pub fn from_u64(n: u64) -> Self {
+                // unsatured solinas run the risk of overflow, so split the u64 over
+                // the limbs, no risk of running into the P limit with a u64
+                Self::from_u64_const(n)
             }
 
-            pub fn from_u64(n: u64) -> Self {
-                // unsatured solinas run the risk of overflow, so use from_bytes
-                // no risk of running into the P limit with a u64
-                let mut bytes = [0u8; Self::SIZE_BYTES];
-                bytes[Self::SIZE_BYTES-8..].copy_from_slice(&n.to_be_bytes());
-                Self::from_bytes_unchecked(&bytes)
+            /// Same as `from_u64`, usable in constants
+            ///
+            /// The u64 is split over the first two (unsaturated) limbs, which can't
+            /// overflow, e.g. `const EIGHT: FieldElement = FieldElement::from_u64_const(8);`
+            pub const fn from_u64_const(n: u64) -> Self {
+                const LIMB_BITS: usize = ($SIZE_BITS + $FE_LIMBS_SIZE - 1) / $FE_LIMBS_SIZE;
+                let mut limbs = [0u64; $FE_LIMBS_SIZE];
+                limbs[0] = n & ((1 << LIMB_BITS) - 1);
+                limbs[1] = n >> LIMB_BITS;
+                Self(limbs)
             }
 
             /// Get the sign of the field element
@@ -501,19 +977,48 @@ macro_rules! fiat_field_ops_impl {
                 (out[0] & 1) != 0
             }
 
-            /// Initialize a new scalar from its bytes representation (BE)
+            /// Initialize from the bytes representation (BE) of any value, reduced modulo p
             ///
-            /// This doesn't verify if the element represented fits in the field,
-            /// so that run the run of having elements that are greater or equal
-            /// than the order of the field
-            pub fn from_bytes_unchecked(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
-                let mut buf = [0u8; Self::SIZE_BYTES];
-                buf.copy_from_slice(bytes);
-                buf.reverse(); // swap endianness
+            /// Unlike `from_bytes`, the values greater or equal than the modulus are
+            /// accepted, e.g. the bytes of p give zero, and the bytes of p+1 give one.
+            ///
+            /// The unsaturated limbs only hold `SIZE_BITS` bits, so the bits above, h,
+            /// are added back as h * (2^SIZE_BITS - p), in constant time.
+            pub fn from_bytes_reduce(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
+                const TOP_BITS: usize = $SIZE_BITS - 8 * ($FE::SIZE_BYTES - 1);
+                const TOP_MASK: u8 = (((1u16 << TOP_BITS) - 1) & 0xff) as u8;
+
+                // 2^SIZE_BITS - p, which is the two's complement of p on SIZE_BITS bits
+                let mut c = [0u8; Self::SIZE_BYTES];
+                let mut carry = 1u16;
+                for i in (0..Self::SIZE_BYTES).rev() {
+                    let v = (!$FIELD_P_BYTES[i]) as u16 + carry;
+                    c[i] = v as u8;
+                    carry = v >> 8;
+                }
+                c[0] &= TOP_MASK;
+
+                let mut low = *bytes;
+                low[0] &= TOP_MASK;
+                let high = (bytes[0] & !TOP_MASK) as u64 >> (TOP_BITS % 8);
+
+                let from_limbs = |bytes: &[u8; Self::SIZE_BYTES]| {
+                    let mut buf = *bytes;
+                    buf.reverse(); // swap endianness
+                    let mut out = [0u64; $FE_LIMBS_SIZE];
+                    $OPS::from_bytes(&mut out, &buf);
+                    $FE(out)
+                };
+                from_limbs(&low) + Self::from_u64_const(high) * from_limbs(&c)
+            }
 
-                let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_bytes(&mut out, &buf);
-                $FE(out)
+            /// Check if the bytes representation (BE) is canonical, i.e. less than the modulus
+            ///
+            /// This is the constant time check done by `from_bytes`, without
+            /// constructing an element.
+            pub fn is_canonical_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> bool {
+                use crate::mp::ct::CtLesser;
+                <&[u8; Self::SIZE_BYTES]>::ct_lt(bytes, &$FIELD_P_BYTES).is_true()
             }
 
             /// Initialize a new scalar from its bytes representation (BE)
@@ -528,20 +1033,34 @@ macro_rules! fiat_field_ops_impl {
                 buf.reverse(); // swap endianness
 
                 let mut out = [0u64; $FE_LIMBS_SIZE];
-                $fiat_from_bytes(&mut out, &buf);
+                $OPS::from_bytes(&mut out, &buf);
 
                 // TODO: non constant
                 if <&[u8; Self::SIZE_BYTES]>::ct_lt(bytes, &$FIELD_P_BYTES).is_true() {
                     Some($FE(out))
                 } else {
+                    trace_event!($crate::trace::TraceEvent::FromBytesRejected {
+                        module: module_path!(),
+                        type_name: stringify!($FE),
+                        reason: "value not less than the modulus",
+                    });
                     None
                 }
             }
 
+            /// Check the invariant of the representation, always true here
+            ///
+            /// The unsaturated limbs are not canonical by design, and the zero test (so
+            /// the equality) goes through the canonical bytes, so any limbs output by the
+            /// field operations are valid.
+            pub(crate) fn is_reduced(&self) -> bool {
+                true
+            }
+
             /// Output the scalar bytes representation (BE)
             pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
                 let mut out = [0u8; Self::SIZE_BYTES];
-                $fiat_to_bytes(&mut out, &self.0);
+                $OPS::to_bytes(&mut out, &self.0);
                 out.reverse(); // swap endianness
                 out
             }
@@ -549,6 +1068,40 @@ macro_rules! fiat_field_ops_impl {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_scalar_kdf_define {
+    ($curve:literal) => {
+        impl Scalar {
+            /// Derive a scalar with HKDF-SHA256 (RFC 5869), see `derive_from_kdf`
+            ///
+            #[doc = concat!(
+                "The HKDF info is `\"eccoxide-scalar-v1/",
+                $curve,
+                "/\" || info`, so that the same inputs give unrelated scalars on different curves"
+            )]
+            pub fn derive_hkdf_sha256(ikm: &[u8], salt: &[u8], info: &[u8]) -> Self {
+                const PREFIX: &[u8] = concat!("eccoxide-scalar-v1/", $curve, "/").as_bytes();
+                let hkdf = hkdf::Hkdf::<sha2::Sha256>::new(Some(salt), ikm);
+                Self::derive_from_kdf(|okm| {
+                    hkdf.expand_multi_info(&[PREFIX, info], okm)
+                        .expect("valid HKDF output length")
+                })
+            }
+
+            /// Derive a scalar with HKDF-SHA256 from the X coordinate bytes, e.g. an ECDH
+            /// shared secret, same as `derive_hkdf_sha256` with the bytes as the input keying material
+            pub fn derive_hkdf_sha256_from_x(
+                shared: &XCoordinateBytes,
+                salt: &[u8],
+                info: &[u8],
+            ) -> Self {
+                Self::derive_hkdf_sha256(shared.as_bytes(), salt, info)
+            }
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! fiat_field_sqrt_define {
@@ -559,6 +1112,560 @@ macro_rules! fiat_field_sqrt_define {
             }
         }
     };
+    // for a new curve without a tuned square root
+    ($FE:ident, generic) => {
+        impl $FE {
+            /// Compute the square root, see `sqrt_generic`
+            pub fn sqrt(&self) -> CtOption<Self> {
+                self.sqrt_generic()
+            }
+        }
+        $crate::fiat_field_sqrt_define!($FE);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_field_legendre_define {
+    ($FE:ident) => {
+        impl $FE {
+            /// Check if the element is a quadratic residue (a non zero square)
+            ///
+            /// Zero is not a quadratic residue. The symbol comes from the constant
+            /// time square root: x is a non zero square exactly when sqrt(x)^2 = x
+            pub fn is_quadratic_residue(&self) -> Choice {
+                self.sqrt().is_some() & self.ct_nonzero()
+            }
+        }
+        $crate::fiat_field_legendre_define!($FE, tuned);
+    };
+    // for the fields with their own `is_quadratic_residue`
+    ($FE:ident, tuned) => {
+        impl $crate::curve::field::FieldLegendre for $FE {
+            fn is_quadratic_residue(&self) -> Choice {
+                self.is_quadratic_residue()
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_scalar_define {
+    ($SCALAR:ident, $CURVE:ident) => {
+        /// Bytes representation of a scalar, tagged with the curve it belongs to
+        pub type TaggedScalarBytes =
+            $crate::curve::tagged::TaggedBytes<$CURVE, { $SCALAR::SIZE_BYTES }>;
+
+        // (order - 1) / 2, computed on first use
+        fn scalar_half_max() -> &'static $SCALAR {
+            static VALUE: std::sync::OnceLock<$SCALAR> = std::sync::OnceLock::new();
+            VALUE.get_or_init(|| -$SCALAR::from_u64(2).inverse())
+        }
+
+        impl $SCALAR {
+            /// The largest scalar, the group order minus one
+            pub fn max() -> Self {
+                -Self::one()
+            }
+
+            /// Half of the largest scalar, `(order - 1) / 2`
+            ///
+            /// Scalars up to this value are the lower half of the non zero scalars
+            pub fn half_max() -> Self {
+                scalar_half_max().clone()
+            }
+
+            /// Joint sparse form of the pair of scalars, least significant column first
+            ///
+            /// This is variable time, see `jacobian::jsf` for the digits
+            pub fn jsf(a: &Self, b: &Self) -> Vec<(i8, i8)> {
+                $crate::curve::jacobian::jsf(&a.to_bytes(), &b.to_bytes())
+            }
+
+            /// Signed digits of the scalar in radix 2^w, for the constant time fixed window
+            /// multiplications
+            ///
+            /// There are exactly ⌈SIZE_BITS/w⌉ digits in [-2^(w-1), 2^(w-1)[ and a final
+            /// carry, whatever the value of the scalar, see `projective::recode_signed_fixed`.
+            /// Panics if the width is not between 2 and 8.
+            pub fn recode_signed_fixed(
+                &self,
+                w: usize,
+            ) -> $crate::curve::projective::RecodedScalar {
+                $crate::curve::projective::recode_signed_fixed(&self.to_bytes(), Self::SIZE_BITS, w)
+            }
+
+            /// Bits of the scalar, most significant first, for the scalar multiplications
+            ///
+            /// The canonical limbs are computed once out of the Montgomery domain, into the
+            /// iterator, without going through the bytes of `to_bytes`. There are always
+            /// `SIZE_BITS` bits, whatever the value.
+            pub(crate) fn iter_bits_msb_first(
+                &self,
+            ) -> impl DoubleEndedIterator<Item = $crate::Choice> + ExactSizeIterator {
+                $crate::mp::limbs::BitsMsbFirst::new(self.canonical_limbs(), Self::SIZE_BITS)
+            }
+
+            /// Scalar of 32 bytes clamped with the policy (e.g. an exported X25519 secret),
+            /// reduced modulo the group order
+            ///
+            /// Unlike `from_bytes`, which rejects the values not less than the order, any
+            /// bytes give a scalar, always the same one (zero included, e.g. from zero bytes
+            /// without clamping). See the `curve::clamp` module for the information lost.
+            pub fn from_bytes_clamped(
+                bytes: &[u8; 32],
+                clamp: $crate::curve::clamp::ClampPolicy,
+            ) -> Self {
+                Self::from_be_bytes_mod_p(&clamp.clamp(bytes))
+            }
+
+            /// Derive a scalar from the output of a key derivation function
+            ///
+            /// `fill` is called once, with a buffer of `2 * SIZE_BYTES` bytes to fill with the
+            /// KDF output, which is reduced modulo the group order. With twice the size of the
+            /// order, the bias of the reduction is negligible, unlike hashing and truncating
+            /// to the scalar size, which can also give values not less than the order.
+            pub fn derive_from_kdf<K: FnMut(&mut [u8])>(mut fill: K) -> Self {
+                let mut okm = [0u8; 2 * Self::SIZE_BYTES];
+                fill(&mut okm);
+                Self::from_be_bytes_mod_p(&okm)
+            }
+
+            /// Initialize a scalar from its bytes representation (BE), rejecting zero
+            ///
+            /// This is for the contexts where zero is not a valid scalar (e.g. the secret
+            /// keys or the ECDSA r and s), see `from_bytes_in_range`
+            pub fn from_bytes_nonzero(bytes: &[u8; Self::SIZE_BYTES]) -> Option<Self> {
+                Self::from_bytes_in_range(bytes, false)
+            }
+
+            /// Initialize a scalar from its bytes representation (BE), which needs to be
+            /// less than the group order, and non zero unless `allow_zero` is set
+            ///
+            /// The range check and the zero check are combined in a single Choice, so
+            /// the time taken doesn't depend on which check fails
+            pub fn from_bytes_in_range(
+                bytes: &[u8; Self::SIZE_BYTES],
+                allow_zero: bool,
+            ) -> Option<Self> {
+                use $crate::mp::ct::{CtLesser, CtZero};
+
+                let max = Self::max().to_bytes();
+                let in_range = <&[u8; Self::SIZE_BYTES]>::ct_lt(&max, bytes).negate();
+                let valid = if allow_zero {
+                    in_range
+                } else {
+                    in_range & bytes.ct_nonzero()
+                };
+                if valid.is_true() {
+                    Some(Self::from_bytes_unchecked(bytes))
+                } else {
+                    trace_event!($crate::trace::TraceEvent::FromBytesRejected {
+                        module: module_path!(),
+                        type_name: stringify!($SCALAR),
+                        reason: "value zero or not less than the order",
+                    });
+                    None
+                }
+            }
+
+            /// Same as `from_bytes_nonzero` for a slice, which also returns None for a size mismatch
+            pub fn from_slice_nonzero(slice: &[u8]) -> Option<Self> {
+                let bytes =
+                    <&[u8; Self::SIZE_BYTES] as std::convert::TryFrom<&[u8]>>::try_from(slice)
+                        .ok()?;
+                Self::from_bytes_nonzero(bytes)
+            }
+
+            /// Same as `from_be_bytes_mod_p`, but returns None when the reduced value is zero
+            pub fn from_be_bytes_mod_p_nonzero(bytes: &[u8]) -> Option<Self> {
+                let s = Self::from_be_bytes_mod_p(bytes);
+                if s.ct_nonzero().is_true() {
+                    Some(s)
+                } else {
+                    None
+                }
+            }
+
+            /// Output the bytes representation of the scalar, tagged with this curve
+            ///
+            /// See the `tagged` module for the rationale
+            pub fn to_tagged_bytes(&self) -> TaggedScalarBytes {
+                TaggedScalarBytes::from_untagged(self.to_bytes())
+            }
+
+            /// Initialize a scalar from bytes tagged with this curve
+            ///
+            /// Same as `from_bytes`, but bytes of another curve are rejected at compile time
+            pub fn from_tagged_bytes(bytes: &TaggedScalarBytes) -> Option<Self> {
+                Self::from_bytes(bytes.as_bytes())
+            }
+
+            /// Same as `from_bytes_reduce`, which reduces modulo the group order
+            #[deprecated(note = "use from_bytes_reduce")]
+            pub fn from_bytes_mod_order(bytes: &[u8; Self::SIZE_BYTES]) -> Self {
+                Self::from_bytes_reduce(bytes)
+            }
+
+            /// Same as `from_be_bytes_mod_p`, which reduces modulo the group order
+            #[deprecated(note = "use from_be_bytes_mod_p")]
+            pub fn from_bytes_mod_order_wide(bytes: &[u8; Self::SIZE_BYTES * 2]) -> Self {
+                Self::from_be_bytes_mod_p(bytes)
+            }
+
+            /// Get the multiplicative inverse in variable time, or None for zero
+            ///
+            /// # :warning: Variable time
+            ///
+            /// The binary extended Euclidean algorithm on the canonical limbs has a
+            /// running time which depends on the value: this is only for public scalars,
+            /// e.g. the s of a signature being verified, never for a nonce or a secret key
+            /// (use `inverse` or `inverse_blinded`). It is about 2 times faster than the
+            /// constant time `inverse` on p256r1, and 4 times on p384r1
+            /// (`cargo bench --bench inverse_vartime`).
+            pub fn inverse_vartime(&self) -> Option<Self> {
+                const ORDER: [u64; $SCALAR::LIMBS_U64] =
+                    $crate::mp::limbs::limbs_from_be_bytes(&ORDER_BYTES);
+                let limbs = $crate::mp::limbs::inverse_vartime(&self.to_limbs_u64(), &ORDER)?;
+                let mut bytes = [0u8; Self::SIZE_BYTES];
+                for (i, b) in bytes.iter_mut().rev().enumerate() {
+                    *b = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+                }
+                Some(Self::from_bytes(&bytes).expect("inverse less than the order"))
+            }
+
+            /// Get the multiplicative inverse, blinded by a random non zero multiplier
+            ///
+            /// Compute `(self * r)^-1 * r` for a random r, so that the value going through
+            /// the inversion chain is not the secret itself (e.g. the ECDSA nonce).
+            ///
+            /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
+            #[cfg(feature = "rand_core")]
+            pub fn inverse_blinded<R: rand_core::RngCore + rand_core::CryptoRng>(
+                &self,
+                rng: &mut R,
+            ) -> Self {
+                let r = loop {
+                    let r = Self::random(rng);
+                    if !r.is_zero() {
+                        break r;
+                    }
+                };
+                (self * &r).inverse() * r
+            }
+
+            /// Return the canonical value of the scalar as bits, most significant bit first
+            ///
+            /// The output is always exactly `SIZE_BITS` long, including the leading zeros,
+            /// and is the same value as `to_bytes` without the padding bits of the first byte.
+            pub fn to_bits_be(&self) -> Vec<bool> {
+                let bytes = self.to_bytes();
+                let skip = Self::SIZE_BYTES * 8 - Self::SIZE_BITS;
+                (skip..Self::SIZE_BYTES * 8)
+                    .map(|i| (bytes[i / 8] >> (7 - i % 8)) & 1 == 1)
+                    .collect()
+            }
+
+            /// Initialize a scalar from a message hash, as `bits2int` of RFC 6979 and ECDSA
+            ///
+            /// The hash is read as a big endian integer, of which only the leftmost
+            /// `SIZE_BITS` bits are kept (i.e. it is shifted right when longer than the
+            /// group order), and then reduced modulo the group order. Shorter hashes
+            /// are used as is.
+            pub fn from_hash_bits(hash: &[u8]) -> Self {
+                let shift = (hash.len() * 8).saturating_sub(Self::SIZE_BITS);
+                let kept = &hash[..hash.len() - shift / 8];
+                let bits = shift % 8;
+
+                // the shifted value is less than 2^SIZE_BITS, and so less than twice
+                // the order, but it's simpler to reduce each byte along the way
+                let radix = Self::from_u64(256);
+                let mut r = Self::zero();
+                let mut prev = 0u8;
+                for b in kept.iter() {
+                    let byte = if bits == 0 {
+                        *b
+                    } else {
+                        (prev << (8 - bits)) | (b >> bits)
+                    };
+                    r = r * &radix + Self::from_u64(byte as u64);
+                    prev = *b;
+                }
+                r
+            }
+
+            /// Convert a message hash to bytes, as `bits2octets` of RFC 6979
+            ///
+            /// This is `from_hash_bits` output as `SIZE_BYTES` big endian bytes
+            pub fn bits2octets(hash: &[u8]) -> [u8; Self::SIZE_BYTES] {
+                Self::from_hash_bits(hash).to_bytes()
+            }
+
+            /// Add two scalars, also returning whether the sum went over the group order
+            ///
+            /// The scalar returned is always the modular sum, the choice is true when
+            /// the integer sum was reduced, which happens exactly when the result is
+            /// smaller than the operands.
+            pub fn overflowing_add(&self, other: &Self) -> (Self, $crate::mp::ct::Choice) {
+                use $crate::mp::ct::CtLesser;
+                let r = self + other;
+                let wrapped = <&[u8; Self::SIZE_BYTES]>::ct_lt(&r.to_bytes(), &self.to_bytes());
+                (r, wrapped)
+            }
+
+            /// Subtract two scalars, also returning whether the difference went below zero
+            ///
+            /// The scalar returned is always the modular difference, the choice is true
+            /// when the integer difference was negative, which happens exactly when
+            /// self is smaller than other.
+            pub fn overflowing_sub(&self, other: &Self) -> (Self, $crate::mp::ct::Choice) {
+                use $crate::mp::ct::CtLesser;
+                let r = self - other;
+                let borrow = <&[u8; Self::SIZE_BYTES]>::ct_lt(&self.to_bytes(), &other.to_bytes());
+                (r, borrow)
+            }
+
+            /// Add two scalars, returning None if the sum goes over the group order
+            /// instead of wrapping around
+            pub fn checked_add_nowrap(&self, other: &Self) -> Option<Self> {
+                let (r, wrapped) = self.overflowing_add(other);
+                $crate::mp::ct::CtOption::from((wrapped.negate(), r)).into_option()
+            }
+
+            /// Subtract two scalars, returning None if the difference goes below zero
+            /// instead of wrapping around
+            pub fn checked_sub_nowrap(&self, other: &Self) -> Option<Self> {
+                let (r, borrow) = self.overflowing_sub(other);
+                $crate::mp::ct::CtOption::from((borrow.negate(), r)).into_option()
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_scalar_unittest {
+    ($SCALAR:ident) => {
+        #[cfg(feature = "rand_core")]
+        #[test]
+        fn inverse_blinded() {
+            use crate::tests::rng::TestRng;
+
+            let mut rng = TestRng::new(0x5eed);
+            for _ in 0..32 {
+                let s = $SCALAR::random(&mut rng);
+                if s.is_zero() {
+                    continue;
+                }
+                assert_eq!(s.inverse_blinded(&mut rng), s.inverse());
+            }
+
+            let one = $SCALAR::one();
+            let order_m1 = -$SCALAR::one();
+            assert_eq!(one.inverse_blinded(&mut rng), one);
+            assert_eq!(order_m1.inverse_blinded(&mut rng), order_m1);
+        }
+
+        #[cfg(feature = "rand_core")]
+        #[test]
+        fn inverse_blinded_retry() {
+            use crate::tests::rng::TestRng;
+
+            // the first blinding factor drawn is zero, and need to be rejected
+            let mut rng = TestRng::with_zeros(0x5eed, $SCALAR::SIZE_BYTES);
+            let s = $SCALAR::from_u64(0x1234);
+            assert_eq!(s.inverse_blinded(&mut rng), s.inverse());
+            assert_eq!(rng.zeros_left(), 0);
+        }
+
+        #[test]
+        fn inverse_vartime() {
+            let mut values = vec![
+                $SCALAR::one(),
+                $SCALAR::from_u64(2),
+                $SCALAR::from_u64(u64::MAX),
+                -$SCALAR::one(),
+                -$SCALAR::from_u64(2),
+                $SCALAR::half_max(),
+                $SCALAR::half_max() + $SCALAR::one(),
+            ];
+            let mut p = $SCALAR::one();
+            for _ in 0..$SCALAR::SIZE_BITS {
+                p = p.double();
+                values.push(p.clone());
+                values.push(&p - &$SCALAR::one());
+            }
+            let mut x = $SCALAR::from_u64(0x1a2b_3c4d);
+            for i in 0..256 {
+                x = x.square() + $SCALAR::from_u64(i);
+                values.push(x.clone());
+            }
+            for v in values.iter().filter(|v| !v.is_zero()) {
+                assert_eq!(v.inverse_vartime(), Some(v.inverse()), "{:?}", v);
+            }
+            assert_eq!($SCALAR::zero().inverse_vartime(), None);
+        }
+
+        #[test]
+        fn reduce_mod_order() {
+            use num_bigint::BigUint;
+
+            let order = BigUint::from_bytes_be(&(-$SCALAR::one()).to_bytes()) + 1u32;
+            let size = $SCALAR::SIZE_BYTES;
+            let to_bytes = |v: &BigUint, len: usize| {
+                let b = v.to_bytes_be();
+                let mut out = vec![0u8; len - b.len()];
+                out.extend_from_slice(&b);
+                out
+            };
+            let expected = |bytes: &[u8]| {
+                let r = BigUint::from_bytes_be(bytes) % &order;
+                to_bytes(&r, size)
+            };
+
+            let all_ones = (BigUint::from(1u32) << (8 * size)) - 1u32;
+            let mut values = vec![
+                BigUint::from(0u32),
+                BigUint::from(1u32),
+                &order - 1u32,
+                order.clone(),
+                &order + 1u32,
+                &all_ones - &order,
+                all_ones.clone(),
+            ];
+            let mut x = BigUint::from(0x5eed_u32);
+            for _ in 0..16 {
+                x = (&x * &x + 1u32) % (&all_ones + 1u32);
+                values.push(x.clone());
+            }
+            for v in values.iter() {
+                let bytes = to_bytes(v, size);
+                let mut narrow = [0u8; $SCALAR::SIZE_BYTES];
+                narrow.copy_from_slice(&bytes);
+                let s = $SCALAR::from_bytes_reduce(&narrow);
+                assert_eq!(s.to_bytes().to_vec(), expected(&bytes), "{:x}", v);
+                #[allow(deprecated)]
+                let alias = $SCALAR::from_bytes_mod_order(&narrow);
+                assert_eq!(alias, s);
+            }
+
+            // the wide values, with the same values in the low and high halves
+            let wide_ones = (BigUint::from(1u32) << (16 * size)) - 1u32;
+            let mut wides = vec![wide_ones.clone(), &order * &order, &order * (&order - 1u32)];
+            for v in values.iter() {
+                wides.push(v.clone());
+                wides.push(v << (8 * size));
+                wides.push((v << (8 * size)) + v);
+            }
+            for v in wides.iter() {
+                let bytes = to_bytes(v, 2 * size);
+                let mut wide = [0u8; $SCALAR::SIZE_BYTES * 2];
+                wide.copy_from_slice(&bytes);
+                let s = $SCALAR::from_be_bytes_mod_p(&wide);
+                assert_eq!(s.to_bytes().to_vec(), expected(&bytes), "{:x}", v);
+                #[allow(deprecated)]
+                let alias = $SCALAR::from_bytes_mod_order_wide(&wide);
+                assert_eq!(alias, s);
+            }
+        }
+
+        #[test]
+        fn to_bits_be() {
+            let mut x = $SCALAR::from_u64(0xdeadbeef);
+            let mut values = vec![$SCALAR::zero(), $SCALAR::one(), -$SCALAR::one()];
+            for _ in 0..8 {
+                x = x.square() + $SCALAR::one();
+                values.push(x.clone());
+            }
+            for v in values.iter() {
+                let bits = v.to_bits_be();
+                assert_eq!(bits.len(), $SCALAR::SIZE_BITS);
+                // reconstruct the bytes, padding the first byte with zeros
+                let mut bytes = vec![0u8; $SCALAR::SIZE_BYTES];
+                let skip = $SCALAR::SIZE_BYTES * 8 - $SCALAR::SIZE_BITS;
+                for (i, bit) in bits.iter().enumerate() {
+                    let i = i + skip;
+                    bytes[i / 8] |= (*bit as u8) << (7 - i % 8);
+                }
+                assert_eq!(&bytes[..], &v.to_bytes()[..]);
+            }
+
+            let zero = $SCALAR::zero().to_bits_be();
+            assert!(zero.iter().all(|b| !b));
+            let one = $SCALAR::one().to_bits_be();
+            assert!(one[..$SCALAR::SIZE_BITS - 1].iter().all(|b| !b));
+            assert!(one[$SCALAR::SIZE_BITS - 1]);
+            // the group order is SIZE_BITS long, so the largest scalar has its top bit set
+            assert!((-$SCALAR::one()).to_bits_be()[0]);
+        }
+
+        #[test]
+        fn iter_bits_msb_first() {
+            let mut x = $SCALAR::from_u64(0x5eed);
+            let mut values = vec![$SCALAR::zero(), $SCALAR::one(), -$SCALAR::one()];
+            for _ in 0..8 {
+                x = x.square() + $SCALAR::one();
+                values.push(x.clone());
+            }
+            for v in values.iter() {
+                let expected = v.to_bits_be();
+                let bits = v.iter_bits_msb_first();
+                assert_eq!(bits.len(), $SCALAR::SIZE_BITS);
+                let bits: Vec<bool> = bits.map(|b| b.is_true()).collect();
+                assert_eq!(bits, expected);
+                let lsb_first: Vec<bool> =
+                    v.iter_bits_msb_first().rev().map(|b| b.is_true()).collect();
+                assert!(lsb_first.iter().eq(expected.iter().rev()));
+            }
+        }
+
+        #[test]
+        fn overflowing_add() {
+            let zero = $SCALAR::zero();
+            let one = $SCALAR::one();
+            let two = $SCALAR::from_u64(2);
+            let order_m1 = -$SCALAR::one();
+
+            let (r, wrapped) = order_m1.overflowing_add(&one);
+            assert_eq!((r, wrapped.is_true()), (zero.clone(), true));
+            let (r, wrapped) = order_m1.overflowing_add(&two);
+            assert_eq!((r, wrapped.is_true()), (one.clone(), true));
+            let (r, wrapped) = order_m1.overflowing_add(&order_m1);
+            assert_eq!((r, wrapped.is_true()), (-two.clone(), true));
+            let (r, wrapped) = order_m1.overflowing_add(&zero);
+            assert_eq!((r, wrapped.is_true()), (order_m1.clone(), false));
+            let (r, wrapped) = one.overflowing_add(&one);
+            assert_eq!((r, wrapped.is_true()), (two.clone(), false));
+            let (r, wrapped) = (-two.clone()).overflowing_add(&one);
+            assert_eq!((r, wrapped.is_true()), (order_m1.clone(), false));
+
+            assert_eq!(order_m1.checked_add_nowrap(&one), None);
+            assert_eq!(one.checked_add_nowrap(&order_m1), None);
+            assert_eq!(order_m1.checked_add_nowrap(&zero), Some(order_m1.clone()));
+            assert_eq!(one.checked_add_nowrap(&one), Some(two));
+        }
+
+        #[test]
+        fn overflowing_sub() {
+            let zero = $SCALAR::zero();
+            let one = $SCALAR::one();
+            let order_m1 = -$SCALAR::one();
+
+            let (r, borrow) = zero.overflowing_sub(&one);
+            assert_eq!((r, borrow.is_true()), (order_m1.clone(), true));
+            let (r, borrow) = one.overflowing_sub(&order_m1);
+            assert_eq!((r, borrow.is_true()), ($SCALAR::from_u64(2), true));
+            let (r, borrow) = order_m1.overflowing_sub(&order_m1);
+            assert_eq!((r, borrow.is_true()), (zero.clone(), false));
+            let (r, borrow) = order_m1.overflowing_sub(&one);
+            assert_eq!((r, borrow.is_true()), (-$SCALAR::from_u64(2), false));
+
+            assert_eq!(zero.checked_sub_nowrap(&one), None);
+            assert_eq!(one.checked_sub_nowrap(&one), Some(zero.clone()));
+            assert_eq!(order_m1.checked_sub_nowrap(&zero), Some(order_m1.clone()));
+        }
+    };
 }
 
 #[doc(hidden)]
@@ -630,6 +1737,204 @@ macro_rules! fiat_field_unittest {
             assert_eq!(f1.power_u64(v2 as u64), fr)
         }
 
+        #[test]
+        fn square_rep() {
+            let x = $FE::from_u64(0xdead_beef) + $FE::from_u64(0x1234).inverse();
+            let mut squared = x.clone();
+            for k in 0..8 {
+                assert_eq!(x.square_rep(k), squared);
+                assert_eq!(x.pow_2k(k), squared);
+                assert_eq!(x.power_u64(1 << k), squared);
+                squared = squared.square();
+            }
+            assert_eq!(x.square_rep(63), x.power_u64(1 << 63));
+        }
+
+        #[test]
+        fn constants() {
+            const EIGHT: $FE = $FE::from_u64_const(8);
+            assert_eq!($FE::ZERO, $FE::zero());
+            assert_eq!($FE::ONE, $FE::one());
+            assert_eq!($FE::TWO, $FE::from_u64(2));
+            assert_eq!($FE::THREE, $FE::from_u64(3));
+            assert_eq!(EIGHT, $FE::from_u64(8));
+            for v in [0x1234_5678_9abc_def0, u64::MAX - 1, u64::MAX].iter() {
+                assert_eq!($FE::from_u64_const(*v), $FE::from_u64(*v));
+            }
+        }
+
+        #[test]
+        fn from_bytes_reduce() {
+            let plus_one = |mut bytes: [u8; $FE::SIZE_BYTES]| {
+                for b in bytes.iter_mut().rev() {
+                    let (v, carry) = b.overflowing_add(1);
+                    *b = v;
+                    if !carry {
+                        break;
+                    }
+                }
+                bytes
+            };
+            let pm1 = (-$FE::one()).to_bytes();
+            let p = plus_one(pm1);
+            let p1 = plus_one(p);
+            let ff = [0xff; $FE::SIZE_BYTES];
+
+            assert_eq!($FE::from_bytes_reduce(&p), $FE::zero());
+            assert_eq!($FE::from_bytes_reduce(&p1), $FE::one());
+            assert_eq!($FE::from_bytes_reduce(&ff), $FE::from_be_bytes_mod_p(&ff));
+            assert_eq!($FE::from_bytes_reduce(&pm1), -$FE::one());
+            // the reduced values are usable in the arithmetic
+            let r = $FE::from_bytes_reduce(&ff);
+            let expected = $FE::from_be_bytes_mod_p(&ff);
+            assert_eq!(&r * &r, &expected * &expected);
+            assert_eq!(
+                (r + $FE::one()).to_bytes(),
+                (expected + $FE::one()).to_bytes()
+            );
+
+            for v in [0u64, 1, 0xdeadbeef, u64::MAX].iter() {
+                let bytes = $FE::from_u64(*v).to_bytes();
+                assert_eq!($FE::from_bytes_reduce(&bytes), $FE::from_u64(*v));
+                assert_eq!($FE::from_bytes_unchecked(&bytes), $FE::from_u64(*v));
+            }
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "from_bytes_unchecked")]
+        fn from_bytes_unchecked_non_canonical() {
+            $FE::from_bytes_unchecked(&[0xff; $FE::SIZE_BYTES]);
+        }
+
+        // in release builds, the unchecked constructor reduces the values not less than
+        // the modulus, like `from_bytes_reduce`
+        #[test]
+        #[cfg(not(debug_assertions))]
+        fn from_bytes_unchecked_reduces() {
+            let ff = [0xff; $FE::SIZE_BYTES];
+            let x = $FE::from_bytes_unchecked(&ff);
+            assert!(x.is_reduced());
+            assert_eq!(x, $FE::from_be_bytes_mod_p(&ff));
+        }
+
+        // every public constructor and operation outputs values keeping the invariant of
+        // the representation, which the equality relies on
+        #[test]
+        fn reduced_invariant() {
+            let ff = [0xff; $FE::SIZE_BYTES];
+            let pm1 = (-$FE::one()).to_bytes();
+            let mut values = vec![
+                $FE::zero(),
+                $FE::one(),
+                $FE::ZERO,
+                $FE::ONE,
+                $FE::TWO,
+                $FE::THREE,
+                $FE::from_u64(u64::MAX),
+                $FE::from_u64_const(u64::MAX),
+                $FE::from_bytes(&pm1).unwrap(),
+                $FE::from_slice(&pm1).unwrap(),
+                $FE::from_bytes_unchecked(&pm1),
+                $FE::from_bytes_reduce(&ff),
+                $FE::from_be_bytes_mod_p(&ff),
+                $FE::from_be_bytes_mod_p(&[0xff; 3 * $FE::SIZE_BYTES]),
+                $FE::from_be_bytes_mod_p(&[]),
+                -$FE::zero(),
+                -$FE::one(),
+            ];
+            let x = $FE::from_be_bytes_mod_p(&[0xa5; 2 * $FE::SIZE_BYTES]);
+            let m1 = -$FE::one();
+            values.extend_from_slice(&[
+                &m1 + &m1,
+                &m1 * &m1,
+                m1.square(),
+                m1.double(),
+                &$FE::zero() - &$FE::one(),
+                &x - &x,
+                &x + &-&x,
+                x.inverse(),
+                x.power_u64(u64::MAX),
+            ]);
+            for (i, v) in values.iter().enumerate() {
+                assert!(v.is_reduced(), "{}: {}", i, v);
+            }
+        }
+
+        // a representation breaking the invariant is only possible from the raw limbs:
+        // the limbs of p are congruent to zero, but not zero for the limb test
+        #[test]
+        fn non_reduced_representation() {
+            let mut p_bytes = (-$FE::one()).to_bytes();
+            for b in p_bytes.iter_mut().rev() {
+                let (v, carry) = b.overflowing_add(1);
+                *b = v;
+                if !carry {
+                    break;
+                }
+            }
+            let mut limbs = $FE::zero().0;
+            for (i, b) in p_bytes.iter().rev().enumerate() {
+                limbs[i / 8] |= (*b as u64) << (8 * (i % 8));
+            }
+            let p = $FE(limbs);
+            // the unsaturated representations don't have this invariant
+            if p.is_reduced() {
+                return;
+            }
+            assert!(p.0.iter().any(|l| *l != 0));
+            #[cfg(debug_assertions)]
+            assert!(std::panic::catch_unwind(|| p == $FE::zero()).is_err());
+        }
+
+        #[test]
+        fn is_canonical_bytes() {
+            let pm1 = (-$FE::one()).to_bytes();
+            let mut p = pm1;
+            for b in p.iter_mut().rev() {
+                let (v, carry) = b.overflowing_add(1);
+                *b = v;
+                if !carry {
+                    break;
+                }
+            }
+            assert!($FE::is_canonical_bytes(&pm1));
+            assert!(!$FE::is_canonical_bytes(&p));
+            assert!(!$FE::is_canonical_bytes(&[0xff; $FE::SIZE_BYTES]));
+            assert!($FE::is_canonical_bytes(&[0; $FE::SIZE_BYTES]));
+
+            assert!($FE::is_canonical_slice(&pm1));
+            assert!(!$FE::is_canonical_slice(&p));
+            assert!(!$FE::is_canonical_slice(&[]));
+            assert!(!$FE::is_canonical_slice(&pm1[1..]));
+            assert!(!$FE::is_canonical_slice(&[0; $FE::SIZE_BYTES + 1]));
+
+            // pseudo random bytes, and the same bytes with the top of p, so
+            // that both sides of the modulus are exercised
+            let mut state = 0x9e37_79b9_7f4a_7c15u64;
+            for i in 0..256 {
+                let mut bytes = [0u8; $FE::SIZE_BYTES];
+                for b in bytes.iter_mut() {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    *b = state as u8;
+                }
+                if i % 2 == 1 {
+                    let top = (i / 2) % ($FE::SIZE_BYTES - 1) + 1;
+                    bytes[..top].copy_from_slice(&p[..top]);
+                }
+                assert_eq!(
+                    $FE::is_canonical_bytes(&bytes),
+                    $FE::from_bytes(&bytes).is_some()
+                );
+                assert_eq!(
+                    $FE::is_canonical_slice(&bytes),
+                    $FE::from_slice(&bytes).is_some()
+                );
+            }
+        }
+
         #[test]
         fn eq() {
             for i in 1..56 {
@@ -700,6 +2005,180 @@ macro_rules! fiat_field_unittest {
             }
         }
 
+        #[test]
+        fn to_u64() {
+            use std::convert::TryFrom;
+
+            for v in [0u64, 1, 2, 0xff, 0x100, 0xdeadbeef, u64::MAX - 1, u64::MAX].iter() {
+                let fe = $FE::from_u64(*v);
+                assert_eq!(fe.to_u64(), Some(*v));
+                assert_eq!(fe.to_u128(), Some(*v as u128));
+                assert_eq!(u64::try_from(&fe), Ok(*v));
+            }
+
+            let above = $FE::from_u64(u64::MAX) + $FE::one();
+            assert_eq!(above.to_u64(), None);
+            assert_eq!(above.to_u128(), Some(u64::MAX as u128 + 1));
+            assert!(u64::try_from(&above).is_err());
+
+            let mut bytes = [0u8; $FE::SIZE_BYTES];
+            bytes[$FE::SIZE_BYTES - 16..].copy_from_slice(&u128::MAX.to_be_bytes());
+            let max128 = $FE::from_bytes(&bytes).unwrap();
+            assert_eq!(max128.to_u64(), None);
+            assert_eq!(max128.to_u128(), Some(u128::MAX));
+            assert_eq!(u128::try_from(&max128), Ok(u128::MAX));
+
+            let m1 = -$FE::one();
+            assert_eq!(m1.to_u64(), None);
+            assert_eq!(m1.to_u128(), None);
+            assert!(u128::try_from(&m1).is_err());
+        }
+
+        #[test]
+        fn bytes_size() {
+            let excess_bits = $FE::SIZE_BYTES * 8 - $FE::SIZE_BITS;
+            assert!(excess_bits < 8);
+
+            // the largest element (-1) is exactly SIZE_BITS long
+            let m1 = (-$FE::one()).to_bytes();
+            assert_eq!(m1.len(), $FE::SIZE_BYTES);
+            assert_eq!(m1[0] >> (7 - excess_bits), 1);
+            assert_eq!($FE::from_slice(&m1), Some(-$FE::one()));
+
+            let mut longer = vec![0u8; $FE::SIZE_BYTES + 1];
+            longer[1..].copy_from_slice(&m1);
+            assert_eq!($FE::from_slice(&longer), None);
+            assert_eq!($FE::from_slice(&m1[1..]), None);
+
+            let mut out = vec![0u8; $FE::SIZE_BYTES];
+            (-$FE::one()).to_slice(&mut out);
+            assert_eq!(&out[..], &m1[..]);
+        }
+
+        #[test]
+        fn write_hex() {
+            use core::fmt::Write;
+
+            // fixed size writer on the stack, to check formatting doesn't need allocation
+            struct StackBuf {
+                buf: [u8; 2 * $FE::SIZE_BYTES],
+                len: usize,
+            }
+            impl Write for StackBuf {
+                fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                    let end = self.len + s.len();
+                    if end > self.buf.len() {
+                        return Err(core::fmt::Error);
+                    }
+                    self.buf[self.len..end].copy_from_slice(s.as_bytes());
+                    self.len = end;
+                    Ok(())
+                }
+            }
+
+            let values = [
+                $FE::zero(),
+                $FE::one(),
+                $FE::from_u64(0x0123_4567_89ab_cdef),
+                $FE::from_u64(0xfedc_ba98_7654_3210),
+                -$FE::one(),
+            ];
+            for v in values.iter() {
+                let expected: String = v.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+
+                let mut out = [0u8; 2 * $FE::SIZE_BYTES];
+                v.write_hex(&mut out);
+                assert_eq!(&out[..], expected.as_bytes());
+
+                let mut stack = StackBuf {
+                    buf: [0u8; 2 * $FE::SIZE_BYTES],
+                    len: 0,
+                };
+                write!(stack, "{}", v).unwrap();
+                assert_eq!(&stack.buf[..stack.len], expected.as_bytes());
+
+                assert_eq!(v.to_string(), expected);
+                assert_eq!(format!("{:?}", v), expected);
+            }
+        }
+
+        #[test]
+        fn write_to_slice() {
+            use $crate::curve::field::InvalidLength;
+            let fe = -$FE::one();
+
+            let mut out = vec![0u8; $FE::SIZE_BYTES];
+            assert_eq!(fe.write_to_slice(&mut out), Ok($FE::SIZE_BYTES));
+            assert_eq!(&out[..], &fe.to_bytes()[..]);
+
+            for len in [0, $FE::SIZE_BYTES - 1, $FE::SIZE_BYTES + 1] {
+                let mut out = vec![0u8; len];
+                assert_eq!(
+                    fe.write_to_slice(&mut out),
+                    Err(InvalidLength {
+                        expected: $FE::SIZE_BYTES,
+                        got: len
+                    })
+                );
+                assert!(out.iter().all(|b| *b == 0));
+            }
+        }
+
+        #[test]
+        fn to_limbs_u64() {
+            let mut x = $FE::from_u64(0xdeadbeef);
+            let mut values = vec![$FE::zero(), $FE::one(), -$FE::one()];
+            for _ in 0..8 {
+                x = x.square() + $FE::one();
+                values.push(x.clone());
+            }
+            for v in values.iter() {
+                let limbs = v.to_limbs_u64();
+                assert_eq!(limbs.len(), $FE::SIZE_BITS.div_ceil(64));
+                let mut bytes = Vec::new();
+                for l in limbs.iter().rev() {
+                    bytes.extend_from_slice(&l.to_be_bytes());
+                }
+                let padding = $FE::LIMBS_U64 * 8 - $FE::SIZE_BYTES;
+                assert!(bytes[..padding].iter().all(|b| *b == 0));
+                assert_eq!(&bytes[padding..], &v.to_bytes()[..]);
+            }
+            assert_eq!(
+                $FE::from_u64(0x0123456789abcdef).to_limbs_u64()[0],
+                0x0123456789abcdef
+            );
+            assert!($FE::from_u64(0x0123456789abcdef).to_limbs_u64()[1..]
+                .iter()
+                .all(|l| *l == 0));
+        }
+
+        #[cfg(feature = "subtle")]
+        #[test]
+        fn subtle_traits() {
+            use subtle::{ConditionallySelectable, ConstantTimeEq};
+            let values = [$FE::zero(), $FE::one(), $FE::from_u64(0xcafe), -$FE::one()];
+            for a in values.iter() {
+                for b in values.iter() {
+                    let eq = ConstantTimeEq::ct_eq(a, b);
+                    assert_eq!(bool::from(eq), a == b);
+                    assert_eq!(
+                        bool::from(eq),
+                        $crate::mp::ct::CtEqual::ct_eq(a, b).is_true()
+                    );
+                    assert_eq!($FE::conditional_select(a, b, 0.into()), *a);
+                    assert_eq!($FE::conditional_select(a, b, 1.into()), *b);
+
+                    let mut c = *a;
+                    c.conditional_assign(b, eq);
+                    assert_eq!(c, *a);
+                    let mut d = $FE::from_u64(3);
+                    $FE::conditional_swap(&mut c, &mut d, 1.into());
+                    assert_eq!(c, $FE::from_u64(3));
+                    assert_eq!(d, *a);
+                }
+            }
+        }
+
         #[test]
         fn inverse() {
             for i in 1..124 {
@@ -718,6 +2197,7 @@ macro_rules! fiat_field_sqrt_unittest {
     ($FE:ident) => {
         #[test]
         fn sqrt() {
+            assert_eq!($FE::zero().sqrt().into_option(), Some($FE::zero()));
             let mut found = 0;
             let tested = 56;
             for i in 2..tested {
@@ -739,3 +2219,172 @@ macro_rules! fiat_field_sqrt_unittest {
         }
     };
 }
+
+// check sqrt_generic on residues and non residues, and against the tuned sqrt
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_field_sqrt_generic_unittest {
+    ($FE:ident) => {
+        #[test]
+        fn sqrt_generic() {
+            assert_eq!($FE::zero().sqrt_generic().into_option(), Some($FE::zero()));
+            let minus_one = -$FE::one();
+            // (p-1)/2 as big endian bytes, for the euler criterion
+            let pm1_half = {
+                let pm1 = minus_one.to_bytes();
+                let mut out = pm1.clone();
+                let mut carry = 0;
+                for (o, b) in out.iter_mut().zip(pm1.iter()) {
+                    *o = (b >> 1) | carry;
+                    carry = b << 7;
+                }
+                out
+            };
+            let mut x = $FE::from_u64(0xdeadbeef);
+            let (mut residues, mut non_residues) = (0, 0);
+            for _ in 0..32 {
+                let is_residue = x.power(&pm1_half) != minus_one;
+                match x.sqrt_generic().into_option() {
+                    None => {
+                        assert!(!is_residue);
+                        non_residues += 1
+                    }
+                    Some(r) => {
+                        assert!(is_residue);
+                        assert_eq!(r.square(), x);
+                        residues += 1
+                    }
+                }
+                // always a residue
+                let r = x.square().sqrt_generic().into_option().unwrap();
+                assert!(r == x || r == -&x);
+                x = x.square() + $FE::one();
+            }
+            assert!(residues > 1 && non_residues > 1);
+        }
+    };
+    ($FE:ident, tuned) => {
+        $crate::fiat_field_sqrt_generic_unittest!($FE);
+
+        #[test]
+        fn sqrt_generic_tuned() {
+            let mut x = $FE::from_u64(0xdeadbeef);
+            for _ in 0..32 {
+                for v in [x.clone(), x.square()] {
+                    let tuned = v.sqrt().into_option();
+                    let generic = v.sqrt_generic().into_option();
+                    match (tuned, generic) {
+                        (None, None) => {}
+                        (Some(t), Some(g)) => assert!(g == t || g == -&t),
+                        (t, g) => panic!("sqrt {:?} and sqrt_generic {:?} disagree", t, g),
+                    }
+                }
+                x = x.square() + $FE::one();
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_field_chain_unittest {
+    ($FE:ident, $($name:ident: $chain_fn:ident == $table:path),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut x = $FE::from_u64(0xdeadbeef);
+                for _ in 0..16 {
+                    assert_eq!(x.$chain_fn(), x.pow_chain(&$table));
+                    x = x.square() + $FE::one();
+                }
+            }
+        )*
+    };
+}
+
+// check that two function sets give the same results on the limbs of the same elements
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_field_ops_equivalence_unittest {
+    ($FE:ident, $A:path, $B:path, montgomery) => {
+        $crate::fiat_field_ops_equivalence_unittest!($FE, $A, $B);
+
+        #[test]
+        fn montgomery_equivalence() {
+            use $A as A;
+            use $B as B;
+            use $crate::curve::fiat::ops::FiatMontgomeryOps;
+
+            for x in elements().iter() {
+                let x = &x.0;
+                let (mut a, mut b) = (*x, *x);
+                A::from_montgomery(&mut a, x);
+                B::from_montgomery(&mut b, x);
+                assert_eq!(a, b, "from_montgomery {:?}", x);
+                // the normal domain value is also a valid input of to_montgomery
+                let n = a;
+                A::to_montgomery(&mut a, &n);
+                B::to_montgomery(&mut b, &n);
+                assert_eq!(a, b, "to_montgomery {:?}", n);
+                assert_eq!(&a, x);
+            }
+        }
+    };
+    ($FE:ident, $A:path, $B:path) => {
+        // edge values followed by a pseudo random looking sequence
+        fn elements() -> Vec<$FE> {
+            let mut v = vec![$FE::zero(), $FE::one(), -$FE::one()];
+            let mut x = $FE::from_u64(0x5eed);
+            for i in 0..32 {
+                x = x.square() + $FE::from_u64(i);
+                v.push(x.clone());
+            }
+            v
+        }
+
+        #[test]
+        fn ops_equivalence() {
+            use $A as A;
+            use $B as B;
+            use $crate::curve::fiat::ops::FiatFieldOps;
+
+            let elements = elements();
+            for x in elements.iter() {
+                let x = &x.0;
+                let (mut a, mut b) = (0, 0);
+                A::nonzero(&mut a, x);
+                B::nonzero(&mut b, x);
+                assert_eq!(a == 0, b == 0, "nonzero {:?}", x);
+
+                let (mut a, mut b) = (*x, *x);
+                A::square(&mut a, x);
+                B::square(&mut b, x);
+                assert_eq!(a, b, "square {:?}", x);
+                A::opp(&mut a, x);
+                B::opp(&mut b, x);
+                assert_eq!(a, b, "opp {:?}", x);
+
+                let (mut ab, mut bb) = ([0u8; $FE::SIZE_BYTES], [0u8; $FE::SIZE_BYTES]);
+                A::to_bytes(&mut ab, x);
+                B::to_bytes(&mut bb, x);
+                assert_eq!(ab, bb, "to_bytes {:?}", x);
+                A::from_bytes(&mut a, &ab);
+                B::from_bytes(&mut b, &ab);
+                assert_eq!(a, b, "from_bytes {:?}", ab);
+
+                for y in elements.iter() {
+                    let y = &y.0;
+                    A::add(&mut a, x, y);
+                    B::add(&mut b, x, y);
+                    assert_eq!(a, b, "add {:?} {:?}", x, y);
+                    A::sub(&mut a, x, y);
+                    B::sub(&mut b, x, y);
+                    assert_eq!(a, b, "sub {:?} {:?}", x, y);
+                    A::mul(&mut a, x, y);
+                    B::mul(&mut b, x, y);
+                    assert_eq!(a, b, "mul {:?} {:?}", x, y);
+                }
+            }
+        }
+    };
+}
@@ -18,5 +18,12 @@ pub mod p521_scalar_64;
 pub mod secp256k1_64;
 pub mod secp256k1_scalar_64;
 
+pub mod montgomery_64;
+pub mod ops;
+pub mod sqrt;
+#[cfg(feature = "fiat-crypto")]
+pub mod upstream;
+pub mod vendored;
+
 mod curve_macros;
 mod field_macros;
@@ -0,0 +1,466 @@
+//! Word by word Montgomery arithmetic for any odd modulus, on 64 bits limbs
+//!
+//! This is the fallback for the primes without fiat generated code: the functions
+//! follow the fiat `word_by_word_montgomery` template (little endian limbs, values
+//! in the Montgomery domain with R = 2^(64*N), outputs first) and are exposed through
+//! the same `FiatFieldOps` and `FiatMontgomeryOps` traits, with `montgomery_ops_define`.
+//!
+//! The modulus dependent constants (-m^-1 mod 2^64 and R^2 mod m) are computed
+//! at compile time from the modulus, so a new field only needs its modulus.
+//!
+//! The operations don't branch on the values: the final conditional subtraction
+//! of the modulus is a selection with a `Choice`. Unlike the fiat code, this is not
+//! formally verified, and is tested against big integers and the fiat p256 code instead.
+
+use crate::mp::ct::{Choice, CtSelect};
+
+/// Modulus of a field, with its precomputed Montgomery constants
+pub struct Modulus<const N: usize> {
+    /// The modulus, in little endian limbs
+    pub m: [u64; N],
+    /// -m^-1 mod 2^64
+    pub m0inv: u64,
+    /// R^2 mod m, with R = 2^(64*N)
+    pub r2: [u64; N],
+}
+
+impl<const N: usize> Modulus<N> {
+    /// Precompute the constants of an odd modulus with its top limb not zero
+    pub const fn new(m: [u64; N]) -> Self {
+        assert!(m[0] & 1 == 1);
+        assert!(m[N - 1] != 0);
+        Modulus {
+            m,
+            m0inv: neg_inverse_u64(m[0]),
+            r2: r2_mod(&m),
+        }
+    }
+}
+
+// -m^-1 mod 2^64 with newton iterations, each one doubling the number of correct bits
+const fn neg_inverse_u64(m0: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+// 2^(2*64*N) mod m, by doubling 1 modulo m (the compile time is not an issue)
+const fn r2_mod<const N: usize>(m: &[u64; N]) -> [u64; N] {
+    let mut one = [0u64; N];
+    one[0] = 1;
+    shl_mod(m, one, 2 * 64 * N)
+}
+
+/// v * 2^bits mod m for v < m, with little endian limbs
+///
+/// This doubles v modulo m one bit at a time, so it is only meant for compile time
+/// constants, e.g. the Montgomery form v * 2^(64*N) of a small value.
+pub const fn shl_mod<const N: usize>(m: &[u64; N], v: [u64; N], bits: usize) -> [u64; N] {
+    let mut r = v;
+    let mut i = 0;
+    while i < bits {
+        // r = 2r, which fits in N limbs plus a carry bit
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < N {
+            let next = r[j] >> 63;
+            r[j] = (r[j] << 1) | carry;
+            carry = next;
+            j += 1;
+        }
+        // subtract m when 2r >= m
+        let mut d = [0u64; N];
+        let mut borrow = 0u64;
+        let mut j = 0;
+        while j < N {
+            let (s1, b1) = r[j].overflowing_sub(m[j]);
+            let (s2, b2) = s1.overflowing_sub(borrow);
+            d[j] = s2;
+            borrow = (b1 | b2) as u64;
+            j += 1;
+        }
+        if carry == 1 || borrow == 0 {
+            r = d;
+        }
+        i += 1;
+    }
+    r
+}
+
+#[inline]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+#[inline]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, ((t >> 64) as u64) & 1)
+}
+
+// a + b * c + carry, which always fits in 128 bits
+#[inline]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+// the value (hi * 2^(64*N) + a) reduced once by the modulus, for a value less than 2m
+#[inline]
+fn reduce_once<const N: usize>(out1: &mut [u64; N], a: &[u64; N], hi: u64, modulus: &Modulus<N>) {
+    let mut d = [0u64; N];
+    let mut borrow = 0;
+    for i in 0..N {
+        let (x, b) = sbb(a[i], modulus.m[i], borrow);
+        d[i] = x;
+        borrow = b;
+    }
+    // keep a only when a - m went negative without a carry out of a
+    let keep = Choice::from_bit(borrow & (1 ^ hi));
+    *out1 = <[u64; N]>::ct_select(&d, a, keep);
+}
+
+/// Set out1 to a non zero value if arg1 is not zero, and to zero otherwise
+pub fn nonzero<const N: usize>(out1: &mut u64, arg1: &[u64; N]) {
+    *out1 = arg1.iter().fold(0, |acc, x| acc | x);
+}
+
+/// Modular addition of two values less than the modulus
+pub fn add<const N: usize>(
+    out1: &mut [u64; N],
+    arg1: &[u64; N],
+    arg2: &[u64; N],
+    modulus: &Modulus<N>,
+) {
+    let mut s = [0u64; N];
+    let mut carry = 0;
+    for i in 0..N {
+        let (x, c) = adc(arg1[i], arg2[i], carry);
+        s[i] = x;
+        carry = c;
+    }
+    reduce_once(out1, &s, carry, modulus);
+}
+
+/// Modular subtraction of two values less than the modulus
+pub fn sub<const N: usize>(
+    out1: &mut [u64; N],
+    arg1: &[u64; N],
+    arg2: &[u64; N],
+    modulus: &Modulus<N>,
+) {
+    let mut d = [0u64; N];
+    let mut borrow = 0;
+    for i in 0..N {
+        let (x, b) = sbb(arg1[i], arg2[i], borrow);
+        d[i] = x;
+        borrow = b;
+    }
+    // add back the modulus when the difference is negative
+    let mask = Choice::from_bit(borrow).to_mask_u64();
+    let mut carry = 0;
+    for i in 0..N {
+        let (x, c) = adc(d[i], modulus.m[i] & mask, carry);
+        out1[i] = x;
+        carry = c;
+    }
+}
+
+/// Modular negation of a value less than the modulus
+pub fn opp<const N: usize>(out1: &mut [u64; N], arg1: &[u64; N], modulus: &Modulus<N>) {
+    sub(out1, &[0u64; N], arg1, modulus)
+}
+
+/// Montgomery multiplication: arg1 * arg2 * R^-1 mod m, of two values less than the modulus
+///
+/// This is the coarsely integrated operand scanning (CIOS) method, which interleaves
+/// the multiplication by one limb of arg2 and the reduction of one limb.
+pub fn mul<const N: usize>(
+    out1: &mut [u64; N],
+    arg1: &[u64; N],
+    arg2: &[u64; N],
+    modulus: &Modulus<N>,
+) {
+    // t is always less than 2m, on N limbs plus the top bit t_hi
+    let mut t = [0u64; N];
+    let mut t_hi = 0u64;
+    for b in arg2.iter() {
+        // t += arg1 * b
+        let mut carry = 0;
+        for j in 0..N {
+            let (x, c) = mac(t[j], arg1[j], *b, carry);
+            t[j] = x;
+            carry = c;
+        }
+        let (top, top_carry) = adc(t_hi, carry, 0);
+
+        // t = (t + q * m) / 2^64, where q makes the lowest limb zero
+        let q = t[0].wrapping_mul(modulus.m0inv);
+        let (_, mut carry) = mac(t[0], q, modulus.m[0], 0);
+        for j in 1..N {
+            let (x, c) = mac(t[j], q, modulus.m[j], carry);
+            t[j - 1] = x;
+            carry = c;
+        }
+        let (x, c) = adc(top, carry, 0);
+        t[N - 1] = x;
+        t_hi = top_carry + c;
+    }
+    reduce_once(out1, &t, t_hi, modulus);
+}
+
+/// Montgomery squaring, same as `mul(out1, arg1, arg1)`
+pub fn square<const N: usize>(out1: &mut [u64; N], arg1: &[u64; N], modulus: &Modulus<N>) {
+    mul(out1, arg1, arg1, modulus)
+}
+
+/// Convert a value less than the modulus to the Montgomery domain
+pub fn to_montgomery<const N: usize>(out1: &mut [u64; N], arg1: &[u64; N], modulus: &Modulus<N>) {
+    mul(out1, arg1, &modulus.r2, modulus)
+}
+
+/// Convert a value out of the Montgomery domain
+pub fn from_montgomery<const N: usize>(out1: &mut [u64; N], arg1: &[u64; N], modulus: &Modulus<N>) {
+    let mut one = [0u64; N];
+    one[0] = 1;
+    mul(out1, arg1, &one, modulus)
+}
+
+/// Little endian bytes of the little endian limbs, the bytes being 8 * N long
+pub fn to_bytes<const N: usize>(out1: &mut [u8], arg1: &[u64; N]) {
+    for (o, limb) in out1.chunks_mut(8).zip(arg1.iter()) {
+        o.copy_from_slice(&limb.to_le_bytes());
+    }
+}
+
+/// Little endian limbs of the little endian bytes, the bytes being 8 * N long
+pub fn from_bytes<const N: usize>(out1: &mut [u64; N], arg1: &[u8]) {
+    for (o, b) in out1.iter_mut().zip(arg1.chunks(8)) {
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(b);
+        *o = u64::from_le_bytes(limb);
+    }
+}
+
+/// Define a function set type for the Montgomery arithmetic modulo a `Modulus`
+///
+/// Only for the moduli of exactly 64 * N bits, as the bytes are 8 * N long
+#[doc(hidden)]
+#[macro_export]
+macro_rules! montgomery_ops_define {
+    ($(#[$outer:meta])* $OPS:ident, $LIMBS:expr, $MODULUS:expr) => {
+        $(#[$outer])*
+        pub struct $OPS;
+
+        impl $OPS {
+            const MODULUS: $crate::curve::fiat::montgomery_64::Modulus<$LIMBS> =
+                $crate::curve::fiat::montgomery_64::Modulus::new($MODULUS);
+        }
+
+        impl $crate::curve::fiat::ops::FiatFieldOps for $OPS {
+            type Limbs = [u64; $LIMBS];
+            type Bytes = [u8; $LIMBS * 8];
+            const BACKEND: $crate::curve::Backend = $crate::curve::Backend::Montgomery64;
+
+            #[inline]
+            fn nonzero(out1: &mut u64, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::nonzero(out1, arg1)
+            }
+            #[inline]
+            fn add(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::add(out1, arg1, arg2, &Self::MODULUS)
+            }
+            #[inline]
+            fn sub(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::sub(out1, arg1, arg2, &Self::MODULUS)
+            }
+            #[inline]
+            fn mul(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::mul(out1, arg1, arg2, &Self::MODULUS)
+            }
+            #[inline]
+            fn square(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::square(out1, arg1, &Self::MODULUS)
+            }
+            #[inline]
+            fn opp(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::opp(out1, arg1, &Self::MODULUS)
+            }
+            #[inline]
+            fn to_bytes(out1: &mut Self::Bytes, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::to_bytes(out1, arg1)
+            }
+            #[inline]
+            fn from_bytes(out1: &mut Self::Limbs, arg1: &Self::Bytes) {
+                $crate::curve::fiat::montgomery_64::from_bytes(out1, arg1)
+            }
+        }
+
+        impl $crate::curve::fiat::ops::FiatMontgomeryOps for $OPS {
+            #[inline]
+            fn to_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::to_montgomery(out1, arg1, &Self::MODULUS)
+            }
+            #[inline]
+            fn from_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $crate::curve::fiat::montgomery_64::from_montgomery(out1, arg1, &Self::MODULUS)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // p256r1 base field, whose Montgomery constants are the fiat ones
+    const P256: Modulus<4> = Modulus::new([
+        0xffffffffffffffff,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+    ]);
+
+    #[test]
+    fn constants() {
+        assert_eq!(P256.m0inv, 1);
+        assert_eq!(
+            P256.r2,
+            [
+                0x0000000000000003,
+                0xfffffffbffffffff,
+                0xfffffffffffffffe,
+                0x00000004fffffffd
+            ]
+        );
+        // -m^-1 * m = -1 mod 2^64, for a modulus without the special low limb
+        let m = Modulus::new([0x53bbf40939d54123u64, 0x7203df6b21c6052b, 1]);
+        assert_eq!(m.m0inv.wrapping_mul(m.m[0]), u64::MAX);
+    }
+
+    #[cfg(feature = "p256r1")]
+    #[test]
+    fn same_as_fiat() {
+        use crate::curve::fiat::p256_64 as fiat;
+        let values: [[u64; 4]; 4] = [
+            [0, 0, 0, 0],
+            [1, 0, 0, 0],
+            [
+                0xfffffffffffffffe,
+                0x00000000ffffffff,
+                0,
+                0xffffffff00000001,
+            ],
+            [
+                0x0123456789abcdef,
+                0xfedcba9876543210,
+                0x5555aaaa5555aaaa,
+                0x1234,
+            ],
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                let (mut x, mut y) = ([0u64; 4], [0u64; 4]);
+                mul(&mut x, a, b, &P256);
+                fiat::fiat_p256_mul(&mut y, a, b);
+                assert_eq!(x, y);
+                add(&mut x, a, b, &P256);
+                fiat::fiat_p256_add(&mut y, a, b);
+                assert_eq!(x, y);
+                sub(&mut x, a, b, &P256);
+                fiat::fiat_p256_sub(&mut y, a, b);
+                assert_eq!(x, y);
+            }
+            let (mut x, mut y) = ([0u64; 4], [0u64; 4]);
+            to_montgomery(&mut x, a, &P256);
+            fiat::fiat_p256_to_montgomery(&mut y, a);
+            assert_eq!(x, y);
+            from_montgomery(&mut x, a, &P256);
+            fiat::fiat_p256_from_montgomery(&mut y, a);
+            assert_eq!(x, y);
+            opp(&mut x, a, &P256);
+            fiat::fiat_p256_opp(&mut y, a);
+            assert_eq!(x, y);
+        }
+    }
+
+    fn to_big<const N: usize>(a: &[u64; N]) -> num_bigint::BigUint {
+        let bytes: Vec<u8> = a.iter().flat_map(|l| l.to_le_bytes()).collect();
+        num_bigint::BigUint::from_bytes_le(&bytes)
+    }
+
+    fn from_big<const N: usize>(a: &num_bigint::BigUint) -> [u64; N] {
+        let mut bytes = a.to_bytes_le();
+        bytes.resize(8 * N, 0);
+        let mut out = [0u64; N];
+        for (o, chunk) in out.iter_mut().zip(bytes.chunks(8)) {
+            *o = u64::from_le_bytes([
+                chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+            ]);
+        }
+        out
+    }
+
+    // the field operations on canonical values, through the Montgomery domain,
+    // against the same operations on big integers modulo m
+    fn same_as_bigint<const N: usize>(modulus: &Modulus<N>) {
+        let m = to_big(&modulus.m);
+        let mut values = vec![0u32.into(), 1u32.into(), &m - 1u32, &m >> 1];
+        let mut v = num_bigint::BigUint::from(0x0e1au32);
+        for i in 0..8u32 {
+            v = (&v * &v + i + 1u32) % &m;
+            values.push(v.clone());
+        }
+        let mont = |a: &num_bigint::BigUint| {
+            let mut out = [0u64; N];
+            to_montgomery(&mut out, &from_big(a), modulus);
+            out
+        };
+        let canonical = |a: &[u64; N]| {
+            let mut out = [0u64; N];
+            from_montgomery(&mut out, a, modulus);
+            to_big(&out)
+        };
+        for a in values.iter() {
+            let ma = mont(a);
+            assert_eq!(canonical(&ma), *a);
+            let mut x = [0u64; N];
+            opp(&mut x, &ma, modulus);
+            assert_eq!(canonical(&x), (&m - a) % &m);
+            square(&mut x, &ma, modulus);
+            assert_eq!(canonical(&x), (a * a) % &m);
+            for b in values.iter() {
+                let mb = mont(b);
+                mul(&mut x, &ma, &mb, modulus);
+                assert_eq!(canonical(&x), (a * b) % &m);
+                add(&mut x, &ma, &mb, modulus);
+                assert_eq!(canonical(&x), (a + b) % &m);
+                sub(&mut x, &ma, &mb, modulus);
+                assert_eq!(canonical(&x), (a + &m - b) % &m);
+            }
+        }
+    }
+
+    #[test]
+    fn bigint() {
+        same_as_bigint(&P256);
+        // SM2 base field
+        same_as_bigint(&Modulus::new([
+            0xffffffffffffffff,
+            0xffffffff00000000,
+            0xffffffffffffffff,
+            0xfffffffeffffffff,
+        ]));
+        same_as_bigint(&Modulus::new([
+            0x53bbf40939d54123u64,
+            0x7203df6b21c6052b,
+            1,
+        ]));
+        same_as_bigint(&Modulus::new([0xffffffffffffffc5u64]));
+    }
+}
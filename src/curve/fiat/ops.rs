@@ -0,0 +1,99 @@
+//! Function sets of the fiat generated code used by the field elements
+//!
+//! The field macros only call the fiat code through these traits, so that
+//! the functions can come either from the vendored modules (see `vendored`),
+//! or from the `fiat-crypto` crate with the `fiat-crypto` feature (see `upstream`).
+//!
+//! The functions keep the fiat calling convention (outputs first), the limbs
+//! are in the implementation representation (e.g. the Montgomery domain) and
+//! the bytes are in little endian.
+
+/// Arithmetic on the limbs representation of a field element
+pub trait FiatFieldOps {
+    /// Limbs representation of a field element
+    type Limbs;
+    /// Little endian bytes representation of a field element
+    type Bytes;
+    /// Origin of the functions
+    const BACKEND: crate::curve::Backend;
+
+    /// Set out1 to a non zero value if arg1 is not zero, and to zero otherwise
+    fn nonzero(out1: &mut u64, arg1: &Self::Limbs);
+    fn add(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs);
+    fn sub(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs);
+    fn mul(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs);
+    fn square(out1: &mut Self::Limbs, arg1: &Self::Limbs);
+    fn opp(out1: &mut Self::Limbs, arg1: &Self::Limbs);
+    fn to_bytes(out1: &mut Self::Bytes, arg1: &Self::Limbs);
+    fn from_bytes(out1: &mut Self::Limbs, arg1: &Self::Bytes);
+}
+
+/// Conversions from and to the Montgomery domain, for the Montgomery implementations
+///
+/// `from_bytes` and `to_bytes` work on the normal domain.
+pub trait FiatMontgomeryOps: FiatFieldOps {
+    fn to_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs);
+    fn from_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs);
+}
+
+/// Define a function set type from fiat functions operating on plain arrays
+#[doc(hidden)]
+#[macro_export]
+macro_rules! fiat_ops_define {
+    ($(#[$outer:meta])* $OPS:ident, $LIMBS:expr, $BYTES:expr, $fiat_nonzero:path, $fiat_add:path, $fiat_sub:path, $fiat_mul:path, $fiat_square:path, $fiat_opp:path, $fiat_to_bytes:path, $fiat_from_bytes:path $(, montgomery { $fiat_to_montgomery:path, $fiat_from_montgomery:path })?) => {
+        $(#[$outer])*
+        pub struct $OPS;
+
+        impl $crate::curve::fiat::ops::FiatFieldOps for $OPS {
+            type Limbs = [u64; $LIMBS];
+            type Bytes = [u8; $BYTES];
+            const BACKEND: $crate::curve::Backend = $crate::curve::Backend::Fiat64;
+
+            #[inline]
+            fn nonzero(out1: &mut u64, arg1: &Self::Limbs) {
+                $fiat_nonzero(out1, arg1)
+            }
+            #[inline]
+            fn add(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $fiat_add(out1, arg1, arg2)
+            }
+            #[inline]
+            fn sub(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $fiat_sub(out1, arg1, arg2)
+            }
+            #[inline]
+            fn mul(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                $fiat_mul(out1, arg1, arg2)
+            }
+            #[inline]
+            fn square(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $fiat_square(out1, arg1)
+            }
+            #[inline]
+            fn opp(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $fiat_opp(out1, arg1)
+            }
+            #[inline]
+            fn to_bytes(out1: &mut Self::Bytes, arg1: &Self::Limbs) {
+                $fiat_to_bytes(out1, arg1)
+            }
+            #[inline]
+            fn from_bytes(out1: &mut Self::Limbs, arg1: &Self::Bytes) {
+                $fiat_from_bytes(out1, arg1)
+            }
+        }
+
+        $(
+        impl $crate::curve::fiat::ops::FiatMontgomeryOps for $OPS {
+            #[inline]
+            fn to_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $fiat_to_montgomery(out1, arg1)
+            }
+            #[inline]
+            fn from_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                $fiat_from_montgomery(out1, arg1)
+            }
+        }
+        )?
+    };
+}
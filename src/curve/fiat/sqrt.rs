@@ -0,0 +1,135 @@
+//! Exponents of the generic square root of the fiat field elements (see `sqrt_generic`)
+//!
+//! The method depends on the prime p, which is given as the big endian bytes of p-1:
+//!
+//! * p = 3 mod 4: x^((p+1)/4)
+//! * p = 5 mod 8: Atkin's method, with gamma = (2x)^((p-5)/8)
+//! * p = 1 mod 8: the constant time Tonelli-Shanks of RFC 9380 (appendix I.4), which
+//!   always does the same number of operations for a given p
+
+/// Square root method for a prime p
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqrtMethod {
+    /// p = 3 mod 4
+    Mod4,
+    /// p = 5 mod 8
+    Mod8,
+    /// p = 1 mod 8
+    TonelliShanks,
+}
+
+/// Method and exponents of the square root for a prime p
+#[derive(Debug, Clone)]
+pub struct SqrtParams {
+    pub method: SqrtMethod,
+    /// (p+1)/4 for `Mod4`, (p-5)/8 for `Mod8`, and (q-1)/2 for `TonelliShanks`
+    /// where p-1 = q * 2^s with q odd
+    pub exponent: Vec<u8>,
+    /// s, only used by `TonelliShanks`
+    pub s: usize,
+    /// q, only used by `TonelliShanks`
+    pub q: Vec<u8>,
+    /// (p-1)/2, to find a non residue with the euler criterion
+    pub euler: Vec<u8>,
+}
+
+// big endian bytes shifted right by n bits
+fn shr(bytes: &[u8], n: usize) -> Vec<u8> {
+    let (shift_bytes, bits) = (n / 8, n % 8);
+    let kept = &bytes[..bytes.len() - shift_bytes];
+    let mut out = vec![0u8; bytes.len()];
+    let offset = out.len() - kept.len();
+    for (i, b) in kept.iter().enumerate() {
+        let prev = if i == 0 { 0 } else { kept[i - 1] };
+        out[offset + i] = if bits == 0 {
+            *b
+        } else {
+            (b >> bits) | (prev << (8 - bits))
+        };
+    }
+    out
+}
+
+// big endian bytes plus one, which doesn't overflow for the values used here
+fn add_one(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    for b in out.iter_mut().rev() {
+        let (v, carry) = b.overflowing_add(1);
+        *b = v;
+        if !carry {
+            break;
+        }
+    }
+    out
+}
+
+impl SqrtParams {
+    /// Compute the method and the exponents from the big endian bytes of p-1, for an odd prime p
+    pub fn new(pm1: &[u8]) -> Self {
+        let low = pm1[pm1.len() - 1];
+        assert!(low & 1 == 0, "p-1 needs to be even");
+        let euler = shr(pm1, 1);
+        if low & 0b11 == 0b10 {
+            SqrtParams {
+                method: SqrtMethod::Mod4,
+                exponent: add_one(&shr(pm1, 2)),
+                s: 1,
+                q: Vec::new(),
+                euler,
+            }
+        } else if low & 0b111 == 0b100 {
+            SqrtParams {
+                method: SqrtMethod::Mod8,
+                exponent: shr(pm1, 3),
+                s: 2,
+                q: Vec::new(),
+                euler,
+            }
+        } else {
+            let mut s = 0;
+            for b in pm1.iter().rev() {
+                if *b == 0 {
+                    s += 8;
+                } else {
+                    s += b.trailing_zeros() as usize;
+                    break;
+                }
+            }
+            SqrtParams {
+                method: SqrtMethod::TonelliShanks,
+                exponent: shr(pm1, s + 1),
+                s,
+                q: shr(pm1, s),
+                euler,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponents() {
+        // p = 23 = 3 mod 4: (p+1)/4 = 6
+        let p = SqrtParams::new(&[0, 22]);
+        assert_eq!(p.method, SqrtMethod::Mod4);
+        assert_eq!(p.exponent, vec![0, 6]);
+        assert_eq!(p.euler, vec![0, 11]);
+
+        // p = 0x10d = 269 = 5 mod 8: (p-5)/8 = 33
+        let p = SqrtParams::new(&[1, 0x0c]);
+        assert_eq!(p.method, SqrtMethod::Mod8);
+        assert_eq!(p.exponent, vec![0, 33]);
+
+        // p = 0x301 = 769 = 3 * 2^8 + 1: s = 8, q = 3, (q-1)/2 = 1
+        let p = SqrtParams::new(&[3, 0]);
+        assert_eq!(p.method, SqrtMethod::TonelliShanks);
+        assert_eq!(
+            (p.s, p.q.clone(), p.exponent.clone()),
+            (8, vec![0, 3], vec![0, 1])
+        );
+        assert_eq!(p.euler, vec![1, 0x80]);
+    }
+}
@@ -0,0 +1,264 @@
+//! Function sets of the `fiat-crypto` crate, for the curves it publishes
+//!
+//! The upstream Montgomery functions operate on Montgomery and non Montgomery
+//! domain newtypes around the same limbs arrays as the vendored code, and the
+//! solinas p521 functions on tight and loose newtypes, so the limbs are wrapped
+//! and unwrapped around each call.
+
+use super::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::Backend;
+use fiat_crypto::{
+    p256_64, p256_scalar_64, p384_64, p384_scalar_64, p521_64, secp256k1_montgomery_64,
+    secp256k1_montgomery_scalar_64,
+};
+
+macro_rules! upstream_montgomery_ops_define {
+    ($OPS:ident, $LIMBS:expr, $BYTES:expr, $m:ident, $Mont:ident, $NonMont:ident, $nonzero:ident, $add:ident, $sub:ident, $mul:ident, $square:ident, $opp:ident, $to_bytes:ident, $from_bytes:ident, $to_montgomery:ident, $from_montgomery:ident) => {
+        pub struct $OPS;
+
+        impl FiatFieldOps for $OPS {
+            type Limbs = [u64; $LIMBS];
+            type Bytes = [u8; $BYTES];
+            const BACKEND: Backend = Backend::FiatCrypto64;
+
+            #[inline]
+            fn nonzero(out1: &mut u64, arg1: &Self::Limbs) {
+                $m::$nonzero(out1, arg1)
+            }
+            #[inline]
+            fn add(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$add(&mut out, &$m::$Mont(*arg1), &$m::$Mont(*arg2));
+                *out1 = out.0
+            }
+            #[inline]
+            fn sub(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$sub(&mut out, &$m::$Mont(*arg1), &$m::$Mont(*arg2));
+                *out1 = out.0
+            }
+            #[inline]
+            fn mul(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$mul(&mut out, &$m::$Mont(*arg1), &$m::$Mont(*arg2));
+                *out1 = out.0
+            }
+            #[inline]
+            fn square(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$square(&mut out, &$m::$Mont(*arg1));
+                *out1 = out.0
+            }
+            #[inline]
+            fn opp(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$opp(&mut out, &$m::$Mont(*arg1));
+                *out1 = out.0
+            }
+            #[inline]
+            fn to_bytes(out1: &mut Self::Bytes, arg1: &Self::Limbs) {
+                $m::$to_bytes(out1, arg1)
+            }
+            #[inline]
+            fn from_bytes(out1: &mut Self::Limbs, arg1: &Self::Bytes) {
+                $m::$from_bytes(out1, arg1)
+            }
+        }
+
+        impl FiatMontgomeryOps for $OPS {
+            #[inline]
+            fn to_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                let mut out = $m::$Mont([0; $LIMBS]);
+                $m::$to_montgomery(&mut out, &$m::$NonMont(*arg1));
+                *out1 = out.0
+            }
+            #[inline]
+            fn from_montgomery(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+                let mut out = $m::$NonMont([0; $LIMBS]);
+                $m::$from_montgomery(&mut out, &$m::$Mont(*arg1));
+                *out1 = out.0
+            }
+        }
+    };
+}
+
+upstream_montgomery_ops_define!(
+    P256,
+    4,
+    32,
+    p256_64,
+    fiat_p256_montgomery_domain_field_element,
+    fiat_p256_non_montgomery_domain_field_element,
+    fiat_p256_nonzero,
+    fiat_p256_add,
+    fiat_p256_sub,
+    fiat_p256_mul,
+    fiat_p256_square,
+    fiat_p256_opp,
+    fiat_p256_to_bytes,
+    fiat_p256_from_bytes,
+    fiat_p256_to_montgomery,
+    fiat_p256_from_montgomery
+);
+
+upstream_montgomery_ops_define!(
+    P256Scalar,
+    4,
+    32,
+    p256_scalar_64,
+    fiat_p256_scalar_montgomery_domain_field_element,
+    fiat_p256_scalar_non_montgomery_domain_field_element,
+    fiat_p256_scalar_nonzero,
+    fiat_p256_scalar_add,
+    fiat_p256_scalar_sub,
+    fiat_p256_scalar_mul,
+    fiat_p256_scalar_square,
+    fiat_p256_scalar_opp,
+    fiat_p256_scalar_to_bytes,
+    fiat_p256_scalar_from_bytes,
+    fiat_p256_scalar_to_montgomery,
+    fiat_p256_scalar_from_montgomery
+);
+
+upstream_montgomery_ops_define!(
+    Secp256k1,
+    4,
+    32,
+    secp256k1_montgomery_64,
+    fiat_secp256k1_montgomery_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_non_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_nonzero,
+    fiat_secp256k1_montgomery_add,
+    fiat_secp256k1_montgomery_sub,
+    fiat_secp256k1_montgomery_mul,
+    fiat_secp256k1_montgomery_square,
+    fiat_secp256k1_montgomery_opp,
+    fiat_secp256k1_montgomery_to_bytes,
+    fiat_secp256k1_montgomery_from_bytes,
+    fiat_secp256k1_montgomery_to_montgomery,
+    fiat_secp256k1_montgomery_from_montgomery
+);
+
+upstream_montgomery_ops_define!(
+    Secp256k1Scalar,
+    4,
+    32,
+    secp256k1_montgomery_scalar_64,
+    fiat_secp256k1_montgomery_scalar_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_scalar_non_montgomery_domain_field_element,
+    fiat_secp256k1_montgomery_scalar_nonzero,
+    fiat_secp256k1_montgomery_scalar_add,
+    fiat_secp256k1_montgomery_scalar_sub,
+    fiat_secp256k1_montgomery_scalar_mul,
+    fiat_secp256k1_montgomery_scalar_square,
+    fiat_secp256k1_montgomery_scalar_opp,
+    fiat_secp256k1_montgomery_scalar_to_bytes,
+    fiat_secp256k1_montgomery_scalar_from_bytes,
+    fiat_secp256k1_montgomery_scalar_to_montgomery,
+    fiat_secp256k1_montgomery_scalar_from_montgomery
+);
+
+upstream_montgomery_ops_define!(
+    P384,
+    6,
+    48,
+    p384_64,
+    fiat_p384_montgomery_domain_field_element,
+    fiat_p384_non_montgomery_domain_field_element,
+    fiat_p384_nonzero,
+    fiat_p384_add,
+    fiat_p384_sub,
+    fiat_p384_mul,
+    fiat_p384_square,
+    fiat_p384_opp,
+    fiat_p384_to_bytes,
+    fiat_p384_from_bytes,
+    fiat_p384_to_montgomery,
+    fiat_p384_from_montgomery
+);
+
+upstream_montgomery_ops_define!(
+    P384Scalar,
+    6,
+    48,
+    p384_scalar_64,
+    fiat_p384_scalar_montgomery_domain_field_element,
+    fiat_p384_scalar_non_montgomery_domain_field_element,
+    fiat_p384_scalar_nonzero,
+    fiat_p384_scalar_add,
+    fiat_p384_scalar_sub,
+    fiat_p384_scalar_mul,
+    fiat_p384_scalar_square,
+    fiat_p384_scalar_opp,
+    fiat_p384_scalar_to_bytes,
+    fiat_p384_scalar_from_bytes,
+    fiat_p384_scalar_to_montgomery,
+    fiat_p384_scalar_from_montgomery
+);
+
+// fiat-crypto doesn't publish the p521 scalar field, which stays on the vendored code
+pub struct P521;
+
+impl FiatFieldOps for P521 {
+    type Limbs = [u64; 9];
+    type Bytes = [u8; 66];
+    const BACKEND: Backend = Backend::FiatCrypto64;
+
+    // same as the vendored code, as there's no nonzero function for p521
+    fn nonzero(out1: &mut u64, arg1: &Self::Limbs) {
+        use crate::mp::ct::CtZero;
+        let mut bytes = [0u8; 66];
+        Self::to_bytes(&mut bytes, arg1);
+        *out1 = bytes.ct_nonzero().to_mask_u64();
+    }
+    fn add(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+        let mut loose = p521_64::fiat_p521_loose_field_element([0; 9]);
+        p521_64::fiat_p521_add(&mut loose, &tight(arg1), &tight(arg2));
+        carry(out1, &loose)
+    }
+    fn sub(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+        let mut loose = p521_64::fiat_p521_loose_field_element([0; 9]);
+        p521_64::fiat_p521_sub(&mut loose, &tight(arg1), &tight(arg2));
+        carry(out1, &loose)
+    }
+    fn mul(out1: &mut Self::Limbs, arg1: &Self::Limbs, arg2: &Self::Limbs) {
+        let mut out = p521_64::fiat_p521_tight_field_element([0; 9]);
+        p521_64::fiat_p521_carry_mul(&mut out, &relax(arg1), &relax(arg2));
+        *out1 = out.0
+    }
+    fn square(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+        let mut out = p521_64::fiat_p521_tight_field_element([0; 9]);
+        p521_64::fiat_p521_carry_square(&mut out, &relax(arg1));
+        *out1 = out.0
+    }
+    fn opp(out1: &mut Self::Limbs, arg1: &Self::Limbs) {
+        let mut loose = p521_64::fiat_p521_loose_field_element([0; 9]);
+        p521_64::fiat_p521_opp(&mut loose, &tight(arg1));
+        carry(out1, &loose)
+    }
+    fn to_bytes(out1: &mut Self::Bytes, arg1: &Self::Limbs) {
+        p521_64::fiat_p521_to_bytes(out1, &tight(arg1))
+    }
+    fn from_bytes(out1: &mut Self::Limbs, arg1: &Self::Bytes) {
+        let mut out = p521_64::fiat_p521_tight_field_element([0; 9]);
+        p521_64::fiat_p521_from_bytes(&mut out, arg1);
+        *out1 = out.0
+    }
+}
+
+// the limbs of the field elements are always tight (carried)
+fn tight(limbs: &[u64; 9]) -> p521_64::fiat_p521_tight_field_element {
+    p521_64::fiat_p521_tight_field_element(*limbs)
+}
+
+fn relax(limbs: &[u64; 9]) -> p521_64::fiat_p521_loose_field_element {
+    let mut out = p521_64::fiat_p521_loose_field_element([0; 9]);
+    p521_64::fiat_p521_relax(&mut out, &tight(limbs));
+    out
+}
+
+fn carry(out1: &mut [u64; 9], loose: &p521_64::fiat_p521_loose_field_element) {
+    let mut out = p521_64::fiat_p521_tight_field_element([0; 9]);
+    p521_64::fiat_p521_carry(&mut out, loose);
+    *out1 = out.0
+}
@@ -0,0 +1,344 @@
+//! Function sets of the vendored fiat modules
+//!
+//! The fields without fiat generated code use the `montgomery_64` fallback.
+
+use super::*;
+use crate::fiat_ops_define;
+#[cfg(feature = "sm2")]
+use crate::montgomery_ops_define;
+use crate::mp::ct::CtZero;
+
+fiat_ops_define!(
+    P192k1,
+    3,
+    24,
+    p192k1_64::fiat_p192k1_nonzero,
+    p192k1_64::fiat_p192k1_add,
+    p192k1_64::fiat_p192k1_sub,
+    p192k1_64::fiat_p192k1_mul,
+    p192k1_64::fiat_p192k1_square,
+    p192k1_64::fiat_p192k1_opp,
+    p192k1_64::fiat_p192k1_to_bytes,
+    p192k1_64::fiat_p192k1_from_bytes,
+    montgomery {
+        p192k1_64::fiat_p192k1_to_montgomery,
+        p192k1_64::fiat_p192k1_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P192k1Scalar,
+    3,
+    24,
+    p192k1_scalar_64::fiat_p192k1_scalar_nonzero,
+    p192k1_scalar_64::fiat_p192k1_scalar_add,
+    p192k1_scalar_64::fiat_p192k1_scalar_sub,
+    p192k1_scalar_64::fiat_p192k1_scalar_mul,
+    p192k1_scalar_64::fiat_p192k1_scalar_square,
+    p192k1_scalar_64::fiat_p192k1_scalar_opp,
+    p192k1_scalar_64::fiat_p192k1_scalar_to_bytes,
+    p192k1_scalar_64::fiat_p192k1_scalar_from_bytes,
+    montgomery {
+        p192k1_scalar_64::fiat_p192k1_scalar_to_montgomery,
+        p192k1_scalar_64::fiat_p192k1_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P192r1,
+    3,
+    24,
+    p192r1_64::fiat_p192r1_nonzero,
+    p192r1_64::fiat_p192r1_add,
+    p192r1_64::fiat_p192r1_sub,
+    p192r1_64::fiat_p192r1_mul,
+    p192r1_64::fiat_p192r1_square,
+    p192r1_64::fiat_p192r1_opp,
+    p192r1_64::fiat_p192r1_to_bytes,
+    p192r1_64::fiat_p192r1_from_bytes,
+    montgomery {
+        p192r1_64::fiat_p192r1_to_montgomery,
+        p192r1_64::fiat_p192r1_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P192r1Scalar,
+    3,
+    24,
+    p192r1_scalar_64::fiat_p192r1_scalar_nonzero,
+    p192r1_scalar_64::fiat_p192r1_scalar_add,
+    p192r1_scalar_64::fiat_p192r1_scalar_sub,
+    p192r1_scalar_64::fiat_p192r1_scalar_mul,
+    p192r1_scalar_64::fiat_p192r1_scalar_square,
+    p192r1_scalar_64::fiat_p192r1_scalar_opp,
+    p192r1_scalar_64::fiat_p192r1_scalar_to_bytes,
+    p192r1_scalar_64::fiat_p192r1_scalar_from_bytes,
+    montgomery {
+        p192r1_scalar_64::fiat_p192r1_scalar_to_montgomery,
+        p192r1_scalar_64::fiat_p192r1_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P224k1,
+    4,
+    28,
+    p224k1_64::fiat_p224k1_nonzero,
+    p224k1_64::fiat_p224k1_add,
+    p224k1_64::fiat_p224k1_sub,
+    p224k1_64::fiat_p224k1_mul,
+    p224k1_64::fiat_p224k1_square,
+    p224k1_64::fiat_p224k1_opp,
+    p224k1_64::fiat_p224k1_to_bytes,
+    p224k1_64::fiat_p224k1_from_bytes,
+    montgomery {
+        p224k1_64::fiat_p224k1_to_montgomery,
+        p224k1_64::fiat_p224k1_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P224k1Scalar,
+    4,
+    29,
+    p224k1_scalar_64::fiat_p224k1_scalar_nonzero,
+    p224k1_scalar_64::fiat_p224k1_scalar_add,
+    p224k1_scalar_64::fiat_p224k1_scalar_sub,
+    p224k1_scalar_64::fiat_p224k1_scalar_mul,
+    p224k1_scalar_64::fiat_p224k1_scalar_square,
+    p224k1_scalar_64::fiat_p224k1_scalar_opp,
+    p224k1_scalar_64::fiat_p224k1_scalar_to_bytes,
+    p224k1_scalar_64::fiat_p224k1_scalar_from_bytes,
+    montgomery {
+        p224k1_scalar_64::fiat_p224k1_scalar_to_montgomery,
+        p224k1_scalar_64::fiat_p224k1_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P224r1,
+    4,
+    28,
+    p224r1_64::fiat_p224r1_nonzero,
+    p224r1_64::fiat_p224r1_add,
+    p224r1_64::fiat_p224r1_sub,
+    p224r1_64::fiat_p224r1_mul,
+    p224r1_64::fiat_p224r1_square,
+    p224r1_64::fiat_p224r1_opp,
+    p224r1_64::fiat_p224r1_to_bytes,
+    p224r1_64::fiat_p224r1_from_bytes,
+    montgomery {
+        p224r1_64::fiat_p224r1_to_montgomery,
+        p224r1_64::fiat_p224r1_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P224r1Scalar,
+    4,
+    28,
+    p224r1_scalar_64::fiat_p224r1_scalar_nonzero,
+    p224r1_scalar_64::fiat_p224r1_scalar_add,
+    p224r1_scalar_64::fiat_p224r1_scalar_sub,
+    p224r1_scalar_64::fiat_p224r1_scalar_mul,
+    p224r1_scalar_64::fiat_p224r1_scalar_square,
+    p224r1_scalar_64::fiat_p224r1_scalar_opp,
+    p224r1_scalar_64::fiat_p224r1_scalar_to_bytes,
+    p224r1_scalar_64::fiat_p224r1_scalar_from_bytes,
+    montgomery {
+        p224r1_scalar_64::fiat_p224r1_scalar_to_montgomery,
+        p224r1_scalar_64::fiat_p224r1_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P256,
+    4,
+    32,
+    p256_64::fiat_p256_nonzero,
+    p256_64::fiat_p256_add,
+    p256_64::fiat_p256_sub,
+    p256_64::fiat_p256_mul,
+    p256_64::fiat_p256_square,
+    p256_64::fiat_p256_opp,
+    p256_64::fiat_p256_to_bytes,
+    p256_64::fiat_p256_from_bytes,
+    montgomery {
+        p256_64::fiat_p256_to_montgomery,
+        p256_64::fiat_p256_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P256Scalar,
+    4,
+    32,
+    p256_scalar_64::fiat_p256_scalar_nonzero,
+    p256_scalar_64::fiat_p256_scalar_add,
+    p256_scalar_64::fiat_p256_scalar_sub,
+    p256_scalar_64::fiat_p256_scalar_mul,
+    p256_scalar_64::fiat_p256_scalar_square,
+    p256_scalar_64::fiat_p256_scalar_opp,
+    p256_scalar_64::fiat_p256_scalar_to_bytes,
+    p256_scalar_64::fiat_p256_scalar_from_bytes,
+    montgomery {
+        p256_scalar_64::fiat_p256_scalar_to_montgomery,
+        p256_scalar_64::fiat_p256_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P384,
+    6,
+    48,
+    p384_64::fiat_p384_nonzero,
+    p384_64::fiat_p384_add,
+    p384_64::fiat_p384_sub,
+    p384_64::fiat_p384_mul,
+    p384_64::fiat_p384_square,
+    p384_64::fiat_p384_opp,
+    p384_64::fiat_p384_to_bytes,
+    p384_64::fiat_p384_from_bytes,
+    montgomery {
+        p384_64::fiat_p384_to_montgomery,
+        p384_64::fiat_p384_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    P384Scalar,
+    6,
+    48,
+    p384_scalar_64::fiat_p384_scalar_nonzero,
+    p384_scalar_64::fiat_p384_scalar_add,
+    p384_scalar_64::fiat_p384_scalar_sub,
+    p384_scalar_64::fiat_p384_scalar_mul,
+    p384_scalar_64::fiat_p384_scalar_square,
+    p384_scalar_64::fiat_p384_scalar_opp,
+    p384_scalar_64::fiat_p384_scalar_to_bytes,
+    p384_scalar_64::fiat_p384_scalar_from_bytes,
+    montgomery {
+        p384_scalar_64::fiat_p384_scalar_to_montgomery,
+        p384_scalar_64::fiat_p384_scalar_from_montgomery
+    }
+);
+
+// the unsaturated solinas p521 functions output loose elements that need to be carried,
+// and don't provide a nonzero function
+
+fn p521_nonzero(out: &mut u64, fe: &[u64; 9]) {
+    let mut bytes = [0u8; 66];
+    p521_64::fiat_p521_to_bytes(&mut bytes, fe);
+    *out = bytes.ct_nonzero().to_mask_u64();
+}
+
+fn p521_carry_add(out: &mut [u64; 9], a: &[u64; 9], b: &[u64; 9]) {
+    let mut loose = [0u64; 9];
+    p521_64::fiat_p521_add(&mut loose, a, b);
+    p521_64::fiat_p521_carry(out, &loose)
+}
+
+fn p521_carry_sub(out: &mut [u64; 9], a: &[u64; 9], b: &[u64; 9]) {
+    let mut loose = [0u64; 9];
+    p521_64::fiat_p521_sub(&mut loose, a, b);
+    p521_64::fiat_p521_carry(out, &loose)
+}
+
+fn p521_carry_opp(out: &mut [u64; 9], a: &[u64; 9]) {
+    let mut loose = [0u64; 9];
+    p521_64::fiat_p521_opp(&mut loose, a);
+    p521_64::fiat_p521_carry(out, &loose)
+}
+
+fiat_ops_define!(
+    P521,
+    9,
+    66,
+    p521_nonzero,
+    p521_carry_add,
+    p521_carry_sub,
+    p521_64::fiat_p521_carry_mul,
+    p521_64::fiat_p521_carry_square,
+    p521_carry_opp,
+    p521_64::fiat_p521_to_bytes,
+    p521_64::fiat_p521_from_bytes
+);
+
+fiat_ops_define!(
+    P521Scalar,
+    9,
+    66,
+    p521_scalar_64::fiat_p521_scalar_nonzero,
+    p521_scalar_64::fiat_p521_scalar_add,
+    p521_scalar_64::fiat_p521_scalar_sub,
+    p521_scalar_64::fiat_p521_scalar_mul,
+    p521_scalar_64::fiat_p521_scalar_square,
+    p521_scalar_64::fiat_p521_scalar_opp,
+    p521_scalar_64::fiat_p521_scalar_to_bytes,
+    p521_scalar_64::fiat_p521_scalar_from_bytes,
+    montgomery {
+        p521_scalar_64::fiat_p521_scalar_to_montgomery,
+        p521_scalar_64::fiat_p521_scalar_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    Secp256k1,
+    4,
+    32,
+    secp256k1_64::fiat_secp256k1_nonzero,
+    secp256k1_64::fiat_secp256k1_add,
+    secp256k1_64::fiat_secp256k1_sub,
+    secp256k1_64::fiat_secp256k1_mul,
+    secp256k1_64::fiat_secp256k1_square,
+    secp256k1_64::fiat_secp256k1_opp,
+    secp256k1_64::fiat_secp256k1_to_bytes,
+    secp256k1_64::fiat_secp256k1_from_bytes,
+    montgomery {
+        secp256k1_64::fiat_secp256k1_to_montgomery,
+        secp256k1_64::fiat_secp256k1_from_montgomery
+    }
+);
+
+fiat_ops_define!(
+    Secp256k1Scalar,
+    4,
+    32,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_nonzero,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_add,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_sub,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_mul,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_square,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_opp,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_to_bytes,
+    secp256k1_scalar_64::fiat_secp256k1_scalar_from_bytes,
+    montgomery {
+        secp256k1_scalar_64::fiat_secp256k1_scalar_to_montgomery,
+        secp256k1_scalar_64::fiat_secp256k1_scalar_from_montgomery
+    }
+);
+
+#[cfg(feature = "sm2")]
+montgomery_ops_define!(
+    Sm2,
+    4,
+    [
+        0xffffffffffffffff,
+        0xffffffff00000000,
+        0xffffffffffffffff,
+        0xfffffffeffffffff
+    ]
+);
+
+#[cfg(feature = "sm2")]
+montgomery_ops_define!(
+    Sm2Scalar,
+    4,
+    [
+        0x53bbf40939d54123,
+        0x7203df6b21c6052b,
+        0xffffffffffffffff,
+        0xfffffffeffffffff
+    ]
+);
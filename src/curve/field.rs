@@ -6,12 +6,17 @@
 //! We also expect a way to define a sign in the field, additive inverse (opposite), and the
 //! overall subtraction operations so that the math checks out.
 //!
-//! For now the the field abstracted is expected to be a prime field (expected multiplicative inverse).
-//! In the future, this will change to make the distinction
-//! between field and prime field.
+//! The field hierarchy is split between:
 //!
+//! * Field : the basic arithmetic available on any field, including extension fields (e.g. Fp²)
+//! * PrimeField : a prime field, which add the integer embedding, the sign and the serialization
+//! * FieldSqrt : fields where the square root can be computed
+//! * FieldLegendre : fields where the quadratic residues can be recognized
+//!
+//! The generic point operations only require the bounds they use, so that the
+//! point arithmetic is also available for curves defined over an extension field.
 
-use crate::mp::ct::{CtEqual, CtOption};
+use crate::mp::ct::{Choice, CtEqual, CtOption, CtSelect};
 use std::fmt;
 use std::ops::{Add, Mul, Neg, Sub};
 
@@ -22,7 +27,50 @@ pub enum Sign {
     Negative,
 }
 
-/// abstract trait for prime field support
+impl Sign {
+    /// Convert the sign into a constant time Choice, Positive being false and Negative true
+    pub fn to_choice(self) -> Choice {
+        match self {
+            Sign::Positive => Choice::from_bit(0),
+            Sign::Negative => Choice::from_bit(1),
+        }
+    }
+
+    /// Convert a constant time Choice into a sign, false being Positive and true Negative
+    pub fn from_choice(choice: Choice) -> Self {
+        match choice.to_mask_u64() & 1 {
+            0 => Sign::Positive,
+            _ => Sign::Negative,
+        }
+    }
+}
+
+impl CtSelect for Sign {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mask = choice.to_mask_u64();
+        let negative = (a.to_choice().to_mask_u64() & !mask) | (b.to_choice().to_mask_u64() & mask);
+        Sign::from_choice(Choice::from_bit(negative & 1))
+    }
+}
+
+/// This is the error returned when an element doesn't fit in the requested primitive integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerOverflow;
+
+/// This is the error returned when an integer is not less than the order of the field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotReduced;
+
+/// This is the error returned when a serialization buffer doesn't have the expected size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidLength {
+    /// Size in bytes of the serialized element
+    pub expected: usize,
+    /// Size in bytes of the buffer provided
+    pub got: usize,
+}
+
+/// abstract trait for field support
 pub trait Field<Output = Self>:
     Sized
     + 'static
@@ -34,7 +82,6 @@ pub trait Field<Output = Self>:
     + CtEqual
     + fmt::Debug
     + fmt::Display
-    + From<u64>
     + Add<Output = Output>
     + Sub<Output = Output>
     + Mul<Output = Output>
@@ -49,12 +96,37 @@ pub trait Field<Output = Self>:
     fn double(&self) -> Output;
 
     fn inverse(&self) -> Output;
-    fn sign(&self) -> Sign;
 
     fn square(&self) -> Output;
     fn cube(&self) -> Output;
 }
 
+/// abstract trait for prime field support
+///
+/// On top of the field operations, elements of a prime field can be created from integers,
+/// have a sign, and have a canonical big endian bytes representation of SIZE_BYTES bytes.
+pub trait PrimeField: Field + From<u64> {
+    /// Size in bits of the field order
+    const SIZE_BITS: usize;
+    /// Size in bytes of the serialized element
+    const SIZE_BYTES: usize;
+
+    fn from_u64(v: u64) -> Self;
+    fn sign(&self) -> Sign;
+
+    /// Initialize from a big endian bytes representation of exactly SIZE_BYTES bytes,
+    /// returning None for non canonical representation
+    fn from_slice(slice: &[u8]) -> Option<Self>;
+    /// Output the big endian bytes representation, the slice needs to be exactly SIZE_BYTES bytes
+    fn write_to_slice(&self, slice: &mut [u8]) -> Result<usize, InvalidLength>;
+}
+
 pub trait FieldSqrt: Field {
     fn sqrt(&self) -> CtOption<Self>;
 }
+
+/// Fields where the quadratic residues can be recognized, without computing a square root
+pub trait FieldLegendre: Field {
+    /// Check if the element is a non zero square, zero not being a quadratic residue
+    fn is_quadratic_residue(&self) -> Choice;
+}
@@ -0,0 +1,668 @@
+//! Jacobian Elliptic Curve Point defined over Field element as (X,Y,Z)
+//!
+//! The affine point associated with (X,Y,Z) is (X/Z², Y/Z³). The addition and
+//! doubling formulas are the ones from the [Explicit-Formulas Database](https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html)
+//! (add-2007-bl, madd-2007-bl, dbl-2007-bl, dbl-2001-b for a = -3 and dbl-2009-l for a = 0),
+//! which need fewer multiplications than the complete projective formulas.
+//!
+//! Those formulas are not complete: the addition has branches for the point at
+//! infinity, P+P and P+(-P), so everything in this module is variable time and only
+//! meant for public inputs (e.g. signature verification). The projective points stay
+//! the secure default.
+
+use super::projective::wnaf_digits;
+use super::weierstrass::{WeierstrassCurve, WeierstrassCurveA0, WeierstrassCurveAM3};
+use super::{affine, projective};
+use crate::curve::field::Field;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Width of the NAF used by the scalar multiplication
+const WNAF_WIDTH: usize = 5;
+
+/// Jacobian point with field element FE
+///
+/// Affine point associated with (X,Y,Z) : (X/Z², Y/Z³)
+///
+/// The point at infinity is represented by any (X,Y,0), and all those
+/// representations are equal. Two points are equal if they are in the same
+/// equivalence class, which is determined with 6 FieldElement multiplications.
+#[derive(Clone, Debug)]
+pub struct JacobianPoint<FE> {
+    pub x: FE,
+    pub y: FE,
+    pub z: FE,
+}
+
+impl<FE> PartialEq for JacobianPoint<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => true,
+            (false, false) => {
+                let zz1 = self.z.square();
+                let zz2 = other.z.square();
+                &self.x * &zz2 == &other.x * &zz1
+                    && &self.y * &(&zz2 * &other.z) == &other.y * &(&zz1 * &self.z)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<FE> Eq for JacobianPoint<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+}
+
+impl<FE: Field> JacobianPoint<FE> {
+    /// Returns the point at infinity
+    pub fn infinity() -> Self {
+        JacobianPoint {
+            x: FE::one(),
+            y: FE::one(),
+            z: FE::zero(),
+        }
+    }
+
+    /// Check if a point is at infinity
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn from_affine(p: &affine::Point<FE>) -> Self {
+        JacobianPoint {
+            x: p.x.clone(),
+            y: p.y.clone(),
+            z: FE::one(),
+        }
+    }
+}
+
+impl<FE> JacobianPoint<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// Convert a projective point (X/Z, Y/Z) to (XZ, YZ², Z)
+    pub fn from_projective(p: &projective::Point<FE>) -> Self {
+        if p.z.is_zero() {
+            return Self::infinity();
+        }
+        JacobianPoint {
+            x: &p.x * &p.z,
+            y: &p.y * &p.z.square(),
+            z: p.z.clone(),
+        }
+    }
+
+    /// Convert to the projective point (XZ, Y, Z³)
+    pub fn to_projective(&self) -> projective::Point<FE> {
+        if self.is_infinity() {
+            return projective::Point::infinity();
+        }
+        projective::Point {
+            x: &self.x * &self.z,
+            y: self.y.clone(),
+            z: self.z.cube(),
+        }
+    }
+
+    pub fn to_affine(&self) -> Option<affine::Point<FE>> {
+        if self.is_infinity() {
+            return None;
+        }
+        let zinv = self.z.inverse();
+        let zinv2 = zinv.square();
+        Some(affine::Point {
+            x: &self.x * &zinv2,
+            y: &self.y * &(&zinv2 * &zinv),
+        })
+    }
+
+    /// Double the point, for any curve (dbl-2007-bl)
+    ///
+    /// The point at infinity doubles to a point with Z = 0
+    pub fn double<C: WeierstrassCurve<FieldElement = FE>>(&self, curve: C) -> Self {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let yyyy = yy.square();
+        let zz = self.z.square();
+        let s = (&(&self.x + &yy).square() - &(&xx + &yyyy)).double();
+        let m = &(&xx.double() + &xx) + &(curve.a() * &zz.square());
+        let x3 = &m.square() - &s.double();
+        let y3 = &(&m * &(&s - &x3)) - &yyyy.double().double().double();
+        let z3 = &(&self.y + &self.z).square() - &(&yy + &zz);
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Double the point, for curves with a = 0 (dbl-2009-l)
+    pub fn double_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        _curve: C,
+    ) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = (&(&self.x + &b).square() - &(&a + &c)).double();
+        let e = &a.double() + &a;
+        let f = e.square();
+        let x3 = &f - &d.double();
+        let y3 = &(&e * &(&d - &x3)) - &c.double().double().double();
+        let z3 = (&self.y * &self.z).double();
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Double the point, for curves with a = -3 (dbl-2001-b)
+    pub fn double_am3<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveAM3>(
+        &self,
+        _curve: C,
+    ) -> Self {
+        let delta = self.z.square();
+        let gamma = self.y.square();
+        let beta = &self.x * &gamma;
+        let t = &(&self.x - &delta) * &(&self.x + &delta);
+        let alpha = &t.double() + &t;
+        let beta4 = beta.double().double();
+        let x3 = &alpha.square() - &beta4.double();
+        let z3 = &(&self.y + &self.z).square() - &(&gamma + &delta);
+        let y3 = &(&alpha * &(&beta4 - &x3)) - &gamma.square().double().double().double();
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Add two points (add-2007-bl), with any of the operands possibly the point at infinity
+    ///
+    /// When the points are equal, the result is `self.double(curve)`
+    pub fn add<C: WeierstrassCurve<FieldElement = FE>>(&self, other: &Self, curve: C) -> Self {
+        self.add_with(other, |p| p.double(curve))
+    }
+
+    /// Add an affine point (madd-2007-bl), with self possibly the point at infinity
+    ///
+    /// When the points are equal, the result is `self.double(curve)`
+    pub fn add_affine<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        other: &affine::Point<FE>,
+        curve: C,
+    ) -> Self {
+        self.add_affine_with(other, |p| p.double(curve))
+    }
+
+    fn add_with<Dbl: Fn(&Self) -> Self>(&self, other: &Self, dbl: Dbl) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &self.y * &(&other.z * &z2z2);
+        let s2 = &other.y * &(&self.z * &z1z1);
+        let h = &u2 - &u1;
+        let r = (&s2 - &s1).double();
+        if h.is_zero() {
+            // same X: either the same point, or opposite points
+            return if r.is_zero() {
+                dbl(self)
+            } else {
+                Self::infinity()
+            };
+        }
+        let i = h.double().square();
+        let j = &h * &i;
+        let v = &u1 * &i;
+        let x3 = &(&r.square() - &j) - &v.double();
+        let y3 = &(&r * &(&v - &x3)) - &(&s1 * &j).double();
+        let z3 = &(&(&self.z + &other.z).square() - &(&z1z1 + &z2z2)) * &h;
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    fn add_affine_with<Dbl: Fn(&Self) -> Self>(&self, other: &affine::Point<FE>, dbl: Dbl) -> Self {
+        if self.is_infinity() {
+            return Self::from_affine(other);
+        }
+        let z1z1 = self.z.square();
+        let u2 = &other.x * &z1z1;
+        let s2 = &other.y * &(&self.z * &z1z1);
+        let h = &u2 - &self.x;
+        let r = (&s2 - &self.y).double();
+        if h.is_zero() {
+            return if r.is_zero() {
+                dbl(self)
+            } else {
+                Self::infinity()
+            };
+        }
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = &h * &i;
+        let v = &self.x * &i;
+        let x3 = &(&r.square() - &j) - &v.double();
+        let y3 = &(&r * &(&v - &x3)) - &(&self.y * &j).double();
+        let z3 = &(&self.z + &h).square() - &(&z1z1 + &hh);
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // add an entry of a co-Z table (add-2007-bl with the Z², Z³ of the entry cached)
+    fn add_coz_with<Dbl: Fn(&Self) -> Self>(
+        &self,
+        table: &CoZTable<FE>,
+        index: usize,
+        negate: bool,
+        dbl: Dbl,
+    ) -> Self {
+        let (x2, y2) = &table.xy[index];
+        let y2 = if negate { -y2.clone() } else { y2.clone() };
+        if self.is_infinity() {
+            return JacobianPoint {
+                x: x2.clone(),
+                y: y2,
+                z: table.z.clone(),
+            };
+        }
+        let z1z1 = self.z.square();
+        let u1 = &self.x * &table.zz;
+        let u2 = x2 * &z1z1;
+        let s1 = &self.y * &table.zzz;
+        let s2 = &y2 * &(&self.z * &z1z1);
+        let h = &u2 - &u1;
+        let r = (&s2 - &s1).double();
+        if h.is_zero() {
+            return if r.is_zero() {
+                dbl(self)
+            } else {
+                Self::infinity()
+            };
+        }
+        let i = h.double().square();
+        let j = &h * &i;
+        let v = &u1 * &i;
+        let x3 = &(&r.square() - &j) - &v.double();
+        let y3 = &(&r * &(&v - &x3)) - &(&s1 * &j).double();
+        let z3 = (&(&self.z * &table.z) * &h).double();
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// scalar multiplication : `n * self` with the width-5 NAF of n, where n is in big endian
+    ///
+    /// The odd multiples P, 3P, .., 15P are precomputed in a `CoZTable`, then each
+    /// non zero digit adds or subtracts one of them. The table falls back to
+    /// general additions for the (small order) points it can't be built for.
+    fn scalar_mul_vartime<Dbl: Fn(&Self) -> Self>(&self, n: &[u8], dbl: Dbl) -> Self {
+        let table = match CoZTable::<FE>::build(self, 1 << (WNAF_WIDTH - 2), &dbl) {
+            None => return self.scalar_mul_vartime_plain(n, dbl),
+            Some(table) => table,
+        };
+
+        let mut q = Self::infinity();
+        for d in wnaf_digits(n, WNAF_WIDTH).iter().rev() {
+            q = dbl(&q);
+            if *d > 0 {
+                q = q.add_coz_with(&table, (*d as usize - 1) / 2, false, &dbl);
+            } else if *d < 0 {
+                q = q.add_coz_with(&table, ((-*d) as usize - 1) / 2, true, &dbl);
+            }
+        }
+        q
+    }
+
+    // same as `scalar_mul_vartime` with a table of jacobian points of different Z
+    fn scalar_mul_vartime_plain<Dbl: Fn(&Self) -> Self>(&self, n: &[u8], dbl: Dbl) -> Self {
+        let p2 = dbl(self);
+        let mut table = vec![self.clone()];
+        for i in 1..(1 << (WNAF_WIDTH - 2)) {
+            let next = table[i - 1].add_with(&p2, &dbl);
+            table.push(next);
+        }
+
+        let mut q = Self::infinity();
+        for d in wnaf_digits(n, WNAF_WIDTH).iter().rev() {
+            q = dbl(&q);
+            if *d > 0 {
+                q = q.add_with(&table[(*d as usize - 1) / 2], &dbl);
+            } else if *d < 0 {
+                q = q.add_with(&-table[((-*d) as usize - 1) / 2].clone(), &dbl);
+            }
+        }
+        q
+    }
+
+    /// double scalar multiplication : `a * self + b * other` with the joint sparse form
+    /// of (a, b), where a and b are in big endian
+    ///
+    /// P+Q and P-Q are precomputed, then every non zero column of the JSF adds one
+    /// of ±P, ±Q, ±(P+Q) or ±(P-Q), with a single chain of doublings for both scalars.
+    fn double_scalar_mul_vartime<Dbl: Fn(&Self) -> Self>(
+        &self,
+        a: &[u8],
+        other: &Self,
+        b: &[u8],
+        dbl: Dbl,
+    ) -> Self {
+        let sum = self.add_with(other, &dbl);
+        let diff = self.add_with(&-other.clone(), &dbl);
+
+        let mut q = Self::infinity();
+        for (u, v) in jsf(a, b).iter().rev() {
+            q = dbl(&q);
+            let t = match (*u, *v) {
+                (0, 0) => continue,
+                (_, 0) => self,
+                (0, _) => other,
+                (u, v) if u == v => &sum,
+                _ => &diff,
+            };
+            // the sign of the column is the sign of its first non zero digit
+            if *u < 0 || (*u == 0 && *v < 0) {
+                q = q.add_with(&-t.clone(), &dbl);
+            } else {
+                q = q.add_with(t, &dbl);
+            }
+        }
+        q
+    }
+
+    /// double scalar multiplication : `a * self + b * other`, where a and b are in big
+    /// endian, for any curve
+    ///
+    /// This is variable time, and only meant for the scalars and points that are
+    /// public, e.g. for signature verification.
+    pub fn double_scale_vartime<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        a: &[u8],
+        other: &Self,
+        b: &[u8],
+        curve: C,
+    ) -> Self {
+        self.double_scalar_mul_vartime(a, other, b, |p| p.double(curve))
+    }
+
+    /// Same as `double_scale_vartime`, with the doubling for curves with a = 0
+    pub fn double_scale_vartime_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        a: &[u8],
+        other: &Self,
+        b: &[u8],
+        curve: C,
+    ) -> Self {
+        self.double_scalar_mul_vartime(a, other, b, |p| p.double_a0(curve))
+    }
+
+    /// Same as `double_scale_vartime`, with the doubling for curves with a = -3
+    pub fn double_scale_vartime_am3<
+        C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveAM3,
+    >(
+        &self,
+        a: &[u8],
+        other: &Self,
+        b: &[u8],
+        curve: C,
+    ) -> Self {
+        self.double_scalar_mul_vartime(a, other, b, |p| p.double_am3(curve))
+    }
+
+    /// Same as `scale_vartime`, without the co-Z table (kept for benchmarks and cross checks)
+    #[doc(hidden)]
+    pub fn scale_vartime_plain<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scalar_mul_vartime_plain(n, |p| p.double(curve))
+    }
+
+    /// scalar multiplication : `n * self`, where n is in big endian, for any curve
+    ///
+    /// This is variable time, and only meant for the scalars and points that are public
+    pub fn scale_vartime<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scalar_mul_vartime(n, |p| p.double(curve))
+    }
+
+    /// Same as `scale_vartime`, with the doubling for curves with a = 0
+    pub fn scale_vartime_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scalar_mul_vartime(n, |p| p.double_a0(curve))
+    }
+
+    /// Same as `scale_vartime`, with the doubling for curves with a = -3
+    pub fn scale_vartime_am3<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveAM3>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scalar_mul_vartime(n, |p| p.double_am3(curve))
+    }
+}
+
+/// Joint sparse form of the big endian a and b, least significant column first
+///
+/// The digits of each column are in {-1, 0, 1}, with a = sum(u_i 2^i) and
+/// b = sum(v_i 2^i) for the columns (u_i, v_i). Among the signed binary representations
+/// of the pair, this is the one with the fewest non zero columns, about half of them
+/// (J. Solinas, "Low-Weight Binary Representations for Pairs of Integers", 2001).
+///
+/// The number of columns is at most one more than the number of bits of the larger
+/// of a and b, and the digits are variable time.
+pub fn jsf(a: &[u8], b: &[u8]) -> Vec<(i8, i8)> {
+    // little endian bytes, of the same length
+    let len = a.len().max(b.len());
+    let le = |n: &[u8]| {
+        let mut v: Vec<u8> = n.iter().rev().copied().collect();
+        v.resize(len, 0);
+        v
+    };
+    let mut k = [le(a), le(b)];
+    let mut d = [0u8; 2];
+
+    let mut digits = Vec::with_capacity(len * 8 + 1);
+    while d != [0, 0] || k.iter().any(|k| k.iter().any(|l| *l != 0)) {
+        // l = d + k modulo 8
+        let l = [
+            k[0][0].wrapping_add(d[0]) & 7,
+            k[1][0].wrapping_add(d[1]) & 7,
+        ];
+        let mut u = [0i8; 2];
+        for i in 0..2 {
+            if l[i] & 1 == 1 {
+                u[i] = if l[i] & 3 == 1 { 1 } else { -1 };
+                if (l[i] == 3 || l[i] == 5) && l[1 - i] & 3 == 2 {
+                    u[i] = -u[i];
+                }
+            }
+        }
+        for i in 0..2 {
+            if 2 * d[i] as i8 == 1 + u[i] {
+                d[i] = 1 - d[i];
+            }
+            // k >>= 1
+            for j in 0..len {
+                let high = k[i].get(j + 1).map_or(0, |h| h & 1);
+                k[i][j] = (k[i][j] >> 1) | (high << 7);
+            }
+        }
+        digits.push((u[0], u[1]));
+    }
+    digits
+}
+
+impl<FE> Neg for JacobianPoint<FE>
+where
+    FE: Neg<Output = FE>,
+{
+    type Output = JacobianPoint<FE>;
+
+    fn neg(self) -> Self::Output {
+        JacobianPoint {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+/// Odd multiples P, 3P, 5P, .. of a point, all sharing the same Z coordinate
+///
+/// The table is built without any inversion: 2P is taken as an affine point of an
+/// isomorphic curve (scaled by its Z), so each next multiple is a mixed addition
+/// whose Z is a known ratio of the previous one, and all the entries are then
+/// brought to the Z of the last one. Adding an entry of the table to a point reuses
+/// the cached Z² and Z³, which saves a squaring and two multiplications over a
+/// general addition.
+///
+/// Like everything else in this module, this is variable time.
+#[derive(Clone, Debug)]
+pub struct CoZTable<FE> {
+    xy: Vec<(FE, FE)>,
+    z: FE,
+    zz: FE,
+    zzz: FE,
+}
+
+impl<FE> CoZTable<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// Table of the `len` odd multiples P, 3P, .., (2len-1)P
+    ///
+    /// Returns None for the point at infinity and for the points of small order
+    /// where one of the multiples is ±2P or the point at infinity
+    pub fn new<C: WeierstrassCurve<FieldElement = FE>>(
+        p: &JacobianPoint<FE>,
+        len: usize,
+        curve: C,
+    ) -> Option<Self> {
+        Self::build(p, len, |p| p.double(curve))
+    }
+
+    fn build<Dbl: Fn(&JacobianPoint<FE>) -> JacobianPoint<FE>>(
+        p: &JacobianPoint<FE>,
+        len: usize,
+        dbl: Dbl,
+    ) -> Option<Self> {
+        assert!(len > 0);
+        let d = dbl(p);
+        if p.is_infinity() || d.is_infinity() {
+            return None;
+        }
+        // on the curve isomorphic by u = Z(2P), 2P is the affine (X(2P), Y(2P))
+        // and P is (X u², Y u³, Z)
+        let uu = d.z.square();
+        let mut x = &p.x * &uu;
+        let mut y = &p.y * &(&uu * &d.z);
+        let mut z = p.z.clone();
+
+        // each entry with the ratio of its Z over the Z of the previous one
+        let mut entries = Vec::with_capacity(len);
+        for _ in 1..len {
+            // madd-2007-bl, where Z3 = 2 Z1 H
+            let z1z1 = z.square();
+            let u2 = &d.x * &z1z1;
+            let s2 = &d.y * &(&z * &z1z1);
+            let h = &u2 - &x;
+            if h.is_zero() {
+                return None;
+            }
+            let r = (&s2 - &y).double();
+            let i = h.double().square();
+            let j = &h * &i;
+            let v = &x * &i;
+            let x3 = &(&r.square() - &j) - &v.double();
+            let y3 = &(&r * &(&v - &x3)) - &(&y * &j).double();
+            let ratio = h.double();
+            z = &z * &ratio;
+            entries.push((x, y, ratio));
+            x = x3;
+            y = y3;
+        }
+
+        // bring every entry to the Z of the last one
+        let mut xy = Vec::with_capacity(len);
+        xy.push((x, y));
+        let mut scale = FE::one();
+        for (x, y, ratio) in entries.into_iter().rev() {
+            scale = &scale * &ratio;
+            let ss = scale.square();
+            xy.push((&x * &ss, &y * &(&ss * &scale)));
+        }
+        xy.reverse();
+
+        // back on the original curve, the shared Z is multiplied by u
+        let z = &z * &d.z;
+        let zz = z.square();
+        let zzz = &zz * &z;
+        Some(CoZTable { xy, z, zz, zzz })
+    }
+
+    /// Number of entries of the table
+    pub fn len(&self) -> usize {
+        self.xy.len()
+    }
+
+    /// Check if the table is empty, which never happens for a built table
+    pub fn is_empty(&self) -> bool {
+        self.xy.is_empty()
+    }
+
+    /// The entry i of the table, (2i+1)P
+    pub fn get(&self, i: usize) -> JacobianPoint<FE> {
+        let (x, y) = &self.xy[i];
+        JacobianPoint {
+            x: x.clone(),
+            y: y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    /// Add the entry i of the table, (2i+1)P, to a point
+    pub fn add_to<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        q: &JacobianPoint<FE>,
+        i: usize,
+        curve: C,
+    ) -> JacobianPoint<FE> {
+        q.add_coz_with(self, i, false, |p| p.double(curve))
+    }
+}
@@ -4,10 +4,19 @@
 //! * field: Field abstractions
 //! * affine: Affine point on short weierstrass curve
 //! * projective: Projective point on short weierstrass curve
+//! * jacobian: Jacobian point on short weierstrass curve, for variable time operations
 //! * weierstrass: Abstraction for short weierstrass curve
+//! * montgomery: Montgomery curve, x-only point, and the maps to the other forms
+//! * edwards: Twisted Edwards curve and extended point
+//! * tagged: Bytes representation tagged with their curve
+//! * elliptic: Uniform interface over the curves, for the code generic over the curve
+//! * sec1: Validation policy and errors of the SEC1 encodings of the points
+//! * poly: Polynomials over a prime field, for threshold schemes
+//! * fault: Hardened entry points against fault attacks (with the `fault-hardening` feature)
 //!
 //! For implementation of specific curve:
 //! * sec2 (e.g. p192r1, p5p256k1, p256k1, p384r1, p521r1)
+//! * sm2 (sm2p256v1 of the chinese standard GB/T 32918)
 
 #[cfg(any(
     feature = "p112r1",
@@ -17,17 +26,82 @@
     feature = "p160k1",
     feature = "p160r1",
     feature = "p160r2",
+    all(test, feature = "num-bigint", feature = "num-traits"),
+    all(feature = "fuzzing", feature = "p192r1"),
 ))]
 pub(crate) mod bigint; // module used for compat and naive implementations
 
 pub(crate) mod fiat;
 
 pub mod affine;
+pub mod clamp;
+pub mod edwards;
+pub mod elliptic;
+#[cfg(feature = "fault-hardening")]
+pub mod fault;
 pub mod field;
+pub mod jacobian;
+pub mod montgomery;
+pub mod poly;
 pub mod projective;
+pub mod sec1;
+pub mod tagged;
 pub mod weierstrass;
 
+pub use elliptic::EllipticCurve;
 pub use field::Sign;
+pub use projective::MulAlgorithm;
+
+/// Implementation of the base field arithmetic of a curve, as reported by `Curve::backend()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Fiat generated code vendored in this crate, on 64 bits limbs
+    Fiat64,
+    /// Fiat generated code of the `fiat-crypto` crate, on 64 bits limbs
+    FiatCrypto64,
+    /// Generic Montgomery arithmetic of this crate, on 64 bits limbs, for the fields
+    /// without fiat generated code
+    Montgomery64,
+    /// Arbitrary precision integers of the `num-bigint` crate
+    BigInt,
+}
+
+impl Backend {
+    /// Size in bits of the limbs of the field elements, none for the arbitrary precision integers
+    pub const fn limb_bits(self) -> Option<u32> {
+        match self {
+            Backend::Fiat64 | Backend::FiatCrypto64 | Backend::Montgomery64 => Some(64),
+            Backend::BigInt => None,
+        }
+    }
+}
+
+/// Number of bytes over the size of a scalar accepted by the `scale_bytes` functions
+/// without a debug assertion, which leaves room for some zero padding
+pub const SCALE_BYTES_SLACK: usize = 8;
+
+// the `scale_bytes` length check: a longer input is almost always the scalar of
+// another curve, which would be silently used as a much larger integer
+#[doc(hidden)]
+#[inline]
+pub fn check_scale_bytes_len(len: usize, scalar_size: usize) {
+    debug_assert!(
+        len <= scalar_size + SCALE_BYTES_SLACK,
+        "scale_bytes: {} bytes for a scalar of {} bytes",
+        len,
+        scalar_size
+    );
+    #[cfg(feature = "paranoid")]
+    debug_assert!(
+        len <= scalar_size,
+        "scale_bytes (paranoid): {} bytes for a scalar of {} bytes",
+        len,
+        scalar_size
+    );
+}
 
 // exports the SEC2 curves
 pub mod sec2;
+
+#[cfg(feature = "sm2")]
+pub mod sm2;
@@ -0,0 +1,277 @@
+//! Montgomery curves, the x-only Montgomery ladder, and the birational maps
+//! to the twisted Edwards and short Weierstrass forms
+//!
+//! All Montgomery curves are defined as B*v^{2} = u^{3} + A*u^{2} + u, with B(A^{2} - 4) non zero.
+//!
+//! The curve is birationally equivalent to:
+//!
+//! * the twisted Edwards curve a*x^{2} + y^{2} = 1 + d*x^{2}*y^{2} with a = (A+2)/B and d = (A-2)/B,
+//!   with (x, y) = (u/v, (u-1)/(u+1)) and (u, v) = ((1+y)/(1-y), u/x)
+//! * the short Weierstrass curve y^{2} = x^{3} + a*x + b with a = (3-A^{2})/(3B^{2})
+//!   and b = (2A^{3}-9A)/(27B^{3}), with (x, y) = (u/B + A/3B, v/B) and (u, v) = (B*x - A/3, B*y)
+//!
+//! The Weierstrass map is an isomorphism of the groups, and the Edwards map is
+//! too apart from its exceptional points: the points of order 2 (v = 0) and the points
+//! with u = -1 on the Montgomery side, and the points with x = 0 on the Edwards side.
+
+use super::affine;
+use super::edwards::EdwardsPoint;
+use super::field::{Field, PrimeField};
+use crate::mp::ct::{Choice, CtSelect};
+use core::ops::{Add, Mul, Sub};
+
+/// Montgomery curves are defined as B*v^{2} = u^{3} + A*u^{2} + u
+pub trait MontgomeryCurve: Copy + Clone {
+    type FieldElement;
+
+    // Montgomery A parameter
+    fn a(self) -> &'static Self::FieldElement;
+    // Montgomery B parameter
+    fn b(self) -> &'static Self::FieldElement;
+    // (A+2)/4, used by the ladder doubling
+    fn a24(self) -> &'static Self::FieldElement;
+}
+
+/// Affine point (u, v) on a Montgomery curve
+///
+/// Note that this representation cannot handle the point at infinity
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryAffine<FE> {
+    pub u: FE,
+    pub v: FE,
+}
+
+/// x-only point in projective coordinate (X:Z), associated with u = X/Z
+///
+/// A point and its opposite have the same representation. The point at infinity
+/// is represented by (X:0) with X non zero.
+#[derive(Clone, Debug)]
+pub struct MontgomeryPoint<FE> {
+    pub x: FE,
+    pub z: FE,
+}
+
+impl<FE> MontgomeryAffine<FE>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+    /// Try to create a point with U, V coordinates
+    ///
+    /// check if the equation B*v^2 = u^3 + A*u^2 + u holds for this curve, if it doesn't
+    /// None is returned
+    pub fn from_coordinate<C: MontgomeryCurve<FieldElement = FE>>(
+        u: &FE,
+        v: &FE,
+        curve: C,
+    ) -> Option<Self> {
+        let uu = u.square();
+        let rhs = &(&(&uu * u) + &(curve.a() * &uu)) + u;
+        if curve.b() * &v.square() == rhs {
+            Some(MontgomeryAffine {
+                u: u.clone(),
+                v: v.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Forget the V coordinate
+    pub fn to_x_only(&self) -> MontgomeryPoint<FE> {
+        MontgomeryPoint::from_u(&self.u)
+    }
+}
+
+impl<FE: Field> MontgomeryPoint<FE> {
+    /// Returns the point at infinity
+    pub fn infinity() -> Self {
+        MontgomeryPoint {
+            x: FE::one(),
+            z: FE::zero(),
+        }
+    }
+
+    /// Create the x-only point (u:1)
+    pub fn from_u(u: &FE) -> Self {
+        MontgomeryPoint {
+            x: u.clone(),
+            z: FE::one(),
+        }
+    }
+}
+
+impl<FE> MontgomeryPoint<FE>
+where
+    FE: Field + CtSelect,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// Return the u coordinate X/Z, or None for the point at infinity
+    pub fn to_u(&self) -> Option<FE> {
+        if self.z.is_zero() {
+            None
+        } else {
+            Some(&self.x * &self.z.inverse())
+        }
+    }
+
+    /// scalar multiplication : `n * self` with the x-only Montgomery ladder, where n is in big endian
+    ///
+    /// The sequence of operations only depends on the length of n, not on its value.
+    /// The differential addition needs a base point that is neither the point at infinity
+    /// nor the point (0,0) of order 2, which are mapped to an invalid (0:0) result.
+    pub fn ladder<C: MontgomeryCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Self {
+        let mut r0 = Self::infinity();
+        let mut r1 = self.clone();
+
+        for digit in n.iter() {
+            for i in (0..8).rev() {
+                let bit = Choice::from_bit(((digit >> i) & 1) as u64);
+                // (r0, r1) = bit ? (r0+r1, 2r1) : (2r0, r0+r1)
+                let (a, b) = (
+                    Self::ct_select(&r0, &r1, bit),
+                    Self::ct_select(&r1, &r0, bit),
+                );
+                let sum = a.differential_add(&b, self);
+                let doubled = a.double(curve);
+                r0 = Self::ct_select(&doubled, &sum, bit);
+                r1 = Self::ct_select(&sum, &doubled, bit);
+            }
+        }
+        r0
+    }
+
+    // X2 = (X+Z)^2 (X-Z)^2 and Z2 = 4XZ ((X-Z)^2 + a24 4XZ), with 4XZ = (X+Z)^2 - (X-Z)^2
+    fn double<C: MontgomeryCurve<FieldElement = FE>>(&self, curve: C) -> Self {
+        let s = (&self.x + &self.z).square();
+        let d = (&self.x - &self.z).square();
+        let e = &s - &d;
+        MontgomeryPoint {
+            x: &s * &d,
+            z: &e * &(&d + &(curve.a24() * &e)),
+        }
+    }
+
+    // P+Q given P, Q and P-Q:
+    // X = Z(P-Q) ((XP-ZP)(XQ+ZQ) + (XP+ZP)(XQ-ZQ))^2
+    // Z = X(P-Q) ((XP-ZP)(XQ+ZQ) - (XP+ZP)(XQ-ZQ))^2
+    fn differential_add(&self, other: &Self, difference: &Self) -> Self {
+        let da = &(&self.x - &self.z) * &(&other.x + &other.z);
+        let cb = &(&self.x + &self.z) * &(&other.x - &other.z);
+        MontgomeryPoint {
+            x: &difference.z * &(&da + &cb).square(),
+            z: &difference.x * &(&da - &cb).square(),
+        }
+    }
+}
+
+impl<FE: CtSelect> CtSelect for MontgomeryPoint<FE> {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        MontgomeryPoint {
+            x: FE::ct_select(&a.x, &b.x, choice),
+            z: FE::ct_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Compute the twisted Edwards (a, d) parameters of the Montgomery curve (A, B)
+pub fn edwards_parameters<FE>(a: &FE, b: &FE) -> (FE, FE)
+where
+    FE: PrimeField,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    let two = FE::from_u64(2);
+    let binv = b.inverse();
+    (&(a + &two) * &binv, &(a - &two) * &binv)
+}
+
+/// Compute the short Weierstrass (a, b) parameters of the Montgomery curve (A, B)
+pub fn weierstrass_parameters<FE>(a: &FE, b: &FE) -> (FE, FE)
+where
+    FE: PrimeField,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    let aa = a.square();
+    let bb = b.square();
+    let wa = &(FE::from_u64(3) - &aa) * &(FE::from_u64(3) * &bb).inverse();
+    let wb = &(&(&aa * a).double() - &(FE::from_u64(9) * a))
+        * &(FE::from_u64(27) * &(&bb * b)).inverse();
+    (wa, wb)
+}
+
+/// Map a Montgomery point to the equivalent short Weierstrass point
+pub fn to_weierstrass<FE, C>(p: &MontgomeryAffine<FE>, curve: C) -> affine::Point<FE>
+where
+    FE: PrimeField,
+    C: MontgomeryCurve<FieldElement = FE>,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+    let binv = curve.b().inverse();
+    let a3 = curve.a() * &FE::from_u64(3).inverse();
+    affine::Point {
+        x: &(&p.u + &a3) * &binv,
+        y: &p.v * &binv,
+    }
+}
+
+/// Map a short Weierstrass point to the equivalent Montgomery point
+pub fn from_weierstrass<FE, C>(p: &affine::Point<FE>, curve: C) -> MontgomeryAffine<FE>
+where
+    FE: PrimeField,
+    C: MontgomeryCurve<FieldElement = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    let a3 = curve.a() * &FE::from_u64(3).inverse();
+    MontgomeryAffine {
+        u: &(curve.b() * &p.x) - &a3,
+        v: curve.b() * &p.y,
+    }
+}
+
+/// Map a Montgomery point to the equivalent twisted Edwards point
+///
+/// None is returned for the exceptional points, which have v = 0 or u = -1
+pub fn to_edwards<FE>(p: &MontgomeryAffine<FE>) -> Option<EdwardsPoint<FE>>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    let u1 = &p.u + &FE::one();
+    if p.v.is_zero() || u1.is_zero() {
+        return None;
+    }
+    let x = &p.u * &p.v.inverse();
+    let y = &(&p.u - &FE::one()) * &u1.inverse();
+    Some(EdwardsPoint::<FE>::from_affine(&x, &y))
+}
+
+/// Map a twisted Edwards point to the equivalent Montgomery point
+///
+/// None is returned for the exceptional points, which have x = 0 (including the neutral element)
+pub fn from_edwards<FE>(p: &EdwardsPoint<FE>) -> Option<MontgomeryAffine<FE>>
+where
+    FE: Field,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    let (x, y) = p.to_affine()?;
+    let y1 = &FE::one() - &y;
+    if x.is_zero() || y1.is_zero() {
+        return None;
+    }
+    let u = &(&FE::one() + &y) * &y1.inverse();
+    let v = &u * &x.inverse();
+    Some(MontgomeryAffine { u, v })
+}
@@ -0,0 +1,309 @@
+//! Polynomials over a prime field, for threshold schemes
+//!
+//! Provide the building blocks of Shamir secret sharing and FROST-style distributed
+//! key generation over the scalar field of a curve:
+//!
+//! * `Polynomial`: evaluation of `a0 + a1.x + ... + at.x^t`, where a0 is the secret
+//! * `lagrange_coefficient`: the coefficient of the share i when interpolating at 0
+//! * `lagrange_interpolate`: the value at 0 of the polynomial going through the points
+//!
+//! The shares are the evaluations at non zero x (typically 1, 2, ..., n), since
+//! the evaluation at 0 is the secret itself.
+//!
+//! ```
+//! use eccoxide::curve::poly::{lagrange_interpolate, Polynomial};
+//! use eccoxide::curve::sec2::p256k1::Scalar;
+//!
+//! let secret = Scalar::from_u64(1234);
+//! let poly = Polynomial::from_coefficients(vec![secret.clone(), Scalar::from_u64(5), Scalar::from_u64(7)]);
+//!
+//! // 3 shares out of 5 are enough to recover a degree 2 polynomial
+//! let shares: Vec<_> = (1..=5)
+//!     .map(|i| (Scalar::from_u64(i), poly.eval(&Scalar::from_u64(i))))
+//!     .collect();
+//! assert_eq!(lagrange_interpolate(&shares[1..4]), Some(secret));
+//! ```
+
+use super::field::PrimeField;
+
+/// Polynomial `a0 + a1.x + ... + at.x^t` over the prime field F
+///
+/// The coefficients are secret (a0 is the shared secret, and the others give it away
+/// with the shares), so the Debug output only shows the degree.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Polynomial<F> {
+    coefficients: Vec<F>,
+}
+
+impl<F> std::fmt::Debug for Polynomial<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("degree", &(self.coefficients.len() - 1))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: PrimeField> Polynomial<F> {
+    /// Create a polynomial from its coefficients, starting from the constant term
+    ///
+    /// Panics if there's no coefficient
+    pub fn from_coefficients(coefficients: Vec<F>) -> Self {
+        assert!(!coefficients.is_empty(), "polynomial without coefficient");
+        Polynomial { coefficients }
+    }
+
+    /// Create a random polynomial of the given degree
+    ///
+    /// All the coefficients, including the constant term, are uniformly distributed
+    #[cfg(feature = "rand_core")]
+    pub fn random<R: rand_core::RngCore + rand_core::CryptoRng>(
+        degree: usize,
+        rng: &mut R,
+    ) -> Self {
+        let mut bytes = vec![0u8; F::SIZE_BYTES];
+        let coefficients = (0..=degree)
+            .map(|_| loop {
+                rng.fill_bytes(&mut bytes);
+                bytes[0] &= 0xff >> (F::SIZE_BYTES * 8 - F::SIZE_BITS);
+                if let Some(e) = F::from_slice(&bytes) {
+                    break e;
+                }
+            })
+            .collect();
+        Polynomial { coefficients }
+    }
+
+    /// Create a random polynomial of the given degree with the secret as constant term
+    #[cfg(feature = "rand_core")]
+    pub fn random_with_secret<R: rand_core::RngCore + rand_core::CryptoRng>(
+        secret: F,
+        degree: usize,
+        rng: &mut R,
+    ) -> Self {
+        let mut p = Self::random(degree, rng);
+        p.coefficients[0] = secret;
+        p
+    }
+
+    /// Degree of the polynomial, which is the number of coefficients minus 1
+    ///
+    /// Note that the leading coefficients are not checked for zero
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Coefficients of the polynomial, starting from the constant term
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Constant term of the polynomial, which is the secret being shared
+    pub fn constant_term(&self) -> &F {
+        &self.coefficients[0]
+    }
+
+    /// Evaluate the polynomial at x, using the Horner method
+    pub fn eval(&self, x: &F) -> F {
+        let mut r = F::zero();
+        for c in self.coefficients.iter().rev() {
+            r = r * x + c;
+        }
+        r
+    }
+}
+
+/// Lagrange coefficient of the index i for interpolating at 0 from the x coordinates
+///
+/// This is the product of `x_j / (x_j - x_i)` for all j different of i, such that the value
+/// at 0 is the sum of `lagrange_coefficient(xs, i) * y_i`.
+///
+/// Panics if i is out of bounds, or if the x coordinate of index i is duplicated
+pub fn lagrange_coefficient<F: PrimeField>(indices: &[F], i: usize) -> F {
+    let xi = &indices[i];
+    let mut num = F::one();
+    let mut den = F::one();
+    for (j, xj) in indices.iter().enumerate() {
+        if j == i {
+            continue;
+        }
+        let diff = xj.clone() - xi;
+        assert!(!diff.is_zero(), "duplicated x coordinate");
+        num = num * xj;
+        den = den * &diff;
+    }
+    num * &den.inverse()
+}
+
+/// Value at 0 of the polynomial of degree `points.len() - 1` going through the points (x, y)
+///
+/// Returns None if there's no point, or if an x coordinate is duplicated
+pub fn lagrange_interpolate<F: PrimeField>(points: &[(F, F)]) -> Option<F> {
+    if points.is_empty() {
+        return None;
+    }
+    for (i, (xi, _)) in points.iter().enumerate() {
+        if points[i + 1..].iter().any(|(xj, _)| xj == xi) {
+            return None;
+        }
+    }
+    let xs: Vec<F> = points.iter().map(|(x, _)| x.clone()).collect();
+    let r = points
+        .iter()
+        .enumerate()
+        .fold(F::zero(), |acc, (i, (_, y))| {
+            acc + lagrange_coefficient(&xs, i) * y
+        });
+    Some(r)
+}
+
+#[cfg(all(test, feature = "p256k1"))]
+mod tests {
+    use super::*;
+    use crate::curve::sec2::p256k1::Scalar;
+
+    // deterministic polynomial with pseudo random looking coefficients
+    fn poly(degree: usize, seed: u64) -> Polynomial<Scalar> {
+        let mut c = Scalar::from_u64(seed);
+        let coefficients = (0..=degree)
+            .map(|_| {
+                c = c.square() + Scalar::one();
+                c.clone()
+            })
+            .collect();
+        Polynomial::from_coefficients(coefficients)
+    }
+
+    fn shares(p: &Polynomial<Scalar>, n: u64) -> Vec<(Scalar, Scalar)> {
+        (1..=n)
+            .map(|i| (Scalar::from_u64(i), p.eval(&Scalar::from_u64(i))))
+            .collect()
+    }
+
+    #[test]
+    fn eval() {
+        // 3 + 2x + x^2
+        let p = Polynomial::from_coefficients(vec![
+            Scalar::from_u64(3),
+            Scalar::from_u64(2),
+            Scalar::from_u64(1),
+        ]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(p.eval(&Scalar::zero()), Scalar::from_u64(3));
+        assert_eq!(p.eval(&Scalar::from_u64(1)), Scalar::from_u64(6));
+        assert_eq!(p.eval(&Scalar::from_u64(10)), Scalar::from_u64(123));
+        assert_eq!(p.eval(&-Scalar::one()), Scalar::from_u64(2));
+
+        for degree in 0..6 {
+            let p = poly(degree, degree as u64 + 1);
+            assert_eq!(&p.eval(&Scalar::zero()), p.constant_term());
+        }
+    }
+
+    #[test]
+    fn debug_redacted() {
+        let p = Polynomial::from_coefficients(vec![Scalar::from_u64(0xdeadbeef); 3]);
+        assert_eq!(format!("{:?}", p), "Polynomial { degree: 2, .. }");
+    }
+
+    #[test]
+    fn interpolate_threshold() {
+        for t in 0..6 {
+            let p = poly(t, 0x1000 + t as u64);
+            let n = t as u64 + 3;
+            let shares = shares(&p, n);
+            // every window of t+1 shares recovers the secret
+            for start in 0..(n as usize - t) {
+                let subset = &shares[start..start + t + 1];
+                assert_eq!(
+                    lagrange_interpolate(subset).as_ref(),
+                    Some(p.constant_term())
+                );
+            }
+            // all the shares too, since the polynomial is of degree t < n
+            assert_eq!(
+                lagrange_interpolate(&shares).as_ref(),
+                Some(p.constant_term())
+            );
+            // but t shares are not enough
+            if t > 0 {
+                assert_ne!(
+                    lagrange_interpolate(&shares[..t]).as_ref(),
+                    Some(p.constant_term())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_unordered() {
+        let p = poly(3, 0x2000);
+        let mut s = shares(&p, 7);
+        s.reverse();
+        s.swap(0, 4);
+        assert_eq!(
+            lagrange_interpolate(&s[1..5]).as_ref(),
+            Some(p.constant_term())
+        );
+    }
+
+    #[test]
+    fn coefficients_match_interpolation() {
+        let p = poly(4, 0x3000);
+        let s = shares(&p, 5);
+        let xs: Vec<Scalar> = s.iter().map(|(x, _)| x.clone()).collect();
+        let mut acc = Scalar::zero();
+        let mut sum = Scalar::zero();
+        for (i, (_, y)) in s.iter().enumerate() {
+            let l = lagrange_coefficient(&xs, i);
+            acc = acc + &l * y;
+            sum = sum + l;
+        }
+        assert_eq!(&acc, p.constant_term());
+        // interpolating the constant polynomial 1
+        assert_eq!(sum, Scalar::one());
+        assert_eq!(lagrange_coefficient(&xs[..1], 0), Scalar::one());
+    }
+
+    #[test]
+    fn duplicates() {
+        let p = poly(2, 0x4000);
+        let mut s = shares(&p, 3);
+        s.push(s[1].clone());
+        assert_eq!(lagrange_interpolate(&s), None);
+        // same x with a different y is also rejected
+        s[3].1 = s[3].1.clone() + Scalar::one();
+        assert_eq!(lagrange_interpolate(&s), None);
+        assert_eq!(lagrange_interpolate::<Scalar>(&[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicated x coordinate")]
+    fn coefficient_duplicates() {
+        let xs = [
+            Scalar::from_u64(1),
+            Scalar::from_u64(2),
+            Scalar::from_u64(1),
+        ];
+        lagrange_coefficient(&xs, 2);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn random() {
+        use crate::tests::rng::TestRng;
+
+        let mut rng = TestRng::new(0x9017);
+        for t in 0..5 {
+            let secret = Scalar::random(&mut rng);
+            let p = Polynomial::random_with_secret(secret.clone(), t, &mut rng);
+            assert_eq!(p.degree(), t);
+            assert_eq!(p.eval(&Scalar::zero()), secret);
+            let shares = shares(&p, t as u64 + 2);
+            assert_eq!(lagrange_interpolate(&shares[1..]), Some(secret));
+
+            let q = Polynomial::<Scalar>::random(t, &mut rng);
+            assert_ne!(q, p);
+            assert_eq!(q.coefficients().len(), t + 1);
+        }
+    }
+}
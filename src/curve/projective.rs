@@ -10,12 +10,48 @@
 //! * [NIST.SP.800-186](https://csrc.nist.gov/publications/detail/sp/800-186/draft) : Appendix D & E
 
 use super::affine;
-use super::field::Field;
+use super::field::{Field, PrimeField, Sign};
 use super::weierstrass::{WeierstrassCurve, WeierstrassCurveA0};
-use crate::mp::ct::{Choice, CtEqual};
+use crate::mp::ct::{Choice, CtEqual, CtSelect};
+use crate::mp::ct_util::{ct_index_bits, ct_table_lookup};
 use std::convert::TryFrom;
 use std::ops::{Add, Mul, Neg, Sub};
 
+/// Scalar multiplication algorithm, to select explicitly the one used
+///
+/// This is for benchmarking and cross-checking the algorithms against each other, the
+/// `Mul` operator of the curves being `Point::scale_ct` or `Point::scale_vartime`
+/// depending on the `default-mul-ct` and `default-mul-vartime` features.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MulAlgorithm {
+    /// double-and-add on the bits of the scalar, from the least significant (variable time)
+    Daa8,
+    /// Montgomery ladder, with a sequence of operations independent of the scalar value
+    Ladder,
+    /// width-w NAF with precomputed odd multiples (variable time), w between 2 and 8
+    Wnaf { width: usize },
+}
+
+impl MulAlgorithm {
+    /// Algorithm of the `Mul` operator of the curves with the `default-mul-ct` feature
+    /// (the constant time ladder), or the variable time double-and-add otherwise
+    #[cfg(feature = "default-mul-ct")]
+    pub const DEFAULT: MulAlgorithm = MulAlgorithm::Ladder;
+
+    /// Algorithm of the `Mul` operator of the curves with the `default-mul-ct` feature
+    /// (the constant time ladder), or the variable time double-and-add otherwise
+    #[cfg(not(feature = "default-mul-ct"))]
+    pub const DEFAULT: MulAlgorithm = MulAlgorithm::Daa8;
+
+    /// All the algorithms, with the wNAF widths from 2 to 8
+    pub fn all() -> impl Iterator<Item = MulAlgorithm> {
+        [MulAlgorithm::Daa8, MulAlgorithm::Ladder]
+            .iter()
+            .copied()
+            .chain((2..=8).map(|width| MulAlgorithm::Wnaf { width }))
+    }
+}
+
 /// Projective point with field element FE
 ///
 /// Affine point associated with (X,Y,Z) : (X/Z, Y/Z)
@@ -24,6 +60,10 @@ use std::ops::{Add, Mul, Neg, Sub};
 /// which is determined with 4 FieldElement multiplications.
 ///
 /// Example: (1,2,1) and (2,4,2) are equal
+///
+/// The point at infinity is represented by (0,Y,0) with Y non zero, and all
+/// those representations are equal. (0,0,0) is not a valid projective point,
+/// and is only equal to itself.
 #[derive(Clone, Debug)]
 pub struct Point<FE> {
     pub x: FE,
@@ -42,6 +82,16 @@ where
 
 impl<FE: Field + CtEqual> Eq for Point<FE> where for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE> {}
 
+impl<FE: CtSelect> CtSelect for Point<FE> {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Point {
+            x: FE::ct_select(&a.x, &b.x, choice),
+            y: FE::ct_select(&a.y, &b.y, choice),
+            z: FE::ct_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
 impl<'y, FE: Field + CtEqual> CtEqual for Point<FE>
 where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
@@ -67,7 +117,7 @@ where
     type Error = AffineAtInfinity;
 
     fn try_from(p: Point<FE>) -> Result<affine::Point<FE>, Self::Error> {
-        p.to_affine().ok_or(AffineAtInfinity)
+        p.into_affine().ok_or(AffineAtInfinity)
     }
 }
 
@@ -77,12 +127,25 @@ where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
 {
     /// Check if a point are in the same equivalent class
+    ///
+    /// All the cross products of (0,0,0) are zero, which would make it equivalent
+    /// to any point, so it is explicitly only equivalent to itself
     fn is_equivalent<'y>(&self, other: &Point<FE>) -> Choice {
         let nx1 = &self.x * &other.z;
         let nx2 = &other.x * &self.z;
         let ny1 = &self.y * &other.z;
         let ny2 = &other.y * &self.z;
-        nx1.ct_eq(&nx2) & ny1.ct_eq(&ny2)
+        let same_validity = self
+            .is_invalid()
+            .to_mask_u64()
+            .ct_eq(&other.is_invalid().to_mask_u64());
+        nx1.ct_eq(&nx2) & ny1.ct_eq(&ny2) & same_validity
+    }
+
+    // (0,0,0) is the only representation not associated with a point
+    fn is_invalid(&self) -> Choice {
+        let zero = FE::zero();
+        self.x.ct_eq(&zero) & self.y.ct_eq(&zero) & self.z.ct_eq(&zero)
     }
 
     /// Check if a point is at infinity
@@ -111,6 +174,13 @@ where
             z: FE::one(),
         }
     }
+
+    /// Check if Z is one, X and Y being then the affine coordinates
+    ///
+    /// This is a single comparison of Z. The point at infinity is not normalized.
+    pub fn is_normalized(&self) -> bool {
+        self.z.ct_eq(&FE::one()).is_true()
+    }
 }
 
 impl<FE> Point<FE>
@@ -120,6 +190,10 @@ where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
     for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
 {
+    /// Normalize the point to Z = 1, leaving the point at infinity unchanged
+    ///
+    /// This costs a field inversion and two multiplications. As in `compress`, Z is
+    /// inverted even when it's one, so normalized points don't take a shorter path.
     pub fn normalize(&mut self) {
         if !self.z.is_zero() {
             let zinv = self.z.inverse();
@@ -129,6 +203,12 @@ where
             self.z = FE::one()
         }
     }
+
+    /// Same as `normalize`, by value
+    pub fn normalized(mut self) -> Self {
+        self.normalize();
+        self
+    }
 }
 
 impl<FE: Field> Point<FE> {
@@ -299,6 +379,11 @@ where
     for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
     for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
 {
+    /// Double the point, for any curve
+    ///
+    /// The formula is complete on prime order curves: the point at infinity
+    /// (0,Y,0) doubles to (0,Y^4,0), which is still a valid representation
+    /// of the point at infinity, so there's no special case.
     #[inline]
     pub fn double<C: WeierstrassCurve<FieldElement = FE>>(&self, curve: C) -> Self {
         // Algorithm 3 from (1) - doubling formula for arbitrary a
@@ -357,6 +442,9 @@ where
         }
     }
 
+    /// Double the point, for curves with a = 0
+    ///
+    /// Same as `double`, the point at infinity (0,Y,0) doubles to (0,Y^4,0).
     #[inline]
     pub fn double_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
         &self,
@@ -402,13 +490,11 @@ where
         }
     }
 
+    /// Affine point, None for the point at infinity
+    ///
+    /// This costs a field inversion and two multiplications. As in `compress`, Z is
+    /// inverted even when it's one, so normalized points don't take a shorter path.
     pub fn to_affine(&self) -> Option<affine::Point<FE>> {
-        if self.z == FE::one() {
-            return Some(affine::Point {
-                x: self.x.clone(),
-                y: self.y.clone(),
-            });
-        }
         if self.z.is_zero() {
             None
         } else {
@@ -420,57 +506,267 @@ where
         }
     }
 
+    /// Same as `to_affine`, but consuming the point, which multiplies the coordinates
+    /// in place instead of building new ones
+    pub fn into_affine(self) -> Option<affine::Point<FE>> {
+        if self.z.is_zero() {
+            None
+        } else {
+            let inv = self.z.inverse();
+            Some(affine::Point {
+                x: self.x * &inv,
+                y: self.y * &inv,
+            })
+        }
+    }
+
     /// scalar multiplication : `n * self` with double-and-add algorithm with increasing index
+    ///
+    /// The bits of n are least significant first
     #[inline]
-    fn scalar_mul_daa_limbs8<C: WeierstrassCurve<FieldElement = FE>>(
-        &self,
-        n: &[u8],
-        curve: C,
-    ) -> Self {
+    fn scalar_mul_daa<I, Add2, Dbl>(&self, bits: I, add: Add2, dbl: Dbl) -> Self
+    where
+        I: Iterator<Item = Choice>,
+        Add2: Fn(&Self, &Self) -> Self,
+        Dbl: Fn(&Self) -> Self,
+    {
         let mut a: Point<FE> = self.clone();
         let mut q: Point<FE> = Point::infinity();
 
-        for digit in n.iter().rev() {
-            for i in 0..8 {
-                if digit & (1 << i) != 0 {
-                    q = q.add_or_double(&a, curve);
-                }
-                a = a.double(curve)
+        for bit in bits {
+            if bit.is_true() {
+                q = add(&q, &a);
             }
+            a = dbl(&a)
         }
         q
     }
 
+    /// scalar multiplication : `n * self` with a Montgomery ladder
+    ///
+    /// The ladder does one addition and one doubling for every bit of n, and the
+    /// points are swapped with constant time selection, so the sequence of operations
+    /// only depends on the number of bits of n (most significant first), not on its value.
+    ///
+    /// This relies on the addition formula being complete (valid for doubling
+    /// and for the point at infinity), which is the case of the formulas used here.
     #[inline]
-    fn scalar_mul_daa_limbs8_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+    fn scalar_mul_ladder<I, Add2, Dbl>(&self, bits: I, add: Add2, dbl: Dbl) -> Self
+    where
+        FE: CtSelect,
+        I: Iterator<Item = Choice>,
+        Add2: Fn(&Self, &Self) -> Self,
+        Dbl: Fn(&Self) -> Self,
+    {
+        let mut r0: Point<FE> = Point::infinity();
+        let mut r1: Point<FE> = self.clone();
+
+        for bit in bits {
+            // (r0, r1) = bit ? (r0+r1, 2r1) : (2r0, r0+r1)
+            let sum = add(&r0, &r1);
+            let dbl_in = Point::ct_select(&r0, &r1, bit);
+            let doubled = dbl(&dbl_in);
+            r0 = Point::ct_select(&doubled, &sum, bit);
+            r1 = Point::ct_select(&sum, &doubled, bit);
+        }
+        r0
+    }
+
+    /// scalar multiplication : `n * self` with the width-w NAF of n
+    ///
+    /// The odd multiples P, 3P, .., (2^(w-1)-1)P are precomputed, then each
+    /// non zero signed digit adds or subtracts one of them. This is variable time,
+    /// and only meant for the scalars that are public.
+    #[inline]
+    fn scalar_mul_wnaf<Add2, Dbl>(&self, n: &[u8], width: usize, add: Add2, dbl: Dbl) -> Self
+    where
+        Add2: Fn(&Self, &Self) -> Self,
+        Dbl: Fn(&Self) -> Self,
+    {
+        let digits = wnaf_digits(n, width);
+        let p2 = dbl(self);
+        let mut table = vec![self.clone()];
+        for i in 1..(1 << (width - 2)) {
+            let next = add(&table[i - 1], &p2);
+            table.push(next);
+        }
+
+        let mut q: Point<FE> = Point::infinity();
+        for d in digits.iter().rev() {
+            q = dbl(&q);
+            if *d > 0 {
+                q = add(&q, &table[(*d as usize - 1) / 2]);
+            } else if *d < 0 {
+                q = add(&q, &-table[((-*d) as usize - 1) / 2].clone());
+            }
+        }
+        q
+    }
+
+    /// scalar multiplication : `n * self` with the width-w NAF of n, where n is in big endian
+    ///
+    /// Panics if the width is not between 2 and 8
+    pub fn scale_wnaf<C: WeierstrassCurve<FieldElement = FE>>(
         &self,
         n: &[u8],
+        width: usize,
         curve: C,
     ) -> Self {
-        let mut a: Point<FE> = self.clone();
-        let mut q: Point<FE> = Point::infinity();
+        self.scalar_mul_wnaf(
+            n,
+            width,
+            |a, b| a.add_different(b, curve),
+            |a| a.double(curve),
+        )
+    }
 
-        for digit in n.iter().rev() {
-            for i in 0..8 {
-                if digit & (1 << i) != 0 {
-                    q = q.add_or_double_a0(&a, curve);
-                }
-                a = a.double_a0(curve)
-            }
-        }
-        q
+    /// scalar multiplication : `n * self` with the width-w NAF of n, where n is in big endian
+    ///
+    /// Panics if the width is not between 2 and 8
+    pub fn scale_wnaf_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        n: &[u8],
+        width: usize,
+        curve: C,
+    ) -> Self {
+        self.scalar_mul_wnaf(
+            n,
+            width,
+            |a, b| a.add_different_a0(b, curve),
+            |a| a.double_a0(curve),
+        )
+    }
+
+    /// scalar multiplication : `n * self` with a Montgomery ladder, where n is in big endian
+    pub fn scale_ladder<C: WeierstrassCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Self
+    where
+        FE: CtSelect,
+    {
+        self.scale_ladder_bits(bytes_bits(n), curve)
+    }
+
+    /// scalar multiplication : `n * self` with a Montgomery ladder, where n is in big endian
+    pub fn scale_ladder_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self
+    where
+        FE: CtSelect,
+    {
+        self.scale_ladder_bits_a0(bytes_bits(n), curve)
     }
 
+    /// Same as `scale_ladder`, with the bits of n most significant first
+    ///
+    /// e.g. from `Scalar::iter_bits_msb_first`, which doesn't go through the bytes
+    pub(crate) fn scale_ladder_bits<C, I>(&self, bits: I, curve: C) -> Self
+    where
+        FE: CtSelect,
+        C: WeierstrassCurve<FieldElement = FE>,
+        I: Iterator<Item = Choice>,
+    {
+        self.scalar_mul_ladder(bits, |a, b| a.add_different(b, curve), |a| a.double(curve))
+    }
+
+    /// Same as `scale_ladder_a0`, with the bits of n most significant first
+    pub(crate) fn scale_ladder_bits_a0<C, I>(&self, bits: I, curve: C) -> Self
+    where
+        FE: CtSelect,
+        C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0,
+        I: Iterator<Item = Choice>,
+    {
+        self.scalar_mul_ladder(
+            bits,
+            |a, b| a.add_different_a0(b, curve),
+            |a| a.double_a0(curve),
+        )
+    }
+
+    /// Constant time scalar multiplication : `n * self`, where n is in big endian
+    ///
+    /// The sequence of field operations only depends on the length of n, not on its
+    /// value: this is `scale_ladder`, the entry point for the secret scalars.
+    pub fn scale_ct<C: WeierstrassCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Self
+    where
+        FE: CtSelect,
+    {
+        self.scale_ladder(n, curve)
+    }
+
+    /// Same as `scale_ct`, with the formulas of the curves where a = 0
+    pub fn scale_a0_ct<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self
+    where
+        FE: CtSelect,
+    {
+        self.scale_ladder_a0(n, curve)
+    }
+
+    /// Variable time scalar multiplication : `n * self`, where n is in big endian
+    ///
+    /// This double-and-add only adds for the set bits of n, so its timing depends on
+    /// the bits of the scalar: only for the public scalars, see `scale_ct` otherwise.
+    pub fn scale_vartime<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scale_bits(bytes_bits(n), curve)
+    }
+
+    /// Same as `scale_vartime`, with the formulas of the curves where a = 0
+    pub fn scale_a0_vartime<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
+        &self,
+        n: &[u8],
+        curve: C,
+    ) -> Self {
+        self.scale_bits_a0(bytes_bits(n), curve)
+    }
+
+    /// Variable time scalar multiplication, the same as `scale_vartime`
+    #[deprecated(note = "variable time, use scale_vartime or scale_ct")]
     pub fn scale<C: WeierstrassCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Self {
-        self.scalar_mul_daa_limbs8(n, curve)
+        self.scale_vartime(n, curve)
     }
 
+    /// Variable time scalar multiplication, the same as `scale_a0_vartime`
+    #[deprecated(note = "variable time, use scale_a0_vartime or scale_a0_ct")]
     pub fn scale_a0<C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0>(
         &self,
         n: &[u8],
         curve: C,
     ) -> Self {
-        self.scalar_mul_daa_limbs8_a0(n, curve)
+        self.scale_a0_vartime(n, curve)
+    }
+
+    /// Same as `scale_vartime`, with the bits of n most significant first
+    pub(crate) fn scale_bits<C, I>(&self, bits: I, curve: C) -> Self
+    where
+        C: WeierstrassCurve<FieldElement = FE>,
+        I: DoubleEndedIterator<Item = Choice>,
+    {
+        self.scalar_mul_daa(
+            bits.rev(),
+            |a, b| a.add_or_double(b, curve),
+            |a| a.double(curve),
+        )
+    }
+
+    /// Same as `scale_a0_vartime`, with the bits of n most significant first
+    pub(crate) fn scale_bits_a0<C, I>(&self, bits: I, curve: C) -> Self
+    where
+        C: WeierstrassCurve<FieldElement = FE> + WeierstrassCurveA0,
+        I: DoubleEndedIterator<Item = Choice>,
+    {
+        self.scalar_mul_daa(
+            bits.rev(),
+            |a, b| a.add_or_double_a0(b, curve),
+            |a| a.double_a0(curve),
+        )
     }
 
     #[inline]
@@ -500,6 +796,627 @@ where
     }
 }
 
+impl<FE> Point<FE>
+where
+    FE: Field,
+    for<'a> &'a FE: Add<FE, Output = FE>,
+    for<'a> &'a FE: Mul<FE, Output = FE>,
+    for<'a> &'a FE: Sub<FE, Output = FE>,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// multi scalar multiplication : `n_0 * p_0 + ... + n_k * p_k` with the bucket method,
+    /// where the n_i are in big endian
+    ///
+    /// For each window of bits of the scalars, the points are added in the bucket
+    /// of their window value, and the buckets are then summed weighted by their index
+    /// with a running sum. This is variable time, and doesn't need the same number
+    /// of bytes for each scalar.
+    ///
+    /// Panics if the number of scalars and points differ
+    pub fn multi_scale<S: AsRef<[u8]>, C: WeierstrassCurve<FieldElement = FE>>(
+        scalars: &[S],
+        points: &[Point<FE>],
+        curve: C,
+    ) -> Self {
+        let width = msm_window_bits(points.len());
+        let mut buckets = vec![Point::infinity(); (1 << width) - 1];
+        Self::multi_scale_buckets(scalars, points, &mut buckets, curve)
+    }
+
+    /// Same as `multi_scale`, with the buckets provided by the caller, so that
+    /// nothing is allocated
+    ///
+    /// The number of buckets must be `2^w - 1` for a window of w bits, their content
+    /// is overwritten.
+    ///
+    /// Panics if the number of scalars and points differ, or if the number of buckets is invalid
+    pub fn multi_scale_buckets<S: AsRef<[u8]>, C: WeierstrassCurve<FieldElement = FE>>(
+        scalars: &[S],
+        points: &[Point<FE>],
+        buckets: &mut [Point<FE>],
+        curve: C,
+    ) -> Self {
+        assert_eq!(
+            scalars.len(),
+            points.len(),
+            "multi_scale: scalars and points length mismatch"
+        );
+        assert!(
+            !buckets.is_empty() && (buckets.len() + 1).is_power_of_two(),
+            "multi_scale: invalid number of buckets {}",
+            buckets.len()
+        );
+
+        let bits = scalars
+            .iter()
+            .map(|s| s.as_ref().len() * 8)
+            .max()
+            .unwrap_or(0);
+        let width = (buckets.len() + 1).trailing_zeros() as usize;
+
+        let mut acc = Point::infinity();
+        for window in (0..bits.div_ceil(width)).rev() {
+            for _ in 0..width {
+                acc = acc.double(curve);
+            }
+
+            for bucket in buckets.iter_mut() {
+                *bucket = Point::infinity();
+            }
+            for (s, p) in scalars.iter().zip(points.iter()) {
+                let digit = msm_window(s.as_ref(), window * width, width);
+                if digit != 0 {
+                    buckets[digit - 1] = buckets[digit - 1].add_different(p, curve);
+                }
+            }
+
+            // sum of (i+1) * buckets[i], as the sum of the running sums from the top bucket
+            let mut running = Point::infinity();
+            let mut sum = Point::infinity();
+            for bucket in buckets.iter().rev() {
+                running = running.add_different(bucket, curve);
+                sum = sum.add_different(&running, curve);
+            }
+            acc = acc.add_different(&sum, curve);
+        }
+        acc
+    }
+
+    /// Convert the points to affine points, with a single field inversion
+    ///
+    /// The points at infinity are converted to None.
+    pub fn batch_to_affine(points: &[Point<FE>]) -> Vec<Option<affine::Point<FE>>> {
+        let mut scratch = vec![FE::one(); points.len()];
+        let mut out = vec![None; points.len()];
+        Self::batch_to_affine_into(points, &mut scratch, &mut out);
+        out
+    }
+
+    /// Same as `batch_to_affine`, on arrays, so that nothing is allocated
+    pub fn batch_to_affine_array<const N: usize>(
+        points: &[Point<FE>; N],
+    ) -> [Option<affine::Point<FE>>; N] {
+        let mut scratch: [FE; N] = core::array::from_fn(|_| FE::one());
+        let mut out = core::array::from_fn(|_| None);
+        Self::batch_to_affine_into(points, &mut scratch, &mut out);
+        out
+    }
+
+    /// Convert the points to affine points in `out`, with a single field inversion,
+    /// using `scratch` for the intermediate products (Montgomery's trick)
+    ///
+    /// Panics if `scratch` and `out` are not of the same length as the points
+    pub fn batch_to_affine_into(
+        points: &[Point<FE>],
+        scratch: &mut [FE],
+        out: &mut [Option<affine::Point<FE>>],
+    ) {
+        assert_eq!(
+            points.len(),
+            scratch.len(),
+            "batch_to_affine: scratch length mismatch"
+        );
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "batch_to_affine: output length mismatch"
+        );
+
+        // scratch[i] is the product of all the non zero z up to i
+        let mut acc = FE::one();
+        for (p, product) in points.iter().zip(scratch.iter_mut()) {
+            if !p.z.is_zero() {
+                acc = &acc * &p.z;
+            }
+            *product = acc.clone();
+        }
+
+        // inv is the inverse of scratch[i] at each step
+        let mut inv = acc.inverse();
+        for i in (0..points.len()).rev() {
+            let p = &points[i];
+            if p.z.is_zero() {
+                out[i] = None;
+                continue;
+            }
+            let zinv = if i == 0 {
+                inv.clone()
+            } else {
+                &inv * &scratch[i - 1]
+            };
+            inv = &inv * &p.z;
+            out[i] = Some(affine::Point {
+                x: &p.x * &zinv,
+                y: &p.y * &zinv,
+            });
+        }
+    }
+}
+
+// bits of the big endian n, most significant first
+fn bytes_bits(n: &[u8]) -> impl DoubleEndedIterator<Item = Choice> + '_ {
+    n.iter().flat_map(|d| {
+        (0..8)
+            .rev()
+            .map(move |i| Choice::from_bit(((d >> i) & 1) as u64))
+    })
+}
+
+// width-w non adjacent form of the big endian n: signed odd digits in ]-2^(w-1), 2^(w-1)[
+// or zero, least significant first, with at most one non zero digit in any w consecutive ones
+pub(crate) fn wnaf_digits(n: &[u8], width: usize) -> Vec<i32> {
+    assert!((2..=8).contains(&width), "wnaf: invalid width {}", width);
+    // little endian limbs, with an extra limb for the carry of the negative digits
+    let mut k: Vec<u32> = n.iter().rev().map(|b| *b as u32).collect();
+    k.push(0);
+    let modulus = 1i32 << width;
+
+    let mut digits = Vec::with_capacity(n.len() * 8 + 1);
+    while k.iter().any(|l| *l != 0) {
+        let mut d = 0;
+        if k[0] & 1 == 1 {
+            d = (k[0] as i32) & (modulus - 1);
+            if d >= modulus / 2 {
+                d -= modulus;
+            }
+            // k -= d, which leaves the low w bits to zero
+            let mut carry = -d;
+            for l in k.iter_mut() {
+                let v = *l as i32 + carry;
+                *l = (v & 0xff) as u32;
+                carry = v >> 8;
+                if carry == 0 {
+                    break;
+                }
+            }
+        }
+        digits.push(d);
+        // k >>= 1
+        for i in 0..k.len() {
+            let high = k.get(i + 1).map_or(0, |h| h & 1);
+            k[i] = (k[i] >> 1) | (high << 7);
+        }
+    }
+    digits
+}
+
+/// Signed digits of fixed length of a scalar, see `recode_signed_fixed`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecodedScalar {
+    width: usize,
+    digits: Vec<i8>,
+    carry: u8,
+}
+
+impl RecodedScalar {
+    /// Width of the windows, in bits
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Digits in [-2^(w-1), 2^(w-1)[, least significant first
+    pub fn digits(&self) -> &[i8] {
+        &self.digits
+    }
+
+    /// Final carry (0 or 1) of the top digit, of weight 2^(w * number of digits)
+    pub fn carry(&self) -> u8 {
+        self.carry
+    }
+}
+
+/// Signed radix 2^w digits of the big endian n, of which only the low `bits` bits are used
+///
+/// There are exactly ⌈bits/w⌉ digits d_i in [-2^(w-1), 2^(w-1)[, and a final carry c
+/// in {0, 1}, such that n = sum(d_i 2^(w i)) + c 2^(w ⌈bits/w⌉). Each window of w bits plus
+/// the incoming carry is mapped to the signed range by subtracting 2^w when it is at
+/// least 2^(w-1), which carries one into the next window.
+///
+/// The recoding is constant time: the windows are read at fixed positions, and the
+/// digits and carries are computed with arithmetic only, whatever the value of n
+/// (even or odd, with or without a top carry). Panics if the width is not between 2 and 8.
+///
+/// This is the recoding of the constant time fixed base multiplications, see
+/// `FixedBaseTable::scale_recoded`.
+pub fn recode_signed_fixed(n: &[u8], bits: usize, width: usize) -> RecodedScalar {
+    assert!((2..=8).contains(&width), "recode: invalid width {}", width);
+    let count = bits.div_ceil(width);
+    let mut digits = Vec::with_capacity(count);
+    let mut carry = 0i32;
+    for i in 0..count {
+        // the last window only has the bits left
+        let w = core::cmp::min(width, bits - i * width);
+        let window = msm_window(n, i * width, w) as i32 + carry;
+        // 1 when the window is at least 2^(w-1), the window being at most 2^w
+        carry = (window + (1 << (width - 1))) >> width;
+        digits.push((window - (carry << width)) as i8);
+    }
+    RecodedScalar {
+        width,
+        digits,
+        carry: carry as u8,
+    }
+}
+
+// number of bits of the windows of the bucket method for n points,
+// which keeps the number of buckets (2^width) below the number of points
+fn msm_window_bits(n: usize) -> usize {
+    let log2 = (usize::BITS - n.leading_zeros()) as usize;
+    (log2 * 2 / 3).clamp(1, 16)
+}
+
+// value of the `width` bits starting at bit `start` (from the least significant bit)
+// of the big endian number n
+fn msm_window(n: &[u8], start: usize, width: usize) -> usize {
+    let mut digit = 0;
+    for i in (start..start + width).rev() {
+        let bit = match n.len().checked_sub(1 + i / 8) {
+            None => 0,
+            Some(byte) => (n[byte] >> (i % 8)) & 1,
+        };
+        digit = (digit << 1) | bit as usize;
+    }
+    digit
+}
+
+/// Table of the multiples P, 2P, .., (2^W-1)P of a point, for fixed base scalar
+/// multiplications with windows of W bits
+///
+/// The number of entries N has to be 2^W-1, and W between 2 and 8, which is checked
+/// at compile time, when the table is created. The entries are normalized (Z = 1),
+/// after the point at infinity of the digit 0.
+///
+/// The multiplication is constant time: every window does W doublings and one
+/// addition, and the entry added is selected with `ct_table_lookup`, which scans the
+/// whole table.
+///
+/// ```
+/// use eccoxide::curve::sec2::p256r1::{Curve, FieldElement};
+/// use eccoxide::curve::{affine, projective};
+///
+/// let (x, y) = Curve::generator();
+/// let g = projective::Point::from_affine(&affine::Point::new(x.clone(), y.clone(), Curve).unwrap());
+/// let table = projective::PointTable::<FieldElement, 4, 15>::new(&g, Curve).unwrap();
+/// assert_eq!(table.scale(&[2], Curve), g.double(Curve));
+/// ```
+///
+/// A width outside of 2..=8, or a number of entries which is not 2^W-1, doesn't compile:
+///
+/// ```compile_fail
+/// use eccoxide::curve::sec2::p256r1::{Curve, FieldElement};
+/// use eccoxide::curve::{affine, projective};
+///
+/// let (x, y) = Curve::generator();
+/// let g = projective::Point::from_affine(&affine::Point::new(x.clone(), y.clone(), Curve).unwrap());
+/// let table = projective::PointTable::<FieldElement, 0, 0>::new(&g, Curve);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PointTable<FE, const W: usize, const N: usize> {
+    // the 2^W multiples 0, P, .., (2^W-1)P
+    points: Vec<Point<FE>>,
+}
+
+impl<FE, const W: usize, const N: usize> PointTable<FE, W, N>
+where
+    FE: Field + CtSelect,
+    for<'a> &'a FE: Add<FE, Output = FE>,
+    for<'a> &'a FE: Mul<FE, Output = FE>,
+    for<'a> &'a FE: Sub<FE, Output = FE>,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    const VALID: () = assert!(
+        W >= 2 && W <= 8 && N == (1 << W) - 1,
+        "PointTable needs a width W in 2..=8, and 2^W-1 entries"
+    );
+
+    /// Table of the multiples of p, or None for the point at infinity
+    pub fn new<C: WeierstrassCurve<FieldElement = FE>>(p: &Point<FE>, curve: C) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        let mut multiples: [Point<FE>; N] = core::array::from_fn(|_| p.clone());
+        for i in 1..N {
+            multiples[i] = multiples[i - 1].add_different(p, curve);
+        }
+        // with a prime order, none of the multiples is infinity unless p is
+        let affines = Point::batch_to_affine_array(&multiples);
+        let mut points = Vec::with_capacity(N + 1);
+        points.push(Point::infinity());
+        for a in affines.iter() {
+            points.push(Point::from_affine(a.as_ref()?));
+        }
+        Some(PointTable { points })
+    }
+
+    /// Width of the windows, in bits
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    /// scalar multiplication : `n * P` where n is in big endian
+    pub fn scale<C: WeierstrassCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Point<FE> {
+        let bits = n.len() * 8;
+        let mut q = Point::infinity();
+        for window in (0..bits.div_ceil(W)).rev() {
+            for _ in 0..W {
+                q = q.double(curve);
+            }
+            let digit = ct_index_bits::<W>(msm_window(n, window * W, W));
+            q = q.add_different(&ct_table_lookup(&self.points, &digit), curve);
+        }
+        q
+    }
+}
+
+/// Number of pairs buffered by `MsmAccumulator` before they are summed
+pub const MSM_CHUNK: usize = 256;
+
+/// Streaming multi scalar multiplication
+///
+/// The (scalar, point) pairs are pushed one at a time, and summed with
+/// `Point::multi_scale` every `MSM_CHUNK` pairs into a partial result, so the
+/// memory used doesn't depend on the total number of pairs.
+///
+/// The scalars are often secret, so the Debug output only shows the number of
+/// pairs not summed yet.
+#[derive(Clone)]
+pub struct MsmAccumulator<C: WeierstrassCurve, S> {
+    curve: C,
+    scalars: Vec<S>,
+    points: Vec<Point<C::FieldElement>>,
+    sum: Point<C::FieldElement>,
+}
+
+impl<C: WeierstrassCurve, S> std::fmt::Debug for MsmAccumulator<C, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MsmAccumulator")
+            .field("pending", &self.scalars.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Table of the multiples P, 2P, .., 2^(W-1)P of `2^(W*i) P` for every window i
+/// of W bits of the scalars, for fixed base scalar multiplications without any doubling
+///
+/// The scalars are recoded in signed digits of W bits (see `recode_signed_fixed`),
+/// each window adding the multiple of its digit, negated for the negative digits, so
+/// the rows only need N = 2^(W-1) entries. It is about `bits/W` times larger than a
+/// `PointTable`, so it is meant for the points used for many multiplications, the
+/// generator above all. The multiplication is constant time: every window does one
+/// addition, and the entry added is selected with `ct_table_lookup`.
+///
+/// ```
+/// use eccoxide::curve::sec2::p256r1::{Curve, FieldElement};
+/// use eccoxide::curve::{affine, projective};
+///
+/// let (x, y) = Curve::generator();
+/// let g = projective::Point::from_affine(&affine::Point::new(x.clone(), y.clone(), Curve).unwrap());
+/// let table = projective::FixedBaseTable::<FieldElement, 4, 8>::new(&g, 256, Curve).unwrap();
+/// assert_eq!(table.scale(&[3], Curve), g.scale_vartime(&[3], Curve));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<FE, const W: usize, const N: usize> {
+    // the normalized multiples of the base of each row, the bases being 2^(W*i) P
+    rows: Vec<[Point<FE>; N]>,
+    // 2^(W*rows) P, the weight of the final carry of the recoding
+    top: Point<FE>,
+}
+
+impl<FE, const W: usize, const N: usize> FixedBaseTable<FE, W, N>
+where
+    FE: Field + CtSelect,
+    for<'a> &'a FE: Add<FE, Output = FE>,
+    for<'a> &'a FE: Mul<FE, Output = FE>,
+    for<'a> &'a FE: Sub<FE, Output = FE>,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    const VALID: () = assert!(
+        W >= 2 && W <= 8 && N == 1 << (W - 1),
+        "FixedBaseTable needs a width W in 2..=8, and 2^(W-1) entries"
+    );
+
+    /// Table of the multiples of p for the scalars of up to `bits` bits, or None if
+    /// one of the multiples is the point at infinity (e.g. p is)
+    pub fn new<C: WeierstrassCurve<FieldElement = FE>>(
+        p: &Point<FE>,
+        bits: usize,
+        curve: C,
+    ) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        let windows = bits.div_ceil(W);
+        let mut rows = Vec::with_capacity(windows);
+        let mut base = p.clone();
+        for _ in 0..windows {
+            let mut multiples: [Point<FE>; N] = core::array::from_fn(|_| base.clone());
+            for i in 1..N {
+                multiples[i] = multiples[i - 1].add_different(&base, curve);
+            }
+            let affines = Point::batch_to_affine_array(&multiples);
+            if affines.iter().any(|a| a.is_none()) {
+                return None;
+            }
+            rows.push(affines.map(|a| Point::from_affine(&a.unwrap())));
+            // 2^W.base, the next row base
+            base = multiples[N - 1].double(curve);
+        }
+        Some(FixedBaseTable { rows, top: base })
+    }
+
+    /// Width of the windows, in bits
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    /// Number of bits of the scalars covered by the table
+    pub fn bits(&self) -> usize {
+        self.rows.len() * W
+    }
+
+    // constant time lookup of d.P in a row, the point at infinity for d = 0
+    fn select(row: &[Point<FE>; N], d: i8) -> Point<FE> {
+        let sign = (d as i32) >> 31;
+        let magnitude = ((d as i32 ^ sign) - sign) as usize;
+        // |d|.P is the entry |d|-1, the entry selected for 0 being replaced by infinity
+        let index = ct_index_bits::<8>(magnitude.wrapping_sub(1) & (N - 1));
+        let mut p = ct_table_lookup(row, &index[..W - 1]);
+        p = Point::ct_select(&p, &Point::infinity(), (magnitude as u64).ct_eq(&0));
+        p.conditional_negate(Choice::from_bit((sign & 1) as u64));
+        p
+    }
+
+    /// scalar multiplication : `n * P` for the signed digits of n
+    ///
+    /// Panics if the width of the digits is not W, or if there are more digits than
+    /// windows in the table, which only depend on the sizes of the scalars.
+    pub fn scale_recoded<C: WeierstrassCurve<FieldElement = FE>>(
+        &self,
+        n: &RecodedScalar,
+        curve: C,
+    ) -> Point<FE> {
+        assert_eq!(n.width(), W, "recoded scalar of another width");
+        let digits = n.digits();
+        assert!(
+            digits.len() <= self.rows.len(),
+            "scalar longer than the fixed base table"
+        );
+        let mut q = Point::infinity();
+        for (row, d) in self.rows.iter().zip(digits.iter()) {
+            q = q.add_different(&Self::select(row, *d), curve);
+        }
+        // the carry is the digit 1 of the window after the last digit
+        let carry_base = match self.rows.get(digits.len()) {
+            Some(row) => &row[0],
+            None => &self.top,
+        };
+        let carry = Point::ct_select(
+            &Point::infinity(),
+            carry_base,
+            Choice::from_bit(n.carry() as u64),
+        );
+        q.add_different(&carry, curve)
+    }
+
+    /// scalar multiplication : `n * P` where n is in big endian
+    ///
+    /// All the bits of n are used, whatever their value: this panics if n is
+    /// longer than the bits covered by the table, rounded up to a window.
+    pub fn scale<C: WeierstrassCurve<FieldElement = FE>>(&self, n: &[u8], curve: C) -> Point<FE> {
+        self.scale_recoded(&recode_signed_fixed(n, 8 * n.len(), W), curve)
+    }
+}
+
+impl<C, S, FE> MsmAccumulator<C, S>
+where
+    C: WeierstrassCurve<FieldElement = FE>,
+    S: AsRef<[u8]>,
+    FE: Field,
+    for<'a> &'a FE: Add<FE, Output = FE>,
+    for<'a> &'a FE: Mul<FE, Output = FE>,
+    for<'a> &'a FE: Sub<FE, Output = FE>,
+    for<'a, 'b> &'a FE: Add<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+    for<'a, 'b> &'a FE: Sub<&'b FE, Output = FE>,
+{
+    /// Create a new accumulator, whose sum is the point at infinity
+    pub fn new(curve: C) -> Self {
+        MsmAccumulator {
+            curve,
+            scalars: Vec::with_capacity(MSM_CHUNK),
+            points: Vec::with_capacity(MSM_CHUNK),
+            sum: Point::infinity(),
+        }
+    }
+
+    /// Add `n * p` to the sum, where n is in big endian
+    pub fn push(&mut self, n: S, p: &Point<FE>) {
+        self.scalars.push(n);
+        self.points.push(p.clone());
+        if self.scalars.len() == MSM_CHUNK {
+            self.flush()
+        }
+    }
+
+    // sum the buffered pairs into the partial result
+    fn flush(&mut self) {
+        if self.scalars.is_empty() {
+            return;
+        }
+        let chunk = Point::multi_scale(&self.scalars, &self.points, self.curve);
+        self.sum = self.sum.add_different(&chunk, self.curve);
+        self.scalars.clear();
+        self.points.clear();
+    }
+
+    /// Return the sum of all the pushed `n * p`
+    pub fn finalize(mut self) -> Point<FE> {
+        self.flush();
+        self.sum
+    }
+}
+
+impl<FE> Point<FE>
+where
+    FE: Field + CtSelect,
+{
+    /// Negate the point if the choice is true, otherwise leave it unchanged
+    ///
+    /// The negated Y coordinate is always computed and selected in constant time
+    pub fn conditional_negate(&mut self, c: Choice) {
+        let ny = -self.y.clone();
+        self.y = FE::ct_select(&self.y, &ny, c);
+    }
+}
+
+impl<FE> Point<FE>
+where
+    FE: PrimeField,
+    for<'a, 'b> &'a FE: Mul<&'b FE, Output = FE>,
+{
+    /// Compress the point as the affine X coordinate and the sign of the affine Y coordinate
+    ///
+    /// This is the same as `to_affine` followed by `compress` on the affine point, but
+    /// computes X/Z and Y/Z directly, without building the affine point. Z is inverted
+    /// even when it's one, so normalized points don't take a shorter path.
+    /// The point at infinity doesn't have a compressed form and gives None
+    pub fn compress(&self) -> Option<(FE, Sign)> {
+        if self.z.is_zero() {
+            None
+        } else {
+            let inv = self.z.inverse();
+            let y = &self.y * &inv;
+            Some((&self.x * &inv, y.sign()))
+        }
+    }
+}
+
 impl<FE> std::ops::Neg for Point<FE>
 where
     FE: Neg<Output = FE>,
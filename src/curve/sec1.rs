@@ -0,0 +1,106 @@
+//! Validation of the SEC1 encodings of the points
+//!
+//! The SEC1 (and X9.62) encodings are a prefix byte followed by the coordinates:
+//!
+//! * 0x00 alone is the point at infinity
+//! * 0x02 or 0x03 followed by X is the compressed form, the prefix being the parity of Y
+//! * 0x04 followed by X and Y is the uncompressed form
+//! * 0x06 or 0x07 followed by X and Y is the hybrid form, the prefix being the parity of Y
+//!
+//! The curves `Point::decode_sec1` and `PointAffine::decode_sec1` decode any of these
+//! forms, reporting the reason of a rejection with a `Sec1Error` and following the policy
+//! of the `ValidationFlags`, and `PointAffine::batch_validate_sec1` checks many encoded
+//! public keys at once.
+
+use std::fmt;
+
+/// Reason for rejecting the SEC1 encoding of a point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sec1Error {
+    /// The encoding is empty, or its length is not the one of its prefix
+    InvalidLength,
+    /// The prefix is not one of a SEC1 encoding
+    InvalidPrefix,
+    /// The encoding is the point at infinity (0x00), which is not a valid key
+    Infinity,
+    /// The encoding form is valid, but not allowed by the validation flags
+    Disallowed,
+    /// A coordinate is not less than the field order
+    NotCanonical,
+    /// The coordinates are not on the curve, or there's no point with this X coordinate
+    NotOnCurve,
+    /// The parity of the hybrid prefix is not the one of the Y coordinate
+    ParityMismatch,
+}
+
+impl fmt::Display for Sec1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sec1Error::InvalidLength => f.write_str("invalid encoding length"),
+            Sec1Error::InvalidPrefix => f.write_str("invalid encoding prefix"),
+            Sec1Error::Infinity => f.write_str("point at infinity"),
+            Sec1Error::Disallowed => f.write_str("encoding not allowed"),
+            Sec1Error::NotCanonical => f.write_str("non canonical coordinate"),
+            Sec1Error::NotOnCurve => f.write_str("point not on the curve"),
+            Sec1Error::ParityMismatch => f.write_str("hybrid prefix parity mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for Sec1Error {}
+
+/// Policy of the SEC1 validation of points
+///
+/// The default accepts the compressed and uncompressed encodings, but not the rarely
+/// used hybrid one, and validates all the encodings of a batch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationFlags {
+    /// Accept the compressed encodings (0x02 or 0x03 followed by X)
+    pub allow_compressed: bool,
+    /// Accept the uncompressed encoding (0x04 followed by X and Y)
+    pub allow_uncompressed: bool,
+    /// Accept the hybrid encodings (0x06 or 0x07 followed by X and Y)
+    pub allow_hybrid: bool,
+    /// Stop at the first rejected encoding, which is the last result of the batch
+    pub fail_fast: bool,
+}
+
+impl Default for ValidationFlags {
+    fn default() -> Self {
+        ValidationFlags {
+            allow_compressed: true,
+            allow_uncompressed: true,
+            allow_hybrid: false,
+            fail_fast: false,
+        }
+    }
+}
+
+impl ValidationFlags {
+    /// Same flags, stopping at the first rejected encoding
+    pub const fn fail_fast(self) -> Self {
+        ValidationFlags {
+            fail_fast: true,
+            ..self
+        }
+    }
+
+    /// Same flags, also accepting the hybrid encodings
+    pub const fn with_hybrid(self) -> Self {
+        ValidationFlags {
+            allow_hybrid: true,
+            ..self
+        }
+    }
+}
+
+/// Form of the SEC1 encoding of a point, the point at infinity being 0x00 in all of them
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sec1Form {
+    /// 0x02 or 0x03 (the parity of Y) followed by X
+    Compressed,
+    /// 0x04 followed by X and Y
+    Uncompressed,
+    /// 0x06 or 0x07 (the parity of Y) followed by X and Y
+    Hybrid,
+}
@@ -1,4 +1,19 @@
 //! macros to generate curve definition using num_trait/num_bigint dependencies
+//!
+//! Note that `==` on the field elements and scalars of those curves is variable time,
+//! secret values should be compared with `ct_eq` instead:
+//!
+//! ```
+//! # #[cfg(feature = "p160r1")]
+//! # {
+//! use eccoxide::curve::sec2::p160r1::Scalar;
+//!
+//! let secret = Scalar::from_u64(0x1234);
+//! let guess = Scalar::from_u64(0x1235);
+//! assert!(!secret.ct_eq(&guess).is_true());
+//! assert!(secret.ct_eq(&Scalar::from_u64(0x1234)).is_true());
+//! # }
+//! ```
 
 #[cfg(any(
     feature = "p112r1",
@@ -20,18 +35,18 @@ bigint_prime_curve!(p128r1, 128);
 #[cfg(feature = "p128r2")]
 bigint_prime_curve!(p128r2, 128);
 #[cfg(feature = "p160k1")]
-bigint_prime_curve!(p160k1, 160);
+bigint_prime_curve!(p160k1, 160, 161);
 #[cfg(feature = "p160r1")]
-bigint_prime_curve!(p160r1, 160);
+bigint_prime_curve!(p160r1, 160, 161);
 #[cfg(feature = "p160r2")]
-bigint_prime_curve!(p160r2, 160);
+bigint_prime_curve!(p160r2, 160, 161);
 /*
 #[cfg(feature = "p192k1")]
 bigint_prime_curve!(p192k1, 192);
 #[cfg(feature = "p192r1")]
 bigint_prime_curve!(p192r1, 192);
 #[cfg(feature = "p224k1")]
-bigint_prime_curve!(p224k1, 224);
+bigint_prime_curve!(p224k1, 224, 225);
 #[cfg(feature = "p224r1")]
 bigint_prime_curve!(p224r1, 224);
 #[cfg(feature = "p256k1")]
@@ -0,0 +1,284 @@
+//! Exponentiation chains as data, for the `small-code` feature
+//!
+//! Each chain is a list of (squarings, multiplier) steps: the accumulator is squared
+//! `squarings` times then multiplied by x^(2*multiplier+1), or not multiplied when
+//! the multiplier is `NO_MUL`. The chains are a left-to-right sliding window (of width 4)
+//! decomposition of the exponent, so only the odd powers x^1, x^3, .., x^15 are needed.
+//!
+//! The exponents are:
+//!
+//! * FE_INVERSE: p - 2
+//! * SCALAR_INVERSE: n - 2 where n is the group order
+//! * FE_SQRT: (p+1)/4 for p = 3 mod 4, (p-5)/8 for p224k1 (p = 5 mod 8),
+//!   and (p-1)/2 for p224r1 (euler criterion used by Tonelli-Shanks)
+//!
+//! p521r1 square root is x^(2^519), which is already compact and has no chain.
+
+/// Multiplier value for a step with only squarings
+pub(crate) const NO_MUL: u8 = 0xff;
+
+#[cfg(feature = "p192k1")]
+pub(crate) mod p192k1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 48] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7), (4, 7),
+        (3, 3), (4, 3), (7, 6), (2, 0),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 44] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (3, 3), (7, 4), (4, 5), (2, 1), (6, 5), (4, 7), (9, 5), (1, 0), (8, 7), (5, 6),
+        (5, 2), (5, 1), (6, 6), (5, 4), (4, 6), (6, 6), (3, 3), (5, 7), (4, 6), (1, 0), (7, 5),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 48] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7), (4, 7),
+        (3, 3), (4, 3), (6, 3), (1, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p192r1")]
+pub(crate) mod p192r1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 48] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 6),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 44] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 4), (4, 4), (4, 6), (3, 3), (5, 7), (1, 0), (9, 6), (1, 0), (7, 2),
+        (7, 6), (5, 7), (6, 4), (4, 5), (7, 6), (3, 2), (6, 6), (3, 0), (6, 2), (9, 5), (2, 1),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 33] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (62, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p224k1")]
+pub(crate) mod p224k1 {
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 56] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 2), (5, 6),
+        (3, 1),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 25] = [
+        (0, 0), (114, 3), (4, 3), (5, 3), (2, 0), (7, 6), (6, 5), (4, 5), (5, 1), (6, 1), (8, 4),
+        (4, 4), (5, 5), (2, 1), (7, 2), (5, 4), (4, 3), (7, 5), (4, 5), (5, 4), (4, 7), (4, 5),
+        (7, 7), (3, 2), (2, 0),
+    ];
+    /// x^((p-5)/8)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 55] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 2), (5, 6),
+    ];
+}
+
+#[cfg(feature = "p224r1")]
+pub(crate) mod p224r1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 56] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 52] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (7, 5), (4, 2), (7, 5), (1, 0), (9, 5),
+        (1, 0), (7, 7), (10, 7), (1, 0), (8, 4), (3, 3), (4, 3), (5, 4), (5, 4), (2, 0), (6, 2),
+        (5, 5), (1, 0), (7, 5), (1, 0), (7, 2), (2, 0), (6, 3), (3, 1),
+    ];
+    /// x^((p-1)/2)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 33] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (95, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p256k1")]
+pub(crate) mod p256k1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 64] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (3, 3), (5, 7), (4, 7), (4, 7), (4, 7), (4, 7), (2, 1), (8, 5), (2, 0),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 58] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 5),
+        (3, 2), (4, 2), (4, 3), (5, 6), (2, 1), (5, 3), (6, 6), (5, 5), (4, 6), (3, 0), (6, 2),
+        (10, 3), (4, 3), (5, 7), (4, 7), (5, 4), (6, 5), (4, 6), (5, 1), (6, 6), (10, 6), (4, 4),
+        (9, 4), (4, 7), (1, 0),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 64] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (3, 3), (5, 7), (4, 7), (4, 7), (4, 7), (4, 7), (2, 1), (6, 1), (2, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p256r1")]
+pub(crate) mod p256r1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 33] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (32, 0), (100, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 6),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 51] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (36, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 5), (2, 1), (5, 3), (6, 6), (4, 7), (4, 2), (5, 5), (5, 6), (5, 3),
+        (7, 5), (2, 1), (6, 7), (2, 0), (8, 4), (3, 3), (5, 3), (4, 3), (5, 3), (5, 2), (3, 1),
+        (8, 5), (4, 7), (5, 1), (5, 1), (6, 4), (4, 2), (6, 7),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 11] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (32, 0), (96, 0),
+        (94, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p384r1")]
+pub(crate) mod p384r1 {
+    use super::NO_MUL;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 80] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (68, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 6),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 87] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (2, 1), (6, 3), (3, 1), (7, 6), (6, 6), (1, 0), (10, 7),
+        (3, 2), (8, 6), (2, 1), (6, 5), (4, 3), (5, 7), (3, 2), (3, 1), (10, 6), (9, 6), (4, 5),
+        (6, 4), (3, 0), (7, 5), (7, 2), (5, 3), (5, 7), (5, 5), (4, 5), (5, 3), (3, 1), (7, 1),
+        (6, 5), (4, 2), (3, 1), (4, 1), (4, 1), (6, 2), (5, 2), (6, 5), (1, 0), (4, 0),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 74] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (64, 0), (30, NO_MUL),
+    ];
+}
+
+#[cfg(feature = "p521r1")]
+pub(crate) mod p521r1 {
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 131] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (2, 0),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 117] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 6),
+        (5, 2), (5, 1), (8, 6), (8, 7), (8, 3), (5, 7), (2, 1), (6, 5), (3, 3), (6, 5), (6, 6),
+        (5, 6), (4, 7), (4, 7), (4, 1), (12, 2), (3, 0), (7, 7), (4, 3), (8, 4), (3, 2), (6, 5),
+        (3, 2), (9, 3), (4, 3), (5, 6), (4, 3), (6, 4), (4, 5), (1, 0), (4, 0), (7, 4), (4, 4),
+        (2, 1), (4, 0), (7, 7), (5, 5), (3, 2), (4, 3), (5, 6), (4, 5), (3, 3), (5, 6), (2, 1),
+        (7, 7), (5, 4), (7, 4), (2, 1), (6, 1), (3, 0), (10, 3),
+    ];
+}
@@ -0,0 +1,714 @@
+//! Elliptic Curve Digital Signature Algorithm shared construction
+//!
+//! The signing functions of the curves don't take the nonce k directly, but a
+//! `NonceSource` from which k is drawn: a reused or biased nonce leaks the
+//! secret key, so the choice is between the sources provided here:
+//!
+//! * `Rfc6979`: deterministic nonce derived from the secret key and the message hash
+//!   with HMAC-DRBG, as specified by [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979)
+//! * `Rfc6979WithExtraEntropy`: the same with fresh random data added to the
+//!   derivation (RFC 6979 section 3.6), which doesn't depend on the quality
+//!   of the random generator and doesn't give the same signature twice
+//! * `RandomNonce`: nonce drawn from a random generator
+//!
+//! Signing with a given k is only available with the `dangerous-apis` feature,
+//! as `sign_with_fixed_nonce_for_testing`.
+//!
+//! Messages received in chunks can be hashed as they come with a `SigningContext` and
+//! a `VerifyingContext`, which give the same signatures as `sign` and `verify` on the
+//! hash of the whole message.
+//!
+//! For a signature (r, s), (r, -s) is also valid: verifiers that need a single
+//! valid signature per message (e.g. to identify transactions) only accept the
+//! one with the low s, see `VerifyOptions` and `Signature::normalize_s`.
+
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::digest::Digest;
+use hmac::{Mac, SimpleHmac};
+use std::marker::PhantomData;
+
+/// Source of the per signature nonces k of ECDSA
+///
+/// For each signature, `init` is called once, and then `next` until the candidate
+/// is a valid nonce: the leftmost bits (as many as the bit size of the group order)
+/// of the candidate, read as a big endian integer, need to be in [1, n-1].
+pub trait NonceSource {
+    /// Start the generation of the nonce for the secret key and the message hash
+    ///
+    /// Both are big endian integers of the byte size of the group order, the hash
+    /// being already truncated and reduced (`int2octets` and `bits2octets` of RFC 6979)
+    fn init(&mut self, secret: &[u8], hash: &[u8]);
+
+    /// Fill `out`, which is of the byte size of the group order, with the next candidate
+    fn next(&mut self, out: &mut [u8]);
+}
+
+impl<N: NonceSource + ?Sized> NonceSource for &mut N {
+    fn init(&mut self, secret: &[u8], hash: &[u8]) {
+        (**self).init(secret, hash)
+    }
+
+    fn next(&mut self, out: &mut [u8]) {
+        (**self).next(out)
+    }
+}
+
+/// Options of the signature verification
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyOptions {
+    /// Accept the signatures where s is greater than (n-1)/2, which is what ECDSA
+    /// does. When not set, only the normalized signatures (low s) are accepted
+    pub allow_high_s: bool,
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        VerifyOptions { allow_high_s: true }
+    }
+}
+
+// HMAC_DRBG of RFC 6979 section 3.2, with the optional extra data k' of section 3.6
+struct HmacDrbg<H: Digest + BlockSizeUser> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    started: bool,
+    hash: PhantomData<H>,
+}
+
+impl<H: Digest + BlockSizeUser> HmacDrbg<H> {
+    fn new() -> Self {
+        HmacDrbg {
+            k: Vec::new(),
+            v: Vec::new(),
+            started: false,
+            hash: PhantomData,
+        }
+    }
+
+    fn hmac(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        let mut mac = <SimpleHmac<H> as Mac>::new_from_slice(key).expect("any HMAC key size");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    fn init(&mut self, secret: &[u8], hash: &[u8], extra: &[u8]) {
+        let hlen = <H as Digest>::output_size();
+        self.v = vec![0x01; hlen];
+        self.k = vec![0x00; hlen];
+        self.k = Self::hmac(&self.k, &[&self.v, &[0x00], secret, hash, extra]);
+        self.v = Self::hmac(&self.k, &[&self.v]);
+        self.k = Self::hmac(&self.k, &[&self.v, &[0x01], secret, hash, extra]);
+        self.v = Self::hmac(&self.k, &[&self.v]);
+        self.started = false;
+    }
+
+    fn next(&mut self, out: &mut [u8]) {
+        // the state is only updated when the previous candidate was rejected
+        if self.started {
+            self.k = Self::hmac(&self.k, &[&self.v, &[0x00]]);
+            self.v = Self::hmac(&self.k, &[&self.v]);
+        }
+        self.started = true;
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.v = Self::hmac(&self.k, &[&self.v]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+    }
+}
+
+/// Deterministic nonces of RFC 6979, with the hash function H for HMAC
+///
+/// The same secret key and message hash always give the same nonce, so a signature
+/// doesn't depend on a random generator. H is usually the hash used on the message,
+/// which is what the test vectors of the RFC use.
+pub struct Rfc6979<H: Digest + BlockSizeUser>(HmacDrbg<H>);
+
+impl<H: Digest + BlockSizeUser> Rfc6979<H> {
+    pub fn new() -> Self {
+        Rfc6979(HmacDrbg::new())
+    }
+}
+
+impl<H: Digest + BlockSizeUser> Default for Rfc6979<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Digest + BlockSizeUser> NonceSource for Rfc6979<H> {
+    fn init(&mut self, secret: &[u8], hash: &[u8]) {
+        self.0.init(secret, hash, &[])
+    }
+
+    fn next(&mut self, out: &mut [u8]) {
+        self.0.next(out)
+    }
+}
+
+/// Nonces of RFC 6979 with extra random data (section 3.6), a.k.a. hedged signatures
+///
+/// 32 random bytes are drawn for each signature and added to the HMAC-DRBG
+/// seed, so the nonce stays safe with a broken random generator, and the
+/// signatures of a same message are different.
+pub struct Rfc6979WithExtraEntropy<H: Digest + BlockSizeUser, R> {
+    drbg: HmacDrbg<H>,
+    rng: R,
+}
+
+impl<H: Digest + BlockSizeUser, R: rand_core::RngCore + rand_core::CryptoRng>
+    Rfc6979WithExtraEntropy<H, R>
+{
+    pub fn new(rng: R) -> Self {
+        Rfc6979WithExtraEntropy {
+            drbg: HmacDrbg::new(),
+            rng,
+        }
+    }
+}
+
+impl<H: Digest + BlockSizeUser, R: rand_core::RngCore + rand_core::CryptoRng> NonceSource
+    for Rfc6979WithExtraEntropy<H, R>
+{
+    fn init(&mut self, secret: &[u8], hash: &[u8]) {
+        let mut extra = [0u8; 32];
+        self.rng.fill_bytes(&mut extra);
+        self.drbg.init(secret, hash, &extra)
+    }
+
+    fn next(&mut self, out: &mut [u8]) {
+        self.drbg.next(out)
+    }
+}
+
+/// Nonces drawn from a random generator
+///
+/// The generator needs to be cryptographically secure: a bias in the nonces is
+/// enough to recover the secret key from a few signatures. `Rfc6979WithExtraEntropy`
+/// doesn't have this requirement.
+pub struct RandomNonce<R>(R);
+
+impl<R: rand_core::RngCore + rand_core::CryptoRng> RandomNonce<R> {
+    pub fn new(rng: R) -> Self {
+        RandomNonce(rng)
+    }
+}
+
+impl<R: rand_core::RngCore + rand_core::CryptoRng> NonceSource for RandomNonce<R> {
+    fn init(&mut self, _secret: &[u8], _hash: &[u8]) {}
+
+    fn next(&mut self, out: &mut [u8]) {
+        self.0.fill_bytes(out)
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_ecdsa_define {
+    () => {
+        use super::{Point, PublicKey, Scalar, SecretKey};
+        pub use $crate::curve::sec2::ecdsa::*;
+
+        /// ECDSA signature (r, s), where r and s are both non zero
+        ///
+        /// Equality is constant time, like for the values of a MAC, and `eq_vartime`
+        /// is the faster comparison of signatures known to be public. The hash is over
+        /// the bytes r || s.
+        #[derive(Clone, Debug)]
+        pub struct Signature {
+            r: Scalar,
+            s: Scalar,
+        }
+
+        impl Signature {
+            /// Size in bytes of the fixed size encoding r || s
+            pub const SIZE_BYTES: usize = 2 * Scalar::SIZE_BYTES;
+
+            /// Create a signature from r and s, returning None if either is zero
+            pub fn from_scalars(r: Scalar, s: Scalar) -> Option<Self> {
+                if r.is_zero() || s.is_zero() {
+                    None
+                } else {
+                    Some(Signature { r, s })
+                }
+            }
+
+            /// Create a signature from the big endian bytes r || s
+            ///
+            /// Returns None if the size is not `SIZE_BYTES`, or if r or s
+            /// is zero or not less than the group order
+            pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+                if bytes.len() != Self::SIZE_BYTES {
+                    return None;
+                }
+                let (r, s) = bytes.split_at(Scalar::SIZE_BYTES);
+                Some(Signature {
+                    r: Scalar::from_slice_nonzero(r)?,
+                    s: Scalar::from_slice_nonzero(s)?,
+                })
+            }
+
+            /// Same as `from_slice`, with the size checked at compile time
+            pub fn from_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> Option<Self> {
+                Self::from_slice(bytes)
+            }
+
+            /// Output the big endian bytes r || s
+            pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
+                let mut out = [0u8; Self::SIZE_BYTES];
+                out[..Scalar::SIZE_BYTES].copy_from_slice(&self.r.to_bytes());
+                out[Scalar::SIZE_BYTES..].copy_from_slice(&self.s.to_bytes());
+                out
+            }
+
+            pub fn r(&self) -> &Scalar {
+                &self.r
+            }
+
+            pub fn s(&self) -> &Scalar {
+                &self.s
+            }
+
+            /// Check if s is in the lower half, i.e. not greater than (n-1)/2
+            pub fn is_normalized(&self) -> bool {
+                // (n-1)/2 - s borrows when s is greater
+                let (_, high) = Scalar::half_max().overflowing_sub(&self.s);
+                !high.is_true()
+            }
+
+            /// The signature (r, s) with s in the lower half, replacing s by -s if needed
+            ///
+            /// Both are valid signatures of the same message, so this is idempotent,
+            /// and gives the only signature that `VerifyOptions { allow_high_s: false }` accepts
+            pub fn normalize_s(&self) -> Self {
+                let s = if self.is_normalized() {
+                    self.s.clone()
+                } else {
+                    -&self.s
+                };
+                Signature {
+                    r: self.r.clone(),
+                    s,
+                }
+            }
+
+            /// Variable time equality, for the signatures that are public
+            pub fn eq_vartime(&self, other: &Self) -> bool {
+                self.to_bytes()[..] == other.to_bytes()[..]
+            }
+        }
+
+        impl PartialEq for Signature {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::mp::ct::CtEqual::ct_eq(self, other).is_true()
+            }
+        }
+
+        impl Eq for Signature {}
+
+        impl $crate::mp::ct::CtEqual for Signature {
+            fn ct_eq(&self, other: &Self) -> $crate::mp::ct::Choice {
+                $crate::mp::ct::CtEqual::ct_eq(&self.r, &other.r)
+                    & $crate::mp::ct::CtEqual::ct_eq(&self.s, &other.s)
+            }
+        }
+
+        impl std::hash::Hash for Signature {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.to_bytes()[..].hash(state)
+            }
+        }
+
+        // the X coordinate of k.G reduced modulo the group order, None if zero
+        fn r_of(k: &Scalar) -> Option<Scalar> {
+            let p = Point::generator().scale_ct(k).to_affine()?;
+            let (r, _) = Scalar::from_field_reduced(p.to_coordinate().0);
+            if r.is_zero() {
+                None
+            } else {
+                Some(r)
+            }
+        }
+
+        fn sign_with(secret: &SecretKey, e: &Scalar, k: &Scalar) -> Option<Signature> {
+            let r = r_of(k)?;
+            let s = k.inverse() * (e + &r * secret.as_scalar());
+            Signature::from_scalars(r, s)
+        }
+
+        // bits2int of RFC 6979 for a candidate, None if not in [1, n-1]
+        fn nonce_of(candidate: &mut [u8; Scalar::SIZE_BYTES]) -> Option<Scalar> {
+            let shift = Scalar::SIZE_BYTES * 8 - Scalar::SIZE_BITS;
+            if shift > 0 {
+                for i in (0..candidate.len()).rev() {
+                    let high = if i > 0 {
+                        candidate[i - 1] << (8 - shift)
+                    } else {
+                        0
+                    };
+                    candidate[i] = (candidate[i] >> shift) | high;
+                }
+            }
+            let k = Scalar::from_bytes(candidate)?;
+            if k.is_zero() {
+                None
+            } else {
+                Some(k)
+            }
+        }
+
+        /// Sign the message hash with the secret key, the nonce being drawn from the nonce source
+        ///
+        /// The hash is truncated to the bit size of the group order if longer (`bits2int`).
+        /// Nonces are drawn until one gives non zero r and s, which is the retry loop
+        /// of RFC 6979 for the `Rfc6979` sources.
+        pub fn sign<N: NonceSource>(secret: &SecretKey, hash: &[u8], mut nonce: N) -> Signature {
+            let e = Scalar::from_hash_bits(hash);
+            nonce.init(&secret.to_bytes(), &e.to_bytes());
+            let mut candidate = [0u8; Scalar::SIZE_BYTES];
+            loop {
+                nonce.next(&mut candidate);
+                if let Some(k) = nonce_of(&mut candidate) {
+                    if let Some(signature) = sign_with(secret, &e, &k) {
+                        return signature;
+                    }
+                }
+            }
+        }
+
+        /// Same as `sign`, the signature being verified before being released, None if
+        /// the verification fails
+        ///
+        /// A fault during the signature (e.g. on the multiplication of the nonce) gives
+        /// a signature which leaks the secret key, and doesn't verify. This costs the
+        /// public key multiplication and the verification, about three times `sign`,
+        /// see the `fault` module.
+        #[cfg(feature = "fault-hardening")]
+        pub fn sign_verified<N: NonceSource>(
+            secret: &SecretKey,
+            hash: &[u8],
+            nonce: N,
+        ) -> Option<Signature> {
+            let signature = sign(secret, hash, nonce);
+            if verify(&secret.public_key(), hash, &signature) {
+                Some(signature)
+            } else {
+                None
+            }
+        }
+
+        /// Sign the message hash with the given nonce k
+        ///
+        /// Only for test vectors: signing two different messages with the same k
+        /// (or with related values of k) discloses the secret key. Returns None if k
+        /// gives a zero r or s.
+        #[cfg(feature = "dangerous-apis")]
+        pub fn sign_with_fixed_nonce_for_testing(
+            secret: &SecretKey,
+            hash: &[u8],
+            k: &Scalar,
+        ) -> Option<Signature> {
+            sign_with(secret, &Scalar::from_hash_bits(hash), k)
+        }
+
+        /// Verify the signature of the message hash against the public key
+        ///
+        /// Both (r, s) and (r, -s) are accepted, see `verify_with` to reject the high s.
+        /// This is variable time, all the inputs being public.
+        pub fn verify(public: &PublicKey, hash: &[u8], signature: &Signature) -> bool {
+            verify_with(public, hash, signature, &VerifyOptions::default())
+        }
+
+        /// Same as `verify`, with the options of the verification
+        pub fn verify_with(
+            public: &PublicKey,
+            hash: &[u8],
+            signature: &Signature,
+            options: &VerifyOptions,
+        ) -> bool {
+            if !options.allow_high_s && !signature.is_normalized() {
+                return false;
+            }
+            let e = Scalar::from_hash_bits(hash);
+            // s is public, and not zero
+            let w = signature.s.inverse_vartime().expect("non zero s");
+            let u1 = &e * &w;
+            let u2 = &signature.r * &w;
+            let q = Point::from(public.as_point());
+            let x = match Point::mul_gen_add_vartime(&u1, &u2, &q).to_affine() {
+                None => return false,
+                Some(x) => x,
+            };
+            Scalar::from_field_reduced(x.to_coordinate().0).0 == signature.r
+        }
+
+        /// Signature of a message fed in chunks, hashed with H
+        ///
+        /// `finalize` gives the same signature as `sign` on the hash of the whole message.
+        pub struct SigningContext<'a, H: hmac::digest::Digest> {
+            secret: &'a SecretKey,
+            hasher: H,
+        }
+
+        impl<'a, H: hmac::digest::Digest> SigningContext<'a, H> {
+            /// Start the signature of a message with the secret key, with an empty hash
+            pub fn new(secret: &'a SecretKey) -> Self {
+                SigningContext {
+                    secret,
+                    hasher: H::new(),
+                }
+            }
+
+            /// Hash the next chunk of the message
+            pub fn update(&mut self, chunk: &[u8]) {
+                self.hasher.update(chunk)
+            }
+
+            /// Sign the hash of the message, the nonce being drawn from the nonce source
+            pub fn finalize<N: NonceSource>(self, nonce: N) -> Signature {
+                sign(self.secret, &self.hasher.finalize(), nonce)
+            }
+        }
+
+        /// Verification of the signature of a message fed in chunks, hashed with H
+        ///
+        /// `finalize` gives the same result as `verify` on the hash of the whole message.
+        pub struct VerifyingContext<'a, H: hmac::digest::Digest> {
+            public: &'a PublicKey,
+            hasher: H,
+        }
+
+        impl<'a, H: hmac::digest::Digest> VerifyingContext<'a, H> {
+            /// Start the verification of a message with the public key, with an empty hash
+            pub fn new(public: &'a PublicKey) -> Self {
+                VerifyingContext {
+                    public,
+                    hasher: H::new(),
+                }
+            }
+
+            /// Hash the next chunk of the message
+            pub fn update(&mut self, chunk: &[u8]) {
+                self.hasher.update(chunk)
+            }
+
+            /// Verify the signature of the hash of the message
+            pub fn finalize(self, signature: &Signature) -> bool {
+                self.finalize_with(signature, &VerifyOptions::default())
+            }
+
+            /// Same as `finalize`, with the options of the verification
+            pub fn finalize_with(self, signature: &Signature, options: &VerifyOptions) -> bool {
+                verify_with(self.public, &self.hasher.finalize(), signature, options)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_ecdsa_unittest {
+    () => {
+        use sha2::{Digest, Sha256};
+        use $crate::tests::rng::TestRng;
+
+        fn hash(message: &[u8]) -> Vec<u8> {
+            Sha256::digest(message).to_vec()
+        }
+
+        fn secret(seed: u64) -> SecretKey {
+            SecretKey::generate(&mut TestRng::new(seed))
+        }
+
+        #[test]
+        fn roundtrip() {
+            for seed in [1, 0x5eed, 0xdeadbeef] {
+                let d = secret(seed);
+                let q = d.public_key();
+                for message in [&b""[..], b"sample", b"test"] {
+                    let h = hash(message);
+                    let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+                    assert!(verify(&q, &h, &signature));
+                    // deterministic
+                    assert_eq!(sign(&d, &h, Rfc6979::<Sha256>::new()), signature);
+                    let bytes = signature.to_bytes();
+                    assert_eq!(Signature::from_slice(&bytes), Some(signature));
+                }
+            }
+        }
+
+        #[test]
+        fn rejected() {
+            let d = secret(0x1234);
+            let h = hash(b"rejected");
+            let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+            assert!(!verify(&secret(0x5678).public_key(), &h, &signature));
+            assert!(!verify(&d.public_key(), &hash(b"other"), &signature));
+
+            let bytes = signature.to_bytes();
+            for i in 0..bytes.len() {
+                let mut m = bytes;
+                m[i] ^= 0x01;
+                if let Some(modified) = Signature::from_slice(&m) {
+                    assert!(!verify(&d.public_key(), &h, &modified), "byte {}", i);
+                }
+            }
+            // zero and out of range r or s
+            let mut zero = bytes;
+            zero[..Scalar::SIZE_BYTES].iter_mut().for_each(|b| *b = 0);
+            assert_eq!(Signature::from_slice(&zero), None);
+            let mut high = bytes;
+            high[Scalar::SIZE_BYTES..]
+                .iter_mut()
+                .for_each(|b| *b = 0xff);
+            assert_eq!(Signature::from_slice(&high), None);
+            assert_eq!(Signature::from_slice(&bytes[1..]), None);
+        }
+
+        #[test]
+        fn serialization() {
+            let d = secret(0x5e71);
+            let signature = sign(&d, &hash(b"bytes"), Rfc6979::<Sha256>::new());
+            let bytes = signature.to_bytes();
+            assert_eq!(bytes.len(), 2 * Scalar::SIZE_BYTES);
+            assert_eq!(Signature::from_bytes(&bytes), Some(signature.clone()));
+            assert_eq!(&bytes[..Scalar::SIZE_BYTES], &signature.r().to_bytes()[..]);
+            assert_eq!(&bytes[Scalar::SIZE_BYTES..], &signature.s().to_bytes()[..]);
+
+            let r = signature.r().clone();
+            assert_eq!(Signature::from_scalars(Scalar::zero(), r.clone()), None);
+            assert_eq!(Signature::from_scalars(r.clone(), Scalar::zero()), None);
+            assert_eq!(Signature::from_bytes(&[0; 2 * Scalar::SIZE_BYTES]), None);
+        }
+
+        #[test]
+        fn normalization() {
+            let strict = VerifyOptions {
+                allow_high_s: false,
+            };
+            let (mut low, mut high) = (0, 0);
+            for seed in 0..8u64 {
+                let d = secret(0x4e00 + seed);
+                let h = hash(&seed.to_be_bytes());
+                let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+                let normalized = signature.normalize_s();
+                let flipped =
+                    Signature::from_scalars(signature.r().clone(), -signature.s()).unwrap();
+
+                assert!(normalized.is_normalized());
+                assert_eq!(normalized.normalize_s(), normalized);
+                assert_eq!(flipped.normalize_s(), normalized);
+                assert_eq!(signature.is_normalized(), signature == normalized);
+                assert_ne!(flipped.is_normalized(), signature.is_normalized());
+
+                // both verify by default, only the low s one when strict
+                for sig in [&signature, &flipped] {
+                    assert!(verify(&d.public_key(), &h, sig));
+                    assert_eq!(
+                        verify_with(&d.public_key(), &h, sig, &strict),
+                        sig.is_normalized()
+                    );
+                }
+                if signature.is_normalized() {
+                    low += 1;
+                } else {
+                    high += 1;
+                }
+            }
+            assert!(low > 0 && high > 0);
+
+            // the boundary: (n-1)/2 is normalized, (n+1)/2 isn't
+            let r = Scalar::one();
+            let half = Signature::from_scalars(r.clone(), Scalar::half_max()).unwrap();
+            assert!(half.is_normalized());
+            let above = Signature::from_scalars(r, Scalar::half_max() + Scalar::one()).unwrap();
+            assert!(!above.is_normalized());
+            assert_eq!(above.normalize_s(), half);
+        }
+
+        #[test]
+        fn streaming() {
+            let d = secret(0x57e4);
+            let q = d.public_key();
+            let message: Vec<u8> = (0..300u32).map(|i| (i * 7) as u8).collect();
+            for len in [0, 1, 64, 65, 300] {
+                let message = &message[..len];
+                let h = hash(message);
+                let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+
+                for chunk_size in [1, 7, 64] {
+                    let mut signer = SigningContext::<Sha256>::new(&d);
+                    let mut verifier = VerifyingContext::<Sha256>::new(&q);
+                    for chunk in message.chunks(chunk_size) {
+                        signer.update(chunk);
+                        verifier.update(chunk);
+                    }
+                    assert_eq!(signer.finalize(Rfc6979::<Sha256>::new()), signature);
+                    assert!(verifier.finalize(&signature));
+                }
+
+                let mut verifier = VerifyingContext::<Sha256>::new(&q);
+                verifier.update(message);
+                verifier.update(b"!");
+                assert!(!verifier.finalize(&signature));
+
+                let strict = VerifyOptions {
+                    allow_high_s: false,
+                };
+                let mut verifier = VerifyingContext::<Sha256>::new(&q);
+                verifier.update(message);
+                assert_eq!(
+                    verifier.finalize_with(&signature, &strict),
+                    signature.is_normalized()
+                );
+            }
+        }
+
+        #[test]
+        fn hedged() {
+            let d = secret(0x9abc);
+            let h = hash(b"hedged");
+            let mut nonce = Rfc6979WithExtraEntropy::<Sha256, _>::new(TestRng::new(0xdef0));
+            let s1 = sign(&d, &h, &mut nonce);
+            let s2 = sign(&d, &h, &mut nonce);
+            assert!(verify(&d.public_key(), &h, &s1));
+            assert!(verify(&d.public_key(), &h, &s2));
+            assert_ne!(s1, s2);
+            assert_ne!(s1, sign(&d, &h, Rfc6979::<Sha256>::new()));
+        }
+
+        #[test]
+        fn random_nonce() {
+            let d = secret(0x4321);
+            let h = hash(b"random");
+            let mut nonce = RandomNonce::new(TestRng::new(0x8765));
+            let s1 = sign(&d, &h, &mut nonce);
+            let s2 = sign(&d, &h, &mut nonce);
+            assert!(verify(&d.public_key(), &h, &s1));
+            assert!(verify(&d.public_key(), &h, &s2));
+            assert_ne!(s1, s2);
+        }
+
+        // why the nonce is never taken directly: the same k on two different messages
+        // gives k = (e1 - e2) / (s1 - s2), and then the secret key d = (s1.k - e1) / r
+        #[cfg(feature = "dangerous-apis")]
+        #[test]
+        fn nonce_reuse() {
+            let d = secret(0xcafe);
+            let k = Scalar::from_u64(0x1234_5678);
+            let (h1, h2) = (hash(b"first"), hash(b"second"));
+            let s1 = sign_with_fixed_nonce_for_testing(&d, &h1, &k).unwrap();
+            let s2 = sign_with_fixed_nonce_for_testing(&d, &h2, &k).unwrap();
+            assert!(verify(&d.public_key(), &h1, &s1));
+            assert!(verify(&d.public_key(), &h2, &s2));
+            assert_eq!(s1.r(), s2.r());
+
+            let (e1, e2) = (Scalar::from_hash_bits(&h1), Scalar::from_hash_bits(&h2));
+            let recovered_k = (&e1 - &e2) * (s1.s() - s2.s()).inverse();
+            assert_eq!(recovered_k, k);
+            let recovered_d = (s1.s() * &recovered_k - &e1) * s1.r().inverse();
+            assert_eq!(&recovered_d, d.as_scalar());
+        }
+    };
+}
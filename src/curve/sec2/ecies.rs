@@ -0,0 +1,231 @@
+//! Elliptic Curve Integrated Encryption Scheme shared construction
+//!
+//! Each message is encrypted under a fresh ephemeral key pair (k, E = k.G),
+//! and the output blob is:
+//!
+//! ```text
+//! compressed(E) || ciphertext || tag
+//! ```
+//!
+//! where compressed(E) is the 0x02/0x03 prefixed X coordinate of E. The symmetric
+//! key and nonce are derived from the X coordinate of the shared point k.R (or d.E on
+//! the recipient side) with:
+//!
+//! ```text
+//! key || nonce = HKDF-SHA256(salt = compressed(E), ikm = x(shared), info = "eccoxide-ecies-v1/<curve>", L = 44)
+//! ```
+//!
+//! and the plaintext is encrypted with ChaCha20-Poly1305 (RFC 8439), with the 32 bytes key,
+//! 12 bytes nonce and no associated data. ChaCha20-Poly1305 is used rather than AES-GCM
+//! since it is constant time without hardware support.
+//!
+//! Since the ephemeral key is never reused, each key is used for a single message and
+//! deriving the nonce along with the key is safe. The ephemeral public key is bound
+//! as the HKDF salt, and the curve name as the HKDF info for domain separation. The tag
+//! is always verified before any plaintext is returned.
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// Size in bytes of the authentication tag
+pub(crate) const TAG_SIZE: usize = 16;
+
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+
+fn cipher(info: &[u8], ephemeral: &[u8], shared_x: &[u8]) -> (ChaCha20Poly1305, Nonce) {
+    let mut okm = [0u8; KEY_SIZE + NONCE_SIZE];
+    Hkdf::<Sha256>::new(Some(ephemeral), shared_x)
+        .expand(info, &mut okm)
+        .expect("valid HKDF output length");
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&okm[..KEY_SIZE]));
+    (cipher, *Nonce::from_slice(&okm[KEY_SIZE..]))
+}
+
+/// Encrypt the plaintext and return the ephemeral || ciphertext || tag blob
+pub(crate) fn seal(info: &[u8], ephemeral: &[u8], shared_x: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let (cipher, nonce) = cipher(info, ephemeral, shared_x);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .expect("plaintext within ChaCha20-Poly1305 limits");
+    let mut out = Vec::with_capacity(ephemeral.len() + ciphertext.len());
+    out.extend_from_slice(ephemeral);
+    out.extend_from_slice(&ciphertext);
+    out
+}
+
+/// Verify the tag and decrypt the ciphertext || tag, returning None if the verification fails
+pub(crate) fn open(
+    info: &[u8],
+    ephemeral: &[u8],
+    shared_x: &[u8],
+    ciphertext: &[u8],
+) -> Option<Vec<u8>> {
+    let (cipher, nonce) = cipher(info, ephemeral, shared_x);
+    cipher.decrypt(&nonce, ciphertext).ok()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_ecies_define {
+    ($curve:literal) => {
+        use super::{FieldElement, Point, PointAffine, Scalar};
+        use $crate::curve::sec2::ecies::{open, seal, TAG_SIZE};
+        use $crate::curve::Sign;
+
+        const INFO: &[u8] = concat!("eccoxide-ecies-v1/", $curve).as_bytes();
+        const EPHEMERAL_SIZE: usize = 1 + FieldElement::SIZE_BYTES;
+
+        /// Number of bytes added to the plaintext by the encryption
+        pub const OVERHEAD: usize = EPHEMERAL_SIZE + TAG_SIZE;
+
+        fn shared_x(secret: &Scalar, peer: &PointAffine) -> Option<[u8; FieldElement::SIZE_BYTES]> {
+            let p = Point::from(peer).scale_ct(secret).to_affine()?;
+            Some(p.to_coordinate().0.to_bytes())
+        }
+
+        fn compress(p: &PointAffine) -> [u8; EPHEMERAL_SIZE] {
+            let (x, sign) = p.compress();
+            let mut out = [0u8; EPHEMERAL_SIZE];
+            out[0] = match sign {
+                Sign::Positive => 0x02,
+                Sign::Negative => 0x03,
+            };
+            out[1..].copy_from_slice(&x.to_bytes());
+            out
+        }
+
+        fn decompress(bytes: &[u8]) -> Option<PointAffine> {
+            let sign = match bytes[0] {
+                0x02 => Sign::Positive,
+                0x03 => Sign::Negative,
+                _ => return None,
+            };
+            let x = FieldElement::from_slice(&bytes[1..])?;
+            PointAffine::decompress(&x, sign)
+        }
+
+        /// Encrypt the plaintext to the recipient public key
+        ///
+        /// A new ephemeral key is drawn from the random generator for each call, and the
+        /// output is the compressed ephemeral public key followed by the ciphertext and the tag,
+        /// which is `OVERHEAD` bytes longer than the plaintext.
+        pub fn encrypt<R: rand_core::RngCore + rand_core::CryptoRng>(
+            recipient: &PointAffine,
+            plaintext: &[u8],
+            rng: &mut R,
+        ) -> Vec<u8> {
+            loop {
+                let ephemeral = Scalar::random(rng);
+                if !ephemeral.is_zero() {
+                    return encrypt_with_ephemeral(recipient, plaintext, &ephemeral);
+                }
+            }
+        }
+
+        fn encrypt_with_ephemeral(
+            recipient: &PointAffine,
+            plaintext: &[u8],
+            ephemeral: &Scalar,
+        ) -> Vec<u8> {
+            let e = Point::generator()
+                .scale_ct(ephemeral)
+                .to_affine()
+                .expect("non zero ephemeral key");
+            // the group order is prime, so a non zero multiple of a valid point is never infinity
+            let shared = shared_x(ephemeral, recipient).expect("non zero shared point");
+            seal(INFO, &compress(&e), &shared, plaintext)
+        }
+
+        /// Decrypt a blob produced by `encrypt` with the recipient secret key
+        ///
+        /// Returns None if the blob is too short, the ephemeral public key is not a valid
+        /// point, the secret key is zero, or the authentication tag doesn't verify
+        pub fn decrypt(secret: &Scalar, blob: &[u8]) -> Option<Vec<u8>> {
+            if blob.len() < OVERHEAD {
+                return None;
+            }
+            let (ephemeral, ciphertext) = blob.split_at(EPHEMERAL_SIZE);
+            let e = decompress(ephemeral)?;
+            let shared = shared_x(secret, &e)?;
+            open(INFO, ephemeral, &shared, ciphertext)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_ecies_unittest {
+    () => {
+        use $crate::tests::hex::from_hex;
+        use $crate::tests::rng::TestRng;
+
+        fn public(secret: &Scalar) -> PointAffine {
+            (&Point::generator() * secret).to_affine().unwrap()
+        }
+
+        #[test]
+        fn vectors() {
+            for (d, k, plaintext, blob) in VECTORS.iter() {
+                let d = Scalar::from_slice(&from_hex(d)).unwrap();
+                let k = Scalar::from_slice(&from_hex(k)).unwrap();
+                let (plaintext, blob) = (from_hex(plaintext), from_hex(blob));
+                assert_eq!(encrypt_with_ephemeral(&public(&d), &plaintext, &k), blob);
+                assert_eq!(decrypt(&d, &blob), Some(plaintext));
+            }
+        }
+
+        #[test]
+        fn roundtrip() {
+            let mut rng = TestRng::new(0x1234);
+            let d = Scalar::random(&mut rng);
+            let q = public(&d);
+            for len in [0, 1, 15, 16, 17, 64, 200].iter() {
+                let plaintext = vec![0xa5; *len];
+                let blob = encrypt(&q, &plaintext, &mut rng);
+                assert_eq!(blob.len(), plaintext.len() + OVERHEAD);
+                assert_eq!(decrypt(&d, &blob), Some(plaintext.clone()));
+                // a fresh ephemeral key is used every time
+                assert_ne!(encrypt(&q, &plaintext, &mut rng), blob);
+            }
+        }
+
+        #[test]
+        fn truncated() {
+            let mut rng = TestRng::new(0x5678);
+            let d = Scalar::random(&mut rng);
+            let blob = encrypt(&public(&d), b"truncated", &mut rng);
+            for len in 0..blob.len() {
+                assert_eq!(decrypt(&d, &blob[..len]), None, "length {}", len);
+            }
+            let mut extended = blob.clone();
+            extended.push(0);
+            assert_eq!(decrypt(&d, &extended), None);
+        }
+
+        #[test]
+        fn modified() {
+            let mut rng = TestRng::new(0x9abc);
+            let d = Scalar::random(&mut rng);
+            let blob = encrypt(&public(&d), b"modified", &mut rng);
+            for i in 0..blob.len() {
+                for bit in 0..8 {
+                    let mut m = blob.clone();
+                    m[i] ^= 1 << bit;
+                    assert_eq!(decrypt(&d, &m), None, "byte {} bit {}", i, bit);
+                }
+            }
+        }
+
+        #[test]
+        fn wrong_secret() {
+            let mut rng = TestRng::new(0xdef0);
+            let d = Scalar::random(&mut rng);
+            let blob = encrypt(&public(&d), b"wrong secret", &mut rng);
+            assert_eq!(decrypt(&(&d + &Scalar::one()), &blob), None);
+            assert_eq!(decrypt(&Scalar::zero(), &blob), None);
+        }
+    };
+}
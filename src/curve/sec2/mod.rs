@@ -11,6 +11,18 @@
 ))]
 mod bigint;
 
+#[cfg(any(test, feature = "small-code"))]
+mod chains;
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+
+#[cfg(feature = "ecies")]
+mod ecies;
+
+#[cfg(feature = "transcript")]
+pub mod transcript;
+
 #[cfg(feature = "p192k1")]
 pub mod p192k1;
 #[cfg(feature = "p192r1")]
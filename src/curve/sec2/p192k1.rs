@@ -1,16 +1,25 @@
 //! Curve p192k1 as defined over the prime field of order 2^192 - 2^32 - 2^12 - 2^8 - 2^7 - 2^6 - 2^3 - 1
 
-use crate::curve::fiat::p192k1_64::*;
-use crate::curve::fiat::p192k1_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::fiat::vendored::{P192k1 as FieldOps, P192k1Scalar as ScalarOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
 use crate::curve::{
-    affine, projective,
+    affine, jacobian, projective,
     weierstrass::{WeierstrassCurve, WeierstrassCurveA0},
 };
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p192k1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 3;
 const FE_LIMBS_SIZE: usize = 3;
@@ -20,29 +29,34 @@ fiat_field_ops_impl!(
     FieldElement,
     192,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p192k1_nonzero,
-    fiat_p192k1_add,
-    fiat_p192k1_sub,
-    fiat_p192k1_mul,
-    fiat_p192k1_square,
-    fiat_p192k1_opp,
-    fiat_p192k1_to_bytes,
-    fiat_p192k1_from_bytes,
-    montgomery {
-        fiat_p192k1_to_montgomery,
-        fiat_p192k1_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
     ///
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
-        // 1*159,0*1,1*19,0*1,1*3,0*3,1*2,0*1,1*1,0*1,1*1
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p192k1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // 1*159,0*1,1*19,0*1,1*3,0*3,1*2,0*1,1*1,0*1,1*1
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -64,6 +78,18 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::p192k1::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
         // 1*159,0*1,1*19,0*1,1*3,0*3,1*3,0*1
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
@@ -81,9 +107,7 @@ impl FieldElement {
         t1 = t1.square_rep(4) * &x3; // 0*1 1*3
         t1 = t1.square_rep(6) * &x3; // 0*3 1*3
 
-        let r = &t1 * &t1;
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        &t1 * &t1
     }
 }
 
@@ -92,19 +116,10 @@ fiat_field_ops_impl!(
     Scalar,
     192,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p192k1_scalar_nonzero,
-    fiat_p192k1_scalar_add,
-    fiat_p192k1_scalar_sub,
-    fiat_p192k1_scalar_mul,
-    fiat_p192k1_scalar_square,
-    fiat_p192k1_scalar_opp,
-    fiat_p192k1_scalar_to_bytes,
-    fiat_p192k1_scalar_from_bytes,
-    montgomery {
-        fiat_p192k1_scalar_to_montgomery,
-        fiat_p192k1_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -113,8 +128,21 @@ impl Scalar {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
-        // 1*95,0*3,1*1,0*2,1*2,0*1,1*4,0*2,1*1,0*1,1*6,0*5,1*1,0*1,1*3,0*4,1*4,0*1,1*2,0*1,1*1,0*2,1*1,0*1,1*1,0*3,1*2,0*2,1*2,0*1,1*1,0*1,1*1,0*2,1*3,0*1,1*1,0*2,1*2,0*1,1*4,0*1,1*6,0*1,1*2,0*3,1*1,0*1,1*2
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p192k1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // 1*95,0*3,1*1,0*2,1*2,0*1,1*4,0*2,1*1,0*1,1*6,0*5,1*1,0*1,1*3,0*4,1*4,0*1,1*2,0*1,1*1,0*2,1*1,0*1,1*1,0*3,1*2,0*2,1*2,0*1,1*1,0*1,1*1,0*2,1*3,0*1,1*1,0*2,1*2,0*1,1*4,0*1,1*6,0*1,1*2,0*3,1*1,0*1,1*2
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x4 = x3.square() * self;
@@ -155,8 +183,15 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p192k1");
 
 impl WeierstrassCurveA0 for Curve {}
 
@@ -165,7 +200,28 @@ impl Point {
         Point(self.0.add_or_double_a0(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale_a0(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits_a0(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(
+            self.0
+                .scale_ladder_bits_a0(other.iter_bits_msb_first(), Curve),
+        )
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf_a0(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(p.scale_vartime_a0(&other.to_bytes(), Curve).to_projective())
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_a0(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -173,14 +229,37 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p192k1::FE_INVERSE,
+            sqrt_chain: sqrt_chain == crate::curve::sec2::chains::p192k1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p192k1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
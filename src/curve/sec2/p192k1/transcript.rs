@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p192k1
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p192k1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "f82d01693bd3940d38f5fa38dfef32366271501a9be5b817",
+        "44d270c5624c239261855068ea724dde3ead4b594c41aedc"
+    );
+}
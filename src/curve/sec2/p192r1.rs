@@ -1,13 +1,25 @@
 //! Curve p192r1 as defined over the prime field of order 2^192 - 2^64 - 1
 
-use crate::curve::fiat::p192r1_64::*;
-use crate::curve::fiat::p192r1_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::fiat::vendored::{P192r1 as FieldOps, P192r1Scalar as ScalarOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
-use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p192r1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 3;
 const FE_LIMBS_SIZE: usize = 3;
@@ -17,21 +29,13 @@ fiat_field_ops_impl!(
     FieldElement,
     192,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p192r1_nonzero,
-    fiat_p192r1_add,
-    fiat_p192r1_sub,
-    fiat_p192r1_mul,
-    fiat_p192r1_square,
-    fiat_p192r1_opp,
-    fiat_p192r1_to_bytes,
-    fiat_p192r1_from_bytes,
-    montgomery {
-        fiat_p192r1_to_montgomery,
-        fiat_p192r1_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
@@ -39,6 +43,19 @@ impl FieldElement {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p192r1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         // 1*127,0*1,1*62,0*1,1*1
         let x2 = self.square() * self;
         let x4 = x2.square_rep(2) * &x2;
@@ -59,6 +76,18 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::p192r1::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
         // 1*128,0*62
         let x2 = self.square() * self;
         let x4 = x2.square_rep(2) * &x2;
@@ -68,10 +97,7 @@ impl FieldElement {
         let x64 = x32.square_rep(32) * &x32;
         let x128 = x64.square_rep(64) * &x64;
 
-        let r = x128.square_rep(62); // 1*128 0*62
-
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        x128.square_rep(62) // 1*128 0*62
     }
 }
 
@@ -80,19 +106,10 @@ fiat_field_ops_impl!(
     Scalar,
     192,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p192r1_scalar_nonzero,
-    fiat_p192r1_scalar_add,
-    fiat_p192r1_scalar_sub,
-    fiat_p192r1_scalar_mul,
-    fiat_p192r1_scalar_square,
-    fiat_p192r1_scalar_opp,
-    fiat_p192r1_scalar_to_bytes,
-    fiat_p192r1_scalar_from_bytes,
-    montgomery {
-        fiat_p192r1_scalar_to_montgomery,
-        fiat_p192r1_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -102,6 +119,19 @@ impl Scalar {
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p192r1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x4 = x3.square() * self;
@@ -143,15 +173,45 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p192r1");
+
+impl WeierstrassCurveAM3 for Curve {}
 
 impl Point {
     fn add_or_double<'b>(&self, other: &'b Point) -> Point {
         Point(self.0.add_or_double(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -159,14 +219,37 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
-
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
+
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p192r1::FE_INVERSE,
+            sqrt_chain: sqrt_chain == crate::curve::sec2::chains::p192r1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p192r1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
@@ -0,0 +1,14 @@
+//! Elliptic Curve Digital Signature Algorithm on p192r1 (NIST P-192)
+//!
+//! Sign message hashes with a `SecretKey` and verify them with a `PublicKey`, the
+//! nonces being drawn from a `NonceSource` (see the `sec2::ecdsa` module), e.g.
+//! `sign(&secret, &hash, Rfc6979::<Sha256>::new())` for RFC 6979 deterministic nonces.
+
+crate::sec2_ecdsa_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_ecdsa_unittest!();
+}
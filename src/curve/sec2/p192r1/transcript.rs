@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p192r1 (NIST P-192)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p192r1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "e229224117f2aa5ee34da921bd3355f2f64c07af5ddc74cf",
+        "29143d5725462c9b8747004f6c1963b15b6917167e0c7f94"
+    );
+}
@@ -1,16 +1,25 @@
 //! Curve p224k1 as defined over the prime field of order  2^224 - 2^32 - 2^12 - 2^11 - 2^9 - 2^7 - 2^4 - 2 - 1
 
-use crate::curve::fiat::p224k1_64::*;
-use crate::curve::fiat::p224k1_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::fiat::vendored::{P224k1 as FieldOps, P224k1Scalar as ScalarOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
 use crate::curve::{
-    affine, projective,
+    affine, jacobian, projective,
     weierstrass::{WeierstrassCurve, WeierstrassCurveA0},
 };
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p224k1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 4;
 const FE_LIMBS_SIZE: usize = 4;
@@ -20,21 +29,13 @@ fiat_field_ops_impl!(
     FieldElement,
     224,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p224k1_nonzero,
-    fiat_p224k1_add,
-    fiat_p224k1_sub,
-    fiat_p224k1_mul,
-    fiat_p224k1_square,
-    fiat_p224k1_opp,
-    fiat_p224k1_to_bytes,
-    fiat_p224k1_from_bytes,
-    montgomery {
-        fiat_p224k1_to_montgomery,
-        fiat_p224k1_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
@@ -42,6 +43,19 @@ impl FieldElement {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p224k1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x4 = x3.square() * self;
@@ -73,30 +87,10 @@ impl FieldElement {
 
         // raise 2*self == x to power of (p-5)/8
         let x = self.double();
-        let gamma = {
-            let x2 = x.square() * &x;
-            let x3 = x2.square() * &x;
-            let x4 = x3.square() * &x;
-            let x6 = x3.square_rep(3) * &x3;
-            let x9 = x6.square_rep(3) * &x3;
-            let x11 = x9.square_rep(2) * &x2;
-            let x17 = x11.square_rep(6) * &x6;
-            let x19 = x17.square_rep(2) * &x2;
-            let x22 = x11.square_rep(11) * &x11;
-            let x44 = x22.square_rep(22) * &x22;
-            let x88 = x44.square_rep(44) * &x44;
-            let x176 = x88.square_rep(88) * &x88;
-            let x187 = x176.square_rep(11) * &x11;
-            let x191 = x187.square_rep(4) * &x4;
-
-            // 1*191,0*1,1*19,0*2,1*1,0*1,1*1,0*1,1*2,0*1,1*1
-            let mut t1 = x191.square_rep(20) * &x19; // 1*191 0*1 1*19
-            t1 = t1.square_rep(3) * &x; // 0*2 1*1
-            t1 = t1.square_rep(2) * &x; // 0*1 1*1
-            t1 = t1.square_rep(3) * &x2; // 0*1 1*2
-            t1 = t1.square_rep(2) * &x; // 0*1 1*1
-            t1
-        };
+        #[cfg(not(feature = "small-code"))]
+        let gamma = x.sqrt_gamma_chain();
+        #[cfg(feature = "small-code")]
+        let gamma = x.pow_chain(&chains::p224k1::FE_SQRT);
 
         let ggamma = self * &gamma;
         let i = (&ggamma * &gamma).double();
@@ -106,6 +100,33 @@ impl FieldElement {
         let r2 = &r * &r;
         CtOption::from((CtEqual::ct_eq(&r2, self), r))
     }
+
+    // self^((p-5)/8) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_gamma_chain(&self) -> Self {
+        let x2 = self.square() * self;
+        let x3 = x2.square() * self;
+        let x4 = x3.square() * self;
+        let x6 = x3.square_rep(3) * &x3;
+        let x9 = x6.square_rep(3) * &x3;
+        let x11 = x9.square_rep(2) * &x2;
+        let x17 = x11.square_rep(6) * &x6;
+        let x19 = x17.square_rep(2) * &x2;
+        let x22 = x11.square_rep(11) * &x11;
+        let x44 = x22.square_rep(22) * &x22;
+        let x88 = x44.square_rep(44) * &x44;
+        let x176 = x88.square_rep(88) * &x88;
+        let x187 = x176.square_rep(11) * &x11;
+        let x191 = x187.square_rep(4) * &x4;
+
+        // 1*191,0*1,1*19,0*2,1*1,0*1,1*1,0*1,1*2,0*1,1*1
+        let mut t1 = x191.square_rep(20) * &x19; // 1*191 0*1 1*19
+        t1 = t1.square_rep(3) * self; // 0*2 1*1
+        t1 = t1.square_rep(2) * self; // 0*1 1*1
+        t1 = t1.square_rep(3) * &x2; // 0*1 1*2
+        t1 = t1.square_rep(2) * self; // 0*1 1*1
+        t1
+    }
 }
 
 fiat_field_ops_impl!(
@@ -113,19 +134,10 @@ fiat_field_ops_impl!(
     Scalar,
     225,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p224k1_scalar_nonzero,
-    fiat_p224k1_scalar_add,
-    fiat_p224k1_scalar_sub,
-    fiat_p224k1_scalar_mul,
-    fiat_p224k1_scalar_square,
-    fiat_p224k1_scalar_opp,
-    fiat_p224k1_scalar_to_bytes,
-    fiat_p224k1_scalar_from_bytes,
-    montgomery {
-        fiat_p224k1_scalar_to_montgomery,
-        fiat_p224k1_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -135,6 +147,19 @@ impl Scalar {
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p224k1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x4 = x3.square() * self;
@@ -176,8 +201,15 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p224k1");
 
 impl WeierstrassCurveA0 for Curve {}
 
@@ -186,7 +218,28 @@ impl Point {
         Point(self.0.add_or_double_a0(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale_a0(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits_a0(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(
+            self.0
+                .scale_ladder_bits_a0(other.iter_bits_msb_first(), Curve),
+        )
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf_a0(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(p.scale_vartime_a0(&other.to_bytes(), Curve).to_projective())
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_a0(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -194,14 +247,118 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p224k1::FE_INVERSE,
+            sqrt_chain: sqrt_gamma_chain == crate::curve::sec2::chains::p224k1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p224k1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+
+        // the order is slightly above 2^224, so the scalar is 225 bits and need 29 bytes,
+        // whereas the field element is 224 bits and 28 bytes.
+        use crate::params::sec2::p224k1::ORDER_BYTES;
+
+        fn two_pow_224() -> [u8; 29] {
+            let mut bytes = [0u8; 29];
+            bytes[0] = 1;
+            bytes
+        }
+
+        #[test]
+        fn sizes() {
+            assert_eq!(Scalar::SIZE_BITS, 225);
+            assert_eq!(Scalar::SIZE_BYTES, 29);
+            assert_eq!(super::super::FieldElement::SIZE_BYTES, 28);
+        }
+
+        #[test]
+        fn bytes_around_2_224() {
+            // 2^224 - 1
+            let mut below = [0xffu8; 29];
+            below[0] = 0;
+            let s_below = Scalar::from_bytes(&below).expect("2^224-1 is a valid scalar");
+            assert_eq!(s_below.to_bytes(), below);
+
+            // 2^224
+            let s = Scalar::from_bytes(&two_pow_224()).expect("2^224 is a valid scalar");
+            assert_eq!(s.to_bytes(), two_pow_224());
+            assert_eq!(&s_below + Scalar::one(), s);
+
+            // 2^224 + 1
+            let mut above = two_pow_224();
+            above[28] = 1;
+            let s_above = Scalar::from_bytes(&above).expect("2^224+1 is a valid scalar");
+            assert_eq!(s_above.to_bytes(), above);
+            assert_eq!(&s + Scalar::one(), s_above);
+        }
+
+        #[test]
+        fn bytes_around_order() {
+            let order_m1 = -Scalar::one();
+            let mut expected = ORDER_BYTES;
+            expected[28] -= 1;
+            assert_eq!(order_m1.to_bytes(), expected);
+            assert_eq!(Scalar::from_bytes(&expected), Some(order_m1));
+
+            assert_eq!(Scalar::from_bytes(&ORDER_BYTES), None);
+            let mut order_p1 = ORDER_BYTES;
+            order_p1[28] += 1;
+            assert_eq!(Scalar::from_bytes(&order_p1), None);
+
+            // any of the 7 high bits of the leading byte makes the value overflow
+            for bit in 1..8 {
+                let mut bytes = [0u8; 29];
+                bytes[0] = 1 << bit;
+                assert_eq!(Scalar::from_bytes(&bytes), None);
+            }
+            assert_eq!(Scalar::from_bytes(&[0xff; 29]), None);
+        }
+
+        #[test]
+        fn slice_length() {
+            let bytes = two_pow_224();
+            assert!(Scalar::from_slice(&bytes).is_some());
+            assert!(Scalar::from_slice(&bytes[1..]).is_none());
+            assert!(Scalar::from_slice(&[0u8; 30]).is_none());
+        }
+    }
+
+    mod point {
+        use super::super::{Point, Scalar};
+
+        #[test]
+        fn scale_around_2_224() {
+            let mut bytes = [0u8; 29];
+            bytes[0] = 1;
+            let s = Scalar::from_bytes(&bytes).unwrap();
+            let s_m1 = &s - Scalar::one();
+
+            let p = &Point::generator() * &s;
+            let p_m1 = &Point::generator() * &s_m1;
+            assert_eq!(p, p_m1 + Point::generator());
+        }
+
+        crate::test_point_operators!(Scalar);
     }
 }
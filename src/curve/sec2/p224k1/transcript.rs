@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p224k1
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p224k1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "00e2669f21886f803348818f9de9869ed8fb743775fbafaf3382f2b88b",
+        "003f0bd18f7e62a5d8ff320766d6bdc13ac22dcf993de8529f09deef07"
+    );
+}
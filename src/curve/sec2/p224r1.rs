@@ -1,12 +1,24 @@
 //! Curve p224r1 as defined over the prime field of order 2^224 - 2^96 + 1
-use crate::curve::fiat::p224r1_64::*;
-use crate::curve::fiat::p224r1_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::fiat::vendored::{P224r1 as FieldOps, P224r1Scalar as ScalarOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
-use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p224r1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 4;
 const FE_LIMBS_SIZE: usize = 4;
@@ -16,21 +28,13 @@ fiat_field_ops_impl!(
     FieldElement,
     224,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p224r1_nonzero,
-    fiat_p224r1_add,
-    fiat_p224r1_sub,
-    fiat_p224r1_mul,
-    fiat_p224r1_square,
-    fiat_p224r1_opp,
-    fiat_p224r1_to_bytes,
-    fiat_p224r1_from_bytes,
-    montgomery {
-        fiat_p224r1_to_montgomery,
-        fiat_p224r1_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement, tuned);
 
 impl FieldElement {
     /// Get the multiplicative inverse
@@ -38,6 +42,19 @@ impl FieldElement {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p224r1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x4 = x3.square() * self;
@@ -56,21 +73,29 @@ impl FieldElement {
         t1
     }
 
+    /// Check if the element is a quadratic residue (a non zero square), with the euler criterion
     pub fn is_quadratic_residue(&self) -> Choice {
-        let euler = {
-            let x2 = self.square() * self;
-            let x4 = x2.square_rep(2) * &x2;
-            let x8 = x4.square_rep(4) * &x4;
-            let x16 = x8.square_rep(8) * &x8;
-            let x32 = x16.square_rep(16) * &x16;
-            let x64 = x32.square_rep(32) * &x32;
-            let x128 = x64.square_rep(64) * &x64;
-            // [1*128,0*95]
-            x128.square_rep(95)
-        };
+        #[cfg(not(feature = "small-code"))]
+        let euler = self.euler_chain();
+        #[cfg(feature = "small-code")]
+        let euler = self.pow_chain(&chains::p224r1::FE_SQRT);
         euler.ct_eq(&FieldElement::one())
     }
 
+    // self^((p-1)/2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn euler_chain(&self) -> Self {
+        let x2 = self.square() * self;
+        let x4 = x2.square_rep(2) * &x2;
+        let x8 = x4.square_rep(4) * &x4;
+        let x16 = x8.square_rep(8) * &x8;
+        let x32 = x16.square_rep(16) * &x16;
+        let x64 = x32.square_rep(32) * &x32;
+        let x128 = x64.square_rep(64) * &x64;
+        // [1*128,0*95]
+        x128.square_rep(95)
+    }
+
     fn square_add_rep(&self, count: usize) -> Self {
         let mut a = self.clone();
         let mut q = Self::one();
@@ -83,52 +108,36 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     ///
-    /// This function is not constant time, as it uses the Tonelli-Shanks algorithm
+    /// This is the constant time Tonelli-Shanks of RFC 9380 (appendix I.4), as in
+    /// `sqrt_generic`, with the values related to the prime p hardcoded: every
+    /// element goes through the same 96 rounds, zero and the non residues included.
     pub fn sqrt(&self) -> CtOption<Self> {
-        // use Tonelli-Shanks algorithm with hardcoded values related to the prime p,
+        use crate::mp::ct::CtSelect;
+
         // Z=11, S=96, Q=340282366920938463463374607431768211455 (0xffffffffffffffffffffffffffffffff)
         // as such that p-1 is q*2^s and z the first non-quadratic residue in p.
         //
-        // also (Q+1)/2 = 0x80000000000000000000000000000000 (1 bit to 1, 127 bits to 0)
-
-        if !bool::from(self.is_quadratic_residue()) {
-            return CtOption::from((CtEqual::ct_ne(self, self), FieldElement::zero()));
-        }
-
-        let n = self;
-        let s = 96;
-
-        let z = FieldElement::from_u64(11);
-
-        let mut m = s;
-        let mut c = z.square_add_rep(128); // c = z.power(&q)
-        let mut t = n.square_add_rep(128); // t = n.power(&q)
-        let mut r = n.square_rep(127); // r = n.power((q+1)/2)
+        // also (Q-1)/2 = 2^127-1 and (Q+1)/2 = 2^127
+        let mut z = self.square_add_rep(127); // self^((q-1)/2)
+        let mut t = &z.square() * self; // self^q
+        z = &z * self; // self^((q+1)/2)
+        let mut b = t.clone();
+        let mut c = FieldElement::from_u64(11).square_add_rep(128); // z^q
 
         let one = FieldElement::one();
-
-        while t != one {
-            let mut tt = t.clone();
-            let mut i = 0;
-            while tt != one {
-                tt = tt.square();
-                i += 1;
-                if i == m {
-                    return CtOption::from((CtEqual::ct_ne(self, self), one));
-                }
+        for i in (2..=96).rev() {
+            for _ in 2..i {
+                b = b.square();
             }
-            let e = m - i - 1;
-            let b = if e == 0 { c.clone() } else { c.square_rep(e) };
-
-            let b2 = b.square();
-            r = r * b;
-            t = t * &b2;
-            c = b2;
-            m = i;
+            let e = b.ct_eq(&one);
+            z = FieldElement::ct_select(&(&z * &c), &z, e);
+            c = c.square();
+            t = FieldElement::ct_select(&(&t * &c), &t, e);
+            b = t.clone();
         }
 
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        // 0 is its own square root, and the non residues don't square back
+        CtOption::from((CtEqual::ct_eq(&z.square(), self), z))
     }
 }
 
@@ -137,19 +146,10 @@ fiat_field_ops_impl!(
     Scalar,
     224,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p224r1_scalar_nonzero,
-    fiat_p224r1_scalar_add,
-    fiat_p224r1_scalar_sub,
-    fiat_p224r1_scalar_mul,
-    fiat_p224r1_scalar_square,
-    fiat_p224r1_scalar_opp,
-    fiat_p224r1_scalar_to_bytes,
-    fiat_p224r1_scalar_from_bytes,
-    montgomery {
-        fiat_p224r1_scalar_to_montgomery,
-        fiat_p224r1_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -159,6 +159,19 @@ impl Scalar {
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p224r1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         // 1*112,0*3,1*1,0*1,1*2,0*1,1*1,0*1,1*1,0*3,1*1,0*1,1*3,0*5,1*1,0*1,1*3,0*3,1*4,0*6,1*5,0*4,1*1,0*2,1*4,0*1,1*3,0*1,1*1,0*2,1*1,0*1,1*1,0*2,1*1,0*1,1*1,0*3,1*1,0*1,1*1,0*1,1*1,0*1,1*3,0*3,1*1,0*1,1*3,0*4,1*1,0*1,1*1,0*1,1*1,0*3,1*3,0*1,1*2
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
@@ -205,15 +218,45 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p224r1");
+
+impl WeierstrassCurveAM3 for Curve {}
 
 impl Point {
     fn add_or_double<'b>(&self, other: &'b Point) -> Point {
         Point(self.0.add_or_double(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -221,14 +264,37 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p224r1::FE_INVERSE,
+            euler_chain: euler_chain == crate::curve::sec2::chains::p224r1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p224r1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
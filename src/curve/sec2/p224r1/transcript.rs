@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p224r1 (NIST P-224)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p224r1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "f211b769ee31cbee6fbdd4bbe0a9d21bb986f370147e1f5fe962fe6a",
+        "5009146fec8a2e35bba8ca221c9c9edc661804078e5a183d0d427ace"
+    );
+}
@@ -2,17 +2,33 @@
 //!
 //! This is also the curve used by some crypto-currencies (e.g. Bitcoin, Ethereum)
 
-use crate::curve::fiat::secp256k1_64::*;
-use crate::curve::fiat::secp256k1_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+#[cfg(feature = "fiat-crypto")]
+use crate::curve::fiat::upstream::{Secp256k1 as FieldOps, Secp256k1Scalar as ScalarOps};
+#[cfg(not(feature = "fiat-crypto"))]
+use crate::curve::fiat::vendored::{Secp256k1 as FieldOps, Secp256k1Scalar as ScalarOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
 use crate::curve::{
-    affine, projective,
+    affine, jacobian, projective,
     weierstrass::{WeierstrassCurve, WeierstrassCurveA0},
 };
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p256k1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+pub mod ecdh;
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+pub mod musig;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 4;
 const FE_LIMBS_SIZE: usize = 4;
@@ -22,21 +38,13 @@ fiat_field_ops_impl!(
     FieldElement,
     256,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_secp256k1_nonzero,
-    fiat_secp256k1_add,
-    fiat_secp256k1_sub,
-    fiat_secp256k1_mul,
-    fiat_secp256k1_square,
-    fiat_secp256k1_opp,
-    fiat_secp256k1_to_bytes,
-    fiat_secp256k1_from_bytes,
-    montgomery {
-        fiat_secp256k1_to_montgomery,
-        fiat_secp256k1_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
@@ -44,6 +52,19 @@ impl FieldElement {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p256k1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -66,6 +87,18 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::p256k1::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -82,9 +115,7 @@ impl FieldElement {
         t1 = t1.square_rep(6) * &x2;
         t1 = &t1 * &t1;
 
-        let r = &t1 * &t1;
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        &t1 * &t1
     }
 }
 
@@ -93,19 +124,10 @@ fiat_field_ops_impl!(
     Scalar,
     256,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_secp256k1_scalar_nonzero,
-    fiat_secp256k1_scalar_add,
-    fiat_secp256k1_scalar_sub,
-    fiat_secp256k1_scalar_mul,
-    fiat_secp256k1_scalar_square,
-    fiat_secp256k1_scalar_opp,
-    fiat_secp256k1_scalar_to_bytes,
-    fiat_secp256k1_scalar_from_bytes,
-    montgomery {
-        fiat_secp256k1_scalar_to_montgomery,
-        fiat_secp256k1_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -115,6 +137,19 @@ impl Scalar {
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p256k1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x = self;
         let u2 = self.square();
         let x2 = &u2 * x;
@@ -163,8 +198,17 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p256k1");
+#[cfg(feature = "jwk")]
+crate::jwk_define!("secp256k1");
 
 impl WeierstrassCurveA0 for Curve {}
 
@@ -173,7 +217,28 @@ impl Point {
         Point(self.0.add_or_double_a0(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale_a0(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits_a0(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(
+            self.0
+                .scale_ladder_bits_a0(other.iter_bits_msb_first(), Curve),
+        )
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf_a0(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(p.scale_vartime_a0(&other.to_bytes(), Curve).to_projective())
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_a0(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -181,14 +246,51 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p256k1::FE_INVERSE,
+            sqrt_chain: sqrt_chain == crate::curve::sec2::chains::p256k1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            FieldElement,
+            crate::curve::fiat::vendored::Secp256k1,
+            crate::curve::fiat::upstream::Secp256k1,
+            montgomery
+        );
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p256k1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            Scalar,
+            crate::curve::fiat::vendored::Secp256k1Scalar,
+            crate::curve::fiat::upstream::Secp256k1Scalar,
+            montgomery
+        );
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
@@ -0,0 +1,184 @@
+//! Elliptic Curve Diffie-Hellman on p256k1, compatible with libsecp256k1
+//!
+//! The shared point is computed with a Montgomery ladder, and output either as
+//! the raw X coordinate (`ecdh_xonly`), or hashed the same way as the default hash
+//! function of libsecp256k1's `secp256k1_ecdh` (`ecdh_libsecp_compatible`):
+//!
+//! ```text
+//! SHA256( (0x02 | parity(y)) || x )
+//! ```
+//!
+//! libsecp256k1 fails on a secret scalar that is zero or not less than the group order,
+//! the latter cannot be represented by a `Scalar` so the functions here return None
+//! only for the zero scalar, or if the shared point is the point at infinity
+//! (which cannot happen with a valid public key, since the group order is prime).
+
+use super::{FieldElement, Point, PointAffine, Scalar};
+
+fn shared_point(scalar: &Scalar, peer: &PointAffine) -> Option<PointAffine> {
+    if scalar.is_zero() {
+        return None;
+    }
+    Point::from(peer).scale_ladder(scalar).to_affine()
+}
+
+/// Compute the shared secret as the big endian X coordinate of `scalar * peer`
+///
+/// Returns None if the scalar is zero
+pub fn ecdh_xonly(scalar: &Scalar, peer: &PointAffine) -> Option<[u8; FieldElement::SIZE_BYTES]> {
+    let p = shared_point(scalar, peer)?;
+    Some(p.to_coordinate().0.to_bytes())
+}
+
+/// Compute the shared secret as the SHA256 of the compressed encoding of `scalar * peer`
+///
+/// This is byte compatible with libsecp256k1's `secp256k1_ecdh` using the
+/// default hash function. Returns None if the scalar is zero
+#[cfg(feature = "digest")]
+pub fn ecdh_libsecp_compatible(scalar: &Scalar, peer: &PointAffine) -> Option<[u8; 32]> {
+    use crate::curve::Sign;
+    use sha2::{Digest, Sha256};
+
+    let p = shared_point(scalar, peer)?;
+    let (x, sign) = p.compress();
+    let version = match sign {
+        Sign::Positive => 0x02,
+        Sign::Negative => 0x03,
+    };
+    let mut hasher = Sha256::new();
+    hasher.update([version]);
+    hasher.update(x.to_bytes());
+    Some(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Sign;
+    use crate::tests::hex::from_hex;
+
+    // generated with libsecp256k1 (through secp256k1-sys 0.10) as:
+    // (secret key, peer compressed public key, secp256k1_ecdh default output, shared x)
+    const VECTORS: [(&str, &str, &str, &str); 8] = [
+        (
+            "7c9482472cb6708cd5705692bf1f28de88b71e3ba5e005c05e5d8a88f0c0cbe6",
+            "031e1dfe4223c7d5c9cc688bb87f340d1528ba1762e9d17fc438b7c663d8f1ba0d",
+            "4a51170c2cc4d449091852589de501d39daf2f00875ec63481a5880f4f460cfc",
+            "3ae7b9f90d7d5b6fd2ce03db73aa3c380686b9983d18b10fac28bfa1f74e9d60",
+        ),
+        (
+            "bbc56f3e6b39d569ed345a0aba0dd88e2b5016c60f4e43ad95dd227280676153",
+            "02b3fa7e79bd4f5201e41a3da1a85ccde393416701ae65471dac9d966626a5722e",
+            "f1f1b641b98711ab4754ad53f5299267758cb2bd35e1bfe876bbfcc76173dca5",
+            "6110268d791b36ead47096697578e3601f9ea07aef70a43cf43bb3d79ad60b18",
+        ),
+        (
+            "bda8bde6e08ff11223a5d02613ca9a14470fcf616a094f066fa52cc20bc6fd98",
+            "02c1833f708ac772320e923991760387c210317f87e7294d9454f6fc3bfa5e37a6",
+            "e12680a2e2524d16a8bca094ef4d99ebe4bae90a924b2e85c6e4a65bdaf79da9",
+            "79724ed86237906a7334843705aaa52612a920b7f350d273552b56e7d0147666",
+        ),
+        (
+            "28d9d7eb2889ceba9f7420337af361db97852de73b8d7744dacbbd3c4a14f0c0",
+            "02476e72a76eae9afa9f085be297831801885a9f31c911831f28deb1cff6202eb8",
+            "839a5123c9ae610b073f93613092dc2f8c3157c43297f9c5df64beec8dd2296d",
+            "2c3d447ab127b6427f7b2afc6d92c8f1d15128c332522b30e897ded99e731493",
+        ),
+        (
+            "c5fa991e0c1578003d89035ee7ccafbb2c722d5c90c66d9a731ade06a746bbd6",
+            "03def6e4deb8791d7337a092038b6b19243bad273c1821336972813cdb808ebd5a",
+            "c55aa1b1d448cee999dcfdcf8c347a5ef679b0ed2800dac361af78fd8db80e5a",
+            "4145c96bb750ec26a1f6eb37e8f88c98e417fd734ca628ee8b7a63a667770933",
+        ),
+        (
+            "6258e205fa74ad6939d5ead74cb3050a5ef2aa54d911a8de37e1397d5cda56df",
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "30f83bdcdfccab24a716f4de595eaa1c44fa2c8b029a7d8b35dcafbe1ddb4ed9",
+            "def6e4deb8791d7337a092038b6b19243bad273c1821336972813cdb808ebd5a",
+        ),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "fbd27dbb9e7f471bf3de3704a35e884e37d35c676dc2cc8c3cc574c3962376d2",
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        ),
+        (
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+            "034815eb7ac90ca06e2984bb1d2b35c99258fc32712e1bb544eda05d8cc6ac626d",
+            "727de5a423457f79051e45d587b702f2e996a6c15ccc13e47f18ed460e91dd34",
+            "4815eb7ac90ca06e2984bb1d2b35c99258fc32712e1bb544eda05d8cc6ac626d",
+        ),
+    ];
+
+    // secret keys rejected by libsecp256k1: zero, the group order, and 2^256-1
+    const REJECTED: [&str; 3] = [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    ];
+
+    fn decode_pubkey(bytes: &[u8]) -> PointAffine {
+        let sign = match bytes[0] {
+            0x02 => Sign::Positive,
+            0x03 => Sign::Negative,
+            _ => panic!("invalid compressed point prefix"),
+        };
+        let x = FieldElement::from_slice(&bytes[1..]).unwrap();
+        PointAffine::decompress(&x, sign).unwrap()
+    }
+
+    #[test]
+    fn xonly_vectors() {
+        for (sk, pk, _, x) in VECTORS.iter() {
+            let sk = Scalar::from_slice(&from_hex(sk)).unwrap();
+            let pk = decode_pubkey(&from_hex(pk));
+            assert_eq!(ecdh_xonly(&sk, &pk).unwrap()[..], from_hex(x)[..]);
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn libsecp_vectors() {
+        for (sk, pk, shared, _) in VECTORS.iter() {
+            let sk = Scalar::from_slice(&from_hex(sk)).unwrap();
+            let pk = decode_pubkey(&from_hex(pk));
+            assert_eq!(
+                ecdh_libsecp_compatible(&sk, &pk).unwrap()[..],
+                from_hex(shared)[..]
+            );
+        }
+    }
+
+    #[test]
+    fn rejected_scalars() {
+        let pk = PointAffine::generator();
+        for sk in REJECTED.iter() {
+            match Scalar::from_slice(&from_hex(sk)) {
+                None => {}
+                Some(sk) => {
+                    assert!(sk.is_zero());
+                    assert_eq!(ecdh_xonly(&sk, &pk), None);
+                    #[cfg(feature = "digest")]
+                    assert_eq!(ecdh_libsecp_compatible(&sk, &pk), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ladder_matches_scale() {
+        let p = Point::generator();
+        let mut k = Scalar::from_u64(0xdeadbeef);
+        for _ in 0..8 {
+            assert_eq!(p.scale_ladder(&k), &p * &k);
+            assert_eq!(
+                Point(p.0.scale_ladder(&k.to_bytes(), super::super::Curve)),
+                &p * &k
+            );
+            k = k.square() + Scalar::one();
+        }
+        assert_eq!(p.scale_ladder(&Scalar::zero()), Point::infinity());
+        assert_eq!(p.scale_ladder(&-Scalar::one()), -&p);
+        assert_eq!(Point::infinity().scale_ladder(&k), Point::infinity());
+    }
+}
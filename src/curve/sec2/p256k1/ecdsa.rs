@@ -0,0 +1,55 @@
+//! Elliptic Curve Digital Signature Algorithm on p256k1 (secp256k1)
+//!
+//! Sign message hashes with a `SecretKey` and verify them with a `PublicKey`, the
+//! nonces being drawn from a `NonceSource` (see the `sec2::ecdsa` module), e.g.
+//! `sign(&secret, &hash, Rfc6979::<Sha256>::new())` for RFC 6979 deterministic nonces.
+
+crate::sec2_ecdsa_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::hex::from_hex;
+
+    // RFC 6979 with SHA-256, the vectors of the bitcoin libraries with the secret key 1:
+    // (message, k, r, s), s being the low one
+    const SECRET: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const VECTORS: [(&str, &str, &str, &str); 2] = [
+        (
+            "Satoshi Nakamoto",
+            "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15",
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+            "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+        ),
+        (
+            "All those moments will be lost in time, like tears in rain. Time to die...",
+            "38aa22d72376b4dbc472e06c3ba403ee0a394da63fc58d88686c611aba98d6b3",
+            "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b",
+            "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+        ),
+    ];
+
+    #[test]
+    fn rfc6979_vectors() {
+        let strict = VerifyOptions {
+            allow_high_s: false,
+        };
+        let d = SecretKey::from_slice(&from_hex(SECRET)).unwrap();
+        for (message, k, r, s) in VECTORS.iter() {
+            let h = hash(message.as_bytes());
+
+            let mut nonce = Rfc6979::<Sha256>::new();
+            nonce.init(&d.to_bytes(), &Scalar::bits2octets(&h));
+            let mut candidate = [0u8; Scalar::SIZE_BYTES];
+            nonce.next(&mut candidate);
+            assert_eq!(&candidate[..], &from_hex(k)[..]);
+
+            let signature = sign(&d, &h, Rfc6979::<Sha256>::new()).normalize_s();
+            assert_eq!(&signature.r().to_bytes()[..], &from_hex(r)[..]);
+            assert_eq!(&signature.s().to_bytes()[..], &from_hex(s)[..]);
+            assert!(verify_with(&d.public_key(), &h, &signature, &strict));
+        }
+    }
+
+    crate::sec2_ecdsa_unittest!();
+}
@@ -0,0 +1,48 @@
+//! Elliptic Curve Integrated Encryption Scheme on p256k1 (secp256k1)
+//!
+//! Encrypt short messages to a public key, using HKDF-SHA256 and ChaCha20-Poly1305:
+//!
+//! ```text
+//! blob = compressed(E) || ChaCha20-Poly1305(key, nonce, plaintext) || tag
+//! key || nonce = HKDF-SHA256(salt = compressed(E), ikm = x(k.R), info = "eccoxide-ecies-v1/p256k1", L = 44)
+//! ```
+//!
+//! with k a fresh random ephemeral secret key, E = k.G and R the recipient public key.
+
+crate::sec2_ecies_define!("p256k1");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // generated with python cryptography (ECDH, HKDF, ChaCha20Poly1305) as:
+    // (recipient secret key, ephemeral secret key, plaintext, blob)
+    const VECTORS: [(&str, &str, &str, &str); 4] = [
+        (
+            "eeaa54f3910885eb4792f6a3ec9432f712cf9a19cd1604dc8982f8bdb503e3c7",
+            "dbbfb0ad4112d4f456d050c6cea39a61848e7f4e733702dff8fbc641fe8a2367",
+            "",
+            "02d808e2a1c44caa8f53c3df665752a54e01429a85194bd73f74d06a246d22a04d1d0cf2f1bbf749b84103eadb5c4177fd",
+        ),
+        (
+            "90e4f1a0277771a0f0baffd9808f1f537eb90f6db24a59c401eeef5283102caa",
+            "118c483a30ac555e6b0baa06ec73e2beef523865043f533f41e12523dc55bcb5",
+            "61",
+            "037ccadb0fcfb03c7243e30699a52917e8f28ef2dec10fac7c8207b611d0b2f483ef6a9e6fb1460c9b0d4449cd1282c7afc1",
+        ),
+        (
+            "802e1e689264075de47fd4f05797df829fdc7ae64e241e915291c91791f670c6",
+            "c606c253dbf629d9e6ffbd2e5d186375383dec33f966cc95cf7aacb2dac0baa6",
+            "68656c6c6f206563696573",
+            "02303101eb8a9c4929b8233c51d4eebf5ddfaf01bf4248ee9482ef1d4e664a07b7b911960f53ddc2a4d034ba650f37c5884bc8b37e3545060750aa72",
+        ),
+        (
+            "e45b4b0d66c1662334bb23d46aaac310afc2dbb7299469980b17c4c4f60db946",
+            "e86dbba183abf24619d735b28c2ca433b18eebbc994189c58bcc98577bc6bd1d",
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f60616263",
+            "030a43a484bb175b031f1a7f333851a8a87defbf4fa669a0f25a08a685c85ee2ea728250186390fd704e2b478feccd009abfffe6e0b93146c7ecec0c4320fc32c43d5d4500d7b87c43ab3819c0d84816d6b1340121ac0b94f57d75ed2e8eca1627d248f653402d0f7170d285ea8c72f3bac46e80d9c1c500305279cf7dd559f136f6a5de11653d127e88d3d6642129bd00538a5e0b",
+        ),
+    ];
+
+    crate::sec2_ecies_unittest!();
+}
@@ -0,0 +1,212 @@
+//! Building blocks of the MuSig2 multi signatures (BIP-327) on p256k1
+//!
+//! This is not the protocol, but the point and scalar operations it needs on top
+//! of the basic arithmetic:
+//!
+//! * `Point::linear_combination` and `Point::linear_combination_vartime`, for small
+//!   sums of weighted points, e.g. the final nonce R1 + b.R2 or the aggregated key
+//! * `PointAffine::has_even_y` and `PointAffine::to_even_y`, the x-only normalization
+//!   of BIP-340
+//! * `Scalar::negate_if`, to negate the secret nonces and keys along with their points
+
+use super::{Curve, FieldElement, Point, PointAffine, Scalar};
+use crate::curve::projective;
+use crate::mp::ct::{Choice, CtSelect};
+use crate::mp::ct_util::{ct_index_bits, ct_table_lookup};
+
+// window of the constant time linear combination, the tables having 2^4 points
+const WINDOW_BITS: usize = 4;
+
+impl Point {
+    /// Constant time `s_0 * P_0 + ... + s_k * P_k` of the pairs (s_i, P_i)
+    ///
+    /// The scalars are processed together by windows of 4 bits, with a single chain of
+    /// doublings, and a constant time lookup in a table of the 16 first multiples of each
+    /// point. This is meant for a few pairs, see `msm` for the large public sums.
+    pub fn linear_combination(pairs: &[(&Scalar, &Point)]) -> Point {
+        let tables: Vec<[projective::Point<FieldElement>; 1 << WINDOW_BITS]> = pairs
+            .iter()
+            .map(|(_, p)| {
+                let mut table: [projective::Point<FieldElement>; 1 << WINDOW_BITS] =
+                    core::array::from_fn(|_| projective::Point::infinity());
+                for i in 1..table.len() {
+                    table[i] = table[i - 1].add_different_a0(&p.0, Curve);
+                }
+                table
+            })
+            .collect();
+        let scalars: Vec<_> = pairs.iter().map(|(s, _)| s.to_bytes()).collect();
+
+        let mut acc = projective::Point::<FieldElement>::infinity();
+        for i in 0..Scalar::SIZE_BYTES * 8 / WINDOW_BITS {
+            for _ in 0..WINDOW_BITS {
+                acc = acc.double_a0(Curve);
+            }
+            for (table, scalar) in tables.iter().zip(scalars.iter()) {
+                let byte = scalar[i / 2];
+                let digit = if i % 2 == 0 { byte >> 4 } else { byte & 0xf };
+                let selected =
+                    ct_table_lookup(table, &ct_index_bits::<WINDOW_BITS>(digit as usize));
+                // the a = 0 addition formula is complete, so the equal points don't branch
+                acc = acc.add_different_a0(&selected, Curve);
+            }
+        }
+        Point(acc)
+    }
+
+    /// Variable time `s_0 * P_0 + ... + s_k * P_k`, for the points and scalars that are public
+    ///
+    /// One and two pairs use `mul_vartime` and `double_scalar_mul_vartime`, and more
+    /// pairs the bucket method of `msm`.
+    pub fn linear_combination_vartime(pairs: &[(&Scalar, &Point)]) -> Point {
+        match pairs {
+            [] => Point::infinity(),
+            [(s, p)] => p.mul_vartime(s),
+            [(a, p), (b, q)] => p.double_scalar_mul_vartime(a, q, b),
+            _ => {
+                let scalars: Vec<Scalar> = pairs.iter().map(|(s, _)| (*s).clone()).collect();
+                let points: Vec<Point> = pairs.iter().map(|(_, p)| (*p).clone()).collect();
+                Point::msm(&scalars, &points)
+            }
+        }
+    }
+}
+
+impl PointAffine {
+    /// Check if the Y coordinate is even, i.e. the point is its own x-only (BIP-340) lift
+    pub fn has_even_y(&self) -> Choice {
+        self.to_coordinate().1.sign().to_choice().negate()
+    }
+
+    /// The point with the same X coordinate and an even Y, along with whether the point
+    /// was negated to get it
+    ///
+    /// The flag is what negates the matching secret scalar with `Scalar::negate_if`, so
+    /// that it stays the discrete logarithm of the x-only point. This is constant time.
+    pub fn to_even_y(&self) -> (PointAffine, Choice) {
+        let negated = self.has_even_y().negate();
+        let mut p = self.clone();
+        p.conditional_negate(negated);
+        (p, negated)
+    }
+}
+
+impl Scalar {
+    /// `-self` if the choice is true, `self` otherwise, in constant time
+    pub fn negate_if(&self, c: Choice) -> Scalar {
+        Scalar::ct_select(self, &-self, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::hex::from_hex;
+
+    fn point(bytes: &[u8]) -> Point {
+        Point::from(PointAffine::from_sec1_bytes(bytes).unwrap())
+    }
+
+    // compressed SEC1 encoding, 33 zero bytes for the point at infinity as in BIP-327
+    fn encode(p: &Point) -> Vec<u8> {
+        p.to_sec1_compressed()
+            .map(|b| b.to_vec())
+            .unwrap_or_else(|| vec![0; 33])
+    }
+
+    fn scalars() -> Vec<Scalar> {
+        let mut v = vec![Scalar::zero(), Scalar::one(), -Scalar::one()];
+        let mut s = Scalar::from_u64(0x5eed);
+        for i in 0..4 {
+            s = s.square() + Scalar::from_u64(i);
+            v.push(s.clone());
+        }
+        v
+    }
+
+    // nonce aggregation of BIP-327 (nonce_agg_vectors.json, valid cases): the
+    // aggregated nonce (R1, R2) is the sum of the public nonces (R1_i, R2_i)
+    #[test]
+    fn bip327_nonce_aggregation() {
+        let vectors = [
+            (
+                "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E66603BA47FBC1834437B3212E89A84D8425E7BF12E0245D98262268EBDCB385D50641",
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833",
+                "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B024725377345BDE0E9C33AF3C43C0A29A9249F2F2956FA8CFEB55C8573D0262DC8",
+            ),
+            // the second nonces are G and -G, which aggregate to the point at infinity
+            (
+                "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E6660279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60379BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B000000000000000000000000000000000000000000000000000000000000000000",
+            ),
+        ];
+        let one = Scalar::one();
+        for (nonce1, nonce2, expected) in vectors.iter() {
+            let (nonce1, nonce2) = (from_hex(nonce1), from_hex(nonce2));
+            let mut aggregated = Vec::new();
+            for half in [0..33, 33..66] {
+                let (a, b) = (point(&nonce1[half.clone()]), point(&nonce2[half]));
+                let sum = Point::linear_combination(&[(&one, &a), (&one, &b)]);
+                assert_eq!(
+                    Point::linear_combination_vartime(&[(&one, &a), (&one, &b)]),
+                    sum
+                );
+                aggregated.extend_from_slice(&encode(&sum));
+            }
+            assert_eq!(aggregated, from_hex(expected));
+        }
+    }
+
+    #[test]
+    fn linear_combination() {
+        let ss = scalars();
+        let ps: Vec<Point> = ss.iter().map(|s| &Point::generator() * s).collect();
+        for n in 0..=ss.len() {
+            let pairs: Vec<(&Scalar, &Point)> = ss[..n].iter().zip(ps.iter().rev()).collect();
+            let mut expected = Point::infinity();
+            for (s, p) in pairs.iter() {
+                expected = &expected + &(*p * *s);
+            }
+            assert_eq!(Point::linear_combination(&pairs), expected, "{}", n);
+            assert_eq!(Point::linear_combination_vartime(&pairs), expected, "{}", n);
+        }
+
+        // the same pair twice, and a pair cancelling the other
+        let (s, p) = (&ss[4], &ps[5]);
+        let twice = Point::linear_combination(&[(s, p), (s, p)]);
+        assert_eq!(twice, &(p * s) + &(p * s));
+        let minus_s = -s;
+        let zero = Point::linear_combination(&[(s, p), (&minus_s, p)]);
+        assert_eq!(zero, Point::infinity());
+        assert_eq!(
+            Point::linear_combination_vartime(&[(s, p), (&minus_s, p)]),
+            Point::infinity()
+        );
+    }
+
+    #[test]
+    fn even_y() {
+        let mut even = 0;
+        for s in scalars().iter().skip(1) {
+            let p = (&Point::generator() * s).to_affine().unwrap();
+            let (q, negated) = p.to_even_y();
+            assert!(q.has_even_y().is_true());
+            assert_eq!(q.to_coordinate().0, p.to_coordinate().0);
+            assert_eq!(negated.is_true(), !p.has_even_y().is_true());
+            assert_eq!(q.to_even_y().0, q);
+            assert!(!q.to_even_y().1.is_true());
+
+            // the negated scalar is the discrete logarithm of the even y point
+            let t = s.negate_if(negated);
+            assert_eq!((&Point::generator() * &t).to_affine().unwrap(), q);
+            if !negated.is_true() {
+                even += 1;
+            }
+        }
+        assert!(even > 0 && even < 6);
+        let s = Scalar::from_u64(7);
+        assert_eq!(s.negate_if(Choice::from_bit(0)), s);
+        assert_eq!(s.negate_if(Choice::from_bit(1)), -&s);
+    }
+}
@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p256k1 (secp256k1)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p256k1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "82ed23c2b358a499c6b6dd91127bd024346683535a7d42fc858843c24228cbc5",
+        "72973c643f3c105f798c071bb7d45623a1c235a75b128c53f96af1caea1cee0f"
+    );
+}
@@ -1,12 +1,29 @@
 //! Curve p256r1 as defined over the prime field of order 2^256 - 2^224 + 2^192 + 2^96 - 1
-use crate::curve::fiat::p256_64::*;
-use crate::curve::fiat::p256_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+#[cfg(feature = "fiat-crypto")]
+use crate::curve::fiat::upstream::{P256Scalar as ScalarOps, P256 as FieldOps};
+#[cfg(not(feature = "fiat-crypto"))]
+use crate::curve::fiat::vendored::{P256Scalar as ScalarOps, P256 as FieldOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
-use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p256r1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "ecies")]
+pub mod ecies;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 4;
 const FE_LIMBS_SIZE: usize = 4;
@@ -16,21 +33,13 @@ fiat_field_ops_impl!(
     FieldElement,
     256,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p256_nonzero,
-    fiat_p256_add,
-    fiat_p256_sub,
-    fiat_p256_mul,
-    fiat_p256_square,
-    fiat_p256_opp,
-    fiat_p256_to_bytes,
-    fiat_p256_from_bytes,
-    montgomery {
-        fiat_p256_to_montgomery,
-        fiat_p256_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
@@ -38,6 +47,19 @@ impl FieldElement {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p256r1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -56,6 +78,18 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::p256r1::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
         // (p+1)/4 = 1*32,0*31,1*1,0*95,1*1,0*94
 
         let x2 = self.square() * self;
@@ -68,10 +102,7 @@ impl FieldElement {
 
         let mut t1 = x32.square_rep(32) * self;
         t1 = t1.square_rep(96) * self;
-        let r = t1.square_rep(94);
-
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        t1.square_rep(94)
     }
 }
 
@@ -80,19 +111,10 @@ fiat_field_ops_impl!(
     Scalar,
     256,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p256_scalar_nonzero,
-    fiat_p256_scalar_add,
-    fiat_p256_scalar_sub,
-    fiat_p256_scalar_mul,
-    fiat_p256_scalar_square,
-    fiat_p256_scalar_opp,
-    fiat_p256_scalar_to_bytes,
-    fiat_p256_scalar_from_bytes,
-    montgomery {
-        fiat_p256_scalar_to_montgomery,
-        fiat_p256_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -102,6 +124,19 @@ impl Scalar {
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p256r1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         let b10 = self.square();
         let b11 = &b10 * self; // x2
         let b101 = &b10 * &b11;
@@ -158,15 +193,47 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p256r1");
+#[cfg(feature = "jwk")]
+crate::jwk_define!("P-256");
+
+impl WeierstrassCurveAM3 for Curve {}
 
 impl Point {
     fn add_or_double<'b>(&self, other: &'b Point) -> Point {
         Point(self.0.add_or_double(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -174,14 +241,51 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p256r1::FE_INVERSE,
+            sqrt_chain: sqrt_chain == crate::curve::sec2::chains::p256r1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            FieldElement,
+            crate::curve::fiat::vendored::P256,
+            crate::curve::fiat::upstream::P256,
+            montgomery
+        );
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p256r1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            Scalar,
+            crate::curve::fiat::vendored::P256Scalar,
+            crate::curve::fiat::upstream::P256Scalar,
+            montgomery
+        );
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
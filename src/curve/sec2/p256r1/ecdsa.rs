@@ -0,0 +1,51 @@
+//! Elliptic Curve Digital Signature Algorithm on p256r1 (NIST P-256)
+//!
+//! Sign message hashes with a `SecretKey` and verify them with a `PublicKey`, the
+//! nonces being drawn from a `NonceSource` (see the `sec2::ecdsa` module), e.g.
+//! `sign(&secret, &hash, Rfc6979::<Sha256>::new())` for RFC 6979 deterministic nonces.
+
+crate::sec2_ecdsa_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::hex::from_hex;
+
+    // RFC 6979 A.2.5, with SHA-256: (message, k, r, s)
+    const SECRET: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const VECTORS: [(&str, &str, &str, &str); 2] = [
+        (
+            "sample",
+            "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+            "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+        ),
+        (
+            "test",
+            "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+            "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+            "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+        ),
+    ];
+
+    #[test]
+    fn rfc6979_vectors() {
+        let d = SecretKey::from_slice(&from_hex(SECRET)).unwrap();
+        for (message, k, r, s) in VECTORS.iter() {
+            let h = hash(message.as_bytes());
+
+            let mut nonce = Rfc6979::<Sha256>::new();
+            nonce.init(&d.to_bytes(), &Scalar::bits2octets(&h));
+            let mut candidate = [0u8; Scalar::SIZE_BYTES];
+            nonce.next(&mut candidate);
+            assert_eq!(&candidate[..], &from_hex(k)[..]);
+
+            let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+            assert_eq!(&signature.r().to_bytes()[..], &from_hex(r)[..]);
+            assert_eq!(&signature.s().to_bytes()[..], &from_hex(s)[..]);
+            assert!(verify(&d.public_key(), &h, &signature));
+        }
+    }
+
+    crate::sec2_ecdsa_unittest!();
+}
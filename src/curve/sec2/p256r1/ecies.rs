@@ -0,0 +1,48 @@
+//! Elliptic Curve Integrated Encryption Scheme on p256r1 (NIST P-256)
+//!
+//! Encrypt short messages to a public key, using HKDF-SHA256 and ChaCha20-Poly1305:
+//!
+//! ```text
+//! blob = compressed(E) || ChaCha20-Poly1305(key, nonce, plaintext) || tag
+//! key || nonce = HKDF-SHA256(salt = compressed(E), ikm = x(k.R), info = "eccoxide-ecies-v1/p256r1", L = 44)
+//! ```
+//!
+//! with k a fresh random ephemeral secret key, E = k.G and R the recipient public key.
+
+crate::sec2_ecies_define!("p256r1");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // generated with python cryptography (ECDH, HKDF, ChaCha20Poly1305) as:
+    // (recipient secret key, ephemeral secret key, plaintext, blob)
+    const VECTORS: [(&str, &str, &str, &str); 4] = [
+        (
+            "bd35be1a3355a821317ba9efa69b6c983eb673e258a54e49cef69b29f63aabc4",
+            "091793310a5ead398dfe22b9391274a9f0bd66ecc6aeded1aaf628a2eae70941",
+            "",
+            "030b3f8d84f5b2bce6f1e65dda7475ee4773754e1a8b206df6990e3406fe575de835fadb713d998b24b670c0eee3e60f0a",
+        ),
+        (
+            "f2b2a369159d8ee2082c1058314d98471b5fb2ce2227183564f4af8841c9f9b8",
+            "a060146dddcd39b43c212a016b5c7ef0c6a3792878d0ee76269246730d9883fa",
+            "61",
+            "029fc4e96d329e1441a94e7739056b4e1f034fa429a9cffd4445b15d706e5440346ab9e531730e59f03a2204e2c79cd7dea8",
+        ),
+        (
+            "10e8d97c3471e0ba172db9803f40d17a6cd236d627501ea67598ea10c52ac4b9",
+            "5deeb733862f7a0c43ad150aadc2a786ad00157d0c981897a3b29db15159d0d7",
+            "68656c6c6f206563696573",
+            "037a06a1275bfbf251d1637f7781bbdb7fa4151791e645958d1d2733719a9bfa56bf6a0657ca884185157a5b603acf42e6b53d8005438289145d745f",
+        ),
+        (
+            "e32c2c80f5aa364f04dac9a656d2d7ae6e895958eb97499563919a102273f4c3",
+            "008391e7faafda5a9cf4f9c21483da322985faba31467a5f95796244661ec61e",
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f60616263",
+            "032d486309005d53fa3a36cc7c5714464b7ce13195da847a5656b3baacdfa7656be2f87d9700e9feba40a6262fc715f4b49ab1a1e4ed61a019040c5f5d17a83774c2a08206f92d23092376ea5ea3565566b1aca96e83601a5d361db5f9ceaaf77d5f73147b826991fc96531b85abbb7ebd9f782456e83cc245782c02acee36bd784f54fc6f71e40cf41dc97cd8119451dccdd5641a",
+        ),
+    ];
+
+    crate::sec2_ecies_unittest!();
+}
@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p256r1 (NIST P-256)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p256r1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "57b14b071a34031fe6d76b98a9334e28596e07250cdb7336c55c771054e2c73c",
+        "ac6672f941a57f5846ff9cd04da4263ff67a9e4456a1b9a064b462faf4bc7cfc"
+    );
+}
@@ -1,13 +1,28 @@
 //! Curve p384r1 as defined over the prime field of order 2^384 - 2^128 - 2^96 + 2^32 - 1
 
-use crate::curve::fiat::p384_64::*;
-use crate::curve::fiat::p384_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+#[cfg(feature = "fiat-crypto")]
+use crate::curve::fiat::upstream::{P384Scalar as ScalarOps, P384 as FieldOps};
+#[cfg(not(feature = "fiat-crypto"))]
+use crate::curve::fiat::vendored::{P384Scalar as ScalarOps, P384 as FieldOps};
 use crate::curve::field::{Field, FieldSqrt, Sign};
-use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p384r1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 6;
 const FE_LIMBS_SIZE: usize = 6;
@@ -17,29 +32,34 @@ fiat_field_ops_impl!(
     FieldElement,
     384,
     P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p384_nonzero,
-    fiat_p384_add,
-    fiat_p384_sub,
-    fiat_p384_mul,
-    fiat_p384_square,
-    fiat_p384_opp,
-    fiat_p384_to_bytes,
-    fiat_p384_from_bytes,
-    montgomery {
-        fiat_p384_to_montgomery,
-        fiat_p384_from_montgomery
-    }
+    FieldOps,
+    montgomery
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
     ///
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
-        // p-2 = 1*255,0*1,1*32,0*64,1*30,0*1,1*1
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p384r1::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // p-2 = 1*255,0*1,1*32,0*64,1*30,0*1,1*1
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -59,6 +79,18 @@ impl FieldElement {
 
     /// Compute the square root 'x' of the field element such that x*x = self
     pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::p384r1::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
         // (p+1)/4 = 1*255,0*1,1*32,0*63,1*1,0*30
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
@@ -74,10 +106,7 @@ impl FieldElement {
         let mut t1 = x255.square_rep(31) * &x30;
         t1 = t1.square_rep(2) * &x2;
         t1 = t1.square_rep(64) * self;
-        let r = t1.square_rep(30);
-
-        let r2 = &r * &r;
-        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+        t1.square_rep(30)
     }
 }
 
@@ -86,19 +115,10 @@ fiat_field_ops_impl!(
     Scalar,
     384,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p384_scalar_nonzero,
-    fiat_p384_scalar_add,
-    fiat_p384_scalar_sub,
-    fiat_p384_scalar_mul,
-    fiat_p384_scalar_square,
-    fiat_p384_scalar_opp,
-    fiat_p384_scalar_to_bytes,
-    fiat_p384_scalar_from_bytes,
-    montgomery {
-        fiat_p384_scalar_to_montgomery,
-        fiat_p384_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -107,9 +127,22 @@ impl Scalar {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
+        assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p384r1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
         // 1*194,0001110110001101001101100000011111010000110111001011011101111101011000000110100000110110110010010010001011000010100111011110101110110011101100000110010110101011001100110001010010100101110001
         // 1*194,0*3,1*3,0*1,1*2,0*3,1*2,0*1,1*1,0*2,1*2,0*1,1*2,0*6,1*5,0*1,1*1,0*4,1*2,0*1,1*3,0*2,1*1,0*1,1*2,0*1,1*3,0*1,1*5,0*1,1*1,0*1,1*2,0*6,1*2,0*1,1*1,0*5,1*2,0*1,1*2,0*1,1*2,0*2,1*1,0*2,1*1,0*2,1*1,0*3,1*1,0*1,1*2,0*4,1*1,0*1,1*1,0*2,1*3,0*1,1*4,0*1,1*1,0*1,1*3,0*1,1*2,0*2,1*3,0*1,1*2,0*5,1*2,0*2,1*1,0*1,1*2,0*1,1*1,0*1,1*1,0*1,1*2,0*2,1*2,0*2,1*2,0*3,1*1,0*1,1*1,0*2,1*1,0*1,1*1,0*2,1*1,0*1,1*3,0*3,1*1
-        assert!(!self.is_zero());
         let b10 = self.square();
         let b11 = &b10 * self; // x2
         let b101 = &b10 * &b11;
@@ -179,15 +212,47 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p384r1");
+#[cfg(feature = "jwk")]
+crate::jwk_define!("P-384");
+
+impl WeierstrassCurveAM3 for Curve {}
 
 impl Point {
     fn add_or_double<'b>(&self, other: &'b Point) -> Point {
         Point(self.0.add_or_double(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -195,14 +260,51 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p384r1::FE_INVERSE,
+            sqrt_chain: sqrt_chain == crate::curve::sec2::chains::p384r1::FE_SQRT,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            FieldElement,
+            crate::curve::fiat::vendored::P384,
+            crate::curve::fiat::upstream::P384,
+            montgomery
+        );
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p384r1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            Scalar,
+            crate::curve::fiat::vendored::P384Scalar,
+            crate::curve::fiat::upstream::P384Scalar,
+            montgomery
+        );
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
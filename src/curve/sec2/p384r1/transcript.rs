@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p384r1 (NIST P-384)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p384r1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "88bfc428df9b2ab445ec18a90b753419f0f3194c770b4f03eb0358db2a9bd1d9f971734d408fbf5c8a03ee640a77c24c",
+        "b64a679e240e0d579a3d3bf3ab5c3b10cd4ea170002a852155e21c46b5d03fd45ab49b19186ba7520baf714b914b46f5"
+    );
+}
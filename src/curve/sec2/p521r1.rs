@@ -1,75 +1,67 @@
 //! Curve p521r1 as defined over the prime field of order 2^521 - 1
 
-use crate::curve::fiat::p521_64::*;
-use crate::curve::fiat::p521_scalar_64::*;
+#[cfg(feature = "small-code")]
+use super::chains;
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+#[cfg(feature = "fiat-crypto")]
+use crate::curve::fiat::upstream::P521 as FieldOps;
+use crate::curve::fiat::vendored::P521Scalar as ScalarOps;
+#[cfg(not(feature = "fiat-crypto"))]
+use crate::curve::fiat::vendored::P521 as FieldOps;
 use crate::curve::field::{Field, FieldSqrt, Sign};
-use crate::curve::{affine, projective, weierstrass::WeierstrassCurve};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
 use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
 use crate::params::sec2::p521r1::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
 use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
-use crate::{fiat_field_ops_impl, fiat_field_sqrt_define};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+#[cfg(feature = "ecdsa")]
+pub mod ecdsa;
+#[cfg(feature = "transcript")]
+pub mod transcript;
 
 const GM_LIMBS_SIZE: usize = 9;
 const FE_LIMBS_SIZE: usize = 9;
 
-fn fiat_p521_nonzero(out: &mut u64, fe: &[u64; FE_LIMBS_SIZE]) -> () {
-    let mut bytes = [0u8; 66];
-    fiat_p521_to_bytes(&mut bytes, fe);
-    *out = bytes.ct_nonzero().0;
-}
-
-fn fiat_p521_carry_add(
-    out: &mut [u64; FE_LIMBS_SIZE],
-    a: &[u64; FE_LIMBS_SIZE],
-    b: &[u64; FE_LIMBS_SIZE],
-) {
-    let mut loose = [0u64; FE_LIMBS_SIZE];
-    fiat_p521_add(&mut loose, a, b);
-    fiat_p521_carry(out, &loose)
-}
-
-fn fiat_p521_carry_sub(
-    out: &mut [u64; FE_LIMBS_SIZE],
-    a: &[u64; FE_LIMBS_SIZE],
-    b: &[u64; FE_LIMBS_SIZE],
-) {
-    let mut loose = [0u64; FE_LIMBS_SIZE];
-    fiat_p521_sub(&mut loose, a, b);
-    fiat_p521_carry(out, &loose);
-}
-
-fn fiat_p521_carry_opp(out: &mut [u64; FE_LIMBS_SIZE], a: &[u64; FE_LIMBS_SIZE]) {
-    let mut loose = [0u64; FE_LIMBS_SIZE];
-    fiat_p521_opp(&mut loose, a);
-    fiat_p521_carry(out, &loose)
-}
-
 fiat_field_ops_impl!(
     #[doc = "Element of the prime field Fp where p = 2^521-1"]
     FieldElement,
     521,
     P_BYTES,
+    P_LIMBS,
+    MICRO_LIMBS,
     FE_LIMBS_SIZE,
-    fiat_p521_nonzero,
-    fiat_p521_carry_add,
-    fiat_p521_carry_sub,
-    fiat_p521_carry_mul,
-    fiat_p521_carry_square,
-    fiat_p521_carry_opp,
-    fiat_p521_to_bytes,
-    fiat_p521_from_bytes,
+    FieldOps,
     solinas
 );
 fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
 
 impl FieldElement {
     /// Get the multiplicative inverse
     ///
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     pub fn inverse(&self) -> Self {
-        // p-2 = 1*519,0*1,1*1
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p521r1::FE_INVERSE)
+        }
+    }
 
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // p-2 = 1*519,0*1,1*1
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
         let x6 = x3.square_rep(3) * &x3;
@@ -100,19 +92,10 @@ fiat_field_ops_impl!(
     Scalar,
     521,
     ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
     GM_LIMBS_SIZE,
-    fiat_p521_scalar_nonzero,
-    fiat_p521_scalar_add,
-    fiat_p521_scalar_sub,
-    fiat_p521_scalar_mul,
-    fiat_p521_scalar_square,
-    fiat_p521_scalar_opp,
-    fiat_p521_scalar_to_bytes,
-    fiat_p521_scalar_from_bytes,
-    montgomery {
-        fiat_p521_scalar_to_montgomery,
-        fiat_p521_scalar_from_montgomery
-    }
+    ScalarOps,
+    montgomery
 );
 
 impl Scalar {
@@ -121,8 +104,21 @@ impl Scalar {
     /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
     /// TODO this will change to being a method of NonZeroScalar
     pub fn inverse(&self) -> Self {
-        // p-2 = 1*262,0*1,1*1,0*2,1*1,0*1,1*1,0*3,1*2,0*4,1*2,0*1,1*1,0*4,1*4,0*5,1*3,0*1,1*6,0*2,1*1,0*1,1*5,0*2,1*1,0*1,1*2,0*2,1*2,0*1,1*1,0*1,1*2,0*1,1*9,0*2,1*2,0*9,1*1,0*1,1*1,0*2,1*1,0*3,1*4,0*1,1*3,0*4,1*1,0*2,1*2,0*1,1*1,0*2,1*1,0*1,1*3,0*1,1*1,0*6,1*3,0*1,1*3,0*1,1*2,0*1,1*1,0*1,1*3,0*2,1*1,0*2,1*2,0*1,1*3,0*3,1*1,0*3,1*1,0*2,1*2,0*2,1*3,0*3,1*1,0*3,1*4,0*1,1*1,0*1,1*3,0*1,1*1,0*1,1*3,0*1,1*2,0*1,1*2,0*1,1*5,0*1,1*2,0*1,1*3,0*3,1*4,0*1,1*1,0*2,1*1,0*3,1*1,0*2,1*3,0*4,1*2,0*2,1*1,0*7,1*3
         assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::p521r1::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // p-2 = 1*262,0*1,1*1,0*2,1*1,0*1,1*1,0*3,1*2,0*4,1*2,0*1,1*1,0*4,1*4,0*5,1*3,0*1,1*6,0*2,1*1,0*1,1*5,0*2,1*1,0*1,1*2,0*2,1*2,0*1,1*1,0*1,1*2,0*1,1*9,0*2,1*2,0*9,1*1,0*1,1*1,0*2,1*1,0*3,1*4,0*1,1*3,0*4,1*1,0*2,1*2,0*1,1*1,0*2,1*1,0*1,1*3,0*1,1*1,0*6,1*3,0*1,1*3,0*1,1*2,0*1,1*1,0*1,1*3,0*2,1*1,0*2,1*2,0*1,1*3,0*3,1*1,0*3,1*1,0*2,1*2,0*2,1*3,0*3,1*1,0*3,1*4,0*1,1*1,0*1,1*3,0*1,1*1,0*1,1*3,0*1,1*2,0*1,1*2,0*1,1*5,0*1,1*2,0*1,1*3,0*3,1*4,0*1,1*1,0*2,1*1,0*3,1*1,0*2,1*3,0*4,1*2,0*2,1*1,0*7,1*3
 
         let x2 = self.square() * self;
         let x3 = x2.square() * self;
@@ -202,15 +198,47 @@ impl Scalar {
     }
 }
 
+fiat_scalar_define!(Scalar, Curve);
+
 fiat_define_weierstrass_curve!(FieldElement);
 fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("p521r1");
+#[cfg(feature = "jwk")]
+crate::jwk_define!("P-521");
+
+impl WeierstrassCurveAM3 for Curve {}
 
 impl Point {
     fn add_or_double<'b>(&self, other: &'b Point) -> Point {
         Point(self.0.add_or_double(&other.0, Curve))
     }
     fn scale<'b>(&self, other: &'b Scalar) -> Self {
-        Point(self.0.scale(&other.to_bytes(), Curve))
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
     }
 }
 
@@ -218,14 +246,53 @@ impl Point {
 mod tests {
     mod fe {
         use super::super::FieldElement;
-        use crate::{fiat_field_sqrt_unittest, fiat_field_unittest};
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
 
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p521r1::FE_INVERSE,
+        );
         fiat_field_unittest!(FieldElement);
         fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
+        #[cfg(feature = "fiat-crypto")]
+        crate::fiat_field_ops_equivalence_unittest!(
+            FieldElement,
+            crate::curve::fiat::vendored::P521,
+            crate::curve::fiat::upstream::P521
+        );
     }
     mod gm {
         use super::super::Scalar;
-        use crate::fiat_field_unittest;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == crate::curve::sec2::chains::p521r1::SCALAR_INVERSE
+        );
         fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+
+        // 521 bits, not rounded up to the 66 bytes (528 bits) of the bytes representation
+        #[test]
+        fn bits_521() {
+            assert_eq!(Scalar::SIZE_BITS, 521);
+            assert_eq!(Scalar::LIMBS_U64, 9);
+            let bits = (-Scalar::one()).to_bits_be();
+            assert_eq!(bits.len(), 521);
+            assert!(bits[0]);
+            assert_eq!((-Scalar::one()).to_limbs_u64()[8], 0x1ff);
+        }
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
     }
 }
@@ -0,0 +1,17 @@
+//! Fiat-Shamir transcripts on p521r1 (NIST P-521)
+//!
+//! `TranscriptExt` adds the absorption of the points and scalars of this curve, and the
+//! challenge scalars, to any `Transcript` (see the `sec2::transcript` module), e.g.
+//! `Sha256Transcript::new(b"my protocol on p521r1").challenge_scalar(b"c")`.
+
+crate::sec2_transcript_define!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sec2_transcript_unittest!(
+        "01f017040327196749590de27df06270e9b161e5f30247f89e7faf0879058f2a356c507c2dbe26eeb0e8850acb67d8c855bbd7cb3b80282ff582baa9aa1a911225b2",
+        "01123be4d0fb9e4609d970e3bb9f7c38b3b93f0caacf59cc3ab4fea1b1f350862c184508a132ab451e630349ab20b169a46bfee3f589cf2550f369fffdf8b5ae2d99"
+    );
+}
@@ -0,0 +1,217 @@
+//! Fiat-Shamir transcripts, to turn interactive proofs (e.g. Schnorr proofs of knowledge)
+//! into non interactive ones
+//!
+//! A `Transcript` absorbs labelled messages, and squeezes labelled challenges
+//! depending on all the messages absorbed so far. The curves add the methods to
+//! absorb their points and scalars, and to squeeze challenge scalars, in their
+//! `transcript` modules (e.g. `p256k1::transcript::TranscriptExt`).
+//!
+//! # Encoding
+//!
+//! The encoding is frozen, the same operations always giving the same challenges
+//! across the versions. With `H` the hash function of the transcript, `u32be` and
+//! `u64be` the big endian encodings of the lengths, and `||` the concatenation:
+//!
+//! * `new(domain)` starts the hash with `"eccoxide-transcript-v1"`, and then absorbs
+//!   the domain with the label `"domain"`
+//! * `absorb_bytes(label, data)` appends
+//!   `0x01 || u32be(len(label)) || label || u64be(len(data)) || data`
+//! * `absorb_point(label, p)` is `absorb_bytes(label, compressed SEC1 encoding of p)`
+//! * `absorb_scalar(label, s)` is `absorb_bytes(label, big endian bytes of s)`, of the
+//!   byte size of the group order
+//! * `challenge_bytes(label, out)` appends `0x02 || u32be(len(label)) || label || u64be(len(out))`,
+//!   computes the seed `H(all the bytes appended so far)`, and fills `out` with the
+//!   blocks `H(seed || u32be(0))`, `H(seed || u32be(1))`, ... (the last one truncated).
+//!   The framing stays in the transcript, so the next challenges are different.
+//! * `challenge_scalar(label)` is the `challenge_bytes(label, out)` of twice the byte size
+//!   of the group order, reduced modulo the order (see `Scalar::derive_from_kdf`)
+//!
+//! The point and scalar encodings don't include the curve, which is expected to be
+//! part of the domain, along with the protocol.
+
+use sha2::digest::Digest;
+
+/// Fiat-Shamir transcript of labelled messages and challenges
+pub trait Transcript {
+    /// Absorb the labelled bytes
+    fn absorb_bytes(&mut self, label: &'static [u8], data: &[u8]);
+
+    /// Fill `out` with the labelled challenge, depending on everything absorbed so far
+    fn challenge_bytes(&mut self, label: &'static [u8], out: &mut [u8]);
+}
+
+impl<T: Transcript + ?Sized> Transcript for &mut T {
+    fn absorb_bytes(&mut self, label: &'static [u8], data: &[u8]) {
+        (**self).absorb_bytes(label, data)
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], out: &mut [u8]) {
+        (**self).challenge_bytes(label, out)
+    }
+}
+
+const VERSION: &[u8] = b"eccoxide-transcript-v1";
+const TAG_ABSORB: u8 = 0x01;
+const TAG_CHALLENGE: u8 = 0x02;
+
+/// Transcript backed by a hash function, e.g. `HashTranscript::<Sha256>::new(b"my protocol")`
+#[derive(Clone)]
+pub struct HashTranscript<H: Digest + Clone> {
+    state: H,
+}
+
+/// Transcript backed by SHA-256
+pub type Sha256Transcript = HashTranscript<sha2::Sha256>;
+
+/// Transcript backed by SHA-512
+pub type Sha512Transcript = HashTranscript<sha2::Sha512>;
+
+impl<H: Digest + Clone> HashTranscript<H> {
+    /// Start a new transcript for the domain, which identifies the protocol and the curve
+    pub fn new(domain: &'static [u8]) -> Self {
+        let mut t = HashTranscript { state: H::new() };
+        t.state.update(VERSION);
+        t.absorb_bytes(b"domain", domain);
+        t
+    }
+
+    fn frame(&mut self, tag: u8, label: &[u8], len: usize) {
+        self.state.update([tag]);
+        self.state.update((label.len() as u32).to_be_bytes());
+        self.state.update(label);
+        self.state.update((len as u64).to_be_bytes());
+    }
+}
+
+impl<H: Digest + Clone> Transcript for HashTranscript<H> {
+    fn absorb_bytes(&mut self, label: &'static [u8], data: &[u8]) {
+        self.frame(TAG_ABSORB, label, data.len());
+        self.state.update(data);
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], out: &mut [u8]) {
+        self.frame(TAG_CHALLENGE, label, out.len());
+        let seed = self.state.clone().finalize();
+        for (i, chunk) in out.chunks_mut(<H as Digest>::output_size()).enumerate() {
+            let mut h = H::new();
+            h.update(&seed);
+            h.update((i as u32).to_be_bytes());
+            chunk.copy_from_slice(&h.finalize()[..chunk.len()]);
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_transcript_define {
+    () => {
+        use super::{compressed_sec1, PointAffine, Scalar};
+        pub use $crate::curve::sec2::transcript::*;
+
+        /// Points and scalars of this curve in a `Transcript`, see the `sec2::transcript`
+        /// module for their encoding
+        pub trait TranscriptExt: Transcript {
+            /// Absorb the labelled point, with its compressed SEC1 encoding
+            fn absorb_point(&mut self, label: &'static [u8], p: &PointAffine) {
+                self.absorb_bytes(label, &compressed_sec1(p))
+            }
+
+            /// Absorb the labelled scalar, with its big endian bytes
+            fn absorb_scalar(&mut self, label: &'static [u8], s: &Scalar) {
+                self.absorb_bytes(label, &s.to_bytes())
+            }
+
+            /// Squeeze the labelled challenge scalar, from twice the size of the group order
+            /// of challenge bytes so that the reduction bias is negligible
+            fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar {
+                Scalar::derive_from_kdf(|okm| self.challenge_bytes(label, okm))
+            }
+        }
+
+        impl<T: Transcript + ?Sized> TranscriptExt for T {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sec2_transcript_unittest {
+    ($GOLDEN_SHA256:expr, $GOLDEN_SHA512:expr) => {
+        use super::super::{Point, PointAffine};
+        use $crate::tests::hex::from_hex;
+
+        fn golden<T: Transcript>(mut t: T) -> Scalar {
+            let g = Point::generator().to_affine().unwrap();
+            t.absorb_bytes(b"message", b"abc");
+            t.absorb_point(b"point", &g);
+            t.absorb_scalar(b"scalar", &Scalar::from_u64(7));
+            t.challenge_scalar(b"challenge")
+        }
+
+        #[test]
+        fn golden_vectors() {
+            let c = golden(Sha256Transcript::new(b"eccoxide test"));
+            assert_eq!(c.to_bytes().to_vec(), from_hex($GOLDEN_SHA256));
+            let c = golden(Sha512Transcript::new(b"eccoxide test"));
+            assert_eq!(c.to_bytes().to_vec(), from_hex($GOLDEN_SHA512));
+        }
+
+        #[test]
+        fn challenges() {
+            let mut t = Sha256Transcript::new(b"eccoxide test");
+            let mut u = t.clone();
+            let (c1, c2) = (t.challenge_scalar(b"c"), t.challenge_scalar(b"c"));
+            assert_ne!(c1, c2);
+            assert_eq!(u.challenge_scalar(b"c"), c1);
+
+            // the label, the domain, and the framing of the messages matter
+            assert_ne!(
+                Sha256Transcript::new(b"eccoxide test").challenge_scalar(b"d"),
+                c1
+            );
+            assert_ne!(Sha256Transcript::new(b"other").challenge_scalar(b"c"), c1);
+            let mut a = Sha256Transcript::new(b"eccoxide test");
+            a.absorb_bytes(b"m", b"ab");
+            a.absorb_bytes(b"m", b"c");
+            let mut b = Sha256Transcript::new(b"eccoxide test");
+            b.absorb_bytes(b"m", b"a");
+            b.absorb_bytes(b"m", b"bc");
+            assert_ne!(a.challenge_scalar(b"c"), b.challenge_scalar(b"c"));
+        }
+
+        // Schnorr proof of knowledge of the discrete logarithm x of X = x.G
+        fn prove(x: &Scalar, r: &Scalar) -> (PointAffine, Scalar) {
+            let big_x = Point::generator().scale_ct(x).to_affine().unwrap();
+            let big_r = Point::generator().scale_ct(r).to_affine().unwrap();
+            let mut t = Sha256Transcript::new(b"eccoxide schnorr pok");
+            t.absorb_point(b"X", &big_x);
+            t.absorb_point(b"R", &big_r);
+            let c = t.challenge_scalar(b"c");
+            (big_r, r + &(&c * x))
+        }
+
+        fn verify(big_x: &PointAffine, proof: &(PointAffine, Scalar)) -> bool {
+            let (big_r, s) = proof;
+            let mut t = Sha256Transcript::new(b"eccoxide schnorr pok");
+            t.absorb_point(b"X", big_x);
+            t.absorb_point(b"R", big_r);
+            let c = t.challenge_scalar(b"c");
+            &Point::generator() * s == &Point::from(big_r) + &(&Point::from(big_x) * &c)
+        }
+
+        #[test]
+        fn schnorr_proof_of_knowledge() {
+            let x = Scalar::from_u64(0xdeadbeef);
+            let big_x = (&Point::generator() * &x).to_affine().unwrap();
+            let proof = prove(&x, &Scalar::from_u64(0x1234_5678));
+            assert!(verify(&big_x, &proof));
+
+            // another statement, or a modified response, don't verify
+            let other = (&Point::generator() * &(&x + &Scalar::one()))
+                .to_affine()
+                .unwrap();
+            assert!(!verify(&other, &proof));
+            let (big_r, s) = proof;
+            assert!(!verify(&big_x, &(big_r, &s + &Scalar::one())));
+        }
+    };
+}
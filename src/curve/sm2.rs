@@ -0,0 +1,308 @@
+//! Curve sm2p256v1 of the chinese standard SM2 (GB/T 32918), defined over the
+//! prime field of order 2^256 - 2^224 - 2^96 + 2^64 - 1
+//!
+//! The curve has a = -3 like p256r1, and the same API as the SEC2 curves.
+//! There's no fiat generated code for its fields, which use the word by word
+//! Montgomery arithmetic of `montgomery_64` instead.
+use crate::curve::fiat::ops::{FiatFieldOps, FiatMontgomeryOps};
+use crate::curve::fiat::vendored::{Sm2 as FieldOps, Sm2Scalar as ScalarOps};
+use crate::curve::field::{Field, FieldSqrt, Sign};
+use crate::curve::{
+    affine, jacobian, projective,
+    weierstrass::{WeierstrassCurve, WeierstrassCurveAM3},
+};
+use crate::mp::ct::{Choice, CtEqual, CtOption, CtZero};
+use crate::params::sm2::*;
+use crate::{fiat_define_bytes_api, fiat_define_keys, fiat_define_signed_scalar};
+use crate::{fiat_define_weierstrass_curve, fiat_define_weierstrass_points};
+use crate::{fiat_field_legendre_define, fiat_field_ops_impl};
+use crate::{fiat_field_sqrt_define, fiat_scalar_define};
+
+const GM_LIMBS_SIZE: usize = 4;
+const FE_LIMBS_SIZE: usize = 4;
+
+// the exponentiation chains as data, see the sec2 `chains` module for the format
+#[cfg(any(test, feature = "small-code"))]
+mod chains {
+    /// Multiplier value for a step with only squarings
+    const NO_MUL: u8 = 0xff;
+
+    /// x^(p-2)
+    #[rustfmt::skip]
+    pub(crate) const FE_INVERSE: [(u16, u8); 56] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (36, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 6),
+    ];
+    /// x^(n-2)
+    #[rustfmt::skip]
+    pub(crate) const SCALAR_INVERSE: [(u16, u8); 56] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 3),
+        (3, 0), (11, 7), (5, 7), (4, 5), (5, 5), (3, 0), (7, 3), (5, 1), (9, 2), (5, 2), (5, 6),
+        (5, 4), (4, 6), (4, 6), (4, 7), (3, 2), (10, 4), (5, 3), (5, 3), (4, 2), (4, 2), (9, 4),
+        (5, 0),
+    ];
+    /// x^((p+1)/4)
+    #[rustfmt::skip]
+    pub(crate) const FE_SQRT: [(u16, u8); 42] = [
+        (0, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (3, 3), (5, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7),
+        (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (4, 7), (32, 0), (62, NO_MUL),
+    ];
+}
+
+fiat_field_ops_impl!(
+    #[doc = "Element of the prime field Fp where p = 2^256 - 2^224 - 2^96 + 2^64 - 1"]
+    FieldElement,
+    256,
+    P_LIMBS,
+    MICRO_LIMBS,
+    FE_LIMBS_SIZE,
+    FieldOps,
+    montgomery
+);
+fiat_field_sqrt_define!(FieldElement);
+fiat_field_legendre_define!(FieldElement);
+
+impl FieldElement {
+    /// Get the multiplicative inverse
+    ///
+    /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
+    pub fn inverse(&self) -> Self {
+        assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::FE_INVERSE)
+        }
+    }
+
+    // self^(p-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // p-2 = 1*31,0*1,1*128,0*32,1*62,0*1,1*1
+        let x2 = self.square() * self;
+        let x3 = x2.square() * self;
+        let x6 = x3.square_rep(3) * &x3;
+        let x12 = x6.square_rep(6) * &x6;
+        let x24 = x12.square_rep(12) * &x12;
+        let x30 = x24.square_rep(6) * &x6;
+        let x31 = x30.square() * self;
+        let x32 = x31.square() * self;
+        let x62 = x31.square_rep(31) * &x31;
+        let x64 = x32.square_rep(32) * &x32;
+        let x128 = x64.square_rep(64) * &x64;
+
+        let mut t1 = x31.square_rep(1 + 128) * &x128;
+        t1 = t1.square_rep(32 + 62) * &x62;
+        t1 = t1.square_rep(2);
+        t1 * self
+    }
+
+    /// Compute the square root 'x' of the field element such that x*x = self
+    pub fn sqrt(&self) -> CtOption<Self> {
+        #[cfg(not(feature = "small-code"))]
+        let r = self.sqrt_chain();
+        #[cfg(feature = "small-code")]
+        let r = self.pow_chain(&chains::FE_SQRT);
+
+        let r2 = &r * &r;
+        CtOption::from((CtEqual::ct_eq(&r2, self), r))
+    }
+
+    // self^((p+1)/4) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn sqrt_chain(&self) -> Self {
+        // (p+1)/4 = 1*31,0*1,1*128,0*31,1*1,0*62
+        let x2 = self.square() * self;
+        let x3 = x2.square() * self;
+        let x6 = x3.square_rep(3) * &x3;
+        let x12 = x6.square_rep(6) * &x6;
+        let x24 = x12.square_rep(12) * &x12;
+        let x30 = x24.square_rep(6) * &x6;
+        let x31 = x30.square() * self;
+        let x32 = x31.square() * self;
+        let x64 = x32.square_rep(32) * &x32;
+        let x128 = x64.square_rep(64) * &x64;
+
+        let mut t1 = x31.square_rep(1 + 128) * &x128;
+        t1 = t1.square_rep(32) * self;
+        t1.square_rep(62)
+    }
+}
+
+fiat_field_ops_impl!(
+    #[doc = "Element of the prime field Fp for scalar where p is the order of the SM2 curve"]
+    Scalar,
+    256,
+    ORDER_LIMBS,
+    ORDER_MICRO_LIMBS,
+    GM_LIMBS_SIZE,
+    ScalarOps,
+    montgomery
+);
+
+impl Scalar {
+    /// Get the multiplicative inverse
+    ///
+    /// Note that 0 doesn't have a multiplicative inverse and will result in a panic
+    pub fn inverse(&self) -> Self {
+        assert!(!self.is_zero());
+        #[cfg(not(feature = "small-code"))]
+        {
+            self.inverse_chain()
+        }
+        #[cfg(feature = "small-code")]
+        {
+            self.pow_chain(&chains::SCALAR_INVERSE)
+        }
+    }
+
+    // self^(n-2) as a straight-line addition chain
+    #[cfg(any(test, not(feature = "small-code")))]
+    fn inverse_chain(&self) -> Self {
+        // the high half of n-2 is 1*31,0*1,1*96, and the low half uses
+        // a sliding window of width 4
+        let b10 = self.square();
+        let b11 = &b10 * self;
+        let b101 = &b11 * &b10;
+        let b111 = &b101 * &b10;
+        let b1001 = &b111 * &b10;
+        let b1011 = &b1001 * &b10;
+        let b1101 = &b1011 * &b10;
+        let b1111 = &b1101 * &b10;
+
+        let x6 = b111.square_rep(3) * &b111;
+        let x12 = x6.square_rep(6) * &x6;
+        let x24 = x12.square_rep(12) * &x12;
+        let x30 = x24.square_rep(6) * &x6;
+        let x31 = x30.square() * self;
+        let x32 = x31.square() * self;
+        let x64 = x32.square_rep(32) * &x32;
+        let x96 = x64.square_rep(32) * &x32;
+
+        let mut t1 = x31.square_rep(1 + 96) * &x96;
+
+        t1 = t1.square_rep(4) * &b111;
+        t1 = t1.square_rep(3) * self;
+        t1 = t1.square_rep(11) * &b1111;
+        t1 = t1.square_rep(5) * &b1111;
+
+        t1 = t1.square_rep(4) * &b1011;
+        t1 = t1.square_rep(5) * &b1011;
+        t1 = t1.square_rep(3) * self;
+        t1 = t1.square_rep(7) * &b111;
+
+        t1 = t1.square_rep(5) * &b11;
+        t1 = t1.square_rep(9) * &b101;
+        t1 = t1.square_rep(5) * &b101;
+        t1 = t1.square_rep(5) * &b1101;
+
+        t1 = t1.square_rep(5) * &b1001;
+        t1 = t1.square_rep(4) * &b1101;
+        t1 = t1.square_rep(4) * &b1101;
+        t1 = t1.square_rep(4) * &b1111;
+
+        t1 = t1.square_rep(3) * &b101;
+        t1 = t1.square_rep(10) * &b1001;
+        t1 = t1.square_rep(5) * &b111;
+        t1 = t1.square_rep(5) * &b111;
+
+        t1 = t1.square_rep(4) * &b101;
+        t1 = t1.square_rep(4) * &b101;
+        t1 = t1.square_rep(9) * &b1001;
+        t1 = t1.square_rep(5) * self;
+
+        t1
+    }
+}
+
+fiat_scalar_define!(Scalar, Curve);
+
+fiat_define_weierstrass_curve!(FieldElement);
+fiat_define_weierstrass_points!(FieldElement);
+fiat_define_keys!();
+fiat_define_signed_scalar!();
+fiat_define_bytes_api!();
+#[cfg(feature = "kdf")]
+crate::fiat_scalar_kdf_define!("sm2");
+
+impl WeierstrassCurveAM3 for Curve {}
+
+impl Point {
+    fn add_or_double<'b>(&self, other: &'b Point) -> Point {
+        Point(self.0.add_or_double(&other.0, Curve))
+    }
+    fn scale<'b>(&self, other: &'b Scalar) -> Self {
+        Point(self.0.scale_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_ladder(&self, other: &Scalar) -> Self {
+        Point(self.0.scale_ladder_bits(other.iter_bits_msb_first(), Curve))
+    }
+    fn scale_wnaf(&self, other: &Scalar, width: usize) -> Self {
+        Point(self.0.scale_wnaf(&other.to_bytes(), width, Curve))
+    }
+    fn scale_jacobian(&self, other: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        Point(
+            p.scale_vartime_am3(&other.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+    fn double_scale_vartime(&self, a: &Scalar, other: &Point, b: &Scalar) -> Self {
+        let p = jacobian::JacobianPoint::<FieldElement>::from_projective(&self.0);
+        let q = jacobian::JacobianPoint::<FieldElement>::from_projective(&other.0);
+        Point(
+            p.double_scale_vartime_am3(&a.to_bytes(), &q, &b.to_bytes(), Curve)
+                .to_projective(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod fe {
+        use super::super::FieldElement;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_sqrt_unittest,
+            fiat_field_unittest,
+        };
+
+        fiat_field_chain_unittest!(
+            FieldElement,
+            inverse_chain: inverse_chain == super::super::chains::FE_INVERSE,
+            sqrt_chain: sqrt_chain == super::super::chains::FE_SQRT,
+        );
+        fiat_field_unittest!(FieldElement);
+        fiat_field_sqrt_unittest!(FieldElement);
+        fiat_field_sqrt_generic_unittest!(FieldElement, tuned);
+    }
+    mod gm {
+        use super::super::Scalar;
+        use crate::{
+            fiat_field_chain_unittest, fiat_field_sqrt_generic_unittest, fiat_field_unittest,
+            fiat_scalar_unittest,
+        };
+        fiat_field_chain_unittest!(
+            Scalar,
+            inverse_chain: inverse_chain == super::super::chains::SCALAR_INVERSE
+        );
+        fiat_field_unittest!(Scalar);
+        fiat_scalar_unittest!(Scalar);
+        fiat_field_sqrt_generic_unittest!(Scalar);
+    }
+    mod point {
+        use super::super::{Point, Scalar};
+
+        crate::test_point_operators!(Scalar);
+    }
+}
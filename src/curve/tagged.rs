@@ -0,0 +1,223 @@
+//! Bytes representation tagged with the curve they belong to
+//!
+//! The scalars of different curves often have the same size (e.g. p256k1 and p256r1
+//! scalars are both 32 bytes), so nothing prevents the bytes of a scalar of one curve
+//! to be parsed as a scalar of another curve, which is almost always a bug.
+//!
+//! `TaggedBytes<C, N>` carries the curve marker type `C` along the bytes, and can only
+//! be parsed back by the scalar of the same curve:
+//!
+//! ```
+//! use eccoxide::curve::sec2::p256k1;
+//!
+//! let bytes: p256k1::TaggedScalarBytes = p256k1::Scalar::from_u64(42).to_tagged_bytes();
+//! let s = p256k1::Scalar::from_tagged_bytes(&bytes).unwrap();
+//! assert_eq!(s, p256k1::Scalar::from_u64(42));
+//! ```
+//!
+//! Mixing curves is a compile error:
+//!
+//! ```compile_fail
+//! use eccoxide::curve::sec2::{p256k1, p256r1};
+//!
+//! let bytes = p256k1::Scalar::from_u64(42).to_tagged_bytes();
+//! let s = p256r1::Scalar::from_tagged_bytes(&bytes);
+//! ```
+//!
+//! including when storing the bytes in a structure typed for another curve:
+//!
+//! ```compile_fail
+//! use eccoxide::curve::sec2::{p256k1, p256r1};
+//!
+//! struct Keys {
+//!     p256r1_secret: p256r1::TaggedScalarBytes,
+//! }
+//!
+//! let keys = Keys {
+//!     p256r1_secret: p256k1::Scalar::from_u64(42).to_tagged_bytes(),
+//! };
+//! ```
+//!
+//! In a multi curve codebase, the tagged bytes should be used for anything stored
+//! or passed around, and the untagged `to_bytes`/`from_bytes` kept for the interoperability
+//! boundary, where the curve is known from the context (e.g. a protocol field or
+//! a key type), and `TaggedBytes::from_untagged` makes this choice explicit:
+//!
+//! ```
+//! use eccoxide::curve::sec2::p256r1;
+//!
+//! fn load_p256r1_secret(raw: [u8; 32]) -> Option<p256r1::Scalar> {
+//!     p256r1::Scalar::from_tagged_bytes(&p256r1::TaggedScalarBytes::from_untagged(raw))
+//! }
+//! # assert!(load_p256r1_secret([1; 32]).is_some());
+//! ```
+//!
+//! # X coordinates and scalars
+//!
+//! On most curves, the X coordinates and the scalars have the same size too, and an
+//! ECDH shared secret (the X coordinate of the shared point) is not a scalar. The
+//! typed layer separates them: `PointAffine::x_bytes` gives `XCoordinateBytes`, which
+//! are only created by this serialization, while the scalars stay on their tagged
+//! bytes. The X coordinate bytes can be turned into untyped bytes with `into_inner`,
+//! but not into scalar bytes:
+//!
+//! ```
+//! use eccoxide::curve::sec2::p256r1;
+//!
+//! let secret = p256r1::Scalar::from_u64(42);
+//! let peer = p256r1::PointAffine::generator();
+//! let shared = (&p256r1::Point::from(&peer) * &secret).to_affine().unwrap();
+//!
+//! let shared_x: p256r1::XCoordinateBytes = shared.x_bytes();
+//! let secret_bytes: p256r1::TaggedScalarBytes = secret.to_tagged_bytes();
+//! assert_eq!(p256r1::Scalar::from_tagged_bytes(&secret_bytes), Some(secret));
+//! assert_eq!(shared_x.into_inner(), shared.to_coordinate().0.to_bytes());
+//! ```
+//!
+//! The shared secret can't be used as a scalar:
+//!
+//! ```compile_fail
+//! use eccoxide::curve::sec2::p256r1;
+//!
+//! let shared_x = p256r1::PointAffine::generator().x_bytes();
+//! let s = p256r1::Scalar::from_tagged_bytes(&shared_x);
+//! ```
+//!
+//! nor converted into scalar bytes:
+//!
+//! ```compile_fail
+//! use eccoxide::curve::sec2::p256r1;
+//!
+//! let s: p256r1::TaggedScalarBytes = p256r1::PointAffine::generator().x_bytes().into();
+//! ```
+//!
+//! and can't be created from arbitrary bytes, which go through `from_bytes`:
+//!
+//! ```compile_fail
+//! use eccoxide::curve::sec2::p256r1;
+//!
+//! let x = p256r1::XCoordinateBytes::from([1u8; 32]);
+//! ```
+
+use crate::mp::ct::{Choice, CtEqual};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Array of N bytes tagged with the curve marker type C
+pub struct TaggedBytes<C, const N: usize> {
+    bytes: [u8; N],
+    curve: PhantomData<C>,
+}
+
+impl<C, const N: usize> TaggedBytes<C, N> {
+    /// Tag bytes coming from an untagged source as belonging to the curve C
+    pub fn from_untagged(bytes: [u8; N]) -> Self {
+        TaggedBytes {
+            bytes,
+            curve: PhantomData,
+        }
+    }
+
+    /// Get the untagged bytes
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Remove the tag, returning the untagged bytes
+    pub fn into_untagged(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+// implemented manually, since deriving would add bounds on the marker type C
+
+impl<C, const N: usize> Clone for TaggedBytes<C, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, const N: usize> Copy for TaggedBytes<C, N> {}
+
+impl<C, const N: usize> CtEqual for TaggedBytes<C, N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+// the bytes are often secret (e.g. a serialized secret scalar), so the comparison
+// is done in constant time and the debug output doesn't show them
+
+impl<C, const N: usize> PartialEq for TaggedBytes<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).is_true()
+    }
+}
+
+impl<C, const N: usize> Eq for TaggedBytes<C, N> {}
+
+impl<C, const N: usize> fmt::Debug for TaggedBytes<C, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TaggedBytes<{}>(..)", std::any::type_name::<C>())
+    }
+}
+
+impl<C, const N: usize> AsRef<[u8]> for TaggedBytes<C, N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Big endian bytes of the X coordinate of a point of the curve C, e.g. an ECDH
+/// shared secret, see `PointAffine::x_bytes`
+pub struct XCoordinateBytes<C, const N: usize>(TaggedBytes<C, N>);
+
+impl<C, const N: usize> XCoordinateBytes<C, N> {
+    pub(crate) fn new(bytes: [u8; N]) -> Self {
+        XCoordinateBytes(TaggedBytes::from_untagged(bytes))
+    }
+
+    /// Get the untyped bytes
+    pub fn as_bytes(&self) -> &[u8; N] {
+        self.0.as_bytes()
+    }
+
+    /// Remove the type, returning the untyped bytes
+    pub fn into_inner(self) -> [u8; N] {
+        self.0.into_untagged()
+    }
+}
+
+impl<C, const N: usize> Clone for XCoordinateBytes<C, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, const N: usize> Copy for XCoordinateBytes<C, N> {}
+
+impl<C, const N: usize> CtEqual for XCoordinateBytes<C, N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<C, const N: usize> PartialEq for XCoordinateBytes<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).is_true()
+    }
+}
+
+impl<C, const N: usize> Eq for XCoordinateBytes<C, N> {}
+
+impl<C, const N: usize> fmt::Debug for XCoordinateBytes<C, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "XCoordinateBytes<{}>(..)", std::any::type_name::<C>())
+    }
+}
+
+impl<C, const N: usize> From<XCoordinateBytes<C, N>> for [u8; N] {
+    fn from(b: XCoordinateBytes<C, N>) -> [u8; N] {
+        b.into_inner()
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! All short weierstrass curve are defined as as y^{2} = x^{3} + Ax + B
 
+use core::ops::Add;
+
 /// Weierstrass curve are defined as y^{2} = x^{3} + Ax + B
 pub trait WeierstrassCurve: Copy + Clone {
     type FieldElement;
@@ -19,3 +21,17 @@ pub trait WeierstrassCurveA0: WeierstrassCurve {}
 
 /// Weierstrass curves with with A=-3
 pub trait WeierstrassCurveAM3: WeierstrassCurve {}
+
+/// Check that the `b3` parameter of the curve is `b + b + b`
+///
+/// The formulas of the projective points use `b3` directly, so a `b3` that doesn't
+/// match `b` gives wrong results without any other visible error.
+pub fn b3_consistent<C>(curve: C) -> bool
+where
+    C: WeierstrassCurve,
+    C::FieldElement: PartialEq + 'static,
+    for<'a, 'b> &'a C::FieldElement: Add<&'b C::FieldElement, Output = C::FieldElement>,
+{
+    let b = curve.b();
+    &(b + b) + b == *curve.b3()
+}
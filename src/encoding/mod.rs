@@ -0,0 +1,5 @@
+//! Standard encodings of the keys
+//!
+//! * pkcs8: PKCS#8 (RFC 5208 and RFC 5915) private keys, in DER and PEM
+
+pub mod pkcs8;
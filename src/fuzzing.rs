@@ -0,0 +1,260 @@
+//! Bodies of the fuzz targets of the `fuzz` directory
+//!
+//! The targets are thin cargo-fuzz wrappers around these functions, which panic when
+//! an invariant doesn't hold, so that the invariants stay compiled and are run by the
+//! tests on the regression corpus (`fuzz/corpus`) without the fuzzing toolchain:
+//!
+//! * `parse` feeds the bytes to the field elements, scalars and points decoders of
+//!   all the enabled curves: no panic, the accepted values round trip to the same bytes,
+//!   and the decompressed and decoded points are on the curve
+//! * `diff_p192r1` runs the fiat and the bigint p192r1 on the same operands, which
+//!   need to agree (only with the num-bigint and num-traits features)
+//!
+//! This is not a stable API, and is only compiled for the tests or with the
+//! `fuzzing` feature.
+
+macro_rules! parse_curve {
+    ($curve:ident, $module:path) => {
+        fn $curve(data: &[u8]) {
+            use crate::curve::sec1::{Sec1Error, Sec1Form, ValidationFlags};
+            use crate::curve::Sign;
+            use curve::{FieldElement, Point, PointAffine, Scalar};
+            use $module as curve;
+
+            // canonical iff of the right size and equal to its reduction
+            let fe = FieldElement::from_slice(data);
+            let canonical = data.len() == FieldElement::SIZE_BYTES
+                && FieldElement::from_be_bytes_mod_p(data).to_bytes()[..] == *data;
+            assert_eq!(fe.is_some(), canonical, "field element {:02x?}", data);
+            if let Some(x) = &fe {
+                assert_eq!(x.to_bytes()[..], *data);
+            }
+
+            let scalar = Scalar::from_slice(data);
+            let canonical = data.len() == Scalar::SIZE_BYTES
+                && Scalar::from_be_bytes_mod_p(data).to_bytes()[..] == *data;
+            assert_eq!(scalar.is_some(), canonical, "scalar {:02x?}", data);
+            if let Some(s) = &scalar {
+                assert_eq!(s.to_bytes()[..], *data);
+            }
+
+            // both roots or none, opposite and on the curve
+            if let Some(x) = &fe {
+                let pos = PointAffine::decompress(x, Sign::Positive);
+                let neg = PointAffine::decompress(x, Sign::Negative);
+                assert_eq!(pos.is_some(), neg.is_some(), "decompress {:02x?}", data);
+                if let (Some(pos), Some(neg)) = (pos, neg) {
+                    let (px, py) = pos.to_coordinate();
+                    let (nx, ny) = neg.to_coordinate();
+                    assert!(px == x && nx == x);
+                    assert_eq!(py.sign(), Sign::Positive);
+                    assert_eq!(ny.sign(), Sign::Negative);
+                    assert_eq!(&-py, ny);
+                    assert!(PointAffine::from_coordinate(px, py).is_some());
+                    assert!(PointAffine::from_coordinate(nx, ny).is_some());
+                }
+            }
+
+            // all the forms, the accepted ones being encoded back to the same bytes
+            let flags = ValidationFlags::default().with_hybrid();
+            let decoded = PointAffine::decode_sec1(data, flags);
+            match &decoded {
+                Ok(p) => {
+                    let form = match data[0] {
+                        0x02 | 0x03 => Sec1Form::Compressed,
+                        0x04 => Sec1Form::Uncompressed,
+                        _ => Sec1Form::Hybrid,
+                    };
+                    assert_eq!(p.encode_sec1(form), data, "sec1 {:02x?}", data);
+                    let (x, y) = p.to_coordinate();
+                    assert!(PointAffine::from_coordinate(x, y).is_some());
+                }
+                Err(e) => assert!(*e != Sec1Error::Disallowed, "sec1 {:02x?}", data),
+            }
+            let hybrid = data.first().map_or(false, |t| *t == 0x06 || *t == 0x07);
+            if !hybrid {
+                assert_eq!(PointAffine::from_sec1_bytes(data), decoded.clone().ok());
+            }
+            match (Point::decode_sec1(data, flags), decoded) {
+                (Ok(p), Err(Sec1Error::Infinity)) => {
+                    assert_eq!(p, Point::infinity());
+                    assert_eq!(p.encode_sec1(Sec1Form::Compressed), data);
+                }
+                (Ok(p), Ok(q)) => assert_eq!(p.to_affine(), Some(q)),
+                (Err(e), Err(f)) => assert_eq!(e, f),
+                (p, q) => panic!("sec1 {:02x?}: point {:?} affine {:?}", data, p, q),
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+parse_curve!(p192k1, crate::curve::sec2::p192k1);
+#[cfg(feature = "p192r1")]
+parse_curve!(p192r1, crate::curve::sec2::p192r1);
+#[cfg(feature = "p224k1")]
+parse_curve!(p224k1, crate::curve::sec2::p224k1);
+#[cfg(feature = "p224r1")]
+parse_curve!(p224r1, crate::curve::sec2::p224r1);
+#[cfg(feature = "p256k1")]
+parse_curve!(p256k1, crate::curve::sec2::p256k1);
+#[cfg(feature = "p256r1")]
+parse_curve!(p256r1, crate::curve::sec2::p256r1);
+#[cfg(feature = "p384r1")]
+parse_curve!(p384r1, crate::curve::sec2::p384r1);
+#[cfg(feature = "p521r1")]
+parse_curve!(p521r1, crate::curve::sec2::p521r1);
+#[cfg(feature = "sm2")]
+parse_curve!(sm2, crate::curve::sm2);
+
+/// Decode the bytes with all the enabled curves, checking the parsing invariants
+pub fn parse(data: &[u8]) {
+    #[cfg(feature = "p192k1")]
+    p192k1(data);
+    #[cfg(feature = "p192r1")]
+    p192r1(data);
+    #[cfg(feature = "p224k1")]
+    p224k1(data);
+    #[cfg(feature = "p224r1")]
+    p224r1(data);
+    #[cfg(feature = "p256k1")]
+    p256k1(data);
+    #[cfg(feature = "p256r1")]
+    p256r1(data);
+    #[cfg(feature = "p384r1")]
+    p384r1(data);
+    #[cfg(feature = "p521r1")]
+    p521r1(data);
+    #[cfg(feature = "sm2")]
+    sm2(data);
+    let _ = data;
+}
+
+// only the arithmetic is compared
+#[cfg(all(feature = "p192r1", feature = "num-bigint", feature = "num-traits"))]
+#[allow(dead_code)]
+mod bigint {
+    crate::bigint_prime_curve!(p192r1, 192, 192);
+}
+
+/// Compare the fiat and the bigint p192r1 on the two halves of the bytes
+///
+/// The halves are reduced modulo the field prime and the order, and the field
+/// elements, scalars and the multiples of the generator are compared through their
+/// bytes, the square roots up to the sign.
+#[cfg(all(feature = "p192r1", feature = "num-bigint", feature = "num-traits"))]
+pub fn diff_p192r1(data: &[u8]) {
+    use crate::curve::sec2::p192r1 as fiat;
+    use bigint::p192r1 as big;
+
+    let (a, b) = data.split_at(data.len() / 2);
+
+    let (x, y) = (
+        fiat::FieldElement::from_be_bytes_mod_p(a),
+        fiat::FieldElement::from_be_bytes_mod_p(b),
+    );
+    let bx = big::FieldElement::from_bytes(&x.to_bytes()).unwrap();
+    let by = big::FieldElement::from_bytes(&y.to_bytes()).unwrap();
+    assert_eq!(
+        (&x + &y).to_bytes(),
+        (&bx + &by).to_bytes(),
+        "add {} {}",
+        x,
+        y
+    );
+    assert_eq!(
+        (&x - &y).to_bytes(),
+        (&bx - &by).to_bytes(),
+        "sub {} {}",
+        x,
+        y
+    );
+    assert_eq!(
+        (&x * &y).to_bytes(),
+        (&bx * &by).to_bytes(),
+        "mul {} {}",
+        x,
+        y
+    );
+    assert_eq!((-&x).to_bytes(), (-&bx).to_bytes(), "neg {}", x);
+    assert_eq!(
+        x.square().to_bytes(),
+        (&bx * &bx).to_bytes(),
+        "square {}",
+        x
+    );
+    match bx.inverse() {
+        Some(inv) => assert_eq!(x.inverse().to_bytes(), inv.to_bytes(), "inverse {}", x),
+        None => assert!(x.is_zero()),
+    }
+    match (x.sqrt().into_option(), bx.sqrt()) {
+        (Some(r), Some(br)) => {
+            let br = br.to_bytes();
+            assert!(r.to_bytes() == br || (-&r).to_bytes() == br, "sqrt {}", x);
+        }
+        (None, None) => {}
+        (r, br) => panic!("sqrt {}: fiat {:?} bigint {:?}", x, r, br),
+    }
+
+    let (s, t) = (
+        fiat::Scalar::from_be_bytes_mod_p(a),
+        fiat::Scalar::from_be_bytes_mod_p(b),
+    );
+    let bs = big::Scalar::from_bytes(&s.to_bytes()).unwrap();
+    let bt = big::Scalar::from_bytes(&t.to_bytes()).unwrap();
+    assert_eq!(
+        (&s + &t).to_bytes(),
+        (&bs + &bt).to_bytes(),
+        "add {:?} {:?}",
+        s,
+        t
+    );
+    assert_eq!(
+        (&s - &t).to_bytes(),
+        (&bs - &bt).to_bytes(),
+        "sub {:?} {:?}",
+        s,
+        t
+    );
+    assert_eq!(
+        (&s * &t).to_bytes(),
+        (&bs * &bt).to_bytes(),
+        "mul {:?} {:?}",
+        s,
+        t
+    );
+
+    let coordinates = |p: Option<fiat::PointAffine>| {
+        p.map(|p| {
+            (
+                p.to_coordinate().0.to_bytes(),
+                p.to_coordinate().1.to_bytes(),
+            )
+        })
+    };
+    let big_coordinates = |p: Option<big::PointAffine>| {
+        p.map(|p| {
+            (
+                p.to_coordinate().0.to_bytes(),
+                p.to_coordinate().1.to_bytes(),
+            )
+        })
+    };
+    let p = &fiat::Point::generator() * &s;
+    let bp = &big::Point::generator() * &bs;
+    assert_eq!(
+        coordinates(p.to_affine()),
+        big_coordinates(bp.to_affine()),
+        "{:?}.G",
+        s
+    );
+    let q = &p + &(&fiat::Point::generator() * &t);
+    let bq = &bp + &(&big::Point::generator() * &bt);
+    assert_eq!(
+        coordinates(q.to_affine()),
+        big_coordinates(bq.to_affine()),
+        "{:?}.G + {:?}.G",
+        s,
+        t
+    );
+}
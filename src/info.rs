@@ -0,0 +1,85 @@
+//! Description of the build, to log which curves and which implementations a binary
+//! was compiled with
+//!
+//! ```
+//! let info = eccoxide::build_info();
+//! for curve in info.curves {
+//!     println!("{} {}: {:?}", info.version, curve.name, curve.backend);
+//! }
+//! ```
+
+use crate::curve::Backend;
+
+/// Curve compiled in, with the implementation of its base field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveBuild {
+    /// Name of the curve module (e.g. "p256r1")
+    pub name: &'static str,
+    pub backend: Backend,
+}
+
+/// Features and implementations of this build of the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Version of the crate
+    pub version: &'static str,
+    /// Curves enabled by the features, in the order of their size
+    pub curves: &'static [CurveBuild],
+    /// Size in bits of the limbs of the fixed size backends
+    pub limb_bits: u32,
+    /// Whether the tables of multiples of the generators are computed at build time,
+    /// rather than at runtime
+    pub precomputed_tables: bool,
+}
+
+macro_rules! curve_build {
+    ($name:literal, $curve:path) => {
+        CurveBuild {
+            name: $name,
+            backend: <$curve>::backend(),
+        }
+    };
+}
+
+static BUILD_INFO: BuildInfo = BuildInfo {
+    version: env!("CARGO_PKG_VERSION"),
+    curves: &[
+        #[cfg(feature = "p112r2")]
+        curve_build!("p112r2", crate::curve::sec2::p112r2::Curve),
+        #[cfg(feature = "p128r1")]
+        curve_build!("p128r1", crate::curve::sec2::p128r1::Curve),
+        #[cfg(feature = "p128r2")]
+        curve_build!("p128r2", crate::curve::sec2::p128r2::Curve),
+        #[cfg(feature = "p160k1")]
+        curve_build!("p160k1", crate::curve::sec2::p160k1::Curve),
+        #[cfg(feature = "p160r1")]
+        curve_build!("p160r1", crate::curve::sec2::p160r1::Curve),
+        #[cfg(feature = "p160r2")]
+        curve_build!("p160r2", crate::curve::sec2::p160r2::Curve),
+        #[cfg(feature = "p192k1")]
+        curve_build!("p192k1", crate::curve::sec2::p192k1::Curve),
+        #[cfg(feature = "p192r1")]
+        curve_build!("p192r1", crate::curve::sec2::p192r1::Curve),
+        #[cfg(feature = "p224k1")]
+        curve_build!("p224k1", crate::curve::sec2::p224k1::Curve),
+        #[cfg(feature = "p224r1")]
+        curve_build!("p224r1", crate::curve::sec2::p224r1::Curve),
+        #[cfg(feature = "p256k1")]
+        curve_build!("p256k1", crate::curve::sec2::p256k1::Curve),
+        #[cfg(feature = "p256r1")]
+        curve_build!("p256r1", crate::curve::sec2::p256r1::Curve),
+        #[cfg(feature = "sm2")]
+        curve_build!("sm2p256v1", crate::curve::sm2::Curve),
+        #[cfg(feature = "p384r1")]
+        curve_build!("p384r1", crate::curve::sec2::p384r1::Curve),
+        #[cfg(feature = "p521r1")]
+        curve_build!("p521r1", crate::curve::sec2::p521r1::Curve),
+    ],
+    limb_bits: 64,
+    precomputed_tables: false,
+};
+
+/// Describe the curves compiled in and their implementations
+pub fn build_info() -> &'static BuildInfo {
+    &BUILD_INFO
+}
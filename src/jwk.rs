@@ -0,0 +1,195 @@
+//! JSON Web Key (RFC 7517) import and export of the elliptic curve keys
+//!
+//! As defined in RFC 7518 section 6.2, a public key is:
+//!
+//! ```text
+//! {"kty":"EC","crv":"P-256","x":"<base64url(X)>","y":"<base64url(Y)>"}
+//! ```
+//!
+//! and a private key adds the secret scalar as `"d":"<base64url(d)>"`. The coordinates
+//! and the scalar are the fixed size big endian encodings, in base64url without padding.
+//!
+//! The curves with a registered name are supported: "P-256" (p256r1), "P-384" (p384r1),
+//! "P-521" (p521r1), and "secp256k1" (p256k1, RFC 8812). The conversions are `to_jwk` and
+//! `from_jwk` on the `PublicKey` and `SecretKey` of these curves.
+//!
+//! Only the key members are kept: the other members (e.g. "kid", "use" or "alg") are ignored
+//! when deserializing, which also means that a private key can be deserialized as a public key.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use core::convert::TryInto;
+use serde::{Deserialize, Serialize};
+
+/// Key type of the elliptic curve keys
+pub const KTY_EC: &str = "EC";
+
+/// JSON Web Key of an elliptic curve public key
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKeyJwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    pub y: String,
+}
+
+/// JSON Web Key of an elliptic curve private key
+///
+/// The public coordinates are optional when importing, and checked against the secret
+/// scalar when present. The Debug output doesn't show the secret scalar.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrivateKeyJwk {
+    pub kty: String,
+    pub crv: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    pub d: String,
+}
+
+impl core::fmt::Debug for PrivateKeyJwk {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PrivateKeyJwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reason for rejecting a JSON Web Key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JwkError {
+    /// The key type is not "EC"
+    InvalidKeyType,
+    /// The curve name is not the one of the curve the key is imported into
+    InvalidCurve,
+    /// A member is not base64url without padding, or doesn't have the expected size
+    InvalidEncoding,
+    /// The coordinates are not the ones of a point on the curve
+    InvalidPoint,
+    /// The secret scalar is zero, or not less than the group order
+    InvalidScalar,
+    /// Only one of the public coordinates is present in a private key
+    MissingCoordinate,
+    /// The public coordinates of a private key don't match the secret scalar
+    KeyMismatch,
+}
+
+impl core::fmt::Display for JwkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            JwkError::InvalidKeyType => "invalid key type",
+            JwkError::InvalidCurve => "invalid curve",
+            JwkError::InvalidEncoding => "invalid base64url encoding or size",
+            JwkError::InvalidPoint => "coordinates not on the curve",
+            JwkError::InvalidScalar => "invalid secret scalar",
+            JwkError::MissingCoordinate => "missing public coordinate",
+            JwkError::KeyMismatch => "public coordinates don't match the secret scalar",
+        };
+        f.write_str(s)
+    }
+}
+
+impl std::error::Error for JwkError {}
+
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub(crate) fn decode<const N: usize>(s: &str) -> Result<[u8; N], JwkError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(s)
+        .map_err(|_| JwkError::InvalidEncoding)?;
+    bytes.try_into().map_err(|_| JwkError::InvalidEncoding)
+}
+
+pub(crate) fn check_header(kty: &str, crv: &str, expected_crv: &str) -> Result<(), JwkError> {
+    if kty != KTY_EC {
+        Err(JwkError::InvalidKeyType)
+    } else if crv != expected_crv {
+        Err(JwkError::InvalidCurve)
+    } else {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! jwk_define {
+    ($crv:literal) => {
+        // decode and validate the public coordinates
+        fn jwk_point(x: &str, y: &str) -> Result<PointAffine, $crate::jwk::JwkError> {
+            use $crate::jwk::{decode, JwkError};
+            let x = FieldElement::from_bytes(&decode(x)?).ok_or(JwkError::InvalidPoint)?;
+            let y = FieldElement::from_bytes(&decode(y)?).ok_or(JwkError::InvalidPoint)?;
+            PointAffine::from_coordinate(&x, &y).ok_or(JwkError::InvalidPoint)
+        }
+
+        impl PublicKey {
+            #[doc = concat!("Export the public key as a JSON Web Key on the curve \"", $crv, "\"")]
+            pub fn to_jwk(&self) -> $crate::jwk::PublicKeyJwk {
+                let (x, y) = self.as_point().to_coordinate();
+                $crate::jwk::PublicKeyJwk {
+                    kty: $crate::jwk::KTY_EC.to_string(),
+                    crv: $crv.to_string(),
+                    x: $crate::jwk::encode(&x.to_bytes()),
+                    y: $crate::jwk::encode(&y.to_bytes()),
+                }
+            }
+
+            #[doc = concat!("Import a public key from a JSON Web Key on the curve \"", $crv, "\"")]
+            ///
+            /// The coordinates need to be of the field element size, and to be a point on the curve
+            pub fn from_jwk(
+                jwk: &$crate::jwk::PublicKeyJwk,
+            ) -> Result<Self, $crate::jwk::JwkError> {
+                $crate::jwk::check_header(&jwk.kty, &jwk.crv, $crv)?;
+                jwk_point(&jwk.x, &jwk.y).map(PublicKey::from_point)
+            }
+        }
+
+        impl SecretKey {
+            #[doc = concat!(
+                "Export the secret key, along with its public key, as a JSON Web Key on the curve \"",
+                $crv,
+                "\""
+            )]
+            pub fn to_jwk(&self) -> $crate::jwk::PrivateKeyJwk {
+                let public = self.public_key().to_jwk();
+                $crate::jwk::PrivateKeyJwk {
+                    kty: public.kty,
+                    crv: public.crv,
+                    x: Some(public.x),
+                    y: Some(public.y),
+                    d: $crate::jwk::encode(&self.to_bytes()),
+                }
+            }
+
+            #[doc = concat!("Import a secret key from a JSON Web Key on the curve \"", $crv, "\"")]
+            ///
+            /// The scalar needs to be of the scalar size, and when the public coordinates
+            /// are present, they need to be the public key of this secret key
+            pub fn from_jwk(
+                jwk: &$crate::jwk::PrivateKeyJwk,
+            ) -> Result<Self, $crate::jwk::JwkError> {
+                use $crate::jwk::JwkError;
+                $crate::jwk::check_header(&jwk.kty, &jwk.crv, $crv)?;
+                let key = SecretKey::from_bytes(&$crate::jwk::decode(&jwk.d)?)
+                    .ok_or(JwkError::InvalidScalar)?;
+                match (&jwk.x, &jwk.y) {
+                    (Some(x), Some(y)) => {
+                        if &jwk_point(x, y)? != key.public_key().as_point() {
+                            return Err(JwkError::KeyMismatch);
+                        }
+                    }
+                    (None, None) => {}
+                    _ => return Err(JwkError::MissingCoordinate),
+                }
+                Ok(key)
+            }
+        }
+    };
+}
@@ -26,6 +26,10 @@
 //! let generator = Point::generator();
 //! let same_generator = &generator + Point::infinity();
 //!
+//! // multiply the generator by a small integer
+//! let three_generator = &generator * 3u64;
+//! assert_eq!(three_generator, &(&generator + &generator) + &generator);
+//!
 //! // transform the point to affine coordinate
 //! let point = generator.to_affine().unwrap();
 //! let (x, y) = point.to_coordinate();
@@ -57,12 +61,61 @@
 //! public_key_bytes.extend_from_slice(&x.to_bytes());
 //! ```
 
-#[macro_use]
-extern crate lazy_static;
+// emit a debugging event when the trace feature is enabled, otherwise expand to nothing
+// (checked on the binary of the trace_hooks benchmark by tools/check_trace_hooks.sh)
+#[cfg(feature = "trace")]
+macro_rules! trace_event {
+    ($event:expr) => {
+        crate::trace::emit(&$event)
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! trace_event {
+    ($event:expr) => {};
+}
+
+// corrupt the value with the closure when a fault is armed by a test (see the curve::fault
+// module), otherwise expand to the value
+#[cfg(all(test, feature = "fault-hardening"))]
+macro_rules! fault_point {
+    ($value:expr, $corrupt:expr) => {
+        crate::curve::fault::inject($value, $corrupt)
+    };
+}
+
+#[cfg(not(all(test, feature = "fault-hardening")))]
+macro_rules! fault_point {
+    ($value:expr, $corrupt:expr) => {
+        $value
+    };
+}
+
+#[cfg(all(feature = "default-mul-ct", feature = "default-mul-vartime"))]
+compile_error!("the features default-mul-ct and default-mul-vartime are mutually exclusive");
+
+#[cfg(not(any(feature = "default-mul-ct", feature = "default-mul-vartime")))]
+compile_error!("one of the features default-mul-ct (the default) or default-mul-vartime is needed");
 
 pub mod curve;
+#[cfg(feature = "pkcs8")]
+pub mod encoding;
+#[cfg(any(test, feature = "fuzzing"))]
+#[doc(hidden)]
+pub mod fuzzing;
+pub mod info;
+#[cfg(feature = "jwk")]
+pub mod jwk;
 pub(crate) mod mp;
 pub mod params;
+pub mod registry;
+pub mod selftest;
+#[cfg(feature = "trace")]
+pub mod trace;
+
+pub use info::{build_info, BuildInfo};
+pub use mp::ct::{Choice, CtEqual, CtOption};
+pub use selftest::{self_test, SelfTestError};
 
 #[cfg(test)]
 mod tests;
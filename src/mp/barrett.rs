@@ -0,0 +1,98 @@
+//! Barrett reduction of the integers of twice the size of a modulus
+//!
+//! With b = 2^64 and a modulus m of k limbs (its top limb not zero), the precomputed
+//! µ = floor(b^(2k) / m) gives an estimate of the quotient of any x < b^(2k) by m,
+//! from which x mod m is at most two subtractions of m away (HAC algorithm 14.42).
+//! The µ of the curves primes and orders are in `params` (`MICRO_LIMBS` and
+//! `ORDER_MICRO_LIMBS`).
+//!
+//! The limbs are in big endian order, like the `params` constants, and the reduction
+//! doesn't branch on the values.
+
+use super::ct::Choice;
+
+/// Number of limbs of the largest modulus (p521r1)
+const MAX_LIMBS: usize = 9;
+
+// little endian a - b of the same length, returning the borrow
+fn sub_borrow(out: &mut [u64], a: &[u64], b: &[u64]) -> u64 {
+    let mut borrow = 0u64;
+    for i in 0..out.len() {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        out[i] = d;
+        borrow = (b1 | b2) as u64;
+    }
+    borrow
+}
+
+// little endian product of a and b, truncated to the size of out
+fn mul_truncated(out: &mut [u64], a: &[u64], b: &[u64]) {
+    out.iter_mut().for_each(|l| *l = 0);
+    for (i, ai) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, bj) in b.iter().enumerate() {
+            if i + j >= out.len() {
+                break;
+            }
+            let t = (*ai as u128) * (*bj as u128) + out[i + j] as u128 + carry;
+            out[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        if i + b.len() < out.len() {
+            out[i + b.len()] = carry as u64;
+        }
+    }
+}
+
+/// Reduce x of `2 * N` limbs modulo m of N limbs, µ being `floor(b^(2N) / m)`
+///
+/// All in big endian limbs, µ having between `N + 1` and `2 * N` limbs (its leading limbs
+/// are zero). Panics if the sizes don't match, or if the modulus has more than 9 limbs.
+pub fn reduce_wide<const N: usize>(x: &[u64], m: &[u64; N], mu: &[u64]) -> [u64; N] {
+    assert!(N <= MAX_LIMBS);
+    assert_eq!(x.len(), 2 * N);
+    assert!(mu.len() > N && mu.len() <= 2 * N);
+
+    // little endian copies, µ being less than b^(N+1)
+    let mut xl = [0u64; 2 * MAX_LIMBS];
+    let mut ml = [0u64; MAX_LIMBS + 1];
+    let mut mul = [0u64; MAX_LIMBS + 1];
+    for i in 0..2 * N {
+        xl[i] = x[2 * N - 1 - i];
+    }
+    for i in 0..N {
+        ml[i] = m[N - 1 - i];
+    }
+    for i in 0..N + 1 {
+        mul[i] = mu[mu.len() - 1 - i];
+    }
+    debug_assert!(mu[..mu.len() - N - 1].iter().all(|l| *l == 0));
+
+    // q3 = floor(floor(x / b^(N-1)) * µ / b^(N+1))
+    let mut q2 = [0u64; 2 * MAX_LIMBS + 2];
+    mul_truncated(&mut q2[..2 * N + 2], &xl[N - 1..2 * N], &mul[..N + 1]);
+    let q3 = &q2[N + 1..2 * N + 2];
+
+    // r = (x - q3 * m) mod b^(N+1), which is less than 3m
+    let mut r2 = [0u64; MAX_LIMBS + 1];
+    mul_truncated(&mut r2[..N + 1], q3, &ml[..N + 1]);
+    let mut r = [0u64; MAX_LIMBS + 1];
+    sub_borrow(&mut r[..N + 1], &xl[..N + 1], &r2[..N + 1]);
+
+    // subtract m twice when there's no borrow
+    for _ in 0..2 {
+        let mut t = [0u64; MAX_LIMBS + 1];
+        let borrow = sub_borrow(&mut t[..N + 1], &r[..N + 1], &ml[..N + 1]);
+        let keep = Choice::from_bit(borrow).to_mask_u64();
+        for i in 0..N + 1 {
+            r[i] = (r[i] & keep) | (t[i] & !keep);
+        }
+    }
+
+    let mut out = [0u64; N];
+    for i in 0..N {
+        out[i] = r[N - 1 - i];
+    }
+    out
+}
@@ -7,6 +7,7 @@
 //! * CtEqual : constant time equality and non-equality checking
 //! * CtLesser : constant time less (<) and opposite greater-equal (>=) checking
 //! * CtGreater : constant time greater (>) and opposite lesser-equal (<=) checking
+//! * CtSelect : constant time selection between two objects
 //!
 //! And simple types to manipulate those capabilities in a safer way:
 //!
@@ -20,6 +21,25 @@
 //!
 //! The general functionality would be a great addition to the rust core library
 //! to have those type of things built-in and crucially more eyeballs.
+//!
+//! # Choice hygiene
+//!
+//! Rules for the code of this crate that manipulates a `Choice`:
+//!
+//! * a Choice is only built with `Choice::from_bit`, from a value already computed
+//!   with arithmetic (e.g. the top bit of a word, or a borrow), never from the
+//!   result of a branch. The value goes through `core::hint::black_box`, so the
+//!   compiler doesn't see that it's only ever 0 or 1 and cannot turn the masking
+//!   that follows back into a branch.
+//! * a mask is only derived with `Choice::to_mask_u64`, which is 0 or `u64::MAX`,
+//!   and the selection is done with `x ^ (mask & (x ^ y))`.
+//! * `is_true`, `is_false` and the conversion to bool are only used once the
+//!   value is public (e.g. the final result of a verification).
+//!
+//! `black_box` is a best effort barrier and not a guarantee. When changing this
+//! module, check the generated assembly of the selection (e.g. `ct_select` of a
+//! `[u64; N]` with `cargo asm` or `--emit asm`) for conditional jumps: on x86_64
+//! the mask should stay a `neg`/`and`/`xor` sequence, or a `cmov`.
 
 /// Constant time boolean
 ///
@@ -32,7 +52,7 @@
 /// Choice can be converted back to a boolean operations, although
 /// once this is done, the operation will likely be non-constant.
 #[derive(Clone, Copy)]
-pub struct Choice(pub(crate) u64);
+pub struct Choice(u64);
 
 /// Constant time equivalent to Option.
 ///
@@ -46,6 +66,22 @@ pub struct CtOption<T> {
 }
 
 impl Choice {
+    /// Create a Choice from a bit, which has to be either 0 or 1
+    ///
+    /// The bit is hidden from the optimizer, see the module documentation
+    #[inline]
+    pub(crate) fn from_bit(bit: u64) -> Self {
+        debug_assert!(bit <= 1);
+        Choice(core::hint::black_box(bit))
+    }
+
+    /// Get the mask associated with the choice: all bits set (`u64::MAX`) when
+    /// true, all bits cleared (0) when false
+    #[inline]
+    pub fn to_mask_u64(self) -> u64 {
+        self.0.wrapping_neg()
+    }
+
     pub fn is_true(self) -> bool {
         self.0 == 1
     }
@@ -53,7 +89,7 @@ impl Choice {
         self.0 == 0
     }
     pub fn negate(self) -> Self {
-        Choice(1 ^ self.0)
+        Choice::from_bit(1 ^ self.0)
     }
 }
 
@@ -66,7 +102,14 @@ impl From<Choice> for bool {
 impl core::ops::BitAnd for Choice {
     type Output = Choice;
     fn bitand(self, b: Choice) -> Choice {
-        Choice(self.0 & b.0)
+        Choice::from_bit(self.0 & b.0)
+    }
+}
+
+impl core::ops::BitXor for Choice {
+    type Output = Choice;
+    fn bitxor(self, b: Choice) -> Choice {
+        Choice::from_bit(self.0 ^ b.0)
     }
 }
 
@@ -80,6 +123,11 @@ impl<T> From<(Choice, T)> for CtOption<T> {
 }
 
 impl<T> CtOption<T> {
+    /// Choice of the value being present
+    pub fn is_some(&self) -> Choice {
+        self.present
+    }
+
     pub fn into_option(self) -> Option<T> {
         if self.present.is_true() {
             Some(self.t)
@@ -128,12 +176,19 @@ pub trait CtEqual<Rhs: ?Sized = Self> {
     }
 }
 
+/// Select in constant time between the left object and the right object
+///
+/// This is equivalent to `if choice { b } else { a }`, without branching
+pub trait CtSelect: Sized {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self;
+}
+
 impl CtZero for u64 {
     fn ct_zero(&self) -> Choice {
-        Choice(1 ^ ((self | self.wrapping_neg()) >> 63))
+        Choice::from_bit(1 ^ ((self | self.wrapping_neg()) >> 63))
     }
     fn ct_nonzero(&self) -> Choice {
-        Choice((self | self.wrapping_neg()) >> 63)
+        Choice::from_bit((self | self.wrapping_neg()) >> 63)
     }
 }
 
@@ -148,7 +203,7 @@ impl CtEqual for u64 {
 
 impl CtLesser for u64 {
     fn ct_lt(a: Self, b: Self) -> Choice {
-        Choice((a ^ ((a ^ b) | ((a - b) ^ b))) >> 63)
+        Choice::from_bit((a ^ ((a ^ b) | (a.wrapping_sub(b) ^ b))) >> 63)
     }
 }
 
@@ -239,6 +294,18 @@ impl CtEqual for [u64] {
     }
 }
 
+impl<const N: usize> CtSelect for [u64; N] {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        // all bits set when choice is true, all bits cleared otherwise
+        let mask = choice.to_mask_u64();
+        let mut out = [0u64; N];
+        for (o, (x, y)) in out.iter_mut().zip(a.iter().zip(b.iter())) {
+            *o = x ^ (mask & (x ^ y));
+        }
+        out
+    }
+}
+
 // big endian representation of a number, but also leading byte of a array being the MSB.
 impl<const N: usize> CtLesser for &[u8; N] {
     fn ct_lt(a: Self, b: Self) -> Choice {
@@ -249,7 +316,41 @@ impl<const N: usize> CtLesser for &[u8; N] {
             borrow = (0x0 - x2) as u8;
         }
         let borrow = borrow as u64;
-        Choice((borrow | borrow.wrapping_neg()) >> 63)
+        Choice::from_bit((borrow | borrow.wrapping_neg()) >> 63)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl From<Choice> for subtle::Choice {
+    fn from(c: Choice) -> subtle::Choice {
+        subtle::Choice::from(c.0 as u8)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl From<subtle::Choice> for Choice {
+    fn from(c: subtle::Choice) -> Choice {
+        Choice::from_bit(c.unwrap_u8() as u64)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<T> From<CtOption<T>> for subtle::CtOption<T> {
+    fn from(o: CtOption<T>) -> subtle::CtOption<T> {
+        subtle::CtOption::new(o.t, o.present.into())
+    }
+}
+
+// subtle doesn't give access to the value without a fallback, so a default
+// value is selected in constant time when the option is not present
+#[cfg(feature = "subtle")]
+impl<T: subtle::ConditionallySelectable + Default> From<subtle::CtOption<T>> for CtOption<T> {
+    fn from(o: subtle::CtOption<T>) -> CtOption<T> {
+        let present = o.is_some().into();
+        CtOption {
+            present,
+            t: o.unwrap_or(T::default()),
+        }
     }
 }
 
@@ -263,9 +364,92 @@ mod tests {
         assert_eq!(1u64.ct_zero().is_false(), true);
     }
 
+    #[test]
+    fn masks() {
+        assert_eq!(Choice::from_bit(0).to_mask_u64(), 0);
+        assert_eq!(Choice::from_bit(1).to_mask_u64(), !0);
+        assert_eq!(Choice::from_bit(1).negate().to_mask_u64(), 0);
+        assert_eq!(Choice::from_bit(0).negate().to_mask_u64(), !0);
+        assert_eq!(0u64.ct_zero().to_mask_u64(), !0);
+        assert_eq!(u64::MAX.ct_zero().to_mask_u64(), 0);
+    }
+
+    // the constant time operations agree with the core library operators
+    #[test]
+    fn u64_semantics() {
+        let values = [
+            0u64,
+            1,
+            2,
+            0x7fff_ffff_ffff_ffff,
+            0x8000_0000_0000_0000,
+            0x8000_0000_0000_0001,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for a in values.iter().copied() {
+            assert_eq!(a.ct_zero().is_true(), a == 0);
+            assert_eq!(a.ct_nonzero().is_true(), a != 0);
+            for b in values.iter().copied() {
+                assert_eq!(a.ct_eq(&b).is_true(), a == b);
+                assert_eq!(a.ct_ne(&b).is_true(), a != b);
+                assert_eq!(u64::ct_lt(a, b).is_true(), a < b);
+                assert_eq!(u64::ct_ge(a, b).is_true(), b < a);
+                assert_eq!(u64::ct_gt(a, b).is_true(), a > b);
+                assert_eq!(u64::ct_le(a, b).is_true(), b > a);
+                assert_eq!((a.ct_eq(&b) & a.ct_zero()).is_true(), a == b && a == 0);
+                let selected = <[u64; 1]>::ct_select(&[a], &[b], u64::ct_lt(a, b));
+                assert_eq!(selected, [if a < b { b } else { a }]);
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_semantics() {
+        let values: [[u8; 3]; 6] = [
+            [0, 0, 0],
+            [0, 0, 1],
+            [0, 1, 0],
+            [0x80, 0, 0],
+            [0xff, 0xff, 0xfe],
+            [0xff, 0xff, 0xff],
+        ];
+        for a in values.iter() {
+            assert_eq!(a.ct_zero().is_true(), a == &[0, 0, 0]);
+            for b in values.iter() {
+                assert_eq!(a.ct_eq(b).is_true(), a == b);
+                assert_eq!(<&[u8; 3]>::ct_lt(a, b).is_true(), a < b);
+            }
+        }
+    }
+
     #[test]
     fn test_ct_less() {
         let a: [u8; 4] = [0u8, 1, 2, 3];
         assert_eq!(<&[u8; 4]>::ct_lt(&a, &[1, 1, 2, 3]).is_true(), true);
     }
+
+    #[test]
+    fn ct_select() {
+        let a = [1u64, 2, 0xffff_ffff_ffff_ffff];
+        let b = [0x8000_0000_0000_0000u64, 0, 3];
+        assert_eq!(<[u64; 3]>::ct_select(&a, &b, Choice(0)), a);
+        assert_eq!(<[u64; 3]>::ct_select(&a, &b, Choice(1)), b);
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn subtle_conversions() {
+        for v in [0u64, 1] {
+            let c: subtle::Choice = Choice(v).into();
+            assert_eq!(c.unwrap_u8() as u64, v);
+            let back: Choice = c.into();
+            assert_eq!(back.0, v);
+
+            let o: subtle::CtOption<u64> = CtOption::from((Choice(v), 42u64)).into();
+            assert_eq!(o.is_some().unwrap_u8() as u64, v);
+            let back: CtOption<u64> = o.into();
+            assert_eq!(back.into_option(), if v == 1 { Some(42) } else { None });
+        }
+    }
 }
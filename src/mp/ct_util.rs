@@ -0,0 +1,39 @@
+//! Constant time utilities built on top of the `ct` traits
+//!
+//! * ct_table_lookup : read a table entry at a secret index
+//! * ct_index_bits : the bits of a secret index, for ct_table_lookup
+
+use super::ct::{Choice, CtEqual, CtSelect};
+
+/// Return `table[index]` where index is given by its little endian bits, without
+/// a memory access pattern depending on the index
+///
+/// Every entry is read and selected with `CtSelect`, so the number of select
+/// operations is always `table.len() - 1`, whatever the index is. The table
+/// needs to have `2^index_bits.len()` entries.
+///
+/// This is the lookup of all the window based constant time algorithms, e.g. the
+/// `PointTable` and `FixedBaseTable` multiplications.
+pub fn ct_table_lookup<T: CtSelect + Clone>(table: &[T], index_bits: &[Choice]) -> T {
+    assert!(index_bits.len() < 32);
+    assert_eq!(table.len(), 1 << index_bits.len());
+
+    // rebuild the index without branching on its bits
+    let index = index_bits
+        .iter()
+        .enumerate()
+        .fold(0u64, |acc, (i, bit)| acc | ((bit.to_mask_u64() & 1) << i));
+
+    // no early exit: the loop goes through the whole table for any index
+    let mut out = table[0].clone();
+    for (i, entry) in table.iter().enumerate().skip(1) {
+        out = T::ct_select(&out, entry, (i as u64).ct_eq(&index));
+    }
+    out
+}
+
+/// The `B` little endian bits of a secret index, e.g. a window of a scalar, for
+/// `ct_table_lookup`
+pub fn ct_index_bits<const B: usize>(index: usize) -> [Choice; B] {
+    core::array::from_fn(|i| Choice::from_bit(((index >> i) & 1) as u64))
+}
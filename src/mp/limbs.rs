@@ -56,7 +56,7 @@ pub fn limbsbe_le<'a, 'b>(a: LimbsBE<'a>, b: LimbsBE<'b>) -> Choice {
         let copied_borrow = borrow;
         limb_subborrow(&mut out, &mut borrow, copied_borrow, *x, *y);
     }
-    Choice(1 ^ borrow as u64)
+    Choice::from_bit(1 ^ borrow as u64)
 }
 
 pub fn limbsbe_lt<'a, 'b>(a: LimbsBE<'a>, b: LimbsBE<'b>) -> Choice {
@@ -69,7 +69,7 @@ pub fn limbsbe_lt<'a, 'b>(a: LimbsBE<'a>, b: LimbsBE<'b>) -> Choice {
         limb_subborrow(&mut out, &mut borrow, copied_borrow, *x, *y);
     }
     let borrow = borrow as u64;
-    Choice((borrow | borrow.wrapping_neg()) >> 63)
+    Choice::from_bit((borrow | borrow.wrapping_neg()) >> 63)
 }
 
 pub fn limbsle_le<'a, 'b>(a: LimbsLE<'a>, b: LimbsLE<'b>) -> Choice {
@@ -81,7 +81,7 @@ pub fn limbsle_le<'a, 'b>(a: LimbsLE<'a>, b: LimbsLE<'b>) -> Choice {
         let copied_borrow = borrow;
         limb_subborrow(&mut out, &mut borrow, copied_borrow, *x, *y)
     }
-    Choice(1 ^ borrow as u64)
+    Choice::from_bit(1 ^ borrow as u64)
 }
 
 pub fn limbsle_lt<'a, 'b>(a: LimbsLE<'a>, b: LimbsLE<'b>) -> Choice {
@@ -94,7 +94,7 @@ pub fn limbsle_lt<'a, 'b>(a: LimbsLE<'a>, b: LimbsLE<'b>) -> Choice {
         limb_subborrow(&mut out, &mut borrow, copied_borrow, *x, *y);
     }
     let borrow = borrow as u64;
-    Choice((borrow | borrow.wrapping_neg()) >> 63)
+    Choice::from_bit((borrow | borrow.wrapping_neg()) >> 63)
 }
 
 impl<'a> CtEqual for LimbsLE<'a> {
@@ -130,6 +130,192 @@ impl<'a> CtLesser for LimbsBE<'a> {
     }
 }
 
+/// Bits of a value in little endian limbs, most significant bit first
+///
+/// The iterator owns its copy of the limbs (e.g. the canonical limbs of a secret scalar,
+/// computed once for a whole scalar multiplication), and the number of bits is given
+/// by the caller, so the iteration doesn't depend on the leading zeros of the value.
+/// The bits are `Choice`, for the constant time users, and `rev()` gives them least
+/// significant first.
+///
+/// This copy is the only one the scalar multiplications make of the scalar: it's the
+/// one a zeroize feature would need to clear on drop.
+pub struct BitsMsbFirst<const N: usize> {
+    limbs: [Limb; N],
+    // the remaining bits are the indices low..high
+    low: usize,
+    high: usize,
+}
+
+impl<const N: usize> BitsMsbFirst<N> {
+    /// The `bits` least significant bits of the limbs, which needs to be at most `64 * N`
+    pub fn new(limbs: [Limb; N], bits: usize) -> Self {
+        assert!(bits <= 64 * N);
+        BitsMsbFirst {
+            limbs,
+            low: 0,
+            high: bits,
+        }
+    }
+
+    fn bit(&self, i: usize) -> Choice {
+        Choice::from_bit((self.limbs[i / 64] >> (i % 64)) & 1)
+    }
+}
+
+impl<const N: usize> Iterator for BitsMsbFirst<N> {
+    type Item = Choice;
+
+    fn next(&mut self) -> Option<Choice> {
+        if self.low == self.high {
+            return None;
+        }
+        self.high -= 1;
+        Some(self.bit(self.high))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.high - self.low, Some(self.high - self.low))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for BitsMsbFirst<N> {
+    fn next_back(&mut self) -> Option<Choice> {
+        if self.low == self.high {
+            return None;
+        }
+        self.low += 1;
+        Some(self.bit(self.low - 1))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for BitsMsbFirst<N> {}
+
+/// Little endian limbs of big endian bytes, which need to fit in N limbs
+pub const fn limbs_from_be_bytes<const N: usize>(bytes: &[u8]) -> [Limb; N] {
+    assert!(bytes.len() <= 8 * N);
+    let mut limbs = [0; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        limbs[i / 8] |= (bytes[bytes.len() - 1 - i] as Limb) << (8 * (i % 8));
+        i += 1;
+    }
+    limbs
+}
+
+// the variable time helpers of `inverse_vartime`, on little endian limbs
+
+fn is_even<const N: usize>(a: &[Limb; N]) -> bool {
+    a[0] & 1 == 0
+}
+
+fn is_small<const N: usize>(a: &[Limb; N], v: Limb) -> bool {
+    a[0] == v && a[1..].iter().all(|l| *l == 0)
+}
+
+// a >= b
+fn ge<const N: usize>(a: &[Limb; N], b: &[Limb; N]) -> bool {
+    for (x, y) in a.iter().zip(b.iter()).rev() {
+        if x != y {
+            return x > y;
+        }
+    }
+    true
+}
+
+// a -= b, returning the borrow
+fn sub_assign<const N: usize>(a: &mut [Limb; N], b: &[Limb; N]) -> bool {
+    let mut borrow = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (v, b1) = x.overflowing_sub(*y);
+        let (v, b2) = v.overflowing_sub(borrow as Limb);
+        *x = v;
+        borrow = b1 | b2;
+    }
+    borrow
+}
+
+// a += b, returning the carry
+fn add_assign<const N: usize>(a: &mut [Limb; N], b: &[Limb; N]) -> bool {
+    let mut carry = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (v, c1) = x.overflowing_add(*y);
+        let (v, c2) = v.overflowing_add(carry as Limb);
+        *x = v;
+        carry = c1 | c2;
+    }
+    carry
+}
+
+// a = (a, with the bit `high` over the limbs) / 2
+fn shr1<const N: usize>(a: &mut [Limb; N], high: bool) {
+    for i in 0..N {
+        let next = if i + 1 < N {
+            a[i + 1] & 1
+        } else {
+            high as Limb
+        };
+        a[i] = (a[i] >> 1) | (next << 63);
+    }
+}
+
+// x / 2 mod m, for x < m and m odd
+fn half_mod<const N: usize>(x: &mut [Limb; N], m: &[Limb; N]) {
+    if is_even(x) {
+        shr1(x, false)
+    } else {
+        let carry = add_assign(x, m);
+        shr1(x, carry)
+    }
+}
+
+// x = x - y mod m, for x, y < m
+fn sub_mod<const N: usize>(x: &mut [Limb; N], y: &[Limb; N], m: &[Limb; N]) {
+    if sub_assign(x, y) {
+        add_assign(x, m);
+    }
+}
+
+/// Inverse of a modulo m with the binary extended Euclidean algorithm, in variable time
+///
+/// a and m are little endian limbs, m being odd and a less than m. Returns None if a
+/// is not invertible (0, or not coprime with m). The number of iterations and the
+/// branches depend on the values, so this is only for public values.
+pub fn inverse_vartime<const N: usize>(a: &[Limb; N], m: &[Limb; N]) -> Option<[Limb; N]> {
+    assert!(!is_even(m) && !ge(a, m));
+    // invariants: x1.a = u and x2.a = v mod m
+    let (mut u, mut v) = (*a, *m);
+    let mut x1 = [0; N];
+    x1[0] = 1;
+    let mut x2 = [0; N];
+    loop {
+        if is_small(&u, 0) {
+            return None;
+        }
+        if is_small(&u, 1) {
+            return Some(x1);
+        }
+        if is_small(&v, 1) {
+            return Some(x2);
+        }
+        while is_even(&u) {
+            shr1(&mut u, false);
+            half_mod(&mut x1, m);
+        }
+        while is_even(&v) {
+            shr1(&mut v, false);
+            half_mod(&mut x2, m);
+        }
+        if ge(&u, &v) {
+            sub_assign(&mut u, &v);
+            sub_mod(&mut x1, &x2, m);
+        } else {
+            sub_assign(&mut v, &u);
+            sub_mod(&mut x2, &x1, m);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,2 +1,4 @@
+pub mod barrett;
 pub mod ct;
+pub mod ct_util;
 pub mod limbs;
@@ -1,3 +1,4 @@
 //! Constant related to known elliptic curves
 
 pub mod sec2;
+pub mod sm2;
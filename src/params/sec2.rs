@@ -32,6 +32,18 @@ pub mod p112r1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 2] = [0x0000db7c2abf62e3, 0x5e7628dfac6561c5];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 28] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2a, 0x96, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xea, 0xb2, 0xea, 0x46, 0xb3, 0x44, 0x7e, 0x7b, 0xc5, 0x4d, 0x7b,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 4] = [
+        0x0000000000000000,
+        0x0000000000012a96,
+        0xffffffffffeab2ea,
+        0x46b3447e7bc54d7b,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 14] = [
         0xdb, 0x7c, 0x2a, 0xbf, 0x62, 0xe3, 0x5e, 0x66, 0x80, 0x76, 0xbe, 0xad, 0x20, 0x88,
@@ -96,6 +108,18 @@ pub mod p112r2 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 2] = [0x000036df0aafd8b8, 0xd7597ca10520d04b];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 28] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xaa, 0x5c, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0x74, 0x14, 0x02, 0x57, 0x5b, 0xcf, 0xc3, 0xcd, 0xbe, 0xe6, 0x24,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 4] = [
+        0x0000000000000000,
+        0x000000000004aa5c,
+        0x0000000005741402,
+        0x575bcfc3cdbee624,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 14] = [
         0x61, 0x27, 0xc2, 0x4c, 0x05, 0xf3, 0x8a, 0x0a, 0xaa, 0xf6, 0x5c, 0x0e, 0xf0, 0x2c,
@@ -164,6 +188,19 @@ pub mod p128r1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 2] = [0xfffffffe00000000, 0x75a30d1b9038a115];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 32] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x8a, 0x5c, 0xf2, 0xea, 0x99, 0x3b,
+        0x2a, 0x87,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 4] = [
+        0x0000000000000000,
+        0x0000000000000001,
+        0x0000000200000003,
+        0x8a5cf2ea993b2a87,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 16] = [
         0xff, 0xff, 0xff, 0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -237,6 +274,19 @@ pub mod p128r2 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 2] = [0x3fffffff7fffffff, 0xbe0024720613b5a3];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 32] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x14, 0x1f, 0xfd, 0xb9, 0x10, 0x1e, 0xbb,
+        0x89, 0xc4,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 4] = [
+        0x0000000000000000,
+        0x0000000000000004,
+        0x0000000800000014,
+        0x1ffdb9101ebb89c4,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 16] = [
         0xd6, 0x03, 0x19, 0x98, 0xd1, 0xb3, 0xbb, 0xfe, 0xbf, 0x59, 0xcc, 0x9b, 0xbf, 0xf9, 0xae,
@@ -311,6 +361,21 @@ pub mod p160k1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 3] = [0x0000000100000000, 0x000000000001b8fa, 0x16dfab9aca16b6b3];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 42] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x47, 0x05, 0xe9, 0x20, 0x54, 0x65,
+        0x35, 0xe9, 0x49, 0x4f, 0xf7, 0x9c, 0xa2, 0xf1, 0x8c, 0xca, 0x75, 0xeb,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 6] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ffffffff,
+        0xfffffffffffe4705,
+        0xe920546535e9494f,
+        0xf79ca2f18cca75eb,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 20] = [
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -385,6 +450,21 @@ pub mod p160r1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 3] = [0x0000000100000000, 0x000000000001f4c8, 0xf927aed3ca752257];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 42] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x0b, 0x37, 0x06, 0xd8, 0x51, 0x2c,
+        0x35, 0x8a, 0xdd, 0xac, 0xd3, 0xa1, 0xab, 0x09, 0x43, 0x6a, 0xb2, 0x04,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 6] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ffffffff,
+        0xfffffffffffe0b37,
+        0x06d8512c358addac,
+        0xd3a1ab09436ab204,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 20] = [
         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -459,6 +539,21 @@ pub mod p160r2 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 3] = [0x0000000100000000, 0x000000000000351e, 0xe786a818f3a1a16b];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 42] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xca, 0xe1, 0x18, 0x79, 0x57, 0xe7,
+        0x0c, 0x5e, 0x5e, 0x95, 0x0b, 0x05, 0xcf, 0x98, 0xd6, 0x8e, 0x04, 0xeb,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 6] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ffffffff,
+        0xffffffffffffcae1,
+        0x187957e70c5e5e95,
+        0x0b05cf98d68e04eb,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 20] = [
         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -535,6 +630,22 @@ pub mod p192k1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 3] = [0xffffffffffffffff, 0xfffffffe26f2fc17, 0x0f69466a74defd8d];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 48] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xd9, 0x0d, 0x03, 0xe8, 0xf0, 0x96, 0xb9, 0x95, 0x8b,
+        0x21, 0x02, 0x76,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 6] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x00000001d90d03e8,
+        0xf096b9958b210276,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 24] = [
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -611,6 +722,22 @@ pub mod p192r1 {
     ];
     /// Order of point on the curve (BE 64-bits limbs representation)
     pub const ORDER_LIMBS: [u64; 3] = [0xffffffffffffffff, 0xffffffff99def836, 0x146bc9b1b4d22831];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 48] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x66, 0x21, 0x07, 0xc9, 0xeb, 0x94, 0x36, 0x4e, 0x4b,
+        0x2d, 0xd7, 0xcf,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 6] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x00000000662107c9,
+        0xeb94364e4b2dd7cf,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 24] = [
         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -703,6 +830,24 @@ pub mod p224k1 {
         0x0001dce8d2ec6184,
         0xcaf0a971769fb1f7,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 58] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x23, 0x17, 0x2d, 0x13, 0x9e, 0x7b, 0x35, 0x0f, 0x56,
+        0x8e, 0x89, 0x60, 0x4e, 0x0c, 0x78, 0x72, 0xa4, 0x1e, 0x04, 0xdf, 0x3d, 0xa0,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 8] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ffffffff,
+        0xffffffffffffffff,
+        0xfffe23172d139e7b,
+        0x350f568e89604e0c,
+        0x7872a41e04df3da0,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 28] = [
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -820,6 +965,24 @@ pub mod p224r1 {
         0xffff16a2e0b8f03e,
         0x13dd29455c5c2a3d,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 56] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xe9, 0x5d, 0x1f, 0x47, 0x0f, 0xc1, 0xec, 0x22, 0xd6, 0xba, 0xa3,
+        0xa3, 0xd5, 0xc3, 0xd4, 0xba, 0xa4, 0xcf, 0x18, 0x22, 0xbc, 0x47,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 8] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000100000000,
+        0x0000000000000000,
+        0x0000e95d1f470fc1,
+        0xec22d6baa3a3d5c3,
+        0xd4baa4cf1822bc47,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 28] = [
         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -942,6 +1105,25 @@ pub mod p256k1 {
         0xbaaedce6af48a03b,
         0xbfd25e8cd0364141,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 64] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x45, 0x51, 0x23, 0x19, 0x50, 0xb7, 0x5f, 0xc4, 0x40, 0x2d, 0xa1, 0x73,
+        0x2f, 0xc9, 0xbe, 0xc0,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 8] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x4551231950b75fc4,
+        0x402da1732fc9bec0,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 32] = [
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -1069,6 +1251,25 @@ pub mod p256r1 {
         0xbce6faada7179e84,
         0xf3b9cac2fc632551,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 64] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff,
+        0xff, 0xff, 0xff, 0x43, 0x19, 0x05, 0x52, 0xdf, 0x1a, 0x6c, 0x21, 0x01, 0x2f, 0xfd, 0x85,
+        0xee, 0xdf, 0x9b, 0xfe,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 8] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x00000000ffffffff,
+        0xfffffffeffffffff,
+        0x43190552df1a6c21,
+        0x012ffd85eedf9bfe,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 32] = [
         0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -1211,6 +1412,31 @@ pub mod p384r1 {
         0x581a0db248b0a77a,
         0xecec196accc52973,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 96] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x9c, 0xb2,
+        0x7e, 0x0b, 0xc8, 0xd2, 0x20, 0xa7, 0xe5, 0xf2, 0x4d, 0xb7, 0x4f, 0x58, 0x85, 0x13, 0x13,
+        0xe6, 0x95, 0x33, 0x3a, 0xd6, 0x8d,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 12] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x389cb27e0bc8d220,
+        0xa7e5f24db74f5885,
+        0x1313e695333ad68d,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 48] = [
         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -1387,6 +1613,39 @@ pub mod p521r1 {
         0x3bb5c9b8899c47ae,
         0xbb6fb71e91386409,
     ];
+    /// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+    pub const ORDER_MICRO_BYTES: [u8; 132] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x6b, 0x9e, 0x5e, 0x1f,
+        0x10, 0x34, 0x1a, 0x65, 0x20, 0x0c, 0xff, 0xad, 0xc2, 0x3d, 0x96, 0x8b, 0xf1, 0x12, 0x8d,
+        0x91, 0xdd, 0x98, 0xee, 0x14, 0x51, 0x24, 0x12, 0x38, 0x5b, 0xb1, 0xe6, 0xfd, 0xc4, 0x08,
+        0xf5, 0x01, 0xc8, 0xd1, 0xcd, 0x2d, 0xad, 0x1d, 0x7f, 0x46, 0x22, 0x1c,
+    ];
+    /// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+    pub const ORDER_MICRO_LIMBS: [u64; 18] = [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00016b9e5e1f1034,
+        0x1a65200cffadc23d,
+        0x968bf1128d91dd98,
+        0xee14512412385bb1,
+        0xe6fdc408f501c8d1,
+        0xcd2dad1d7f46221c,
+    ];
     /// A factor in the short weirstrass curve (BE bytes representation)
     pub const A_BYTES: [u8; 66] = [
         0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
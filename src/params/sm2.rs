@@ -0,0 +1,136 @@
+//! SM2 : Public key cryptographic algorithm based on elliptic curves (GB/T 32918)
+//!
+//! Parameters of the recommended curve sm2p256v1 (GB/T 32918.5)
+
+/// Finite field of prime order (BE bytes representation)
+pub const P_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+/// Finite field of prime order (BE 64-bits limbs representation)
+pub const P_LIMBS: [u64; 4] = [
+    0xfffffffeffffffff,
+    0xffffffffffffffff,
+    0xffffffff00000000,
+    0xffffffffffffffff,
+];
+/// P-2 (BE bytes representation)
+pub const PM2_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfd,
+];
+/// P-2 (BE 64-bits limbs representation)
+pub const PM2_LIMBS: [u64; 4] = [
+    0xfffffffeffffffff,
+    0xffffffffffffffff,
+    0xffffffff00000000,
+    0xfffffffffffffffd,
+];
+/// pre-computed µ barrett modular reduction (BE bytes representation)
+pub const MICRO_BYTES: [u8; 64] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03,
+];
+/// pre-computed µ barrett modular reduction (BE 64-bits limbs representation)
+pub const MICRO_LIMBS: [u64; 8] = [
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000001,
+    0x0000000100000001,
+    0x0000000100000001,
+    0x0000000200000002,
+    0x0000000200000003,
+];
+/// Order of point on the curve (BE bytes representation)
+pub const ORDER_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x72, 0x03, 0xdf, 0x6b, 0x21, 0xc6, 0x05, 0x2b, 0x53, 0xbb, 0xf4, 0x09, 0x39, 0xd5, 0x41, 0x23,
+];
+/// Order of point on the curve (BE 64-bits limbs representation)
+pub const ORDER_LIMBS: [u64; 4] = [
+    0xfffffffeffffffff,
+    0xffffffffffffffff,
+    0x7203df6b21c6052b,
+    0x53bbf40939d54123,
+];
+/// pre-computed µ barrett modular reduction of the order (BE bytes representation)
+pub const ORDER_MICRO_BYTES: [u8; 64] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+    0x8d, 0xfc, 0x20, 0x96, 0xfa, 0x32, 0x3c, 0x01, 0x12, 0xac, 0x63, 0x61, 0xf1, 0x51, 0x49, 0xa0,
+];
+/// pre-computed µ barrett modular reduction of the order (BE 64-bits limbs representation)
+pub const ORDER_MICRO_LIMBS: [u64; 8] = [
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000001,
+    0x0000000100000001,
+    0x0000000100000001,
+    0x8dfc2096fa323c01,
+    0x12ac6361f15149a0,
+];
+/// A factor in the short weirstrass curve (BE bytes representation)
+pub const A_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+];
+/// A factor in the short weirstrass curve (BE 64-bits limbs representation)
+pub const A_LIMBS: [u64; 4] = [
+    0xfffffffeffffffff,
+    0xffffffffffffffff,
+    0xffffffff00000000,
+    0xfffffffffffffffc,
+];
+/// B factor in the short weirstrass curve (BE bytes representation)
+pub const B_BYTES: [u8; 32] = [
+    0x28, 0xe9, 0xfa, 0x9e, 0x9d, 0x9f, 0x5e, 0x34, 0x4d, 0x5a, 0x9e, 0x4b, 0xcf, 0x65, 0x09, 0xa7,
+    0xf3, 0x97, 0x89, 0xf5, 0x15, 0xab, 0x8f, 0x92, 0xdd, 0xbc, 0xbd, 0x41, 0x4d, 0x94, 0x0e, 0x93,
+];
+/// B factor in the short weirstrass curve (BE 64-bits limbs representation)
+pub const B_LIMBS: [u64; 4] = [
+    0x28e9fa9e9d9f5e34,
+    0x4d5a9e4bcf6509a7,
+    0xf39789f515ab8f92,
+    0xddbcbd414d940e93,
+];
+/// B*3 factor in the short weirstrass curve (BE bytes representation)
+pub const B3_BYTES: [u8; 32] = [
+    0x7a, 0xbd, 0xef, 0xdb, 0xd8, 0xde, 0x1a, 0x9c, 0xe8, 0x0f, 0xda, 0xe3, 0x6e, 0x2f, 0x1c, 0xf7,
+    0xda, 0xc6, 0x9d, 0xdf, 0x41, 0x02, 0xae, 0xb8, 0x99, 0x36, 0x37, 0xc3, 0xe8, 0xbc, 0x2b, 0xb9,
+];
+/// B*3 factor in the short weirstrass curve (BE 64-bits limbs representation)
+pub const B3_LIMBS: [u64; 4] = [
+    0x7abdefdbd8de1a9c,
+    0xe80fdae36e2f1cf7,
+    0xdac69ddf4102aeb8,
+    0x993637c3e8bc2bb9,
+];
+/// X-Coordinate of the generator point of the curve (BE bytes representation)
+pub const GX_BYTES: [u8; 32] = [
+    0x32, 0xc4, 0xae, 0x2c, 0x1f, 0x19, 0x81, 0x19, 0x5f, 0x99, 0x04, 0x46, 0x6a, 0x39, 0xc9, 0x94,
+    0x8f, 0xe3, 0x0b, 0xbf, 0xf2, 0x66, 0x0b, 0xe1, 0x71, 0x5a, 0x45, 0x89, 0x33, 0x4c, 0x74, 0xc7,
+];
+/// X-Coordinate of the generator point of the curve (BE 64-bits limbs representation)
+pub const GX_LIMBS: [u64; 4] = [
+    0x32c4ae2c1f198119,
+    0x5f9904466a39c994,
+    0x8fe30bbff2660be1,
+    0x715a4589334c74c7,
+];
+/// Y-Coordinate of the generator point of the curve (BE bytes representation)
+pub const GY_BYTES: [u8; 32] = [
+    0xbc, 0x37, 0x36, 0xa2, 0xf4, 0xf6, 0x77, 0x9c, 0x59, 0xbd, 0xce, 0xe3, 0x6b, 0x69, 0x21, 0x53,
+    0xd0, 0xa9, 0x87, 0x7c, 0xc6, 0x2a, 0x47, 0x40, 0x02, 0xdf, 0x32, 0xe5, 0x21, 0x39, 0xf0, 0xa0,
+];
+/// Y-Coordinate of the generator point of the curve (BE 64-bits limbs representation)
+pub const GY_LIMBS: [u64; 4] = [
+    0xbc3736a2f4f6779c,
+    0x59bdcee36b692153,
+    0xd0a9877cc62a4740,
+    0x02df32e52139f0a0,
+];
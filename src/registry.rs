@@ -0,0 +1,441 @@
+//! Runtime curve registry, for the protocols negotiating the curve
+//!
+//! `CurveId` identifies the SEC2 curves and the SM2 curve by their IANA TLS group id,
+//! their name and their OID, whether their feature is enabled or not. The `AnyPublicKey`,
+//! `AnySecretKey` and `AnyPoint` enums dispatch the decompression, the serialization,
+//! the scalar multiplication and ECDH to the curves compiled in, without allocating
+//! a trait object:
+//!
+//! ```
+//! # #[cfg(feature = "p256r1")] {
+//! use eccoxide::registry::{AnySecretKey, CurveId};
+//!
+//! let curve = CurveId::from_iana(23).unwrap();
+//! assert_eq!(curve, CurveId::Secp256r1);
+//! let alice = AnySecretKey::from_bytes(curve, &[1; 32]).unwrap();
+//! let bob = AnySecretKey::from_bytes(curve, &[2; 32]).unwrap();
+//! assert_eq!(
+//!     alice.diffie_hellman(&bob.public_key()),
+//!     bob.diffie_hellman(&alice.public_key())
+//! );
+//! # }
+//! ```
+//!
+//! Using an id whose feature is disabled returns `RegistryError::DisabledCurve`.
+
+use std::fmt;
+
+/// Reason for a registry operation to fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The IANA id, name or OID is not the one of a known curve
+    UnknownCurve,
+    /// The curve is known, but its feature is not enabled
+    DisabledCurve(CurveId),
+    /// The operation mixes keys or points of different curves
+    CurveMismatch,
+    /// The encoding of the point is invalid, or the point is not on the curve
+    InvalidEncoding,
+    /// The secret scalar is zero, not less than the group order, or of the wrong size
+    InvalidScalar,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownCurve => f.write_str("unknown curve"),
+            RegistryError::DisabledCurve(c) => write!(f, "curve {} not enabled", c),
+            RegistryError::CurveMismatch => f.write_str("curve mismatch"),
+            RegistryError::InvalidEncoding => f.write_str("invalid point encoding"),
+            RegistryError::InvalidScalar => f.write_str("invalid secret scalar"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// Identifier of the curves with a TLS group id
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurveId {
+    Secp192k1,
+    Secp192r1,
+    Secp224k1,
+    Secp224r1,
+    Secp256k1,
+    Secp256r1,
+    Secp384r1,
+    Secp521r1,
+    Sm2p256v1,
+}
+
+// (id, IANA TLS group id, name, OID, enabled)
+const CURVES: [(CurveId, u16, &str, &str, bool); 9] = [
+    (
+        CurveId::Secp192k1,
+        18,
+        "secp192k1",
+        "1.3.132.0.31",
+        cfg!(feature = "p192k1"),
+    ),
+    (
+        CurveId::Secp192r1,
+        19,
+        "secp192r1",
+        "1.2.840.10045.3.1.1",
+        cfg!(feature = "p192r1"),
+    ),
+    (
+        CurveId::Secp224k1,
+        20,
+        "secp224k1",
+        "1.3.132.0.32",
+        cfg!(feature = "p224k1"),
+    ),
+    (
+        CurveId::Secp224r1,
+        21,
+        "secp224r1",
+        "1.3.132.0.33",
+        cfg!(feature = "p224r1"),
+    ),
+    (
+        CurveId::Secp256k1,
+        22,
+        "secp256k1",
+        "1.3.132.0.10",
+        cfg!(feature = "p256k1"),
+    ),
+    (
+        CurveId::Secp256r1,
+        23,
+        "secp256r1",
+        "1.2.840.10045.3.1.7",
+        cfg!(feature = "p256r1"),
+    ),
+    (
+        CurveId::Secp384r1,
+        24,
+        "secp384r1",
+        "1.3.132.0.34",
+        cfg!(feature = "p384r1"),
+    ),
+    (
+        CurveId::Secp521r1,
+        25,
+        "secp521r1",
+        "1.3.132.0.35",
+        cfg!(feature = "p521r1"),
+    ),
+    (
+        CurveId::Sm2p256v1,
+        41,
+        "sm2p256v1",
+        "1.2.156.10197.1.301",
+        cfg!(feature = "sm2"),
+    ),
+];
+
+impl CurveId {
+    /// All the known curves, enabled or not
+    pub const ALL: [CurveId; 9] = [
+        CurveId::Secp192k1,
+        CurveId::Secp192r1,
+        CurveId::Secp224k1,
+        CurveId::Secp224r1,
+        CurveId::Secp256k1,
+        CurveId::Secp256r1,
+        CurveId::Secp384r1,
+        CurveId::Secp521r1,
+        CurveId::Sm2p256v1,
+    ];
+
+    fn entry(self) -> &'static (CurveId, u16, &'static str, &'static str, bool) {
+        &CURVES[self as usize]
+    }
+
+    /// Find a curve from its IANA TLS supported group id (e.g. 23 for secp256r1)
+    ///
+    /// The ids of the other groups (e.g. 29 for x25519, or the finite field groups)
+    /// are unknown curves
+    pub fn from_iana(id: u16) -> Result<Self, RegistryError> {
+        CURVES
+            .iter()
+            .find(|c| c.1 == id)
+            .map(|c| c.0)
+            .ok_or(RegistryError::UnknownCurve)
+    }
+
+    /// Find a curve from its name (e.g. "secp256r1" or "sm2p256v1")
+    pub fn from_name(name: &str) -> Result<Self, RegistryError> {
+        CURVES
+            .iter()
+            .find(|c| c.2 == name)
+            .map(|c| c.0)
+            .ok_or(RegistryError::UnknownCurve)
+    }
+
+    /// Find a curve from its OID in dotted form (e.g. "1.2.840.10045.3.1.7")
+    pub fn from_oid(oid: &str) -> Result<Self, RegistryError> {
+        CURVES
+            .iter()
+            .find(|c| c.3 == oid)
+            .map(|c| c.0)
+            .ok_or(RegistryError::UnknownCurve)
+    }
+
+    /// IANA TLS supported group id
+    pub fn iana_id(self) -> u16 {
+        self.entry().1
+    }
+
+    /// Name of the curve (the SEC name for the SEC2 curves)
+    pub fn name(self) -> &'static str {
+        self.entry().2
+    }
+
+    /// OID in dotted form
+    pub fn oid(self) -> &'static str {
+        self.entry().3
+    }
+
+    /// Check if the feature of the curve is enabled
+    pub fn is_enabled(self) -> bool {
+        self.entry().4
+    }
+
+    /// Iterate over the curves whose feature is enabled
+    pub fn enabled() -> impl Iterator<Item = CurveId> {
+        CurveId::ALL.iter().copied().filter(|c| c.is_enabled())
+    }
+}
+
+impl fmt::Display for CurveId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+macro_rules! registry_define {
+    ($(($feature:literal, $($m:ident)::+, $variant:ident)),* $(,)?) => {
+        /// Public key of any of the enabled curves
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum AnyPublicKey {
+            $(
+                #[cfg(feature = $feature)]
+                $variant(crate::curve::$($m)::+::PublicKey),
+            )*
+        }
+
+        /// Secret key of any of the enabled curves
+        ///
+        /// The Debug output doesn't show the secret scalar
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum AnySecretKey {
+            $(
+                #[cfg(feature = $feature)]
+                $variant(crate::curve::$($m)::+::SecretKey),
+            )*
+        }
+
+        /// Point (including the point at infinity) of any of the enabled curves
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum AnyPoint {
+            $(
+                #[cfg(feature = $feature)]
+                $variant(crate::curve::$($m)::+::Point),
+            )*
+        }
+
+        // the arguments are unused when no curve is enabled
+        #[allow(unused_variables)]
+        impl AnyPublicKey {
+            /// Decode a public key from its SEC1 encoding, compressed or uncompressed
+            pub fn from_sec1_bytes(curve: CurveId, bytes: &[u8]) -> Result<Self, RegistryError> {
+                match curve {
+                    $(
+                        #[cfg(feature = $feature)]
+                        CurveId::$variant => crate::curve::$($m)::+::PublicKey::from_sec1_bytes(bytes)
+                            .map(AnyPublicKey::$variant)
+                            .ok_or(RegistryError::InvalidEncoding),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(RegistryError::DisabledCurve(curve)),
+                }
+            }
+
+            /// Get the curve of the public key
+            pub fn curve(&self) -> CurveId {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPublicKey::$variant(_) => CurveId::$variant,
+                    )*
+                }
+            }
+
+            /// Output the SEC1 encoding of the public key, compressed or uncompressed
+            pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPublicKey::$variant(ref pk) => pk.to_sec1_bytes(compressed),
+                    )*
+                }
+            }
+        }
+
+        // the arguments are unused when no curve is enabled
+        #[allow(unused_variables)]
+        impl AnySecretKey {
+            /// Create a secret key from the big endian bytes of the scalar
+            ///
+            /// The bytes need to be of the size of the curve scalars
+            pub fn from_bytes(curve: CurveId, bytes: &[u8]) -> Result<Self, RegistryError> {
+                match curve {
+                    $(
+                        #[cfg(feature = $feature)]
+                        CurveId::$variant => crate::curve::$($m)::+::SecretKey::from_slice(bytes)
+                            .map(AnySecretKey::$variant)
+                            .ok_or(RegistryError::InvalidScalar),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(RegistryError::DisabledCurve(curve)),
+                }
+            }
+
+            /// Output the big endian bytes of the scalar, of the size of the curve scalars
+            pub fn to_bytes(&self) -> Vec<u8> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnySecretKey::$variant(ref sk) => sk.to_bytes().to_vec(),
+                    )*
+                }
+            }
+
+            /// Get the curve of the secret key
+            pub fn curve(&self) -> CurveId {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnySecretKey::$variant(_) => CurveId::$variant,
+                    )*
+                }
+            }
+
+            /// Get the public key associated with this secret key
+            pub fn public_key(&self) -> AnyPublicKey {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnySecretKey::$variant(ref sk) => AnyPublicKey::$variant(sk.public_key()),
+                    )*
+                }
+            }
+
+            /// ECDH shared secret with the peer public key, which is the big endian
+            /// X coordinate of the shared point
+            pub fn diffie_hellman(&self, peer: &AnyPublicKey) -> Result<Vec<u8>, RegistryError> {
+                match (self, peer) {
+                    $(
+                        #[cfg(feature = $feature)]
+                        (AnySecretKey::$variant(sk), AnyPublicKey::$variant(pk)) => {
+                            let p = crate::curve::$($m)::+::Point::from(pk.as_point());
+                            let shared = p
+                                .mul_to_affine(sk.as_scalar())
+                                .expect("non zero multiple of a point of prime order");
+                            Ok(shared.to_coordinate().0.to_bytes().to_vec())
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(RegistryError::CurveMismatch),
+                }
+            }
+        }
+
+        // the arguments are unused when no curve is enabled
+        #[allow(unused_variables)]
+        impl AnyPoint {
+            /// Curve generator point
+            pub fn generator(curve: CurveId) -> Result<Self, RegistryError> {
+                match curve {
+                    $(
+                        #[cfg(feature = $feature)]
+                        CurveId::$variant => Ok(AnyPoint::$variant(crate::curve::$($m)::+::Point::generator())),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(RegistryError::DisabledCurve(curve)),
+                }
+            }
+
+            /// Get the point of a public key
+            pub fn from_public_key(pk: &AnyPublicKey) -> Self {
+                match *pk {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPublicKey::$variant(ref pk) => AnyPoint::$variant(pk.as_point().into()),
+                    )*
+                }
+            }
+
+            /// Get the curve of the point
+            pub fn curve(&self) -> CurveId {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPoint::$variant(_) => CurveId::$variant,
+                    )*
+                }
+            }
+
+            /// Scalar multiplication by a big endian integer of any size
+            ///
+            /// Same length checks as the `scale_bytes` of the curves, relative to the
+            /// scalar size of the curve of the point
+            pub fn scale_bytes(&self, n: &[u8]) -> Self {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPoint::$variant(ref p) => AnyPoint::$variant(p.scale_bytes(n)),
+                    )*
+                }
+            }
+
+            /// Point addition, which fails for points of different curves
+            pub fn add(&self, other: &AnyPoint) -> Result<Self, RegistryError> {
+                match (self, other) {
+                    $(
+                        #[cfg(feature = $feature)]
+                        (AnyPoint::$variant(a), AnyPoint::$variant(b)) => Ok(AnyPoint::$variant(a + b)),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(RegistryError::CurveMismatch),
+                }
+            }
+
+            /// Convert the point to a public key, or None if it is the point at infinity
+            pub fn to_public_key(&self) -> Option<AnyPublicKey> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyPoint::$variant(ref p) => p
+                            .to_affine()
+                            .map(|p| AnyPublicKey::$variant(p.into())),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+registry_define!(
+    ("p192k1", sec2::p192k1, Secp192k1),
+    ("p192r1", sec2::p192r1, Secp192r1),
+    ("p224k1", sec2::p224k1, Secp224k1),
+    ("p224r1", sec2::p224r1, Secp224r1),
+    ("p256k1", sec2::p256k1, Secp256k1),
+    ("p256r1", sec2::p256r1, Secp256r1),
+    ("p384r1", sec2::p384r1, Secp384r1),
+    ("p521r1", sec2::p521r1, Secp521r1),
+    ("sm2", sm2, Sm2p256v1),
+);
@@ -0,0 +1,693 @@
+//! Known answer self tests of the compiled in curves
+//!
+//! `self_test` is meant to be run at startup, e.g. for FIPS 140 style power-on
+//! self tests. For each curve enabled, it checks that:
+//!
+//! * the generator is on the curve
+//! * the generator multiplied by the group order is the point at infinity
+//! * a scalar multiplication of the generator gives the expected point
+//! * the inverse and the square root of a field element are the expected ones
+//!
+//! The expected values are embedded below, and were computed independently of
+//! this crate from the SEC2 parameters.
+
+use std::fmt;
+
+/// Check of the self test that failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestCheck {
+    /// The generator doesn't satisfy the curve equation
+    GeneratorOnCurve,
+    /// The generator multiplied by the group order is not the point at infinity
+    GeneratorOrder,
+    /// The scalar multiplication of the generator doesn't give the expected point
+    ScalarMul,
+    /// The inverse of the field element is not the expected one
+    FieldInverse,
+    /// The square root of the square of the field element is not the element or its negation
+    FieldSqrt,
+}
+
+/// This is the error returned when a self test fails, with the curve and the check that failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelfTestError {
+    /// Name of the curve (e.g. "p256r1")
+    pub curve: &'static str,
+    pub check: SelfTestCheck,
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "self test failed on {}: {:?}", self.curve, self.check)
+    }
+}
+
+impl std::error::Error for SelfTestError {}
+
+/// Run the known answer tests of all the curves enabled
+///
+/// Stop at the first check failing, and return which one it is.
+pub fn self_test() -> Result<(), SelfTestError> {
+    run(CURVES)
+}
+
+// expected values for a curve, in big endian (unused when no curve is enabled)
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+struct CurveKat {
+    scalar: &'static [u8],
+    // scalar * G
+    point_x: &'static [u8],
+    point_y: &'static [u8],
+    element: &'static [u8],
+    element_inverse: &'static [u8],
+    // the square roots of element_square are element and -element
+    element_square: &'static [u8],
+}
+
+type Check = fn(&CurveKat) -> Result<(), SelfTestCheck>;
+
+// name of the curve, its checks and their expected values
+type CurveTest = (&'static str, Check, CurveKat);
+
+fn run(curves: &[CurveTest]) -> Result<(), SelfTestError> {
+    for (curve, check, kat) in curves.iter() {
+        check(kat).map_err(|check| SelfTestError { curve, check })?;
+    }
+    Ok(())
+}
+
+// the differences between the backends are the fallible inverse and the type of the sqrt result
+#[allow(unused_macros)]
+macro_rules! curve_check {
+    (fiat, $curve:ident) => {
+        curve_check!(
+            $curve,
+            |x| if x.is_zero() { None } else { Some(x.inverse()) },
+            |x| x.sqrt().into_option()
+        );
+    };
+    (bigint, $curve:ident) => {
+        curve_check!($curve, |x| x.inverse(), |x| x.sqrt());
+    };
+    ($curve:ident, $inverse:expr, $sqrt:expr) => {
+        mod $curve {
+            use super::{CurveKat, SelfTestCheck};
+            use crate::curve::sec2::$curve::{FieldElement, Point, PointAffine, Scalar};
+
+            pub fn check(kat: &CurveKat) -> Result<(), SelfTestCheck> {
+                let inverse: fn(&FieldElement) -> Option<FieldElement> = $inverse;
+                let sqrt: fn(&FieldElement) -> Option<FieldElement> = $sqrt;
+
+                let generator = PointAffine::generator();
+                let (gx, gy) = generator.to_coordinate();
+                if PointAffine::from_coordinate(gx, gy).is_none() {
+                    return Err(SelfTestCheck::GeneratorOnCurve);
+                }
+
+                // order.G as (order-1).G + G
+                let g = Point::generator();
+                if &g * &-Scalar::one() + &g != Point::infinity() {
+                    return Err(SelfTestCheck::GeneratorOrder);
+                }
+
+                let k = Scalar::from_slice(kat.scalar).ok_or(SelfTestCheck::ScalarMul)?;
+                let p = (&g * &k).to_affine().ok_or(SelfTestCheck::ScalarMul)?;
+                let (x, y) = p.to_coordinate();
+                if x.to_bytes()[..] != kat.point_x[..] || y.to_bytes()[..] != kat.point_y[..] {
+                    return Err(SelfTestCheck::ScalarMul);
+                }
+
+                let e = FieldElement::from_slice(kat.element).ok_or(SelfTestCheck::FieldInverse)?;
+                match inverse(&e) {
+                    Some(i) if i.to_bytes()[..] == kat.element_inverse[..] => {}
+                    _ => return Err(SelfTestCheck::FieldInverse),
+                }
+
+                let square =
+                    FieldElement::from_slice(kat.element_square).ok_or(SelfTestCheck::FieldSqrt)?;
+                match sqrt(&square) {
+                    Some(r) if r == e || r == -e.clone() => {}
+                    _ => return Err(SelfTestCheck::FieldSqrt),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p112r2")]
+curve_check!(bigint, p112r2);
+#[cfg(feature = "p128r1")]
+curve_check!(bigint, p128r1);
+#[cfg(feature = "p128r2")]
+curve_check!(bigint, p128r2);
+#[cfg(feature = "p160k1")]
+curve_check!(bigint, p160k1);
+#[cfg(feature = "p160r1")]
+curve_check!(bigint, p160r1);
+#[cfg(feature = "p160r2")]
+curve_check!(bigint, p160r2);
+#[cfg(feature = "p192k1")]
+curve_check!(fiat, p192k1);
+#[cfg(feature = "p192r1")]
+curve_check!(fiat, p192r1);
+#[cfg(feature = "p224k1")]
+curve_check!(fiat, p224k1);
+#[cfg(feature = "p224r1")]
+curve_check!(fiat, p224r1);
+#[cfg(feature = "p256k1")]
+curve_check!(fiat, p256k1);
+#[cfg(feature = "p256r1")]
+curve_check!(fiat, p256r1);
+#[cfg(feature = "p384r1")]
+curve_check!(fiat, p384r1);
+#[cfg(feature = "p521r1")]
+curve_check!(fiat, p521r1);
+
+const CURVES: &[CurveTest] = &[
+    #[cfg(feature = "p112r2")]
+    ("p112r2", p112r2::check, P112R2),
+    #[cfg(feature = "p128r1")]
+    ("p128r1", p128r1::check, P128R1),
+    #[cfg(feature = "p128r2")]
+    ("p128r2", p128r2::check, P128R2),
+    #[cfg(feature = "p160k1")]
+    ("p160k1", p160k1::check, P160K1),
+    #[cfg(feature = "p160r1")]
+    ("p160r1", p160r1::check, P160R1),
+    #[cfg(feature = "p160r2")]
+    ("p160r2", p160r2::check, P160R2),
+    #[cfg(feature = "p192k1")]
+    ("p192k1", p192k1::check, P192K1),
+    #[cfg(feature = "p192r1")]
+    ("p192r1", p192r1::check, P192R1),
+    #[cfg(feature = "p224k1")]
+    ("p224k1", p224k1::check, P224K1),
+    #[cfg(feature = "p224r1")]
+    ("p224r1", p224r1::check, P224R1),
+    #[cfg(feature = "p256k1")]
+    ("p256k1", p256k1::check, P256K1),
+    #[cfg(feature = "p256r1")]
+    ("p256r1", p256r1::check, P256R1),
+    #[cfg(feature = "p384r1")]
+    ("p384r1", p384r1::check, P384R1),
+    #[cfg(feature = "p521r1")]
+    ("p521r1", p521r1::check, P521R1),
+];
+
+// expected values
+
+#[cfg(feature = "p112r2")]
+const P112R2: CurveKat = CurveKat {
+    scalar: &[
+        0x29, 0x68, 0xcb, 0x30, 0xe2, 0x5b, 0x52, 0xc4, 0x12, 0xb4, 0x83, 0x36, 0x82, 0x7a,
+    ],
+    point_x: &[
+        0x67, 0x9b, 0xc9, 0xd1, 0x20, 0xb3, 0x81, 0x1f, 0x8e, 0xce, 0xfe, 0x6f, 0xab, 0xce,
+    ],
+    point_y: &[
+        0xc3, 0x38, 0xd6, 0xa6, 0xc9, 0x88, 0xf2, 0xfa, 0x4d, 0xf1, 0x00, 0x24, 0x90, 0xc8,
+    ],
+    element: &[
+        0x71, 0xf8, 0x4e, 0x1b, 0xdb, 0xfb, 0xd2, 0x52, 0xb1, 0xca, 0xea, 0x36, 0x53, 0x4c,
+    ],
+    element_inverse: &[
+        0x8d, 0x04, 0xd8, 0x85, 0x00, 0xf1, 0xfb, 0x87, 0x0c, 0x61, 0x23, 0xa1, 0xa6, 0xb8,
+    ],
+    element_square: &[
+        0x08, 0x68, 0x7a, 0x53, 0x50, 0x5f, 0x86, 0x39, 0x95, 0x19, 0xb0, 0xef, 0xb7, 0xa4,
+    ],
+};
+
+#[cfg(feature = "p128r1")]
+const P128R1: CurveKat = CurveKat {
+    scalar: &[
+        0x62, 0xbc, 0x2e, 0xa0, 0xc6, 0x94, 0x27, 0x5a, 0x0e, 0x1d, 0x93, 0x18, 0xa9, 0xa7, 0x1c,
+        0xda,
+    ],
+    point_x: &[
+        0xc0, 0xd0, 0x5f, 0x86, 0x22, 0xd4, 0x03, 0x7b, 0x21, 0xb2, 0xc8, 0xb4, 0xe1, 0x0b, 0xd0,
+        0x22,
+    ],
+    point_y: &[
+        0x4e, 0x50, 0x1b, 0x29, 0x4f, 0xf1, 0x06, 0x32, 0xe5, 0x75, 0xab, 0xdc, 0x3c, 0x20, 0xdd,
+        0x0f,
+    ],
+    element: &[
+        0x8c, 0x3f, 0x09, 0x5d, 0x86, 0x96, 0x09, 0xf9, 0xca, 0x0f, 0x1e, 0x12, 0x4a, 0xcf, 0x71,
+        0x69,
+    ],
+    element_inverse: &[
+        0x6d, 0x60, 0x29, 0x16, 0xba, 0x1a, 0x5a, 0xea, 0xf9, 0x70, 0x41, 0xa5, 0x76, 0x3a, 0x65,
+        0x85,
+    ],
+    element_square: &[
+        0xbc, 0xb2, 0xa1, 0xbe, 0xd9, 0x8a, 0x80, 0x4e, 0xf4, 0xd1, 0x7e, 0xae, 0xa6, 0x69, 0x8b,
+        0x67,
+    ],
+};
+
+#[cfg(feature = "p128r2")]
+const P128R2: CurveKat = CurveKat {
+    scalar: &[
+        0x1f, 0x57, 0x32, 0x00, 0x8e, 0x10, 0x94, 0xca, 0x51, 0xa4, 0xf5, 0xb1, 0xfc, 0xbd, 0x88,
+        0x6b,
+    ],
+    point_x: &[
+        0x6e, 0x84, 0xf3, 0x76, 0x2f, 0x8f, 0xab, 0xc7, 0x0f, 0x30, 0x9c, 0x75, 0xd5, 0x1f, 0xa3,
+        0x5f,
+    ],
+    point_y: &[
+        0x52, 0xb5, 0xe8, 0x7d, 0x29, 0x56, 0x1d, 0x10, 0xe7, 0x75, 0x19, 0xcd, 0xc7, 0x1a, 0x3b,
+        0x1d,
+    ],
+    element: &[
+        0x10, 0x60, 0x6a, 0x5b, 0x6b, 0xc0, 0x67, 0x6f, 0x4c, 0x53, 0x9e, 0xf9, 0x08, 0xe0, 0xce,
+        0x7f,
+    ],
+    element_inverse: &[
+        0xda, 0xe5, 0xfd, 0x31, 0xd5, 0xeb, 0x2e, 0x92, 0xb3, 0xe5, 0x5e, 0x47, 0xcb, 0x97, 0xeb,
+        0xfb,
+    ],
+    element_square: &[
+        0x50, 0x21, 0xa5, 0x00, 0x37, 0x67, 0xf0, 0x0e, 0x6e, 0x51, 0x44, 0x04, 0xe5, 0x3b, 0xbf,
+        0x3d,
+    ],
+};
+
+#[cfg(feature = "p160k1")]
+const P160K1: CurveKat = CurveKat {
+    scalar: &[
+        0x00, 0x0b, 0x6a, 0xb2, 0x17, 0xab, 0x8d, 0x16, 0x44, 0xde, 0x76, 0x51, 0x40, 0xaa, 0x90,
+        0xcd, 0x88, 0xbb, 0x66, 0x56, 0x3a,
+    ],
+    point_x: &[
+        0xdb, 0x6d, 0x76, 0xc9, 0x94, 0x72, 0x0b, 0x59, 0x46, 0x5d, 0xa1, 0x71, 0xbf, 0x99, 0xd4,
+        0x3c, 0x48, 0xea, 0x13, 0xac,
+    ],
+    point_y: &[
+        0xbf, 0x37, 0x5d, 0xdf, 0x8f, 0xd4, 0xb3, 0x9c, 0x1f, 0xdd, 0x58, 0xdb, 0x53, 0xec, 0xb9,
+        0xb1, 0xe2, 0xef, 0xa2, 0x32,
+    ],
+    element: &[
+        0x89, 0x51, 0x65, 0xc1, 0xfc, 0x48, 0xaa, 0xb4, 0x83, 0xcc, 0x54, 0xbc, 0x93, 0xc1, 0x54,
+        0x77, 0x12, 0x7d, 0x13, 0x9e,
+    ],
+    element_inverse: &[
+        0x3f, 0xbe, 0x66, 0x9b, 0xe9, 0xe6, 0xc3, 0x9c, 0x91, 0x6f, 0xba, 0x86, 0x50, 0x4a, 0xe2,
+        0xa9, 0x45, 0x03, 0xff, 0x6a,
+    ],
+    element_square: &[
+        0x04, 0x96, 0xad, 0xf9, 0x96, 0xc5, 0x8e, 0xa6, 0x3d, 0x5c, 0x76, 0x01, 0x1f, 0x96, 0x0a,
+        0xe2, 0x05, 0x09, 0x3a, 0x7e,
+    ],
+};
+
+#[cfg(feature = "p160r1")]
+const P160R1: CurveKat = CurveKat {
+    scalar: &[
+        0x00, 0x07, 0x3e, 0xc2, 0x7d, 0xee, 0x85, 0xd9, 0x43, 0x99, 0x1d, 0x61, 0x2d, 0x0a, 0xac,
+        0x5e, 0x45, 0xa7, 0x27, 0x65, 0xc5,
+    ],
+    point_x: &[
+        0x3b, 0x2e, 0xde, 0x45, 0x11, 0x92, 0x11, 0x5d, 0xaa, 0x46, 0x9d, 0x28, 0x71, 0x12, 0xad,
+        0xe7, 0xa3, 0x11, 0x90, 0xae,
+    ],
+    point_y: &[
+        0x40, 0xe4, 0x34, 0xd0, 0x2d, 0x70, 0x6e, 0xdc, 0xaf, 0xc7, 0xd7, 0x96, 0x0c, 0xda, 0x91,
+        0x16, 0x33, 0xef, 0xb0, 0x8c,
+    ],
+    element: &[
+        0x6c, 0xf0, 0xb5, 0xe0, 0xa9, 0xdd, 0xe7, 0x6c, 0x98, 0x6c, 0xb6, 0x0e, 0xb5, 0x89, 0xa0,
+        0xd7, 0xe6, 0xcd, 0x9c, 0xbe,
+    ],
+    element_inverse: &[
+        0x4f, 0x23, 0x1a, 0xe0, 0xa5, 0x64, 0xee, 0x75, 0xd1, 0x0a, 0x57, 0x48, 0x79, 0x61, 0xf4,
+        0x60, 0x56, 0xcf, 0xd1, 0xcd,
+    ],
+    element_square: &[
+        0x7a, 0x52, 0x63, 0x62, 0x0a, 0x2e, 0x1f, 0x4f, 0xdc, 0xe4, 0x5c, 0xb8, 0x77, 0xb3, 0x72,
+        0x79, 0xdd, 0x69, 0xb3, 0x42,
+    ],
+};
+
+#[cfg(feature = "p160r2")]
+const P160R2: CurveKat = CurveKat {
+    scalar: &[
+        0x00, 0xac, 0xeb, 0xe7, 0xf4, 0xd0, 0xa6, 0x44, 0x19, 0xac, 0xcc, 0xfe, 0x48, 0x8b, 0xf7,
+        0xd4, 0x74, 0x26, 0x96, 0x39, 0x01,
+    ],
+    point_x: &[
+        0x2c, 0x7b, 0xe5, 0xaa, 0xfb, 0x66, 0x87, 0x25, 0xf9, 0x78, 0xd8, 0x28, 0x4e, 0x5e, 0x73,
+        0x5d, 0xff, 0x2a, 0x90, 0xb4,
+    ],
+    point_y: &[
+        0x4e, 0x1b, 0x0c, 0xb7, 0xdf, 0xa6, 0x23, 0xcb, 0xf0, 0x8c, 0x5c, 0x15, 0x91, 0x11, 0x9c,
+        0x02, 0x3e, 0x49, 0x81, 0xb6,
+    ],
+    element: &[
+        0xbe, 0x17, 0xce, 0xb4, 0x86, 0x91, 0x37, 0x1f, 0x38, 0x20, 0xe1, 0x69, 0xf7, 0x76, 0x80,
+        0x3c, 0xc0, 0x81, 0x44, 0x27,
+    ],
+    element_inverse: &[
+        0xd6, 0x3d, 0xc7, 0xca, 0x95, 0x27, 0xde, 0x12, 0x98, 0x66, 0xa1, 0x41, 0x37, 0x4b, 0xd3,
+        0x36, 0x81, 0x23, 0x65, 0xee,
+    ],
+    element_square: &[
+        0x5b, 0x7d, 0x9c, 0x9b, 0x5d, 0x9c, 0xf6, 0x57, 0x61, 0x5c, 0xe2, 0x1e, 0x3d, 0xd8, 0xc2,
+        0x0d, 0xeb, 0x3d, 0xd1, 0xdb,
+    ],
+};
+
+#[cfg(feature = "p192k1")]
+const P192K1: CurveKat = CurveKat {
+    scalar: &[
+        0xf4, 0x97, 0x74, 0x58, 0x01, 0x48, 0xab, 0x42, 0x5f, 0x7f, 0x0a, 0x7f, 0xf6, 0x8d, 0xa8,
+        0x03, 0x00, 0xfe, 0x82, 0x35, 0xbb, 0xfb, 0x28, 0x1b,
+    ],
+    point_x: &[
+        0xf2, 0x13, 0xd6, 0xa5, 0x65, 0x0b, 0xab, 0x67, 0x1e, 0xef, 0x5a, 0x45, 0x70, 0x97, 0x36,
+        0x52, 0x68, 0xe6, 0x22, 0x99, 0xcc, 0x9d, 0xb7, 0x85,
+    ],
+    point_y: &[
+        0x50, 0xa4, 0x5f, 0x43, 0x2a, 0x44, 0xad, 0x34, 0x44, 0xdc, 0x95, 0x07, 0xa8, 0x0b, 0xc0,
+        0xd4, 0x8d, 0xee, 0xb2, 0xba, 0x75, 0x04, 0x27, 0xbb,
+    ],
+    element: &[
+        0x71, 0x58, 0xb4, 0x1b, 0x63, 0xf2, 0xa3, 0xc0, 0x0e, 0x2b, 0x3f, 0x5e, 0x1e, 0xdb, 0x5b,
+        0x43, 0xa9, 0xf8, 0x5f, 0xac, 0xe8, 0xd3, 0x70, 0x36,
+    ],
+    element_inverse: &[
+        0x70, 0x78, 0x3c, 0xc0, 0xa6, 0x19, 0x8c, 0xa7, 0x81, 0x34, 0xec, 0x0d, 0x1e, 0xc9, 0xc3,
+        0xa5, 0xc9, 0x85, 0x51, 0xf6, 0x6a, 0x1d, 0xc4, 0x43,
+    ],
+    element_square: &[
+        0xe1, 0x41, 0xe4, 0xb9, 0xce, 0x93, 0xd9, 0xe0, 0xb4, 0x7c, 0x46, 0x07, 0x99, 0x46, 0x11,
+        0x51, 0x12, 0x19, 0xcf, 0xc6, 0x54, 0x0d, 0x7d, 0x08,
+    ],
+};
+
+#[cfg(feature = "p192r1")]
+const P192R1: CurveKat = CurveKat {
+    scalar: &[
+        0xd5, 0x9e, 0x5f, 0x9b, 0x14, 0x82, 0xfb, 0x01, 0x67, 0x0d, 0x81, 0x00, 0xbd, 0x43, 0xcc,
+        0x41, 0xfd, 0x0b, 0x3d, 0x95, 0x32, 0x8f, 0x98, 0xdb,
+    ],
+    point_x: &[
+        0xad, 0x27, 0xc2, 0x8c, 0xcb, 0xfd, 0x4e, 0x2a, 0x86, 0x09, 0x6d, 0x20, 0x95, 0x8f, 0xba,
+        0x9f, 0xb0, 0x4e, 0x67, 0xb1, 0xb0, 0x5d, 0xfb, 0xc4,
+    ],
+    point_y: &[
+        0x4c, 0xad, 0xbd, 0x0a, 0x48, 0xe0, 0xf5, 0x5d, 0xca, 0xab, 0x7e, 0x4f, 0x28, 0x72, 0x82,
+        0xdf, 0x1e, 0xf3, 0xcf, 0xd8, 0x47, 0x8c, 0x8f, 0xd1,
+    ],
+    element: &[
+        0x6b, 0x7c, 0x68, 0x66, 0x57, 0xc6, 0x0a, 0xef, 0x95, 0xcc, 0xf7, 0x20, 0xa8, 0x36, 0xb2,
+        0x56, 0x32, 0x2a, 0x0d, 0x1e, 0x99, 0xb9, 0xff, 0x34,
+    ],
+    element_inverse: &[
+        0xa9, 0xf3, 0xec, 0x23, 0x11, 0x6a, 0x9e, 0x23, 0x7f, 0x80, 0xc6, 0x02, 0xc3, 0x43, 0x05,
+        0x32, 0x05, 0xcf, 0xe6, 0x73, 0xfd, 0xfd, 0x00, 0x00,
+    ],
+    element_square: &[
+        0xfa, 0xa9, 0xa4, 0x4b, 0xfa, 0x03, 0x6b, 0x93, 0x62, 0x87, 0xb5, 0xcd, 0x63, 0x27, 0x15,
+        0x08, 0x58, 0x75, 0xac, 0xf7, 0xe6, 0xe3, 0xf2, 0xf4,
+    ],
+};
+
+#[cfg(feature = "p224k1")]
+const P224K1: CurveKat = CurveKat {
+    scalar: &[
+        0x00, 0x3e, 0x37, 0xec, 0x16, 0xdd, 0xfa, 0xb2, 0xa5, 0xdf, 0x1e, 0x4f, 0x3d, 0x0b, 0x9c,
+        0x77, 0x4e, 0xd5, 0x68, 0xed, 0x5c, 0xd9, 0xff, 0xc3, 0x6c, 0x95, 0xc0, 0xc7, 0x8e,
+    ],
+    point_x: &[
+        0xf6, 0x35, 0x4e, 0x93, 0x5f, 0xb6, 0x42, 0x33, 0xd3, 0xec, 0x3f, 0xee, 0x1d, 0x3b, 0x0a,
+        0x2d, 0x89, 0xc3, 0x9c, 0x13, 0xbf, 0xdc, 0x78, 0x8c, 0x09, 0x1f, 0x48, 0x89,
+    ],
+    point_y: &[
+        0x26, 0xec, 0x05, 0xff, 0xed, 0xe8, 0xbf, 0x05, 0x97, 0x99, 0x19, 0x40, 0x11, 0xc8, 0xa9,
+        0xf2, 0x2f, 0x09, 0xa9, 0x72, 0x6e, 0xef, 0xa7, 0x9e, 0xbd, 0x90, 0x90, 0x94,
+    ],
+    element: &[
+        0x1a, 0x7d, 0x45, 0xf1, 0x31, 0x9c, 0xae, 0xd7, 0xb6, 0x31, 0x07, 0xb1, 0xc7, 0xb9, 0x39,
+        0xed, 0x63, 0x2e, 0xa6, 0x8c, 0x4b, 0xf3, 0x54, 0x1d, 0x62, 0x62, 0xcf, 0x14,
+    ],
+    element_inverse: &[
+        0x94, 0xde, 0xfd, 0xb4, 0x79, 0x85, 0x05, 0xce, 0x3b, 0x5f, 0xfd, 0xf2, 0x67, 0xb7, 0xc3,
+        0x0a, 0x3c, 0x73, 0x47, 0x6c, 0x47, 0x37, 0x42, 0x33, 0x02, 0x07, 0x76, 0xd5,
+    ],
+    element_square: &[
+        0xe4, 0xed, 0x52, 0x01, 0x18, 0x0b, 0xe7, 0x99, 0x82, 0x4e, 0xd2, 0x06, 0x58, 0xa9, 0xff,
+        0x49, 0xc0, 0x98, 0x2e, 0xdd, 0x2f, 0xd5, 0x16, 0x83, 0x13, 0x46, 0x74, 0xb4,
+    ],
+};
+
+#[cfg(feature = "p224r1")]
+const P224R1: CurveKat = CurveKat {
+    scalar: &[
+        0x33, 0xfb, 0x99, 0x04, 0xe3, 0xa1, 0x1b, 0x6f, 0x76, 0x0e, 0x73, 0x9c, 0x74, 0xda, 0x79,
+        0xe0, 0x5c, 0x13, 0x89, 0x93, 0x26, 0xa2, 0xb3, 0x22, 0x85, 0xd3, 0x2a, 0xa8,
+    ],
+    point_x: &[
+        0x6b, 0xee, 0xba, 0xb3, 0xbd, 0x7b, 0xeb, 0x85, 0x6a, 0xea, 0xea, 0x05, 0x78, 0xf4, 0xad,
+        0x30, 0xb2, 0x29, 0x0f, 0xf2, 0xa2, 0x11, 0x4d, 0x09, 0x48, 0x3e, 0x74, 0x35,
+    ],
+    point_y: &[
+        0x60, 0x96, 0x66, 0x0a, 0xa0, 0x0f, 0x17, 0x43, 0x96, 0x33, 0x7e, 0x0a, 0x79, 0xc9, 0x7c,
+        0x3b, 0xc8, 0xf7, 0x73, 0x0e, 0xaa, 0x87, 0xea, 0x8f, 0x81, 0xd0, 0x01, 0xcb,
+    ],
+    element: &[
+        0x3d, 0x42, 0x68, 0x75, 0x08, 0x19, 0xac, 0xae, 0x57, 0x05, 0x7f, 0x0b, 0x08, 0xdd, 0x8c,
+        0xae, 0x7f, 0x26, 0x5f, 0x6e, 0x7c, 0x36, 0x89, 0xd8, 0xfe, 0x00, 0xfe, 0xf7,
+    ],
+    element_inverse: &[
+        0x78, 0x5f, 0xd9, 0xc2, 0x19, 0xd8, 0x4f, 0x0f, 0x2d, 0xe8, 0x40, 0x85, 0x13, 0x38, 0x83,
+        0xc6, 0xdf, 0xcd, 0x1e, 0x76, 0x5a, 0xbe, 0x1d, 0x09, 0x29, 0x4a, 0x69, 0x24,
+    ],
+    element_square: &[
+        0x81, 0xc9, 0xfb, 0x39, 0x9e, 0xb0, 0xd7, 0xf2, 0x57, 0xb6, 0x7a, 0x1c, 0x36, 0x87, 0x4b,
+        0xe7, 0x46, 0xbb, 0xbe, 0xf2, 0x9d, 0x74, 0x04, 0x59, 0xc5, 0xd7, 0xfe, 0x5b,
+    ],
+};
+
+#[cfg(feature = "p256k1")]
+const P256K1: CurveKat = CurveKat {
+    scalar: &[
+        0xfb, 0x76, 0x1c, 0x98, 0x24, 0x83, 0x4a, 0xb9, 0x98, 0xa7, 0x6b, 0x9c, 0x9e, 0x36, 0xdb,
+        0xae, 0x78, 0xc3, 0x3c, 0x7b, 0x71, 0x5c, 0xd0, 0x57, 0x3f, 0x42, 0x76, 0xd7, 0x46, 0xe8,
+        0xca, 0x32,
+    ],
+    point_x: &[
+        0x02, 0x7b, 0x2f, 0x9f, 0x87, 0x3a, 0x61, 0x83, 0x2d, 0xe6, 0xa4, 0x58, 0xbd, 0x96, 0xa2,
+        0xcd, 0xdf, 0xe7, 0x6e, 0x82, 0xf7, 0xd6, 0x6e, 0xca, 0xc4, 0x58, 0x54, 0x8e, 0xe3, 0xe7,
+        0x74, 0x19,
+    ],
+    point_y: &[
+        0xc5, 0xcb, 0xcc, 0xfc, 0xd0, 0x60, 0xcc, 0xbb, 0xaf, 0x24, 0xb2, 0x92, 0x7d, 0xee, 0x7a,
+        0x26, 0x12, 0x2f, 0x5c, 0xf3, 0xce, 0x94, 0xfb, 0x36, 0x55, 0x78, 0x41, 0xe8, 0xf0, 0x6c,
+        0xc9, 0x25,
+    ],
+    element: &[
+        0x75, 0x57, 0x7a, 0x13, 0x9d, 0x71, 0x26, 0xe0, 0x18, 0x24, 0x6b, 0x80, 0x9f, 0x60, 0xd4,
+        0x1e, 0xa8, 0xf4, 0xcf, 0xfc, 0x73, 0x7a, 0x35, 0x3e, 0xd4, 0x52, 0x94, 0x3c, 0xf2, 0xc7,
+        0xe1, 0xe0,
+    ],
+    element_inverse: &[
+        0x5d, 0x28, 0x15, 0xc0, 0x96, 0xad, 0x51, 0x07, 0x0e, 0xef, 0x00, 0x10, 0x8b, 0xa0, 0x23,
+        0x18, 0x64, 0xbf, 0x67, 0x4e, 0x8a, 0x7a, 0x82, 0x95, 0x77, 0x74, 0x53, 0x87, 0xed, 0xcb,
+        0xa1, 0x28,
+    ],
+    element_square: &[
+        0x67, 0x82, 0xfd, 0x42, 0x4e, 0x59, 0x4f, 0x8a, 0x1f, 0xd5, 0x60, 0x3c, 0x7e, 0x14, 0x0c,
+        0xaf, 0xad, 0xdd, 0x4f, 0xc5, 0xda, 0x43, 0x40, 0xf4, 0xb1, 0xa8, 0x8c, 0xcc, 0x78, 0x06,
+        0x18, 0x61,
+    ],
+};
+
+#[cfg(feature = "p256r1")]
+const P256R1: CurveKat = CurveKat {
+    scalar: &[
+        0xdb, 0xcf, 0x1c, 0x92, 0xe1, 0x2f, 0x81, 0x40, 0x1f, 0x9a, 0x20, 0xfd, 0x17, 0xf1, 0x0e,
+        0xfb, 0x5a, 0x26, 0xc5, 0xd0, 0x92, 0x46, 0x0b, 0xe6, 0x94, 0x49, 0xa7, 0xa3, 0x8d, 0xac,
+        0x48, 0x9f,
+    ],
+    point_x: &[
+        0x56, 0xd9, 0x95, 0x9f, 0x2a, 0xf7, 0xe7, 0xd5, 0x35, 0x52, 0xb2, 0x1c, 0x59, 0x87, 0xeb,
+        0x47, 0xaa, 0x81, 0x53, 0x55, 0x23, 0x54, 0x98, 0xdd, 0x56, 0x98, 0x03, 0x24, 0x27, 0xe3,
+        0xb5, 0xb6,
+    ],
+    point_y: &[
+        0x02, 0x19, 0x72, 0x21, 0xf9, 0x64, 0x0d, 0xd8, 0x1f, 0xe8, 0x18, 0xca, 0xea, 0xfa, 0x92,
+        0x68, 0xca, 0x5d, 0x73, 0xf8, 0xae, 0x39, 0x39, 0x85, 0x8d, 0x81, 0x20, 0xad, 0xd2, 0x51,
+        0x2e, 0xd4,
+    ],
+    element: &[
+        0x7f, 0xce, 0x66, 0xdb, 0x2a, 0xfe, 0x6d, 0x0d, 0xa3, 0x1a, 0xbd, 0xda, 0x8e, 0x39, 0xbc,
+        0x1f, 0x1d, 0x59, 0x54, 0x98, 0x34, 0xc2, 0x4d, 0xd3, 0x6c, 0x8a, 0x53, 0xcb, 0x42, 0x25,
+        0x67, 0xaa,
+    ],
+    element_inverse: &[
+        0x4b, 0x36, 0xb8, 0x15, 0x76, 0x92, 0x24, 0x54, 0x23, 0x97, 0x03, 0x8b, 0x9c, 0x7a, 0xf8,
+        0xb1, 0x3a, 0x0a, 0x29, 0xdd, 0x54, 0x63, 0x6d, 0x2c, 0x24, 0x23, 0xcd, 0xac, 0x2f, 0xe3,
+        0x7b, 0xce,
+    ],
+    element_square: &[
+        0x01, 0xce, 0x14, 0xb3, 0xb4, 0x03, 0x55, 0xb2, 0xe9, 0xd5, 0x52, 0x81, 0x74, 0xc8, 0xfa,
+        0x20, 0x45, 0x8f, 0xef, 0x74, 0x2e, 0x2e, 0xee, 0xd7, 0x8d, 0x3d, 0x6c, 0x27, 0xb0, 0x90,
+        0xbe, 0xb1,
+    ],
+};
+
+#[cfg(feature = "p384r1")]
+const P384R1: CurveKat = CurveKat {
+    scalar: &[
+        0x59, 0x5c, 0x53, 0xd1, 0x60, 0x1f, 0xc3, 0x19, 0xdb, 0x28, 0x75, 0x2f, 0x85, 0x18, 0x40,
+        0xa3, 0x7c, 0xba, 0xd1, 0xf6, 0x88, 0x01, 0x8b, 0x88, 0x77, 0x66, 0x66, 0x12, 0x07, 0x11,
+        0x46, 0x25, 0x11, 0x4a, 0xc4, 0x5c, 0x58, 0x56, 0x83, 0x64, 0xa4, 0xee, 0x05, 0xaa, 0x92,
+        0xd7, 0xff, 0x5f,
+    ],
+    point_x: &[
+        0x94, 0x1b, 0xba, 0xf4, 0x35, 0x01, 0x5c, 0xdc, 0xa4, 0x8d, 0x57, 0x10, 0xf0, 0xa3, 0x00,
+        0x45, 0x0b, 0x79, 0x42, 0x02, 0x4f, 0x2f, 0x0c, 0xde, 0x5e, 0xfc, 0x4f, 0x5b, 0x31, 0xf3,
+        0xe9, 0x7f, 0xc0, 0x6d, 0x83, 0x5f, 0x59, 0x1a, 0x7f, 0x95, 0xeb, 0xbe, 0xb0, 0xba, 0x0a,
+        0xbc, 0x97, 0xa9,
+    ],
+    point_y: &[
+        0x91, 0xca, 0xdd, 0x4e, 0x63, 0xc6, 0x62, 0x7c, 0xb7, 0x8f, 0x3a, 0x37, 0xb3, 0x66, 0xfc,
+        0xf0, 0x48, 0x3f, 0x2a, 0x5a, 0xc3, 0x15, 0xad, 0x62, 0xa1, 0xc3, 0xda, 0x70, 0xc7, 0xbf,
+        0x03, 0x7b, 0x31, 0x4f, 0x8d, 0xc0, 0x12, 0xe5, 0x4a, 0xa7, 0xff, 0x05, 0xcd, 0x53, 0x1d,
+        0xe4, 0x33, 0x81,
+    ],
+    element: &[
+        0x66, 0x0e, 0x61, 0x42, 0xa5, 0x15, 0xd4, 0xab, 0x16, 0x06, 0xff, 0xc9, 0xbb, 0x69, 0x01,
+        0x2e, 0x74, 0xa1, 0x93, 0xa5, 0xda, 0x9e, 0x04, 0xa2, 0x4d, 0x11, 0xaa, 0x31, 0x45, 0x01,
+        0x51, 0x92, 0x99, 0xdc, 0xe0, 0x48, 0x97, 0xc5, 0x53, 0x97, 0x33, 0x34, 0x85, 0x16, 0xe8,
+        0x80, 0x84, 0x60,
+    ],
+    element_inverse: &[
+        0x61, 0x27, 0x40, 0x51, 0x4d, 0x49, 0xa5, 0xe9, 0x9e, 0x31, 0xab, 0x9e, 0xf3, 0xca, 0x7b,
+        0xef, 0x9b, 0xfd, 0xa1, 0xc8, 0xa0, 0x22, 0x42, 0xcb, 0x7c, 0x3b, 0x4c, 0x39, 0x51, 0xf7,
+        0x1a, 0x16, 0x44, 0x58, 0x2d, 0x3c, 0x01, 0x06, 0x04, 0xbd, 0xaa, 0x1a, 0x88, 0x77, 0x15,
+        0x73, 0x49, 0x71,
+    ],
+    element_square: &[
+        0xb1, 0xc7, 0xeb, 0x03, 0x8b, 0x99, 0xf6, 0x94, 0xfd, 0xde, 0x18, 0x8a, 0x4c, 0x37, 0x34,
+        0x37, 0xfc, 0x6b, 0xbc, 0x1f, 0x03, 0x7f, 0xba, 0x11, 0xf4, 0xe4, 0xaa, 0x58, 0x8e, 0x8d,
+        0x1b, 0xc3, 0x22, 0x38, 0x6a, 0x87, 0x90, 0x3e, 0x92, 0xfc, 0x2f, 0x01, 0x65, 0xa7, 0xe9,
+        0x35, 0x63, 0xe7,
+    ],
+};
+
+#[cfg(feature = "p521r1")]
+const P521R1: CurveKat = CurveKat {
+    scalar: &[
+        0x00, 0x00, 0x60, 0xf4, 0x95, 0xaa, 0x80, 0xff, 0x0c, 0x5f, 0xaf, 0x1c, 0xfb, 0x42, 0xb5,
+        0x6e, 0x2d, 0x45, 0xf0, 0x93, 0x0b, 0x7f, 0xd7, 0x74, 0xd3, 0x77, 0x0c, 0xcc, 0x97, 0xd5,
+        0x4e, 0xb9, 0xa9, 0x50, 0xab, 0x20, 0xbb, 0xe5, 0xe8, 0x41, 0xeb, 0x91, 0x2c, 0x78, 0x7b,
+        0x80, 0x4b, 0x8f, 0xce, 0xd4, 0x69, 0xd8, 0x2c, 0xf0, 0xc3, 0x73, 0x1c, 0x50, 0x35, 0x52,
+        0x7d, 0xc9, 0x5a, 0x52, 0xcd, 0x19,
+    ],
+    point_x: &[
+        0x01, 0xc1, 0xd9, 0xff, 0xd3, 0xb7, 0x0b, 0x17, 0x49, 0x45, 0xff, 0x5a, 0xd2, 0x93, 0x4a,
+        0xc1, 0xf0, 0xd0, 0xd8, 0x33, 0xea, 0x14, 0x3d, 0x6a, 0xa3, 0x45, 0x0b, 0x3f, 0xac, 0x78,
+        0x99, 0x44, 0x43, 0x3c, 0xac, 0x9a, 0x2d, 0x91, 0x3c, 0xeb, 0xab, 0xd7, 0x99, 0x99, 0xf9,
+        0xcb, 0xe2, 0x13, 0xf5, 0x2b, 0x62, 0xd0, 0x75, 0xba, 0x6b, 0x8b, 0xd9, 0xb6, 0x9f, 0xdd,
+        0x7d, 0x94, 0x7d, 0x33, 0xa1, 0xd1,
+    ],
+    point_y: &[
+        0x00, 0x17, 0xcb, 0x77, 0x06, 0xb1, 0x71, 0x67, 0xa3, 0x99, 0x39, 0x3b, 0x2b, 0x53, 0x16,
+        0x34, 0xa2, 0xa6, 0x9a, 0xd7, 0xea, 0xaa, 0x6a, 0x6a, 0x67, 0xd6, 0xe7, 0xb3, 0x85, 0xf9,
+        0xa4, 0x4d, 0x7b, 0xbb, 0xca, 0xf6, 0xda, 0xfc, 0xfd, 0xb3, 0xb8, 0x58, 0x76, 0xb3, 0x23,
+        0x40, 0x7b, 0xf1, 0x21, 0x43, 0x65, 0x9f, 0x1a, 0x69, 0x9f, 0x68, 0xd2, 0xe2, 0x13, 0x86,
+        0x96, 0x40, 0x25, 0x47, 0xf6, 0x39,
+    ],
+    element: &[
+        0x00, 0x00, 0xc9, 0x49, 0xe7, 0xbc, 0x52, 0x0e, 0x19, 0xfc, 0x1d, 0x3b, 0x0e, 0x0f, 0xc0,
+        0x2a, 0x5e, 0x70, 0xb3, 0x0e, 0xe6, 0x65, 0x83, 0x54, 0xf2, 0xff, 0x70, 0x31, 0xac, 0x52,
+        0x85, 0xae, 0x1e, 0x6e, 0xc5, 0xb7, 0x36, 0x82, 0xef, 0xeb, 0x30, 0xef, 0x81, 0x70, 0x41,
+        0x08, 0x88, 0x14, 0x23, 0xc9, 0xde, 0x73, 0xd4, 0xca, 0xe6, 0x74, 0x58, 0xa5, 0x57, 0x27,
+        0xc3, 0x1b, 0xd2, 0x88, 0x12, 0xa9,
+    ],
+    element_inverse: &[
+        0x01, 0xdf, 0x4a, 0xc4, 0x1e, 0x99, 0xb3, 0x3b, 0xc4, 0x1f, 0x8a, 0xed, 0xc5, 0xc8, 0xe8,
+        0xb9, 0xa9, 0x6b, 0xef, 0x43, 0xe4, 0xe6, 0x72, 0x39, 0x4a, 0x76, 0x06, 0xb2, 0x07, 0x8a,
+        0xac, 0xf1, 0x47, 0x64, 0x44, 0x5c, 0xe2, 0x2c, 0x58, 0x91, 0x38, 0xc0, 0x3d, 0xcf, 0x76,
+        0xfb, 0x0d, 0x89, 0xeb, 0xc2, 0x8f, 0xbb, 0x69, 0x82, 0x1f, 0x95, 0x16, 0x3c, 0x91, 0x38,
+        0x71, 0xe7, 0x88, 0x96, 0xa1, 0xa0,
+    ],
+    element_square: &[
+        0x00, 0x28, 0x30, 0x17, 0xa9, 0xd3, 0x37, 0xca, 0xc2, 0x5c, 0x67, 0x05, 0xe0, 0xe7, 0x0a,
+        0x95, 0x9e, 0xcb, 0x2a, 0xf0, 0xad, 0xd0, 0xa9, 0x9c, 0xc1, 0x06, 0x19, 0xa8, 0x16, 0x95,
+        0x88, 0x20, 0x4b, 0x57, 0x31, 0x5e, 0xb9, 0xf0, 0x1f, 0xb6, 0xe4, 0x23, 0xfd, 0x33, 0xbe,
+        0x19, 0xe7, 0xe4, 0x43, 0x1b, 0x5b, 0x29, 0x40, 0x84, 0xc5, 0x1d, 0xb2, 0xb7, 0x0b, 0x76,
+        0xe8, 0x38, 0x6b, 0xaa, 0xe1, 0x8e,
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    // corrupt each expected value of each curve in turn
+    #[test]
+    fn corrupted_kat() {
+        type Corruption = fn(&mut CurveKat);
+        let corruptions: [(Corruption, SelfTestCheck); 6] = [
+            (|k| k.scalar = k.element, SelfTestCheck::ScalarMul),
+            (|k| k.point_x = k.point_y, SelfTestCheck::ScalarMul),
+            (|k| k.point_y = k.point_x, SelfTestCheck::ScalarMul),
+            (
+                |k| k.element = k.element_inverse,
+                SelfTestCheck::FieldInverse,
+            ),
+            (
+                |k| k.element_inverse = k.element,
+                SelfTestCheck::FieldInverse,
+            ),
+            (
+                |k| k.element_square = k.element_inverse,
+                SelfTestCheck::FieldSqrt,
+            ),
+        ];
+        for kat in CURVES.iter() {
+            for (corrupt, check) in corruptions.iter() {
+                let mut curve = *kat;
+                corrupt(&mut curve.2);
+                let expected = SelfTestError {
+                    curve: curve.0,
+                    check: *check,
+                };
+                assert_eq!(run(&[curve]), Err(expected));
+            }
+        }
+
+        // the curves before the corrupted one pass, and the ones after are not run
+        if let Some(last) = CURVES.len().checked_sub(1) {
+            let mut curves = CURVES.to_vec();
+            curves[last].2.point_x = curves[last].2.point_y;
+            assert_eq!(
+                run(&curves),
+                Err(SelfTestError {
+                    curve: CURVES[last].0,
+                    check: SelfTestCheck::ScalarMul
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn error_display() {
+        let e = SelfTestError {
+            curve: "p256r1",
+            check: SelfTestCheck::ScalarMul,
+        };
+        assert_eq!(e.to_string(), "self test failed on p256r1: ScalarMul");
+    }
+}
@@ -0,0 +1,226 @@
+//! Consistency between the fiat and the bigint backends
+//!
+//! The bigint backend is instantiated here for curves that are otherwise only
+//! provided by the fiat backend, and both are run on the same pseudo random
+//! values derived from fixed seeds: points are compared through the bytes of their
+//! affine coordinates, and field elements and scalars through their bytes.
+//!
+//! The square roots are compared up to the sign, since the choice of the root
+//! depends on the algorithm, and the point decompression only as a set of
+//! candidate points, since the bigint backend uses the "high" half of the
+//! field as the sign instead of the parity.
+
+macro_rules! test_backends {
+    ($curve:ident, $fe_bits:expr, $scalar_bits:expr) => {
+        mod $curve {
+            use crate::curve::sec2::$curve as fiat;
+            use crate::curve::Sign;
+
+            mod bigint {
+                crate::bigint_prime_curve!($curve, $fe_bits, $scalar_bits);
+            }
+            use bigint::$curve as big;
+
+            const SEEDS: [u64; 4] = [1, 0x5eed, 0xdeadbeef, u64::MAX];
+
+            // pseudo random looking sequence, starting with small values
+            fn fiat_scalars() -> Vec<fiat::Scalar> {
+                let mut v = vec![fiat::Scalar::one(), fiat::Scalar::from_u64(2)];
+                for seed in SEEDS.iter() {
+                    let mut s = fiat::Scalar::from_u64(*seed);
+                    for i in 0..8 {
+                        s = s.square() + fiat::Scalar::from_u64(i + 1);
+                        v.push(s.clone());
+                    }
+                }
+                v.push(-fiat::Scalar::one());
+                v
+            }
+
+            fn fiat_elements() -> Vec<fiat::FieldElement> {
+                let mut v = vec![fiat::FieldElement::zero(), fiat::FieldElement::one()];
+                for seed in SEEDS.iter() {
+                    let mut x = fiat::FieldElement::from_u64(*seed);
+                    for i in 0..8 {
+                        x = x.square() + fiat::FieldElement::from_u64(i + 1);
+                        v.push(x.clone());
+                    }
+                }
+                v.push(-fiat::FieldElement::one());
+                v
+            }
+
+            fn scalar(s: &fiat::Scalar) -> big::Scalar {
+                big::Scalar::from_bytes(&s.to_bytes()).unwrap()
+            }
+
+            fn element(x: &fiat::FieldElement) -> big::FieldElement {
+                big::FieldElement::from_bytes(&x.to_bytes()).unwrap()
+            }
+
+            fn same_root(fiat_root: &fiat::FieldElement, big_root: &big::FieldElement) -> bool {
+                let b = big_root.to_bytes();
+                fiat_root.to_bytes() == b || (-fiat_root).to_bytes() == b
+            }
+
+            #[test]
+            fn sizes() {
+                assert_eq!(fiat::FieldElement::SIZE_BITS, big::FieldElement::SIZE_BITS);
+                assert_eq!(
+                    fiat::FieldElement::SIZE_BYTES,
+                    big::FieldElement::SIZE_BYTES
+                );
+                assert_eq!(fiat::Scalar::SIZE_BITS, big::Scalar::SIZE_BITS);
+                assert_eq!(fiat::Scalar::SIZE_BYTES, big::Scalar::SIZE_BYTES);
+            }
+
+            #[test]
+            fn field_arithmetic() {
+                let elements = fiat_elements();
+                for x in elements.iter() {
+                    let bx = element(x);
+                    assert_eq!((-x).to_bytes(), (-&bx).to_bytes(), "neg {}", x);
+                    assert_eq!(
+                        x.square().to_bytes(),
+                        (&bx * &bx).to_bytes(),
+                        "square {}",
+                        x
+                    );
+                    for y in elements.iter() {
+                        let by = element(y);
+                        assert_eq!((x + y).to_bytes(), (&bx + &by).to_bytes());
+                        assert_eq!((x - y).to_bytes(), (&bx - &by).to_bytes());
+                        assert_eq!((x * y).to_bytes(), (&bx * &by).to_bytes());
+                    }
+                }
+            }
+
+            #[test]
+            fn field_inverse() {
+                for x in fiat_elements().iter().filter(|x| !x.is_zero()) {
+                    let big_inv = element(x).inverse().unwrap();
+                    assert_eq!(x.inverse().to_bytes(), big_inv.to_bytes(), "inverse {}", x);
+                }
+                assert!(big::FieldElement::zero().inverse().is_none());
+            }
+
+            #[test]
+            fn field_sqrt() {
+                let mut roots = 0;
+                for x in fiat_elements().iter() {
+                    let fiat_root = x.sqrt().into_option();
+                    let big_root = element(x).sqrt();
+                    match (fiat_root, big_root) {
+                        (Some(r), Some(br)) => {
+                            assert!(same_root(&r, &br), "sqrt {}: {} {:?}", x, r, br);
+                            roots += 1;
+                        }
+                        (None, None) => {}
+                        (r, br) => panic!("sqrt {}: fiat {:?} bigint {:?}", x, r, br),
+                    }
+                }
+                // both squares and non squares are covered
+                assert!(roots > 2 && roots < fiat_elements().len());
+            }
+
+            #[test]
+            fn scalar_arithmetic() {
+                let scalars = fiat_scalars();
+                for s in scalars.iter() {
+                    let bs = scalar(s);
+                    assert_eq!((-s).to_bytes(), (-&bs).to_bytes(), "neg {:?}", s);
+                    let inv = bs.inverse().unwrap();
+                    assert_eq!(s.inverse().to_bytes(), inv.to_bytes(), "inverse {:?}", s);
+                    for t in scalars.iter() {
+                        let bt = scalar(t);
+                        assert_eq!((s + t).to_bytes(), (&bs + &bt).to_bytes());
+                        assert_eq!((s - t).to_bytes(), (&bs - &bt).to_bytes());
+                        assert_eq!((s * t).to_bytes(), (&bs * &bt).to_bytes());
+                    }
+                }
+            }
+
+            #[test]
+            fn scalar_sqrt() {
+                for s in fiat_scalars().iter() {
+                    let bs = scalar(s);
+                    let square = &bs * &bs;
+                    let root = square.sqrt().expect("square has a root");
+                    assert!(root == bs || root == -&bs, "sqrt {:?}^2", s);
+                    // a non square times a square is a non square
+                    if let Some(r) = (&square * &big::Scalar::from_u64(3)).sqrt() {
+                        assert_eq!(&r * &r, &square * &big::Scalar::from_u64(3));
+                    }
+                }
+            }
+
+            fn fiat_coordinates(p: &fiat::Point) -> Option<(Vec<u8>, Vec<u8>)> {
+                p.to_affine().map(|p| {
+                    let (x, y) = p.to_coordinate();
+                    (x.to_bytes().to_vec(), y.to_bytes().to_vec())
+                })
+            }
+
+            fn big_coordinates(p: &big::Point) -> Option<(Vec<u8>, Vec<u8>)> {
+                p.to_affine().map(|p| {
+                    let (x, y) = p.to_coordinate();
+                    (x.to_bytes().to_vec(), y.to_bytes().to_vec())
+                })
+            }
+
+            #[test]
+            fn points() {
+                let g = fiat::Point::generator();
+                let bg = big::Point::generator();
+                for s in fiat_scalars().iter() {
+                    let p = &g * s;
+                    let bp = &bg * &scalar(s);
+                    assert_eq!(fiat_coordinates(&p), big_coordinates(&bp), "{:?}.G", s);
+                    assert_eq!(
+                        fiat_coordinates(&(&p + &g)),
+                        big_coordinates(&(&bp + &bg)),
+                        "{:?}.G + G",
+                        s
+                    );
+                    assert_eq!(
+                        fiat_coordinates(&(&p + &p)),
+                        big_coordinates(&(&bp + &bp)),
+                        "2.{:?}.G",
+                        s
+                    );
+                }
+            }
+
+            #[test]
+            fn decompress() {
+                let mut valid = 0;
+                for x in fiat_elements().iter() {
+                    let bx = element(x);
+                    let fiat_points: Vec<_> = [Sign::Positive, Sign::Negative]
+                        .iter()
+                        .filter_map(|sign| fiat::PointAffine::decompress(x, *sign))
+                        .map(|p| p.to_coordinate().1.to_bytes())
+                        .collect();
+                    let mut big_points: Vec<_> = [false, true]
+                        .iter()
+                        .filter_map(|bit| big::PointAffine::decompress(&bx, *bit))
+                        .map(|p| p.to_coordinate().1.to_bytes())
+                        .collect();
+                    if fiat_points.first() != big_points.first() {
+                        big_points.reverse();
+                    }
+                    assert_eq!(fiat_points, big_points, "decompress {}", x);
+                    valid += fiat_points.len();
+                }
+                assert!(valid > 0);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192r1")]
+test_backends!(p192r1, 192, 192);
+#[cfg(feature = "p224k1")]
+test_backends!(p224k1, 224, 225);
+#[cfg(feature = "p224r1")]
+test_backends!(p224r1, 224, 224);
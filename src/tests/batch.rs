@@ -0,0 +1,138 @@
+macro_rules! test_batch {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{FieldElement, Point, Scalar};
+
+            // non normalized multiples of G, with the point at infinity at every 5th position
+            fn points<const N: usize>() -> [Point; N] {
+                let g = Point::generator();
+                let mut p = g.clone();
+                core::array::from_fn(|i| {
+                    p = &p + &p + &g;
+                    if i % 5 == 3 {
+                        Point::infinity()
+                    } else {
+                        p.clone()
+                    }
+                })
+            }
+
+            fn scalars<const N: usize>() -> [Scalar; N] {
+                let mut s = Scalar::from_u64(0x5eed);
+                core::array::from_fn(|i| {
+                    s = s.square() + Scalar::from_u64(i as u64);
+                    if i % 7 == 1 {
+                        -Scalar::one()
+                    } else {
+                        s.clone()
+                    }
+                })
+            }
+
+            fn check_batch_to_affine<const N: usize>() {
+                let points = points::<N>();
+                let affines = Point::batch_to_affine_array(&points);
+                assert_eq!(&affines[..], &Point::batch_to_affine(&points)[..]);
+                for (p, a) in points.iter().zip(affines.iter()) {
+                    assert_eq!(&p.to_affine(), a);
+                }
+            }
+
+            fn check_msm<const N: usize>() {
+                let scalars = scalars::<N>();
+                let points = points::<N>();
+                assert_eq!(
+                    Point::msm_array(&scalars, &points),
+                    Point::msm(&scalars, &points)
+                );
+            }
+
+            fn check_batch_inverse<const N: usize>() {
+                // scalars() has no zero
+                let values = scalars::<N>();
+                let mut batch = values.clone();
+                assert!(Scalar::batch_inverse_array(&mut batch).is_true());
+                let mut vec = values.to_vec();
+                assert!(Scalar::batch_inverse(&mut vec).is_true());
+                assert_eq!(&batch[..], &vec[..]);
+                for (v, inv) in values.iter().zip(batch.iter()) {
+                    assert_eq!(&v.inverse(), inv);
+                }
+
+                let mut fe = FieldElement::from_u64(0x5eed);
+                let fes: Vec<_> = (0..N)
+                    .map(|i| {
+                        fe = fe.square() + FieldElement::from_u64(i as u64);
+                        fe.clone()
+                    })
+                    .collect();
+                let mut fe_batch = fes.clone();
+                assert!(FieldElement::batch_inverse(&mut fe_batch).is_true());
+                for (v, inv) in fes.iter().zip(fe_batch.iter()) {
+                    assert_eq!(&v.inverse(), inv);
+                }
+            }
+
+            #[test]
+            fn batch_inverse() {
+                check_batch_inverse::<0>();
+                check_batch_inverse::<1>();
+                check_batch_inverse::<2>();
+                check_batch_inverse::<257>();
+            }
+
+            #[test]
+            fn batch_inverse_zero() {
+                for zero_at in [0, 5, 9] {
+                    let mut values = scalars::<10>();
+                    values[zero_at] = Scalar::zero();
+                    let before = values.clone();
+                    assert!(Scalar::batch_inverse_array(&mut values).is_false());
+                    assert_eq!(values, before);
+                    let mut vec = before.to_vec();
+                    assert!(Scalar::batch_inverse(&mut vec).is_false());
+                    assert_eq!(&vec[..], &before[..]);
+                }
+                let mut single = [FieldElement::zero()];
+                assert!(FieldElement::batch_inverse_array(&mut single).is_false());
+                assert_eq!(single, [FieldElement::zero()]);
+            }
+
+            #[test]
+            fn batch_to_affine_array() {
+                check_batch_to_affine::<1>();
+                check_batch_to_affine::<2>();
+                check_batch_to_affine::<8>();
+                check_batch_to_affine::<33>();
+                assert_eq!(Point::batch_to_affine_array(&[Point::infinity()]), [None]);
+                assert_eq!(Point::batch_to_affine_array::<0>(&[]), []);
+            }
+
+            #[test]
+            fn msm_array() {
+                check_msm::<1>();
+                check_msm::<2>();
+                check_msm::<8>();
+                check_msm::<33>();
+                assert_eq!(Point::msm_array::<0>(&[], &[]), Point::infinity());
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_batch!(p192k1);
+#[cfg(feature = "p192r1")]
+test_batch!(p192r1);
+#[cfg(feature = "p224k1")]
+test_batch!(p224k1);
+#[cfg(feature = "p224r1")]
+test_batch!(p224r1);
+#[cfg(feature = "p256k1")]
+test_batch!(p256k1);
+#[cfg(feature = "p256r1")]
+test_batch!(p256r1);
+#[cfg(feature = "p384r1")]
+test_batch!(p384r1);
+#[cfg(feature = "p521r1")]
+test_batch!(p521r1);
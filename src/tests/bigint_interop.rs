@@ -0,0 +1,105 @@
+//! Conversions between the fiat field elements and scalars and num-bigint's BigUint,
+//! with BigUint as the oracle of the scalar arithmetic
+
+use num_bigint::BigUint;
+use std::convert::TryFrom;
+
+// reproducible big integers, a bit larger than the elements
+fn biguints(seed: u64, size_bytes: usize, count: usize) -> Vec<BigUint> {
+    let mut state = seed | 1;
+    (0..count)
+        .map(|_| {
+            let bytes: Vec<u8> = (0..size_bytes + 8)
+                .map(|_| {
+                    state ^= state >> 12;
+                    state ^= state << 25;
+                    state ^= state >> 27;
+                    (state.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 56) as u8
+                })
+                .collect();
+            BigUint::from_bytes_be(&bytes)
+        })
+        .collect()
+}
+
+macro_rules! test_interop {
+    ($name:ident, $($m:ident)::+) => {
+        mod $name {
+            use super::*;
+            use crate::curve::$($m)::+::{FieldElement, Scalar};
+            use crate::curve::field::NotReduced;
+
+            fn order() -> BigUint {
+                BigUint::from(&Scalar::max()) + 1u32
+            }
+
+            fn modulus() -> BigUint {
+                BigUint::from(&(-FieldElement::one())) + 1u32
+            }
+
+            #[test]
+            fn round_trip() {
+                for v in [Scalar::zero(), Scalar::one(), Scalar::half_max(), Scalar::max()] {
+                    assert_eq!(Scalar::try_from(&BigUint::from(&v)), Ok(v));
+                }
+                let fe = FieldElement::from_u64(0xdeadbeef);
+                assert_eq!(BigUint::from(&fe), BigUint::from(0xdeadbeefu64));
+                assert_eq!(FieldElement::try_from(&BigUint::from(&fe)), Ok(fe));
+                let fe_max = -FieldElement::one();
+                assert_eq!(FieldElement::try_from(&BigUint::from(&fe_max)), Ok(fe_max));
+            }
+
+            #[test]
+            fn not_reduced() {
+                assert_eq!(Scalar::try_from(&order()), Err(NotReduced));
+                assert_eq!(Scalar::try_from(&(order() << 64)), Err(NotReduced));
+                assert_eq!(FieldElement::try_from(&modulus()), Err(NotReduced));
+                assert_eq!(FieldElement::try_from(&(modulus() + 1u32)), Err(NotReduced));
+            }
+
+            #[test]
+            fn reduced() {
+                let (n, p) = (order(), modulus());
+                for v in biguints(1, Scalar::SIZE_BYTES, 32) {
+                    assert_eq!(BigUint::from(&Scalar::from_biguint_reduced(&v)), &v % &n);
+                    assert_eq!(BigUint::from(&FieldElement::from_biguint_reduced(&v)), &v % &p);
+                }
+                assert_eq!(Scalar::from_biguint_reduced(&n), Scalar::zero());
+            }
+
+            #[test]
+            fn differential() {
+                let n = order();
+                let values = biguints(2, Scalar::SIZE_BYTES, 33);
+                for w in values.windows(2) {
+                    let (a, b) = (&w[0] % &n, &w[1] % &n);
+                    let (sa, sb) = (Scalar::try_from(&a).unwrap(), Scalar::try_from(&b).unwrap());
+                    assert_eq!(BigUint::from(&(&sa + &sb)), (&a + &b) % &n);
+                    assert_eq!(BigUint::from(&(&sa * &sb)), (&a * &b) % &n);
+                    // the order is prime: a^-1 = a^(n-2)
+                    let inverse = a.modpow(&(&n - 2u32), &n);
+                    assert_eq!(BigUint::from(&sa.inverse()), inverse);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_interop!(p192k1, sec2::p192k1);
+#[cfg(feature = "p192r1")]
+test_interop!(p192r1, sec2::p192r1);
+#[cfg(feature = "p224k1")]
+test_interop!(p224k1, sec2::p224k1);
+#[cfg(feature = "p224r1")]
+test_interop!(p224r1, sec2::p224r1);
+#[cfg(feature = "p256k1")]
+test_interop!(p256k1, sec2::p256k1);
+#[cfg(feature = "p256r1")]
+test_interop!(p256r1, sec2::p256r1);
+#[cfg(feature = "p384r1")]
+test_interop!(p384r1, sec2::p384r1);
+#[cfg(feature = "p521r1")]
+test_interop!(p521r1, sec2::p521r1);
+#[cfg(feature = "sm2")]
+test_interop!(sm2, sm2);
@@ -0,0 +1,147 @@
+// the boundary scalars 0, 1 and the group order, on every backend
+macro_rules! test_boundary {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{Point, Scalar};
+            use crate::params::sec2::$curve::ORDER_BYTES;
+
+            // add a small value to big endian bytes, without overflow
+            fn add_small(bytes: &[u8], n: u8) -> Vec<u8> {
+                let mut out = bytes.to_vec();
+                let mut carry = n as u16;
+                for b in out.iter_mut().rev() {
+                    let v = *b as u16 + carry;
+                    *b = v as u8;
+                    carry = v >> 8;
+                }
+                assert_eq!(carry, 0);
+                out
+            }
+
+            fn points() -> Vec<Point> {
+                let g = Point::generator();
+                vec![g.clone(), &g * &Scalar::from_u64(0x5eed), &g + &g]
+            }
+
+            #[test]
+            fn zero() {
+                assert!(Scalar::zero().to_bytes().iter().all(|b| *b == 0));
+                assert_eq!(Scalar::from_u64(0).to_bytes(), Scalar::zero().to_bytes());
+                for p in points() {
+                    assert_eq!(&p * &Scalar::zero(), Point::infinity());
+                    assert_eq!(&Scalar::from_u64(0) * &p, Point::infinity());
+                    assert!((&p * &Scalar::zero()).to_affine().is_none());
+                    assert_eq!(p.scale_bytes(&[]), Point::infinity());
+                    assert_eq!(p.scale_bytes(&[0; Scalar::SIZE_BYTES]), Point::infinity());
+                }
+            }
+
+            #[test]
+            fn one() {
+                for p in points() {
+                    let r = &p * &Scalar::one();
+                    assert_eq!(r, p);
+                    assert_eq!(r.to_affine(), p.to_affine());
+                    assert_eq!(&Scalar::from_u64(1) * &p, p);
+                    assert_eq!(p.scale_bytes(&[1]), p);
+                    assert_eq!(p.scale_bytes(&[0, 0, 0, 1]), p);
+                }
+            }
+
+            #[test]
+            fn order() {
+                let minus_one = (-Scalar::one()).to_bytes();
+                assert_eq!(add_small(&minus_one, 1)[..], ORDER_BYTES[..]);
+                let padded = [&[0u8; 8][..], &ORDER_BYTES[..]].concat();
+                for p in points() {
+                    assert_eq!(p.scale_bytes(&ORDER_BYTES), Point::infinity());
+                    #[cfg(not(feature = "paranoid"))]
+                    assert_eq!(p.scale_bytes(&padded), Point::infinity());
+                    assert_eq!(p.scale_bytes(&add_small(&ORDER_BYTES, 1)), p);
+                    assert_eq!(p.scale_bytes(&add_small(&ORDER_BYTES, 2)), &p + &p);
+                    assert_eq!(p.scale_bytes(&minus_one), -&p);
+                    assert_eq!(&p * &-Scalar::one(), -&p);
+                }
+            }
+
+            // exact: the size of a scalar, with values over the order reduced
+            // short: shorter inputs are zero extended
+            // boundary: up to 8 more bytes, which are meant to be zero padding
+            // long: anything longer is a debug assertion (and over the exact size with `paranoid`)
+            #[test]
+            fn lengths() {
+                let s = Scalar::from_u64(0x5eed);
+                let bytes = s.to_bytes();
+                let mut over = [0xffu8; Scalar::SIZE_BYTES];
+                over[0] = 0;
+                for p in points() {
+                    let expected = &p * &s;
+                    assert_eq!(p.scale_bytes_exact(&bytes), expected);
+                    assert_eq!(p.scale_bytes(&bytes), expected);
+                    assert_eq!(p.scale_bytes(&bytes[Scalar::SIZE_BYTES - 2..]), expected);
+                    assert_eq!(p.scale_bytes_exact(&over), p.scale_bytes(&over));
+                    #[cfg(not(feature = "paranoid"))]
+                    {
+                        let padded = [&[0u8; 8][..], &bytes[..]].concat();
+                        assert_eq!(p.scale_bytes(&padded), expected);
+                    }
+                }
+            }
+
+            #[cfg(debug_assertions)]
+            #[test]
+            #[should_panic(expected = "scale_bytes")]
+            fn too_long() {
+                Point::generator().scale_bytes(&[0; Scalar::SIZE_BYTES + 9]);
+            }
+
+            #[cfg(all(debug_assertions, feature = "paranoid"))]
+            #[test]
+            #[should_panic(expected = "paranoid")]
+            fn paranoid_too_long() {
+                Point::generator().scale_bytes(&[0; Scalar::SIZE_BYTES + 1]);
+            }
+
+            #[test]
+            fn infinity() {
+                let inf = Point::infinity();
+                let mut normalized = inf.clone();
+                normalized.normalize();
+                assert_eq!(normalized, inf);
+                for s in [Scalar::zero(), Scalar::one(), -Scalar::one()] {
+                    assert_eq!(&inf * &s, inf);
+                }
+                assert_eq!(inf.scale_bytes(&ORDER_BYTES), inf);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p112r2")]
+test_boundary!(p112r2);
+#[cfg(feature = "p128r1")]
+test_boundary!(p128r1);
+#[cfg(feature = "p128r2")]
+test_boundary!(p128r2);
+#[cfg(feature = "p160k1")]
+test_boundary!(p160k1);
+#[cfg(feature = "p160r1")]
+test_boundary!(p160r1);
+#[cfg(feature = "p160r2")]
+test_boundary!(p160r2);
+#[cfg(feature = "p192k1")]
+test_boundary!(p192k1);
+#[cfg(feature = "p192r1")]
+test_boundary!(p192r1);
+#[cfg(feature = "p224k1")]
+test_boundary!(p224k1);
+#[cfg(feature = "p224r1")]
+test_boundary!(p224r1);
+#[cfg(feature = "p256k1")]
+test_boundary!(p256k1);
+#[cfg(feature = "p256r1")]
+test_boundary!(p256r1);
+#[cfg(feature = "p384r1")]
+test_boundary!(p384r1);
+#[cfg(feature = "p521r1")]
+test_boundary!(p521r1);
@@ -0,0 +1,248 @@
+// the byte oriented facade agrees with the typed interface, and rejects invalid inputs
+macro_rules! test_bytes {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::bytes::*;
+            use crate::curve::sec2::$curve::{FieldElement, Point, PublicKey, Scalar, SecretKey};
+            use crate::params::sec2::$curve::ORDER_BYTES;
+
+            fn secret(n: u64) -> Vec<u8> {
+                Scalar::from_u64(n).to_bytes().to_vec()
+            }
+
+            fn public(n: u64) -> PublicKey {
+                SecretKey::from_slice(&secret(n)).unwrap().public_key()
+            }
+
+            fn compressed(n: u64) -> Vec<u8> {
+                public(n).to_sec1_bytes(true)
+            }
+
+            #[test]
+            fn valid() {
+                assert_eq!(COMPRESSED_SIZE, 1 + FieldElement::SIZE_BYTES);
+                let p = public(0x1234);
+                let c = compressed(0x1234);
+                let u = p.to_sec1_bytes(false);
+
+                assert_eq!(pubkey_create(&secret(0x1234)).unwrap()[..], c[..]);
+                for pk in [&c, &u].iter() {
+                    assert_eq!(
+                        pubkey_tweak_add(pk, &secret(5)).unwrap()[..],
+                        compressed(0x1234 + 5)[..]
+                    );
+                    assert_eq!(pubkey_tweak_add(pk, &secret(0)).unwrap()[..], c[..]);
+                    assert_eq!(
+                        point_add_bytes(pk, &compressed(7)).unwrap()[..],
+                        compressed(0x1234 + 7)[..]
+                    );
+                    assert_eq!(point_add_bytes(pk, pk).unwrap()[..], compressed(0x2468)[..]);
+                    let expected = (&Point::from_affine(p.as_point()) * &Scalar::from_u64(3))
+                        .to_affine()
+                        .map(PublicKey::from_point)
+                        .unwrap()
+                        .to_sec1_bytes(true);
+                    assert_eq!(scalar_mul_bytes(pk, &secret(3)).unwrap()[..], expected[..]);
+                }
+            }
+
+            #[test]
+            fn infinity_results() {
+                let c = compressed(9);
+                let neg = (-&Point::from_affine(public(9).as_point()))
+                    .to_affine()
+                    .unwrap();
+                let neg = PublicKey::from_point(neg).to_sec1_bytes(true);
+                assert_eq!(point_add_bytes(&c, &neg), None);
+                let minus_nine = (-Scalar::from_u64(9)).to_bytes();
+                assert_eq!(pubkey_tweak_add(&c, &minus_nine), None);
+            }
+
+            #[test]
+            fn invalid_lengths() {
+                let c = compressed(2);
+                let s = secret(2);
+                assert_eq!(pubkey_create(&s[1..]), None);
+                assert_eq!(pubkey_create(&[&[0u8][..], &s[..]].concat()), None);
+                assert_eq!(pubkey_create(&[]), None);
+                assert_eq!(pubkey_tweak_add(&c[..c.len() - 1], &s), None);
+                assert_eq!(pubkey_tweak_add(&c, &s[1..]), None);
+                assert_eq!(point_add_bytes(&c, &[]), None);
+                assert_eq!(point_add_bytes(&[&c[..], &[0u8][..]].concat(), &c), None);
+                assert_eq!(scalar_mul_bytes(&c, &[&s[..], &[0u8][..]].concat()), None);
+                assert_eq!(scalar_mul_bytes(&c[1..], &s), None);
+            }
+
+            #[test]
+            fn invalid_points() {
+                let s = secret(2);
+                let c = compressed(2);
+                // the point at infinity, an invalid tag, and an off curve uncompressed point
+                let mut bad = vec![vec![0u8], c.clone(), public(2).to_sec1_bytes(false)];
+                bad[1][0] = 0x05;
+                let last = bad[2].len() - 1;
+                bad[2][last] ^= 1;
+                for b in bad.iter() {
+                    assert_eq!(pubkey_tweak_add(b, &s), None);
+                    assert_eq!(point_add_bytes(b, &c), None);
+                    assert_eq!(point_add_bytes(&c, b), None);
+                    assert_eq!(scalar_mul_bytes(b, &s), None);
+                }
+            }
+
+            #[test]
+            fn invalid_scalars() {
+                let c = compressed(2);
+                let zero = secret(0);
+                let ones = vec![0xff; zero.len()];
+                let mut order = vec![0u8; zero.len() - ORDER_BYTES.len()];
+                order.extend_from_slice(&ORDER_BYTES);
+                assert_eq!(pubkey_create(&zero), None);
+                assert_eq!(scalar_mul_bytes(&c, &zero), None);
+                for s in [&order, &ones].iter() {
+                    assert_eq!(pubkey_create(s), None);
+                    assert_eq!(pubkey_tweak_add(&c, s), None);
+                    assert_eq!(scalar_mul_bytes(&c, s), None);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_bytes!(p192k1);
+#[cfg(feature = "p192r1")]
+test_bytes!(p192r1);
+#[cfg(feature = "p224k1")]
+test_bytes!(p224k1);
+#[cfg(feature = "p224r1")]
+test_bytes!(p224r1);
+#[cfg(feature = "p256k1")]
+test_bytes!(p256k1);
+#[cfg(feature = "p256r1")]
+test_bytes!(p256r1);
+#[cfg(feature = "p384r1")]
+test_bytes!(p384r1);
+#[cfg(feature = "p521r1")]
+test_bytes!(p521r1);
+
+// fixed vectors of the libsecp256k1 functions of the same names
+#[cfg(feature = "p256k1")]
+mod libsecp256k1 {
+    use crate::curve::sec2::p256k1::bytes::*;
+    use crate::tests::hex::from_hex;
+
+    const G: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const G2: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+    const G3: &str = "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+    const MINUS_G: &str = "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const G_UNCOMPRESSED: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+    const ORDER_MINUS_ONE: &str =
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140";
+
+    fn scalar(n: u8) -> [u8; 32] {
+        let mut s = [0u8; 32];
+        s[31] = n;
+        s
+    }
+
+    #[test]
+    fn vectors() {
+        let g = from_hex(G);
+        assert_eq!(pubkey_create(&scalar(1)).unwrap()[..], g[..]);
+        assert_eq!(pubkey_create(&scalar(2)).unwrap()[..], from_hex(G2)[..]);
+        assert_eq!(pubkey_create(&scalar(3)).unwrap()[..], from_hex(G3)[..]);
+        assert_eq!(
+            pubkey_create(&from_hex(ORDER_MINUS_ONE)).unwrap()[..],
+            from_hex(MINUS_G)[..]
+        );
+        let gu = from_hex(G_UNCOMPRESSED);
+        assert_eq!(
+            pubkey_tweak_add(&gu, &scalar(1)).unwrap()[..],
+            from_hex(G2)[..]
+        );
+        assert_eq!(
+            point_add_bytes(&g, &from_hex(G2)).unwrap()[..],
+            from_hex(G3)[..]
+        );
+        assert_eq!(
+            scalar_mul_bytes(&gu, &scalar(3)).unwrap()[..],
+            from_hex(G3)[..]
+        );
+        assert_eq!(point_add_bytes(&g, &from_hex(MINUS_G)), None);
+    }
+}
+
+// parsing scalars where zero is invalid, on the fiat and the bigint curves
+macro_rules! test_scalar_nonzero {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::Scalar;
+            use crate::params::sec2::$curve::ORDER_BYTES;
+
+            fn order() -> [u8; Scalar::SIZE_BYTES] {
+                let mut order = [0u8; Scalar::SIZE_BYTES];
+                order[Scalar::SIZE_BYTES - ORDER_BYTES.len()..].copy_from_slice(&ORDER_BYTES);
+                order
+            }
+
+            #[test]
+            fn nonzero() {
+                let zero = [0u8; Scalar::SIZE_BYTES];
+                let one = Scalar::from_u64(1).to_bytes();
+                let order = order();
+                let mut max = order;
+                // the orders are odd
+                *max.last_mut().unwrap() -= 1;
+                let ones = [0xffu8; Scalar::SIZE_BYTES];
+
+                assert!(Scalar::from_bytes_nonzero(&zero).is_none());
+                assert!(Scalar::from_bytes_in_range(&zero, true).unwrap().is_zero());
+                for valid in [one, max].iter() {
+                    let s = Scalar::from_bytes(valid).unwrap();
+                    assert!(Scalar::from_bytes_nonzero(valid) == Some(s.clone()));
+                    assert!(Scalar::from_bytes_in_range(valid, true) == Some(s.clone()));
+                    assert!(Scalar::from_slice_nonzero(&valid[..]) == Some(s));
+                    assert!(Scalar::from_slice_nonzero(&valid[1..]).is_none());
+                }
+                for invalid in [order, ones].iter() {
+                    assert!(Scalar::from_bytes_nonzero(invalid).is_none());
+                    assert!(Scalar::from_bytes_in_range(invalid, true).is_none());
+                    assert!(Scalar::from_slice_nonzero(&invalid[..]).is_none());
+                }
+                assert!(Scalar::from_slice_nonzero(&zero[..]).is_none());
+            }
+        }
+    };
+}
+
+mod scalar_nonzero {
+    #[cfg(feature = "p112r2")]
+    test_scalar_nonzero!(p112r2);
+    #[cfg(feature = "p128r1")]
+    test_scalar_nonzero!(p128r1);
+    #[cfg(feature = "p128r2")]
+    test_scalar_nonzero!(p128r2);
+    #[cfg(feature = "p160k1")]
+    test_scalar_nonzero!(p160k1);
+    #[cfg(feature = "p160r1")]
+    test_scalar_nonzero!(p160r1);
+    #[cfg(feature = "p160r2")]
+    test_scalar_nonzero!(p160r2);
+    #[cfg(feature = "p192k1")]
+    test_scalar_nonzero!(p192k1);
+    #[cfg(feature = "p192r1")]
+    test_scalar_nonzero!(p192r1);
+    #[cfg(feature = "p224k1")]
+    test_scalar_nonzero!(p224k1);
+    #[cfg(feature = "p224r1")]
+    test_scalar_nonzero!(p224r1);
+    #[cfg(feature = "p256k1")]
+    test_scalar_nonzero!(p256k1);
+    #[cfg(feature = "p256r1")]
+    test_scalar_nonzero!(p256r1);
+    #[cfg(feature = "p384r1")]
+    test_scalar_nonzero!(p384r1);
+    #[cfg(feature = "p521r1")]
+    test_scalar_nonzero!(p521r1);
+}
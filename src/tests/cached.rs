@@ -0,0 +1,108 @@
+macro_rules! test_cached_point {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{
+                CachedPoint, FieldElement, Point, PointAffine, Scalar,
+            };
+            use crate::curve::Sign;
+            use std::collections::HashSet;
+
+            fn points() -> Vec<PointAffine> {
+                [1u64, 2, 3, 0x1234, 0xdeadbeef]
+                    .iter()
+                    .map(|n| {
+                        (&Point::generator() * &Scalar::from_u64(*n))
+                            .to_affine()
+                            .unwrap()
+                    })
+                    .chain((&Point::generator() * &-Scalar::one()).to_affine())
+                    .collect()
+            }
+
+            fn fresh(p: &PointAffine) -> Vec<u8> {
+                let (x, sign) = p.compress();
+                let tag = match sign {
+                    Sign::Positive => 0x02,
+                    Sign::Negative => 0x03,
+                };
+                [&[tag][..], &x.to_bytes()[..]].concat()
+            }
+
+            fn uncompressed(p: &PointAffine) -> Vec<u8> {
+                let (x, y) = p.to_coordinate();
+                [&[0x04][..], &x.to_bytes()[..], &y.to_bytes()[..]].concat()
+            }
+
+            #[test]
+            fn compressed_bytes() {
+                for p in points() {
+                    let cached = CachedPoint::new(p.clone());
+                    assert_eq!(&cached.compressed_bytes()[..], &fresh(&p)[..]);
+                    // second call, and clones, give the same bytes
+                    assert_eq!(&cached.compressed_bytes()[..], &fresh(&p)[..]);
+                    assert_eq!(&cached.clone().compressed_bytes()[..], &fresh(&p)[..]);
+                    assert_eq!(cached.as_point(), &p);
+                    assert_eq!(PointAffine::from(cached), p);
+                }
+            }
+
+            #[test]
+            fn from_sec1_bytes() {
+                for p in points() {
+                    for bytes in [fresh(&p), uncompressed(&p)] {
+                        let cached = CachedPoint::from_sec1_bytes(&bytes).unwrap();
+                        assert_eq!(cached.as_point(), &p);
+                        assert_eq!(&cached.compressed_bytes()[..], &fresh(&p)[..]);
+                        assert_eq!(cached, CachedPoint::new(p.clone()));
+                    }
+                }
+                assert_eq!(CachedPoint::from_sec1_bytes(&[]), None);
+                assert_eq!(CachedPoint::from_sec1_bytes(&[0x00]), None);
+                let x = FieldElement::zero().to_bytes();
+                assert_eq!(
+                    CachedPoint::from_sec1_bytes(&[&[0x05][..], &x[..]].concat()),
+                    None
+                );
+            }
+
+            // the encoding cache is interior mutability, but it only caches
+            // the bytes used for hashing, which never change
+            #[allow(clippy::mutable_key_type)]
+            #[test]
+            fn equality_and_hash() {
+                let points = points();
+                let mut set = HashSet::new();
+                for p in points.iter() {
+                    assert!(set.insert(CachedPoint::new(p.clone())));
+                }
+                for (i, p) in points.iter().enumerate() {
+                    let cached = CachedPoint::from(p.clone());
+                    assert!(!set.insert(cached.clone()));
+                    assert!(set.contains(&CachedPoint::from_sec1_bytes(&uncompressed(p)).unwrap()));
+                    for (j, q) in points.iter().enumerate() {
+                        assert_eq!(cached == CachedPoint::new(q.clone()), p == q);
+                        assert_eq!(i == j, p == q);
+                    }
+                }
+                assert_eq!(set.len(), points.len());
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_cached_point!(p192k1);
+#[cfg(feature = "p192r1")]
+test_cached_point!(p192r1);
+#[cfg(feature = "p224k1")]
+test_cached_point!(p224k1);
+#[cfg(feature = "p224r1")]
+test_cached_point!(p224r1);
+#[cfg(feature = "p256k1")]
+test_cached_point!(p256k1);
+#[cfg(feature = "p256r1")]
+test_cached_point!(p256r1);
+#[cfg(feature = "p384r1")]
+test_cached_point!(p384r1);
+#[cfg(feature = "p521r1")]
+test_cached_point!(p521r1);
@@ -0,0 +1,146 @@
+//! Clamped 32 bytes scalars
+
+use crate::curve::clamp::ClampPolicy;
+use crate::tests::hex::from_hex;
+use std::convert::TryInto;
+
+fn bytes32(s: &str) -> [u8; 32] {
+    from_hex(s).try_into().unwrap()
+}
+
+// the scalar of the first X25519 test vector of RFC 7748 (section 5.2)
+const RFC7748_SCALAR: &str = "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4";
+
+#[test]
+fn x25519_clamp() {
+    let clamped = ClampPolicy::X25519.clamp(&bytes32(RFC7748_SCALAR));
+    assert_eq!(
+        clamped,
+        bytes32("449a44ba44226a50185afcc10a4c1462dd5e46824b15163b9d7c52f06be346a0")
+    );
+    assert_eq!(clamped[31] & 0x07, 0);
+    assert_eq!(clamped[0] & 0xc0, 0x40);
+
+    // the 5 fixed bits: the 32 inputs differing only by them are clamped to the same value
+    let mut other = bytes32(RFC7748_SCALAR);
+    other[0] ^= 0x07;
+    other[31] ^= 0xc0;
+    assert_eq!(ClampPolicy::X25519.clamp(&other), clamped);
+
+    assert_eq!(
+        ClampPolicy::X25519.clamp(&[0; 32]),
+        bytes32("4000000000000000000000000000000000000000000000000000000000000000")
+    );
+    assert_eq!(
+        ClampPolicy::X25519.clamp(&[0xff; 32]),
+        bytes32("7ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff8")
+    );
+}
+
+#[test]
+fn idempotent() {
+    let policies = [
+        ClampPolicy::X25519,
+        ClampPolicy::NONE,
+        ClampPolicy {
+            little_endian: false,
+            clear_low_bits: 8,
+            clear_bit_255: false,
+            set_bit_254: true,
+        },
+    ];
+    let inputs = [[0u8; 32], [0xff; 32], bytes32(RFC7748_SCALAR)];
+    for policy in policies.iter() {
+        for input in inputs.iter() {
+            let once = policy.clamp(input);
+            assert_eq!(policy.big_endian().clamp(&once), once, "{:?}", policy);
+        }
+    }
+    assert_eq!(ClampPolicy::NONE.clamp(&[0x5a; 32]), [0x5a; 32]);
+}
+
+#[test]
+#[should_panic]
+fn too_many_low_bits() {
+    let policy = ClampPolicy {
+        clear_low_bits: 9,
+        ..ClampPolicy::NONE
+    };
+    policy.clamp(&[0; 32]);
+}
+
+#[cfg(feature = "p256k1")]
+#[test]
+fn p256k1_vectors() {
+    use crate::curve::sec2::p256k1::Scalar;
+
+    let input = bytes32(RFC7748_SCALAR);
+    // the X25519 clamped values are between 2^254 and 2^255, below the order
+    let clamped = ClampPolicy::X25519.clamp(&input);
+    let s = Scalar::from_bytes_clamped(&input, ClampPolicy::X25519);
+    assert_eq!(Some(s.clone()), Scalar::from_bytes(&clamped));
+    assert_eq!(s.to_bytes(), clamped);
+
+    // unclamped, the big endian value is below the order and kept as is
+    let s = Scalar::from_bytes_clamped(&input, ClampPolicy::NONE);
+    assert_eq!(Some(s), Scalar::from_bytes(&input));
+
+    // while the all ones value is above it, and reduced
+    assert!(Scalar::from_bytes(&[0xff; 32]).is_none());
+    let s = Scalar::from_bytes_clamped(&[0xff; 32], ClampPolicy::NONE);
+    assert_eq!(
+        s.to_bytes(),
+        bytes32("000000000000000000000000000000014551231950b75fc4402da1732fc9bebe")
+    );
+    assert!(Scalar::from_bytes_clamped(&[0; 32], ClampPolicy::NONE).is_zero());
+}
+
+#[cfg(feature = "p192r1")]
+#[test]
+fn p192r1_vectors() {
+    use crate::curve::sec2::p192r1::Scalar;
+
+    // 256 bits clamped values are always reduced modulo the 192 bits order
+    let s = Scalar::from_bytes_clamped(&bytes32(RFC7748_SCALAR), ClampPolicy::X25519);
+    assert_eq!(
+        s.to_bytes()[..],
+        [
+            0x18, 0x5a, 0xfc, 0xc1, 0x25, 0xaa, 0x65, 0xbe, 0x44, 0xa9, 0x28, 0x3e, 0x8d, 0x33,
+            0x70, 0xbe, 0x37, 0xcf, 0xa8, 0x58, 0x8b, 0x10, 0x6d, 0x50
+        ]
+    );
+}
+
+macro_rules! test_clamped {
+    ($curve:ident) => {
+        mod $curve {
+            use super::{bytes32, RFC7748_SCALAR};
+            use crate::curve::clamp::ClampPolicy;
+            use crate::curve::sec2::$curve::Scalar;
+
+            #[test]
+            fn reduction() {
+                let inputs = [[0u8; 32], [0xff; 32], [0x80; 32], bytes32(RFC7748_SCALAR)];
+                for input in inputs.iter() {
+                    for policy in [ClampPolicy::X25519, ClampPolicy::NONE].iter() {
+                        let clamped = policy.clamp(input);
+                        let s = Scalar::from_bytes_clamped(input, *policy);
+                        assert_eq!(s, Scalar::from_be_bytes_mod_p(&clamped));
+                        // the clamped bytes give the same scalar
+                        let again = Scalar::from_bytes_clamped(&clamped, policy.big_endian());
+                        assert_eq!(again, s);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192r1")]
+test_clamped!(p192r1);
+#[cfg(feature = "p256k1")]
+test_clamped!(p256k1);
+#[cfg(feature = "p256r1")]
+test_clamped!(p256r1);
+#[cfg(feature = "p521r1")]
+test_clamped!(p521r1);
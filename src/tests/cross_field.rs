@@ -0,0 +1,173 @@
+//! Conversions between the field elements and the scalars of the same value
+
+// big endian comparison of the values, the arrays possibly of different sizes
+fn less_than(a: &[u8], b: &[u8]) -> bool {
+    let strip = |x: &[u8]| {
+        x.iter()
+            .skip_while(|b| **b == 0)
+            .cloned()
+            .collect::<Vec<u8>>()
+    };
+    let (a, b) = (strip(a), strip(b));
+    a.len() < b.len() || (a.len() == b.len() && a < b)
+}
+
+macro_rules! test_cross_field {
+    ($curve:ident, $module:path, $params:path) => {
+        mod $curve {
+            use super::less_than;
+            use curve::{FieldElement, Scalar};
+            use $module as curve;
+            use $params as params;
+
+            fn elements() -> Vec<FieldElement> {
+                let mut v = vec![
+                    FieldElement::zero(),
+                    FieldElement::one(),
+                    -FieldElement::one(),
+                ];
+                let mut x = FieldElement::from_u64(0x5eed);
+                for i in 0..8 {
+                    x = x.square() + FieldElement::from_u64(i);
+                    v.push(x.clone());
+                }
+                v
+            }
+
+            #[test]
+            fn from_field_reduced() {
+                for x in elements().iter() {
+                    let (s, reduced) = Scalar::from_field_reduced(x);
+                    assert_eq!(s, Scalar::from_be_bytes_mod_p(&x.to_bytes()));
+                    let at_least_order = !less_than(&x.to_bytes(), &params::ORDER_BYTES);
+                    assert_eq!(reduced.is_true(), at_least_order, "{}", x);
+                    if !reduced.is_true() {
+                        let back = FieldElement::try_from_scalar_exact(&s).into_option();
+                        assert_eq!(back.as_ref(), Some(x));
+                    }
+                }
+                // p - 1 is reduced iff the order is less than p
+                let order_below_p = less_than(&params::ORDER_BYTES, &params::P_BYTES);
+                let (_, reduced) = Scalar::from_field_reduced(&-FieldElement::one());
+                assert_eq!(reduced.is_true(), order_below_p);
+            }
+
+            #[test]
+            fn try_from_scalar_exact() {
+                let order_below_p = less_than(&params::ORDER_BYTES, &params::P_BYTES);
+                let mut s = Scalar::from_u64(0x5eed);
+                for i in 0..8 {
+                    s = s.square() + Scalar::from_u64(i);
+                    if let Some(x) = FieldElement::try_from_scalar_exact(&s).into_option() {
+                        let (r, reduced) = Scalar::from_field_reduced(&x);
+                        assert_eq!(r, s);
+                        assert!(!reduced.is_true());
+                    }
+                }
+                for s in [Scalar::zero(), Scalar::one()].iter() {
+                    let x = FieldElement::try_from_scalar_exact(s)
+                        .into_option()
+                        .unwrap();
+                    assert_eq!(Scalar::from_be_bytes_mod_p(&x.to_bytes()), *s);
+                }
+                // n - 1 is a field element iff the order is not greater than p
+                let max = FieldElement::try_from_scalar_exact(&-Scalar::one());
+                assert_eq!(max.is_some().is_true(), order_below_p);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_cross_field!(
+    p192k1,
+    crate::curve::sec2::p192k1,
+    crate::params::sec2::p192k1
+);
+#[cfg(feature = "p192r1")]
+test_cross_field!(
+    p192r1,
+    crate::curve::sec2::p192r1,
+    crate::params::sec2::p192r1
+);
+#[cfg(feature = "p224k1")]
+test_cross_field!(
+    p224k1,
+    crate::curve::sec2::p224k1,
+    crate::params::sec2::p224k1
+);
+#[cfg(feature = "p224r1")]
+test_cross_field!(
+    p224r1,
+    crate::curve::sec2::p224r1,
+    crate::params::sec2::p224r1
+);
+#[cfg(feature = "p256k1")]
+test_cross_field!(
+    p256k1,
+    crate::curve::sec2::p256k1,
+    crate::params::sec2::p256k1
+);
+#[cfg(feature = "p256r1")]
+test_cross_field!(
+    p256r1,
+    crate::curve::sec2::p256r1,
+    crate::params::sec2::p256r1
+);
+#[cfg(feature = "p384r1")]
+test_cross_field!(
+    p384r1,
+    crate::curve::sec2::p384r1,
+    crate::params::sec2::p384r1
+);
+#[cfg(feature = "p521r1")]
+test_cross_field!(
+    p521r1,
+    crate::curve::sec2::p521r1,
+    crate::params::sec2::p521r1
+);
+#[cfg(feature = "sm2")]
+test_cross_field!(sm2, crate::curve::sm2, crate::params::sm2);
+
+// the window n <= x < p, built from the params constants, of the field elements that are
+// reduced (e.g. the X coordinates x of R for which the ECDSA recovery tries x + n)
+macro_rules! test_order_window {
+    ($curve:ident) => {
+        #[test]
+        fn $curve() {
+            use crate::curve::sec2::$curve::{FieldElement, Scalar};
+            use crate::params::sec2::$curve::{ORDER_BYTES, P_BYTES};
+
+            let n = FieldElement::from_bytes(&ORDER_BYTES).unwrap();
+            // p mod n is p - n, since n < p < 2n
+            let p_mod_n = Scalar::from_be_bytes_mod_p(&P_BYTES);
+            let one = FieldElement::one();
+            let cases = [
+                (&n - &one, -Scalar::one(), false),
+                (n.clone(), Scalar::zero(), true),
+                (&n + &one, Scalar::one(), true),
+                (-&one, &p_mod_n - &Scalar::one(), true),
+            ];
+            for (x, s, reduced) in cases.iter() {
+                let (r, c) = Scalar::from_field_reduced(x);
+                assert_eq!((&r, c.is_true()), (s, *reduced), "{}", x);
+                // the value of the scalar is x - n when reduced
+                let back = FieldElement::try_from_scalar_exact(s)
+                    .into_option()
+                    .unwrap();
+                if *reduced {
+                    assert_eq!(&back + &n, *x);
+                } else {
+                    assert_eq!(back, *x);
+                }
+            }
+        }
+    };
+}
+
+mod order_window {
+    #[cfg(feature = "p256k1")]
+    test_order_window!(p256k1);
+    #[cfg(feature = "p256r1")]
+    test_order_window!(p256r1);
+}
@@ -0,0 +1,77 @@
+use crate::curve::affine;
+use crate::curve::projective::Point;
+use crate::curve::sec2::p256r1::{Curve, FieldElement};
+use crate::mp::ct::{Choice, CtSelect};
+use crate::mp::ct_util::ct_table_lookup;
+use std::cell::Cell;
+
+fn bits(index: usize, n: usize) -> Vec<Choice> {
+    (0..n)
+        .map(|i| Choice::from_bit(((index >> i) & 1) as u64))
+        .collect()
+}
+
+#[test]
+fn field_elements() {
+    let table: Vec<FieldElement> = (0..16).map(|i| FieldElement::from_u64(i * 7 + 3)).collect();
+    for (i, expected) in table.iter().enumerate() {
+        assert_eq!(&ct_table_lookup(&table, &bits(i, 4)), expected);
+    }
+}
+
+#[test]
+fn points() {
+    let (gx, gy) = Curve::generator();
+    let g = Point::from_affine(&affine::Point::new_unchecked(gx.clone(), gy.clone()));
+    let mut table = vec![Point::<FieldElement>::infinity()];
+    for i in 1..16 {
+        table.push(table[i - 1].add_or_double(&g, Curve));
+    }
+    for (i, expected) in table.iter().enumerate() {
+        assert_eq!(&ct_table_lookup(&table, &bits(i, 4)), expected);
+    }
+}
+
+#[test]
+fn single_entry() {
+    let table = [FieldElement::one()];
+    assert_eq!(ct_table_lookup(&table, &[]), FieldElement::one());
+}
+
+thread_local! {
+    static SELECTS: Cell<usize> = const { Cell::new(0) };
+}
+
+// a table entry counting the select operations
+#[derive(Clone)]
+struct Counted(u64);
+
+impl CtSelect for Counted {
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        SELECTS.with(|c| c.set(c.get() + 1));
+        let mask = choice.to_mask_u64();
+        Counted(a.0 ^ (mask & (a.0 ^ b.0)))
+    }
+}
+
+#[test]
+fn select_count_independent_of_index() {
+    let table: Vec<Counted> = (0..16).map(|i| Counted(i * 11)).collect();
+    for i in 0..16 {
+        SELECTS.with(|c| c.set(0));
+        let r = ct_table_lookup(&table, &bits(i, 4));
+        assert_eq!(r.0, i as u64 * 11);
+        assert_eq!(SELECTS.with(|c| c.get()), 15);
+    }
+}
+
+#[test]
+#[should_panic]
+fn size_mismatch() {
+    let table = [
+        FieldElement::one(),
+        FieldElement::zero(),
+        FieldElement::one(),
+    ];
+    ct_table_lookup(&table, &bits(1, 2));
+}
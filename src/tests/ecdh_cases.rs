@@ -0,0 +1,84 @@
+//! ECDH point decoding and shared secret edge cases
+//!
+//! Each case gives a SEC1 encoded public point, a private key as an ASN.1 integer
+//! in hex, and the expected X coordinate of the shared point. The cases of
+//! `ecdh_cases_data.rs` are generated by `tools/ecdh_vectors.py`, the expected results
+//! being computed by OpenSSL (through python cryptography).
+//!
+//! The public point goes through `PointAffine::from_sec1_bytes` (encoding, canonical
+//! coordinates and on curve validation), then the shared secret through the scalar
+//! multiplication. Valid cases need to produce the expected shared secret, invalid
+//! cases to be rejected, and acceptable cases (e.g. compressed points) either.
+
+use super::ecdh_cases_data::*;
+use crate::tests::hex::from_hex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expect {
+    Valid,
+    Acceptable,
+    Invalid,
+}
+
+#[derive(Debug, Clone)]
+pub struct EcdhCase {
+    pub tc_id: usize,
+    pub comment: &'static str,
+    pub public: &'static str,
+    pub private: &'static str,
+    pub shared: &'static str,
+    pub result: Expect,
+}
+
+// ASN.1 integers are minimally encoded, with a leading zero byte when the top bit is set
+fn private_bytes(s: &str, size: usize) -> Vec<u8> {
+    let bytes = from_hex(s);
+    let skip = bytes.iter().take_while(|b| **b == 0).count();
+    let bytes = &bytes[skip..];
+    assert!(bytes.len() <= size, "private key too long");
+    let mut out = vec![0u8; size - bytes.len()];
+    out.extend_from_slice(bytes);
+    out
+}
+
+macro_rules! test_ecdh_cases {
+    ($curve: ident, $cases: ident) => {
+        #[test]
+        fn $curve() {
+            use crate::curve::sec2::$curve::{Point, PointAffine, Scalar};
+
+            for case in $cases.iter() {
+                let d = Scalar::from_slice(&private_bytes(case.private, Scalar::SIZE_BYTES))
+                    .expect("private key in range");
+                let shared = PointAffine::from_sec1_bytes(&from_hex(case.public))
+                    .and_then(|q| (&Point::from(&q) * &d).to_affine())
+                    .map(|p| p.to_coordinate().0.to_bytes().to_vec());
+                match (case.result, shared) {
+                    (Expect::Invalid, None) | (Expect::Acceptable, None) => {}
+                    (Expect::Valid, Some(shared)) | (Expect::Acceptable, Some(shared)) => {
+                        assert_eq!(
+                            shared,
+                            from_hex(case.shared),
+                            "tc {}: {}",
+                            case.tc_id,
+                            case.comment
+                        )
+                    }
+                    (expected, got) => panic!(
+                        "tc {}: {}: expected {:?} got {:?}",
+                        case.tc_id, case.comment, expected, got
+                    ),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p256k1")]
+test_ecdh_cases!(p256k1, P256K1);
+#[cfg(feature = "p256r1")]
+test_ecdh_cases!(p256r1, P256R1);
+#[cfg(feature = "p384r1")]
+test_ecdh_cases!(p384r1, P384R1);
+#[cfg(feature = "p521r1")]
+test_ecdh_cases!(p521r1, P521R1);
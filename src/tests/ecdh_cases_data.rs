@@ -0,0 +1,699 @@
+// generated by tools/ecdh_vectors.py, do not edit
+
+use super::ecdh_cases::{EcdhCase, Expect};
+
+// local edge cases, expected results computed by OpenSSL (python cryptography)
+pub const P256R1: [EcdhCase; 21] = [
+    EcdhCase {
+        tc_id: 1,
+        comment: "normal case",
+        public: "04b4f448bb0f569fddf7b6e8a5fe1eaf095ff68756e967e78bb7494e0b6fc940ef8ecbd4d6328baa3f9cfcae0f585ad808b3d885c56436b26d36e1b7ac46e986bc",
+        private: "0085c9edf2d349e774fa118576b86a757158b586b52c0009ddb0edfc7c4445eee7",
+        shared: "a71ed1c2b1dc15ed4c709b032cb1ba7b0f993b2c927e1040f95b5c1f73c4da84",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 2,
+        comment: "normal case",
+        public: "042e69d0d5d175bdef3e86f2d417acdf155dad41567e37b6b0b3673bc544f3b72d773b9b9973ad492842c5d341c01b62449502f9da438d98753ed1cce5f12ba59c",
+        private: "00fd10658597d6d4e4bb9317afaf87c7a260068b0bf0f1664dcd3af6c647031a25",
+        shared: "834aa897d4742739db448b956df605833a86dbf69813cd8fd1315d1cd3ac367f",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 3,
+        comment: "normal case",
+        public: "043ea39e31ffe0a2480faa2e477e2e4c3cfc0c0677ea400c1602ca959df1503bdda89e6d40320e7fb660c9b7eee0a9b30c9d92efbf8d72ec48ec6fb85f82e66844",
+        private: "00b48b6bc0c5d1daa22399910db629af17e978e488862cd0e6e03a98f89066167e",
+        shared: "6ad3f2d1edd3e22483c23955f25867894f430faed82a57ab28ebbe5561370975",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 4,
+        comment: "compressed public key",
+        public: "0304d3d0dfb802ec8d5f9679c83c4994b3b6618552bc9eb7cfe796c8380c22a667",
+        private: "719c75658bfb4bfe8bdbee232464ddd8341cb477642f97683b62f55053a094ed",
+        shared: "c41b3553a56723ef7d0c94d6ef4fe898ddb9e1bec6036cfd6bb7883101090562",
+        result: Expect::Acceptable,
+    },
+    EcdhCase {
+        tc_id: 5,
+        comment: "shared secret has a leading zero byte",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "00ac19ab4e90b05cec3d31711d07b0261a4fc6c776137ab2a0e5cdfc945e851295",
+        shared: "005021998b7a9e118ae98dd731f3b7de265ee17ada54406fbb0f059354e45ca4",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 6,
+        comment: "private key 1",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "01",
+        shared: "a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd5",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 7,
+        comment: "private key 2",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "02",
+        shared: "524acd2f321eaf1a54b5054462b73ee5ac7adfbaa2e26c1abf6195d773ece6b2",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 8,
+        comment: "private key n-2",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "00ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254f",
+        shared: "524acd2f321eaf1a54b5054462b73ee5ac7adfbaa2e26c1abf6195d773ece6b2",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 9,
+        comment: "private key n-1",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "00ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550",
+        shared: "a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd5",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 10,
+        comment: "private key with leading zero bytes",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "1234",
+        shared: "4dcfc7b70b8f7ccb752e2ec4cc99124b0f0db1777b13c3df55f16ee797f4cc2c",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 11,
+        comment: "point is not on the curve",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0f",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 12,
+        comment: "x is the abscissa of a point on the quadratic twist",
+        public: "020000000000000000000000000000000000000000000000000000000000000001",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 13,
+        comment: "point on the quadratic twist with an uncompressed encoding",
+        public: "0400000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 14,
+        comment: "x coordinate not reduced modulo p",
+        public: "04ffffffff00000001000000000000000000000001000000000000000000000004459243b9aa581806fe913bce99817ade11ca503c64d9a3c533415c083248fbcc",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 15,
+        comment: "compressed x coordinate not reduced modulo p",
+        public: "02ffffffff00000001000000000000000000000001000000000000000000000004",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 16,
+        comment: "point at infinity",
+        public: "00",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 17,
+        comment: "empty public key",
+        public: "",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 18,
+        comment: "truncated uncompressed public key",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 19,
+        comment: "uncompressed public key with trailing byte",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e00",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 20,
+        comment: "compressed public key with uncompressed prefix",
+        public: "04a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd5",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 21,
+        comment: "uncompressed public key with compressed prefix",
+        public: "02a08d92e013dcf7953a89af6fe60ef64339283f3c2dca63a8bceb8abf90a9efd55dae1b50f8cac32526471acdfd960eea49ae06961ddf4b472ad940f2911dcf0e",
+        private: "00eac4b05114ca0a0ab37145ecc35aabf20e65e507e8a55259345f27d0951f689a",
+        shared: "",
+        result: Expect::Invalid,
+    },
+];
+
+// local edge cases, expected results computed by OpenSSL (python cryptography)
+pub const P384R1: [EcdhCase; 21] = [
+    EcdhCase {
+        tc_id: 1,
+        comment: "normal case",
+        public: "041290427d0e03493ab296988046a0385833427f2b13fe57e874c3c4dc3bc6a55e427d1f65401dfc35c2faa81cf6835a066839835d827bc00da338ddbe0a5942462d083981e5a93abf780cdb60d46bab771f42615e72e747d51a5ee2aa5b69734a",
+        private: "00ac322f617f72da4916b3dd79feb357c3948c240599c52a9e7038954167a39eee6bd8d5d2d998aed7eddbce44f8855339",
+        shared: "19fdaad1161af18dfa70df6cbe623f313e94b9ce088aa48d459b397ea217acab3a2995899aa0a02b5133f1f5a5ee7331",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 2,
+        comment: "normal case",
+        public: "043c2606233f83f8cc256098a225ec475f0f3d4e4d63f7ac4df06d214717407ed3177e3b7e569d4b3d1eab246bcff75603810a7214ddb7d5512487262630f8200dd85d63892541d484ac06adee304ccafbcd9cbbe6a46fafa19af2354a6815623f",
+        private: "2f9f8275fa07a13638dceb3ce75f2ba0ff7be0945d384b6c9c8c41cbe63bde11d65abf202407c99c1b64c323f29f6634",
+        shared: "20fb43e3c0ad91f35c1146cab80a528c7af68dbd10e1bf15cf0d20bcee7d39b05d54f8c1e571c1153fb181736f1969bd",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 3,
+        comment: "normal case",
+        public: "040bcfa2d0b0e869ead91d396ccb0143b44065753bbadd8c10f3646629f8d6fa4cb25d7775a233ff7e706e9302b4d91f0e6714edd1353f2203eccd71d339d9e074e1893671c122f2273020aa60eb48d1c0ec94a8ae8eb495c11a03d5a7e9525b36",
+        private: "0093aab2b735fb90723cbdf08c2ed1f2b9052120114e894abc288f8ca4c7060f48faa19882aaf4bcceab1fdb99e8546f53",
+        shared: "292b7877cf9c9b397752814c8588e99d21ff72206cbe82e9069e6f435be9125fa714a3279e89f2ca135bb8416064ac6d",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 4,
+        comment: "compressed public key",
+        public: "02e46c8422ba2777119a3cffec77f1fcefd92f56e47fec8c7f1a3d3aa627209c64faf0cbc8e187e26ba33171db375b3f44",
+        private: "0094e7d30b84f9ee292658461a3b61b879c2ecb94d30c552e0c52e48247ed763aee4bda56b1e84b2f993f741e8c810fa55",
+        shared: "ec9409355114226eb7c5301a78876d1a8159f746d46f67910bd2cf68f6ab362195d8f430e8e36e8bbda6f18362d107ed",
+        result: Expect::Acceptable,
+    },
+    EcdhCase {
+        tc_id: 5,
+        comment: "shared secret has a leading zero byte",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "00d1e6fd74c978a97fb0f3ddc79da22aad599f40718db415ee07c797a4c72246efa0bea5fb93ba34648101cf1f4da5eae8",
+        shared: "0078cb776910e3853f89fe453adca7b70e2150e67f6914613daf3298a257e3bc7933f63e8e3f001a2e89198c0cf56a7a",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 6,
+        comment: "private key 1",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "01",
+        shared: "b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e3",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 7,
+        comment: "private key 2",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "02",
+        shared: "d4f0e61c22cc9aeebdc46e4edd36709c6a73e2a92608e3215c99c977b9b28684edf15b3b395da087247a3452fc2bc897",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 8,
+        comment: "private key n-2",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "00ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52971",
+        shared: "d4f0e61c22cc9aeebdc46e4edd36709c6a73e2a92608e3215c99c977b9b28684edf15b3b395da087247a3452fc2bc897",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 9,
+        comment: "private key n-1",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "00ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52972",
+        shared: "b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e3",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 10,
+        comment: "private key with leading zero bytes",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "1234",
+        shared: "94d55c1163f16e757a2ac36b39978ff01ea2f537f6e97aab155bdbdb15f2a9538efedbcc0eca6127f94f214fad61d5d9",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 11,
+        comment: "point is not on the curve",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c6",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 12,
+        comment: "x is the abscissa of a point on the quadratic twist",
+        public: "02000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 13,
+        comment: "point on the quadratic twist with an uncompressed encoding",
+        public: "04000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 14,
+        comment: "x coordinate not reduced modulo p",
+        public: "04fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000001000000018cdeadbbd04911a3c1931e26df3fa6439dca9c7eb286fbd46fc319f0e2bb780232baf57825fc0c1912ada2fefe84024c",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 15,
+        comment: "compressed x coordinate not reduced modulo p",
+        public: "02fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff000000000000000100000001",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 16,
+        comment: "point at infinity",
+        public: "00",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 17,
+        comment: "empty public key",
+        public: "",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 18,
+        comment: "truncated uncompressed public key",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 19,
+        comment: "uncompressed public key with trailing byte",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c500",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 20,
+        comment: "compressed public key with uncompressed prefix",
+        public: "04b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e3",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 21,
+        comment: "uncompressed public key with compressed prefix",
+        public: "02b59fa5aa4d67ecf850e6f979114c0932c29476e2f1696c985f00a3acb39f207c03ce04494892768dc8cb5c6d7c21b7e31a612c0c42bb5ce2010409784ea7e17775b77661241494b6b2ced57434fbd6a57555321414f62f2d6336306c830756c5",
+        private: "31e4c68acae4e33df4515afccdd412ee391078c4b09d93b51768722cb105fc4fc177928c55e9a85df34d07a10f4a62f6",
+        shared: "",
+        result: Expect::Invalid,
+    },
+];
+
+// local edge cases, expected results computed by OpenSSL (python cryptography)
+pub const P521R1: [EcdhCase; 22] = [
+    EcdhCase {
+        tc_id: 1,
+        comment: "normal case",
+        public: "040056e063ff69513b747c1b01520f5110ff9b5346c3101054664a9ee0434aed49e864a798c0f0fb812243cf714aafaa479c5b20b802ad6a027698c63a6dfbc61474d801490ce8be085791b2989a5a95f520db71b62889f78963b97f2b5918192534f0fa5b2b5d5c6be06f0ef2dba12631cac2fd06a76115248cb44f079d62044f379e578d",
+        private: "504e2ba1ae86ca73607a78a408ae03b9d83e7af4a8adfe09661a05ae568a501e196ed22fed0c3f8bcb57d44eeb571973891cc9039120cd672f9c7de02254a623",
+        shared: "00361b27acaf30f1539f41dbb8b30bad614684e37036c56df8b4a4f9bc6ec44871730390173867cf1891dc12a84fdbbd9ed6ebbcaf8da3c6a9396c519473e7d3fb46",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 2,
+        comment: "normal case",
+        public: "04001e3477698fb88c8e50625379aaf43a1eefa31602c49bd585ef8571c3d26247003addd11996e42fd3573b71e7fc2f1ef28da2795f1bac24f346005ac42584a9192401f36197395a7ad43d1e83f6e27657e1cf94d020c1b884004bb66b85ab615cfe17eeba549f97459eb0a8d71100bc8ae582bc2231d8e2f85f87f01ab6f33bc38a3171",
+        private: "2b9fd3e7c2e5546aaaad33bd06aef3a8cecf3c1c82e148aff6839fc3c06986c1cb18d17820c05425eda5d0322ffe2c64c083d745e67ee44b89a9dd770955b2b6",
+        shared: "0070a66c193c9008dd91c3dfe87c728f4742ce113e1b30ef3f48f3df76baec2c509a62ac4494fe627f7030a2a4e571bc6cb9c4b22a88b8a035447afe72edf9292a30",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 3,
+        comment: "normal case",
+        public: "0400fe3555a8c426dcffe1430360d14e9b696c2b6f5c570fb2d1c7b7da9dc7a9d5638d9240d081a846b1e287a35cdfb63ef28ddab3afacd073f5c5f74c2f6f235c1d4f00736bddab5495844b1208def6ec3c31e243e609ec86ae9da20583325ba7e4cb6c5b559feacfbeec6219a3968b26ad22bcaae28a104ea072f058412c2acbb7c54302",
+        private: "1c5f83ec08ba7e1d6aa8d5fe47223c1246fdd9b0da4a7b8e1ed3cb941ead442f88bea18656567bb79e7eda6ace295ac4b24b628d2d450a9920bd3a9096aaa94f",
+        shared: "0025c636bf5994296675eb89ae6aa06083de314e57599d04901396e0834d3f7fac4cbdb66e182e2eb2854d880751d66cdd137cf35c9754094fcd0a0647a49abac1a4",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 4,
+        comment: "compressed public key",
+        public: "0201511b1fdce83b7010962c925cccdb3472e37256bf99f9658ad34e1d6cbba770dc5323fa4491686e6f9af03d49f542a8cc3a35a38516ea2b96264e9d7868cacfcbaf",
+        private: "00b386a1e47fe81b5cca25f82e45116fb32bc0716ca359649227f2664fb6e8a060a4870e7da221c715a7e0c38da728b78545527c98f22fe95961c5f73f91e537f1",
+        shared: "006b81cb175ee0c7f0147328fe1f190a1469e09d97e3fc1770deb879c8b3e55efc5c48e12c8c19ddfd892f08765669c669a7314c0c172f11efdbf0cfc4731f956221",
+        result: Expect::Acceptable,
+    },
+    EcdhCase {
+        tc_id: 5,
+        comment: "shared secret has a leading zero byte",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "00e965e58e2108ab02f4e5d8c162dce56d33719a8eb0b044bf3c7247f5a3a68ff55e3d056555cf035998a14a136518767bc410874fec63bde3e338e2850753ce75",
+        shared: "00bace76844defda252eb3a773cd9f33951ee7df5c796dbccd10f1cbcb6d72fd6148f15025ae884f05e35d6d052975daa521d7e1075adcc470521554c1b4ccd36cc7",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 6,
+        comment: "private key 1",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "01",
+        shared: "0076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 7,
+        comment: "private key 2",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "02",
+        shared: "00347e43cc1279e997ce524e26fc32f3aad3f467f324c3abc997b2d7b82eedf2a44040ba5d533c76df0fcc628104e040fd3ec08d3e1d0f7fb44b84c2f50be34bf6d8",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 8,
+        comment: "private key n-2",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386407",
+        shared: "00347e43cc1279e997ce524e26fc32f3aad3f467f324c3abc997b2d7b82eedf2a44040ba5d533c76df0fcc628104e040fd3ec08d3e1d0f7fb44b84c2f50be34bf6d8",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 9,
+        comment: "private key n-1",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386408",
+        shared: "0076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 10,
+        comment: "private key with leading zero bytes",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "1234",
+        shared: "0134132c68f524216d5e2b3c470561b4767479c8e94ef7cadde877799beab7d14ac8178e10cd2c736a7488280d0165d2e9baeab41b90ea8763c2dcad40b23b31d208",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 11,
+        comment: "point is not on the curve",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63c",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 12,
+        comment: "x is the abscissa of a point on the quadratic twist",
+        public: "02000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 13,
+        comment: "point on the quadratic twist with an uncompressed encoding",
+        public: "04000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 14,
+        comment: "x coordinate not reduced modulo p",
+        public: "040200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010e59be93c4f269c0269c79e2afd65d6aeaa9b701eacc194fb3ee03df47849bf550ec636ebee0ddd4a16f1cd9406605af38f584567770e3f272d688c832e843564",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 15,
+        comment: "compressed x coordinate not reduced modulo p",
+        public: "02020000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 16,
+        comment: "y coordinate not reduced modulo p",
+        public: "040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010210e59be93c4f269c0269c79e2afd65d6aeaa9b701eacc194fb3ee03df47849bf550ec636ebee0ddd4a16f1cd9406605af38f584567770e3f272d688c832e843563",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 17,
+        comment: "point at infinity",
+        public: "00",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 18,
+        comment: "empty public key",
+        public: "",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 19,
+        comment: "truncated uncompressed public key",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c6",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 20,
+        comment: "uncompressed public key with trailing byte",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b00",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 21,
+        comment: "compressed public key with uncompressed prefix",
+        public: "040076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 22,
+        comment: "uncompressed public key with compressed prefix",
+        public: "020076c6ee8a90c4a9393d68209a7f86c57134382cdad1ec8dac92fc1f021610dca869b807c15dac16013c7e4d506fca1abe09aca836e7192fb995ec00b71f86625ea0004b57b3bf7299c81ef9174ba2700f940d020358bde646f7bcfc58edd69967238e226625d2ac449f90a06154a73eb53b1fd101b7226a46b95965f91bde103338c63b",
+        private: "7608720f96d2edc24c02e24b77bf7362cf4c93678625cf5fbb49b4bce87a25c84014010c0018414f57d85e6154b9f76a2704bcf8fba047d32cda77feb5b00c35",
+        shared: "",
+        result: Expect::Invalid,
+    },
+];
+
+// local edge cases, expected results computed by OpenSSL (python cryptography)
+pub const P256K1: [EcdhCase; 21] = [
+    EcdhCase {
+        tc_id: 1,
+        comment: "normal case",
+        public: "0477f5a4882cb784c5a36b8e0aac0dca6fe010a4e501d6dbeb5d585638d2252f915bc3272e809773f67a2e703a56063097afd6e0e784c3987189f339831d301a93",
+        private: "00fb564ffc69bdec1953b0986669ef34025c9a3bf10eb4896427f7da683115a48b",
+        shared: "967435abac7b835020bdf417f7810b6ef07ac6223580f495362c73bd01a516f9",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 2,
+        comment: "normal case",
+        public: "04441dea5399c393cabd8ff9a6eb4a3fb2a893b88ac1811f2d42c89a3ca77e534c71c33603d0f4b6cc151c0e66c270c057b2fc7d5c1250974f0d332c02dd17d319",
+        private: "4ce3543bf2acf6baa6a34c6e87229e57bb2b63a96e5d52b50d7ee0dea6684daf",
+        shared: "aabe2f612c215de35cb8624fc7c47b26eed629957b2335605c0de9ea8d5ead36",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 3,
+        comment: "normal case",
+        public: "041bedcb37c5dd1c89b23c9b01ba26759294a182b7ca46f8d90a1e4564c1640661b68c0e778398fe80a2409f0d88326cc3df3666ed810f69b106fa09c59a17756d",
+        private: "237b1a575291a1d203907ecbafa41b00530a138e58a1b8a8e81bc2176be302f2",
+        shared: "021d4afeed1b009b65a7978d62323f856ede471f974bed153631e4ba31edb42b",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 4,
+        comment: "compressed public key",
+        public: "033c1f424a87124b8c20c9738c3fa98a5a6010940ecb7e595ce94cb63382f7bc99",
+        private: "00da077b919ebe36c280c752a9778f0e9ceffe850ad1984dc064bd6c753ab2c4d3",
+        shared: "b511b626eedcce18d536d7d0c98f8b6c0f900c250b1100f419a61edff974a547",
+        result: Expect::Acceptable,
+    },
+    EcdhCase {
+        tc_id: 5,
+        comment: "shared secret has a leading zero byte",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "75018dfd69c01ecadacd5dc05c86c518b7aaa29a6754285351d8a91c40ba727e",
+        shared: "0050389a411469ae5b7807fd90cb2794c412c9aa3ca2c4b7fa626a144f5759eb",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 6,
+        comment: "private key 1",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "01",
+        shared: "62b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 7,
+        comment: "private key 2",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "02",
+        shared: "df6c59c2f24ea67282c8dd34fee59f8f4d38fd9f4c4c72a1c9212dae626d3b5a",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 8,
+        comment: "private key n-2",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "00fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f",
+        shared: "df6c59c2f24ea67282c8dd34fee59f8f4d38fd9f4c4c72a1c9212dae626d3b5a",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 9,
+        comment: "private key n-1",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "00fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+        shared: "62b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 10,
+        comment: "private key with leading zero bytes",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "1234",
+        shared: "8f57e5d2e295e596d54291b39b56a8892593ca309540713a475a1852597bd71d",
+        result: Expect::Valid,
+    },
+    EcdhCase {
+        tc_id: 11,
+        comment: "point is not on the curve",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55f",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 12,
+        comment: "x is the abscissa of a point on the quadratic twist",
+        public: "020000000000000000000000000000000000000000000000000000000000000005",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 13,
+        comment: "point on the quadratic twist with an uncompressed encoding",
+        public: "0400000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000001",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 14,
+        comment: "x coordinate not reduced modulo p",
+        public: "04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc304218f20ae6c646b363db68605822fb14264ca8d2587fdd6fbc750d587e76a7ee",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 15,
+        comment: "compressed x coordinate not reduced modulo p",
+        public: "02fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 16,
+        comment: "point at infinity",
+        public: "00",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 17,
+        comment: "empty public key",
+        public: "",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 18,
+        comment: "truncated uncompressed public key",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b5",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 19,
+        comment: "uncompressed public key with trailing byte",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e00",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 20,
+        comment: "compressed public key with uncompressed prefix",
+        public: "0462b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+    EcdhCase {
+        tc_id: 21,
+        comment: "uncompressed public key with compressed prefix",
+        public: "0262b97a6445c11132b1422c71819a615318931edc85bb546481a1de9f7dd34842ae77b6fe324f3b6b30e32a2e7454c8028899be69a5835f2eed1116311809b55e",
+        private: "00e06c5d759c1bf550cb6119299517430c1b176c9acad8c767329ed775a0ebd896",
+        shared: "",
+        result: Expect::Invalid,
+    },
+];
@@ -0,0 +1,117 @@
+//! The `EllipticCurve` implementations, through functions generic over the curve
+
+use crate::curve::{EllipticCurve, Sign};
+
+fn keygen<C: EllipticCurve>(sk: &C::Scalar) -> C::Point {
+    C::scale(&C::generator(), sk)
+}
+
+// a scalar of the given value, from its big endian bytes
+fn scalar<C: EllipticCurve>(v: u64) -> C::Scalar {
+    let mut bytes = vec![0u8; C::SCALAR_BYTES];
+    bytes[C::SCALAR_BYTES - 8..].copy_from_slice(&v.to_be_bytes());
+    C::scalar_from_bytes(&bytes).unwrap()
+}
+
+fn sizes<C: EllipticCurve>() {
+    assert_eq!(C::order_bytes().len(), C::SCALAR_BYTES);
+    assert!(C::scalar_from_bytes(C::order_bytes()).is_none());
+    assert!(C::scalar_from_bytes(&vec![0u8; C::SCALAR_BYTES + 1]).is_none());
+    assert!(C::field_element_from_bytes(&vec![0u8; C::FE_BYTES - 1]).is_none());
+    let s = scalar::<C>(0x1234_5678);
+    assert_eq!(C::scalar_to_bytes(&s).len(), C::SCALAR_BYTES);
+}
+
+fn arithmetic<C: EllipticCurve>() {
+    let g = C::generator();
+    let g2 = C::add(&g, &g);
+    assert_eq!(keygen::<C>(&scalar::<C>(1)), g);
+    assert_eq!(keygen::<C>(&scalar::<C>(2)), g2);
+    assert_eq!(keygen::<C>(&scalar::<C>(5)), C::add(&C::add(&g2, &g2), &g));
+    assert!(C::point_to_affine(&keygen::<C>(&scalar::<C>(0))).is_none());
+
+    // (n - 1).G + G is infinity
+    let mut minus_one = C::order_bytes().to_vec();
+    *minus_one.last_mut().unwrap() -= 1;
+    let p = keygen::<C>(&C::scalar_from_bytes(&minus_one).unwrap());
+    assert!(C::point_to_affine(&C::add(&p, &g)).is_none());
+}
+
+fn compression<C: EllipticCurve>() {
+    for v in [1u64, 2, 3, 0xdead_beef].iter() {
+        let p = C::point_to_affine(&keygen::<C>(&scalar::<C>(*v))).unwrap();
+        let (x, sign) = C::point_compress(&p);
+        assert_eq!(C::point_decompress(&x, sign), Some(p.clone()));
+        let other = match sign {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        };
+        let q = C::point_decompress(&x, other).unwrap();
+        assert_ne!(q, p);
+        assert_eq!(C::point_compress(&q).1, other);
+        assert!(C::point_to_affine(&C::add(
+            &C::point_from_affine(&p),
+            &C::point_from_affine(&q)
+        ))
+        .is_none());
+
+        let xb = C::field_element_to_bytes(&x);
+        assert_eq!(xb.len(), C::FE_BYTES);
+        let x2 = C::field_element_from_bytes(&xb).unwrap();
+        assert_eq!(C::point_decompress(&x2, sign), Some(p));
+    }
+}
+
+macro_rules! test_elliptic {
+    ($curve:ident, $module:path) => {
+        mod $curve {
+            use $module as curve;
+
+            #[test]
+            fn sizes() {
+                super::sizes::<curve::Curve>();
+            }
+
+            #[test]
+            fn arithmetic() {
+                super::arithmetic::<curve::Curve>();
+            }
+
+            #[test]
+            fn compression() {
+                super::compression::<curve::Curve>();
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p112r2")]
+test_elliptic!(p112r2, crate::curve::sec2::p112r2);
+#[cfg(feature = "p128r1")]
+test_elliptic!(p128r1, crate::curve::sec2::p128r1);
+#[cfg(feature = "p128r2")]
+test_elliptic!(p128r2, crate::curve::sec2::p128r2);
+#[cfg(feature = "p160k1")]
+test_elliptic!(p160k1, crate::curve::sec2::p160k1);
+#[cfg(feature = "p160r1")]
+test_elliptic!(p160r1, crate::curve::sec2::p160r1);
+#[cfg(feature = "p160r2")]
+test_elliptic!(p160r2, crate::curve::sec2::p160r2);
+#[cfg(feature = "p192k1")]
+test_elliptic!(p192k1, crate::curve::sec2::p192k1);
+#[cfg(feature = "p192r1")]
+test_elliptic!(p192r1, crate::curve::sec2::p192r1);
+#[cfg(feature = "p224k1")]
+test_elliptic!(p224k1, crate::curve::sec2::p224k1);
+#[cfg(feature = "p224r1")]
+test_elliptic!(p224r1, crate::curve::sec2::p224r1);
+#[cfg(feature = "p256k1")]
+test_elliptic!(p256k1, crate::curve::sec2::p256k1);
+#[cfg(feature = "p256r1")]
+test_elliptic!(p256r1, crate::curve::sec2::p256r1);
+#[cfg(feature = "p384r1")]
+test_elliptic!(p384r1, crate::curve::sec2::p384r1);
+#[cfg(feature = "p521r1")]
+test_elliptic!(p521r1, crate::curve::sec2::p521r1);
+#[cfg(feature = "sm2")]
+test_elliptic!(sm2, crate::curve::sm2);
@@ -0,0 +1,263 @@
+//! Curve arithmetic over a toy quadratic extension field
+//!
+//! Fp² is built as Fp[i]/(i² + 1) over the p256k1 base field, which is valid
+//! since p = 3 mod 4 (-1 is not a square). The curve is y² = x³ + 7 over Fp²,
+//! on which there are points with coordinates that are not in Fp.
+
+use crate::curve::field::Field;
+use crate::curve::projective::Point;
+use crate::curve::sec2::p256k1::FieldElement as Fp;
+use crate::curve::weierstrass::{WeierstrassCurve, WeierstrassCurveA0};
+use crate::mp::ct::{Choice, CtEqual};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::OnceLock;
+
+/// a + b.i
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Fp2 {
+    a: Fp,
+    b: Fp,
+}
+
+impl Fp2 {
+    fn embed(a: Fp) -> Self {
+        Fp2 { a, b: Fp::zero() }
+    }
+}
+
+impl fmt::Display for Fp2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}.i", self.a, self.b)
+    }
+}
+
+impl CtEqual for Fp2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.a.ct_eq(&other.a) & self.b.ct_eq(&other.b)
+    }
+}
+
+impl Add<&Fp2> for &Fp2 {
+    type Output = Fp2;
+    fn add(self, other: &Fp2) -> Fp2 {
+        Fp2 {
+            a: &self.a + &other.a,
+            b: &self.b + &other.b,
+        }
+    }
+}
+
+impl Sub<&Fp2> for &Fp2 {
+    type Output = Fp2;
+    fn sub(self, other: &Fp2) -> Fp2 {
+        Fp2 {
+            a: &self.a - &other.a,
+            b: &self.b - &other.b,
+        }
+    }
+}
+
+// (a1 + b1.i) * (a2 + b2.i) = (a1.a2 - b1.b2) + (a1.b2 + a2.b1).i
+impl Mul<&Fp2> for &Fp2 {
+    type Output = Fp2;
+    fn mul(self, other: &Fp2) -> Fp2 {
+        Fp2 {
+            a: (&self.a * &other.a) - (&self.b * &other.b),
+            b: (&self.a * &other.b) + (&self.b * &other.a),
+        }
+    }
+}
+
+impl Neg for &Fp2 {
+    type Output = Fp2;
+    fn neg(self) -> Fp2 {
+        Fp2 {
+            a: -&self.a,
+            b: -&self.b,
+        }
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+    fn neg(self) -> Fp2 {
+        -&self
+    }
+}
+
+// derive the owned variants of the operations from the reference ones
+macro_rules! owned_ops {
+    ($($tr:ident $f:ident),*) => {
+        $(
+            impl $tr<Fp2> for Fp2 {
+                type Output = Fp2;
+                fn $f(self, other: Fp2) -> Fp2 {
+                    (&self).$f(&other)
+                }
+            }
+
+            impl $tr<&Fp2> for Fp2 {
+                type Output = Fp2;
+                fn $f(self, other: &Fp2) -> Fp2 {
+                    (&self).$f(other)
+                }
+            }
+
+            impl $tr<Fp2> for &Fp2 {
+                type Output = Fp2;
+                fn $f(self, other: Fp2) -> Fp2 {
+                    self.$f(&other)
+                }
+            }
+        )*
+    };
+}
+
+owned_ops!(Add add, Sub sub, Mul mul);
+
+impl Field for Fp2 {
+    fn zero() -> Fp2 {
+        Fp2::embed(Fp::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+    fn one() -> Fp2 {
+        Fp2::embed(Fp::one())
+    }
+    fn double(&self) -> Fp2 {
+        self + self
+    }
+    // (a + b.i)^-1 = (a - b.i) / (a² + b²)
+    fn inverse(&self) -> Fp2 {
+        let norm_inv = (self.a.square() + self.b.square()).inverse();
+        Fp2 {
+            a: &self.a * &norm_inv,
+            b: -(&self.b * &norm_inv),
+        }
+    }
+    fn square(&self) -> Fp2 {
+        self * self
+    }
+    fn cube(&self) -> Fp2 {
+        self.square() * self
+    }
+}
+
+fn curve_a() -> &'static Fp2 {
+    static VALUE: OnceLock<Fp2> = OnceLock::new();
+    VALUE.get_or_init(Fp2::zero)
+}
+
+fn curve_b() -> &'static Fp2 {
+    static VALUE: OnceLock<Fp2> = OnceLock::new();
+    VALUE.get_or_init(|| Fp2::embed(Fp::from_u64(7)))
+}
+
+fn curve_b3() -> &'static Fp2 {
+    static VALUE: OnceLock<Fp2> = OnceLock::new();
+    VALUE.get_or_init(|| Fp2::embed(Fp::from_u64(21)))
+}
+
+#[derive(Clone, Copy)]
+struct Curve;
+
+impl WeierstrassCurve for Curve {
+    type FieldElement = Fp2;
+
+    fn a(self) -> &'static Fp2 {
+        curve_a()
+    }
+    fn b(self) -> &'static Fp2 {
+        curve_b()
+    }
+    fn b3(self) -> &'static Fp2 {
+        curve_b3()
+    }
+}
+
+impl WeierstrassCurveA0 for Curve {}
+
+// points (u, s.i) where s² = -(u³ + 7), which are on the curve over Fp² but not over Fp
+fn points() -> Vec<Point<Fp2>> {
+    let mut points = Vec::new();
+    let mut u = 1;
+    while points.len() < 4 {
+        let x = Fp::from_u64(u);
+        if let Some(s) = (-(x.square() * &x + Fp::from_u64(7))).sqrt().into_option() {
+            points.push(Point {
+                x: Fp2::embed(x),
+                y: Fp2 {
+                    a: Fp::zero(),
+                    b: s,
+                },
+                z: Fp2::one(),
+            })
+        }
+        u += 1;
+    }
+    points
+}
+
+// check y² z = x³ + b z³
+fn on_curve(p: &Point<Fp2>) -> bool {
+    let lhs = p.y.square() * &p.z;
+    let rhs = p.x.cube() + curve_b() * p.z.cube();
+    lhs == rhs
+}
+
+#[test]
+fn fp2_inverse() {
+    for p in points() {
+        let v = &p.x + &p.y;
+        assert_eq!(&v * &v.inverse(), Fp2::one());
+    }
+}
+
+#[test]
+fn points_not_over_fp() {
+    for p in points() {
+        assert!(on_curve(&p));
+        assert!(!p.y.b.is_zero());
+        assert!(p.to_affine().is_some());
+    }
+}
+
+#[test]
+fn add_double() {
+    let ps = points();
+    for p in ps.iter() {
+        let dbl = p.double(Curve);
+        assert!(on_curve(&dbl));
+        assert_eq!(dbl, p.double_a0(Curve));
+        assert_eq!(dbl, p.add_or_double(p, Curve));
+        assert_eq!(
+            &dbl.add_different(p, Curve),
+            &p.double(Curve).add_different_a0(p, Curve)
+        );
+
+        let inf = p.add_different(&-p, Curve);
+        assert!(inf.is_infinity().is_true());
+        assert_eq!(inf.add_different(p, Curve), *p);
+    }
+}
+
+#[test]
+fn add_associative() {
+    let ps = points();
+    let (p, q, r) = (&ps[0], &ps[1], &ps[2]);
+    let pq = p.add_different(q, Curve);
+    let qr = q.add_different(r, Curve);
+    assert!(on_curve(&pq));
+    assert_eq!(pq, q.add_different(p, Curve));
+    assert_eq!(pq.add_different(r, Curve), p.add_different(&qr, Curve));
+    assert_eq!(
+        p.scale_vartime(&[5], Curve),
+        p.scale_a0_vartime(&[5], Curve)
+    );
+    assert_eq!(
+        p.scale_vartime(&[3], Curve),
+        p.double(Curve).add_different(p, Curve)
+    );
+}
@@ -0,0 +1,130 @@
+//! The hardened entry points of the `fault-hardening` feature give the same results as
+//! the plain ones, and catch the faults injected with `curve::fault::arm`
+
+macro_rules! test_fault {
+    ($curve:ident, $module:path) => {
+        mod $curve {
+            use crate::curve::fault;
+            use curve::{Point, Scalar};
+            use $module as curve;
+
+            fn scalars() -> Vec<Scalar> {
+                let mut v = vec![Scalar::one(), Scalar::from_u64(2), -Scalar::one()];
+                let mut s = Scalar::from_u64(0xfa17);
+                for i in 0..4 {
+                    s = s.square() + Scalar::from_u64(i);
+                    v.push(s.clone());
+                }
+                v
+            }
+
+            #[test]
+            fn agrees() {
+                let p = &Point::generator() * &Scalar::from_u64(0x1234_5678);
+                for s in scalars() {
+                    assert_eq!(p.scale_verified(&s), Some(p.scale_ct(&s)));
+                    assert_eq!(p.scale_verified(&s), Some(p.scale_vartime(&s)));
+                }
+                assert_eq!(p.scale_verified(&Scalar::zero()), Some(Point::infinity()));
+                let s = Scalar::from_u64(7);
+                assert_eq!(
+                    Point::infinity().scale_verified(&s),
+                    Some(Point::infinity())
+                );
+            }
+
+            #[test]
+            fn fault_caught() {
+                let p = Point::generator();
+                for s in scalars() {
+                    // on the multiplication, then on its check
+                    for n in 0..2 {
+                        fault::arm(n);
+                        let r = p.scale_verified(&s);
+                        assert!(fault::disarm(), "fault {} not injected", n);
+                        assert_eq!(r, None, "fault {} released", n);
+                    }
+                }
+            }
+
+            // the same fault goes through the plain multiplication
+            #[test]
+            fn fault_released() {
+                let (p, s) = (Point::generator(), Scalar::from_u64(0x5eed));
+                fault::arm(0);
+                let r = p.scale_ct(&s);
+                assert!(fault::disarm());
+                assert_ne!(r, p.scale_vartime(&s));
+            }
+        }
+    };
+}
+
+macro_rules! test_fault_ecdsa {
+    ($curve:ident, $module:path) => {
+        mod $curve {
+            use crate::curve::fault;
+            use crate::tests::rng::TestRng;
+            use curve::ecdsa::{sign, sign_verified, verify, Rfc6979};
+            use curve::SecretKey;
+            use sha2::{Digest, Sha256};
+            use $module as curve;
+
+            #[test]
+            fn agrees() {
+                let d = SecretKey::generate(&mut TestRng::new(0x51));
+                for m in [&b"first"[..], b"second", b""].iter() {
+                    let h = Sha256::digest(m);
+                    let signature = sign(&d, &h, Rfc6979::<Sha256>::new());
+                    assert_eq!(
+                        sign_verified(&d, &h, Rfc6979::<Sha256>::new()),
+                        Some(signature)
+                    );
+                }
+            }
+
+            // the first multiplication of the signature is the one of the nonce
+            #[test]
+            fn fault_caught() {
+                let d = SecretKey::generate(&mut TestRng::new(0x52));
+                let h = Sha256::digest(b"glitch");
+
+                fault::arm(0);
+                let faulted = sign(&d, &h, Rfc6979::<Sha256>::new());
+                assert!(fault::disarm());
+                assert!(!verify(&d.public_key(), &h, &faulted));
+
+                fault::arm(0);
+                let signature = sign_verified(&d, &h, Rfc6979::<Sha256>::new());
+                assert!(fault::disarm());
+                assert_eq!(signature, None);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_fault!(p192k1, crate::curve::sec2::p192k1);
+#[cfg(feature = "p192r1")]
+test_fault!(p192r1, crate::curve::sec2::p192r1);
+#[cfg(feature = "p224k1")]
+test_fault!(p224k1, crate::curve::sec2::p224k1);
+#[cfg(feature = "p224r1")]
+test_fault!(p224r1, crate::curve::sec2::p224r1);
+#[cfg(feature = "p256k1")]
+test_fault!(p256k1, crate::curve::sec2::p256k1);
+#[cfg(feature = "p256r1")]
+test_fault!(p256r1, crate::curve::sec2::p256r1);
+#[cfg(feature = "p384r1")]
+test_fault!(p384r1, crate::curve::sec2::p384r1);
+#[cfg(feature = "p521r1")]
+test_fault!(p521r1, crate::curve::sec2::p521r1);
+#[cfg(feature = "sm2")]
+test_fault!(sm2, crate::curve::sm2);
+
+#[cfg(all(feature = "ecdsa", feature = "p256k1", feature = "default-mul-ct"))]
+test_fault_ecdsa!(ecdsa_p256k1, crate::curve::sec2::p256k1);
+#[cfg(all(feature = "ecdsa", feature = "p256r1", feature = "default-mul-ct"))]
+test_fault_ecdsa!(ecdsa_p256r1, crate::curve::sec2::p256r1);
+#[cfg(all(feature = "ecdsa", feature = "p384r1", feature = "default-mul-ct"))]
+test_fault_ecdsa!(ecdsa_p384r1, crate::curve::sec2::p384r1);
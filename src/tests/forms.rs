@@ -0,0 +1,254 @@
+// a toy Montgomery curve B*v^2 = u^3 + A*u^2 + u over the p256r1 base field, and the
+// equivalent twisted Edwards and short Weierstrass curves, computed with the generic maps
+use crate::curve::affine;
+use crate::curve::edwards::{EdwardsCurve, EdwardsPoint};
+use crate::curve::montgomery::{
+    edwards_parameters, from_edwards, from_weierstrass, to_edwards, to_weierstrass,
+    weierstrass_parameters, MontgomeryAffine, MontgomeryCurve, MontgomeryPoint,
+};
+use crate::curve::projective;
+use crate::curve::sec2::p256r1::FieldElement;
+use crate::curve::weierstrass::WeierstrassCurve;
+use std::sync::OnceLock;
+
+fn ma() -> &'static FieldElement {
+    static VALUE: OnceLock<FieldElement> = OnceLock::new();
+    VALUE.get_or_init(|| FieldElement::from_u64(486662))
+}
+
+fn mb() -> &'static FieldElement {
+    static VALUE: OnceLock<FieldElement> = OnceLock::new();
+    VALUE.get_or_init(|| FieldElement::from_u64(5))
+}
+
+fn ma24() -> &'static FieldElement {
+    static VALUE: OnceLock<FieldElement> = OnceLock::new();
+    VALUE.get_or_init(|| (ma() + &FieldElement::from_u64(2)) * FieldElement::from_u64(4).inverse())
+}
+
+fn ed() -> &'static (FieldElement, FieldElement) {
+    static VALUE: OnceLock<(FieldElement, FieldElement)> = OnceLock::new();
+    VALUE.get_or_init(|| edwards_parameters::<FieldElement>(ma(), mb()))
+}
+
+fn ws() -> &'static (FieldElement, FieldElement) {
+    static VALUE: OnceLock<(FieldElement, FieldElement)> = OnceLock::new();
+    VALUE.get_or_init(|| weierstrass_parameters::<FieldElement>(ma(), mb()))
+}
+
+fn wb3() -> &'static FieldElement {
+    static VALUE: OnceLock<FieldElement> = OnceLock::new();
+    VALUE.get_or_init(|| &ws().1 * &FieldElement::from_u64(3))
+}
+
+#[derive(Clone, Copy)]
+struct Toy;
+
+impl MontgomeryCurve for Toy {
+    type FieldElement = FieldElement;
+    fn a(self) -> &'static FieldElement {
+        ma()
+    }
+    fn b(self) -> &'static FieldElement {
+        mb()
+    }
+    fn a24(self) -> &'static FieldElement {
+        ma24()
+    }
+}
+
+impl EdwardsCurve for Toy {
+    type FieldElement = FieldElement;
+    fn a(self) -> &'static FieldElement {
+        &ed().0
+    }
+    fn d(self) -> &'static FieldElement {
+        &ed().1
+    }
+}
+
+impl WeierstrassCurve for Toy {
+    type FieldElement = FieldElement;
+    fn a(self) -> &'static FieldElement {
+        &ws().0
+    }
+    fn b(self) -> &'static FieldElement {
+        &ws().1
+    }
+    fn b3(self) -> &'static FieldElement {
+        wb3()
+    }
+}
+
+// the points of the toy curve with u = 2, 3, ... when v exists
+fn points(n: usize) -> Vec<MontgomeryAffine<FieldElement>> {
+    let mut out = Vec::new();
+    let binv = mb().inverse();
+    let mut u = FieldElement::from_u64(2);
+    while out.len() < n {
+        let uu = u.square();
+        let vv = (&uu * &u + ma() * &uu + &u) * &binv;
+        if let Some(v) = vv.sqrt().into_option() {
+            let p = MontgomeryAffine::<FieldElement>::from_coordinate(&u, &v, Toy).unwrap();
+            out.push(p);
+        }
+        u = u + FieldElement::one();
+    }
+    out
+}
+
+fn weierstrass(p: &MontgomeryAffine<FieldElement>) -> projective::Point<FieldElement> {
+    projective::Point::<FieldElement>::from_affine(&to_weierstrass::<FieldElement, _>(p, Toy))
+}
+
+fn edwards(p: &MontgomeryAffine<FieldElement>) -> EdwardsPoint<FieldElement> {
+    to_edwards::<FieldElement>(p).unwrap()
+}
+
+fn montgomery_u(p: &projective::Point<FieldElement>) -> Option<FieldElement> {
+    p.to_affine()
+        .map(|w| from_weierstrass::<FieldElement, _>(&w, Toy).u)
+}
+
+#[test]
+fn maps_on_curve() {
+    for p in points(6) {
+        let w = to_weierstrass::<FieldElement, _>(&p, Toy);
+        assert_eq!(
+            affine::Point::<FieldElement>::from_coordinate(&w.x, &w.y, Toy),
+            Some(w)
+        );
+        let (x, y) = edwards(&p).to_affine().unwrap();
+        assert_eq!(
+            EdwardsPoint::<FieldElement>::from_coordinate(&x, &y, Toy),
+            Some(edwards(&p))
+        );
+
+        // not on the curve
+        let off = MontgomeryAffine::<FieldElement>::from_coordinate(
+            &p.u,
+            &(&p.v + &FieldElement::one()),
+            Toy,
+        );
+        assert_eq!(off, None);
+    }
+}
+
+#[test]
+fn maps_round_trip() {
+    for p in points(6) {
+        assert_eq!(
+            from_weierstrass::<FieldElement, _>(&to_weierstrass::<FieldElement, _>(&p, Toy), Toy),
+            p
+        );
+        assert_eq!(from_edwards::<FieldElement>(&edwards(&p)), Some(p.clone()));
+        // Edwards from any representative (X:Y:Z:T) of the point
+        let two = FieldElement::from_u64(2);
+        let e = edwards(&p);
+        let scaled = EdwardsPoint {
+            x: &e.x * &two,
+            y: &e.y * &two,
+            z: &e.z * &two,
+            t: &e.t * &two,
+        };
+        assert_eq!(from_edwards::<FieldElement>(&scaled), Some(p));
+    }
+}
+
+#[test]
+fn maps_preserve_addition() {
+    let ps = points(4);
+    for p in ps.iter() {
+        for q in ps.iter() {
+            let w = weierstrass(p).add_or_double(&weierstrass(q), Toy);
+            let e = edwards(p).add(&edwards(q), Toy);
+            let m = from_edwards::<FieldElement>(&e).unwrap();
+            assert_eq!(weierstrass(&m), w);
+        }
+        let e = edwards(p).double(Toy);
+        assert_eq!(e, edwards(p).add(&edwards(p), Toy));
+        assert_eq!(
+            weierstrass(&from_edwards::<FieldElement>(&e).unwrap()),
+            weierstrass(p).double(Toy)
+        );
+    }
+}
+
+#[test]
+fn edwards_identity() {
+    let id = EdwardsPoint::<FieldElement>::identity();
+    assert!(id.is_identity());
+    assert_eq!(from_edwards::<FieldElement>(&id), None);
+    for p in points(3) {
+        let e = edwards(&p);
+        assert_eq!(e.add(&id, Toy), e);
+        assert!(e.add(&-&e, Toy).is_identity());
+        assert!(!e.is_identity());
+    }
+}
+
+#[test]
+fn exceptional_points() {
+    // (0,0) of order 2, and (-1, v) when it exists
+    let zero = MontgomeryAffine::<FieldElement>::from_coordinate(
+        &FieldElement::zero(),
+        &FieldElement::zero(),
+        Toy,
+    );
+    assert_eq!(to_edwards::<FieldElement>(&zero.unwrap()), None);
+    let minus_one = MontgomeryAffine {
+        u: -FieldElement::one(),
+        v: FieldElement::one(),
+    };
+    assert_eq!(to_edwards::<FieldElement>(&minus_one), None);
+}
+
+#[test]
+fn ladder() {
+    let scalars: [&[u8]; 6] = [
+        &[1],
+        &[2],
+        &[3],
+        &[0x1, 0x00],
+        &[0xde, 0xad, 0xbe, 0xef],
+        &[0xff; 32],
+    ];
+    for p in points(3) {
+        let x = p.to_x_only();
+        for n in scalars.iter() {
+            let expected = montgomery_u(&weierstrass(&p).scale_vartime(n, Toy));
+            assert_eq!(x.ladder(n, Toy).to_u(), expected);
+            let e = edwards(&p).scale(n, Toy);
+            assert_eq!(from_edwards::<FieldElement>(&e).map(|m| m.u), expected);
+        }
+        assert_eq!(x.ladder(&[0], Toy).to_u(), None);
+        assert_eq!(x.ladder(&[], Toy).to_u(), None);
+        assert_eq!(MontgomeryPoint::<FieldElement>::infinity().to_u(), None);
+    }
+}
+
+#[test]
+fn affine_constructors() {
+    for p in points(4) {
+        let w = to_weierstrass::<FieldElement, _>(&p, Toy);
+        let (x, y) = (w.x().clone(), w.y().clone());
+        assert!(w.is_on_curve(Toy));
+        assert_eq!(
+            affine::Point::<FieldElement>::new(x.clone(), y.clone(), Toy),
+            Some(w.clone())
+        );
+
+        // an off curve point is only built explicitly, and is detected
+        let off_y = &y + &FieldElement::one();
+        assert_eq!(
+            affine::Point::<FieldElement>::new(x.clone(), off_y.clone(), Toy),
+            None
+        );
+        assert_eq!(
+            affine::Point::<FieldElement>::from_coordinate(&x, &off_y, Toy),
+            None
+        );
+        let off = affine::Point::<FieldElement>::new_unchecked(x, off_y);
+        assert!(!off.is_on_curve(Toy));
+    }
+}
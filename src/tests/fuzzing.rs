@@ -0,0 +1,40 @@
+//! Fuzz targets bodies run on the regression corpus of `fuzz/corpus`
+
+use crate::fuzzing;
+use std::path::PathBuf;
+
+fn corpus(target: &str) -> Vec<(PathBuf, Vec<u8>)> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fuzz")
+        .join("corpus")
+        .join(target);
+    let mut entries: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {}", dir.display(), e))
+        .map(|e| {
+            let path = e.unwrap().path();
+            let data = std::fs::read(&path).unwrap();
+            (path, data)
+        })
+        .collect();
+    entries.sort();
+    assert!(!entries.is_empty(), "empty corpus {}", dir.display());
+    entries
+}
+
+fn run(target: &str, body: fn(&[u8])) {
+    for (path, data) in corpus(target) {
+        let r = std::panic::catch_unwind(|| body(&data));
+        assert!(r.is_ok(), "{} failed on {}", target, path.display());
+    }
+}
+
+#[test]
+fn parse() {
+    run("parse", fuzzing::parse);
+}
+
+#[cfg(all(feature = "p192r1", feature = "num-bigint", feature = "num-traits"))]
+#[test]
+fn diff_p192r1() {
+    run("diff_p192r1", fuzzing::diff_p192r1);
+}
@@ -0,0 +1,138 @@
+//! Truncation of message hashes to scalars (bits2int and bits2octets)
+
+// (hash, bits2octets(hash)), with the hashes of "sample" of RFC 6979 A.2.5 and A.2.7
+#[cfg(feature = "p256r1")]
+const P256_VECTORS: &[(&str, &str)] = &[
+    // SHA-256
+    (
+        "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+        "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+    ),
+    // SHA-224
+    (
+        "9003e374bc726550c2c289447fd0533160f875709386dfa377bfd41c",
+        "000000009003e374bc726550c2c289447fd0533160f875709386dfa377bfd41c",
+    ),
+    // SHA-384
+    (
+        "9a9083505bc92276aec4be312696ef7bf3bf603f4bbd381196a029f340585312313bca4a9b5b890efee42c77b1ee25fe",
+        "9a9083505bc92276aec4be312696ef7bf3bf603f4bbd381196a029f340585312",
+    ),
+    // SHA-512
+    (
+        "39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f49a739468ab8049bf18eef820cdb1ad6c9015f838556bc7fad4138b23fdf986c7",
+        "39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f4",
+    ),
+    // above the order once truncated
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaae",
+    ),
+];
+
+#[cfg(feature = "p521r1")]
+const P521_VECTORS: &[(&str, &str)] = &[
+    // SHA-256
+    (
+        "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+        "00000000000000000000000000000000000000000000000000000000000000000000af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+    ),
+    // SHA-512
+    (
+        "39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f49a739468ab8049bf18eef820cdb1ad6c9015f838556bc7fad4138b23fdf986c7",
+        "000039a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f49a739468ab8049bf18eef820cdb1ad6c9015f838556bc7fad4138b23fdf986c7",
+    ),
+    // 528 bits shifted right by 7 bits, above the order
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "00000000000000000000000000000000000000000000000000000000000000000005ae79787c40d069948033feb708f65a2fc44a36477663b851449048e16ec79bf6",
+    ),
+];
+
+#[cfg(feature = "p256r1")]
+#[test]
+fn bits2octets_p256r1() {
+    use crate::curve::sec2::p256r1::Scalar;
+    use crate::tests::hex::from_hex;
+    for (hash, expected) in P256_VECTORS {
+        let hash = from_hex(hash);
+        assert_eq!(&Scalar::bits2octets(&hash)[..], &from_hex(expected)[..]);
+        assert_eq!(
+            Scalar::from_hash_bits(&hash).to_bytes(),
+            Scalar::bits2octets(&hash)
+        );
+    }
+}
+
+#[cfg(feature = "p521r1")]
+#[test]
+fn bits2octets_p521r1() {
+    use crate::curve::sec2::p521r1::Scalar;
+    use crate::tests::hex::from_hex;
+    for (hash, expected) in P521_VECTORS {
+        let hash = from_hex(hash);
+        assert_eq!(&Scalar::bits2octets(&hash)[..], &from_hex(expected)[..]);
+        assert_eq!(
+            Scalar::from_hash_bits(&hash).to_bytes(),
+            Scalar::bits2octets(&hash)
+        );
+    }
+}
+
+// compare with the computation on big integers, for hashes shorter and longer than the order
+#[cfg(all(feature = "num-bigint", feature = "num-traits"))]
+macro_rules! test_hash_bits {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{Curve, Scalar};
+            use num_bigint::BigUint;
+
+            fn reference(hash: &[u8]) -> Vec<u8> {
+                let order = BigUint::from_bytes_be(Curve.group_order());
+                let shift = (hash.len() * 8).saturating_sub(Scalar::SIZE_BITS);
+                let v = (BigUint::from_bytes_be(hash) >> shift) % order;
+                let bytes = v.to_bytes_be();
+                let mut out = vec![0u8; Scalar::SIZE_BYTES - bytes.len()];
+                out.extend_from_slice(&bytes);
+                out
+            }
+
+            #[test]
+            fn from_hash_bits() {
+                let mut x = 0x5eedu64;
+                for len in [0, 1, 16, 20, 28, 32, 48, 64, 66, 80] {
+                    let random: Vec<u8> = (0..len)
+                        .map(|_| {
+                            x = x
+                                .wrapping_mul(6364136223846793005)
+                                .wrapping_add(1442695040888963407);
+                            (x >> 56) as u8
+                        })
+                        .collect();
+                    for hash in [vec![0; len], vec![0xff; len], random] {
+                        let bytes = Scalar::bits2octets(&hash);
+                        assert_eq!(&bytes[..], &reference(&hash)[..], "{:?}", hash);
+                        assert_eq!(Scalar::from_hash_bits(&hash).to_bytes(), bytes);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p192k1"))]
+test_hash_bits!(p192k1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p192r1"))]
+test_hash_bits!(p192r1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p224k1"))]
+test_hash_bits!(p224k1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p224r1"))]
+test_hash_bits!(p224r1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p256k1"))]
+test_hash_bits!(p256k1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p256r1"))]
+test_hash_bits!(p256r1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p384r1"))]
+test_hash_bits!(p384r1);
+#[cfg(all(feature = "num-bigint", feature = "num-traits", feature = "p521r1"))]
+test_hash_bits!(p521r1);
@@ -0,0 +1,17 @@
+//! Hexadecimal decoding of the test vectors
+
+/// Bytes of a string of hexadecimal digit pairs, panicking on invalid digits
+pub(crate) fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Same as `from_hex`, left padded with zeros to `size` bytes
+pub(crate) fn from_hex_padded(s: &str, size: usize) -> Vec<u8> {
+    let bytes = from_hex(s);
+    let mut out = vec![0u8; size - bytes.len()];
+    out.extend_from_slice(&bytes);
+    out
+}
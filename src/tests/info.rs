@@ -0,0 +1,75 @@
+use crate::build_info;
+use crate::curve::Backend;
+
+fn backend(name: &str) -> Option<Backend> {
+    build_info()
+        .curves
+        .iter()
+        .find(|c| c.name == name)
+        .map(|c| c.backend)
+}
+
+#[test]
+fn curves_match_features() {
+    let info = build_info();
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.limb_bits, 64);
+    assert!(!info.precomputed_tables);
+
+    let features = [
+        ("p112r2", cfg!(feature = "p112r2")),
+        ("p128r1", cfg!(feature = "p128r1")),
+        ("p128r2", cfg!(feature = "p128r2")),
+        ("p160k1", cfg!(feature = "p160k1")),
+        ("p160r1", cfg!(feature = "p160r1")),
+        ("p160r2", cfg!(feature = "p160r2")),
+        ("p192k1", cfg!(feature = "p192k1")),
+        ("p192r1", cfg!(feature = "p192r1")),
+        ("p224k1", cfg!(feature = "p224k1")),
+        ("p224r1", cfg!(feature = "p224r1")),
+        ("p256k1", cfg!(feature = "p256k1")),
+        ("p256r1", cfg!(feature = "p256r1")),
+        ("sm2p256v1", cfg!(feature = "sm2")),
+        ("p384r1", cfg!(feature = "p384r1")),
+        ("p521r1", cfg!(feature = "p521r1")),
+    ];
+    for (name, enabled) in features.iter() {
+        assert_eq!(backend(name).is_some(), *enabled, "{}", name);
+    }
+    assert_eq!(
+        info.curves.len(),
+        features.iter().filter(|(_, enabled)| *enabled).count()
+    );
+}
+
+#[test]
+fn backends() {
+    let upstream = if cfg!(feature = "fiat-crypto") {
+        Backend::FiatCrypto64
+    } else {
+        Backend::Fiat64
+    };
+    for (name, expected) in [
+        ("p192k1", Backend::Fiat64),
+        ("p224r1", Backend::Fiat64),
+        ("p256k1", upstream),
+        ("p256r1", upstream),
+        ("p384r1", upstream),
+        ("p521r1", upstream),
+        ("sm2p256v1", Backend::Montgomery64),
+        ("p160k1", Backend::BigInt),
+    ]
+    .iter()
+    {
+        if let Some(backend) = backend(name) {
+            assert_eq!(backend, *expected, "{}", name);
+        }
+    }
+
+    #[cfg(feature = "p256r1")]
+    assert_eq!(
+        crate::curve::sec2::p256r1::Curve::backend().limb_bits(),
+        Some(64)
+    );
+    assert_eq!(Backend::BigInt.limb_bits(), None);
+}
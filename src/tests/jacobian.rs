@@ -0,0 +1,371 @@
+//! Jacobian points checked against the projective points
+//!
+//! Every operation is compared through the affine coordinates of the results,
+//! including the cases where the formulas aren't complete: P+P, P+(-P) and the
+//! point at infinity as any of the operands.
+
+macro_rules! test_jacobian {
+    ($curve:ident, $double:ident, $scale:ident, $double_scale:ident) => {
+        mod $curve {
+            use crate::curve::jacobian::{CoZTable, JacobianPoint};
+            use crate::curve::sec2::$curve::{Curve, FieldElement, Point, Scalar};
+            use crate::curve::{affine, projective};
+
+            type Projective = projective::Point<FieldElement>;
+            type Jacobian = JacobianPoint<FieldElement>;
+
+            // pseudo random looking multiples of the generator
+            fn scalars() -> Vec<Scalar> {
+                let mut v = vec![Scalar::one(), Scalar::from_u64(2), -Scalar::one()];
+                let mut s = Scalar::from_u64(0x5eed);
+                for i in 0..6 {
+                    s = s.square() + Scalar::from_u64(i + 1);
+                    v.push(s.clone());
+                }
+                v
+            }
+
+            fn affine(s: &Scalar) -> affine::Point<FieldElement> {
+                let p = (&Point::generator() * s).to_affine().unwrap();
+                let (x, y) = p.to_coordinate();
+                affine::Point::new(x.clone(), y.clone(), Curve).unwrap()
+            }
+
+            // same point with a Z different from one
+            fn jacobian(s: &Scalar) -> Jacobian {
+                let p = Jacobian::from_affine(&affine(s));
+                let z = FieldElement::from_u64(0x1234_5678_9abc);
+                let zz = z.square();
+                Jacobian {
+                    x: &p.x * &zz,
+                    y: &p.y * &(&zz * &z),
+                    z,
+                }
+            }
+
+            fn points() -> Vec<(Jacobian, Projective)> {
+                let mut v: Vec<_> = scalars()
+                    .iter()
+                    .map(|s| (jacobian(s), Projective::from_affine(&affine(s))))
+                    .collect();
+                v.push((Jacobian::infinity(), Projective::infinity()));
+                v
+            }
+
+            fn same(j: &Jacobian, p: &Projective) -> bool {
+                j.to_affine() == p.to_affine() && j.to_projective() == *p
+            }
+
+            #[test]
+            fn conversions() {
+                for (j, p) in points() {
+                    assert!(same(&j, &p));
+                    assert_eq!(Jacobian::from_projective(&p), j);
+                    assert_eq!(Jacobian::from_projective(&j.to_projective()), j);
+                }
+                assert_eq!(
+                    Jacobian::from_affine(&affine(&Scalar::one())),
+                    jacobian(&Scalar::one())
+                );
+                assert_ne!(Jacobian::infinity(), jacobian(&Scalar::one()));
+            }
+
+            #[test]
+            fn double() {
+                for (j, p) in points() {
+                    let expected = p.double(Curve);
+                    assert!(same(&j.double(Curve), &expected));
+                    assert!(same(&j.$double(Curve), &expected));
+                }
+            }
+
+            #[test]
+            fn add() {
+                for (j1, p1) in points() {
+                    for (j2, p2) in points() {
+                        let expected = p1.add_or_double(&p2, Curve);
+                        assert!(same(&j1.add(&j2, Curve), &expected));
+                        if let Some(a2) = p2.to_affine() {
+                            assert!(same(&j1.add_affine(&a2, Curve), &expected));
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn degenerate() {
+                for s in scalars() {
+                    let j = jacobian(&s);
+                    let a = affine(&s);
+                    let neg = jacobian(&-&s);
+                    let neg_a = affine(&-&s);
+                    let doubled = j.double(Curve);
+                    // P+P
+                    assert_eq!(j.add(&j, Curve), doubled);
+                    assert_eq!(j.add_affine(&a, Curve), doubled);
+                    // P+(-P)
+                    assert!(j.add(&neg, Curve).is_infinity());
+                    assert!(j.add_affine(&neg_a, Curve).is_infinity());
+                    // infinity operands
+                    assert_eq!(Jacobian::infinity().add(&j, Curve), j);
+                    assert_eq!(j.add(&Jacobian::infinity(), Curve), j);
+                    assert_eq!(Jacobian::infinity().add_affine(&a, Curve), j);
+                }
+                let inf = Jacobian::infinity();
+                assert!(inf.double(Curve).is_infinity());
+                assert!(inf.$double(Curve).is_infinity());
+                assert!(inf.add(&inf, Curve).is_infinity());
+            }
+
+            #[test]
+            fn scale() {
+                let mut ns = scalars();
+                ns.push(Scalar::zero());
+                for (j, p) in points() {
+                    for n in ns.iter() {
+                        let bytes = n.to_bytes();
+                        let expected = p.scale_vartime(&bytes, Curve);
+                        assert!(same(&j.scale_vartime(&bytes, Curve), &expected));
+                        assert!(same(&j.$scale(&bytes, Curve), &expected));
+                    }
+                }
+            }
+
+            #[test]
+            fn coz_table() {
+                for s in scalars() {
+                    let j = jacobian(&s);
+                    let table = CoZTable::<FieldElement>::new(&j, 8, Curve).unwrap();
+                    assert_eq!(table.len(), 8);
+                    for i in 0..8 {
+                        let multiple = Scalar::from_u64(2 * i as u64 + 1);
+                        let expected = jacobian(&(&s * &multiple));
+                        assert_eq!(table.get(i), expected);
+                        for (q, _) in points() {
+                            assert_eq!(table.add_to(&q, i, Curve), q.add(&expected, Curve));
+                        }
+                    }
+                }
+                assert!(CoZTable::<FieldElement>::new(&Jacobian::infinity(), 8, Curve).is_none());
+            }
+
+            #[test]
+            fn scale_plain() {
+                let mut n = Scalar::from_u64(0xc0_2e);
+                for (j, _) in points() {
+                    for _ in 0..8 {
+                        n = n.square() + Scalar::one();
+                        let bytes = n.to_bytes();
+                        assert_eq!(
+                            j.$scale(&bytes, Curve),
+                            j.scale_vartime_plain(&bytes, Curve)
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn double_scale() {
+                let mut ns = scalars();
+                ns.push(Scalar::zero());
+                let ps = points();
+                for (i, a) in ns.iter().enumerate() {
+                    let b = &ns[(i + 3) % ns.len()];
+                    let (a, b) = (a.to_bytes(), b.to_bytes());
+                    // includes the pairs of same points, and the point at infinity
+                    for ((j1, p1), (j2, p2)) in ps.iter().zip(ps.iter().rev()) {
+                        let expected = p1
+                            .scale_vartime(&a, Curve)
+                            .add_different(&p2.scale_vartime(&b, Curve), Curve);
+                        assert!(same(&j1.double_scale_vartime(&a, j2, &b, Curve), &expected));
+                        assert!(same(&j1.$double_scale(&a, j2, &b, Curve), &expected));
+                        assert!(same(
+                            &j1.$double_scale(&a, j1, &b, Curve),
+                            &p1.scale_vartime(&a, Curve)
+                                .add_different(&p1.scale_vartime(&b, Curve), Curve)
+                        ));
+                    }
+                }
+                // P - P
+                let (j, _) = &ps[0];
+                let one = Scalar::one().to_bytes();
+                let minus_one = (-Scalar::one()).to_bytes();
+                assert!(j.$double_scale(&one, j, &minus_one, Curve).is_infinity());
+            }
+
+            #[test]
+            fn double_scalar_mul_vartime() {
+                let ns = scalars();
+                for (i, a) in ns.iter().enumerate() {
+                    let b = &ns[(i + 1) % ns.len()];
+                    let q = &Point::generator() * &ns[(i + 2) % ns.len()];
+                    let expected = &(&Point::generator() * a) + &(&q * b);
+                    assert_eq!(Point::mul_gen_add_vartime(a, b, &q), expected);
+                    assert_eq!(
+                        Point::generator().double_scalar_mul_vartime(a, &q, b),
+                        expected
+                    );
+                    assert_eq!(
+                        Point::mul_gen_add_vartime(a, &Scalar::zero(), &q),
+                        &Point::generator() * a
+                    );
+                    assert_eq!(
+                        Point::mul_gen_add_vartime(a, b, &Point::infinity()),
+                        &Point::generator() * a
+                    );
+                    assert_eq!(
+                        Point::mul_add_vartime(b, &q, a, &Point::generator()),
+                        expected
+                    );
+                    assert_eq!(Point::mul_add_vartime(a, &q, b, &q), &(&q * a) + &(&q * b));
+                    assert_eq!(Point::mul_add_vartime(a, &q, &-a, &q), Point::infinity());
+                    assert_eq!(
+                        Point::mul_add_vartime(&Scalar::zero(), &q, &Scalar::zero(), &q),
+                        Point::infinity()
+                    );
+                }
+            }
+
+            #[test]
+            fn scalar_jsf() {
+                let ns = scalars();
+                for (i, a) in ns.iter().enumerate() {
+                    let b = &ns[(i + 1) % ns.len()];
+                    let digits = Scalar::jsf(a, b);
+                    assert!(digits.len() <= Scalar::SIZE_BITS + 1);
+                    let digit = |d: i8| match d {
+                        1 => Scalar::one(),
+                        -1 => -Scalar::one(),
+                        _ => Scalar::zero(),
+                    };
+                    let (mut ra, mut rb) = (Scalar::zero(), Scalar::zero());
+                    for (u, v) in digits.iter().rev() {
+                        ra = &ra + &ra + digit(*u);
+                        rb = &rb + &rb + digit(*v);
+                    }
+                    assert_eq!((&ra, &rb), (a, b));
+                }
+            }
+
+            #[test]
+            fn mul_vartime() {
+                let mut ns = scalars();
+                ns.push(Scalar::zero());
+                let ps = ns.iter().map(|s| &Point::generator() * s);
+                for p in ps.chain(Some(Point::infinity())) {
+                    for n in ns.iter() {
+                        assert_eq!(p.mul_vartime(n), &p * n);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_jacobian!(p192k1, double_a0, scale_vartime_a0, double_scale_vartime_a0);
+#[cfg(feature = "p192r1")]
+test_jacobian!(
+    p192r1,
+    double_am3,
+    scale_vartime_am3,
+    double_scale_vartime_am3
+);
+#[cfg(feature = "p224k1")]
+test_jacobian!(p224k1, double_a0, scale_vartime_a0, double_scale_vartime_a0);
+#[cfg(feature = "p224r1")]
+test_jacobian!(
+    p224r1,
+    double_am3,
+    scale_vartime_am3,
+    double_scale_vartime_am3
+);
+#[cfg(feature = "p256k1")]
+test_jacobian!(p256k1, double_a0, scale_vartime_a0, double_scale_vartime_a0);
+#[cfg(feature = "p256r1")]
+test_jacobian!(
+    p256r1,
+    double_am3,
+    scale_vartime_am3,
+    double_scale_vartime_am3
+);
+#[cfg(feature = "p384r1")]
+test_jacobian!(
+    p384r1,
+    double_am3,
+    scale_vartime_am3,
+    double_scale_vartime_am3
+);
+#[cfg(feature = "p521r1")]
+test_jacobian!(
+    p521r1,
+    double_am3,
+    scale_vartime_am3,
+    double_scale_vartime_am3
+);
+
+// the worked example of Solinas' paper, most significant column first:
+// 53 = 1 0 0 -1 0 -1 -1 and 102 = 1 1 0 1 0 -1 0
+#[test]
+fn jsf_solinas_example() {
+    use crate::curve::jacobian::jsf;
+
+    let mut digits = jsf(&[53], &[102]);
+    digits.reverse();
+    assert_eq!(
+        digits,
+        vec![(1, 1), (0, 1), (0, 0), (-1, 1), (0, 0), (-1, -1), (-1, 0)]
+    );
+    assert_eq!(jsf(&[], &[]), vec![]);
+    assert_eq!(jsf(&[0, 0], &[0]), vec![]);
+    assert_eq!(jsf(&[1], &[]), vec![(1, 0)]);
+}
+
+// the digits give back the integers, and have the properties of the joint sparse form,
+// which is unique: among any three consecutive columns one is zero, adjacent non zero
+// digits of a row have the same sign, and if two adjacent digits of a row are non zero,
+// the other row has a non zero digit in the upper column and a zero in the lower one
+#[test]
+fn jsf_properties() {
+    use crate::curve::jacobian::jsf;
+
+    let mut state = 0x006a_7366u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for i in 0..500 {
+        let a = ((next() as u128) << 64 | next() as u128) >> (i % 97);
+        let b = ((next() as u128) << 64 | next() as u128) >> (i % 61);
+        let digits = jsf(&a.to_be_bytes(), &b.to_be_bytes()[i % 16..]);
+        assert!(digits.len() <= 129);
+
+        let (mut ra, mut rb) = (0i128, 0i128);
+        for (u, v) in digits.iter().rev() {
+            ra = ra.wrapping_mul(2).wrapping_add(*u as i128);
+            rb = rb.wrapping_mul(2).wrapping_add(*v as i128);
+        }
+        let b_trunc = if i % 16 == 0 {
+            b
+        } else {
+            b & ((1u128 << (8 * (16 - i % 16))) - 1)
+        };
+        assert_eq!((ra as u128, rb as u128), (a, b_trunc));
+
+        for w in digits.windows(3) {
+            assert!(w.contains(&(0, 0)));
+        }
+        for w in digits.windows(2) {
+            let (lo, hi) = (w[0], w[1]);
+            assert!(lo.0 * hi.0 != -1 && lo.1 * hi.1 != -1);
+            if lo.0 != 0 && hi.0 != 0 {
+                assert!(hi.1 != 0 && lo.1 == 0);
+            }
+            if lo.1 != 0 && hi.1 != 0 {
+                assert!(hi.0 != 0 && lo.0 == 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::jwk::{encode, JwkError, PrivateKeyJwk, PublicKeyJwk};
+
+// members of private keys generated with openssl (ecparam -genkey, then the priv and pub
+// values of ec -text), the same keys as the JSON fixtures of tests/jwk.rs
+struct Fixture {
+    crv: &'static str,
+    x: &'static str,
+    y: &'static str,
+    d: &'static str,
+}
+
+const P256R1: Fixture = Fixture {
+    crv: "P-256",
+    x: "LpMWao4X6kLigtx83n7MRqxkkukaiLQi2k4_M1RTGtc",
+    y: "B02IibsHEw4xp0LQfUYiGbTJO2JGNlk_hdzIToHS0ho",
+    d: "dlDGExH4RkphBkQR9y5QcP6q8U1JN_Q7t8E3Af-Uf2E",
+};
+const P384R1: Fixture = Fixture {
+    crv: "P-384",
+    x: "VqNzphdoo5_CiYJyCCcAg1xgVVZ3xvVFuaiV65IP3Rpgcc86syBCm3ShV5s2qHNY",
+    y: "okOfVLsre9bDUqxu7CRtmUVlKzszoJXPyby5cXOM95K621nK0oc3UhmCEZ_qwg7X",
+    d: "5pycU_SB5Co6ZnlCCYUvhQvnxI6TYr0w8E-cEuEUpT07WAdn1PdBzzLQvs7dcDT3",
+};
+const P521R1: Fixture = Fixture {
+    crv: "P-521",
+    x: "AK4WtaNHQM1E2XiBwLuB91Jj1ZXgL1LBckz_7IQfcUBllHfU7Nj6iF-Rh3EXIEV-WR-1HHC20Nl__WEn2g9qQSRx",
+    y: "AOuqNelTFEbtvqwy2KWKVRsw1GsKw8XyH-Hp4tDBfu7uaLdw5XCHN_XWAwA4Es-fOO7vwy__a8PyiiEacVADK1_0",
+    d: "ANpOpJzDKCyC3uG1-YJbf5FuwOuHH6i_iUYbGQX2p3u9MftvPPzv3eXLIXgoL5b_UvpmXpRQbXVHMuVcb0eppBF5",
+};
+const P256K1: Fixture = Fixture {
+    crv: "secp256k1",
+    x: "ZlHzJMZFsvt1FfgpDxabIgBF2YEl9sByRRCpJIRX5XA",
+    y: "rq3l_Thtqh74xsdAOfOd0O0Oh8jRmVJUDwNpVpHQq30",
+    d: "sr0agLGAx6PpvDsBizibKSsmMMGHFuRfhZTZVeHtypM",
+};
+
+fn private(f: &Fixture) -> PrivateKeyJwk {
+    PrivateKeyJwk {
+        kty: "EC".to_string(),
+        crv: f.crv.to_string(),
+        x: Some(f.x.to_string()),
+        y: Some(f.y.to_string()),
+        d: f.d.to_string(),
+    }
+}
+
+fn public(f: &Fixture) -> PublicKeyJwk {
+    PublicKeyJwk {
+        kty: "EC".to_string(),
+        crv: f.crv.to_string(),
+        x: f.x.to_string(),
+        y: f.y.to_string(),
+    }
+}
+
+#[test]
+fn debug_redacted() {
+    let jwk = private(&P256R1);
+    assert!(!format!("{:?}", jwk).contains(&jwk.d));
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(JwkError::InvalidCurve.to_string(), "invalid curve");
+    assert_eq!(
+        JwkError::KeyMismatch.to_string(),
+        "public coordinates don't match the secret scalar"
+    );
+}
+
+macro_rules! test_jwk {
+    ($curve: ident, $fixture: expr, $other: expr) => {
+        mod $curve {
+            use super::{encode, private, public};
+            use crate::curve::sec2::$curve::{FieldElement, PublicKey, Scalar, SecretKey};
+            use crate::jwk::{JwkError, PrivateKeyJwk};
+
+            #[test]
+            fn fixture() {
+                let jwk = private(&$fixture);
+                let sk = SecretKey::from_jwk(&jwk).unwrap();
+                assert_eq!(sk.to_jwk(), jwk);
+
+                let pk = PublicKey::from_jwk(&public(&$fixture)).unwrap();
+                assert_eq!(pk, sk.public_key());
+                assert_eq!(pk.to_jwk(), public(&$fixture));
+
+                // without the public coordinates
+                let d_only = PrivateKeyJwk {
+                    x: None,
+                    y: None,
+                    ..jwk
+                };
+                assert_eq!(SecretKey::from_jwk(&d_only), Ok(sk));
+            }
+
+            #[test]
+            fn round_trip() {
+                for n in [1u64, 2, 0xdeadbeef] {
+                    let sk = SecretKey::from_scalar(Scalar::from_u64(n)).unwrap();
+                    assert_eq!(SecretKey::from_jwk(&sk.to_jwk()).as_ref(), Ok(&sk));
+                    let pk = sk.public_key();
+                    assert_eq!(PublicKey::from_jwk(&pk.to_jwk()), Ok(pk));
+                }
+            }
+
+            #[test]
+            fn invalid_header() {
+                let jwk = private(&$fixture);
+                let pk = public(&$fixture);
+
+                let wrong = PrivateKeyJwk {
+                    kty: "RSA".to_string(),
+                    ..jwk.clone()
+                };
+                assert_eq!(SecretKey::from_jwk(&wrong), Err(JwkError::InvalidKeyType));
+                let mut wrong_pk = pk.clone();
+                wrong_pk.kty = "ec".to_string();
+                assert_eq!(
+                    PublicKey::from_jwk(&wrong_pk),
+                    Err(JwkError::InvalidKeyType)
+                );
+
+                // the other curves names, and the case variant
+                for crv in [$other, "secp256r1", "p-256", ""] {
+                    let wrong = PrivateKeyJwk {
+                        crv: crv.to_string(),
+                        ..jwk.clone()
+                    };
+                    assert_eq!(SecretKey::from_jwk(&wrong), Err(JwkError::InvalidCurve));
+                    let mut wrong_pk = pk.clone();
+                    wrong_pk.crv = crv.to_string();
+                    assert_eq!(PublicKey::from_jwk(&wrong_pk), Err(JwkError::InvalidCurve));
+                }
+            }
+
+            #[test]
+            fn invalid_encoding() {
+                let pk = public(&$fixture);
+                let x = crate::jwk::decode::<{ FieldElement::SIZE_BYTES }>(&pk.x).unwrap();
+
+                let shorter = encode(&x[1..]);
+                let longer = encode(&[&[0][..], &x[..]].concat());
+                let padded = format!("{}=", encode(&x[..x.len() - 1]));
+                let standard = pk.x.replace('-', "+").replace('_', "/");
+                for bad in [shorter, longer, padded, standard, "!".to_string()] {
+                    if bad == pk.x {
+                        continue;
+                    }
+                    let mut wrong = pk.clone();
+                    wrong.x = bad.clone();
+                    assert_eq!(PublicKey::from_jwk(&wrong), Err(JwkError::InvalidEncoding));
+                    wrong = pk.clone();
+                    wrong.y = bad;
+                    assert_eq!(PublicKey::from_jwk(&wrong), Err(JwkError::InvalidEncoding));
+                }
+
+                let mut jwk = private(&$fixture);
+                jwk.d = encode(&[1]);
+                assert_eq!(SecretKey::from_jwk(&jwk), Err(JwkError::InvalidEncoding));
+            }
+
+            #[test]
+            fn invalid_point() {
+                let pk = public(&$fixture);
+
+                // swapped coordinates, other y, and non canonical coordinate
+                let swapped = crate::jwk::PublicKeyJwk {
+                    x: pk.y.clone(),
+                    y: pk.x.clone(),
+                    ..pk.clone()
+                };
+                assert_eq!(PublicKey::from_jwk(&swapped), Err(JwkError::InvalidPoint));
+                let mut y = crate::jwk::decode::<{ FieldElement::SIZE_BYTES }>(&pk.y).unwrap();
+                y[FieldElement::SIZE_BYTES - 1] ^= 1;
+                let mut wrong = pk.clone();
+                wrong.y = encode(&y);
+                assert_eq!(PublicKey::from_jwk(&wrong), Err(JwkError::InvalidPoint));
+                wrong.y = encode(&[0xff; FieldElement::SIZE_BYTES]);
+                assert_eq!(PublicKey::from_jwk(&wrong), Err(JwkError::InvalidPoint));
+
+                // a private key is checked the same
+                let mut jwk = private(&$fixture);
+                jwk.y = Some(encode(&y));
+                assert_eq!(SecretKey::from_jwk(&jwk), Err(JwkError::InvalidPoint));
+            }
+
+            #[test]
+            fn invalid_private() {
+                let jwk = private(&$fixture);
+
+                // zero, and not less than the order
+                let mut wrong = jwk.clone();
+                wrong.d = encode(&[0; Scalar::SIZE_BYTES]);
+                assert_eq!(SecretKey::from_jwk(&wrong), Err(JwkError::InvalidScalar));
+                wrong.d = encode(&[0xff; Scalar::SIZE_BYTES]);
+                assert_eq!(SecretKey::from_jwk(&wrong), Err(JwkError::InvalidScalar));
+
+                // d of another key, or the public coordinates of another key
+                let other = SecretKey::from_scalar(Scalar::from_u64(7))
+                    .unwrap()
+                    .to_jwk();
+                let mismatched_d = PrivateKeyJwk {
+                    d: other.d.clone(),
+                    ..jwk.clone()
+                };
+                assert_eq!(
+                    SecretKey::from_jwk(&mismatched_d),
+                    Err(JwkError::KeyMismatch)
+                );
+                let mismatched_xy = PrivateKeyJwk {
+                    d: jwk.d.clone(),
+                    ..other
+                };
+                assert_eq!(
+                    SecretKey::from_jwk(&mismatched_xy),
+                    Err(JwkError::KeyMismatch)
+                );
+
+                // a single public coordinate
+                let x_only = PrivateKeyJwk {
+                    y: None,
+                    ..jwk.clone()
+                };
+                assert_eq!(
+                    SecretKey::from_jwk(&x_only),
+                    Err(JwkError::MissingCoordinate)
+                );
+                let y_only = PrivateKeyJwk { x: None, ..jwk };
+                assert_eq!(
+                    SecretKey::from_jwk(&y_only),
+                    Err(JwkError::MissingCoordinate)
+                );
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p256k1")]
+test_jwk!(p256k1, super::P256K1, "P-256");
+#[cfg(feature = "p256r1")]
+test_jwk!(p256r1, super::P256R1, "secp256k1");
+#[cfg(feature = "p384r1")]
+test_jwk!(p384r1, super::P384R1, "P-521");
+#[cfg(feature = "p521r1")]
+test_jwk!(p521r1, super::P521R1, "P-384");
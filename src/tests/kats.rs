@@ -23,6 +23,7 @@ macro_rules! test_kats_mul {
                 let expected = Point::from_affine(&paffine);
                 let got = &Point::generator() * &k;
                 assert_eq!(expected, got);
+                assert_eq!(expected, Point::base_scalar_mul(&k));
             }
         }
     };
@@ -38,3 +39,91 @@ test_kats_mul!(p256r1, 104, 156);
 test_kats_mul!(p384r1, 156, 208);
 #[cfg(feature = "p521r1")]
 test_kats_mul!(p521r1, 208, 260);
+
+// the generated KATs of kats_generated.rs, for the fiat and the bigint curves
+macro_rules! test_generated_kats {
+    ($curve: ident, $module: path, $name: literal) => {
+        mod $curve {
+            use crate::tests::hex::from_hex_padded;
+            use crate::tests::kats_generated::{CurveKats, CURVES};
+            use curve::{FieldElement, Point, Scalar};
+            use $module as curve;
+
+            fn kats() -> &'static CurveKats {
+                CURVES.iter().find(|c| c.name == $name).unwrap()
+            }
+
+            fn scalar(s: &str) -> Scalar {
+                Scalar::from_slice(&from_hex_padded(s, Scalar::SIZE_BYTES)).expect("scalar")
+            }
+
+            fn coordinates(p: &Point) -> (Vec<u8>, Vec<u8>) {
+                let p = p.to_affine().expect("not infinity");
+                let (x, y) = p.to_coordinate();
+                (x.to_bytes().to_vec(), y.to_bytes().to_vec())
+            }
+
+            fn expected(x: &str, y: &str) -> (Vec<u8>, Vec<u8>) {
+                (
+                    from_hex_padded(x, FieldElement::SIZE_BYTES),
+                    from_hex_padded(y, FieldElement::SIZE_BYTES),
+                )
+            }
+
+            #[test]
+            fn mul() {
+                for kat in kats().mul.iter() {
+                    let p = &Point::generator() * &scalar(kat.k);
+                    assert_eq!(coordinates(&p), expected(kat.x, kat.y), "k = {}", kat.k);
+                }
+            }
+
+            #[test]
+            fn add() {
+                for kat in kats().add.iter() {
+                    let a = &Point::generator() * &scalar(kat.a);
+                    let b = &Point::generator() * &scalar(kat.b);
+                    let p = &a + &b;
+                    assert_eq!(
+                        coordinates(&p),
+                        expected(kat.x, kat.y),
+                        "a = {} b = {}",
+                        kat.a,
+                        kat.b
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p112r2")]
+test_generated_kats!(generated_p112r2, crate::curve::sec2::p112r2, "p112r2");
+#[cfg(feature = "p128r1")]
+test_generated_kats!(generated_p128r1, crate::curve::sec2::p128r1, "p128r1");
+#[cfg(feature = "p128r2")]
+test_generated_kats!(generated_p128r2, crate::curve::sec2::p128r2, "p128r2");
+#[cfg(feature = "p160k1")]
+test_generated_kats!(generated_p160k1, crate::curve::sec2::p160k1, "p160k1");
+#[cfg(feature = "p160r1")]
+test_generated_kats!(generated_p160r1, crate::curve::sec2::p160r1, "p160r1");
+#[cfg(feature = "p160r2")]
+test_generated_kats!(generated_p160r2, crate::curve::sec2::p160r2, "p160r2");
+#[cfg(feature = "p192k1")]
+test_generated_kats!(generated_p192k1, crate::curve::sec2::p192k1, "p192k1");
+#[cfg(feature = "p192r1")]
+test_generated_kats!(generated_p192r1, crate::curve::sec2::p192r1, "p192r1");
+#[cfg(feature = "p224k1")]
+test_generated_kats!(generated_p224k1, crate::curve::sec2::p224k1, "p224k1");
+#[cfg(feature = "p224r1")]
+test_generated_kats!(generated_p224r1, crate::curve::sec2::p224r1, "p224r1");
+#[cfg(feature = "p256k1")]
+test_generated_kats!(generated_p256k1, crate::curve::sec2::p256k1, "p256k1");
+#[cfg(feature = "p256r1")]
+test_generated_kats!(generated_p256r1, crate::curve::sec2::p256r1, "p256r1");
+#[cfg(feature = "p384r1")]
+test_generated_kats!(generated_p384r1, crate::curve::sec2::p384r1, "p384r1");
+#[cfg(feature = "p521r1")]
+test_generated_kats!(generated_p521r1, crate::curve::sec2::p521r1, "p521r1");
+#[cfg(feature = "sm2")]
+test_generated_kats!(generated_sm2, crate::curve::sm2, "sm2");
@@ -0,0 +1,422 @@
+//! Generator of the known answer tests of `kats_generated.rs`
+//!
+//! The scalars are derived from `SEED`, and the points computed with the reference
+//! formulas below, on num-bigint, which share nothing with the implementations
+//! of the crate but the curve parameters.
+//!
+//! Derivation of the scalars: each curve has its own SplitMix64 sequence, seeded with
+//! `SEED` xor the FNV-1a 64 bits hash of the curve name. A scalar is made of the
+//! big endian bytes of the next ⌈(size of the order + 8) / 8⌉ outputs, reduced modulo
+//! the order (zero being skipped). The multiplication KATs are the scalars 1, 2, 3,
+//! n - 2, n - 1 then `RANDOM_MUL` derived scalars, and the addition KATs a doubling
+//! (a, a) then `RANDOM_ADD` derived pairs (a, b), a + b being non zero.
+//!
+//! The committed data is checked against the generator output (`up_to_date`), and the
+//! generator against the NIST vectors of `kats_data.rs` (`nist_vectors`). After a change
+//! of the generator (which needs a new `GENERATOR_VERSION`), regenerate with:
+//!
+//! ```text
+//! ECCOXIDE_REGENERATE_KATS=1 cargo test kats_gen::regenerate && cargo fmt
+//! ```
+
+use super::kats_data::KATS;
+use super::kats_generated;
+use num_bigint::BigUint;
+
+const GENERATOR_VERSION: u32 = 1;
+const SEED: u64 = 0x6563_636f_7869_6465;
+const RANDOM_MUL: usize = 8;
+const RANDOM_ADD: usize = 6;
+
+struct Params {
+    name: &'static str,
+    p: &'static [u8],
+    a: &'static [u8],
+    b: &'static [u8],
+    gx: &'static [u8],
+    gy: &'static [u8],
+    order: &'static [u8],
+}
+
+macro_rules! params {
+    ($name:literal, $params:path) => {{
+        use $params as params;
+        Params {
+            name: $name,
+            p: &params::P_BYTES,
+            a: &params::A_BYTES,
+            b: &params::B_BYTES,
+            gx: &params::GX_BYTES,
+            gy: &params::GY_BYTES,
+            order: &params::ORDER_BYTES,
+        }
+    }};
+}
+
+// every curve with an implementation, in the order of the generated data
+fn curves() -> Vec<Params> {
+    vec![
+        params!("p112r2", crate::params::sec2::p112r2),
+        params!("p128r1", crate::params::sec2::p128r1),
+        params!("p128r2", crate::params::sec2::p128r2),
+        params!("p160k1", crate::params::sec2::p160k1),
+        params!("p160r1", crate::params::sec2::p160r1),
+        params!("p160r2", crate::params::sec2::p160r2),
+        params!("p192k1", crate::params::sec2::p192k1),
+        params!("p192r1", crate::params::sec2::p192r1),
+        params!("p224k1", crate::params::sec2::p224k1),
+        params!("p224r1", crate::params::sec2::p224r1),
+        params!("p256k1", crate::params::sec2::p256k1),
+        params!("p256r1", crate::params::sec2::p256r1),
+        params!("p384r1", crate::params::sec2::p384r1),
+        params!("p521r1", crate::params::sec2::p521r1),
+        params!("sm2", crate::params::sm2),
+    ]
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(name: &str) -> Self {
+        let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+        for b in name.bytes() {
+            h = (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        SplitMix64(SEED ^ h)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn scalar(&mut self, order: &BigUint) -> BigUint {
+        let words = (order.to_bytes_be().len() + 8).div_ceil(8);
+        loop {
+            let bytes = (0..words)
+                .flat_map(|_| self.next().to_be_bytes().to_vec())
+                .collect::<Vec<u8>>();
+            let k = BigUint::from_bytes_be(&bytes) % order;
+            if k != BigUint::from(0u32) {
+                return k;
+            }
+        }
+    }
+}
+
+// reference arithmetic: jacobian coordinates (X/Z^2, Y/Z^3), Z = 0 at infinity
+struct Curve {
+    p: BigUint,
+    a: BigUint,
+    b: BigUint,
+    g: (BigUint, BigUint),
+    order: BigUint,
+    size: usize,
+}
+
+type Jacobian = (BigUint, BigUint, BigUint);
+
+fn infinity() -> Jacobian {
+    (
+        BigUint::from(1u32),
+        BigUint::from(1u32),
+        BigUint::from(0u32),
+    )
+}
+
+impl Curve {
+    fn new(params: &Params) -> Self {
+        let n = |b: &[u8]| BigUint::from_bytes_be(b);
+        Curve {
+            p: n(params.p),
+            a: n(params.a),
+            b: n(params.b),
+            g: (n(params.gx), n(params.gy)),
+            order: n(params.order),
+            size: params.p.len(),
+        }
+    }
+
+    fn add_m(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.p
+    }
+
+    fn sub_m(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - b) % &self.p
+    }
+
+    fn mul_m(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.p
+    }
+
+    fn small(&self, k: u32, a: &BigUint) -> BigUint {
+        self.mul_m(&BigUint::from(k), a)
+    }
+
+    fn is_zero(a: &BigUint) -> bool {
+        *a == BigUint::from(0u32)
+    }
+
+    fn double(&self, (x, y, z): &Jacobian) -> Jacobian {
+        if Self::is_zero(z) || Self::is_zero(y) {
+            return infinity();
+        }
+        let yy = self.mul_m(y, y);
+        let s = self.small(4, &self.mul_m(x, &yy));
+        let zz = self.mul_m(z, z);
+        let m = self.add_m(
+            &self.small(3, &self.mul_m(x, x)),
+            &self.mul_m(&self.a, &self.mul_m(&zz, &zz)),
+        );
+        let x3 = self.sub_m(&self.mul_m(&m, &m), &self.small(2, &s));
+        let y3 = self.sub_m(
+            &self.mul_m(&m, &self.sub_m(&s, &x3)),
+            &self.small(8, &self.mul_m(&yy, &yy)),
+        );
+        let z3 = self.small(2, &self.mul_m(y, z));
+        (x3, y3, z3)
+    }
+
+    fn add(&self, p: &Jacobian, q: &Jacobian) -> Jacobian {
+        let ((x1, y1, z1), (x2, y2, z2)) = (p, q);
+        if Self::is_zero(z1) {
+            return q.clone();
+        }
+        if Self::is_zero(z2) {
+            return p.clone();
+        }
+        let (zz1, zz2) = (self.mul_m(z1, z1), self.mul_m(z2, z2));
+        let u1 = self.mul_m(x1, &zz2);
+        let u2 = self.mul_m(x2, &zz1);
+        let s1 = self.mul_m(y1, &self.mul_m(z2, &zz2));
+        let s2 = self.mul_m(y2, &self.mul_m(z1, &zz1));
+        if u1 == u2 {
+            return if s1 == s2 { self.double(p) } else { infinity() };
+        }
+        let h = self.sub_m(&u2, &u1);
+        let r = self.sub_m(&s2, &s1);
+        let hh = self.mul_m(&h, &h);
+        let hhh = self.mul_m(&h, &hh);
+        let v = self.mul_m(&u1, &hh);
+        let x3 = self.sub_m(&self.sub_m(&self.mul_m(&r, &r), &hhh), &self.small(2, &v));
+        let y3 = self.sub_m(
+            &self.mul_m(&r, &self.sub_m(&v, &x3)),
+            &self.mul_m(&s1, &hhh),
+        );
+        let z3 = self.mul_m(&h, &self.mul_m(z1, z2));
+        (x3, y3, z3)
+    }
+
+    // k * G, most significant bit first
+    fn mul(&self, k: &BigUint) -> Jacobian {
+        let g = (self.g.0.clone(), self.g.1.clone(), BigUint::from(1u32));
+        let mut r = infinity();
+        for byte in k.to_bytes_be() {
+            for i in (0..8).rev() {
+                r = self.double(&r);
+                if (byte >> i) & 1 == 1 {
+                    r = self.add(&r, &g);
+                }
+            }
+        }
+        r
+    }
+
+    // affine coordinates, checked to be on the curve
+    fn affine(&self, (x, y, z): &Jacobian) -> (BigUint, BigUint) {
+        assert!(!Self::is_zero(z), "point at infinity");
+        let zinv = z.modpow(&(&self.p - BigUint::from(2u32)), &self.p);
+        let zinv2 = self.mul_m(&zinv, &zinv);
+        let x = self.mul_m(x, &zinv2);
+        let y = self.mul_m(y, &self.mul_m(&zinv, &zinv2));
+        let rhs = self.add_m(
+            &self.add_m(
+                &self.mul_m(&x, &self.mul_m(&x, &x)),
+                &self.mul_m(&self.a, &x),
+            ),
+            &self.b,
+        );
+        assert_eq!(self.mul_m(&y, &y), rhs, "not on the curve");
+        (x, y)
+    }
+
+    fn hex(&self, v: &BigUint, size: usize) -> String {
+        let bytes = v.to_bytes_be();
+        assert!(bytes.len() <= size);
+        let mut s = "00".repeat(size - bytes.len());
+        for b in bytes {
+            s.push_str(&format!("{:02x}", b));
+        }
+        s
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Generated {
+    name: String,
+    mul: Vec<[String; 3]>,
+    add: Vec<[String; 4]>,
+}
+
+fn generate(params: &Params) -> Generated {
+    let curve = Curve::new(params);
+    let mut prf = SplitMix64::new(params.name);
+    let n = &curve.order;
+    let scalar_size = params.order.len();
+    let coordinates = |p: &Jacobian| {
+        let (x, y) = curve.affine(p);
+        (curve.hex(&x, curve.size), curve.hex(&y, curve.size))
+    };
+
+    assert!(
+        Curve::is_zero(&curve.mul(n).2),
+        "n.G is not the point at infinity"
+    );
+
+    let mut scalars = (1u32..=3).map(BigUint::from).collect::<Vec<_>>();
+    scalars.push(n - BigUint::from(2u32));
+    scalars.push(n - BigUint::from(1u32));
+    for _ in 0..RANDOM_MUL {
+        scalars.push(prf.scalar(n));
+    }
+    let mul = scalars
+        .iter()
+        .map(|k| {
+            let (x, y) = coordinates(&curve.mul(k));
+            [curve.hex(k, scalar_size), x, y]
+        })
+        .collect();
+
+    let a = prf.scalar(n);
+    let mut pairs = vec![(a.clone(), a)];
+    while pairs.len() < 1 + RANDOM_ADD {
+        let (a, b) = (prf.scalar(n), prf.scalar(n));
+        if (&a + &b) % n != BigUint::from(0u32) {
+            pairs.push((a, b));
+        }
+    }
+    let add = pairs
+        .iter()
+        .map(|(a, b)| {
+            let (x, y) = coordinates(&curve.add(&curve.mul(a), &curve.mul(b)));
+            [curve.hex(a, scalar_size), curve.hex(b, scalar_size), x, y]
+        })
+        .collect();
+
+    Generated {
+        name: params.name.to_string(),
+        mul,
+        add,
+    }
+}
+
+fn render(generated: &[Generated]) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "//! Known answer tests of the scalar multiplication and the point addition, on every\n\
+         //! curve with an implementation\n\
+         //!\n\
+         //! @generated by `kats_gen.rs` (generator version {}, seed {:#018x}), do not edit\n\
+         \n\
+         pub const GENERATOR_VERSION: u32 = {};\n\
+         pub const SEED: u64 = {:#018x};\n\
+         \n\
+         /// `k.G = (x, y)`\n\
+         pub struct MulKat {{\n    pub k: &'static str,\n    pub x: &'static str,\n    pub y: &'static str,\n}}\n\
+         \n\
+         /// `a.G + b.G = (x, y)`\n\
+         pub struct AddKat {{\n    pub a: &'static str,\n    pub b: &'static str,\n    pub x: &'static str,\n    pub y: &'static str,\n}}\n\
+         \n\
+         pub struct CurveKats {{\n    pub name: &'static str,\n    pub mul: &'static [MulKat],\n    pub add: &'static [AddKat],\n}}\n\
+         \n\
+         pub const CURVES: &[CurveKats] = &[\n",
+        GENERATOR_VERSION, SEED, GENERATOR_VERSION, SEED
+    ));
+    for g in generated {
+        out.push_str(&format!(
+            "    CurveKats {{\n        name: \"{}\",\n        mul: &[\n",
+            g.name
+        ));
+        for [k, x, y] in g.mul.iter() {
+            out.push_str(&format!(
+                "            MulKat {{\n                k: \"{}\",\n                x: \"{}\",\n                y: \"{}\",\n            }},\n",
+                k, x, y
+            ));
+        }
+        out.push_str("        ],\n        add: &[\n");
+        for [a, b, x, y] in g.add.iter() {
+            out.push_str(&format!(
+                "            AddKat {{\n                a: \"{}\",\n                b: \"{}\",\n                x: \"{}\",\n                y: \"{}\",\n            }},\n",
+                a, b, x, y
+            ));
+        }
+        out.push_str("        ],\n    },\n");
+    }
+    out.push_str("];\n");
+    out
+}
+
+#[test]
+fn up_to_date() {
+    assert_eq!(kats_generated::GENERATOR_VERSION, GENERATOR_VERSION);
+    assert_eq!(kats_generated::SEED, SEED);
+    let committed = kats_generated::CURVES
+        .iter()
+        .map(|c| Generated {
+            name: c.name.to_string(),
+            mul: c
+                .mul
+                .iter()
+                .map(|m| [m.k.to_string(), m.x.to_string(), m.y.to_string()])
+                .collect(),
+            add: c
+                .add
+                .iter()
+                .map(|m| {
+                    [
+                        m.a.to_string(),
+                        m.b.to_string(),
+                        m.x.to_string(),
+                        m.y.to_string(),
+                    ]
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    let generated = curves().iter().map(generate).collect::<Vec<_>>();
+    assert_eq!(committed, generated, "regenerate kats_generated.rs");
+}
+
+// the reference formulas give the NIST vectors of the prime curves
+#[test]
+fn nist_vectors() {
+    let nist = [
+        ("p192r1", 0..52),
+        ("p224r1", 52..104),
+        ("p256r1", 104..156),
+        ("p384r1", 156..208),
+        ("p521r1", 208..260),
+    ];
+    let all = curves();
+    for (name, range) in nist.iter() {
+        let curve = Curve::new(all.iter().find(|c| c.name == *name).unwrap());
+        for kv in KATS[range.clone()].iter() {
+            let (x, y) = curve.affine(&curve.mul(&BigUint::from_bytes_be(kv.k)));
+            assert_eq!(x, BigUint::from_bytes_be(kv.x), "{}", name);
+            assert_eq!(y, BigUint::from_bytes_be(kv.y), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn regenerate() {
+    if std::env::var_os("ECCOXIDE_REGENERATE_KATS").is_none() {
+        return;
+    }
+    let generated = curves().iter().map(generate).collect::<Vec<_>>();
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/kats_generated.rs");
+    std::fs::write(path, render(&generated)).expect("write kats_generated.rs");
+}
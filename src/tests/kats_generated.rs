@@ -0,0 +1,1741 @@
+//! Known answer tests of the scalar multiplication and the point addition, on every
+//! curve with an implementation
+//!
+//! @generated by `kats_gen.rs` (generator version 1, seed 0x6563636f78696465), do not edit
+
+pub const GENERATOR_VERSION: u32 = 1;
+pub const SEED: u64 = 0x6563636f78696465;
+
+/// `k.G = (x, y)`
+pub struct MulKat {
+    pub k: &'static str,
+    pub x: &'static str,
+    pub y: &'static str,
+}
+
+/// `a.G + b.G = (x, y)`
+pub struct AddKat {
+    pub a: &'static str,
+    pub b: &'static str,
+    pub x: &'static str,
+    pub y: &'static str,
+}
+
+pub struct CurveKats {
+    pub name: &'static str,
+    pub mul: &'static [MulKat],
+    pub add: &'static [AddKat],
+}
+
+pub const CURVES: &[CurveKats] = &[
+    CurveKats {
+        name: "p112r2",
+        mul: &[
+            MulKat {
+                k: "0000000000000000000000000001",
+                x: "4ba30ab5e892b4e1649dd0928643",
+                y: "adcd46f5882e3747def36e956e97",
+            },
+            MulKat {
+                k: "0000000000000000000000000002",
+                x: "a0b17a1c4094209b98ec519bba03",
+                y: "849523d32315f1916a9c746a7db6",
+            },
+            MulKat {
+                k: "0000000000000000000000000003",
+                x: "02460e4660d50e2991a2eb14b651",
+                y: "7b40184a9e57aa0b667e2edb937c",
+            },
+            MulKat {
+                k: "36df0aafd8b8d7597ca10520d049",
+                x: "a0b17a1c4094209b98ec519bba03",
+                y: "56e706ec3fcd6cd515da4a42a2d5",
+            },
+            MulKat {
+                k: "36df0aafd8b8d7597ca10520d04a",
+                x: "4ba30ab5e892b4e1649dd0928643",
+                y: "2daee3c9dab5271ea1835017b1f4",
+            },
+            MulKat {
+                k: "15d7b61d64f7ff278604700d09b3",
+                x: "8231a66da57a915960a16dd30af7",
+                y: "401f83f0b89099d443299ddc39ba",
+            },
+            MulKat {
+                k: "19cd90749945467afb4b5c58d8dd",
+                x: "53f62b42048e4c9f5a0a2c4d8c8a",
+                y: "627e7bc5c6f91d6e2192b934b145",
+            },
+            MulKat {
+                k: "04ae183665fc93031039049243f3",
+                x: "c1f5a0169de18ab5d05285d52971",
+                y: "7b2eeae8b4ffb90ab9216acf1df4",
+            },
+            MulKat {
+                k: "06faa5038d62ad1e324ee1a88a94",
+                x: "494e65014ddb6095bbe17a689cea",
+                y: "217860b31bbc5b1b021d2f86b7d1",
+            },
+            MulKat {
+                k: "325173fa0efa3a6aeb28edbcda5b",
+                x: "062e9ae4f6eccdbc83e6ed13a07e",
+                y: "c69ba4b66d262beb2f84e70e5ff3",
+            },
+            MulKat {
+                k: "179da70ef391cb32cb7b29925194",
+                x: "0f95efac847533bb1f542dc4412d",
+                y: "5f9b25d66a70e87fa992cc26258f",
+            },
+            MulKat {
+                k: "071646e2d2054f49714a1abb1e31",
+                x: "cbb51011d6a97cb473220d3a2e57",
+                y: "6161e21147eab3b3e1280b586658",
+            },
+            MulKat {
+                k: "0d0ac5dacbd84944e0e398161fb7",
+                x: "c3e4c7ff1b36f7d5a8fd1ca1b1bd",
+                y: "455fe5c82c62d468a16142f89a36",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "16ab8f2df5e2cd70fa2fd90b2a4b",
+                b: "16ab8f2df5e2cd70fa2fd90b2a4b",
+                x: "97e0530e43de88e1332d275b87cc",
+                y: "ba375b9d8524a241bbf8db4f60be",
+            },
+            AddKat {
+                a: "1fe66fc1abdcac2234abe0ac7336",
+                b: "1e068ca85cb33f98dbede4130b47",
+                x: "c65720ab32c2f8d6a0994fe79d75",
+                y: "0b89cd7e8f0072fe4363c5aa5698",
+            },
+            AddKat {
+                a: "1d66a5d7cfe5cad3a4b0e36b3d93",
+                b: "04656d1fbae3495eb44ff22bf1a2",
+                x: "4923f6685689da1fcbe0a772d305",
+                y: "b6f38e8e244c624675431cf639cd",
+            },
+            AddKat {
+                a: "12847e8e64e0c0bb8ba2e1050d89",
+                b: "314e3266c49a411229503981d070",
+                x: "6912e0d1e90ca800d981e25569f4",
+                y: "9b01c22da9c65fb6454617ae5ec3",
+            },
+            AddKat {
+                a: "1afbd87547c9b8e3f71a6ca17ad7",
+                b: "3404da04d1c3e62bd3985ec1f7ca",
+                x: "89cc35b94fd145440d131f2e674f",
+                y: "95cd9afbbeb6154801d0aaa5d45f",
+            },
+            AddKat {
+                a: "1dbcc4c901a47ed2e4da12afea42",
+                b: "25149be765d270c7173d6fc946be",
+                x: "73d81d0c57f859b204d509c4bcac",
+                y: "7efe782770ed687059e930d539c3",
+            },
+            AddKat {
+                a: "0eff895a84a91d45a9a080417dee",
+                b: "0733341572b741d0c4a8edff1c73",
+                x: "6a4b216eab236a2b8360b6dee5ff",
+                y: "88ff75fc8fb0e389aa9d2a7de6a4",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p128r1",
+        mul: &[
+            MulKat {
+                k: "00000000000000000000000000000001",
+                x: "161ff7528b899b2d0c28607ca52c5b86",
+                y: "cf5ac8395bafeb13c02da292dded7a83",
+            },
+            MulKat {
+                k: "00000000000000000000000000000002",
+                x: "8151a0c6b92171db199db84be753a97e",
+                y: "03d853559455caae838395a9275b7e95",
+            },
+            MulKat {
+                k: "00000000000000000000000000000003",
+                x: "0ad632f542942f23aa423b628a304b3b",
+                y: "7aa67ee421c4e78851e4b4679bcdc41f",
+            },
+            MulKat {
+                k: "fffffffe0000000075a30d1b9038a113",
+                x: "8151a0c6b92171db199db84be753a97e",
+                y: "fc27aca86baa35517c7c6a56d8a4816a",
+            },
+            MulKat {
+                k: "fffffffe0000000075a30d1b9038a114",
+                x: "161ff7528b899b2d0c28607ca52c5b86",
+                y: "30a537c4a45014ec3fd25d6d2212857c",
+            },
+            MulKat {
+                k: "99f9ee98053f66413f0b07f1f9a903d6",
+                x: "f00f21bdc7ebbe0e0636e2935455d744",
+                y: "4607d1083a7f91702f55a667ce67c0c5",
+            },
+            MulKat {
+                k: "81a36973b9f96e537bbcc84ac1d64ff9",
+                x: "111d75cb62b823c26ec282771428250c",
+                y: "1ca316764349e80012c4afe975d35584",
+            },
+            MulKat {
+                k: "ab0ede6d6aad90c4246e35e13c02af16",
+                x: "e7558486efd1b40c8f9831cf34e560c4",
+                y: "5cd6c584a65c5724f5e6e773caa3a0c0",
+            },
+            MulKat {
+                k: "8c8c32f3cd4c02088a85c7a3301a3e95",
+                x: "d688e5631cb4dac260de22f8f6b8c882",
+                y: "e832b8ee05ba543befd42f8c00179396",
+            },
+            MulKat {
+                k: "61de105f0b9df79099b069716a3e1c08",
+                x: "d36ed203fb45f29daf6ee17aaeebd952",
+                y: "f63cc04ae2922740e6cd040177226cdb",
+            },
+            MulKat {
+                k: "a2034fcda706e41580e10abfefaacc0c",
+                x: "d25a41139cc03c6eb8570edd1db180e4",
+                y: "e26310757a6d7514d642a41037689753",
+            },
+            MulKat {
+                k: "90e54f58ac4c8b9066721d003ca2d5ee",
+                x: "70d6f5e5486cde50fa10b2b41f6d0dad",
+                y: "00966b1b64963c1d9d8abc5a16d18375",
+            },
+            MulKat {
+                k: "07098c5757c000ae47ed56dc45108683",
+                x: "0435a222a8ff1d3c0f15ab27642b76d9",
+                y: "329b22928fb67506f61fc6fd26c6b02e",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "f99e006cc8b05410d3b1a7d8b6ea7db0",
+                b: "f99e006cc8b05410d3b1a7d8b6ea7db0",
+                x: "b6e128406888cca39b5e6abb1ec1aeab",
+                y: "b51a01fb8eed89fca20a2118bd9cc4d9",
+            },
+            AddKat {
+                a: "b825942911bd0a633ce6cff72c3c89b8",
+                b: "5258e87aaad1c38a782a9d098a461360",
+                x: "fa7e1f4f770717f2af46ac6e409b46dd",
+                y: "a3aa41d96e7bb5ba9b48c8d9266e8293",
+            },
+            AddKat {
+                a: "62975d1d13256dc212cea551070a52c8",
+                b: "71b014154419b5c41b17e6ad16a65e86",
+                x: "1cad1b081eca36e8992f27c7fe8015b9",
+                y: "f7f5821f4fd32bf6fc1cb52da6b8bef5",
+            },
+            AddKat {
+                a: "df6669685e739fed3c297eda755ce567",
+                b: "5659bf4959421e5e770fa1a83f4dd879",
+                x: "da2e61ec34d39e1a17e78aadbffab96d",
+                y: "91ec4313c867fb5721df65efd33c342b",
+            },
+            AddKat {
+                a: "cbc3ab3644f1e253b8dd0a8e31563b67",
+                b: "4ec384e6821abbdebd377447570251d6",
+                x: "d304e6c1b3ebcefe787cd8d38610d9f7",
+                y: "cb5527223e4c11bbaf6401126365ee84",
+            },
+            AddKat {
+                a: "a56cf49220693217049b7ed4ea5a9280",
+                b: "da58331e5b5a3b384a5f8801b577a68f",
+                x: "ce0fbacf83f66743d6cc29b2715249a7",
+                y: "1629a3905f1178c63315b5d7c7ac0cd9",
+            },
+            AddKat {
+                a: "baa05e5bca2db9c6f7f0db3033cd8b30",
+                b: "192f4f46ced399b251b58b41a3824642",
+                x: "b90719e065b6ce9f1e3e512c9e784090",
+                y: "b1fdf4adbdeb05082216d0014636480d",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p128r2",
+        mul: &[
+            MulKat {
+                k: "00000000000000000000000000000001",
+                x: "7b6aa5d85e572983e6fb32a7cdebc140",
+                y: "27b6916a894d3aee7106fe805fc34b44",
+            },
+            MulKat {
+                k: "00000000000000000000000000000002",
+                x: "b78b3cccfbc54009b77ed2d74e6d17a7",
+                y: "e6f18d420aa131f92e420094a1b326eb",
+            },
+            MulKat {
+                k: "00000000000000000000000000000003",
+                x: "47b16f39d7b0de241e91f44619c35a83",
+                y: "9f6e63d5e1d65ac5600d3b164c1f83b6",
+            },
+            MulKat {
+                k: "3fffffff7fffffffbe0024720613b5a1",
+                x: "b78b3cccfbc54009b77ed2d74e6d17a7",
+                y: "190e72bbf55ece06d1bdff6b5e4cd914",
+            },
+            MulKat {
+                k: "3fffffff7fffffffbe0024720613b5a2",
+                x: "7b6aa5d85e572983e6fb32a7cdebc140",
+                y: "d8496e9376b2c5118ef9017fa03cb4bb",
+            },
+            MulKat {
+                k: "0e19b69073798f03ff86ac8c42351c28",
+                x: "709a5e207dcf53aeb5ffbe8cfe078f98",
+                y: "0dab3022afdc8c0c93d775975f5df64a",
+            },
+            MulKat {
+                k: "3482166166815f19d92499f5217b577a",
+                x: "34e936287bd6c22eb45bea956cf9d802",
+                y: "6ed1335ff3beeeff7d61b075898475a3",
+            },
+            MulKat {
+                k: "3dfa9eb4fd363558b64927edf87014e8",
+                x: "c8c49a4464c939176e889564d48ef222",
+                y: "02927c9a5ff9809105807b747f292c96",
+            },
+            MulKat {
+                k: "0a51f71187a022e1b84004cd3ff54c3e",
+                x: "d58d597fd441f1213e51a26779456814",
+                y: "5d02a629a3ae721db435910ce934fb85",
+            },
+            MulKat {
+                k: "196568e05ffa1d13430795e0dc19f231",
+                x: "1308b7f05067cc46081bd9182cc37fa5",
+                y: "2aa31b9914bb94f1c65141a785ed17f3",
+            },
+            MulKat {
+                k: "146eac251ca63708ba3cadaf6926f6b0",
+                x: "2df480fd8ad854bec0c7090cdff54f71",
+                y: "9b9ee089f0919fbf46bacf3837a8dda8",
+            },
+            MulKat {
+                k: "2b3d17679d1b5a299198acbc0e4f685c",
+                x: "3730e8bc39436b849163304ac9d22c10",
+                y: "2d12c66a91b5c4f2721b79b3173a3984",
+            },
+            MulKat {
+                k: "0a77a83432ac99a956a89e233a36fed0",
+                x: "271d674589763edeb744dfd409fa9413",
+                y: "52bf4cfb99be3e9ab288911ecec70398",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "3e7d9d7edfb5782b202928b890d98dda",
+                b: "3e7d9d7edfb5782b202928b890d98dda",
+                x: "0178489f12cff6f4019b8a44ae872835",
+                y: "22c32868d0e1c47573b3ca753dd1608a",
+            },
+            AddKat {
+                a: "339d3ca429e7bd4b66a4240907512a4b",
+                b: "19194c1657643e5e533982618d23a4e8",
+                x: "39fdf62888453d14c3794ae2cbff737e",
+                y: "82f559ed1487d899bf45415dd36f8576",
+            },
+            AddKat {
+                a: "1cec19dc4ec63d54f52d3c22283c3f3f",
+                b: "1eda93aab2c1f2937f7e1162a45f5fb8",
+                x: "35fe0e5380d54938e8b03b1b1550acb6",
+                y: "715ef120f3de27a4c02f80c4d9668bf0",
+            },
+            AddKat {
+                a: "2065ee13d80f344afd1a96171da01c8b",
+                b: "3f5f1aadb817843aaa61a174ff87d45b",
+                x: "1b0b36a2de5e959f2fae151b91cb0f2c",
+                y: "e40fce8e1da691dc925157eea1ef8556",
+            },
+            AddKat {
+                a: "192cf9ea1b028ed03557bb41e60411fb",
+                b: "0a724d0a8794ea3800b691f0100242cc",
+                x: "f7a38c5a45192b61e5753f21fc504735",
+                y: "98013ac021d40fcbc524c03871d10c45",
+            },
+            AddKat {
+                a: "3fab4a08a4f140ba62d93af8a39f6a77",
+                b: "339e36ba1a7390bca385b13c46a9988b",
+                x: "a4c302b8406b201a027031f149e05a01",
+                y: "0d21cb7856487b301a883775c08a13b5",
+            },
+            AddKat {
+                a: "2b945f42dd71191272334785438d9190",
+                b: "2e529fca54c9e97467a93d535b7a9b8c",
+                x: "c579621aebba834b92d92b8cd57568e6",
+                y: "28f6271b5ba0d8410895be113b44de8f",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p160k1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000001",
+                x: "3b4c382ce37aa192a4019e763036f4f5dd4d7ebb",
+                y: "938cf935318fdced6bc28286531733c3f03c4fee",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000002",
+                x: "74fc71cb95e965fc54ee5fa0227aff946533a0e3",
+                y: "cec5178957b56930ed3eb7d49f0db1dcf358cdf9",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000003",
+                x: "76bfb34670af0e3305c57012e49d9aea3dae0d5d",
+                y: "f153607068dcb611d14c6d9a1849db711f6bc52f",
+            },
+            MulKat {
+                k: "0100000000000000000001b8fa16dfab9aca16b6b1",
+                x: "74fc71cb95e965fc54ee5fa0227aff946533a0e3",
+                y: "313ae876a84a96cf12c1482b60f24e220ca6de7a",
+            },
+            MulKat {
+                k: "0100000000000000000001b8fa16dfab9aca16b6b2",
+                x: "3b4c382ce37aa192a4019e763036f4f5dd4d7ebb",
+                y: "6c7306cace702312943d7d79ace8cc3b0fc35c85",
+            },
+            MulKat {
+                k: "00cc7cdb1fcb491167d769ca6964d2ce8bcd5241d0",
+                x: "2822bb9062d94aed1817f914ca9d92c54e4d5669",
+                y: "7ee1ddd309574c825b4cc3801a81a947d56e2d38",
+            },
+            MulKat {
+                k: "0056b3ddca45bddec5fd6156825e4e15caa47c782d",
+                x: "3c0c3e8056144e62aad7ac78c404e37fb12200d6",
+                y: "07950e0cd3caf8d32595684463c5b3bf565b9187",
+            },
+            MulKat {
+                k: "0048475008f97886ba46d3df20d42db5dafc69723e",
+                x: "ac52d9e54ef0f78424d4de5662c9692475bb25af",
+                y: "f340ab58bb3121261c24f3796502be53fcc35254",
+            },
+            MulKat {
+                k: "007bf92327a6122c80b0c15fe94675d93cb1f45226",
+                x: "fcd8c49d3a72ee0fdf2aba88cb9ff23c87f361bd",
+                y: "709cf1f7de2ffa084588cb579d2f881b6ab4a019",
+            },
+            MulKat {
+                k: "00a354ed0f3f2cc5a8a3ca8c3de8a13d31185eb5f0",
+                x: "66d0f65da3e2c27d08e8607d1468f0214d946510",
+                y: "5da58ba2a04acf1c960147c1905832611b230882",
+            },
+            MulKat {
+                k: "00a8b6f68d6d8b9016e8970fb9128228696848de71",
+                x: "0e3f675ac81dab87b313c9071d11a087b476559b",
+                y: "1021e14b6679480feb2260b74a6212f38ff35907",
+            },
+            MulKat {
+                k: "00117b98cb05c6a1a53fd2a08ef4547d6a790a7fd8",
+                x: "85a251160f701503c0858b9f56ad5ef9a0b04ddf",
+                y: "1b7ae4ee7868ffa1440924614d1e685cf10995e3",
+            },
+            MulKat {
+                k: "00f40cc3fbd3f0dca54d15b0d931095f492b5382e1",
+                x: "f59558c9917fd45503f714b11c1f07b11384d287",
+                y: "355082eab8180e89353379a86f12920f2089aca3",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "0023f3899009eb9051556dc538610dc019240716da",
+                b: "0023f3899009eb9051556dc538610dc019240716da",
+                x: "ab32a96e7bbc16cdd60ea16fc7f9f422bfc8f418",
+                y: "aac9174b9bf72eba4f92765aa6065f13e27b941d",
+            },
+            AddKat {
+                a: "009c0c0fb160e1596111823c52051ad01ccf50e3f6",
+                b: "00ebfe9e3097f2a45994d30dff98bd0ec26e87f871",
+                x: "351663ae51688418fdb8d8b46c4d225630a3f1eb",
+                y: "98ce925e785a428ac09390febffd58b14e0a8c8a",
+            },
+            AddKat {
+                a: "0044d9aca50e000025ac346a57db74bc2d928b2afb",
+                b: "0027c4a423c4d67c6002cf97786bb1895150380fa9",
+                x: "5eb397f0a06ceeccddefd35e36981c2837bc9188",
+                y: "2e18bbbbbc1b370816771170f94ad9c6aa909c8c",
+            },
+            AddKat {
+                a: "0029f33799edc1893c08e3ca860642d719dae8306e",
+                b: "004edb153a79fa894b23852c7cfcff2259cc28f2d0",
+                x: "c187d0650857ff790d6705f0d3745931352b9892",
+                y: "d124d874462d50ee036ffc2b18471c6564788893",
+            },
+            AddKat {
+                a: "005023c4c8cea69bbd394842937b377e4433a13076",
+                b: "009484d645e7f2eefe5ad245b4a453d1057cf6a1a0",
+                x: "1e1696c715105e573387a0f28fbd98c7964c9ecd",
+                y: "fa0bfca600b90675cc037fb2f673256ad735194d",
+            },
+            AddKat {
+                a: "00f73f0d39b0831e90dad3fa469ef89db932c646f3",
+                b: "00cf46f31a02e08694e9c2054df3f55ba74e9ef5e4",
+                x: "49a21873a2baaf4eee2737aa4c9291b6a41f2679",
+                y: "878d937693dcbd5e22c128d84ae3d61e05d79ed6",
+            },
+            AddKat {
+                a: "000352bd1a474cac5c6a76d91157ada46d58f464ca",
+                b: "00d7b64f23438de69a3b67e5770ca876c0d4edf1f2",
+                x: "7bb63fa5c4b5dad909344f7919240a360a0eeb55",
+                y: "67eecf167149a196b752a1f212842cc0afdae7d8",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p160r1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000001",
+                x: "4a96b5688ef573284664698968c38bb913cbfc82",
+                y: "23a628553168947d59dcc912042351377ac5fb32",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000002",
+                x: "02f997f33c5ed04c55d3edf8675d3e92e8f46686",
+                y: "f083a323482993e9440e817e21cfb7737df8797b",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000003",
+                x: "7b76ff541ef363f2df13de1650bd48daa958bc59",
+                y: "c915ca790d8c8877b55be0079d12854ffe9f6f5a",
+            },
+            MulKat {
+                k: "0100000000000000000001f4c8f927aed3ca752255",
+                x: "02f997f33c5ed04c55d3edf8675d3e92e8f46686",
+                y: "0f7c5cdcb7d66c16bbf17e81de30488c02078684",
+            },
+            MulKat {
+                k: "0100000000000000000001f4c8f927aed3ca752256",
+                x: "4a96b5688ef573284664698968c38bb913cbfc82",
+                y: "dc59d7aace976b82a62336edfbdcaec8053a04cd",
+            },
+            MulKat {
+                k: "0024152c0853cab72292cc07d99892374c8a9c01f4",
+                x: "6dc31ec45225a2477479b9ccc62427e43370c195",
+                y: "4d4bf8ba6457a23a4e509682b7eff6525e4e6893",
+            },
+            MulKat {
+                k: "0052db48ad7bc7ca4945c5233ca13957444b633c3c",
+                x: "e42465c3d289f3c32e1e4ec5f272bbe2e94829c5",
+                y: "594aa0ed5a12af39c6d5aaf26c66624857f0b82d",
+            },
+            MulKat {
+                k: "00679c3e21f62d35fffdf13369743031a584150853",
+                x: "3860394bbb32eea4766560eb2914e03342519fd7",
+                y: "e831b8c012e12c0d3690b7857e2dabb8a15f347b",
+            },
+            MulKat {
+                k: "004f3ff4c7fc83646054dbe4ad370e180ea9666241",
+                x: "e1a0aece71b05d48f5482f83bde6f09c259f151c",
+                y: "4f674d15b4c470383ead9ea65884fc250ea4206a",
+            },
+            MulKat {
+                k: "00a6ef5b6b44c10c623e5e01ac34d67a76b1fec375",
+                x: "a4fb0b6434f6933dc24dffe4a3083ed2925a0d18",
+                y: "d9530db34204e5f9b3f8704aed4afd88334ac074",
+            },
+            MulKat {
+                k: "0090795956f01093fa9cbd3fa1ffb9b9c46e56dcce",
+                x: "d1fd4f081dfb05cf694f1085b08ade78e6329016",
+                y: "74acfe5415aa93618d105e716c2d415402418fa6",
+            },
+            MulKat {
+                k: "00e43e44f3d76b110b73e58d20598df2684ef6a63b",
+                x: "7fe87a78850c5d06fc3c4205cabd9b82b8a7b8d7",
+                y: "2d4a1f30bad7fa4ba94354199b9d1da19555aa4a",
+            },
+            MulKat {
+                k: "000c107d4836bfdd8e069d70234b1a5529cdcce4e1",
+                x: "f3c3359154e62d92ac7eeb602c80fc7afe924238",
+                y: "b3305821993504e8550ff5dff3cd67a5edd3055e",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "0073c431fffb9d7e6e3a00e3e6576ba7065a141a53",
+                b: "0073c431fffb9d7e6e3a00e3e6576ba7065a141a53",
+                x: "49e12a8bcc5e087f41c5c625a6d781ebf4907f71",
+                y: "3c19e802c58c62005ce7ac809202f51bcfb59cb5",
+            },
+            AddKat {
+                a: "0085ce61cf555eeb42a9286454ea765ca19ef3ad8c",
+                b: "00212f21dbc9b31dfe574d1976abf50b566d22f9c6",
+                x: "34459c41db8b8c0953e1df010c4918d6cd2f96f6",
+                y: "7bc97afa15de1dabf669d36332526d0d3cc5cfaf",
+            },
+            AddKat {
+                a: "00878085466264a4e4bc7b7e17ea1ca31d71f4d7a5",
+                b: "00b9aa3af1bc81736c645a304fca105d35c901f3a8",
+                x: "2eb6e1564b20f6d55d8b07ef09768ec081846cf5",
+                y: "0db15b9afaa1d53de46a9b4339f9159e67d46a39",
+            },
+            AddKat {
+                a: "00a01b3953f4af99d1885611d51d804fe16e6b9d55",
+                b: "007dd813abb9869a0fb2b861f5c5e3bf1dc70fa9da",
+                x: "848b1109b57aae78c76c6d280cfe51afe7b93c31",
+                y: "2506d136083b8c2fae01d8ff4c1fc04874531666",
+            },
+            AddKat {
+                a: "00c321ab7af6b44ab5459b9484974301c391451e6a",
+                b: "00203ac5e36a550462c58a80aa75e03ab91230a46d",
+                x: "8d0ea7fc017c563c5c0d69a0fab7f55d896e94e8",
+                y: "d87874d649c17686bfd8f86dd2640774313e56cd",
+            },
+            AddKat {
+                a: "009edaed79759c46ea3a905e9061db5fb50ffd0a08",
+                b: "00617414f26ef5782bb037df78b4192e861431322c",
+                x: "fe410a5d48e0188f6ed89fc9963b6fcce44bb50b",
+                y: "c2c3200c00da645ef5362f274b6307582ac49ce0",
+            },
+            AddKat {
+                a: "008366ce2fa52180d36328fdcd6ff0496a099e7a33",
+                b: "007b44ce28bc0c79b5295fdd4ef61e8ae19f6f40ad",
+                x: "09310dce23b3048a278df48e9e02e0e8bcee0aaa",
+                y: "f1d5f896db9f94fe68bbfd6323f89dbea6f43a9d",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p160r2",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000001",
+                x: "52dcb034293a117e1f4ff11b30f7199d3144ce6d",
+                y: "feaffef2e331f296e071fa0df9982cfea7d43f2e",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000002",
+                x: "26788d2ba41a035954dd638d883e136ab900c8cd",
+                y: "fce35cebd17744978eb33ca3d8daface4b5ffb4a",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000003",
+                x: "e84ff970605dabe350fd9bf375fc37522c5888a5",
+                y: "78de7599c4a72cea3ef4bc3a29982adc894ec182",
+            },
+            MulKat {
+                k: "0100000000000000000000351ee786a818f3a1a169",
+                x: "26788d2ba41a035954dd638d883e136ab900c8cd",
+                y: "031ca3142e88bb68714cc35c27250530b49fb129",
+            },
+            MulKat {
+                k: "0100000000000000000000351ee786a818f3a1a16a",
+                x: "52dcb034293a117e1f4ff11b30f7199d3144ce6d",
+                y: "0150010d1cce0d691f8e05f20667d300582b6d45",
+            },
+            MulKat {
+                k: "00e39b9f0fc74fe9f3e9eb04baeb224c974a7f8262",
+                x: "e7aa84e7f225d048220e9398446281c8c7352ffd",
+                y: "06b88c5c142b885db50fad01c9b3cf16330b8388",
+            },
+            MulKat {
+                k: "00b90a9d0a24d0dcce71bcb0fba4dc69b585e9c817",
+                x: "b4baf761b1453737288598328b7c7c565424e907",
+                y: "2130a2bbe23456a759ba3a6feff036e6ab58af37",
+            },
+            MulKat {
+                k: "00cbc4c351251ec216ab6925d0a9cf3948dcc443f2",
+                x: "cffb3c5ba5a36cc748b0db43ff6178903939080f",
+                y: "90c4a9d131960b48ddf2c7fcbf6562f9662a208e",
+            },
+            MulKat {
+                k: "00cd293ef0f2deb57405845168b815b117255520bb",
+                x: "a69fa9bda4587e294cf47a443ffae2633bd4c4f5",
+                y: "54c5df87ce013b5dc1b4a40e224d42d305c30be5",
+            },
+            MulKat {
+                k: "00f7bb0d2ca8927bf4b35cef44f3b7ba87ab12861a",
+                x: "39a82687d7f4fadd421a0b06134f27614a71982a",
+                y: "894fb00ae2edeaf900fe217329a260b91cf468c8",
+            },
+            MulKat {
+                k: "00142ad7227e5196a0f093a701b40499f1d15d36e2",
+                x: "04e3113b75c2ee6e1d262151461a2031f824c2ef",
+                y: "de32e11cc9489aad66ec5f0f20b74606da75eb55",
+            },
+            MulKat {
+                k: "00d5811c28b6757593fc2e1b9c06fc662ce0919ddd",
+                x: "ad3e3fd04419be00b4e0c2ef114115aa3becba2a",
+                y: "2e08f73ec3f44add18fd747f1bfcd158dbaa7160",
+            },
+            MulKat {
+                k: "00a2a0ada41990ab33e724aa6c89db06aef30bda21",
+                x: "9608a1f4e2ec68cdd33fb391dc60a474bd80d90a",
+                y: "1c21c8e70d833a380693e7eadae4ad7c4cc3bb68",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "00e7405823902adfa91276e2c9c2d7f27e4f4b4015",
+                b: "00e7405823902adfa91276e2c9c2d7f27e4f4b4015",
+                x: "5dc36d1b05a57295f55110a92e40b1a59843e903",
+                y: "55c3dd93420e1ce30e20359b6add063abb1b4fb2",
+            },
+            AddKat {
+                a: "00e46e91cc540d4c85116dcbccd9e7ad4f1f37d274",
+                b: "000812b26faa1820aeb132fd7d7eef05e7fd702410",
+                x: "6c29555be0b8e7ac514f0854d657d5f0f4975f0f",
+                y: "ccf70cd1692dcb41a8bdfcd132e33d317b8b69b2",
+            },
+            AddKat {
+                a: "00137e8729dc227cfc616719ac5ae3268b6db0dcef",
+                b: "00dcdf2163ed113d5c0b65b61e6046cfcd5eba6773",
+                x: "6a2ce28650f7fd73f0228075f56cf56b742f78c3",
+                y: "8c38238d351798becb8870a378e574f19b95604a",
+            },
+            AddKat {
+                a: "002af8ac49ad595708f5d03803e91c4af5a34b6915",
+                b: "009847fa22f06be80267557d9b174210331ef88c3e",
+                x: "255487def3bac29e3a71116f8f912f6e9e9dd71b",
+                y: "682b90954edcff37140dcd96b00a37cc610bbac6",
+            },
+            AddKat {
+                a: "0002a0e6ba2a71562777e38fe6f1d3a3accf96d59c",
+                b: "003452ee180a5870c1df0016a3ddf1150a4d5933d7",
+                x: "02f9872c84de60a473332b06227c15a526cb0e92",
+                y: "9eeb8b0dc0b12330dd7d00e08b2088780d04f95d",
+            },
+            AddKat {
+                a: "000f9c226c234f9f3b2295c55a49c5f775a3179181",
+                b: "00d642c461ce19ae704e7c19811223c13d043e7385",
+                x: "dc647e9fceedd1276ec1b4ab709153f3cb7c2891",
+                y: "4296786d0f28d927dde769bf96c30d497687b721",
+            },
+            AddKat {
+                a: "00b7f04a18c302a7df8364a4d168f76d9029277e6d",
+                b: "00aeac7ef0b567876155023db9bc2e9ab0d9286dab",
+                x: "565b221e9e94d9d32a931b65bd0f23c71a19076d",
+                y: "7de2b331751985a09c9938c898cec100ab2c62a9",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p192k1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000000000001",
+                x: "db4ff10ec057e9ae26b07d0280b7f4341da5d1b1eae06c7d",
+                y: "9b2f2f6d9c5628a7844163d015be86344082aa88d95e2f9d",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000002",
+                x: "f091cf6331b1747684f5d2549cd1d4b3a8bed93b94f93cb6",
+                y: "fd7af42e1e7565a02e6268661c5e42e603da2d98a18f2ed5",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000003",
+                x: "6e43b7dcae2fd5e0bf2a1ba7615ca3b9065487c9a67b4583",
+                y: "c48dcea47ae08e84d5fedc3d09e4c19606a290f7a19a6a58",
+            },
+            MulKat {
+                k: "fffffffffffffffffffffffe26f2fc170f69466a74defd8b",
+                x: "f091cf6331b1747684f5d2549cd1d4b3a8bed93b94f93cb6",
+                y: "02850bd1e18a9a5fd19d9799e3a1bd19fc25d2665e70bf62",
+            },
+            MulKat {
+                k: "fffffffffffffffffffffffe26f2fc170f69466a74defd8c",
+                x: "db4ff10ec057e9ae26b07d0280b7f4341da5d1b1eae06c7d",
+                y: "64d0d09263a9d7587bbe9c2fea4179cbbf7d557626a1be9a",
+            },
+            MulKat {
+                k: "f20cae225f35163f442ec22cb730bbf771c0cfd795910a07",
+                x: "21d59f51bb3bee7cf8d376a6cc8cae52135f002a4d789797",
+                y: "168ac270f4fe4dfdc0f30649fe6007c5a36828d8d89d20af",
+            },
+            MulKat {
+                k: "3a8edfa582716f06da30fcf4e10cae2ed46602504ac588c3",
+                x: "40fac2a814588bfcde453454693fa9d174d181993e95fa8c",
+                y: "ee57bce9158df91b7cd83f1c434bb0a8019fda2479b5ca2d",
+            },
+            MulKat {
+                k: "550a3b269c839cedb65aa55d76864f916f6967b7197d66fd",
+                x: "9c6776eafcd1dc6052663221cb2fd1478a6f4795b28303ca",
+                y: "cc40d61e0311743d62bbd4ceb3a55c7ae3ba57eaa14df764",
+            },
+            MulKat {
+                k: "c4feb0938a7e363eee5e83cfa0d66bbe2f13bfa8a03ac9ee",
+                x: "ac93169be7064fe68515d914c3b0a381b10d5efe497e41ba",
+                y: "d4e3cb76874c3b4a476b3011004889fd9a4e520d70174903",
+            },
+            MulKat {
+                k: "4e79ffe8e20a98f73ea4dc5f55d0c10ccc620fd451a5f728",
+                x: "e0f115c333d3693725b9b04964f9b6d8c4b03b17dcc375c0",
+                y: "f614a086b3135f9f6aa21e60c3c93c664fefdc79e05297a6",
+            },
+            MulKat {
+                k: "37a632060bf27602b3f1a046d229d81655ffa7cdb49879d3",
+                x: "7db2c848e768952f45738f87fd15d0c29ea0f685394346d3",
+                y: "16f9f11a8890ca571acf394073ca1ab6a29afc28a4369845",
+            },
+            MulKat {
+                k: "d612fdd302feb637800b7748256bdd0a8742a665e665c8fd",
+                x: "92bcccac891e22c504970a7f7e99012e359da50a5c7178f5",
+                y: "87dd3cadc1d25c6c39bf084fd0dba4995294ab0acfa218fd",
+            },
+            MulKat {
+                k: "92e84b4d284d29c900aa1a462aac33b5d9f6156c5a8f12b8",
+                x: "750f32f8b27ff24b587536e17dbee6ac9c345694c5b1e9d8",
+                y: "dcad849ec0b4b43af22d93a70aa7f25308c45a9530a59260",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "5de77d7033579fa1a172f144dba3f98effe77e3d0948710c",
+                b: "5de77d7033579fa1a172f144dba3f98effe77e3d0948710c",
+                x: "cd787b380be14b1bbf19fb1931a0f223168784f0fa392f0a",
+                y: "7c21d2190a5eeb192087d187efe6efd146f82c8da7acf3fd",
+            },
+            AddKat {
+                a: "45af8de05c9bdd92c73ec839b13bc7d4de9f7f8ed7bc26a8",
+                b: "9672e1f042a2f3fc2e93fb066d5e7e9ef73a4524e4c1a8a3",
+                x: "175aef91cb376729d6395102e568f639a38a7f6ad13d2e5d",
+                y: "f50881ff15e40e1f1cefdcd18f0621566f63843afb2feefd",
+            },
+            AddKat {
+                a: "f6f41decff422b59b97f70fd18c5f6bbb5082ed745c16036",
+                b: "fd430ed0dd010adb6f02c3dfa76042396f94473d21bdd587",
+                x: "6e4c4f29a834b3a1aff781b8057cab40dda72e0af55924fa",
+                y: "3e27d1e875128a3be8c90126873fa941e41eecf135c96a69",
+            },
+            AddKat {
+                a: "9a180286f38ffcf1d73fa2eb54cf3a509d6b66bdd3344e74",
+                b: "4ecf6130a16e3b5b36cc9a08c096481349be96f857f091c3",
+                x: "4e74d4e107174e92e541f9e5a0f075a13b9a7fe55799e7e9",
+                y: "58f9fa29ac1a5ee11291014930f789434ae86cbe5079047f",
+            },
+            AddKat {
+                a: "045ab989212292bc857286fdd4b953c68ecbcd3462d586fc",
+                b: "96844e22a9c74da31cdc57be49291ca1f77d93759135871a",
+                x: "57353d62b6a81f7dd36b5c31bc7281880f34059d0dd62418",
+                y: "e669805e3e0b117cdd0cfc2b385ab2b7bfe87bbc76caab6c",
+            },
+            AddKat {
+                a: "4cc14c2d397ded5fd4d33785eecfdcd137c352e5f5a08875",
+                b: "bedf3183821815639299f377d40d198f997b7ae7c20b0f49",
+                x: "7957921e0c25470f7b50965c4e79acf867987d6a1c0199e6",
+                y: "49c854d9575eae2d335d5437cb9a351832e9b5e04352dd66",
+            },
+            AddKat {
+                a: "a0a9d4bd4f6f2eeacaa908132a74f7f8b4115bc11e1fa3ee",
+                b: "79af73dc8729d838a282eef6f6db483b54f3d0afd4b1dbf5",
+                x: "0fc73a2c02e6463fa11d7def535990bc13b796f1e6f257d6",
+                y: "0c2230ea12adb3f7bc02357ff5dcf9c5590ad52da5d0955b",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p192r1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000000000001",
+                x: "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+                y: "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000002",
+                x: "dafebf5828783f2ad35534631588a3f629a70fb16982a888",
+                y: "dd6bda0d993da0fa46b27bbc141b868f59331afa5c7e93ab",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000003",
+                x: "76e32a2557599e6edcd283201fb2b9aadfd0d359cbb263da",
+                y: "782c37e372ba4520aa62e0fed121d49ef3b543660cfd05fd",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffff99def836146bc9b1b4d2282f",
+                x: "dafebf5828783f2ad35534631588a3f629a70fb16982a888",
+                y: "229425f266c25f05b94d8443ebe4796fa6cce505a3816c54",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffff99def836146bc9b1b4d22830",
+                x: "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+                y: "f8e6d46a003725879cefee1294db32298c06885ee186b7ee",
+            },
+            MulKat {
+                k: "a0926f9405e417945b7daa33858ac39cbeb596051a2e2d43",
+                x: "089fb7550273c0f8e0791446dffad800eb2bd512724c048e",
+                y: "ea9473297ec5c4bfc657f53d4c5aae3738b1fa6acb83c962",
+            },
+            MulKat {
+                k: "4888547dcc131db63008731ea63f07c20699a630fdc15e09",
+                x: "199891b0160e5f35378381a2ca3f515f4f63f3791e8695bd",
+                y: "a2075ed5bbafa91b7d3ce2dc7f8b8b952209e45a5a686b84",
+            },
+            MulKat {
+                k: "4d903b4d48ed5f58b64444c9137db3fb4674e36468bed996",
+                x: "3a61b7c60f9cbdcbcd2a0482c873f0bfb66416ed0817b509",
+                y: "1d5b980380cf9c696e4e0518013d815b540bedfe566eada8",
+            },
+            MulKat {
+                k: "52afacb542ebc38df779834f179e843cd58dbc8048c02980",
+                x: "b55db65e7dffbd4a2f0fb7c04e79414a256289c485b5225f",
+                y: "74fbe7d4496db008e94c760a1ea99436919cbb3479799224",
+            },
+            MulKat {
+                k: "255facf2e0dd619a0e7ce53e4f7c6a8a8c9d46ace7a2b302",
+                x: "016131b4b4f97deb4fa3a024c717b207f0c1b1b7d24f4e4a",
+                y: "81316eff378771e1e9868758f8c9d5187fa1c0f8f8d4a2d5",
+            },
+            MulKat {
+                k: "ddea37faa9bb7b9926420e425b9b70fcecb9d3a508cbacb2",
+                x: "e4c33b41ca66d6fb115b49f98e426a508ceda912dba9d220",
+                y: "bc770107fd7271018fd354077796d7e80c6e63d3f6f861c9",
+            },
+            MulKat {
+                k: "1b2dd0587c18a4b16ee2e30220694e7cf807b90814fb3955",
+                x: "b7ff2d4fbb7c92b1ba015ddc2c71bb640ecfd5194d123318",
+                y: "805946f5715bfeb68f5698ecdbbc4a0e7728f5e4714c171d",
+            },
+            MulKat {
+                k: "6e0708c31b7ccc1d9ab59235fa6f670f40cbb25362749ac3",
+                x: "75c351c6eb5f9d1d4e990417f87687ee5962b1648a37448e",
+                y: "f3603b65cf7fd2e1bc496800766f7bd5a5caf3aff7e0fe3d",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "6dfacf5a86ef608d484ce4cfe2db325892529849537cd42e",
+                b: "6dfacf5a86ef608d484ce4cfe2db325892529849537cd42e",
+                x: "5bdac16bda4ae3eaab1c800abf5e12478b41f854ba0371c8",
+                y: "8ca60c96538234091ab5b4fe3f077012dae79c60c5706267",
+            },
+            AddKat {
+                a: "f79a38f1c279fa2437f601a9e1be1393aae4e456706a1b0e",
+                b: "3f0e547322b4d7cb6cd491a4fa178f93c3ea5b7d6e474c70",
+                x: "aef5313c2e2537bd3c4425d71599f01aa041dc4327ffd705",
+                y: "74937518c26ea5c30b18c3dd4608a0359ad0270135cdc5ef",
+            },
+            AddKat {
+                a: "a09b7735ab8fa87e386ec86ddebe25569fd0994662b68bf2",
+                b: "9c93bee665668a72d823f0d3c5b55cb7ca3f155edd41f77d",
+                x: "2518e23c2f8ea8d808680ae35f33d07b4fa1908492a2e546",
+                y: "a3d7834273cdd2f89fa2ed4a0a11d91845983ee66b3ae778",
+            },
+            AddKat {
+                a: "ddf0bf992a2a9c87c13c58ae9c4997c63dc400b0e69e0804",
+                b: "c7b951a4bd74880bf6298636db47d1d51623bc63b4f60891",
+                x: "047f6d74a63bc0a3e494b0aa09b6d9d06e97176198b46782",
+                y: "90a615243f54b8f8c0551f47ac7d989031adad245d4ba0f7",
+            },
+            AddKat {
+                a: "bce82298738458fe4eb459354fc5a32353a40fd401bcea63",
+                b: "5779412128f1da266a7d134ea532ea0a83f17eebc0fd9c58",
+                x: "c876106ed280397bf2a8c0753943767987120a74fde52d54",
+                y: "8e4bb1c8076e6aa5f426c049f8085aee75a6c91722db44a9",
+            },
+            AddKat {
+                a: "ad049694e86fb222564e6b55f5fc1117d1ad38aceffdb81e",
+                b: "db44e22f2380e546489b8ce8267fc593b003d9afbb909de8",
+                x: "6dd787249ca43eb4fb69c7b0b1e926ab341b27ffc646e360",
+                y: "a7dbab48153a84f1f7971843a1b1e448a999878eacde4149",
+            },
+            AddKat {
+                a: "77261c88238b9fda46b9caf6b9f698362197dfab5b4de6a4",
+                b: "da0b6e83a8a2bcef444b0ec97e91d89050dd0937e45d3ef0",
+                x: "5030f9a8d71ece06b2f27be22759fc736ec20f8da85785fe",
+                y: "944aa33c3bbaf5e316a296fbb9935f069ac16ba4b49a06a5",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p224k1",
+        mul: &[
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000001",
+                x: "a1455b334df099df30fc28a169a467e9e47075a90f7e650eb6b7a45c",
+                y: "7e089fed7fba344282cafbd6f7e319f7c0b0bd59e2ca4bdb556d61a5",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000002",
+                x: "86c0deb56aeb9712390999a0232b9bf596b9639fa1ce8cf426749e60",
+                y: "8f598c954e1085555b474a79906b855c539ed633dbf4a9fa9f06b69a",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000003",
+                x: "fa182dc268cc40ad9fc1976af6f1b667d9701679b3d03ec4f7a0dd28",
+                y: "dde2c5f5ae938e863c6f383d2340638959a80a613ba39dd0c121661f",
+            },
+            MulKat {
+                k: "010000000000000000000000000001dce8d2ec6184caf0a971769fb1f5",
+                x: "86c0deb56aeb9712390999a0232b9bf596b9639fa1ce8cf426749e60",
+                y: "70a6736ab1ef7aaaa4b8b5866f947aa3ac6129cc240b560460f92ed3",
+            },
+            MulKat {
+                k: "010000000000000000000000000001dce8d2ec6184caf0a971769fb1f6",
+                x: "a1455b334df099df30fc28a169a467e9e47075a90f7e650eb6b7a45c",
+                y: "81f760128045cbbd7d350429081ce6083f4f42a61d35b423aa9283c8",
+            },
+            MulKat {
+                k: "00baf7847d9bd732f2ae97e5db2d03c8bd291b491654c0e49c8dc7e06f",
+                x: "743dcf03074deb8d0eb2b6ae7b3a4658cb603e8fc188ae481bd52f38",
+                y: "cf65822f7aac2f71728725d8d11d77ed8d703ff624f685834b3926bf",
+            },
+            MulKat {
+                k: "00b1cb80457d21d9afac3aa7920126ac222c6d0b90be8e6337f793194f",
+                x: "cd5dd5dba354a3dc199f18f5709369b05cdd49be689e7966ee633c57",
+                y: "1bc4707dacdfcb41fa30aa7089893426464c863310e4515b8b9fbc75",
+            },
+            MulKat {
+                k: "00208c88897f79b912f60c48462e7c45cd81f5685a3e5691fa39427442",
+                x: "347481d781ba57ae1e9ba93198a7e75d667d4a73be54971bff6fe1bf",
+                y: "d6b38716d98bc618a426cb436f6424adc8294aca2cf2438da7fcfea8",
+            },
+            MulKat {
+                k: "00ecf3c1eb34f26cb5d3814451972d9cce27ace12052640ec841e2dd76",
+                x: "95ef21d50419ae0c75806f0540934a837c63bfef459eecb6bb898075",
+                y: "c9206c427f389956ac9feb592ef46c749ec008981a3ae23558277b9d",
+            },
+            MulKat {
+                k: "0090b8958aa53ee8dd97dcf066777cbaf5da211863e165fecaeb3b6c84",
+                x: "79c2c431a012c7049e572c84f7cbffe7c0d3c0fa635d72552f532b91",
+                y: "e405fe0d5227eed583a814b91a5245a38053b2de250c2163e850f24e",
+            },
+            MulKat {
+                k: "009e9386a7d15657549e8bbe9e0a7ff1141dc418107704088f428cd407",
+                x: "072044090b2637d3f2773e4a9ca1f563ddf8ebdba5a5f3ffcebf0983",
+                y: "a9129856e8a5d5df27255d8fc5bc75451d83d9112bc346e1cedfa620",
+            },
+            MulKat {
+                k: "00a994a914e0d5c03f21c88cbabd4becb14b65017390c4f87a103b27ef",
+                x: "63a4c187b9530c3405fef81234101c78a61a2d8f7ac118c56edfb9a7",
+                y: "4006f49bcf8c7f437449634e4975c5b2a5f60cbc40dc4c4b3a5bd478",
+            },
+            MulKat {
+                k: "0051b4bce89648cc094098cad4d33cdda2ee57817f0f4e05312aad98b6",
+                x: "72d51cd6e40936418885f0cbf97343c96caa6f29ecfb2758329d45a8",
+                y: "8592e5d17f9c7c2a9ca336e021b553acc1b1dc3a5bf50b1f87624a38",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "00609fbbd8afaf6a5522e5a532b61359606400e29df7eb21b63c6e1962",
+                b: "00609fbbd8afaf6a5522e5a532b61359606400e29df7eb21b63c6e1962",
+                x: "0d623dd3105a67b67e323f8d673f7940925dc5a808c83936a8a6c23b",
+                y: "c2238cef867da38adb4159069f712a71b14718c8a71de8d8853d39f2",
+            },
+            AddKat {
+                a: "0005b3f575923269842488a8862352cb6b2b0c02aa36c11054b4f9fd61",
+                b: "00c5e60e422cddda746b4bf74da738514255df8d5240fedbc76c928ca4",
+                x: "b3f4782aae3190307c49ac89cbef6582e065285675ded2acfde6923e",
+                y: "ef224b5aac9adf870b232d3324d6f8fa204fe44714276065c99733c6",
+            },
+            AddKat {
+                a: "004ea120e659669edb88af7a2632885a9fbcd37fbdfdc02f738617890c",
+                b: "00e6704052cf17b0838c53dcbfb10429640d6cb340b0f957098835f7b5",
+                x: "35f7fd886478f6cff99d653094e7c2642d54c757267589e49e45ff4e",
+                y: "4869d45d46f2c601fa3ee3ce863c1a915b4c05dbc13c6fb635f50c9f",
+            },
+            AddKat {
+                a: "005783f3da839dd2a897704e5fb2f0da657cb919e37a7ebe0de601f7bf",
+                b: "0060da3f474ee6ea89cb109c325db5f7691325eff49a2dba5e2c4263dd",
+                x: "8d18b4e2990d9d978cdec3e2a2f141aacd531ebaf5be8853f78b0c04",
+                y: "d843ce5e858b1e0bf7b62f5b9d7b13e37e2d0370ca1689eccbd1cdfd",
+            },
+            AddKat {
+                a: "008d94cf27fc3dbf096514ccd3d90f7937fc245b275c48c47b3e6daf81",
+                b: "00916f9711cce06a8e5bf72c525291318c487a2b6b2fca02d674e94b02",
+                x: "1e851b17f54fb8f3bff46e1d794363d2bddb42b6ba83555375ee6309",
+                y: "0ab14c0410acf8280f63b98c14f53629de5e647f950deb4b1da20054",
+            },
+            AddKat {
+                a: "0095c785538e8b27e3705a59d86b4ead39aaee6d8113f58c95de356116",
+                b: "00f3473e65a9ff1e74f7bb594dcb040b91e2a7e5f7dbd6939ba7c14280",
+                x: "52ae2975d02231aa779178d2af637023cfb8dc3a747fd1665d482bec",
+                y: "18de28741896809ee0e4aaff8f21346d9ce846467b4610099e821fa4",
+            },
+            AddKat {
+                a: "001b2625e2261f932e7c11e3ce5520024bc6f28cb47968569e42502e85",
+                b: "007438ba589013b1383cd1a83370318660b9a4f272a07273b3f882485f",
+                x: "e720d85a5d66ec3fe3a2ebeede32e6145c0030090afb9f170020ce8b",
+                y: "4dfce2991e25c8cfc3c5a64ee391ec7d807277eca12a8b7dfad1a66c",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p224r1",
+        mul: &[
+            MulKat {
+                k: "00000000000000000000000000000000000000000000000000000001",
+                x: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                y: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+            },
+            MulKat {
+                k: "00000000000000000000000000000000000000000000000000000002",
+                x: "706a46dc76dcb76798e60e6d89474788d16dc18032d268fd1a704fa6",
+                y: "1c2b76a7bc25e7702a704fa986892849fca629487acf3709d2e4e8bb",
+            },
+            MulKat {
+                k: "00000000000000000000000000000000000000000000000000000003",
+                x: "df1b1d66a551d0d31eff822558b9d2cc75c2180279fe0d08fd896d04",
+                y: "a3f7f03cadd0be444c0aa56830130ddf77d317344e1af3591981a925",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3b",
+                x: "706a46dc76dcb76798e60e6d89474788d16dc18032d268fd1a704fa6",
+                y: "e3d4895843da188fd58fb0567976d7b50359d6b78530c8f62d1b1746",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3c",
+                x: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                y: "42c89c774a08dc04b3dd201932bc8a5ea5f8b89bbb2a7e667aff81cd",
+            },
+            MulKat {
+                k: "5a96118ab44941d7d62d5bee1aa8919970b5611e466d49745e0feb62",
+                x: "842e3ca750793906f02d38d8adddbb18c0e4f34b2a9bb326773447e7",
+                y: "82d68bf2d8ed617d69c7fa758109e71e87b79e3f7ac32148b17b6631",
+            },
+            MulKat {
+                k: "6258f2b4b54b39ee5150103d4e7c26ba9eb14ffbf53794fa801d6843",
+                x: "a55d9571447aa0fa21fdb4f4eed37f5304f7379de114499ed61dde2c",
+                y: "3fcc89c8380dabd0dfdf0a7169571476c5ecc173a4bd7f357a889804",
+            },
+            MulKat {
+                k: "80eb0e682d8626d16069c6e35fbc53c0135bb24b7edb348c25a7df32",
+                x: "926e90fa5345871b05004eeaa319815443b0c5ec1c3da7e93ada1814",
+                y: "e9ce46df925e5b0b1072e63977386f39a0a123c48fae6f0cb65daaa9",
+            },
+            MulKat {
+                k: "2c2133f47b8fcb38ed2750667e57c58e1ea2ab3ee9d77f2d26a6063b",
+                x: "462e284731f3d55b55bf8bcfcb2d445e832db4e574c6428539194d80",
+                y: "00c726b3bfe827ac35e370f5827b99d2efa737d441c051971dbeabc2",
+            },
+            MulKat {
+                k: "68d21c877b68124cfea15ab8d32a9cb25399177abdb38b0b35122f36",
+                x: "a58f015181f2938fa5d9f304d2def867019a3224e7af0b5b67b3ad9c",
+                y: "063d8e1c9b4ada4678b29ff2303423b695745222e653e6b00b3009f8",
+            },
+            MulKat {
+                k: "ae1ab9a28689d6995b077788615c1f5eb1516c82f4ca15672dcc649e",
+                x: "d6ff86d30a12968fe8849bd1def1d9087f741dd4adfd8c087b2955f8",
+                y: "f3c12e89b1ffb70bba0076fd95d1fdc4e948f869e183ed605db5e826",
+            },
+            MulKat {
+                k: "d3fee3b82c9b48fb35ee02b1b5f34b9e55296929efe5d0dfc93b6f9e",
+                x: "93a60d338b7201a1ab900d222ca74cc3695a76a2ea886001bc4383f0",
+                y: "150e7327c4f01e7b5f1f257b30a4b0349ad541058e73dd3af276b8cc",
+            },
+            MulKat {
+                k: "3e41a9194c859d1c9a0621de861a6b9d8cfcd3061788c71cfb6ccaae",
+                x: "fd9a70e494cfe75673bf662d98a1a9beaf2d35cc9be3f862abf54129",
+                y: "ffb44d591121122b5bf89195b12a234ce532211a4933d2f520cc21ba",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "6218f34b712e8041681dd814a76ca187069f3b44fdd1f3ff5ad36375",
+                b: "6218f34b712e8041681dd814a76ca187069f3b44fdd1f3ff5ad36375",
+                x: "646e146e66581db9dec3c7e5520b826b31e21071f04afda7009c22e0",
+                y: "31dd0570d965e61a24e9c04ebdd9a998af1667a7c02be5ca53476dd0",
+            },
+            AddKat {
+                a: "314436c77439e28acb5c57c1b8c484bb0dd81472adf9206308cdc1d5",
+                b: "726932cc348a419655e11f6c4f2fa15d9f24f3b0c1c8bfabca1375fa",
+                x: "ce46cf3b5d82db2e69046222badd80ece68bbdc881185e2e73350f51",
+                y: "6f3a4a5ac535a221101fa1e87db8fc082cd33cdf9006a6540fbaa6b8",
+            },
+            AddKat {
+                a: "bbc15302313fb2ba477687f07a153a28a727ebaeaa84ca4ece578634",
+                b: "3258eece16239298f946cdf898bb66cbd1b9b13cf0223da07aca1bfc",
+                x: "b7292259e6c56ef1d399c3b4d7033fd9a23b576307c5b38bade923db",
+                y: "f878b009ca2d674e24406cc64cb033fec2507cea51a6df8ff29a888b",
+            },
+            AddKat {
+                a: "e1b7d95677b8e2e817be731748f10e4101ab40052741d0d6ad048bc0",
+                b: "59d1f21703a7aa258b5fc069c3494eb4effd99e5360e6bf6e7f1f4f5",
+                x: "1bc5c742741ffd5d9fddb19f28068729fdca20148cb3efdcbfa89039",
+                y: "f59ff87ac25ecc810d19c788f77a275afa6bdb1eabfc74abca61c55b",
+            },
+            AddKat {
+                a: "ca444b6d5e8adadef7187dc0e258107e2f2c0da188809c312c0da391",
+                b: "cdfce012ec91caa085133410f7e41d831e51dd88dffefa514930a630",
+                x: "32b11c251532039cacf25c55f7dab08dc4db8a2563f6c0bde50585d6",
+                y: "6b1f64a18082a81954e5fb58ba77bb11a51b19ebdd0a33d54156ef5d",
+            },
+            AddKat {
+                a: "6d891ab6ec8aebcbc8229c159dbd2152829c9d828bbec29ce07abdad",
+                b: "0da9aa2eae9a48d2aa51929f529a5957aa625cfa5a699f337a93998c",
+                x: "ca0c0366f6be836b777e8839182d398f03dc3f6aecb914444f87aad6",
+                y: "95cdfd3476c33e8c796d6a8327f378479a0053172ed183a17d617ac4",
+            },
+            AddKat {
+                a: "12f3ee4fdece0f36d62752268c8d3ca08ed24a253c38d06fb193f3d9",
+                b: "5acab4f2c7cd1560c9f6c96e75cd20ed675efb4166adaf368fb8ac91",
+                x: "a9a7b6ef09e32768c61e87692f4c66cdcc156aa57933662d6b877a61",
+                y: "c9a9b76d4f251d4b5a6eb692bf440898f94631bba1aa70234089c087",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p256k1",
+        mul: &[
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000001",
+                x: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                y: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000002",
+                x: "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+                y: "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000003",
+                x: "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                y: "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+            },
+            MulKat {
+                k: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f",
+                x: "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+                y: "e51e970159c23cc65c3a7be6b99315110809cd9acd992f1edc9bce55af301705",
+            },
+            MulKat {
+                k: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+                x: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                y: "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+            },
+            MulKat {
+                k: "91900b02acd6c7fc93f9e0f45a371a113c48276ea72d11b9fb8acb54c691866c",
+                x: "f171a3d55e384c981a2009407511572cd5e480ba4e84b561d3e05733d5af8ec7",
+                y: "03f15583ead26e8890adf81c356762885e29e1684a82dfb7021924b1854f9f4a",
+            },
+            MulKat {
+                k: "b45386ef3f4dc8fb6bba8bbc67c48c056d72fcde46eced886bf6993cabc3b022",
+                x: "353eea6adadf8c84219fd1cee8ec18b33090cd8d6bfdf9d976a83ed26d0b6a1e",
+                y: "165e1b0e40fe14c50ea300a52b31fc416b47e1daee3b429e0315b5252e72f169",
+            },
+            MulKat {
+                k: "509c3acec0d47565604d959f38806a91cbd584f4391ecc1254df39ee565d1ffa",
+                x: "bd5e01087a7e851c26542ffb287435e8ce0264d81966bac51f0b413ae173a6ae",
+                y: "92e1d1b4c280ef349b2d3c95aab6f67817987f915067abcad86f769c29cd10b4",
+            },
+            MulKat {
+                k: "bc484385c012971015bf7b2043dcf010eb132339af03ef123162732306427636",
+                x: "84f2d11b7ccca113ced8f1be4970250d62581b7348e65acb2fc51ad38c6d2b6c",
+                y: "641b8d8858d72608fd15e9de2dc6766d1e9e75358ab8de9fcc63d395b24fbbd2",
+            },
+            MulKat {
+                k: "8c5b92670687fe141e5fc1a482321f53404ddd4c333451b9102d6a41e01ef117",
+                x: "a15c625a7150010e6f022570829fd4809e34fd429494912d4dde6cb67ecab3a6",
+                y: "323b7f55e9ee6279c0e855742a824b009e3a6c847ca9478799d0a1467f4714a5",
+            },
+            MulKat {
+                k: "277838ce077377816b7006f4bb98eaddac9e590355a8d879f4fd2ffad75c7ad0",
+                x: "0ff53f81fe2d0e932c18907161bba279304ef8d4f3263d9668c504caf1127b18",
+                y: "9226cedd54e3c8bf666a3db495b0265c0c0e4cd7971112a987fa10ead776e1fd",
+            },
+            MulKat {
+                k: "0e64ed129ce3afc986f8e711466842b0b6c597a2223854e60e84c5c5fb660bdd",
+                x: "8d405395b31c3ded8f096366af013d5589973c3dfa0222badef75a8159fc0a56",
+                y: "27e75d61485ca32462b74690560420f642402ca3ffaa02d55666bc9a111788a1",
+            },
+            MulKat {
+                k: "1e8bc103d320a7134c6d0b3bcc62ff7c0599c7d360bb78aebc082ac0d2eb41ff",
+                x: "908111d4a2be420bed69999672eebbad8c3252ab4e65578de370c24e87940f86",
+                y: "9187a74ea8018a033af7cba70447fd17a2373eb8dff82c018d474539afd1c14c",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "9872728ce62ba10c9ce38f167624a4d05d98592983651739f32b7a7d8225e11a",
+                b: "9872728ce62ba10c9ce38f167624a4d05d98592983651739f32b7a7d8225e11a",
+                x: "b12bd5341ed63566ed353546b782ae0ce3f971aea583eed711ebcb4f730d930b",
+                y: "ec712ca4a1a589fcae7a155db4f232c58e7947d6c545025b37072d40532c8903",
+            },
+            AddKat {
+                a: "8fc1dfffe80322086b0c73a5556324f74e033b6a17b26ff4c68750eefc43bff6",
+                b: "b71de178a5122c438d8e98d088418451bbaff8d80c77a2adc059bbae627d5455",
+                x: "a6da3ffc91f2598a4a4e625b24f9a560327d748f14ae1b581a8b5aa82763b5a9",
+                y: "917f917e34c14fc472756f14aeb1240e8e2021ba0523855f434ceae836596a7e",
+            },
+            AddKat {
+                a: "dbe3ac16d201e28a610a7d022c4b82fad23fd2abb4e5b287d0cd4027d61f386f",
+                b: "c24e28147a9ef035f85c996a0af2353d2ee082284020e58fa833bcd379a9d052",
+                x: "7536f092f90c0a9955718466b45ecc442027927e9e33d964d3aa26e7e73eba03",
+                y: "bc24c5de96a80d98fd6f16aa720602e54e72b6c20719e6fe49ad7a415f1f4708",
+            },
+            AddKat {
+                a: "49b03c1b0d13b9d84af17b8bd1db793d2bc68f2958c8ab74b3d3f131659da586",
+                b: "7231f18eae74f9d428896522b744277a82d6f1c32217c6f2e1803c0ae016318b",
+                x: "88207ef6a71296af6a3d3b1861591c1577b05741aad763d8d5dac442f365d2fa",
+                y: "f8f29eede612771d312008f46beff164fedf547991838aa8d1300816b4cafdaa",
+            },
+            AddKat {
+                a: "65cb7aae693080d76232b1c875ec51216196f5aac833375d35835909be3cafd5",
+                b: "ba9bc1fd4e489b37b1b727bc43b7e8a115ae1809656e7078185c333c86fa4a35",
+                x: "dc26f3b00d64d8808e721abbf7e938550e536c4d47dbef88368ab2595da3659c",
+                y: "8dc1062b352352589c387cdf7c41c463dbac6b8345c5595ac93cc55fa7e6631d",
+            },
+            AddKat {
+                a: "61cfe87204746e0153c856b339f9cf0818336f3b72b2a00c5ee042639cec5131",
+                b: "87ffce644c14f0005d01b9b29e3910db8d5b27a24aa7e25b3da0112e3eb7a79a",
+                x: "82db7926065b9ea19217148e22606d862675200115eab58339275f97cf6ea533",
+                y: "6f17cf2b983039293507d34bbffacc2bf2ffb0486e72ba4ca40aca726539888d",
+            },
+            AddKat {
+                a: "51680bfccf616b91073af362fbcf613db17e6920013e845e0857a0c5aa00898b",
+                b: "1744dfbda04990f10dfdf25323cde5aae693dbaa6216dae9630c662a98acca7e",
+                x: "b9b1a8fe5feb34d071ecc366866ee662e53ca9c80f86aee9065733e429c711f8",
+                y: "5f1cb169a82ed25e99882c8e1141a488ce779b8e28f11db8a5c17e3837f7dca7",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p256r1",
+        mul: &[
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000001",
+                x: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                y: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000002",
+                x: "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+                y: "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000003",
+                x: "5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+                y: "8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+            },
+            MulKat {
+                k: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254f",
+                x: "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+                y: "f888aaee24712fc0d6c26539608bcf244582521ac3167dd661fb4862dd878c2e",
+            },
+            MulKat {
+                k: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550",
+                x: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                y: "b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a",
+            },
+            MulKat {
+                k: "d4d185170879a05e55fdaaa7d3cbe9b93340a1ecb249d7c2d655f3ed338620d9",
+                x: "1d7fe20c70cf82309c95727ce3c5b6f65c865a54aa88b9fb0bcb7933cd070219",
+                y: "655c44c3c6425ad63a39f9f4c37ba7932a5c19af3290cb1584382449c5c7e8cc",
+            },
+            MulKat {
+                k: "effd8f47fb1ef8117e10e0dc687f9cbc61e3ad670c3226fdd0f3be165bd81da3",
+                x: "dfb89efc7e0ddec3b931f7406c97cef297a3eb94b6e4d4c01197a1242737780e",
+                y: "da14b129cd455530465296f66c493317e83d449b5eb39451de9c5f5a42590021",
+            },
+            MulKat {
+                k: "522d5b2ef7bfd6050a023ff9b1a689b7ee53e0393bad98b358b088124783117b",
+                x: "6aace019826585f4b280fa39b5bec6c261e32ccf17142255f59a42465a1305b3",
+                y: "c718e0b6c8716335d8ae32d1c168fa1addc80f81f5fbe97f60913cf596a27169",
+            },
+            MulKat {
+                k: "c8723ee57e926e9d05705c8c1dd4780466db8075d9e4706c86c5dc138469e3c8",
+                x: "b36b655d0717eb5a9b5528d1119db71af66cdd1c93e88c64d6405438f7566ccd",
+                y: "796ccd9bd2dfd806e98fde5abb73af5b9cef58f3478a6b3d0d289999d59f6a78",
+            },
+            MulKat {
+                k: "ecde80998da55ac3e7b92dc5f3c6a5a4bed78be910d534799d2faff974072132",
+                x: "fc429635c6b3df318239f525cae75c1fe4f39701d10713edfd276d1f9619f359",
+                y: "1a632a64237fb18c66c2ff4312306364f5f1925a00aab58ee7eb6af77c61e827",
+            },
+            MulKat {
+                k: "6137726e81a79d9a64e55586c2a4b49a6fb72dcaf974c51be8999557ea41b0f6",
+                x: "8b72696ab42711bee3fa403d8fe3af5fad88a595ae9d911a88731d0498d9d3dc",
+                y: "2e239fb877af52900652d83a9948c6738c06d287c49beace9d65d30adcbbec4e",
+            },
+            MulKat {
+                k: "7b90be5e97723375a2e2eb8ab9aef5c93d5ae44fab0d6bb229bd29e04d932b68",
+                x: "148e8ae2c0f4e18e5abd5e6c0c635070a2a8aeff710971d67d9ab6bdc3c5df66",
+                y: "1cde66362b02ddef993b7c8b668266faca04537875a3a6442bc0088687efd0ec",
+            },
+            MulKat {
+                k: "5159dac128da6ac3fc17749ccf9522afff316960448dbf81429a342428a2dd04",
+                x: "cb6ecf0ee2a5c2afc2d25d73986c244f12d7a2a758dc81bbf537adcbc12448a2",
+                y: "6c471b6044b835e6a3e087af38038d8f002bccc87bccceb5adc410356ee39273",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "d5a43a6bc81621a4e70526a34e6b4e626d73be25ccd3ec0af02f9f4fe58f4f6e",
+                b: "d5a43a6bc81621a4e70526a34e6b4e626d73be25ccd3ec0af02f9f4fe58f4f6e",
+                x: "4bd03ad81df1445e039d0e952f176368a0d1440b08eaba9dd9f9cde05514b3c3",
+                y: "1455541a280f2bf99f437be6dadbce01d872981be107d1d16efd393bbdad8294",
+            },
+            AddKat {
+                a: "4be5fa72b9c60a8c48e583691877022dad752eb4da069859eceaa9721f74682b",
+                b: "0d4561aa902b4d6f5cfbf611714d54ab7328562f2c1a108474551711a939c24b",
+                x: "49872765ad329fc67d8e6d9516ddf6a91f346a4897f31b6cb29538c6cc3db354",
+                y: "af1c8050a4d94a6d531fa3f98977ff8bca98a6c0998ba81baf94391f7e0a88cc",
+            },
+            AddKat {
+                a: "cbaf999e02671aba136d13b938760e451eb714129d2c4151e2fad64c7cf7ec3e",
+                b: "06280d823596f531263f7f6b378c73f7ad568f59a25ed66a15029a0ace4cd929",
+                x: "3a7521410ab9079d9db925ec562793a9df94a9f2757fdc59a1dfc4d8d29e2f96",
+                y: "e0efc4a83deae77e4830914ee6452dd7c3ddc07e5a873dc159c57a0701f012ff",
+            },
+            AddKat {
+                a: "15cb82fd2b61097475f7364f6b71357f4a8c3bc4babdc5cf0b6a70e8cc07e590",
+                b: "0194bfb99381a2935cebe5bc924935fdce667b87d01413b873018d50003c3606",
+                x: "991685a466d1c4397cf68fb746cc0123c6e3def0f39cca755d2499a50289ee9a",
+                y: "5fd59a51450f11cc93f7058979b5ac0a5d069f363cccf921efc4a91513260ec3",
+            },
+            AddKat {
+                a: "b8582d99a70a615a736117b160304cb08b2fe765589c57d0044b99d86e672d05",
+                b: "bb2cdaec9e5a3c8c95c0e443fc870698f422758592d0e3f0d7db258f6c4ced8b",
+                x: "49cccdcb3c45233f46a4b3e1e43257ba79aaa051a46cdbe5349c0f7841ff7871",
+                y: "8ccdbe25217ddb70ff34c3c60d521f453c374548dcde41f15127b2f300f56617",
+            },
+            AddKat {
+                a: "1af4d779658016aa3c32caf944bd50f0b44eab120323909211fc89846ce37101",
+                b: "ab6f254da2c603712dd327de47dc536276e8779f50d89c9e17a61b815e122aff",
+                x: "e485f68fe7f2264a2e3b079b5858a7a2c314fbc87311f72017e73fc64e19660c",
+                y: "8bec7c9953ed01b457ec6333dd10c727f00941a583589140c1ed23711e574782",
+            },
+            AddKat {
+                a: "15c9c914593c3451edc7f81515fc1c35979ce884a3734f6eadf5f10121f28711",
+                b: "9cfe0b97731eba7884706a2cd091cd12ea1ec515385967f59341a42af807fdb8",
+                x: "43f34cbaf4be798f4694001022402cb95c7a00675215ed7c3817ed505f3dbe4c",
+                y: "81227d3c555e6cb2ebd3e4ca9ddcc7dd41c89d52468eac4d8baa35120d8ba2ca",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p384r1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+                x: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                y: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+                x: "08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+                y: "8e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003",
+                x: "077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06bea4f240d3566da6b408bbae5026580d02d7e5c70500c831",
+                y: "c995f7ca0b0c42837d0bbe9602a9fc998520b41c85115aa5f7684c0edc111eacc24abd6be4b5d298b65f28600a2f1df1",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52971",
+                x: "08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+                y: "717f0e05a4e4c312484017200292458b4d8a278a43933bc16fb1afa0da954bd9a002bc15b2c61dd29eafe190f56bf17f",
+            },
+            MulKat {
+                k: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52972",
+                x: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                y: "c9e821b569d9d390a26167406d6d23d6070be242d765eb831625ceec4a0f473ef59f4e30e2817e6285bce2846f15f1a0",
+            },
+            MulKat {
+                k: "85a5fc9a0c53c1fc5ed9c4a76015a1f81f16ed94c18748eadf59330d2deb75b5390bfccaf02395ac17a0598abe2646df",
+                x: "f245b0e3d59f8090a8dca84e1d00e46b35d4a82d59556409fc28b0a88737f50e5a2bfa0cdd5d874d088f487026d47100",
+                y: "46aa10309ce5d7e9a7c7bb36763dbffb1dce8b8dbcc8a1cf5eb79f1ca49bc681a939d3f7c78cef850369ad2f6603ab58",
+            },
+            MulKat {
+                k: "316c895548c3ae3223cfb5592d65a1f4e5c9f9d26e253263aeb3ca6fb7f15b44df49d8df621f52e7a92b70661e881ee4",
+                x: "8504fc4c0e043c6ffe3a86a4bdddfd752c38a79dc125a9f25b2b47e60f7220edae59299ddcf69081e80b69376b5b90a2",
+                y: "4abdb873bbf2393d496ebdd1cfc288cc80bea04723d0d329cfdf81d4f14ec7ef387af00f3983345b09b80c64eec42af4",
+            },
+            MulKat {
+                k: "74ffe1d6ad01a28d74cbc321db6879dbee2e1bccc775b91e5e58c60f2d54bbb39c935d8bfa701c8ddcbef24e5cdf1069",
+                x: "64bc3204d9d0fa9ea4182d0286cf1257b391cc9e6e3bafa38f6636f2895b12f72f6cd67415873b0acafd0eb6ac34cb0b",
+                y: "86a098856579f251345df8a0568a8080d71ed3e58caeb3ac389edcc0921fc1746ca34aeaf133770d8c905509862926d3",
+            },
+            MulKat {
+                k: "45c9938ff24e061f98d98bc38f30998e1d010a410f317a8dd301fe751de81bfe2df4c2baa868c6bd53611abdbaa663c2",
+                x: "4769edb56499019f306b0c6161a5db186d0c2b08da512bf40f80f146b52afe1e9f94b45fb3d4161fee4ad028f5bc2ef1",
+                y: "a056ceebbdbb675e9029dcd47494bf9204ffebbdf37784cbbc3e165938dbe80534ff7b21537b3507a01f72756797224e",
+            },
+            MulKat {
+                k: "a1d31309061c162ee2b3ef0d0ec775ec0891a2f1a063599cdf0bb7bc9c22bfe1a02e774d2b0c4ff2960e6802a8dcbc7c",
+                x: "42d9bc0e8b3d481c27c3f00acceaa8b5690880e0d2150d067d16186d1b6bda7f4306ed84a3db0348031d22b794434a9f",
+                y: "f53da7c10e44409def09b44f29bbbdced3969ae4f7502e30818ba1ca9e28e8f2b3942ff416e594aa5a102d2408623e0e",
+            },
+            MulKat {
+                k: "7c8feb4fc02d0939ce77cf7ce28b96be03e3b757438707b0db2ed7d9dc447e03fa8b238a69b74875f6743b740ed8548c",
+                x: "9ba7db7eceab71485ad2c31e3877e6f5ba59d23c3d482e9af39914adfa0b8f6615277ab0ee184a779ae8f2a74b7e8d53",
+                y: "37d67e98f893a1794a5d663fc15186224fa5ecf4eec86a2d9c243b724943c89d8f9900f1fadd23d4c691310e45a1f69c",
+            },
+            MulKat {
+                k: "e3ff7210a16b06a11d7ba515af77b1f0698346700b22155f887b0ee017d041bcda96f6e4b5e64111451da3e34b07afa9",
+                x: "40e0cb6f0b0288126ff7c58bc035ed7dab35930a21b3102fd3d78f1e66f72ce770d507e2b16e95ef294500d2a2642e6b",
+                y: "2f39318ad31b22077406bfc336f76580d760d3acd4a319bdb90118f40c4734c4280f47656d05421ca2ed57d2b06488af",
+            },
+            MulKat {
+                k: "173f65acdff362f45834b14334441949a0f4ec9e9979e5465159a3b5dea1d2753d62883ad6517221e7377f42acd774d7",
+                x: "c4c66db7dd588ff2895169dfdde3318f789a3a29e0fe35ce4ed203b4779deeedc41e2b68f6f61a769a16a02c82de425a",
+                y: "f49de865ebd9dee181ece138a32f7c04198381f931dcc467734cc13e23086a8db1cd75fc349f4ab7190153c8d8363bac",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "76c3ab23f00ca007ca9f6f2f8bc9b2e47f2fb02c50ea9ee13bfa0c6243edad92f62fded8fd3bd2d17172153e34ae693e",
+                b: "76c3ab23f00ca007ca9f6f2f8bc9b2e47f2fb02c50ea9ee13bfa0c6243edad92f62fded8fd3bd2d17172153e34ae693e",
+                x: "eb1f0a843f4e9b917022eb89fcc18c20e7ce69e30ebb016d59688b442bdf63a61a79e089712af57fdafb9a689ac009e9",
+                y: "d029a9758fb3b835a5bfd6e007aacb899a0f28a2dec4c21dd0cc5e1dfd5898b6d6a6a37b965e525aee61ebd360443703",
+            },
+            AddKat {
+                a: "cf8e414f29cb0e58a5349261d2ffe0f5e7d956566d3a85508c8b3e9f54f153fd3067aacc07cb0fcc5c677c1f8cd71417",
+                b: "05bdc10d8d76461f96a1de012cfe6030eeb326829be4bddacf4cee35e217342cb02426608d27f9480cdb3ce51ede1aa0",
+                x: "fc931e182292194aa995d8e0f5fa5fb6e5468085be03106830d889cbbddc89a9ef8ee61a6b3bbdeb98f02ad15604f6cc",
+                y: "9fc70a198217cf842550bbff6db06d2ca59a22d323aeb7f4088f21db1c8c232c6db1a2e86c5d6bd4b695cf8f2c771e10",
+            },
+            AddKat {
+                a: "630257d34da6e4e3c0e10d5438d6d45a1672083e54fe898f929af0fffe0e3a7681e1bc53fa514dbec0fd667be2fe228e",
+                b: "84c3681d4edad679b20f641702f97dc917ed3f83975e3702dccbba1128b602018a2dea93d200dec259dace7b9f7cbd59",
+                x: "668b97aa551af9c4a93d3b2085de97f643d8333964a5a29e0b8ddce156684f2a95e552971135531d24aab290c7201d90",
+                y: "a551581c6cfa3feed0e2b5844416ba16427d85aac5d7b959a51212025c8a705e8151461591e3de10ce7d07d5dc33ce66",
+            },
+            AddKat {
+                a: "459adfb74f94bf05274fdbfd841b41b3d8a1cc02b6464fbfd554ca2723e5fbf2b0031f5122ad3c7b4744661bfa903491",
+                b: "fbc8db630201808394eea19c94a2ce3c15c14574bbe21987998029ba3c043142087f93ffa4ef3c832c5387fb283187ae",
+                x: "367631f3ad60d09c044d8e77d3e6511636d946e551708f8e79c75bc8b8f9697a304bc7dd93756e17e17721c267a64d9b",
+                y: "4ca44ef95aeb70e72d3de9d420f6803e0861e4820cdec182fc45899f4d9351743f4b5d9d0c2d7ac4e8632c31825c391d",
+            },
+            AddKat {
+                a: "aefb5e5358eda420bbb0d8722a6e944f0b9441071296b55d40c2269b841b54ecd7742ab64778963e1b7930011aa49374",
+                b: "2d53a39ac40a0e7e59aa819775b8e09cff64626e5d6b6d5c435a775848f5ae8998fdbafb30d122082ef4cca066ea0681",
+                x: "2069ad7a21f678f73cf0780e4d85d42e01de7650fe9149c92d1bc570169385377f5baba6f1137c2e66347bf9bdb04a7f",
+                y: "92beb96581b34c7cb462f45c8936e0faff30db546fb3387fc49ab946aeb44a96f2dccc0380a4b2a633c0c5d418bd67f1",
+            },
+            AddKat {
+                a: "626e38acddac2f578d5e7a7133e9129f310ad4e5ad09ad8447c57d004fbdc1fdf630c0ef077f08b93e4a568f7f08059c",
+                b: "cfdee22dc3bcda5cda23977cee544b7d822b2c585bbb1d07a8baa5b7dc17f45c2ba5ecbd40fafe0087868ac9ef311285",
+                x: "27d0df59efc6788c411f8da4ebff2d2779ef9a8660af6e71168b63702a6c51760bae820fd0052419c4ef37ae37c2a108",
+                y: "b066b283198165d04e615da1eb9a5b033d4122ba859e96c9c498f56b25b986f80caa863d0e69596da5d911181c6a2486",
+            },
+            AddKat {
+                a: "62a1f1283c56b978a1e33e6abdad217b1131850b739102f092c3b1bcea223b97837c15d61e8f7bca004b04921f2f65ac",
+                b: "51dfae0be238a93210f472c16e9ffb3d29a4880a5599b4192324216a1fd22adba2decfd4fcd0edd4edfe351a63c92237",
+                x: "1a72f29e25d633599cc53c8602a9549eb72b8aa776653b6a4c477528663ae6f25e8125b7aee8e774070f8e90666e182e",
+                y: "82112fe5dc8cc26a75001f536e136c17b4d4222c68b7cdb3d027676e083d81d550834af6fab6098343bf6004fae5e36f",
+            },
+        ],
+    },
+    CurveKats {
+        name: "p521r1",
+        mul: &[
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+                x: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                y: "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+                x: "00433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d",
+                y: "00f4bb8cc7f86db26700a7f3eceeeed3f0b5c6b5107c4da97740ab21a29906c42dbbb3e377de9f251f6b93937fa99a3248f4eafcbe95edc0f4f71be356d661f41b02",
+            },
+            MulKat {
+                k: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003",
+                x: "01a73d352443de29195dd91d6a64b5959479b52a6e5b123d9ab9e5ad7a112d7a8dd1ad3f164a3a4832051da6bd16b59fe21baeb490862c32ea05a5919d2ede37ad7d",
+                y: "013e9b03b97dfa62ddd9979f86c6cab814f2f1557fa82a9d0317d2f8ab1fa355ceec2e2dd4cf8dc575b02d5aced1dec3c70cf105c9bc93a590425f588ca1ee86c0e5",
+            },
+            MulKat {
+                k: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386407",
+                x: "00433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d",
+                y: "010b44733807924d98ff580c1311112c0f4a394aef83b25688bf54de5d66f93bd2444c1c882160dae0946c6c805665cdb70b1503416a123f0b08e41ca9299e0be4fd",
+            },
+            MulKat {
+                k: "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386408",
+                x: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                y: "00e7c6d6958765c43ffba375a04bd382e426670abbb6a864bb97e85042e8d8c199d368118d66a10bd9bf3aaf46fec052f89ecac38f795d8d3dbf77416b89602e99af",
+            },
+            MulKat {
+                k: "0167f6e4f5bd418cd7d2008a706cd9604a5f6ec1b17fc3d171f3d59bd278891dc4f39c1b18f7c69e903af0b9b09672f69ea15c197d8998f144dec56fbe3fc6fecc0e",
+                x: "019fb0d0174e16ecfcc5766bf29c43db599845522dfaec67120f103f492f2544db4e3a03464e082ca4f46ea9bae005f7152ab41b09b29348153a0110f6a9480102d3",
+                y: "014ae13ca82909a4f3a75dcc67a305a715292d1dfa72d6cf33a994c08545cbc20e6817021490f79ee9996257282700ca0b24936004627a1e7d418c369453b8f60e4b",
+            },
+            MulKat {
+                k: "00b864bc1035eb0f65932cf6fa6a4264f79c324ead2bfbb034c72a0850c4c5aed7bb8977ccff25bfd57168369830eefaac98d65fc143ae46e6cf501c4766b570d6a2",
+                x: "00ea4ebd1386a4314123644ba7443c70486d8f07d629ddb0988325533cfa0d40bdf737ff6140abe79b4ebab8b0534a436be3486073233afc1e8202513d237d1fa60b",
+                y: "005c447c4cd702105cbc903d02fd829ebe4d1d6c85c3d4a2c29dde9e95c1b78be85f9c427e004a16f69afec88e3cba937b0158c5f54e064d8fac5ec9c423566afb9e",
+            },
+            MulKat {
+                k: "00c2eb80487ac8885885b259393b904b59753ee0d1277430fcec4c57d0b00406b7efe54024f16fa7b30597dbcd4d0f143d6e2f98789c5164c831d5692e637abf3b99",
+                x: "01f1c0e716044ed272918d10a76d00565fffccc396ac22e10c37a5442f2524b2345ff22afce143e29952f4a9cd75a6ad10f1636a4e69a3cfe59925b7df62dffc9f56",
+                y: "018f62e672c24a6d16baf1aec5fd85f3a290836b20e56ed87c41016870e8aee674328fd7ad94ff84e9cb3d7475226f05b1c2921dd85ea8b4e6a084ea472ad0e30007",
+            },
+            MulKat {
+                k: "00d679ccdd66149597fd7a10b418008b5cea675749fbb6afb3bf4e5f5fe0cabb1663c3f0377d1064126fe2580ef873adabe4651b54cd97abf0c5f479f0d2623b9658",
+                x: "00f4d5857db182e7d649fb7004197cb24f004f927e2039a74687e4e71c6b8b081f15b462c391900a7b4d55f49e595e1f23bfdb092c8a0b4671041b42668486cefbbf",
+                y: "01d0fab1b1d767b94e2f7a959202c7e66d3108c5513453ff2519df0cd87cd7ba5f5ef12f29727b8c4adfc30a2c4d97c0d045677a1068724304ce42d98ff76a6dfc71",
+            },
+            MulKat {
+                k: "016ce3a4001a0153587f7317cad8691b922d90216d14e048b72b854e569c1821764c647a5cb5d46795d2f5f602619959ad9f4c2be3a8a7153f8eea835eb14c7aaca1",
+                x: "0168a10e61b197dcb15cd083e59e57f92ba6388d672871b62bd2198613ab38133357643973fd026fa7957b8d2e4a3f3d4f640f0b03bc92bf0bc0e9809076665e18a6",
+                y: "0194c92c22f7902e53c22c96e50039830ba393cd1f34379a49af5a7ae75c0fe8fdc5db6a518cabd7a40e651299c25f7e017e81c4e675d19715e93b4bdccb442c152a",
+            },
+            MulKat {
+                k: "00f610de7dadd93794f0069f1b867fa3e5ca22de42602f1357a9e5f836197ad43c0044d7a9fea2aa1de22067c0d5889a688cb19ba5beabc75ebd83de8b743b179485",
+                x: "00af983365d576daa157a2b3cad976604891301e65d2f5318d68f94798ec654e3d39cc4edce56e57354059b7aaeeca1cedbcb67228cad008e7d321344d669b063c8c",
+                y: "002cf481f459d961fc6ec2760c244eefc626c03876a8815beb9044f82dc4c88f5e18dcd7b10ded7e5cfb9ac1de076bf92dafe7503b3fa190c655e41b6dfa82b34cdc",
+            },
+            MulKat {
+                k: "01f8b325cc43a46e438c2b7da50733cadf9e1577049988ebf01639de75fca386d428b13addf432555256afe6c5e775078eb6441bacbc2cabc9a8fa4155a295d719f4",
+                x: "0116d54bc3121ba0e55c63bb25760c0bab62047ec1000c056e1f6e3b2894677fbaf51012a341e74d78902eb04ebaf58034a86cc58069bbcf9efe261558d68e34104c",
+                y: "002c0f4a679ad93af579c4c7f5a1bde483bba91f8f12ea3bf73e3e25d320bbe6e8e37becdb87348530fcbb85400d1aa629bf7447850c889d6282e1842d1d89cc3793",
+            },
+            MulKat {
+                k: "013d052b60f613226c54fb1a792f4ab51941f38d193d4dd09de36fe2e77e7e910946a041eec085206a011c04179ecabad1ba3ce2b826f39e05cb05092ad487bbf0d1",
+                x: "0066716b94e81a14ea4dbf4121a00f72115041468e5f11da39617e967817b79b1125da04dfd978284032bafe5a465806224f70fbf183c5ee89bfbe0723949f541355",
+                y: "0104f45ee80bec26c83fa50d064a28a0fc20577ccb60888583aa1e14649fba31ad1b8ce6791b44bdc97ab9593f417cc8bdec4a0ff2379271e1f22671c76ff76f1bf2",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "01ce5e912b2f5e68a66de6f3857ca8805521d49de5ddb93dd7fad70d4cc82748fd5156bd527fe9b58736d637fcc67de4317fb1da833a4a0991a35b2cbf18fd4696db",
+                b: "01ce5e912b2f5e68a66de6f3857ca8805521d49de5ddb93dd7fad70d4cc82748fd5156bd527fe9b58736d637fcc67de4317fb1da833a4a0991a35b2cbf18fd4696db",
+                x: "00b483ff35d88fd2169ee4567db770be8b0fb9271fefe9f0b74f76d69c99c8c14440aeb8daf263a1bb5bea2b208c94f112455c4e26e28aec62b738e36b1c5e68cfb2",
+                y: "00d356634e3563b6cf6010df7cb252997f7d091368be62ba9f9bdf5638738f51277affe7a68b8e45412eedf15a1f21953ede51fc0ab97b427a07fdba522b9bfca380",
+            },
+            AddKat {
+                a: "0030d9474061c9d5121b051fd26b60c5377696abd7d6613a1f48070a9bd0a20b817534bac77f9b024e0fbdb14f1f238cb440c3448565355be0a28786bd4938759396",
+                b: "00134fd2c6f13fcf33fb1e4b9c44a8785ec4f4553cf55c1ff7e9963f4b212af1356596cb3065a23c52c015ca8f0674b9d03c1682cf5530370745edd564aafd542126",
+                x: "017f11ec73cbf33db95fd684360af704744bbec69fba89637bbb8de5889a6be8bdbc87822aa436b6e262e400984ef6ae918bcc7090689d97356734bd125c9a75221a",
+                y: "013a78c6c52b7bb4a13bc079de3fa9f8fd84c1fb692b96904e79f222066a1b1be9eab18d655a9bb743a78eed948626943103e7996e361c2b85b41db95acff0517b51",
+            },
+            AddKat {
+                a: "01d4cacb3539c4f3fb1dc8cb0bbc2603de3af80dc148a66c05c4ff68dd4de4358c0fd4f6dd19971e0ab60862a294d48b59f7f1413a20c24690d5f80e89e6a08ceeb5",
+                b: "00b5b95067da161a77cfeda0e10bd746fc94a114bc1acd6ead5898c853c9eae0ef409b7e901ccdd1b093560d6baa6eaf3eb79332c8882b802be03345c78844fb2f5e",
+                x: "0097d5f0fea552a3fbe2b83c0907c0a1258954f5d4b2100cfa7541a56b85623c59e20c538c7d31d4fca04f2a62a3e0ac9ae7f6e9f06676e0e9f2584243d96cf98ca1",
+                y: "00719d7c8b4f9f6cca2c8d89a7ee811f0a216c464b74d4fb31a090165d089d84e792636ca7ef32ec09cd5d3f8e67d9190ba58c8426c00243490931ee0718b84ffbe1",
+            },
+            AddKat {
+                a: "01f6604365b60048c798ee3a28cd7f54cd9e43b572aba89bc7d0830e58b783cdddbe121dda366123d9c991e6f0e83094465f7507de1b943a23d5659fc80c3aeaac36",
+                b: "01606c3b4f314c79699aa5973af8c7b6db45e49ebd869b163cb49ce2c1ad5dc84635ce0288569ae3d323320c9066aaac055c5e232343c5204bd55b72449ab8258675",
+                x: "00449193207f738bc1d74d5deeece97805f4b129f5ba9b6c993289460cd5b1d70658a746ea5e67e6ed76b0f10048bc8cade66470c974b160cb86908f702e0281d52d",
+                y: "01f5429d17504caca612351870449cbf7dbd52b26f93bbb83f2f0f0af309c2b47eb891a1fa4967c4b7b8e4c0dbc9978367004fde63185a2440b1e2e3ae00c70eda80",
+            },
+            AddKat {
+                a: "00712e17aea589d7273f264bc4d9a8662459fd29fc5fd2c90a2b9c07fe2a62e140a83123a24a157c3931e9d73be31d701b1c6daa42fb0243f7614a9a474ae436e026",
+                b: "00c6d326c9dd6a9d90cbecf6dce2219df40bc2c3ff46b8cc64b6dd65f80cc68380c95b302e44128d05bab057253a81dd267c9b86c69ce7e024ef990299e285b9c811",
+                x: "00d5e826d84f839b193efd4e94809e47d103bf4ab5e6a8aff7cc977e3a74562ea23e912632b9888c78ef8a7234d2397f4af3a672fcba10056c7cc5d02e2970ea7640",
+                y: "00887ceeb4a5d32f24e0f7e6c5a7364cabb8e6829f1267c71ed2ca940473150942ac20e5fe154ab32bb212e7007cade1577d3b4881102a5ee437f3a8e0fa8020812b",
+            },
+            AddKat {
+                a: "006962ea24e8b0a7c781fd39b0e9cf9aaf29ea57ae9d47070e4a5e3d2975b9f12adff7cf34a2ab25cfe5cbfc27a664013dc1b0aa28d28e77e5c5e59550714cbbc0d0",
+                b: "014b5671c27acf4f7ae4d74f22304da20fa22698b2ccb0d8a0d7e8841e50790fe99ab241f41bf0df95c304277b198a83a7abb3d8c182b4bd70acd9bd17285e2a74d2",
+                x: "01d94cf06535c558f24135e41d5944bfb473755879ffb1bdcc1b91fa1946787ae502c5d39a2d88adfd22ccf435c33c058d72388eb9e53e776262dd003c6c99f3cd94",
+                y: "006eccdc56044d04c9a2fca80cca82d0a303b60a53a0fcf96858fd1412b257a9a02d4ce59bffb512eee6ddf2e8e8d0c23b01af5c8a5afd4afb5909a84c9749bd57eb",
+            },
+            AddKat {
+                a: "00e48903e1898a4a7c8f77a3a8a785ea173007499939877dc499745189b1d9584ff0210506a707fc1ec965e1955a6059ec95f112dab48e45ef35dab7c79a49a70a26",
+                b: "00c3808e2286a0c754a3d228214b99721223a269ec4769087b322b45799bd9ac84e58f79e114890d828c51ba204bd95f3312d620d1710a9315c98a056b76c63e5d7e",
+                x: "018521fea2d2b505ee0eb729b40be2f48e57fbea724df719cdc07175e63b2ab2bd876d96ab17e0e5f4037f4cbd6802218693795a70b5bb49d7f80724d45bbaafb066",
+                y: "018cc982212419642e597cb3873335ab7e7d28578c363ba14018ac5534ec7d8a8606ca60dc64110f5d728e34725799f2b48d5e8cdbdb97c75e106fd47184f92429f1",
+            },
+        ],
+    },
+    CurveKats {
+        name: "sm2",
+        mul: &[
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000001",
+                x: "32c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7",
+                y: "bc3736a2f4f6779c59bdcee36b692153d0a9877cc62a474002df32e52139f0a0",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000002",
+                x: "56cefd60d7c87c000d58ef57fa73ba4d9c0dfa08c08a7331495c2e1da3f2bd52",
+                y: "31b7e7e6cc8189f668535ce0f8eaf1bd6de84c182f6c8e716f780d3a970a23c3",
+            },
+            MulKat {
+                k: "0000000000000000000000000000000000000000000000000000000000000003",
+                x: "a97f7cd4b3c993b4be2daa8cdb41e24ca13f6bd945302244e26918f1d0509ebf",
+                y: "530b5dd88c688ef5ccc5cec08a72150f7c400ee5cd045292aaacdd037458f6e6",
+            },
+            MulKat {
+                k: "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54121",
+                x: "56cefd60d7c87c000d58ef57fa73ba4d9c0dfa08c08a7331495c2e1da3f2bd52",
+                y: "ce481818337e760997aca31f07150e429217b3e6d093718f9087f2c568f5dc3c",
+            },
+            MulKat {
+                k: "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54122",
+                x: "32c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7",
+                y: "43c8c95c0b098863a642311c9496deac2f56788239d5b8c0fd20cd1adec60f5f",
+            },
+            MulKat {
+                k: "5607ffc158c40b0a0238f22127c19c1a2eb4db42f2a861f4a7193ddc04a68259",
+                x: "010710450cf2f91b359929d92c258f394df585914abb4c723835f964b7e8545c",
+                y: "760f87528d492e144da8a5a2197e66ff0d3c62dfa12f8c7d0590499095d9b86d",
+            },
+            MulKat {
+                k: "cd1cec2de0537335bfb38b6ae5f91c85d058a2059e2192ea02769129ef14d5e0",
+                x: "d9a8302508e0eac4d5f4bf7788ba2c3b711b3490564165d8ec40ae5c5cf9d2aa",
+                y: "89a2cf4394aef376751697d479acb457faf87ffd479757b61dd80bcef726e44d",
+            },
+            MulKat {
+                k: "b01c1bb01a06ff0083c3809a3ce98df4ff59111a9ae9800b6caa676338ed2b6b",
+                x: "02073ff91fcd9e7d68e9f011eb1d8da5d34f73b349e22ab03bd9f32c9aca6266",
+                y: "10caeb15450422372fedd5298321457a0db0fc992e4db3283eda736031d2e6de",
+            },
+            MulKat {
+                k: "9f3ccf1b29966c41a312825dafb76b937c069ab3f552e4fa075e44c0f05e78c1",
+                x: "994ac5d93e52693defe860c056dab0a3320cae061bbf85a0ac85a7fd2d52a657",
+                y: "ab64518570389860066435942f658c85bc7bcd7fc624d5a45a6809fe351b6a06",
+            },
+            MulKat {
+                k: "01ca964b554b1c9b7962cd1c7eb295401dc56bab6d502f046c7643f9d045a9fb",
+                x: "dbb2abc536f9d123e27533ee2033856a1850fc9951b9983fab3571e2549e1903",
+                y: "fd4d565a9599062abe7edf32623d2fbbe40945ee91f29b894e9aa186e89b4c9c",
+            },
+            MulKat {
+                k: "1cbeb13594c28099ccfd76a746a68fdf67d290483503374d5e24c0de79d6b760",
+                x: "c054b244a62c657711c95a7a55c90d863dccb1ed8fe40d094c30cc20f34e128a",
+                y: "0fe74799b79264932e76664c26c78d65322c9bc5e0b984d4034c1d80655c47c2",
+            },
+            MulKat {
+                k: "d4c3ce9d35780595769ee0af0f3de3cdb040dc781306a048541203e34d472f4d",
+                x: "128f6a2ecdd91fd0f050fbecdd017d8efdf830c507b07e3aa9cef4ef5afefa9a",
+                y: "8846f92c1f3969fbbb968cb1fe85397cbb1f24c49314e128ea4841f3e72f9667",
+            },
+            MulKat {
+                k: "2ba192483341b2d5617c9741cb08d9605ce5adefcec7be8a9f8aedbf783ce889",
+                x: "7bcc93556eeaec4bc082c5dfb0defcc5b9691f5df9df7106bf95e709dd1bc86a",
+                y: "d6ba40183b81e568c4cbb732acecde76a4fe51954a76fd8f1b6d4fdcf52fbcb6",
+            },
+        ],
+        add: &[
+            AddKat {
+                a: "92bdca9c16a3729311a448ad5716c74ddc45ab9f3d6955a0d69259544ad67fac",
+                b: "92bdca9c16a3729311a448ad5716c74ddc45ab9f3d6955a0d69259544ad67fac",
+                x: "101fd24fc7593de58f50404d40ef01ed7234ba7ca71ab0829f2a1920adefa5db",
+                y: "a4db73c213f839b540332d2256f5a3853dc797297a57e522043366c7635c7bf2",
+            },
+            AddKat {
+                a: "245058a7bb2cf08b20d7e4a3df6136658e7a14f9cf8babb93e554979d4248c35",
+                b: "c85a2e7596d7f6ddba931c7324c1346b972436408b0e1e838052f34354e5f4ca",
+                x: "23e26dc9e579461aecf4e6dd44d1ecba6b7d3859ff89f9524ac0d3dc9e806a35",
+                y: "3ab3a25c49f971856c9e42554ddc85d378ac02280b97a3a4ea0a778f36a65641",
+            },
+            AddKat {
+                a: "0c035b43db03e90985b7736f0a3615c946baace9aee547f36db7031136ffc977",
+                b: "b4bfb9ccda0f46e03aae422ec8d06f15510c4a6122cfc8686467b39cd32bb2d8",
+                x: "3c8394c095e7dee9fd935658df7d78adf37aae5ada37c74122a92ca9e8640a7b",
+                y: "471f5f86837bc5853550444a1703ac3326d59ff7f440bc5f645fea01e4eb3c3d",
+            },
+            AddKat {
+                a: "d54ebe62576b3c23a2edb9c64ae31da0ec67890f9a57bbb0be60aa0a4240e9a8",
+                b: "fca8b3d5fb1bfbc6ef36018fc1c46f6f002c67715d70a79b2b568d6e49507612",
+                x: "f3739cbeba32baae94e43c496f8894dc24b20686adbb2004a6d021a7c2307b0b",
+                y: "7aff059976766cd3d09ea8500878acd5d744ff5910ce64777e1fd1b7ae76e319",
+            },
+            AddKat {
+                a: "cc6ec6f61cd4e42dd5da122c31af14bf1c8c518d6c67421f8dec3efdbe304c6d",
+                b: "341b4bb036145dbd499ff20a5cf4a55d3ad20e06636eb4a20a4f45d1748d4da3",
+                x: "f918d6cc5ec5f6588d0c3fb7bf9d9cd8672d23b8a0cd8b9a61e0740b8bbd0b3c",
+                y: "ced2cdb8829b3708b35241da00f4792035b0b9cd8df4b6dcb079d174a39a5f8c",
+            },
+            AddKat {
+                a: "9b7ab7ded8bb99fb1a6fe64d80bafcabad87b98b6b5aa7cf6ff5779c372caf59",
+                b: "8bf26b2dc767b68d5b5def1d22e02e542ae6bd535a94cc2fde13b30de1f8a02f",
+                x: "ed0a5af103d12d25d5f4b1cee30cd7c5e73ec20de70cd5707d1790cf0d130083",
+                y: "f86374ea8d283841d86f86ff9ea1afbdd4b55d6d0f08effb2f804b73d3875783",
+            },
+            AddKat {
+                a: "51f911fc2162fcc8c7256ce3a2aeabc8d649d439da4adf8b17ad2545991d8e03",
+                b: "fb23a302a036f1756222e1529ffa50f682ce2f3d36081221b00880234f0845e0",
+                x: "6bd033fa98c61433b2f7f71b14076df445abc9b33776c17ddf2ee73e2dca95b1",
+                y: "96ffbcb06b3423f5c80d1a8001562b6a795dd130b7fa04aa955f88c7945e8f8c",
+            },
+        ],
+    },
+];
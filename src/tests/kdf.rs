@@ -0,0 +1,108 @@
+//! Derivation of scalars from a key derivation function output
+
+// reproducible stream of bytes, standing for the output of a KDF
+fn stream(seed: u64) -> impl FnMut(&mut [u8]) {
+    let mut state = seed | 1;
+    move |out: &mut [u8]| {
+        for b in out.iter_mut() {
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            *b = (state.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 56) as u8;
+        }
+    }
+}
+
+macro_rules! test_kdf {
+    ($name: ident, $($m:ident)::+) => {
+        mod $name {
+            use super::*;
+            use crate::curve::$($m)::+::Scalar;
+
+            #[test]
+            fn reproducible() {
+                let a = Scalar::derive_from_kdf(stream(1));
+                assert_eq!(a, Scalar::derive_from_kdf(stream(1)));
+                assert_ne!(a, Scalar::derive_from_kdf(stream(2)));
+
+                let mut okm = vec![0u8; 2 * Scalar::SIZE_BYTES];
+                stream(1)(&mut okm);
+                assert_eq!(a, Scalar::from_be_bytes_mod_p(&okm));
+
+                let mut len = 0;
+                Scalar::derive_from_kdf(|out| len = out.len());
+                assert_eq!(len, 2 * Scalar::SIZE_BYTES);
+            }
+
+            // the low nibble of the scalars is about uniform: the chi-square statistic of
+            // 16 buckets (15 degrees of freedom) stays well below the 0.1% threshold 37.7
+            #[test]
+            fn distribution() {
+                const SAMPLES: usize = 4096;
+                let mut fill = stream(0x5eed);
+                let mut buckets = [0usize; 16];
+                for _ in 0..SAMPLES {
+                    let s = Scalar::derive_from_kdf(&mut fill);
+                    buckets[(s.to_bytes()[Scalar::SIZE_BYTES - 1] & 0xf) as usize] += 1;
+                }
+                let expected = (SAMPLES / 16) as f64;
+                let chi2: f64 = buckets
+                    .iter()
+                    .map(|b| (*b as f64 - expected).powi(2) / expected)
+                    .sum();
+                assert!(chi2 < 37.7, "chi-square {} for {:?}", chi2, buckets);
+            }
+
+            #[cfg(feature = "kdf")]
+            #[test]
+            fn hkdf() {
+                let ikm = b"correct horse battery staple";
+                let s = Scalar::derive_hkdf_sha256(ikm, b"salt", b"wallet/0");
+                assert_eq!(s, Scalar::derive_hkdf_sha256(ikm, b"salt", b"wallet/0"));
+                assert_ne!(s, Scalar::derive_hkdf_sha256(ikm, b"salt", b"wallet/1"));
+                assert_ne!(s, Scalar::derive_hkdf_sha256(ikm, b"pepper", b"wallet/0"));
+
+                // the same stream through the closure
+                let prefix = format!("eccoxide-scalar-v1/{}/wallet/0", stringify!($name));
+                let hkdf = hkdf::Hkdf::<sha2::Sha256>::new(Some(b"salt"), ikm);
+                let same = Scalar::derive_from_kdf(|okm| hkdf.expand(prefix.as_bytes(), okm).unwrap());
+                assert_eq!(s, same);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_kdf!(p192k1, sec2::p192k1);
+#[cfg(feature = "p192r1")]
+test_kdf!(p192r1, sec2::p192r1);
+#[cfg(feature = "p224k1")]
+test_kdf!(p224k1, sec2::p224k1);
+#[cfg(feature = "p224r1")]
+test_kdf!(p224r1, sec2::p224r1);
+#[cfg(feature = "p256k1")]
+test_kdf!(p256k1, sec2::p256k1);
+#[cfg(feature = "p256r1")]
+test_kdf!(p256r1, sec2::p256r1);
+#[cfg(feature = "p384r1")]
+test_kdf!(p384r1, sec2::p384r1);
+#[cfg(feature = "p521r1")]
+test_kdf!(p521r1, sec2::p521r1);
+#[cfg(feature = "sm2")]
+test_kdf!(sm2, sm2);
+
+// computed with python hmac and hashlib, reduced modulo the group order
+#[cfg(all(feature = "kdf", feature = "p256r1", feature = "p256k1"))]
+#[test]
+fn hkdf_known_answers() {
+    use crate::curve::sec2::{p256k1, p256r1};
+    let ikm = b"correct horse battery staple";
+    assert_eq!(
+        p256r1::Scalar::derive_hkdf_sha256(ikm, b"salt", b"wallet/0").to_string(),
+        "2db7e510cd1e5af1619ce991399b2f2509da6095ed6e63a8b25c4e38f7a03c71"
+    );
+    assert_eq!(
+        p256k1::Scalar::derive_hkdf_sha256(ikm, b"salt", b"wallet/0").to_string(),
+        "2c951c01635f95eb79bf9ec10f7ffe080643ab885cdb25fed65ee7ba1c0bf216"
+    );
+}
@@ -0,0 +1,352 @@
+macro_rules! test_keys {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{
+                Curve, FieldElement, Point, PointAffine, PublicKey, Scalar, SecretKey,
+            };
+
+            fn secrets() -> Vec<SecretKey> {
+                [1u64, 2, 7, 0x1234, 0xdeadbeef]
+                    .iter()
+                    .map(|n| SecretKey::from_scalar(Scalar::from_u64(*n)).unwrap())
+                    .chain(SecretKey::from_scalar(-Scalar::one()))
+                    .collect()
+            }
+
+            #[test]
+            fn secret_key_constructors() {
+                assert_eq!(SecretKey::from_scalar(Scalar::zero()), None);
+                assert_eq!(SecretKey::from_bytes(&[0; Scalar::SIZE_BYTES]), None);
+                assert_eq!(SecretKey::from_slice(&[0; Scalar::SIZE_BYTES]), None);
+
+                // the group order, and the all ones value, are not less than the order
+                let mut order = [0u8; Scalar::SIZE_BYTES];
+                let group_order = Curve.group_order();
+                order[Scalar::SIZE_BYTES - group_order.len()..].copy_from_slice(group_order);
+                assert_eq!(SecretKey::from_bytes(&order), None);
+                assert_eq!(SecretKey::from_bytes(&[0xff; Scalar::SIZE_BYTES]), None);
+
+                // size mismatch
+                let one = Scalar::one().to_bytes();
+                assert_eq!(SecretKey::from_slice(&one[1..]), None);
+                assert_eq!(SecretKey::from_slice(&[&[0][..], &one[..]].concat()), None);
+                assert_eq!(SecretKey::from_slice(&[]), None);
+
+                for sk in secrets() {
+                    let bytes = sk.to_bytes();
+                    assert_eq!(SecretKey::from_bytes(&bytes).as_ref(), Some(&sk));
+                    assert_eq!(SecretKey::from_slice(&bytes).as_ref(), Some(&sk));
+                    assert_eq!(
+                        SecretKey::from_scalar(sk.as_scalar().clone()).as_ref(),
+                        Some(&sk)
+                    );
+                    assert_eq!(
+                        Scalar::from(sk.clone()),
+                        Scalar::from_bytes(&bytes).unwrap()
+                    );
+                }
+            }
+
+            #[test]
+            fn secret_key_debug_redacted() {
+                let sk = SecretKey::from_scalar(Scalar::from_u64(0xdeadbeef)).unwrap();
+                let debug = format!("{:?}", sk);
+                assert_eq!(debug, "SecretKey(..)");
+                assert!(!debug.contains("deadbeef"));
+            }
+
+            #[test]
+            fn public_key() {
+                for sk in secrets() {
+                    let pk = sk.public_key();
+                    let expected = (&Point::generator() * sk.as_scalar()).to_affine().unwrap();
+                    assert_eq!(pk.as_point(), &expected);
+                    assert_eq!(PointAffine::from(pk.clone()), expected);
+                    assert_eq!(PublicKey::from(expected), pk);
+                }
+                let g = SecretKey::from_scalar(Scalar::one()).unwrap().public_key();
+                assert_eq!(g.as_point(), &PointAffine::generator());
+            }
+
+            #[test]
+            fn public_key_sec1() {
+                for sk in secrets() {
+                    let pk = sk.public_key();
+                    let (x, y) = pk.as_point().to_coordinate();
+
+                    let compressed = pk.to_sec1_bytes(true);
+                    assert_eq!(compressed.len(), 1 + FieldElement::SIZE_BYTES);
+                    assert!(compressed[0] == 0x02 || compressed[0] == 0x03);
+                    assert_eq!(&compressed[1..], &x.to_bytes()[..]);
+                    assert_eq!(PublicKey::from_sec1_bytes(&compressed), Some(pk.clone()));
+
+                    let uncompressed = pk.to_sec1_bytes(false);
+                    assert_eq!(uncompressed.len(), 1 + 2 * FieldElement::SIZE_BYTES);
+                    assert_eq!(uncompressed[0], 0x04);
+                    assert_eq!(
+                        &uncompressed[1..1 + FieldElement::SIZE_BYTES],
+                        &x.to_bytes()[..]
+                    );
+                    assert_eq!(
+                        &uncompressed[1 + FieldElement::SIZE_BYTES..],
+                        &y.to_bytes()[..]
+                    );
+                    assert_eq!(PublicKey::from_sec1_bytes(&uncompressed), Some(pk.clone()));
+
+                    // a key created from the uncompressed form caches the same compressed form
+                    let pk2 = PublicKey::from_sec1_bytes(&uncompressed).unwrap();
+                    assert_eq!(pk2.to_sec1_bytes(true), compressed);
+
+                    // the other sign is the opposite point, which is another valid key
+                    let mut other = compressed.clone();
+                    other[0] ^= 1;
+                    let neg = PublicKey::from_sec1_bytes(&other).unwrap();
+                    assert_ne!(neg, pk);
+                    assert_eq!(
+                        neg.to_sec1_bytes(false)[1..1 + FieldElement::SIZE_BYTES],
+                        uncompressed[1..1 + FieldElement::SIZE_BYTES]
+                    );
+                }
+            }
+
+            #[test]
+            fn public_key_sec1_invalid() {
+                let pk = SecretKey::from_scalar(Scalar::from_u64(3))
+                    .unwrap()
+                    .public_key();
+                let compressed = pk.to_sec1_bytes(true);
+                let uncompressed = pk.to_sec1_bytes(false);
+
+                // point at infinity, empty, or tag only
+                assert_eq!(PublicKey::from_sec1_bytes(&[0x00]), None);
+                assert_eq!(PublicKey::from_sec1_bytes(&[]), None);
+                assert_eq!(PublicKey::from_sec1_bytes(&compressed[..1]), None);
+                assert_eq!(PublicKey::from_sec1_bytes(&uncompressed[..1]), None);
+
+                // truncated or extended
+                assert_eq!(
+                    PublicKey::from_sec1_bytes(&compressed[..compressed.len() - 1]),
+                    None
+                );
+                assert_eq!(
+                    PublicKey::from_sec1_bytes(&uncompressed[..uncompressed.len() - 1]),
+                    None
+                );
+                assert_eq!(
+                    PublicKey::from_sec1_bytes(&[&compressed[..], &[0]].concat()),
+                    None
+                );
+                assert_eq!(
+                    PublicKey::from_sec1_bytes(&[&uncompressed[..], &[0]].concat()),
+                    None
+                );
+
+                // wrong tag for the length, and the hybrid encodings
+                let mut wrong = uncompressed.clone();
+                for tag in [0x02u8, 0x03, 0x05, 0x06, 0x07] {
+                    wrong[0] = tag;
+                    assert_eq!(PublicKey::from_sec1_bytes(&wrong), None, "tag {}", tag);
+                }
+                let mut wrong = compressed.clone();
+                for tag in [0x00u8, 0x01, 0x04, 0x05] {
+                    wrong[0] = tag;
+                    assert_eq!(PublicKey::from_sec1_bytes(&wrong), None, "tag {}", tag);
+                }
+
+                // not on the curve
+                let mut off = uncompressed.clone();
+                let last = off.len() - 1;
+                off[last] ^= 1;
+                assert_eq!(PublicKey::from_sec1_bytes(&off), None);
+
+                // non canonical coordinate
+                let mut big = uncompressed.clone();
+                for b in big[1..1 + FieldElement::SIZE_BYTES].iter_mut() {
+                    *b = 0xff;
+                }
+                assert_eq!(PublicKey::from_sec1_bytes(&big), None);
+            }
+
+            // the hash of a value, with the default hasher of the HashMaps and fixed keys
+            fn hash_of<T: std::hash::Hash>(v: &T) -> u64 {
+                use std::hash::Hasher;
+                let mut h = std::collections::hash_map::DefaultHasher::new();
+                v.hash(&mut h);
+                h.finish()
+            }
+
+            #[test]
+            fn key_equality() {
+                use crate::CtEqual;
+                use std::collections::HashSet;
+
+                let keys = secrets();
+                for (i, a) in keys.iter().enumerate() {
+                    for (j, b) in keys.iter().enumerate() {
+                        assert_eq!(a == b, i == j);
+                        assert_eq!(a.ct_eq(b).is_true(), i == j);
+                        assert_eq!(a.public_key() == b.public_key(), i == j);
+                    }
+                    // equal values built separately have the same hash
+                    let copy = SecretKey::from_bytes(&a.to_bytes()).unwrap();
+                    assert_eq!(copy, *a);
+                    assert_eq!(hash_of(&copy), hash_of(a));
+                    let pk = a.public_key();
+                    let pk_copy = PublicKey::from_sec1_bytes(&pk.to_sec1_bytes(false)).unwrap();
+                    assert_eq!(pk_copy, pk);
+                    assert_eq!(hash_of(&pk_copy), hash_of(&pk));
+                }
+
+                let secret_set: HashSet<SecretKey> =
+                    keys.iter().chain(keys.iter()).cloned().collect();
+                assert_eq!(secret_set.len(), keys.len());
+                let public_set: HashSet<PublicKey> = keys.iter().map(|k| k.public_key()).collect();
+                assert_eq!(public_set.len(), keys.len());
+                for k in keys.iter() {
+                    assert!(secret_set.contains(&SecretKey::from_bytes(&k.to_bytes()).unwrap()));
+                    assert!(public_set.contains(&k.public_key()));
+                }
+            }
+
+            #[cfg(feature = "ecdsa")]
+            #[test]
+            fn signature_equality() {
+                use crate::curve::sec2::$curve::ecdsa::Signature;
+                use crate::CtEqual;
+                use std::collections::HashSet;
+
+                let scalars: Vec<Scalar> =
+                    secrets().iter().map(|k| k.as_scalar().clone()).collect();
+                let sigs: Vec<Signature> = scalars
+                    .iter()
+                    .zip(scalars.iter().rev())
+                    .map(|(r, s)| Signature::from_scalars(r.clone(), s.clone()).unwrap())
+                    .collect();
+                for (i, a) in sigs.iter().enumerate() {
+                    for (j, b) in sigs.iter().enumerate() {
+                        assert_eq!(a == b, i == j);
+                        assert_eq!(a.eq_vartime(b), i == j);
+                        assert_eq!(a.ct_eq(b).is_true(), i == j);
+                    }
+                    let copy = Signature::from_slice(&a.to_bytes()).unwrap();
+                    assert!(copy == *a && copy.eq_vartime(a));
+                    assert_eq!(hash_of(&copy), hash_of(a));
+                }
+                // same r, different s
+                let other =
+                    Signature::from_scalars(sigs[0].r().clone(), Scalar::from_u64(3)).unwrap();
+                assert!(other != sigs[0] && !other.eq_vartime(&sigs[0]));
+
+                let set: HashSet<Signature> = sigs.iter().chain(sigs.iter()).cloned().collect();
+                assert_eq!(set.len(), sigs.len());
+            }
+
+            #[cfg(feature = "rand_core")]
+            #[test]
+            fn generate() {
+                use crate::tests::rng::TestRng;
+
+                let mut rng = TestRng::new(0x6b657973);
+                let a = SecretKey::generate(&mut rng);
+                let b = SecretKey::generate(&mut rng);
+                assert_ne!(a, b);
+                assert!(!a.as_scalar().is_zero());
+                // zero bytes first, which would be a zero scalar
+                let mut rng = TestRng::with_zeros(1, 2 * Scalar::SIZE_BYTES);
+                assert!(!SecretKey::generate(&mut rng).as_scalar().is_zero());
+                assert_eq!(rng.zeros_left(), 0);
+            }
+
+            #[test]
+            fn rerandomize_with() {
+                use crate::curve::sec2::$curve::keypair::{derandomize, rerandomize_with};
+
+                let d = Scalar::from_u64(0xdeadbeef);
+                let q = &Point::generator() * &d;
+                let r = Scalar::from_u64(0x1234_5678);
+                let (d2, q2) = rerandomize_with(&d, &q, &r).unwrap();
+                assert_ne!(d2, d);
+                assert_eq!(&Point::generator() * &d2, q2);
+                assert_eq!(derandomize(&d2, &q2, &r), Some((d.clone(), q.clone())));
+
+                // r = 0 doesn't change anything, and r = -d would give a zero secret
+                assert!(rerandomize_with(&d, &q, &Scalar::zero()).is_none());
+                assert!(rerandomize_with(&d, &q, &-&d).is_none());
+                assert!(derandomize(&d, &q, &d).is_none());
+            }
+
+            #[cfg(feature = "rand_core")]
+            #[test]
+            fn rerandomize() {
+                use crate::curve::sec2::$curve::keypair::{derandomize, rerandomize};
+                use crate::tests::rng::ScriptedRng;
+
+                let d = Scalar::from_u64(0xdeadbeef);
+                let q = &Point::generator() * &d;
+                let mut rng = ScriptedRng::new(vec![], 0x72616e64);
+                let (d2, q2, r2) = rerandomize(&d, &q, &mut rng);
+                let (d3, q3, r3) = rerandomize(&d2, &q2, &mut rng);
+                assert_ne!(r2, r3);
+                assert_eq!(&Point::generator() * &d2, q2);
+                assert_eq!(&Point::generator() * &d3, q3);
+
+                let (d2_back, q2_back) = derandomize(&d3, &q3, &r3).unwrap();
+                assert_eq!((&d2_back, &q2_back), (&d2, &q2));
+                assert_eq!(derandomize(&d2, &q2, &r2), Some((d.clone(), q.clone())));
+
+                // a zero r, and then the r giving a zero secret, are both drawn again
+                let minus_d = (-&d).to_bytes().to_vec();
+                let chunks = vec![vec![0; Scalar::SIZE_BYTES], minus_d];
+                let mut rng = ScriptedRng::new(chunks, 1);
+                let (d4, q4, r4) = rerandomize(&d, &q, &mut rng);
+                assert_eq!(rng.chunks_left(), 0);
+                assert!(!d4.is_zero() && !r4.is_zero() && r4 != -&d);
+                assert_eq!(&Point::generator() * &d4, q4);
+            }
+
+            // the operators are variable time with default-mul-vartime, but the secret
+            // scalars still go through scale_ct: the projective points are bit-for-bit the
+            // ones of the ladder, and not the ones of the jacobian multiplication
+            #[cfg(feature = "default-mul-vartime")]
+            #[test]
+            fn secret_mul_ct() {
+                use crate::curve::sec2::$curve::keypair::{derandomize, rerandomize_with};
+
+                let g = Point::generator();
+                let d = Scalar::from_u64(0xdeadbeef);
+                let r = Scalar::from_u64(0x1234_5678);
+                let q = g.scale_ct(&d);
+                let bits = |p: &Point| format!("{:?}", p);
+
+                let (_, q2) = rerandomize_with(&d, &q, &r).unwrap();
+                assert_eq!(bits(&q2), bits(&(&q + &g.scale_ct(&r))));
+                assert_ne!(bits(&q2), bits(&(&q + &(&g * &r))));
+                let (_, q3) = derandomize(&d, &q, &r).unwrap();
+                assert_eq!(bits(&q3), bits(&(&q - &g.scale_ct(&r))));
+                assert_ne!(bits(&q3), bits(&(&q - &(&g * &r))));
+
+                let expected = q.to_affine().unwrap();
+                let key = SecretKey::from_scalar(d.clone()).unwrap();
+                assert_eq!(key.public_key().as_point(), &expected);
+                assert_eq!(g.mul_to_affine(&d), Some(expected));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_keys!(p192k1);
+#[cfg(feature = "p192r1")]
+test_keys!(p192r1);
+#[cfg(feature = "p224k1")]
+test_keys!(p224k1);
+#[cfg(feature = "p224r1")]
+test_keys!(p224r1);
+#[cfg(feature = "p256k1")]
+test_keys!(p256k1);
+#[cfg(feature = "p256r1")]
+test_keys!(p256r1);
+#[cfg(feature = "p384r1")]
+test_keys!(p384r1);
+#[cfg(feature = "p521r1")]
+test_keys!(p521r1);
@@ -0,0 +1,88 @@
+//! Quadratic residues of the fiat field elements, with the bigint legendre symbol
+//! as the oracle when it's compiled in
+
+macro_rules! test_legendre {
+    ($name:ident, $($m:ident)::+, $non_residue:expr) => {
+        mod $name {
+            use crate::curve::$($m)::+::FieldElement;
+            use crate::curve::field::FieldLegendre;
+
+            fn values() -> Vec<FieldElement> {
+                let mut v = vec![FieldElement::one(), FieldElement::from_u64(2), -FieldElement::one()];
+                let mut x = FieldElement::from_u64(0x1e9e);
+                for i in 0..32 {
+                    x = x.square() + FieldElement::from_u64(i + 1);
+                    v.push(x.clone());
+                }
+                v
+            }
+
+            #[test]
+            fn squares() {
+                for x in values() {
+                    assert!(x.square().is_quadratic_residue().is_true());
+                    assert!(FieldLegendre::is_quadratic_residue(&x.square()).is_true());
+                }
+            }
+
+            #[test]
+            fn non_residues() {
+                let n: FieldElement = $non_residue;
+                assert!(!n.is_quadratic_residue().is_true());
+                for x in values() {
+                    assert!(!(&n * &x.square()).is_quadratic_residue().is_true());
+                }
+            }
+
+            #[test]
+            fn zero() {
+                assert!(!FieldElement::zero().is_quadratic_residue().is_true());
+            }
+
+            #[test]
+            fn matches_sqrt() {
+                for x in values() {
+                    let has_sqrt = x.sqrt().into_option().is_some();
+                    assert_eq!(x.is_quadratic_residue().is_true(), has_sqrt);
+                }
+            }
+
+            #[cfg(all(feature = "num-bigint", feature = "num-traits"))]
+            #[test]
+            fn legendre_symbol() {
+                use crate::curve::bigint::maths::{legendre_symbol, LegendreSymbol};
+                use num_bigint::BigUint;
+
+                let p = BigUint::from_bytes_be(&(-FieldElement::one()).to_bytes()) + 1u32;
+                for x in values().into_iter().chain(Some(FieldElement::zero())) {
+                    let expected = legendre_symbol(&BigUint::from_bytes_be(&x.to_bytes()), &p);
+                    assert_eq!(
+                        x.is_quadratic_residue().is_true(),
+                        expected == LegendreSymbol::One
+                    );
+                }
+            }
+        }
+    };
+}
+
+// -1 isn't a square when p = 3 mod 4, 2 isn't when p = 5 mod 8,
+// and 11 is the first non residue of the p224r1 prime (p = 1 mod 8)
+#[cfg(feature = "p192k1")]
+test_legendre!(p192k1, sec2::p192k1, -FieldElement::one());
+#[cfg(feature = "p192r1")]
+test_legendre!(p192r1, sec2::p192r1, -FieldElement::one());
+#[cfg(feature = "p224k1")]
+test_legendre!(p224k1, sec2::p224k1, FieldElement::from_u64(2));
+#[cfg(feature = "p224r1")]
+test_legendre!(p224r1, sec2::p224r1, FieldElement::from_u64(11));
+#[cfg(feature = "p256k1")]
+test_legendre!(p256k1, sec2::p256k1, -FieldElement::one());
+#[cfg(feature = "p256r1")]
+test_legendre!(p256r1, sec2::p256r1, -FieldElement::one());
+#[cfg(feature = "p384r1")]
+test_legendre!(p384r1, sec2::p384r1, -FieldElement::one());
+#[cfg(feature = "p521r1")]
+test_legendre!(p521r1, sec2::p521r1, -FieldElement::one());
+#[cfg(feature = "sm2")]
+test_legendre!(sm2, sm2, -FieldElement::one());
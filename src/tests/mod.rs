@@ -1,3 +1,52 @@
+#[cfg(all(feature = "num-bigint", feature = "num-traits"))]
+mod backends;
+mod batch;
+#[cfg(feature = "num-bigint-interop")]
+mod bigint_interop;
+mod boundary;
+mod bytes;
+mod cached;
+mod clamp;
+mod cross_field;
+#[cfg(feature = "p256r1")]
+mod ct_util;
+mod ecdh_cases;
+mod ecdh_cases_data;
+mod elliptic;
+#[cfg(feature = "p256k1")]
+mod extension;
+#[cfg(feature = "fault-hardening")]
+mod fault;
+#[cfg(feature = "p256r1")]
+mod forms;
+mod fuzzing;
+mod hash_bits;
+pub(crate) mod hex;
+mod info;
+mod jacobian;
+#[cfg(feature = "jwk")]
+mod jwk;
 mod kats;
 mod kats_data;
+mod kats_gen;
+mod kats_generated;
+mod kdf;
+mod keys;
+mod legendre;
+mod mod_p;
+mod modulus_boundary;
+mod msm;
+mod params;
+#[cfg(feature = "pkcs8")]
+mod pkcs8;
+mod point;
+mod recode;
+mod registry;
+#[cfg(feature = "rand_core")]
+pub(crate) mod rng;
 mod sage;
+mod sec1;
+mod signed;
+#[cfg(feature = "sm2")]
+mod sm2;
+mod typed_bytes;
@@ -0,0 +1,166 @@
+//! Reduction of big endian bytes of any length to field elements (from_be_bytes_mod_p)
+
+macro_rules! test_mod_p {
+    ($curve: ident) => {
+        test_mod_p!(
+            $curve,
+            crate::curve::sec2::$curve,
+            crate::params::sec2::$curve
+        );
+    };
+    ($curve: ident, $module: path, $params: path) => {
+        mod $curve {
+            use curve::FieldElement;
+            use $module as curve;
+
+            fn modulus_minus_one() -> Vec<u8> {
+                (-FieldElement::one()).to_bytes().to_vec()
+            }
+
+            #[test]
+            fn canonical() {
+                let mut x = FieldElement::from_u64(0x5eed);
+                for i in 0..8 {
+                    x = x.square() + FieldElement::from_u64(i + 1);
+                    assert_eq!(FieldElement::from_be_bytes_mod_p(&x.to_bytes()), x);
+                }
+                assert_eq!(FieldElement::from_be_bytes_mod_p(&[]), FieldElement::zero());
+                assert_eq!(
+                    FieldElement::from_be_bytes_mod_p(&modulus_minus_one()),
+                    -FieldElement::one()
+                );
+                // leading zeros don't change the value
+                let mut padded = vec![0u8; 17];
+                padded.extend_from_slice(&modulus_minus_one());
+                assert_eq!(
+                    FieldElement::from_be_bytes_mod_p(&padded),
+                    -FieldElement::one()
+                );
+            }
+
+            // compare with the computation on big integers
+            #[cfg(feature = "num-bigint")]
+            #[test]
+            fn reduction() {
+                use num_bigint::BigUint;
+
+                let p = BigUint::from_bytes_be(&modulus_minus_one()) + 1u32;
+                let reference = |bytes: &[u8]| -> Vec<u8> {
+                    let v = BigUint::from_bytes_be(bytes) % &p;
+                    let bytes = v.to_bytes_be();
+                    let mut out = vec![0u8; FieldElement::SIZE_BYTES - bytes.len()];
+                    out.extend_from_slice(&bytes);
+                    out
+                };
+
+                let mut x = 0x5eedu64;
+                for len in [1, 31, 32, 33, 47, 48, 64, 66, 80, 98, 140] {
+                    let random: Vec<u8> = (0..len)
+                        .map(|_| {
+                            x = x
+                                .wrapping_mul(6364136223846793005)
+                                .wrapping_add(1442695040888963407);
+                            (x >> 56) as u8
+                        })
+                        .collect();
+                    for bytes in [vec![0; len], vec![0xff; len], random] {
+                        assert_eq!(
+                            &FieldElement::from_be_bytes_mod_p(&bytes).to_bytes()[..],
+                            &reference(&bytes)[..],
+                            "{:?}",
+                            bytes
+                        );
+                    }
+                }
+            }
+
+            // the Barrett reduction of 2N limbs, compared with the computation on big integers
+            // for random values and the worst cases b^(2N) - 1, m.b^N and m.b^N - 1
+            #[cfg(feature = "num-bigint")]
+            fn check_reduce_wide<const N: usize>(m: &[u64; N], mu: &[u64]) -> Vec<Vec<u8>> {
+                use crate::mp::barrett::reduce_wide;
+                use num_bigint::BigUint;
+
+                let to_bytes = |limbs: &[u64]| -> Vec<u8> {
+                    limbs.iter().flat_map(|l| l.to_be_bytes()).collect()
+                };
+                let modulus = BigUint::from_bytes_be(&to_bytes(m));
+
+                let mut inputs = vec![vec![0u64; 2 * N], vec![u64::MAX; 2 * N]];
+                let mut shifted = m.to_vec();
+                shifted.extend_from_slice(&[0; N]);
+                inputs.push(shifted.clone());
+                let mut below = shifted;
+                let i = below.iter().rposition(|l| *l != 0).unwrap();
+                below[i] -= 1;
+                below[i + 1..].iter_mut().for_each(|l| *l = u64::MAX);
+                inputs.push(below);
+                let mut x = 0x5eedu64;
+                for _ in 0..32 {
+                    inputs.push(
+                        (0..2 * N)
+                            .map(|_| {
+                                x = x
+                                    .wrapping_mul(6364136223846793005)
+                                    .wrapping_add(1442695040888963407);
+                                x ^ (x >> 29)
+                            })
+                            .collect(),
+                    );
+                }
+
+                for input in inputs.iter() {
+                    let expected = BigUint::from_bytes_be(&to_bytes(input)) % &modulus;
+                    let r = reduce_wide(input, m, mu);
+                    assert_eq!(
+                        BigUint::from_bytes_be(&to_bytes(&r)),
+                        expected,
+                        "{:x?}",
+                        input
+                    );
+                }
+                inputs.iter().map(|input| to_bytes(input)).collect()
+            }
+
+            #[cfg(feature = "num-bigint")]
+            #[test]
+            fn wide() {
+                use curve::Scalar;
+                use num_bigint::BigUint;
+                use $params as params;
+
+                for bytes in check_reduce_wide(&params::P_LIMBS, &params::MICRO_LIMBS) {
+                    let p = BigUint::from_bytes_be(&modulus_minus_one()) + 1u32;
+                    let v = BigUint::from_bytes_be(&bytes) % &p;
+                    let r = FieldElement::from_be_bytes_mod_p(&bytes);
+                    assert_eq!(BigUint::from_bytes_be(&r.to_bytes()), v);
+                }
+                for bytes in check_reduce_wide(&params::ORDER_LIMBS, &params::ORDER_MICRO_LIMBS) {
+                    let n = BigUint::from_bytes_be(&params::ORDER_BYTES);
+                    let v = BigUint::from_bytes_be(&bytes) % &n;
+                    let r = Scalar::from_be_bytes_mod_p(&bytes);
+                    assert_eq!(BigUint::from_bytes_be(&r.to_bytes()), v);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_mod_p!(p192k1);
+#[cfg(feature = "p192r1")]
+test_mod_p!(p192r1);
+#[cfg(feature = "p224k1")]
+test_mod_p!(p224k1);
+#[cfg(feature = "p224r1")]
+test_mod_p!(p224r1);
+#[cfg(feature = "p256k1")]
+test_mod_p!(p256k1);
+#[cfg(feature = "p256r1")]
+test_mod_p!(p256r1);
+#[cfg(feature = "p384r1")]
+test_mod_p!(p384r1);
+#[cfg(feature = "p521r1")]
+test_mod_p!(p521r1);
+#[cfg(feature = "sm2")]
+test_mod_p!(sm2, crate::curve::sm2, crate::params::sm2);
@@ -0,0 +1,231 @@
+//! Field elements and scalars near the modulus and at the limb boundaries
+//!
+//! The values are built from the modulus limbs of the params (`P_LIMBS` and
+//! `ORDER_LIMBS`, most significant limb first), and go through the bytes conversions
+//! and the Montgomery conversions of the fiat types, which mostly see small values
+//! in the other tests.
+
+// big endian bytes of the big endian limbs, as `size` bytes, or None if the value doesn't fit
+fn limbs_to_bytes(limbs: &[u64], size: usize) -> Option<Vec<u8>> {
+    let bytes = limbs
+        .iter()
+        .flat_map(|l| l.to_be_bytes().to_vec())
+        .collect::<Vec<u8>>();
+    let (high, low) = bytes.split_at(bytes.len() - size);
+    if high.iter().all(|b| *b == 0) {
+        Some(low.to_vec())
+    } else {
+        None
+    }
+}
+
+// limbs - n * 2^(64 * index), with the index from the least significant limb
+fn sub_limb(limbs: &[u64], index: usize, n: u64) -> Vec<u64> {
+    let mut out = limbs.to_vec();
+    let mut borrow = n;
+    for l in out.iter_mut().rev().skip(index) {
+        let (v, b) = l.overflowing_sub(borrow);
+        *l = v;
+        borrow = b as u64;
+    }
+    assert_eq!(borrow, 0);
+    out
+}
+
+// v * 2^(64 * index) on n limbs
+fn single_limb(n: usize, index: usize, v: u64) -> Vec<u64> {
+    let mut out = vec![0; n];
+    out[n - 1 - index] = v;
+    out
+}
+
+// 2^bit on n limbs
+fn pow2(n: usize, bit: usize) -> Vec<u64> {
+    single_limb(n, bit / 64, 1 << (bit % 64))
+}
+
+macro_rules! test_modulus_boundary {
+    ($name:ident, $T:path, $LIMBS:path) => {
+        mod $name {
+            use crate::tests::modulus_boundary::{limbs_to_bytes, pow2, single_limb, sub_limb};
+            use $T as T;
+
+            fn bytes(limbs: &[u64]) -> Option<Vec<u8>> {
+                limbs_to_bytes(limbs, T::SIZE_BYTES)
+            }
+
+            fn element(limbs: &[u64]) -> T {
+                let b = bytes(limbs).expect("value fits");
+                let mut array = [0u8; T::SIZE_BYTES];
+                array.copy_from_slice(&b);
+                T::from_bytes(&array).expect("canonical value")
+            }
+
+            // 2^bit, doubling one
+            fn power_of_two(bit: usize) -> T {
+                (0..bit).fold(T::one(), |x, _| x.double())
+            }
+
+            // the named values less than the modulus, as big endian limbs
+            fn values() -> Vec<(String, Vec<u64>)> {
+                let p = &$LIMBS;
+                let n = p.len();
+                let mut v = vec![
+                    ("p-1".to_string(), sub_limb(p, 0, 1)),
+                    ("p-2".to_string(), sub_limb(p, 0, 2)),
+                    ("p-2^64".to_string(), sub_limb(p, 1, 1)),
+                    ("2^64".to_string(), pow2(n, 64)),
+                    ("2^(bits-1)".to_string(), pow2(n, T::SIZE_BITS - 1)),
+                ];
+                for i in 0..n {
+                    let limb = single_limb(n, i, u64::MAX);
+                    let below = bytes(&limb).map_or(false, |b| b < bytes(p).unwrap());
+                    if below {
+                        v.push((format!("u64::MAX * 2^{}", 64 * i), limb));
+                    }
+                }
+                v
+            }
+
+            #[test]
+            fn modulus() {
+                let p = bytes(&$LIMBS).unwrap();
+                assert!(T::from_slice(&p).is_none());
+                assert!(T::from_slice(&bytes(&sub_limb(&$LIMBS, 0, 1)).unwrap()).is_some());
+                assert_eq!(T::from_be_bytes_mod_p(&p), T::zero());
+                let mut p1 = p.clone();
+                for b in p1.iter_mut().rev() {
+                    *b = b.wrapping_add(1);
+                    if *b != 0 {
+                        break;
+                    }
+                }
+                assert!(T::from_slice(&p1).is_none());
+                assert_eq!(T::from_be_bytes_mod_p(&p1), T::one());
+            }
+
+            #[test]
+            fn round_trip() {
+                for (name, limbs) in values() {
+                    let b = bytes(&limbs).unwrap();
+                    let x = element(&limbs);
+                    assert_eq!(x.to_bytes()[..], b[..], "{}", name);
+                    assert_eq!(T::from_slice(&b).as_ref(), Some(&x), "{}", name);
+                    assert_eq!(T::from_be_bytes_mod_p(&b), x, "{}", name);
+                    assert_eq!(T::from_slice(&x.to_bytes()).as_ref(), Some(&x), "{}", name);
+                }
+            }
+
+            #[test]
+            fn identities() {
+                for (name, limbs) in values() {
+                    let x = element(&limbs);
+                    assert_eq!(&(&x + &T::one()) - &T::one(), x, "{}", name);
+                    assert_eq!(&(&x - &T::one()) + &T::one(), x, "{}", name);
+                    assert_eq!(&x * &T::one(), x, "{}", name);
+                    assert_eq!(x.square(), &x * &x, "{}", name);
+                    assert_eq!(-(-&x), x, "{}", name);
+                    assert_eq!(&x + &(-&x), T::zero(), "{}", name);
+                }
+            }
+
+            #[test]
+            fn values_arithmetic() {
+                let n = $LIMBS.len();
+                let pm1 = element(&sub_limb(&$LIMBS, 0, 1));
+                let pm2 = element(&sub_limb(&$LIMBS, 0, 2));
+                assert_eq!(&pm1 + &T::one(), T::zero());
+                assert_eq!(-&pm1, T::one());
+                assert_eq!(pm1, -T::one());
+                assert_eq!(&pm2 + &T::one(), pm1);
+                assert_eq!(-&pm2, T::from_u64(2));
+
+                let pm64 = element(&sub_limb(&$LIMBS, 1, 1));
+                let two64 = element(&pow2(n, 64));
+                assert_eq!(two64, &T::from_u64(u64::MAX) + &T::one());
+                assert_eq!(two64, power_of_two(64));
+                assert_eq!(&pm64 + &two64, T::zero());
+
+                let top = element(&pow2(n, T::SIZE_BITS - 1));
+                assert_eq!(top, power_of_two(T::SIZE_BITS - 1));
+
+                let max = T::from_u64(u64::MAX);
+                for i in 0..n {
+                    let limb = single_limb(n, i, u64::MAX);
+                    if let Some(b) = bytes(&limb) {
+                        if b < bytes(&$LIMBS).unwrap() {
+                            assert_eq!(element(&limb), &max * &power_of_two(64 * i), "{}", i);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_curve_boundary {
+    ($curve:ident, $module:path, $params:path) => {
+        mod $curve {
+            use $module as curve;
+            use $params as params;
+
+            test_modulus_boundary!(
+                field_element,
+                super::curve::FieldElement,
+                super::params::P_LIMBS
+            );
+            test_modulus_boundary!(scalar, super::curve::Scalar, super::params::ORDER_LIMBS);
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_curve_boundary!(
+    p192k1,
+    crate::curve::sec2::p192k1,
+    crate::params::sec2::p192k1
+);
+#[cfg(feature = "p192r1")]
+test_curve_boundary!(
+    p192r1,
+    crate::curve::sec2::p192r1,
+    crate::params::sec2::p192r1
+);
+#[cfg(feature = "p224k1")]
+test_curve_boundary!(
+    p224k1,
+    crate::curve::sec2::p224k1,
+    crate::params::sec2::p224k1
+);
+#[cfg(feature = "p224r1")]
+test_curve_boundary!(
+    p224r1,
+    crate::curve::sec2::p224r1,
+    crate::params::sec2::p224r1
+);
+#[cfg(feature = "p256k1")]
+test_curve_boundary!(
+    p256k1,
+    crate::curve::sec2::p256k1,
+    crate::params::sec2::p256k1
+);
+#[cfg(feature = "p256r1")]
+test_curve_boundary!(
+    p256r1,
+    crate::curve::sec2::p256r1,
+    crate::params::sec2::p256r1
+);
+#[cfg(feature = "p384r1")]
+test_curve_boundary!(
+    p384r1,
+    crate::curve::sec2::p384r1,
+    crate::params::sec2::p384r1
+);
+#[cfg(feature = "p521r1")]
+test_curve_boundary!(
+    p521r1,
+    crate::curve::sec2::p521r1,
+    crate::params::sec2::p521r1
+);
+#[cfg(feature = "sm2")]
+test_curve_boundary!(sm2, crate::curve::sm2, crate::params::sm2);
@@ -0,0 +1,142 @@
+macro_rules! test_msm {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::projective::MSM_CHUNK;
+            use crate::curve::sec2::$curve::{MsmAccumulator, Point, Scalar};
+
+            // scalars of all sizes, including zero and -1
+            fn scalars(n: usize) -> Vec<Scalar> {
+                let mut s = Scalar::from_u64(0x5eed);
+                (0..n as u64)
+                    .map(|i| match i % 7 {
+                        0 => Scalar::zero(),
+                        1 => -Scalar::one(),
+                        2 => Scalar::from_u64(i),
+                        _ => {
+                            s = s.square() + Scalar::from_u64(i);
+                            s.clone()
+                        }
+                    })
+                    .collect()
+            }
+
+            // points[i] = (i+1).G, so that sum(s_i . points[i]) = sum(s_i . (i+1)).G
+            fn points(n: usize) -> Vec<Point> {
+                let g = Point::generator();
+                let mut p = Point::infinity();
+                (0..n)
+                    .map(|_| {
+                        p = &p + &g;
+                        p.clone()
+                    })
+                    .collect()
+            }
+
+            fn expected(scalars: &[Scalar]) -> Point {
+                let mut sum = Scalar::zero();
+                let mut i = Scalar::zero();
+                for s in scalars {
+                    i = i + Scalar::one();
+                    sum = sum + s * &i;
+                }
+                &Point::generator() * &sum
+            }
+
+            fn naive(scalars: &[Scalar], points: &[Point]) -> Point {
+                scalars
+                    .iter()
+                    .zip(points.iter())
+                    .fold(Point::infinity(), |acc, (s, p)| acc + p * s)
+            }
+
+            #[test]
+            fn msm_empty() {
+                assert_eq!(Point::msm(&[], &[]), Point::infinity());
+                assert_eq!(MsmAccumulator::new().finalize(), Point::infinity());
+            }
+
+            #[test]
+            fn msm_single() {
+                let p = Point::generator();
+                for s in scalars(8) {
+                    let mut acc = MsmAccumulator::new();
+                    acc.push(&s, &p);
+                    assert_eq!(acc.finalize(), &p * &s);
+                    assert_eq!(
+                        Point::msm(std::slice::from_ref(&s), std::slice::from_ref(&p)),
+                        &p * &s
+                    );
+                }
+            }
+
+            #[test]
+            fn msm_naive() {
+                let scalars = scalars(12);
+                let points = points(12);
+                let naive = naive(&scalars, &points);
+                assert_eq!(naive, expected(&scalars));
+                assert_eq!(Point::msm(&scalars, &points), naive);
+
+                let mut acc = MsmAccumulator::default();
+                for (s, p) in scalars.iter().zip(points.iter()) {
+                    acc.push(s, p);
+                }
+                assert_eq!(acc.finalize(), naive);
+            }
+
+            #[test]
+            fn msm_chunks() {
+                let n = 2 * MSM_CHUNK + 3;
+                let scalars = scalars(n);
+                let points = points(n);
+                assert_eq!(Point::msm(&scalars, &points), expected(&scalars));
+
+                // the partial sums are checked around the chunk boundaries
+                let checkpoints = [1, MSM_CHUNK - 1, MSM_CHUNK, MSM_CHUNK + 1, 2 * MSM_CHUNK, n];
+                let mut acc = MsmAccumulator::new();
+                for (i, (s, p)) in scalars.iter().zip(points.iter()).enumerate() {
+                    acc.push(s, p);
+                    if checkpoints.contains(&(i + 1)) {
+                        assert_eq!(
+                            acc.clone().finalize(),
+                            expected(&scalars[..i + 1]),
+                            "{}",
+                            i + 1
+                        );
+                    }
+                }
+                assert_eq!(acc.finalize(), expected(&scalars));
+            }
+
+            #[test]
+            fn msm_accumulator_debug_redacted() {
+                let mut acc = MsmAccumulator::new();
+                acc.push(&Scalar::from_u64(0xdeadbeef), &Point::generator());
+                assert_eq!(format!("{:?}", acc), "MsmAccumulator { pending: 1, .. }");
+            }
+
+            #[test]
+            #[should_panic]
+            fn msm_length_mismatch() {
+                Point::msm(&scalars(2), &points(3));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_msm!(p192k1);
+#[cfg(feature = "p192r1")]
+test_msm!(p192r1);
+#[cfg(feature = "p224k1")]
+test_msm!(p224k1);
+#[cfg(feature = "p224r1")]
+test_msm!(p224r1);
+#[cfg(feature = "p256k1")]
+test_msm!(p256k1);
+#[cfg(feature = "p256r1")]
+test_msm!(p256r1);
+#[cfg(feature = "p384r1")]
+test_msm!(p384r1);
+#[cfg(feature = "p521r1")]
+test_msm!(p521r1);
@@ -0,0 +1,175 @@
+//! Consistency of the curve parameters
+
+use crate::curve::weierstrass::{b3_consistent, WeierstrassCurve};
+
+macro_rules! test_params {
+    ($curve: ident) => {
+        mod $curve {
+            use super::*;
+            use crate::curve::sec2::$curve::{Curve, FieldElement, Point, Scalar};
+            use crate::params::sec2::$curve::{B3_BYTES, ORDER_BYTES};
+
+            #[test]
+            fn b3() {
+                assert!(b3_consistent::<Curve>(Curve));
+                // the table value is not used by the curve anymore, but stays exported
+                assert_eq!(FieldElement::from_bytes(&B3_BYTES).unwrap(), *Curve.b3());
+            }
+
+            #[test]
+            fn generator() {
+                assert!(Curve.verify_parameters());
+            }
+
+            #[test]
+            fn scalar_bounds() {
+                let mut order_minus_one = ORDER_BYTES;
+                // the orders are odd
+                *order_minus_one.last_mut().unwrap() -= 1;
+                assert_eq!(Scalar::max().to_bytes(), order_minus_one);
+                assert_eq!(Scalar::max().checked_add_nowrap(&Scalar::one()), None);
+
+                let half = Scalar::half_max();
+                assert_eq!(&half + &half, Scalar::max());
+                assert_eq!(half.checked_add_nowrap(&half), Some(Scalar::max()));
+                assert!((&Point::generator() * &half) != Point::infinity());
+
+                assert_eq!(Scalar::from_be_bytes_mod_p_nonzero(&ORDER_BYTES), None);
+                assert_eq!(
+                    Scalar::from_be_bytes_mod_p_nonzero(&order_minus_one),
+                    Some(Scalar::max())
+                );
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_params!(p192k1);
+#[cfg(feature = "p192r1")]
+test_params!(p192r1);
+#[cfg(feature = "p224k1")]
+test_params!(p224k1);
+#[cfg(feature = "p224r1")]
+test_params!(p224r1);
+#[cfg(feature = "p256k1")]
+test_params!(p256k1);
+#[cfg(feature = "p256r1")]
+test_params!(p256r1);
+#[cfg(feature = "p384r1")]
+test_params!(p384r1);
+#[cfg(feature = "p521r1")]
+test_params!(p521r1);
+
+// a curve with the p256r1 parameters, but a B3 off by one
+#[cfg(feature = "p256r1")]
+mod corrupted {
+    use super::*;
+    use crate::curve::sec2::p256r1::{Curve, FieldElement};
+
+    fn bad_b3() -> &'static FieldElement {
+        static VALUE: std::sync::OnceLock<FieldElement> = std::sync::OnceLock::new();
+        VALUE.get_or_init(|| Curve.b3() + &FieldElement::one())
+    }
+
+    #[derive(Clone, Copy)]
+    struct Corrupted;
+
+    impl WeierstrassCurve for Corrupted {
+        type FieldElement = FieldElement;
+        fn a(self) -> &'static FieldElement {
+            Curve.a()
+        }
+        fn b(self) -> &'static FieldElement {
+            Curve.b()
+        }
+        fn b3(self) -> &'static FieldElement {
+            bad_b3()
+        }
+    }
+
+    #[test]
+    fn b3() {
+        assert!(!b3_consistent::<Corrupted>(Corrupted));
+    }
+}
+
+// check that µ = floor(b^(2N) / m) for the N limbs modulus m, i.e. µ.m <= b^(2N) < µ.m + m,
+// with b = 2^64, and that the bytes and limbs representations are the same
+fn check_micro(modulus: &[u64], micro: &[u64], micro_bytes: &[u8]) {
+    // little endian limbs, with the leading zeros removed
+    let le = |be: &[u64]| -> Vec<u64> {
+        let skip = be.iter().take_while(|l| **l == 0).count();
+        be[skip..].iter().rev().cloned().collect()
+    };
+    let (m, mu) = (le(modulus), le(micro));
+    let n = m.len();
+    assert!(micro.len() <= 2 * n);
+
+    let mut prod = vec![0u64; 3 * n + 1];
+    for (i, a) in mu.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b) in m.iter().enumerate() {
+            let t = (*a as u128) * (*b as u128) + prod[i + j] as u128 + carry;
+            prod[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        prod[i + n] = carry as u64;
+    }
+    assert!(prod[2 * n..].iter().all(|l| *l == 0), "µ.m > b^(2N)");
+
+    // b^(2N) - µ.m, which is less than b^(2N) as m doesn't divide b^(2N)
+    let mut rem = vec![0u64; 2 * n];
+    let mut borrow = 0u64;
+    for i in 0..2 * n {
+        let (d, b1) = 0u64.overflowing_sub(prod[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        rem[i] = d;
+        borrow = (b1 | b2) as u64;
+    }
+    assert!(rem[n..].iter().all(|l| *l == 0), "b^(2N) - µ.m >= m");
+    assert!(
+        rem[..n].iter().rev().lt(m.iter().rev()),
+        "b^(2N) - µ.m >= m"
+    );
+
+    let mut limbs_bytes = Vec::new();
+    for l in micro.iter() {
+        limbs_bytes.extend_from_slice(&l.to_be_bytes());
+    }
+    let skip = limbs_bytes.len() - micro_bytes.len();
+    assert!(limbs_bytes[..skip].iter().all(|b| *b == 0));
+    assert_eq!(&limbs_bytes[skip..], micro_bytes);
+}
+
+macro_rules! test_micro {
+    ($name: ident, $params: path) => {
+        #[test]
+        fn $name() {
+            use $params::*;
+            check_micro(&P_LIMBS, &MICRO_LIMBS, &MICRO_BYTES);
+            check_micro(&ORDER_LIMBS, &ORDER_MICRO_LIMBS, &ORDER_MICRO_BYTES);
+        }
+    };
+}
+
+mod micro {
+    use super::check_micro;
+
+    test_micro!(p112r1, crate::params::sec2::p112r1);
+    test_micro!(p112r2, crate::params::sec2::p112r2);
+    test_micro!(p128r1, crate::params::sec2::p128r1);
+    test_micro!(p128r2, crate::params::sec2::p128r2);
+    test_micro!(p160k1, crate::params::sec2::p160k1);
+    test_micro!(p160r1, crate::params::sec2::p160r1);
+    test_micro!(p160r2, crate::params::sec2::p160r2);
+    test_micro!(p192k1, crate::params::sec2::p192k1);
+    test_micro!(p192r1, crate::params::sec2::p192r1);
+    test_micro!(p224k1, crate::params::sec2::p224k1);
+    test_micro!(p224r1, crate::params::sec2::p224r1);
+    test_micro!(p256k1, crate::params::sec2::p256k1);
+    test_micro!(p256r1, crate::params::sec2::p256r1);
+    test_micro!(p384r1, crate::params::sec2::p384r1);
+    test_micro!(p521r1, crate::params::sec2::p521r1);
+    test_micro!(sm2, crate::params::sm2);
+}
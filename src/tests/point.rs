@@ -0,0 +1,879 @@
+// add two big endian numbers of the same size, returning None on overflow
+fn add_be(a: &[u8], b: &[u8]) -> Option<Vec<u8>> {
+    assert_eq!(a.len(), b.len());
+    let mut out = vec![0u8; a.len()];
+    let mut carry = 0u16;
+    for i in (0..a.len()).rev() {
+        let v = a[i] as u16 + b[i] as u16 + carry;
+        out[i] = v as u8;
+        carry = v >> 8;
+    }
+    if carry == 0 {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+// the point at infinity, and the results of the operations giving it, are (0,Y,0) with Y non zero
+macro_rules! test_infinity {
+    ($name:ident, $double:ident, $add:ident, $add_or_double:ident, $scale:ident, $ladder:ident) => {
+        #[test]
+        fn $name() {
+            use crate::curve::affine;
+            use crate::curve::projective::Point as P;
+
+            let inf = P::<FieldElement>::infinity();
+            let (gx, gy) = Curve::generator();
+            let g = P::from_affine(&affine::Point::new_unchecked(gx.clone(), gy.clone()));
+
+            let order = Curve.group_order();
+            let minus_one = (-Scalar::one()).to_bytes();
+            let scalars: [&[u8]; 7] = [&[], &[0], &[1], &[2, 0x37], &[0xff; 80], order, &minus_one];
+
+            let mut results = vec![
+                inf.$double(Curve),
+                inf.$double(Curve).$double(Curve),
+                inf.$add(&inf, Curve),
+                inf.$add_or_double(&inf, Curve),
+                g.$add(&-&g, Curve),
+                g.$add(&-&g, Curve).$double(Curve),
+                g.$scale(&[0], Curve),
+                g.$scale(order, Curve),
+                g.$ladder(&[0], Curve),
+                g.$ladder(order, Curve),
+            ];
+            for n in scalars.iter() {
+                results.push(inf.$scale(n, Curve));
+                results.push(inf.$ladder(n, Curve));
+            }
+
+            for (i, p) in results.iter().enumerate() {
+                assert!(p.x.is_zero(), "{}: x = {}", i, p.x);
+                assert!(!p.y.is_zero(), "{}: y = 0", i);
+                assert!(p.z.is_zero(), "{}: z = {}", i, p.z);
+                assert!(p.is_infinity().is_true());
+                assert_eq!(p, &inf);
+                assert_ne!(p, &g);
+                assert!(p.to_affine().is_none());
+                assert_eq!(p.$add(&g, Curve), g);
+                assert_eq!(g.$add(p, Curve), g);
+            }
+        }
+    };
+}
+
+// the constant time multiplication of the generic projective points, against the
+// variable time one
+macro_rules! test_projective_ct {
+    ($name:ident, $scale:ident, $scale_ct:ident) => {
+        #[test]
+        fn $name() {
+            use crate::curve::affine;
+            use crate::curve::projective::Point as P;
+
+            let (gx, gy) = Curve::generator();
+            let g = P::from_affine(&affine::Point::new_unchecked(gx.clone(), gy.clone()));
+            let mut s = Scalar::from_u64(0x17);
+            for i in 0..16 {
+                s = s.square() + Scalar::from_u64(i);
+                let n = s.to_bytes();
+                let r = g.$scale_ct(&n, Curve);
+                assert_eq!(r.to_affine(), g.$scale(&n, Curve).to_affine(), "{:?}", s);
+            }
+            assert!(g.$scale_ct(&[0], Curve).is_infinity().is_true());
+            let order = Curve.group_order();
+            assert!(g.$scale_ct(order, Curve).is_infinity().is_true());
+        }
+    };
+}
+
+macro_rules! test_point {
+    ($curve: ident, a0) => {
+        test_point!($curve, {
+            test_infinity!(
+                infinity_a0,
+                double_a0,
+                add_different_a0,
+                add_or_double_a0,
+                scale_a0_vartime,
+                scale_ladder_a0
+            );
+            test_projective_ct!(projective_ct_a0, scale_a0_vartime, scale_a0_ct);
+        });
+    };
+    ($curve: ident) => {
+        test_point!($curve, {
+            test_projective_ct!(projective_ct, scale_vartime, scale_ct);
+        });
+    };
+    ($curve: ident, { $($extra:tt)* }) => {
+        mod $curve {
+            use super::add_be;
+            use crate::curve::sec2::$curve::{Curve, FieldElement, Point, PointAffine, Scalar};
+            use crate::curve::Sign;
+            use crate::mp::ct::Choice;
+            use crate::params::sec2::$curve::P_BYTES;
+
+            fn points() -> Vec<PointAffine> {
+                [1u64, 2, 3, 7, 0x1234, 0xdeadbeef]
+                    .iter()
+                    .map(|n| {
+                        (&Point::generator() * &Scalar::from_u64(*n))
+                            .to_affine()
+                            .unwrap()
+                    })
+                    .collect()
+            }
+
+            fn opposite(sign: Sign) -> Sign {
+                match sign {
+                    Sign::Positive => Sign::Negative,
+                    Sign::Negative => Sign::Positive,
+                }
+            }
+
+            #[test]
+            fn matches_compressed() {
+                for p in points() {
+                    let (x, sign) = p.compress();
+                    let x_bytes = x.to_bytes();
+                    assert!(p.matches_compressed(&x_bytes, sign));
+                    assert!(!p.matches_compressed(&x_bytes, opposite(sign)));
+
+                    let other = p.double();
+                    assert!(!other.matches_compressed(&x_bytes, sign));
+                }
+            }
+
+            #[test]
+            fn is_valid_sec1_encoding() {
+                for p in points() {
+                    let (x, sign) = p.compress();
+                    let (x, y) = (x.to_bytes(), p.to_coordinate().1.to_bytes());
+                    let tag = if sign == Sign::Positive { 0x02 } else { 0x03 };
+                    let compressed = [&[tag][..], &x[..]].concat();
+                    let uncompressed = [&[0x04][..], &x[..], &y[..]].concat();
+                    assert!(PointAffine::is_valid_sec1_encoding(&compressed));
+                    assert!(PointAffine::is_valid_sec1_encoding(&uncompressed));
+
+                    // wrong lengths and tags
+                    assert!(!PointAffine::is_valid_sec1_encoding(&compressed[..x.len()]));
+                    assert!(!PointAffine::is_valid_sec1_encoding(&uncompressed[1..]));
+                    let hybrid = [&[0x06][..], &x[..], &y[..]].concat();
+                    assert!(!PointAffine::is_valid_sec1_encoding(&hybrid));
+
+                    // off the curve, and non canonical X
+                    let mut off = uncompressed.clone();
+                    *off.last_mut().unwrap() ^= 1;
+                    assert!(!PointAffine::is_valid_sec1_encoding(&off));
+                    let non_canonical = [&[tag][..], &P_BYTES[..]].concat();
+                    assert!(!PointAffine::is_valid_sec1_encoding(&non_canonical));
+                }
+                assert!(!PointAffine::is_valid_sec1_encoding(&[]));
+                assert!(!PointAffine::is_valid_sec1_encoding(&[0x00]));
+            }
+
+            #[test]
+            fn decompress_both() {
+                let mut on_curve = 0;
+                let mut off_curve = 0;
+                let xs = points().into_iter().map(|p| p.to_coordinate().0.clone());
+                let mut x = FieldElement::from_u64(0x5eed);
+                let mut others = Vec::new();
+                for i in 0..16 {
+                    x = x.square() + FieldElement::from_u64(i + 1);
+                    others.push(x.clone());
+                }
+                for x in xs.chain(others) {
+                    match PointAffine::decompress_both(&x) {
+                        None => {
+                            off_curve += 1;
+                            assert_eq!(PointAffine::decompress_even_y(&x), None);
+                            assert_eq!(PointAffine::decompress(&x, Sign::Positive), None);
+                        }
+                        Some((even, odd)) => {
+                            on_curve += 1;
+                            assert_eq!(even.to_coordinate().0, &x);
+                            assert_eq!(odd.to_coordinate().0, &x);
+                            assert_eq!(even.to_coordinate().1.sign(), Sign::Positive);
+                            assert_eq!(odd.to_coordinate().1.sign(), Sign::Negative);
+                            let (ex, ey) = even.to_coordinate();
+                            let (ox, oy) = odd.to_coordinate();
+                            assert!(PointAffine::from_coordinate(ex, ey).is_some());
+                            assert!(PointAffine::from_coordinate(ox, oy).is_some());
+                            assert_eq!(&-ey.clone(), oy);
+                            assert_eq!(PointAffine::decompress_even_y(&x), Some(even.clone()));
+                            assert_eq!(PointAffine::decompress(&x, Sign::Positive), Some(even));
+                            assert_eq!(PointAffine::decompress(&x, Sign::Negative), Some(odd));
+                        }
+                    }
+                }
+                // about half the X values are not on the curve
+                assert!(on_curve > points().len());
+                assert!(off_curve > 0);
+            }
+
+            #[test]
+            fn conditional_negate() {
+                for p in points() {
+                    let mut a = p.clone();
+                    a.conditional_negate(Choice::from_bit(0));
+                    assert_eq!(a, p);
+
+                    let proj = Point::from(&p);
+                    let mut b = proj.clone();
+                    b.conditional_negate(Choice::from_bit(1));
+                    assert_eq!(b, -&proj);
+                    b.conditional_negate(Choice::from_bit(1));
+                    assert_eq!(b, proj);
+                    b.conditional_negate(Choice::from_bit(0));
+                    assert_eq!(b, proj);
+
+                    // the sign of y is flipped exactly when negating
+                    let (_, sign) = p.compress();
+                    a.conditional_negate(Choice::from_bit(1));
+                    assert_eq!(Point::from(&a), -&proj);
+                    assert_eq!(a.compress().1, opposite(sign));
+
+                    // even y normalization
+                    let mut even = p.clone();
+                    even.conditional_negate(sign.to_choice());
+                    assert_eq!(even.compress().1, Sign::Positive);
+                }
+
+                for c in [0, 1] {
+                    let mut inf = Point::infinity();
+                    inf.conditional_negate(Choice::from_bit(c));
+                    assert_eq!(inf, Point::infinity());
+                    assert!(inf.to_affine().is_none());
+                }
+            }
+
+            test_infinity!(
+                infinity,
+                double,
+                add_different,
+                add_or_double,
+                scale_vartime,
+                scale_ladder
+            );
+            $($extra)*
+
+            // the scalar multiplications from the bits of the limbs of the scalars, and
+            // from their bytes, give the same points
+            #[test]
+            fn scale_from_limbs() {
+                let g = Point::generator();
+                let mut s = Scalar::from_u64(0x5eed);
+                let mut scalars = vec![Scalar::zero(), Scalar::one(), -Scalar::one()];
+                for _ in 0..4 {
+                    s = s.square() + Scalar::one();
+                    scalars.push(s.clone());
+                }
+                for s in scalars.iter() {
+                    let expected = g.scale_bytes(&s.to_bytes());
+                    assert_eq!(g.scale_ct(s), expected, "{:?}", s);
+                    assert_eq!(g.scale_vartime(s), expected, "{:?}", s);
+                    assert_eq!(&g * s, expected, "{:?}", s);
+                }
+            }
+
+            // (0,0,0) is not a valid point, so it must not be equal to any point
+            #[test]
+            fn invalid_point_equality() {
+                use crate::curve::projective::Point as P;
+
+                let zero = P {
+                    x: FieldElement::zero(),
+                    y: FieldElement::zero(),
+                    z: FieldElement::zero(),
+                };
+                let inf = P::<FieldElement>::infinity();
+                let g = P::from_affine(&crate::curve::affine::Point::new_unchecked(
+                    Curve::generator().0.clone(),
+                    Curve::generator().1.clone(),
+                ));
+                assert_eq!(zero, zero.clone());
+                assert_ne!(zero, inf);
+                assert_ne!(inf, zero);
+                assert_ne!(zero, g);
+                assert_ne!(g, zero);
+                // stays invalid when doubled, and is never mistaken for infinity
+                let doubled = zero.double(Curve);
+                assert_eq!(doubled, zero);
+                assert_ne!(doubled, inf);
+            }
+
+            #[test]
+            fn matches_compressed_wrong_length() {
+                let p = PointAffine::generator();
+                let (x, sign) = p.compress();
+                let x_bytes = x.to_bytes();
+
+                let mut longer = vec![0u8];
+                longer.extend_from_slice(&x_bytes);
+                assert!(!p.matches_compressed(&longer, sign));
+                assert!(!p.matches_compressed(&x_bytes[1..], sign));
+                assert!(!p.matches_compressed(&[], sign));
+            }
+
+            #[test]
+            fn matches_compressed_non_canonical() {
+                for p in points() {
+                    let (x, sign) = p.compress();
+                    // x + p represents the same field element but is not a canonical encoding
+                    if let Some(x_plus_p) = add_be(&x.to_bytes(), &P_BYTES) {
+                        assert_eq!(FieldElement::from_slice(&x_plus_p), None);
+                        assert!(!p.matches_compressed(&x_plus_p, sign));
+                    }
+                }
+            }
+
+            #[test]
+            fn matches_sec1() {
+                use crate::curve::sec1::Sec1Form;
+
+                for p in points() {
+                    let other = p.double();
+                    for form in [Sec1Form::Compressed, Sec1Form::Uncompressed, Sec1Form::Hybrid] {
+                        let bytes = p.encode_sec1(form);
+                        assert!(p.matches_sec1(&bytes));
+                        assert!(!other.matches_sec1(&bytes));
+                        assert!(!(-Point::from(&p)).to_affine().unwrap().matches_sec1(&bytes));
+                        assert!(!p.matches_sec1(&bytes[..bytes.len() - 1]));
+                        let mut longer = bytes.clone();
+                        longer.push(0);
+                        assert!(!p.matches_sec1(&longer));
+                    }
+
+                    // wrong sign of the compressed and the hybrid encodings
+                    let mut compressed = p.encode_sec1(Sec1Form::Compressed);
+                    compressed[0] ^= 1;
+                    assert!(!p.matches_sec1(&compressed));
+                    let mut hybrid = p.encode_sec1(Sec1Form::Hybrid);
+                    hybrid[0] ^= 1;
+                    assert!(!p.matches_sec1(&hybrid));
+
+                    // x + p is the same X, but not a canonical encoding
+                    let (x, _) = p.compress();
+                    if let Some(x_plus_p) = add_be(&x.to_bytes(), &P_BYTES) {
+                        let mut compressed = p.encode_sec1(Sec1Form::Compressed);
+                        compressed[1..].copy_from_slice(&x_plus_p);
+                        assert!(!p.matches_sec1(&compressed));
+                        let mut uncompressed = p.encode_sec1(Sec1Form::Uncompressed);
+                        uncompressed[1..1 + FieldElement::SIZE_BYTES].copy_from_slice(&x_plus_p);
+                        assert!(!p.matches_sec1(&uncompressed));
+                    }
+
+                    // other prefixes
+                    let mut bytes = p.encode_sec1(Sec1Form::Uncompressed);
+                    for tag in [0x00, 0x01, 0x05, 0x08, 0xff] {
+                        bytes[0] = tag;
+                        assert!(!p.matches_sec1(&bytes));
+                    }
+                }
+                assert!(!PointAffine::generator().matches_sec1(&[0x00]));
+                assert!(!PointAffine::generator().matches_sec1(&[]));
+            }
+
+            #[test]
+            fn mul_to_affine() {
+                let mut s = Scalar::from_u64(0x61666669);
+                for p in points() {
+                    let p = Point::from(&p);
+                    for _ in 0..4 {
+                        s = s.square() + Scalar::one();
+                        assert_eq!(p.mul_to_affine(&s), (&p * &s).to_affine());
+                        assert!(p.mul_to_affine(&s).is_some());
+                    }
+                    // infinity exactly when the scalar is 0 mod the order
+                    assert_eq!(p.mul_to_affine(&Scalar::zero()), None);
+                    assert_eq!(Point::infinity().mul_to_affine(&s), None);
+                    let minus_one = p.mul_to_affine(&-Scalar::one()).unwrap();
+                    assert_eq!(Point::from(&minus_one), -&p);
+                }
+            }
+
+            #[test]
+            fn compress_projective() {
+                for p in points() {
+                    let (x, sign) = p.compress();
+                    let mut expected = vec![if sign == Sign::Positive { 0x02 } else { 0x03 }];
+                    expected.extend_from_slice(&x.to_bytes());
+
+                    // (X:Y:1) and the same point with Z != 1
+                    let normalized = Point::from(&p);
+                    let scaled = &(&normalized + &normalized) + &(-&normalized);
+                    for q in [normalized, scaled].iter() {
+                        assert_eq!(q.compress(), Some((x.clone(), sign)));
+                        assert_eq!(q.to_sec1_compressed().unwrap().to_vec(), expected);
+                    }
+                }
+                let inf = &Point::generator() + &(-&Point::generator());
+                assert_eq!(inf.compress(), None);
+                assert_eq!(inf.to_sec1_compressed(), None);
+                assert_eq!(Point::infinity().compress(), None);
+            }
+
+            #[test]
+            fn unnormalized_bytes() {
+                let n = FieldElement::SIZE_BYTES;
+                for p in points() {
+                    let normalized = Point::from(&p);
+                    let scaled = &(&normalized + &normalized) + &(-&normalized);
+                    let bytes = scaled.to_unnormalized_bytes();
+                    assert_ne!(bytes, normalized.to_unnormalized_bytes());
+                    let loaded = Point::from_unnormalized_bytes(&bytes).unwrap();
+                    assert_eq!(loaded, normalized);
+                    assert_eq!(loaded.to_unnormalized_bytes(), bytes);
+
+                    // normalizing commutes with the round trip
+                    let mut after = loaded.clone();
+                    after.normalize();
+                    let mut before = scaled.clone();
+                    before.normalize();
+                    let before = Point::from_unnormalized_bytes(&before.to_unnormalized_bytes()).unwrap();
+                    assert_eq!(after.to_unnormalized_bytes(), before.to_unnormalized_bytes());
+
+                    // corrupted coordinates
+                    for i in [0, n - 1, n, 2 * n - 1, 2 * n, 3 * n - 1].iter() {
+                        let mut corrupted = bytes;
+                        corrupted[*i] ^= 0x01;
+                        assert_eq!(Point::from_unnormalized_bytes(&corrupted), None, "byte {}", i);
+                    }
+                    let mut x_p = bytes;
+                    x_p[..n].copy_from_slice(&P_BYTES);
+                    assert_eq!(Point::from_unnormalized_bytes(&x_p), None);
+                }
+
+                for inf in [Point::infinity(), &Point::generator() + &(-&Point::generator())].iter() {
+                    let loaded = Point::from_unnormalized_bytes(&inf.to_unnormalized_bytes()).unwrap();
+                    assert_eq!(loaded, Point::infinity());
+                }
+                assert_eq!(Point::from_unnormalized_bytes(&[0; 3 * FieldElement::SIZE_BYTES]), None);
+            }
+
+            #[test]
+            fn mul_algorithms() {
+                use crate::curve::MulAlgorithm;
+
+                let mut scalars = vec![Scalar::zero(), Scalar::one(), -Scalar::one()];
+                let mut s = Scalar::from_u64(0x5eed);
+                for _ in 0..6 {
+                    s = s.square() + Scalar::one();
+                    scalars.push(s.clone());
+                }
+                for p in points().iter().map(Point::from).chain(Some(Point::infinity())) {
+                    for s in scalars.iter() {
+                        let expected = &p * s;
+                        let expected_bytes = expected
+                            .to_affine()
+                            .map(|a| (a.to_coordinate().0.to_bytes(), a.to_coordinate().1.to_bytes()));
+                        // the operator is bit-for-bit the default algorithm
+                        let default = p.scale_with(s, MulAlgorithm::DEFAULT);
+                        assert_eq!(
+                            default
+                                .to_affine()
+                                .map(|a| (a.to_coordinate().0.to_bytes(), a.to_coordinate().1.to_bytes())),
+                            expected_bytes
+                        );
+                        for alg in MulAlgorithm::all() {
+                            assert_eq!(p.scale_with(s, alg), expected, "{:?}", alg);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn mul_wnaf_invalid_width() {
+                use crate::curve::MulAlgorithm;
+                Point::generator().scale_with(&Scalar::one(), MulAlgorithm::Wnaf { width: 9 });
+            }
+
+            #[test]
+            fn affine_constructors() {
+                use crate::curve::affine;
+
+                for p in points() {
+                    let (x, y) = p.to_coordinate();
+                    let q = affine::Point::new(x.clone(), y.clone(), Curve).unwrap();
+                    assert_eq!((q.x(), q.y()), (x, y));
+                    assert!(q.is_on_curve(Curve));
+
+                    let off_y = y + &FieldElement::one();
+                    assert!(affine::Point::new(x.clone(), off_y.clone(), Curve).is_none());
+                    assert!(PointAffine::from_coordinate(x, &off_y).is_none());
+                    let off = affine::Point::new_unchecked(x.clone(), off_y);
+                    assert!(!off.is_on_curve(Curve));
+                }
+            }
+
+            #[test]
+            fn normalized() {
+                let g = Point::from_affine(&PointAffine::generator());
+                assert!(g.is_normalized());
+                assert!(Point::generator().is_normalized());
+
+                let mut p = &g * 3u64;
+                assert!(!p.is_normalized());
+                let affine = p.to_affine();
+                assert_eq!(p.clone().normalized().to_affine(), affine);
+                assert!(p.clone().normalized().is_normalized());
+                p.normalize();
+                assert!(p.is_normalized());
+                assert_eq!(p.to_affine(), affine);
+                assert_eq!(p.clone().into_affine(), affine);
+
+                // infinity is not normalized, and stays the point at infinity
+                let inf = Point::infinity();
+                assert!(!inf.is_normalized());
+                let mut n = inf.clone().normalized();
+                assert!(!n.is_normalized());
+                n.normalize();
+                assert_eq!(n, inf);
+            }
+
+            #[test]
+            fn affine_add() {
+                let g = PointAffine::generator();
+                let minus_g = (-Point::generator()).to_affine().unwrap();
+                assert_eq!(g.add(&g), Some(g.double()));
+                assert_eq!(&g + &g, (&Point::generator() * 2u64).to_affine().unwrap());
+                assert_eq!(g.add(&minus_g), None);
+                for p in points() {
+                    let expected = (&Point::from(&g) + &Point::from(&p)).to_affine();
+                    assert_eq!(g.add(&p), expected);
+                }
+            }
+
+            #[test]
+            #[should_panic(expected = "point at infinity")]
+            fn affine_add_opposite() {
+                let minus_g = (-Point::generator()).to_affine().unwrap();
+                let _ = &PointAffine::generator() + &minus_g;
+            }
+
+            #[test]
+            fn generic_affine_add() {
+                use crate::curve::affine;
+
+                let g = PointAffine::generator();
+                let (gx, gy) = g.to_coordinate();
+                let ga = affine::Point::new(gx.clone(), gy.clone(), Curve).unwrap();
+                for p in points().iter().skip(1) {
+                    let (x, y) = p.to_coordinate();
+                    let pa = affine::Point::new(x.clone(), y.clone(), Curve).unwrap();
+                    let sum = &ga + &pa;
+                    let expected = (&g + p).into_coordinates();
+                    assert_eq!(sum.into_coordinates(), expected);
+                }
+            }
+
+            #[test]
+            #[should_panic(expected = "point at infinity")]
+            fn generic_affine_add_opposite() {
+                use crate::curve::affine;
+
+                let (x, y) = PointAffine::generator().into_coordinates();
+                let g = affine::Point::new(x.clone(), y.clone(), Curve).unwrap();
+                let minus_g = affine::Point::new(x, -y, Curve).unwrap();
+                let _ = &g + &minus_g;
+            }
+
+            #[test]
+            #[should_panic(expected = "doubling needs the curve")]
+            fn generic_affine_add_equal() {
+                use crate::curve::affine;
+
+                let (x, y) = PointAffine::generator().into_coordinates();
+                let g = affine::Point::new(x, y, Curve).unwrap();
+                let _ = &g + &g;
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "not on the curve")]
+            fn from_affine_off_curve() {
+                use crate::curve::affine;
+
+                let g = PointAffine::generator();
+                let (x, y) = g.to_coordinate();
+                let off = affine::Point::new_unchecked(x.clone(), y + &FieldElement::one());
+                Point::from_affine(&PointAffine(off));
+            }
+
+            #[test]
+            fn point_table() {
+                use crate::curve::sec2::$curve::{PointTable, PointTable4, PointTable6};
+
+                let p = &Point::generator() * &Scalar::from_u64(0x5eed);
+                let t2 = PointTable::<2, 3>::new(&p).unwrap();
+                let t4 = PointTable4::new(&p).unwrap();
+                let t6 = PointTable6::new(&p).unwrap();
+                assert_eq!((t2.width(), t4.width(), t6.width()), (2, 4, 6));
+
+                let mut scalars = vec![
+                    Scalar::zero(),
+                    Scalar::one(),
+                    Scalar::from_u64(2),
+                    Scalar::from_u64(0xdeadbeef),
+                    -Scalar::one(),
+                ];
+                let mut s = Scalar::from_u64(0x1234_5678);
+                for _ in 0..8 {
+                    s = &s * &s + Scalar::one();
+                    scalars.push(s.clone());
+                }
+                for s in scalars.iter() {
+                    let expected = &p * s;
+                    assert_eq!(t2.mul(s), expected);
+                    assert_eq!(t4.mul(s), expected);
+                    assert_eq!(t6.mul(s), expected);
+                }
+                assert!(PointTable4::new(&Point::infinity()).is_none());
+            }
+
+            #[test]
+            fn generator_table() {
+                use crate::curve::sec2::$curve::GeneratorTable;
+
+                let table = GeneratorTable::new();
+                let mut scalars = vec![
+                    Scalar::zero(),
+                    Scalar::one(),
+                    Scalar::from_u64(15),
+                    Scalar::from_u64(16),
+                    -Scalar::one(),
+                    -Scalar::from_u64(2),
+                ];
+                let mut s = Scalar::from_u64(0x9e4e_7a7e);
+                for _ in 0..8 {
+                    s = &s * &s + Scalar::one();
+                    scalars.push(s.clone());
+                }
+                for s in scalars.iter() {
+                    let expected = &Point::generator() * s;
+                    assert_eq!(table.mul(s), expected);
+                    assert_eq!(Point::base_scalar_mul(s), expected);
+                }
+            }
+
+            #[test]
+            fn ct_eq() {
+                use crate::CtEqual;
+
+                let g = Point::generator();
+                let g2 = &g + &g;
+                // same point with a different Z
+                let g2_bis = &(&g2 + &g) - &g;
+                assert!(g2.ct_eq(&g2_bis).is_true());
+                assert!(g2.ct_ne(&g2_bis).is_false());
+                assert!(g2.ct_eq(&g).is_false());
+                assert!(g.ct_eq(&Point::infinity()).is_false());
+                assert!(Point::infinity().ct_eq(&Point::infinity()).is_true());
+                assert!((g2.ct_eq(&g2_bis) & g.ct_eq(&g)).is_true());
+                assert!((g2.ct_eq(&g2_bis) & g.ct_eq(&g2)).is_false());
+
+                let a = g2.to_affine().unwrap();
+                let b = g2_bis.to_affine().unwrap();
+                assert!(a.ct_eq(&b).is_true());
+                assert!(a.ct_eq(&PointAffine::generator()).is_false());
+                // same X, opposite Y
+                assert!(a.ct_eq(&(-&g2).to_affine().unwrap()).is_false());
+                assert_eq!(a == b, a.ct_eq(&b).is_true());
+
+                let s = Scalar::from_u64(0x1234);
+                assert!(s.ct_eq(&Scalar::from_u64(0x1234)).is_true());
+                assert!(s.ct_eq(&Scalar::one()).is_false());
+                let (x, y) = a.to_coordinate();
+                assert!(x.ct_eq(x).is_true());
+                assert!(x.ct_eq(y).is_false());
+                assert!(FieldElement::one().ct_eq(&FieldElement::one()).is_true());
+            }
+        }
+    };
+}
+
+// the consuming accessors agree with the borrowing ones
+macro_rules! test_into {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{Point, PointAffine};
+
+            #[test]
+            fn into_affine() {
+                let g = Point::generator();
+                let g3 = &(&g + &g) + &g;
+                let mut normalized = g3.clone();
+                normalized.normalize();
+                for p in [g3, normalized].iter() {
+                    assert_eq!(p.clone().into_affine(), p.to_affine());
+                }
+                let inf = &g + &(-&g);
+                assert_eq!(inf.clone().into_affine(), None);
+                assert_eq!(inf.to_affine(), None);
+            }
+
+            #[test]
+            fn into_coordinates() {
+                let g = PointAffine::generator();
+                for p in [g.clone(), g.double()].iter() {
+                    let (x, y) = p.to_coordinate();
+                    let (x, y) = (x.clone(), y.clone());
+                    assert_eq!(p.clone().into_coordinates(), (x, y));
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_mul_ops {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec2::$curve::{Point, Scalar};
+
+            #[test]
+            fn mul_u64() {
+                let g = Point::generator();
+                let g7 = &(&g + &g) + &(&(&g + &g) + &(&(&g + &g) + &g));
+                for p in [g.clone(), g7, Point::infinity()].iter() {
+                    for n in [0u64, 1, 2, 5, 0xdead_beef, u64::MAX - 1, u64::MAX].iter() {
+                        let expected = p * &Scalar::from_u64(*n);
+                        assert_eq!(p * *n, expected);
+                        assert_eq!(p.clone() * *n, expected);
+                        assert_eq!(p.scale_u64(*n), expected);
+                    }
+                }
+            }
+
+            #[test]
+            fn mul_bytes() {
+                let g = Point::generator();
+                for s in [
+                    Scalar::zero(),
+                    Scalar::one(),
+                    Scalar::from_u64(u64::MAX),
+                    -Scalar::one(),
+                ]
+                .iter()
+                {
+                    assert_eq!(&g * &s.to_bytes(), Some(&g * s));
+                }
+                // not less than the group order
+                assert_eq!(&g * &[0xff; Scalar::SIZE_BYTES], None);
+            }
+
+            // the operator is bit-for-bit the named method of the default-mul-* feature
+            // of the build, and the other named method gives the same point
+            #[test]
+            fn default_mul() {
+                let g = Point::generator();
+                let p = &g * 7u64;
+                let mut scalars = vec![
+                    Scalar::zero(),
+                    Scalar::one(),
+                    Scalar::from_u64(0x5eed),
+                    -Scalar::one(),
+                ];
+                // and pseudo random looking scalars of any size
+                let mut s = Scalar::from_u64(0xc7);
+                for i in 0..8 {
+                    s = &s * &s + Scalar::from_u64(i);
+                    scalars.push(s.clone());
+                }
+                for q in [g, p, Point::infinity()].iter() {
+                    for s in scalars.iter() {
+                        let r = q * s;
+                        #[cfg(feature = "default-mul-ct")]
+                        let (default, other) = (q.scale_ct(s), q.scale_vartime(s));
+                        #[cfg(feature = "default-mul-vartime")]
+                        let (default, other) = (q.scale_vartime(s), q.scale_ct(s));
+                        assert_eq!(format!("{:?}", r), format!("{:?}", default));
+                        assert_eq!(other, r);
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod mul_ops {
+    #[cfg(feature = "p112r2")]
+    test_mul_ops!(p112r2);
+    #[cfg(feature = "p128r1")]
+    test_mul_ops!(p128r1);
+    #[cfg(feature = "p128r2")]
+    test_mul_ops!(p128r2);
+    #[cfg(feature = "p160k1")]
+    test_mul_ops!(p160k1);
+    #[cfg(feature = "p160r1")]
+    test_mul_ops!(p160r1);
+    #[cfg(feature = "p160r2")]
+    test_mul_ops!(p160r2);
+    #[cfg(feature = "p192k1")]
+    test_mul_ops!(p192k1);
+    #[cfg(feature = "p192r1")]
+    test_mul_ops!(p192r1);
+    #[cfg(feature = "p224k1")]
+    test_mul_ops!(p224k1);
+    #[cfg(feature = "p224r1")]
+    test_mul_ops!(p224r1);
+    #[cfg(feature = "p256k1")]
+    test_mul_ops!(p256k1);
+    #[cfg(feature = "p256r1")]
+    test_mul_ops!(p256r1);
+    #[cfg(feature = "p384r1")]
+    test_mul_ops!(p384r1);
+    #[cfg(feature = "p521r1")]
+    test_mul_ops!(p521r1);
+}
+
+mod into {
+    #[cfg(feature = "p112r2")]
+    test_into!(p112r2);
+    #[cfg(feature = "p128r1")]
+    test_into!(p128r1);
+    #[cfg(feature = "p128r2")]
+    test_into!(p128r2);
+    #[cfg(feature = "p160k1")]
+    test_into!(p160k1);
+    #[cfg(feature = "p160r1")]
+    test_into!(p160r1);
+    #[cfg(feature = "p160r2")]
+    test_into!(p160r2);
+    #[cfg(feature = "p192k1")]
+    test_into!(p192k1);
+    #[cfg(feature = "p192r1")]
+    test_into!(p192r1);
+    #[cfg(feature = "p224k1")]
+    test_into!(p224k1);
+    #[cfg(feature = "p224r1")]
+    test_into!(p224r1);
+    #[cfg(feature = "p256k1")]
+    test_into!(p256k1);
+    #[cfg(feature = "p256r1")]
+    test_into!(p256r1);
+    #[cfg(feature = "p384r1")]
+    test_into!(p384r1);
+    #[cfg(feature = "p521r1")]
+    test_into!(p521r1);
+}
+
+#[cfg(feature = "p192k1")]
+test_point!(p192k1, a0);
+#[cfg(feature = "p192r1")]
+test_point!(p192r1);
+#[cfg(feature = "p224k1")]
+test_point!(p224k1, a0);
+#[cfg(feature = "p224r1")]
+test_point!(p224r1);
+#[cfg(feature = "p256k1")]
+test_point!(p256k1, a0);
+#[cfg(feature = "p256r1")]
+test_point!(p256r1);
+#[cfg(feature = "p384r1")]
+test_point!(p384r1);
+#[cfg(feature = "p521r1")]
+test_point!(p521r1);
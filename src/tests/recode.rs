@@ -0,0 +1,179 @@
+//! Signed fixed window recoding of the scalars (recode_signed_fixed)
+
+use crate::curve::projective::{recode_signed_fixed, RecodedScalar};
+
+// digits in range, and exactly ⌈bits/w⌉ of them
+fn check_shape(r: &RecodedScalar, bits: usize, w: usize) {
+    assert_eq!(r.width(), w);
+    assert_eq!(r.digits().len(), bits.div_ceil(w));
+    let half = 1i32 << (w - 1);
+    assert!(r
+        .digits()
+        .iter()
+        .all(|d| (-half..half).contains(&(*d as i32))));
+    assert!(r.carry() <= 1);
+}
+
+macro_rules! test_recode {
+    ($curve: ident) => {
+        test_recode!($curve, crate::curve::sec2::$curve);
+    };
+    ($curve: ident, $module: path) => {
+        mod $curve {
+            use super::check_shape;
+            use curve::Scalar;
+            use $module as curve;
+
+            // sum(d_i 2^(w i)) + c 2^(w n), computed modulo the order
+            fn reconstruct(r: &crate::curve::projective::RecodedScalar) -> Scalar {
+                let radix = Scalar::from_u64(1 << r.width());
+                let mut acc = Scalar::from_u64(r.carry() as u64);
+                for d in r.digits().iter().rev() {
+                    let digit = Scalar::from_u64((*d as i64).unsigned_abs());
+                    acc = &acc * &radix;
+                    acc = if *d < 0 { &acc - &digit } else { &acc + &digit };
+                }
+                acc
+            }
+
+            fn scalars() -> Vec<Scalar> {
+                let mut v = vec![
+                    Scalar::zero(),
+                    Scalar::one(),
+                    Scalar::from_u64(2),
+                    Scalar::max(),
+                    &Scalar::max() - &Scalar::one(),
+                    Scalar::half_max(),
+                ];
+                let mut x = 0x5eedu64;
+                for _ in 0..32 {
+                    let bytes: Vec<u8> = (0..2 * Scalar::SIZE_BYTES)
+                        .map(|_| {
+                            x = x
+                                .wrapping_mul(6364136223846793005)
+                                .wrapping_add(1442695040888963407);
+                            (x >> 56) as u8
+                        })
+                        .collect();
+                    v.push(Scalar::from_be_bytes_mod_p(&bytes));
+                }
+                v
+            }
+
+            fn generator() -> crate::curve::projective::Point<curve::FieldElement> {
+                use crate::curve::{affine, projective};
+
+                let (x, y) = curve::Curve::generator();
+                let g = affine::Point::new(x.clone(), y.clone(), curve::Curve).unwrap();
+                projective::Point::from_affine(&g)
+            }
+
+            // the recoded digits consumed by the fixed base table, for the scalars and
+            // for bytes of the size of the scalars that are not reduced
+            #[test]
+            fn fixed_base_table() {
+                use crate::curve::projective::FixedBaseTable;
+                use curve::{Curve, FieldElement};
+
+                let g = generator();
+                let bits = Scalar::SIZE_BYTES * 8;
+                let t4 = FixedBaseTable::<FieldElement, 4, 8>::new(&g, bits, Curve).unwrap();
+                let t6 = FixedBaseTable::<FieldElement, 6, 32>::new(&g, bits, Curve).unwrap();
+                for s in scalars() {
+                    let bytes = s.to_bytes();
+                    let expected = g.scale_vartime(&bytes, Curve);
+                    assert_eq!(t4.scale(&bytes, Curve), expected);
+                    assert_eq!(t6.scale(&bytes, Curve), expected);
+                    assert_eq!(t4.scale_recoded(&s.recode_signed_fixed(4), Curve), expected);
+                    assert_eq!(t6.scale_recoded(&s.recode_signed_fixed(6), Curve), expected);
+                }
+                for bytes in [
+                    vec![0xff; Scalar::SIZE_BYTES],
+                    vec![0x80; Scalar::SIZE_BYTES],
+                ] {
+                    let expected = g.scale_vartime(&bytes, Curve);
+                    assert_eq!(t4.scale(&bytes, Curve), expected);
+                    assert_eq!(t6.scale(&bytes, Curve), expected);
+                }
+                assert_eq!(t4.scale(&[], Curve), g.scale_vartime(&[], Curve));
+            }
+
+            #[test]
+            #[should_panic(expected = "scalar longer than the fixed base table")]
+            fn fixed_base_table_longer() {
+                use crate::curve::projective::FixedBaseTable;
+                use curve::{Curve, FieldElement};
+
+                let bits = Scalar::SIZE_BYTES * 8;
+                let t4 =
+                    FixedBaseTable::<FieldElement, 4, 8>::new(&generator(), bits, Curve).unwrap();
+                t4.scale(&vec![0; Scalar::SIZE_BYTES + 1], Curve);
+            }
+
+            #[test]
+            fn reconstruct_scalars() {
+                for w in [4, 5, 6] {
+                    for s in scalars() {
+                        let r = s.recode_signed_fixed(w);
+                        check_shape(&r, Scalar::SIZE_BITS, w);
+                        assert_eq!(reconstruct(&r), s, "w = {}", w);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_recode!(p192k1);
+#[cfg(feature = "p192r1")]
+test_recode!(p192r1);
+#[cfg(feature = "p224k1")]
+test_recode!(p224k1);
+#[cfg(feature = "p224r1")]
+test_recode!(p224r1);
+#[cfg(feature = "p256k1")]
+test_recode!(p256k1);
+#[cfg(feature = "p256r1")]
+test_recode!(p256r1);
+#[cfg(feature = "p384r1")]
+test_recode!(p384r1);
+#[cfg(feature = "p521r1")]
+test_recode!(p521r1);
+#[cfg(feature = "sm2")]
+test_recode!(sm2, crate::curve::sm2);
+
+// every value of a 16 bits synthetic modulus, for all the widths, the reconstruction
+// being exact on integers
+#[test]
+fn exhaustive_16_bits() {
+    for w in 2..=8 {
+        for n in 0u32..1 << 16 {
+            let r = recode_signed_fixed(&(n as u16).to_be_bytes(), 16, w);
+            check_shape(&r, 16, w);
+            let mut v = (r.carry() as i64) << (w * r.digits().len());
+            for (i, d) in r.digits().iter().enumerate() {
+                v += (*d as i64) << (w * i);
+            }
+            assert_eq!(v, n as i64, "n = {}, w = {}", n, w);
+        }
+    }
+}
+
+// only the low bits are recoded, and the length of the input doesn't change the digits
+#[test]
+fn bits_and_padding() {
+    let r = recode_signed_fixed(&[0x00, 0x00, 0xff, 0xff], 16, 4);
+    assert_eq!(r.digits(), &[-1, 0, 0, 0]);
+    assert_eq!(r.carry(), 1);
+    assert_eq!(recode_signed_fixed(&[0xff, 0xff], 16, 4), r);
+
+    // the top window is shorter than the width
+    let r = recode_signed_fixed(&[0x1f], 5, 4);
+    assert_eq!(r.digits(), &[-1, 2]);
+    assert_eq!(r.carry(), 0);
+
+    let r = recode_signed_fixed(&[], 0, 4);
+    assert!(r.digits().is_empty());
+    assert_eq!(r.carry(), 0);
+}
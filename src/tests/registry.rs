@@ -0,0 +1,165 @@
+use crate::registry::{AnyPoint, AnyPublicKey, AnySecretKey, CurveId, RegistryError};
+
+macro_rules! test_registry {
+    ($curve: ident, $id: ident) => {
+        mod $curve {
+            use super::*;
+            use crate::curve::sec2::$curve::{Point, PublicKey, Scalar, SecretKey};
+
+            fn secret(n: u64) -> SecretKey {
+                SecretKey::from_bytes(&Scalar::from_u64(n).to_bytes()).unwrap()
+            }
+
+            #[test]
+            fn identifiers() {
+                let id = CurveId::$id;
+                assert!(id.is_enabled());
+                assert!(CurveId::enabled().any(|c| c == id));
+                assert_eq!(CurveId::from_iana(id.iana_id()), Ok(id));
+                assert_eq!(CurveId::from_name(id.name()), Ok(id));
+                assert_eq!(CurveId::from_oid(id.oid()), Ok(id));
+                assert_eq!(AnyPoint::generator(id).unwrap().curve(), id);
+            }
+
+            #[test]
+            fn sec1() {
+                let id = CurveId::$id;
+                let pk = secret(0x1234).public_key();
+                for compressed in [false, true].iter() {
+                    let bytes = pk.to_sec1_bytes(*compressed);
+                    let any = AnyPublicKey::from_sec1_bytes(id, &bytes).unwrap();
+                    assert_eq!(any, AnyPublicKey::$id(pk.clone()));
+                    assert_eq!(any.curve(), id);
+                    assert_eq!(any.to_sec1_bytes(*compressed), bytes);
+                }
+                let mut bad = pk.to_sec1_bytes(false);
+                let last = bad.len() - 1;
+                bad[last] ^= 1;
+                assert_eq!(
+                    AnyPublicKey::from_sec1_bytes(id, &bad),
+                    Err(RegistryError::InvalidEncoding)
+                );
+            }
+
+            #[test]
+            fn scalar_mul() {
+                let id = CurveId::$id;
+                let n = [0x5a; Scalar::SIZE_BYTES];
+                let expected = Point::generator().scale_bytes(&n);
+                let g = AnyPoint::generator(id).unwrap();
+                assert_eq!(g.scale_bytes(&n), AnyPoint::$id(expected.clone()));
+                assert_eq!(
+                    g.scale_bytes(&n).to_public_key(),
+                    expected
+                        .to_affine()
+                        .map(|p| AnyPublicKey::$id(PublicKey::from_point(p)))
+                );
+                assert_eq!(g.scale_bytes(&[]).to_public_key(), None);
+                assert_eq!(
+                    g.add(&g).unwrap(),
+                    AnyPoint::$id(&Point::generator() + &Point::generator())
+                );
+
+                let pk = secret(7).public_key();
+                let any = AnyPoint::from_public_key(&AnyPublicKey::$id(pk.clone()));
+                assert_eq!(any, AnyPoint::$id(Point::from(pk.as_point())));
+            }
+
+            #[test]
+            fn ecdh() {
+                let id = CurveId::$id;
+                let (a, b) = (secret(0xdead), secret(0xbeef));
+                let shared = Point::from(b.public_key().as_point())
+                    .mul_to_affine(a.as_scalar())
+                    .unwrap()
+                    .to_coordinate()
+                    .0
+                    .to_bytes();
+
+                let any_a = AnySecretKey::from_bytes(id, &a.to_bytes()).unwrap();
+                let any_b = AnySecretKey::from_bytes(id, &b.to_bytes()).unwrap();
+                assert_eq!(any_a.curve(), id);
+                assert_eq!(any_a.public_key(), AnyPublicKey::$id(a.public_key()));
+                let ab = any_a.diffie_hellman(&any_b.public_key()).unwrap();
+                assert_eq!(ab, shared.to_vec());
+                assert_eq!(any_b.diffie_hellman(&any_a.public_key()), Ok(ab));
+
+                assert_eq!(
+                    AnySecretKey::from_bytes(id, &Scalar::zero().to_bytes()),
+                    Err(RegistryError::InvalidScalar)
+                );
+                assert_eq!(
+                    AnySecretKey::from_bytes(id, &[1]),
+                    Err(RegistryError::InvalidScalar)
+                );
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_registry!(p192k1, Secp192k1);
+#[cfg(feature = "p192r1")]
+test_registry!(p192r1, Secp192r1);
+#[cfg(feature = "p224k1")]
+test_registry!(p224k1, Secp224k1);
+#[cfg(feature = "p224r1")]
+test_registry!(p224r1, Secp224r1);
+#[cfg(feature = "p256k1")]
+test_registry!(p256k1, Secp256k1);
+#[cfg(feature = "p256r1")]
+test_registry!(p256r1, Secp256r1);
+#[cfg(feature = "p384r1")]
+test_registry!(p384r1, Secp384r1);
+#[cfg(feature = "p521r1")]
+test_registry!(p521r1, Secp521r1);
+
+#[test]
+fn unknown_curves() {
+    assert_eq!(CurveId::from_iana(29), Err(RegistryError::UnknownCurve));
+    assert_eq!(CurveId::from_iana(0), Err(RegistryError::UnknownCurve));
+    assert_eq!(
+        CurveId::from_name("P-256"),
+        Err(RegistryError::UnknownCurve)
+    );
+    assert_eq!(
+        CurveId::from_oid("1.3.101.110"),
+        Err(RegistryError::UnknownCurve)
+    );
+    assert_eq!(CurveId::from_iana(23), Ok(CurveId::Secp256r1));
+    assert_eq!(CurveId::Secp256r1.oid(), "1.2.840.10045.3.1.7");
+    assert_eq!(CurveId::Secp256k1.to_string(), "secp256k1");
+}
+
+#[test]
+fn disabled_curves() {
+    for id in CurveId::ALL.iter().copied().filter(|c| !c.is_enabled()) {
+        let err = RegistryError::DisabledCurve(id);
+        assert_eq!(AnyPoint::generator(id), Err(err));
+        assert_eq!(AnyPublicKey::from_sec1_bytes(id, &[0]), Err(err));
+        assert_eq!(AnySecretKey::from_bytes(id, &[1]), Err(err));
+    }
+}
+
+#[test]
+fn curve_mismatch() {
+    let mut enabled = CurveId::enabled();
+    if let (Some(c1), Some(c2)) = (enabled.next(), enabled.next()) {
+        let (g1, g2) = (
+            AnyPoint::generator(c1).unwrap(),
+            AnyPoint::generator(c2).unwrap(),
+        );
+        assert_eq!(g1.add(&g2), Err(RegistryError::CurveMismatch));
+        // secret key 1, of the scalar size of c1
+        let sk1 = (1..=66)
+            .find_map(|n| {
+                let mut bytes = vec![0; n];
+                bytes[n - 1] = 1;
+                AnySecretKey::from_bytes(c1, &bytes).ok()
+            })
+            .unwrap();
+        assert_eq!(sk1.public_key(), g1.to_public_key().unwrap());
+        let pk2 = g2.to_public_key().unwrap();
+        assert_eq!(sk1.diffie_hellman(&pk2), Err(RegistryError::CurveMismatch));
+    }
+}
@@ -0,0 +1,108 @@
+//! Deterministic random generator for tests
+//!
+//! This is a simple xorshift64* generator, which is absolutely not
+//! cryptographically secure, but give reproducible values for testing.
+#![allow(dead_code)]
+
+use rand_core::{impls, CryptoRng, Error, RngCore};
+
+pub struct TestRng {
+    state: u64,
+    zeros: usize,
+}
+
+impl TestRng {
+    pub fn new(seed: u64) -> Self {
+        Self::with_zeros(seed, 0)
+    }
+
+    /// Create a generator that output `zeros` zero bytes before any random data
+    pub fn with_zeros(seed: u64, zeros: usize) -> Self {
+        TestRng {
+            state: seed | 1,
+            zeros,
+        }
+    }
+
+    /// Number of zero bytes still to be output
+    pub fn zeros_left(&self) -> usize {
+        self.zeros
+    }
+}
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let zeros = std::cmp::min(self.zeros, dest.len());
+        for b in dest[..zeros].iter_mut() {
+            *b = 0
+        }
+        self.zeros -= zeros;
+        impls::fill_bytes_via_next(self, &mut dest[zeros..])
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+/// Generator that outputs the given chunks first, each filling a whole request,
+/// and then the values of a `TestRng`
+pub struct ScriptedRng {
+    chunks: Vec<Vec<u8>>,
+    rng: TestRng,
+}
+
+impl ScriptedRng {
+    pub fn new(chunks: Vec<Vec<u8>>, seed: u64) -> Self {
+        ScriptedRng {
+            chunks,
+            rng: TestRng::new(seed),
+        }
+    }
+
+    /// Number of chunks still to be output
+    pub fn chunks_left(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
+impl RngCore for ScriptedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.chunks.is_empty() {
+            self.rng.fill_bytes(dest)
+        } else {
+            let chunk = self.chunks.remove(0);
+            assert_eq!(chunk.len(), dest.len(), "scripted chunk of the wrong size");
+            dest.copy_from_slice(&chunk)
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ScriptedRng {}
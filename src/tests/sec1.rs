@@ -0,0 +1,321 @@
+//! Batch validation of the SEC1 encodings of points
+
+macro_rules! test_sec1 {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::sec1::{Sec1Error, Sec1Form, ValidationFlags};
+            use crate::curve::sec2::$curve::{FieldElement, Point, PointAffine, Scalar};
+            use crate::curve::Sign;
+
+            fn points() -> Vec<PointAffine> {
+                [1u64, 2, 3, 0x1234, 0xdeadbeef]
+                    .iter()
+                    .map(|n| {
+                        (&Point::generator() * &Scalar::from_u64(*n))
+                            .to_affine()
+                            .unwrap()
+                    })
+                    .collect()
+            }
+
+            fn compressed(p: &PointAffine) -> Vec<u8> {
+                let (x, sign) = p.compress();
+                let tag = match sign {
+                    Sign::Positive => 0x02,
+                    Sign::Negative => 0x03,
+                };
+                [&[tag][..], &x.to_bytes()[..]].concat()
+            }
+
+            fn uncompressed(p: &PointAffine) -> Vec<u8> {
+                let (x, y) = p.to_coordinate();
+                [&[0x04][..], &x.to_bytes()[..], &y.to_bytes()[..]].concat()
+            }
+
+            // an X coordinate without any point
+            fn not_on_curve_x() -> Vec<u8> {
+                let mut x = FieldElement::one();
+                while PointAffine::decompress(&x, Sign::Positive).is_some() {
+                    x = x + FieldElement::one();
+                }
+                [&[0x02][..], &x.to_bytes()[..]].concat()
+            }
+
+            // each malformed encoding with its error
+            fn malformed() -> Vec<(Vec<u8>, Sec1Error)> {
+                let p = points().remove(1);
+                let mut bad_y = uncompressed(&p);
+                *bad_y.last_mut().unwrap() ^= 1;
+                let mut bad_prefix = compressed(&p);
+                bad_prefix[0] = 0x05;
+                let mut non_canonical = uncompressed(&p);
+                non_canonical[1..1 + FieldElement::SIZE_BYTES]
+                    .iter_mut()
+                    .for_each(|b| *b = 0xff);
+                vec![
+                    (vec![], Sec1Error::InvalidLength),
+                    (vec![0x00], Sec1Error::Infinity),
+                    (bad_prefix, Sec1Error::InvalidPrefix),
+                    (vec![0x00, 0x00], Sec1Error::InvalidLength),
+                    (
+                        compressed(&p)[..FieldElement::SIZE_BYTES].to_vec(),
+                        Sec1Error::InvalidLength,
+                    ),
+                    (
+                        [&uncompressed(&p)[..], &[0][..]].concat(),
+                        Sec1Error::InvalidLength,
+                    ),
+                    (non_canonical, Sec1Error::NotCanonical),
+                    (bad_y, Sec1Error::NotOnCurve),
+                    (not_on_curve_x(), Sec1Error::NotOnCurve),
+                ]
+            }
+
+            #[test]
+            fn valid() {
+                let points = points();
+                let encodings: Vec<Vec<u8>> = points
+                    .iter()
+                    .flat_map(|p| [compressed(p), uncompressed(p)])
+                    .collect();
+                let refs: Vec<&[u8]> = encodings.iter().map(|e| &e[..]).collect();
+                let results = PointAffine::batch_validate_sec1(&refs, ValidationFlags::default());
+                assert_eq!(results.len(), encodings.len());
+                for (i, r) in results.into_iter().enumerate() {
+                    assert_eq!(r, Ok(points[i / 2].clone()));
+                }
+                let results =
+                    PointAffine::batch_validate_sec1(&refs, ValidationFlags::default().fail_fast());
+                assert_eq!(results.len(), encodings.len());
+            }
+
+            #[test]
+            fn error_kinds() {
+                for (bytes, e) in malformed() {
+                    let r = PointAffine::batch_validate_sec1(&[&bytes], ValidationFlags::default());
+                    assert_eq!(r, vec![Err(e)], "{:?}", bytes);
+                    assert_eq!(PointAffine::from_sec1_bytes(&bytes), None);
+                }
+            }
+
+            #[test]
+            fn disallowed() {
+                let p = points().remove(0);
+                let (c, u) = (compressed(&p), uncompressed(&p));
+                let only_uncompressed = ValidationFlags {
+                    allow_compressed: false,
+                    ..ValidationFlags::default()
+                };
+                assert_eq!(
+                    PointAffine::batch_validate_sec1(&[&c, &u], only_uncompressed),
+                    vec![Err(Sec1Error::Disallowed), Ok(p.clone())]
+                );
+                let only_compressed = ValidationFlags {
+                    allow_uncompressed: false,
+                    ..ValidationFlags::default()
+                };
+                assert_eq!(
+                    PointAffine::batch_validate_sec1(&[&c, &u], only_compressed),
+                    vec![Ok(p.clone()), Err(Sec1Error::Disallowed)]
+                );
+                // the length is checked before the policy
+                assert_eq!(
+                    PointAffine::batch_validate_sec1(&[&c[..2]], only_uncompressed),
+                    vec![Err(Sec1Error::InvalidLength)]
+                );
+            }
+
+            #[test]
+            fn one_malformed_among_many() {
+                let points = points();
+                let mut encodings: Vec<Vec<u8>> = points.iter().map(compressed).collect();
+                encodings.insert(2, vec![0x00]);
+                let refs: Vec<&[u8]> = encodings.iter().map(|e| &e[..]).collect();
+
+                let results = PointAffine::batch_validate_sec1(&refs, ValidationFlags::default());
+                assert_eq!(results.len(), points.len() + 1);
+                assert_eq!(results[2], Err(Sec1Error::Infinity));
+                let valid: Vec<PointAffine> = results.into_iter().filter_map(Result::ok).collect();
+                assert_eq!(valid, points);
+
+                // the validation stops at the malformed encoding
+                let results =
+                    PointAffine::batch_validate_sec1(&refs, ValidationFlags::default().fail_fast());
+                assert_eq!(
+                    results,
+                    vec![
+                        Ok(points[0].clone()),
+                        Ok(points[1].clone()),
+                        Err(Sec1Error::Infinity)
+                    ]
+                );
+            }
+
+            #[test]
+            fn all_malformed() {
+                let (encodings, errors): (Vec<Vec<u8>>, Vec<Sec1Error>) =
+                    malformed().into_iter().unzip();
+                let refs: Vec<&[u8]> = encodings.iter().map(|e| &e[..]).collect();
+                let results = PointAffine::batch_validate_sec1(&refs, ValidationFlags::default());
+                let expected: Vec<_> = errors.iter().map(|e| Err(*e)).collect();
+                assert_eq!(results, expected);
+                assert_eq!(
+                    PointAffine::batch_validate_sec1(&refs, ValidationFlags::default().fail_fast()),
+                    vec![Err(errors[0])]
+                );
+            }
+
+            #[test]
+            fn round_trip() {
+                let forms = [
+                    Sec1Form::Compressed,
+                    Sec1Form::Uncompressed,
+                    Sec1Form::Hybrid,
+                ];
+                let flags = ValidationFlags::default().with_hybrid();
+                for p in points() {
+                    for form in forms {
+                        let bytes = p.encode_sec1(form);
+                        assert_eq!(PointAffine::decode_sec1(&bytes, flags), Ok(p.clone()));
+                        let point = Point::from_affine(&p);
+                        assert_eq!(point.encode_sec1(form), bytes);
+                        assert_eq!(Point::decode_sec1(&bytes, flags), Ok(point));
+                    }
+                    assert_eq!(
+                        &p.encode_sec1(Sec1Form::Compressed)[..],
+                        &compressed(&p)[..]
+                    );
+                    assert_eq!(
+                        &p.encode_sec1(Sec1Form::Uncompressed)[..],
+                        &uncompressed(&p)[..]
+                    );
+                    let hybrid = p.encode_hybrid();
+                    assert_eq!(hybrid[0] - 0x04, compressed(&p)[0]);
+                    assert_eq!(&hybrid[1..], &uncompressed(&p)[1..]);
+                }
+                for form in forms {
+                    let bytes = Point::infinity().encode_sec1(form);
+                    assert_eq!(bytes, vec![0x00]);
+                    assert_eq!(Point::decode_sec1(&bytes, flags), Ok(Point::infinity()));
+                    assert_eq!(
+                        PointAffine::decode_sec1(&bytes, flags),
+                        Err(Sec1Error::Infinity)
+                    );
+                }
+            }
+
+            #[test]
+            fn hybrid() {
+                let flags = ValidationFlags::default().with_hybrid();
+                for p in points() {
+                    let hybrid = p.encode_hybrid();
+                    // not accepted by default
+                    assert_eq!(
+                        PointAffine::decode_sec1(&hybrid, ValidationFlags::default()),
+                        Err(Sec1Error::Disallowed)
+                    );
+                    assert_eq!(PointAffine::from_sec1_bytes(&hybrid), None);
+
+                    let mut flipped = hybrid;
+                    flipped[0] ^= 1;
+                    assert_eq!(
+                        PointAffine::decode_sec1(&flipped, flags),
+                        Err(Sec1Error::ParityMismatch)
+                    );
+                    assert_eq!(
+                        Point::decode_sec1(&flipped, flags),
+                        Err(Sec1Error::ParityMismatch)
+                    );
+                    // not on the curve takes precedence over the parity
+                    let mut bad_y = hybrid;
+                    *bad_y.last_mut().unwrap() ^= 1;
+                    let mut bad_y_flipped = bad_y;
+                    bad_y_flipped[0] ^= 1;
+                    for bytes in [bad_y, bad_y_flipped] {
+                        assert_eq!(
+                            PointAffine::decode_sec1(&bytes, flags),
+                            Err(Sec1Error::NotOnCurve)
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn empty_batch() {
+                for flags in [
+                    ValidationFlags::default(),
+                    ValidationFlags::default().fail_fast(),
+                ] {
+                    assert!(PointAffine::batch_validate_sec1(&[], flags).is_empty());
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_sec1!(p192k1);
+#[cfg(feature = "p192r1")]
+test_sec1!(p192r1);
+#[cfg(feature = "p224k1")]
+test_sec1!(p224k1);
+#[cfg(feature = "p224r1")]
+test_sec1!(p224r1);
+#[cfg(feature = "p256k1")]
+test_sec1!(p256k1);
+#[cfg(feature = "p256r1")]
+test_sec1!(p256r1);
+#[cfg(feature = "p384r1")]
+test_sec1!(p384r1);
+#[cfg(feature = "p521r1")]
+test_sec1!(p521r1);
+
+// hybrid encodings of OpenSSL (`openssl ec -pubout -conv_form hybrid`), with both parities
+macro_rules! test_openssl_hybrid {
+    ($curve: ident, $vectors: expr) => {
+        #[test]
+        fn $curve() {
+            use crate::curve::sec1::{Sec1Error, Sec1Form, ValidationFlags};
+            use crate::curve::sec2::$curve::PointAffine;
+            use crate::tests::hex::from_hex;
+
+            let flags = ValidationFlags::default().with_hybrid();
+            for hex in $vectors.iter() {
+                let bytes = from_hex(hex);
+                let p = PointAffine::decode_sec1(&bytes, flags).unwrap();
+                assert_eq!(&p.encode_hybrid()[..], &bytes[..]);
+
+                let mut uncompressed = bytes.clone();
+                uncompressed[0] = 0x04;
+                assert_eq!(PointAffine::from_sec1_bytes(&uncompressed), Some(p.clone()));
+                assert_eq!(p.encode_sec1(Sec1Form::Uncompressed), uncompressed);
+
+                let mut flipped = bytes;
+                flipped[0] ^= 1;
+                assert_eq!(
+                    PointAffine::decode_sec1(&flipped, flags),
+                    Err(Sec1Error::ParityMismatch)
+                );
+            }
+        }
+    };
+}
+
+mod openssl_hybrid {
+    #[cfg(feature = "p256r1")]
+    test_openssl_hybrid!(
+        p256r1,
+        [
+            "06f4bfe25e53756074d38af3e5778ffecfe429ae0c8bc45c3803fc2d6d633dba6b91abc6c5e0dae4064354f3784645ff91e3ad3fb554448d2f55e1d36e84ba1d4a",
+            "07a741eab5289326bb7cfab763aed3c38d111d351557392a126cec9f91774c48fa7da27c70ac9db520000b0bd3778cf915df13bb7e0f6dd8e431d0bace273f48fb",
+        ]
+    );
+    #[cfg(feature = "p384r1")]
+    test_openssl_hybrid!(
+        p384r1,
+        [
+            "065db2a308e829591c042845e5b2a5666aa138f72489307d7ead84af42ea234671575f9d05b24d027d245fe3f0266c8ba93eda9f7065409731ff9d8907c519bbb5826ef3d4d4ef9cd1eab47b9e4fbd923df0d5e33f56fabd8d113128585d190994",
+            "07ba449abf9eeeedc5dc8bd9f6e3fa9d60e13e32ca4e946fc8df61aaa047db06b27a67955605e639e1f604b796c7efcd63e8b4dce6829a19ca4f0abdb54c26d5c0dc8be088199031b03f5a54cfb12670f46b7d326930ff9e835285df9a91b79b15",
+        ]
+    );
+}
@@ -0,0 +1,102 @@
+macro_rules! test_signed {
+    ($curve: ident) => {
+        mod $curve {
+            use crate::curve::field::Sign;
+            use crate::curve::sec2::$curve::{Point, Scalar, SignedScalar};
+
+            fn scalars() -> Vec<Scalar> {
+                [1u64, 2, 7, 0x1234, 0xdeadbeef]
+                    .iter()
+                    .map(|n| Scalar::from_u64(*n))
+                    .chain(Some(-Scalar::one()))
+                    .collect()
+            }
+
+            #[test]
+            fn conversions() {
+                for s in scalars() {
+                    let pos = SignedScalar::positive(s.clone());
+                    let neg = SignedScalar::negative(s.clone());
+                    assert_eq!(pos.to_scalar(), s);
+                    assert_eq!(neg.to_scalar(), -&s);
+                    assert_eq!(Scalar::from(neg.clone()), -&s);
+                    assert_eq!(SignedScalar::from(s.clone()), pos);
+                    assert_eq!(SignedScalar::from(-&s), neg);
+                    assert_eq!(-&neg, pos);
+                    assert_eq!(-(-neg.clone()), neg);
+                    assert_ne!(pos, neg);
+                    assert_eq!(neg.sign, Sign::Negative);
+                    assert_eq!(neg.magnitude, s);
+                }
+            }
+
+            #[test]
+            fn arithmetic() {
+                let two = Scalar::from_u64(2);
+                for s in scalars() {
+                    let neg = SignedScalar::negative(s.clone());
+                    let pos = SignedScalar::positive(s.clone());
+                    assert_eq!((&neg * &two).to_scalar(), -(&s * &two));
+                    assert_eq!((&neg * &neg).to_scalar(), &s * &s);
+                    assert_eq!((&neg * &pos).to_scalar(), -(&s * &s));
+                    assert_eq!(&neg + &s, Scalar::zero());
+                    assert_eq!(&pos - &s, Scalar::zero());
+                    assert_eq!(&neg - &two, -(&s + &two));
+                    assert_eq!((&neg * &neg).sign, Sign::Positive);
+                    assert_eq!((&neg * &pos).sign, Sign::Negative);
+                    assert_eq!((&pos * &neg).sign, Sign::Negative);
+                    assert_eq!((&pos * &pos).sign, Sign::Positive);
+                    assert_eq!((-&pos).sign, Sign::Negative);
+                    assert_eq!((-&neg).sign, Sign::Positive);
+                }
+            }
+
+            #[test]
+            fn point_mul() {
+                let g = Point::generator();
+                for s in scalars() {
+                    let neg = SignedScalar::negative(s.clone());
+                    let pos = SignedScalar::positive(s.clone());
+                    let sg = &g * &s;
+                    assert_eq!(&g * &pos, sg);
+                    assert_eq!(&g * &neg, -&sg);
+                    assert_eq!(&neg * &g, -&sg);
+                    assert_eq!(&g * &neg, &g * &neg.to_scalar());
+                }
+            }
+
+            #[test]
+            fn zero() {
+                let pos = SignedScalar::positive(Scalar::zero());
+                let neg = SignedScalar::negative(Scalar::zero());
+                assert!(pos.is_zero());
+                assert!(neg.is_zero());
+                assert_eq!(pos, neg);
+                assert_eq!(neg.to_scalar(), Scalar::zero());
+                assert_eq!(&Point::generator() * &neg, Point::infinity());
+                assert_eq!(
+                    &Point::infinity() * &SignedScalar::negative(Scalar::one()),
+                    Point::infinity()
+                );
+                assert!((&neg * &SignedScalar::negative(Scalar::one())).is_zero());
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_signed!(p192k1);
+#[cfg(feature = "p192r1")]
+test_signed!(p192r1);
+#[cfg(feature = "p224k1")]
+test_signed!(p224k1);
+#[cfg(feature = "p224r1")]
+test_signed!(p224r1);
+#[cfg(feature = "p256k1")]
+test_signed!(p256k1);
+#[cfg(feature = "p256r1")]
+test_signed!(p256r1);
+#[cfg(feature = "p384r1")]
+test_signed!(p384r1);
+#[cfg(feature = "p521r1")]
+test_signed!(p521r1);
@@ -0,0 +1,113 @@
+//! Known answers of the SM2 curve
+//!
+//! The keypair is the example of GB/T 32918.5 (annex A), and the multiples of the
+//! generator were computed independently of this crate from the standard parameters.
+
+use crate::curve::sm2::{Curve, FieldElement, Point, PointAffine, PublicKey, Scalar, SecretKey};
+use crate::curve::weierstrass::b3_consistent;
+use crate::curve::MulAlgorithm;
+use crate::registry::{AnyPublicKey, AnySecretKey, CurveId};
+use crate::tests::hex::from_hex;
+
+fn fe(s: &str) -> FieldElement {
+    FieldElement::from_slice(&from_hex(s)).unwrap()
+}
+
+fn scalar(s: &str) -> Scalar {
+    Scalar::from_slice(&from_hex(s)).unwrap()
+}
+
+fn point(x: &str, y: &str) -> Point {
+    Point::from_affine(&PointAffine::from_coordinate(&fe(x), &fe(y)).unwrap())
+}
+
+const SECRET: &str = "3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8";
+const PUBLIC_X: &str = "09f9df311e5421a150dd7d161e4bc5c672179fad1833fc076bb08ff356f35020";
+const PUBLIC_Y: &str = "ccea490ce26775a52dc6ea718cc1aa600aed05fbf35e084a6632f6072da9ad13";
+
+// (k, x, y) with (x, y) = k.G
+const MULTIPLES: &[(&str, &str, &str)] = &[
+    (
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "56cefd60d7c87c000d58ef57fa73ba4d9c0dfa08c08a7331495c2e1da3f2bd52",
+        "31b7e7e6cc8189f668535ce0f8eaf1bd6de84c182f6c8e716f780d3a970a23c3",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "a97f7cd4b3c993b4be2daa8cdb41e24ca13f6bd945302244e26918f1d0509ebf",
+        "530b5dd88c688ef5ccc5cec08a72150f7c400ee5cd045292aaacdd037458f6e6",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000005eed",
+        "9f0de05ae505e773c714454e1d9db42834536e029aa4062b25822027f9ec20ed",
+        "3a48eed7f157a080f5ae8797a642fb7ca75d7c957b44455cdfeaed4e2e050911",
+    ),
+    (
+        "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54122",
+        "32c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7",
+        "43c8c95c0b098863a642311c9496deac2f56788239d5b8c0fd20cd1adec60f5f",
+    ),
+];
+
+#[test]
+fn parameters() {
+    assert!(Curve.verify_parameters());
+    assert!(b3_consistent::<Curve>(Curve));
+}
+
+#[test]
+fn generator_multiples() {
+    for (k, x, y) in MULTIPLES.iter() {
+        let k = scalar(k);
+        let expected = point(x, y);
+        for alg in MulAlgorithm::all() {
+            assert_eq!(Point::generator().scale_with(&k, alg), expected);
+        }
+        assert_eq!(&Point::generator() * &k, expected);
+        assert_eq!(Point::generator().mul_vartime(&k), expected);
+    }
+    assert_eq!(
+        &Point::generator() + &Point::generator(),
+        point(MULTIPLES[0].1, MULTIPLES[0].2)
+    );
+}
+
+#[test]
+fn keypair() {
+    let sk = SecretKey::from_slice(&from_hex(SECRET)).unwrap();
+    let pk = sk.public_key();
+    let expected = PointAffine::from_coordinate(&fe(PUBLIC_X), &fe(PUBLIC_Y)).unwrap();
+    assert_eq!(pk, PublicKey::from_point(expected));
+
+    let mut uncompressed = vec![4];
+    uncompressed.extend_from_slice(&from_hex(PUBLIC_X));
+    uncompressed.extend_from_slice(&from_hex(PUBLIC_Y));
+    assert_eq!(pk.to_sec1_bytes(false), uncompressed);
+    // y is odd
+    let mut compressed = vec![3];
+    compressed.extend_from_slice(&from_hex(PUBLIC_X));
+    assert_eq!(pk.to_sec1_bytes(true), compressed);
+    assert_eq!(PublicKey::from_sec1_bytes(&compressed), Some(pk));
+}
+
+#[test]
+fn registry() {
+    let id = CurveId::from_iana(41).unwrap();
+    assert_eq!(id, CurveId::Sm2p256v1);
+    assert_eq!(CurveId::from_oid("1.2.156.10197.1.301"), Ok(id));
+    assert_eq!(CurveId::from_name("sm2p256v1"), Ok(id));
+    assert!(id.is_enabled());
+
+    let sk = AnySecretKey::from_bytes(id, &from_hex(SECRET)).unwrap();
+    let mut uncompressed = vec![4];
+    uncompressed.extend_from_slice(&from_hex(PUBLIC_X));
+    uncompressed.extend_from_slice(&from_hex(PUBLIC_Y));
+    assert_eq!(
+        sk.public_key(),
+        AnyPublicKey::from_sec1_bytes(id, &uncompressed).unwrap()
+    );
+
+    let other = AnySecretKey::from_bytes(id, &from_hex(MULTIPLES[2].0)).unwrap();
+    let shared = sk.diffie_hellman(&other.public_key()).unwrap();
+    assert_eq!(other.diffie_hellman(&sk.public_key()), Ok(shared));
+}
@@ -0,0 +1,107 @@
+//! Valid conversions of the typed X coordinate and tagged scalar bytes, the invalid
+//! ones being compile errors (see the `tagged` module documentation)
+
+macro_rules! test_typed_bytes {
+    ($curve:ident, $module:path) => {
+        mod $curve {
+            use curve::{Point, PointAffine, Scalar, TaggedScalarBytes};
+            use $module as curve;
+
+            fn scalars() -> Vec<Scalar> {
+                let mut v = vec![Scalar::zero(), Scalar::one(), -Scalar::one()];
+                let mut s = Scalar::from_u64(0x5eed);
+                for i in 0..4 {
+                    s = s.square() + Scalar::from_u64(i);
+                    v.push(s.clone());
+                }
+                v
+            }
+
+            #[test]
+            fn scalar_bytes() {
+                for s in scalars() {
+                    let tagged = s.to_tagged_bytes();
+                    assert_eq!(tagged.as_bytes(), &s.to_bytes());
+                    assert_eq!(Scalar::from_tagged_bytes(&tagged), Some(s.clone()));
+                    assert_eq!(TaggedScalarBytes::from_untagged(s.to_bytes()), tagged);
+                    assert_eq!(tagged.into_untagged(), s.to_bytes());
+                }
+            }
+
+            #[test]
+            fn tagged_bytes_debug_redacted() {
+                let tagged = Scalar::from_u64(0xdeadbeef).to_tagged_bytes();
+                let debug = format!("{:?}", tagged);
+                assert!(debug.starts_with("TaggedBytes<"));
+                assert!(debug.ends_with(">(..)"));
+                assert!(!debug.contains("239") && !debug.contains('['));
+                assert_ne!(tagged, Scalar::from_u64(0xdeadbeee).to_tagged_bytes());
+            }
+
+            #[test]
+            fn x_coordinate_bytes() {
+                let g = Point::generator();
+                for s in scalars().iter().skip(1) {
+                    let p = (&g * s).to_affine().unwrap();
+                    let x = p.x_bytes();
+                    assert_eq!(x.as_bytes(), &p.to_coordinate().0.to_bytes());
+                    assert_eq!(x.into_inner(), p.to_coordinate().0.to_bytes());
+                    assert_eq!(x, (-(&g * s)).to_affine().unwrap().x_bytes());
+                    let untyped: [u8; curve::FieldElement::SIZE_BYTES] = x.into();
+                    assert_eq!(untyped, x.into_inner());
+                }
+            }
+
+            #[test]
+            fn x_coordinate_bytes_debug_redacted() {
+                let x = PointAffine::generator().x_bytes();
+                let debug = format!("{:?}", x);
+                assert!(debug.starts_with("XCoordinateBytes<"));
+                assert!(debug.ends_with(">(..)"));
+                assert!(!debug.contains('['));
+            }
+
+            // both sides get the same typed shared secret
+            #[test]
+            fn ecdh() {
+                let (a, b) = (Scalar::from_u64(0x1234), Scalar::from_u64(0x5678));
+                let g = Point::generator();
+                let pa = (&g * &a).to_affine().unwrap();
+                let pb = (&g * &b).to_affine().unwrap();
+                let shared = |s: &Scalar, p: &PointAffine| {
+                    (&Point::from(p) * s).to_affine().unwrap().x_bytes()
+                };
+                assert_eq!(shared(&a, &pb), shared(&b, &pa));
+            }
+
+            #[cfg(feature = "kdf")]
+            #[test]
+            fn kdf_from_x() {
+                let x = PointAffine::generator().x_bytes();
+                assert_eq!(
+                    Scalar::derive_hkdf_sha256_from_x(&x, b"salt", b"info"),
+                    Scalar::derive_hkdf_sha256(x.as_bytes(), b"salt", b"info")
+                );
+            }
+        }
+    };
+}
+
+#[cfg(feature = "p192k1")]
+test_typed_bytes!(p192k1, crate::curve::sec2::p192k1);
+#[cfg(feature = "p192r1")]
+test_typed_bytes!(p192r1, crate::curve::sec2::p192r1);
+#[cfg(feature = "p224k1")]
+test_typed_bytes!(p224k1, crate::curve::sec2::p224k1);
+#[cfg(feature = "p224r1")]
+test_typed_bytes!(p224r1, crate::curve::sec2::p224r1);
+#[cfg(feature = "p256k1")]
+test_typed_bytes!(p256k1, crate::curve::sec2::p256k1);
+#[cfg(feature = "p256r1")]
+test_typed_bytes!(p256r1, crate::curve::sec2::p256r1);
+#[cfg(feature = "p384r1")]
+test_typed_bytes!(p384r1, crate::curve::sec2::p384r1);
+#[cfg(feature = "p521r1")]
+test_typed_bytes!(p521r1, crate::curve::sec2::p521r1);
+#[cfg(feature = "sm2")]
+test_typed_bytes!(sm2, crate::curve::sm2);
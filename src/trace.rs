@@ -0,0 +1,211 @@
+//! Debugging hooks for protocol integrations
+//!
+//! When the `trace` feature is enabled, a hook can be registered with
+//! [`set_trace_hook`] to receive [`TraceEvent`] at key operations:
+//!
+//! * point decompression outcomes
+//! * from_bytes rejections with the reason
+//! * scalar multiplication inputs and outputs (only with `trace-secrets`)
+//!
+//! When the feature is disabled, the tracing call sites expand to nothing
+//! and the event values are never built.
+//!
+//! # :warning: Secret leakage
+//!
+//! The `trace-secrets` feature makes the scalar multiplication events
+//! carry the scalar and the resulting point in clear. Scalars are
+//! usually secret keys or nonces, and the result of a multiplication
+//! can be a shared secret (e.g. ECDH). This feature must never be enabled
+//! outside of a debugging session.
+
+use crate::curve::Sign;
+use std::fmt;
+use std::sync::RwLock;
+
+/// Event emitted at a key operation
+#[derive(Debug, Clone)]
+pub enum TraceEvent<'a> {
+    /// Outcome of a point decompression with the X coordinate bytes and sign of Y
+    Decompress {
+        module: &'static str,
+        x: &'a [u8],
+        sign: Sign,
+        success: bool,
+    },
+    /// Rejection of a bytes representation when initializing an element
+    FromBytesRejected {
+        module: &'static str,
+        type_name: &'static str,
+        reason: &'static str,
+    },
+    /// Scalar multiplication input scalar and output affine coordinates, or None for infinity
+    #[cfg(feature = "trace-secrets")]
+    ScalarMul {
+        module: &'static str,
+        scalar: &'a [u8],
+        result: Option<(&'a [u8], &'a [u8])>,
+    },
+}
+
+type Hook = Box<dyn Fn(&TraceEvent) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Register the hook called on every event, replacing any previous hook
+pub fn set_trace_hook<F>(hook: F)
+where
+    F: Fn(&TraceEvent) + Send + Sync + 'static,
+{
+    *HOOK.write().unwrap() = Some(Box::new(hook))
+}
+
+/// Remove the registered hook
+pub fn clear_trace_hook() {
+    *HOOK.write().unwrap() = None
+}
+
+#[doc(hidden)]
+pub fn emit(event: &TraceEvent) {
+    if let Some(hook) = HOOK.read().unwrap().as_ref() {
+        hook(event)
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for Hex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in self.0.iter() {
+            write!(f, "{:02x}", b)?
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for TraceEvent<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceEvent::Decompress {
+                module,
+                x,
+                sign,
+                success,
+            } => write!(
+                f,
+                "{}: decompress x={} sign={:?} success={}",
+                module,
+                Hex(x),
+                sign,
+                success
+            ),
+            TraceEvent::FromBytesRejected {
+                module,
+                type_name,
+                reason,
+            } => write!(f, "{}: {} rejected: {}", module, type_name, reason),
+            #[cfg(feature = "trace-secrets")]
+            TraceEvent::ScalarMul {
+                module,
+                scalar,
+                result,
+            } => match result {
+                None => write!(
+                    f,
+                    "{}: scalar-mul k={} result=infinity",
+                    module,
+                    Hex(scalar)
+                ),
+                Some((x, y)) => write!(
+                    f,
+                    "{}: scalar-mul k={} result=({}, {})",
+                    module,
+                    Hex(scalar),
+                    Hex(x),
+                    Hex(y)
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static EVENTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // events are recorded per thread, so that concurrent tests don't see each other events
+    fn record() -> Vec<String> {
+        set_trace_hook(|e| EVENTS.with(|events| events.borrow_mut().push(e.to_string())));
+        EVENTS.with(|events| events.borrow_mut().drain(..).collect())
+    }
+
+    #[cfg(feature = "p256r1")]
+    #[test]
+    fn decompress_failure() {
+        use crate::curve::sec2::p256r1::{FieldElement, PointAffine};
+
+        record();
+        let mut i = 0;
+        let x = loop {
+            let x = FieldElement::from_u64(i);
+            if PointAffine::decompress(&x, Sign::Positive).is_none() {
+                break x;
+            }
+            i += 1;
+        };
+        let events = record();
+        let expected = format!(
+            "eccoxide::curve::sec2::p256r1: decompress x={} sign=Positive success=false",
+            x
+        );
+        assert_eq!(events.last(), Some(&expected));
+    }
+
+    #[cfg(feature = "p256r1")]
+    #[test]
+    fn from_bytes_rejected() {
+        use crate::curve::sec2::p256r1::{FieldElement, Scalar};
+
+        record();
+        assert!(FieldElement::from_bytes(&[0xff; 32]).is_none());
+        assert!(Scalar::from_slice(&[0x00; 31]).is_none());
+        let events = record();
+        assert_eq!(
+            events,
+            vec![
+                "eccoxide::curve::sec2::p256r1: FieldElement rejected: value not less than the modulus"
+                    .to_string(),
+                "eccoxide::curve::sec2::p256r1: Scalar rejected: invalid length".to_string(),
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "trace-secrets", feature = "p256r1"))]
+    #[test]
+    fn scalar_mul() {
+        use crate::curve::sec2::p256r1::{Point, Scalar};
+
+        record();
+        let p = &Point::generator() * &Scalar::from_u64(2);
+        let events = record();
+        let affine = p.to_affine().unwrap();
+        let (x, y) = affine.to_coordinate();
+        let expected = format!(
+            "eccoxide::curve::sec2::p256r1: scalar-mul k={} result=({}, {})",
+            Scalar::from_u64(2),
+            x,
+            y
+        );
+        assert_eq!(events, vec![expected]);
+
+        record();
+        let _ = &Point::generator() * &Scalar::zero();
+        let events = record();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].ends_with("result=infinity"));
+    }
+}
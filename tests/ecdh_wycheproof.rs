@@ -0,0 +1,142 @@
+//! Wycheproof ECDH test vectors, with the public point in SEC1 encoding, with only
+//! the public API
+//!
+//! The `ecdh_<curve>_ecpoint_test.json` files of the Wycheproof project
+//! (<https://github.com/C2SP/wycheproof>, `testvectors_v1` directory) are vendored
+//! unmodified in `testvectors/wycheproof`. The public point of each case goes through
+//! `PointAffine::from_sec1_bytes` (encoding, canonical coordinates and on curve
+//! validation), then the shared secret through the scalar multiplication: valid cases
+//! need to produce the expected shared secret, invalid cases to be rejected, and
+//! acceptable cases (e.g. compressed points) either.
+//!
+//! The cases the API can't express are listed in `SKIPPED`, with the reason. A curve
+//! whose file is not vendored is reported on stderr and not run.
+//!
+//! Run with `cargo test --test ecdh_wycheproof`
+
+use serde_json::Value;
+use std::path::PathBuf;
+
+// (file, tcId, reason) of the cases not run
+const SKIPPED: &[(&str, u64, &str)] = &[];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    Valid,
+    Acceptable,
+    Invalid,
+}
+
+// bytes of a string of hexadecimal digit pairs, panicking on invalid digits
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// ASN.1 integers are minimally encoded, with a leading zero byte when the top bit is set
+fn private_bytes(s: &str, size: usize) -> Vec<u8> {
+    let bytes = from_hex(s);
+    let skip = bytes.iter().take_while(|b| **b == 0).count();
+    let bytes = &bytes[skip..];
+    assert!(bytes.len() <= size, "private key too long");
+    let mut out = vec![0u8; size - bytes.len()];
+    out.extend_from_slice(bytes);
+    out
+}
+
+// the X coordinate of the shared point, or None if the public point is rejected
+type Ecdh = fn(public: &[u8], private: &str) -> Option<Vec<u8>>;
+
+macro_rules! ecdh {
+    ($curve:ident) => {
+        |public: &[u8], private: &str| -> Option<Vec<u8>> {
+            use eccoxide::curve::sec2::$curve::{Point, PointAffine, Scalar};
+
+            let d = Scalar::from_slice(&private_bytes(private, Scalar::SIZE_BYTES))
+                .expect("private key in range");
+            PointAffine::from_sec1_bytes(public)
+                .and_then(|q| (&Point::from(&q) * &d).to_affine())
+                .map(|p| p.to_coordinate().0.to_bytes().to_vec())
+        }
+    };
+}
+
+fn run(file: &str, ecdh: Ecdh) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testvectors")
+        .join("wycheproof")
+        .join(file);
+    let data = match std::fs::read_to_string(&path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{}: {}, not run", path.display(), e);
+            return;
+        }
+    };
+    let json: Value = serde_json::from_str(&data).expect("wycheproof json");
+    let (mut valid, mut invalid) = (0, 0);
+    for group in json["testGroups"].as_array().expect("testGroups") {
+        assert_eq!(group["encoding"], "ecpoint", "{}", file);
+        for test in group["tests"].as_array().expect("tests") {
+            let tc_id = test["tcId"].as_u64().expect("tcId");
+            if SKIPPED.iter().any(|(f, id, _)| *f == file && *id == tc_id) {
+                continue;
+            }
+            let field = |name: &str| test[name].as_str().expect(name);
+            let expected = match field("result") {
+                "valid" => Expect::Valid,
+                "acceptable" => Expect::Acceptable,
+                "invalid" => Expect::Invalid,
+                r => panic!("{}: tc {}: unknown result {}", file, tc_id, r),
+            };
+            let shared = ecdh(&from_hex(field("public")), field("private"));
+            let comment = field("comment");
+            match (expected, shared) {
+                (Expect::Invalid, None) => invalid += 1,
+                (Expect::Acceptable, None) => {}
+                (Expect::Valid, Some(shared)) | (Expect::Acceptable, Some(shared)) => {
+                    assert_eq!(
+                        shared,
+                        from_hex(field("shared")),
+                        "{}: tc {}: {}",
+                        file,
+                        tc_id,
+                        comment
+                    );
+                    if expected == Expect::Valid {
+                        valid += 1
+                    }
+                }
+                (expected, got) => panic!(
+                    "{}: tc {}: {}: expected {:?} got {:?}",
+                    file, tc_id, comment, expected, got
+                ),
+            }
+        }
+    }
+    // a file with no valid or no invalid case run is not the expected one
+    assert!(valid > 0, "{}: no valid case", file);
+    assert!(invalid > 0, "{}: no invalid case", file);
+}
+
+#[test]
+fn p256k1() {
+    run("ecdh_secp256k1_ecpoint_test.json", ecdh!(p256k1))
+}
+
+#[test]
+fn p256r1() {
+    run("ecdh_secp256r1_ecpoint_test.json", ecdh!(p256r1))
+}
+
+#[test]
+fn p384r1() {
+    run("ecdh_secp384r1_ecpoint_test.json", ecdh!(p384r1))
+}
+
+#[test]
+fn p521r1() {
+    run("ecdh_secp521r1_ecpoint_test.json", ecdh!(p521r1))
+}
@@ -0,0 +1,209 @@
+//! A TLS style handshake between two parties, with only the public API
+//!
+//! Each party has an ECDSA identity key on p256r1 and an ephemeral ECDH key on p256k1.
+//! The public keys are exchanged in their SEC1 encodings, validated, and the ECDH shared
+//! secrets are derived. Then each party signs the hash of the transcript (the nonces and
+//! all the public keys) with its identity key, and verifies the signature of the other.
+//!
+//! Run with `cargo test --features ecdsa --test handshake`
+
+use eccoxide::curve::sec2::p256k1::{self, ecdh::ecdh_xonly};
+use eccoxide::curve::sec2::p256r1::{self, ecdsa};
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+// xorshift64*, deterministic so that a failure can be replayed, NOT cryptographic
+struct TestRng(u64);
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let v = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+struct Party {
+    nonce: [u8; 32],
+    identity: p256r1::SecretKey,
+    ephemeral: p256k1::SecretKey,
+}
+
+// the first flight of a party: its nonce, identity and ephemeral public keys
+struct Hello {
+    nonce: [u8; 32],
+    identity: Vec<u8>,
+    ephemeral: Vec<u8>,
+}
+
+// the second flight: the signature of the transcript
+struct Finished {
+    signature: Vec<u8>,
+}
+
+// the state after receiving the hello of the peer
+struct Session {
+    shared: [u8; 32],
+    transcript: Vec<u8>,
+    peer_identity: p256r1::PublicKey,
+}
+
+impl Party {
+    fn new(rng: &mut TestRng) -> Self {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        Party {
+            nonce,
+            identity: p256r1::SecretKey::generate(rng),
+            ephemeral: p256k1::SecretKey::generate(rng),
+        }
+    }
+
+    fn hello(&self) -> Hello {
+        Hello {
+            nonce: self.nonce,
+            identity: self.identity.public_key().to_sec1_bytes(false),
+            ephemeral: self.ephemeral.public_key().to_sec1_bytes(true),
+        }
+    }
+
+    // validate the keys of the peer and derive the shared secret, None if a key is invalid
+    fn receive_hello(&self, peer: &Hello, initiator: bool) -> Option<Session> {
+        let peer_identity = p256r1::PublicKey::from_sec1_bytes(&peer.identity)?;
+        let peer_ephemeral = p256k1::PublicKey::from_sec1_bytes(&peer.ephemeral)?;
+        let shared = ecdh_xonly(self.ephemeral.as_scalar(), peer_ephemeral.as_point())?;
+
+        // the initiator's hello first, so that both transcripts are the same bytes
+        let mine = self.hello();
+        let (first, second) = if initiator {
+            (&mine, peer)
+        } else {
+            (peer, &mine)
+        };
+        let mut transcript = Vec::new();
+        for hello in [first, second].iter() {
+            transcript.extend_from_slice(&hello.nonce);
+            transcript.extend_from_slice(&hello.identity);
+            transcript.extend_from_slice(&hello.ephemeral);
+        }
+        Some(Session {
+            shared,
+            transcript,
+            peer_identity,
+        })
+    }
+
+    fn finished(&self, session: &Session, initiator: bool) -> Finished {
+        let h = transcript_hash(session, initiator);
+        let signature = ecdsa::sign(&self.identity, &h, ecdsa::Rfc6979::<Sha256>::new());
+        Finished {
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+}
+
+// the hash signed by a party, including its role so that a signature can't be reflected
+fn transcript_hash(session: &Session, initiator: bool) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(if initiator {
+        b"initiator"
+    } else {
+        b"responder"
+    });
+    hasher.update(&session.transcript);
+    hasher.finalize().to_vec()
+}
+
+fn verify_finished(session: &Session, finished: &Finished, peer_initiator: bool) -> bool {
+    let signature = match ecdsa::Signature::from_slice(&finished.signature) {
+        None => return false,
+        Some(s) => s,
+    };
+    let h = transcript_hash(session, peer_initiator);
+    ecdsa::verify_with(
+        &session.peer_identity,
+        &h,
+        &signature,
+        &ecdsa::VerifyOptions::default(),
+    )
+}
+
+#[test]
+fn handshake() {
+    let mut rng = TestRng(0x7e57_4a11_d5ea_5eed);
+    for _ in 0..4 {
+        let (alice, bob) = (Party::new(&mut rng), Party::new(&mut rng));
+        let (hello_a, hello_b) = (alice.hello(), bob.hello());
+
+        let session_a = alice
+            .receive_hello(&hello_b, true)
+            .expect("valid keys of bob");
+        let session_b = bob
+            .receive_hello(&hello_a, false)
+            .expect("valid keys of alice");
+        assert_eq!(session_a.shared, session_b.shared);
+        assert_eq!(session_a.transcript, session_b.transcript);
+        assert_eq!(session_a.peer_identity, bob.identity.public_key());
+        assert_eq!(session_b.peer_identity, alice.identity.public_key());
+
+        let (finished_a, finished_b) = (
+            alice.finished(&session_a, true),
+            bob.finished(&session_b, false),
+        );
+        assert!(verify_finished(&session_b, &finished_a, true));
+        assert!(verify_finished(&session_a, &finished_b, false));
+
+        // a reflected signature is for the other role
+        assert!(!verify_finished(&session_b, &finished_b, true));
+    }
+}
+
+#[test]
+fn tampered() {
+    let mut rng = TestRng(0xbad_c0ffee);
+    let (alice, bob) = (Party::new(&mut rng), Party::new(&mut rng));
+    let hello_a = alice.hello();
+
+    // keys not on the curve are rejected before any use
+    let mut bad = bob.hello();
+    bad.ephemeral[1..].copy_from_slice(&[0xff; 32]);
+    assert!(p256k1::PublicKey::from_sec1_bytes(&bad.ephemeral).is_none());
+    assert!(alice.receive_hello(&bad, true).is_none());
+    let mut bad = bob.hello();
+    let last = bad.identity.len() - 1;
+    bad.identity[last] ^= 1;
+    assert!(alice.receive_hello(&bad, true).is_none());
+
+    // a signature over another transcript, or altered, is rejected
+    let hello_b = bob.hello();
+    let session_a = alice.receive_hello(&hello_b, true).unwrap();
+    let session_b = bob.receive_hello(&hello_a, false).unwrap();
+    let mut finished_b = bob.finished(&session_b, false);
+    finished_b.signature[5] ^= 0x80;
+    assert!(!verify_finished(&session_a, &finished_b, false));
+
+    let carol = Party::new(&mut rng);
+    let session_c = carol.receive_hello(&hello_a, false).unwrap();
+    assert_ne!(session_c.shared, session_a.shared);
+    let finished_c = carol.finished(&session_c, false);
+    assert!(!verify_finished(&session_a, &finished_c, false));
+}
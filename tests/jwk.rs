@@ -0,0 +1,80 @@
+//! JSON Web Keys in their JSON form, with only the public API
+//!
+//! The conversions between the keys and the JSON Web Key structures are tested in the
+//! library, this is the serialization of the structures with serde_json.
+//!
+//! Run with `cargo test --features jwk --test jwk`
+
+use eccoxide::jwk::{PrivateKeyJwk, PublicKeyJwk};
+
+// generated with openssl (ecparam -genkey, then the priv and pub values of ec -text) as:
+// (curve, private key JSON Web Key)
+const P256R1: &str = r#"{"kty":"EC","crv":"P-256","x":"LpMWao4X6kLigtx83n7MRqxkkukaiLQi2k4_M1RTGtc","y":"B02IibsHEw4xp0LQfUYiGbTJO2JGNlk_hdzIToHS0ho","d":"dlDGExH4RkphBkQR9y5QcP6q8U1JN_Q7t8E3Af-Uf2E"}"#;
+const P384R1: &str = r#"{"kty":"EC","crv":"P-384","x":"VqNzphdoo5_CiYJyCCcAg1xgVVZ3xvVFuaiV65IP3Rpgcc86syBCm3ShV5s2qHNY","y":"okOfVLsre9bDUqxu7CRtmUVlKzszoJXPyby5cXOM95K621nK0oc3UhmCEZ_qwg7X","d":"5pycU_SB5Co6ZnlCCYUvhQvnxI6TYr0w8E-cEuEUpT07WAdn1PdBzzLQvs7dcDT3"}"#;
+const P521R1: &str = r#"{"kty":"EC","crv":"P-521","x":"AK4WtaNHQM1E2XiBwLuB91Jj1ZXgL1LBckz_7IQfcUBllHfU7Nj6iF-Rh3EXIEV-WR-1HHC20Nl__WEn2g9qQSRx","y":"AOuqNelTFEbtvqwy2KWKVRsw1GsKw8XyH-Hp4tDBfu7uaLdw5XCHN_XWAwA4Es-fOO7vwy__a8PyiiEacVADK1_0","d":"ANpOpJzDKCyC3uG1-YJbf5FuwOuHH6i_iUYbGQX2p3u9MftvPPzv3eXLIXgoL5b_UvpmXpRQbXVHMuVcb0eppBF5"}"#;
+const P256K1: &str = r#"{"kty":"EC","crv":"secp256k1","x":"ZlHzJMZFsvt1FfgpDxabIgBF2YEl9sByRRCpJIRX5XA","y":"rq3l_Thtqh74xsdAOfOd0O0Oh8jRmVJUDwNpVpHQq30","d":"sr0agLGAx6PpvDsBizibKSsmMMGHFuRfhZTZVeHtypM"}"#;
+
+fn private(json: &str) -> PrivateKeyJwk {
+    serde_json::from_str(json).unwrap()
+}
+
+fn public(json: &str) -> PublicKeyJwk {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn members() {
+    // other members are ignored, and d is required in a private key only
+    let jwk = private(P256R1);
+    let with_kid = P256R1.replace(r#""kty""#, r#""kid":"k1","use":"sig","kty""#);
+    assert_eq!(private(&with_kid), jwk);
+    assert_eq!(public(&with_kid).x, jwk.x.clone().unwrap());
+
+    let no_d = serde_json::to_string(&public(P256R1)).unwrap();
+    assert!(serde_json::from_str::<PrivateKeyJwk>(&no_d).is_err());
+    assert!(!no_d.contains(r#""d""#));
+
+    // no public coordinates, which are then not serialized
+    let d_only = PrivateKeyJwk {
+        x: None,
+        y: None,
+        ..jwk
+    };
+    let json = serde_json::to_string(&d_only).unwrap();
+    assert!(!json.contains(r#""x""#) && !json.contains(r#""y""#));
+    assert_eq!(private(&json), d_only);
+}
+
+macro_rules! test_jwk_json {
+    ($curve: ident, $fixture: expr) => {
+        mod $curve {
+            use super::{private, public};
+            use eccoxide::curve::sec2::$curve::{PublicKey, Scalar, SecretKey};
+
+            #[test]
+            fn fixture() {
+                let sk = SecretKey::from_jwk(&private($fixture)).unwrap();
+                assert_eq!(serde_json::to_string(&sk.to_jwk()).unwrap(), $fixture);
+                let pk = PublicKey::from_jwk(&public($fixture)).unwrap();
+                assert_eq!(pk, sk.public_key());
+            }
+
+            #[test]
+            fn round_trip() {
+                for n in [1u64, 2, 0xdeadbeef] {
+                    let sk = SecretKey::from_scalar(Scalar::from_u64(n)).unwrap();
+                    let json = serde_json::to_string(&sk.to_jwk()).unwrap();
+                    assert_eq!(SecretKey::from_jwk(&private(&json)).as_ref(), Ok(&sk));
+                    let pk = sk.public_key();
+                    let json = serde_json::to_string(&pk.to_jwk()).unwrap();
+                    assert_eq!(PublicKey::from_jwk(&public(&json)), Ok(pk));
+                }
+            }
+        }
+    };
+}
+
+test_jwk_json!(p256k1, super::P256K1);
+test_jwk_json!(p256r1, super::P256R1);
+test_jwk_json!(p384r1, super::P384R1);
+test_jwk_json!(p521r1, super::P521R1);
@@ -0,0 +1,34 @@
+#!/bin/sh
+# Check that the trace hooks compile to nothing without the trace feature
+#
+# The trace_hooks benchmark goes through all the hooked operations. Built without
+# the feature, its binary has no symbol of the trace module, while built with
+# `trace-secrets` it has (so the check can't pass because the symbols are named
+# differently). The timings of both builds are printed for comparison.
+#
+#     tools/check_trace_hooks.sh
+
+set -eu
+
+bench() {
+    cargo bench --no-run --bench trace_hooks "$@" --message-format=json \
+        | grep -o '"executable":"[^"]*"' | cut -d'"' -f4
+}
+
+without=$(bench)
+with=$(bench --features trace-secrets)
+
+if nm -C "$without" | grep -q 'eccoxide::trace::'; then
+    echo "trace symbols in the build without the trace feature:"
+    nm -C "$without" | grep 'eccoxide::trace::'
+    exit 1
+fi
+if ! nm -C "$with" | grep -q 'eccoxide::trace::emit'; then
+    echo "no trace::emit symbol in the build with the trace-secrets feature"
+    exit 1
+fi
+
+echo "without trace ($(wc -c < "$without") bytes):"
+"$without"
+echo "with trace-secrets ($(wc -c < "$with") bytes):"
+"$with"
@@ -0,0 +1,187 @@
+#!/usr/bin/env python3
+"""Produce src/tests/ecdh_cases_data.rs, the ECDH point decoding and shared secret cases
+
+Each case is a SEC1 encoded public point, an ASN.1 hex integer private key, the X
+coordinate shared secret and a valid/acceptable/invalid result, the expected result
+of each case being computed by OpenSSL through python cryptography.
+
+    ecdh_vectors.py generate > src/tests/ecdh_cases_data.rs
+"""
+
+import hashlib
+import json
+import sys
+
+CURVES = {
+    # name: (SEC2 name, p, a, b, n)
+    "p256r1": (
+        "secp256r1",
+        2**256 - 2**224 + 2**192 + 2**96 - 1,
+        -3,
+        0x5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B,
+        0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551,
+    ),
+    "p384r1": (
+        "secp384r1",
+        2**384 - 2**128 - 2**96 + 2**32 - 1,
+        -3,
+        0xB3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875AC656398D8A2ED19D2A85C8EDD3EC2AEF,
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973,
+    ),
+    "p521r1": (
+        "secp521r1",
+        2**521 - 1,
+        -3,
+        0x0051953EB9618E1C9A1F929A21A0B68540EEA2DA725B99B315F3B8B489918EF109E156193951EC7E937B1652C0BD3BB1BF073573DF883D2C34F1EF451FD46B503F00,
+        0x01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFA51868783BF2F966B7FCC0148F709A5D03BB5C9B8899C47AEBB6FB71E91386409,
+    ),
+    "p256k1": (
+        "secp256k1",
+        2**256 - 2**32 - 977,
+        0,
+        7,
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141,
+    ),
+}
+
+
+def emit(groups):
+    out = []
+    out.append("// generated by tools/ecdh_vectors.py, do not edit")
+    out.append("")
+    out.append("use super::ecdh_cases::{EcdhCase, Expect};")
+    for curve, source, cases in groups:
+        out.append("")
+        out.append("// %s" % source)
+        out.append("pub const %s: [EcdhCase; %d] = [" % (curve.upper(), len(cases)))
+        for c in cases:
+            out.append("    EcdhCase {")
+            out.append("        tc_id: %d," % c["tcId"])
+            out.append("        comment: %s," % json.dumps(c["comment"]))
+            out.append('        public: "%s",' % c["public"])
+            out.append('        private: "%s",' % c["private"])
+            out.append('        shared: "%s",' % c["shared"])
+            out.append("        result: Expect::%s," % c["result"].capitalize())
+            out.append("    },")
+        out.append("];")
+    print("\n".join(out))
+
+
+def generate():
+    from cryptography.hazmat.primitives.asymmetric import ec
+    from cryptography.hazmat.primitives import serialization
+
+    ossl = {
+        "p256r1": ec.SECP256R1(),
+        "p384r1": ec.SECP384R1(),
+        "p521r1": ec.SECP521R1(),
+        "p256k1": ec.SECP256K1(),
+    }
+
+    groups = []
+    for curve, (_, p, a, b, n) in CURVES.items():
+        size = (p.bit_length() + 7) // 8
+
+        def rhs(x):
+            return (x * x * x + a * x + b) % p
+
+        def is_square(v):
+            return v == 0 or pow(v, (p - 1) // 2, p) == 1
+
+        def sqrt(v):
+            # all the curves here have p = 3 mod 4
+            return pow(v, (p + 1) // 4, p)
+
+        def be(v, length=size):
+            return v.to_bytes(length, "big").hex()
+
+        def asn1(d):
+            h = "%x" % d
+            h = ("0" if len(h) % 2 else "") + h
+            return ("00" if int(h[:2], 16) >= 0x80 else "") + h
+
+        def det(label, i):
+            while True:
+                v = int.from_bytes(hashlib.sha512(("%s-%s-%d" % (curve, label, i)).encode()).digest(), "big") % n
+                if v != 0:
+                    return v
+                i += 1
+
+        def public_of(d):
+            key = ec.derive_private_key(d, ossl[curve]).public_key()
+            return key.public_bytes(serialization.Encoding.X962, serialization.PublicFormat.UncompressedPoint)
+
+        def compress(pub):
+            return bytes([2 + (pub[-1] & 1)]) + pub[1 : 1 + size]
+
+        cases = []
+
+        def add(comment, public, d, compressed=False):
+            try:
+                q = ec.EllipticCurvePublicKey.from_encoded_point(ossl[curve], public)
+                shared = ec.derive_private_key(d, ossl[curve]).exchange(ec.ECDH(), q).hex()
+                result = "acceptable" if compressed else "valid"
+            except ValueError:
+                shared, result = "", "invalid"
+            cases.append(
+                {
+                    "tcId": len(cases) + 1,
+                    "comment": comment,
+                    "public": public.hex(),
+                    "private": asn1(d),
+                    "shared": shared,
+                    "result": result,
+                }
+            )
+
+        for i in range(3):
+            add("normal case", public_of(det("peer", i)), det("private", i))
+        add("compressed public key", compress(public_of(det("peer", 3))), det("private", 3), True)
+
+        q = public_of(det("peer", 4))
+        for i in range(4096):
+            d = det("leading zero", i)
+            pub = ec.EllipticCurvePublicKey.from_encoded_point(ossl[curve], q)
+            if ec.derive_private_key(d, ossl[curve]).exchange(ec.ECDH(), pub)[0] == 0:
+                add("shared secret has a leading zero byte", q, d)
+                break
+        for d, comment in [(1, "private key 1"), (2, "private key 2"), (n - 2, "private key n-2"), (n - 1, "private key n-1")]:
+            add(comment, q, d)
+        add("private key with leading zero bytes", q, 0x1234)
+
+        d = det("private", 5)
+        x, y = int.from_bytes(q[1 : 1 + size], "big"), int.from_bytes(q[1 + size :], "big")
+        add("point is not on the curve", bytes([4]) + bytes.fromhex(be(x) + be((y + 1) % p)), d)
+        tx = next(t for t in range(1, 1000) if not is_square(rhs(t)))
+        add("x is the abscissa of a point on the quadratic twist", bytes([2]) + bytes.fromhex(be(tx)), d)
+        add(
+            "point on the quadratic twist with an uncompressed encoding",
+            bytes([4]) + bytes.fromhex(be(tx) + be(1)),
+            d,
+        )
+        sx = next(t for t in range(1, 1000) if is_square(rhs(t)) and rhs(t) != 0)
+        sy = sqrt(rhs(sx))
+        if sx + p < 2 ** (8 * size):
+            add("x coordinate not reduced modulo p", bytes([4]) + bytes.fromhex(be(sx + p) + be(sy)), d)
+            add("compressed x coordinate not reduced modulo p", bytes([2 + (sy & 1)]) + bytes.fromhex(be(sx + p)), d)
+        for yy in (sy, p - sy):
+            if yy + p < 2 ** (8 * size):
+                add("y coordinate not reduced modulo p", bytes([4]) + bytes.fromhex(be(sx) + be(yy + p)), d)
+                break
+        add("point at infinity", bytes([0]), d)
+        add("empty public key", b"", d)
+        add("truncated uncompressed public key", q[:-1], d)
+        add("uncompressed public key with trailing byte", q + b"\x00", d)
+        add("compressed public key with uncompressed prefix", bytes([4]) + q[1 : 1 + size], d)
+        add("uncompressed public key with compressed prefix", bytes([2]) + q[1:], d)
+
+        groups.append((curve, "local edge cases, expected results computed by OpenSSL (python cryptography)", cases))
+    emit(groups)
+
+
+if __name__ == "__main__":
+    if sys.argv[1:2] == ["generate"]:
+        generate()
+    else:
+        print(__doc__)
+        sys.exit(1)